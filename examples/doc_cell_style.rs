@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of using built-in and user defined named cell styles.
+
+use rust_xlsxwriter::{BuiltinCellStyle, CellStyle, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Create some built-in and user defined styles.
+    let heading = CellStyle::new_builtin(BuiltinCellStyle::Heading1);
+    let good = CellStyle::new_builtin(BuiltinCellStyle::Good);
+    let house_style = CellStyle::new(
+        "House Style",
+        &Format::new().set_bold().set_font_color("#1F4E79"),
+    );
+
+    // Add a style to the workbook without using it in a cell.
+    workbook.add_cell_style(&house_style)?;
+
+    // Create formats that inherit from the styles.
+    let heading_format = Format::new().set_cell_style(&heading);
+    let good_format = Format::new().set_cell_style(&good).set_italic();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_with_format(0, 0, "Results", &heading_format)?;
+    worksheet.write_with_format(1, 0, "Passed", &good_format)?;
+
+    workbook.save("cell_styles.xlsx")?;
+
+    Ok(())
+}
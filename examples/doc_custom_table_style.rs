@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of creating a custom table style and applying it to a table.
+
+use rust_xlsxwriter::{
+    CustomTableStyle, Format, FormatBorder, Table, TableStyleElement, Workbook, XlsxError,
+};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Some sample data for the table.
+    let items = ["Apples", "Pears", "Bananas", "Oranges"];
+    let data = [
+        [10000, 5000, 8000, 6000],
+        [2000, 3000, 4000, 5000],
+        [6000, 6000, 6500, 6000],
+        [500, 300, 200, 700],
+    ];
+
+    // Write the table data.
+    worksheet.write_column(3, 1, items)?;
+    worksheet.write_row_matrix(3, 2, data)?;
+    worksheet.set_column_range_width(1, 6, 12)?;
+
+    // Create a custom table style from formats for the table elements.
+    let style = CustomTableStyle::new("Company Style")
+        .set_format(
+            TableStyleElement::WholeTable,
+            &Format::new()
+                .set_border(FormatBorder::Thin)
+                .set_border_color("#1F4E79"),
+        )
+        .set_format(
+            TableStyleElement::HeaderRow,
+            &Format::new()
+                .set_bold()
+                .set_font_color("#FFFFFF")
+                .set_background_color("#1F4E79"),
+        )
+        .set_format(
+            TableStyleElement::FirstRowStripe,
+            &Format::new().set_background_color("#DDEBF7"),
+        );
+
+    // Create a new table and apply the custom style.
+    let table = Table::new().set_custom_style(&style);
+
+    // Add the table to the worksheet.
+    worksheet.add_table(2, 1, 6, 5, &table)?;
+
+    workbook.save("tables.xlsx")?;
+
+    Ok(())
+}
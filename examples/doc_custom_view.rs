@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding two custom views to a worksheet,
+//! one for working with the data on screen and one for printing it.
+
+use rust_xlsxwriter::{CustomView, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_column(0, 0, [10, 20, 0, 40])?;
+
+    // Create a view for editing the data on screen.
+    let edit_view = CustomView::new("Edit")
+        .set_zoom(150)
+        .set_show_zero_values(false);
+
+    // Create a view for printing the data.
+    let print_view = CustomView::new("Print")
+        .set_view_page_layout()
+        .set_landscape()
+        .set_print_gridlines(true)
+        .set_header("&CSales Data");
+
+    worksheet.add_custom_view(&edit_view);
+    worksheet.add_custom_view(&print_view);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
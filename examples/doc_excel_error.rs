@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing Excel error values to a
+//! worksheet.
+
+use rust_xlsxwriter::{ExcelError, Formula, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Write some error values.
+    worksheet.write(0, 0, ExcelError::NA)?;
+    worksheet.write(1, 0, ExcelError::Div0)?;
+
+    // Use an error value as the result of a formula.
+    worksheet.write(2, 0, Formula::new("=1/0").set_result(ExcelError::Div0))?;
+
+    // The error value is recognized by Excel functions.
+    worksheet.write(3, 0, Formula::new("=ISNA(A1)"))?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding font metrics for a monospaced
+//! font that isn't one of the built-in fonts.
+
+use rust_xlsxwriter::{FontMetrics, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // All of the characters in the font have the same width.
+    let metrics = FontMetrics::new("Consolas")
+        .set_default_width(550)
+        .set_line_height(1.3);
+
+    worksheet.set_autofit_font_metrics(&metrics);
+
+    // Write some data in the font.
+    let format = Format::new().set_font_name("Consolas").set_font_size(9);
+    worksheet.write_with_format(0, 0, "Monospaced text", &format)?;
+    worksheet.write_with_format(1, 0, "in Consolas 9", &format)?;
+
+    // Autofit the column using the font metrics.
+    worksheet.autofit();
+
+    workbook.save("font_metrics.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting linear and path gradient fills
+//! for cells.
+
+use rust_xlsxwriter::{Format, FormatGradient, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet.
+    let worksheet = workbook.add_worksheet();
+
+    // A linear gradient from top to bottom.
+    let gradient1 = FormatGradient::new()
+        .set_angle(90)
+        .add_stop(0.0, "#FFFFFF")
+        .add_stop(1.0, "#4472C4");
+
+    // A path gradient radiating from the center of the cell.
+    let gradient2 = FormatGradient::new()
+        .set_path(0.5, 0.5, 0.5, 0.5)
+        .add_stop(0.0, "#FFFFFF")
+        .add_stop(1.0, "#ED7D31");
+
+    let format1 = Format::new().set_gradient_fill(&gradient1);
+    let format2 = Format::new().set_gradient_fill(&gradient2);
+
+    worksheet.write_with_format(1, 1, "Linear", &format1)?;
+    worksheet.write_with_format(3, 1, "Path", &format2)?;
+
+    workbook.save("formats.xlsx")?;
+
+    Ok(())
+}
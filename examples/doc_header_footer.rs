@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating a worksheet header and footer
+//! with the `HeaderFooter` builder.
+
+use rust_xlsxwriter::{HeaderFooter, HeaderFooterField, HeaderFooterText, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a header with a bold title in the center section and the
+    // sheet name on the right.
+    let header = HeaderFooter::new()
+        .add_center(
+            HeaderFooterText::new("Sales & Marketing")
+                .set_bold()
+                .set_font_size(14),
+        )
+        .add_right(HeaderFooterField::SheetName);
+
+    // Create a "Page N of M" footer.
+    let footer = HeaderFooter::new()
+        .add_center("Page ")
+        .add_center(HeaderFooterField::Page)
+        .add_center(" of ")
+        .add_center(HeaderFooterField::Pages);
+
+    worksheet.set_header(header.build()?);
+    worksheet.set_footer(footer.build()?);
+
+    worksheet.write_string(0, 0, "Hello")?;
+    worksheet.set_view_page_layout();
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating number formats with the
+//! `NumFormat` builder.
+
+use rust_xlsxwriter::{
+    Format, NumFormat, NumFormatColor, NumFormatElapsed, NumFormatNegative, Workbook, XlsxError,
+};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(0, 20)?;
+
+    // A number with a thousands separator and red negative numbers.
+    let num_format = NumFormat::number(2)
+        .set_thousands_separator()
+        .set_negative_style(NumFormatNegative::Red);
+    worksheet.write_with_format(0, 0, -1234.5, &Format::try_from(&num_format)?)?;
+
+    // A Euro currency format with an ISO locale tag.
+    let num_format = NumFormat::currency("€", 2).set_locale("x-euro2");
+    worksheet.write_with_format(1, 0, 1234.5, &Format::try_from(&num_format)?)?;
+
+    // Numbers scaled to thousands.
+    let num_format = NumFormat::number(1).set_scale(1).set_suffix("K");
+    worksheet.write_with_format(2, 0, 123456, &Format::try_from(&num_format)?)?;
+
+    // Conditional sections with colors.
+    let num_format = NumFormat::number(0)
+        .set_condition_greater_than(100.0)
+        .set_color(NumFormatColor::Blue)
+        .add_section(&NumFormat::number(0).set_color(NumFormatColor::Red));
+    worksheet.write_with_format(3, 0, 200, &Format::try_from(&num_format)?)?;
+
+    // An elapsed time format.
+    let num_format = NumFormat::elapsed_time(NumFormatElapsed::HoursMinutes);
+    worksheet.write_with_format(4, 0, 1.25, &Format::try_from(&num_format)?)?;
+
+    workbook.save("num_formats.xlsx")?;
+
+    Ok(())
+}
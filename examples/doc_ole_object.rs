@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of embedding a file in a worksheet as an icon.
+
+use rust_xlsxwriter::{Image, OleObject, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write(0, 0, "Double-click the icon to open the source data:")?;
+
+    // Create an image to display the embedded object.
+    let image = Image::new("examples/rust_logo.png")?
+        .set_scale_width(0.5)
+        .set_scale_height(0.5);
+
+    // Create an object to embed some CSV data.
+    let object =
+        OleObject::new_from_buffer(b"Region,Sales\nEast,100\n", "sales.csv").set_image(&image);
+
+    // Insert the object into the worksheet.
+    worksheet.insert_object(1, 1, &object)?;
+
+    // Save the file to disk.
+    workbook.save("ole_object.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the phonetic properties of a
+//! worksheet.
+
+use rust_xlsxwriter::{
+    Format, PhoneticAlignment, PhoneticProperties, PhoneticString, PhoneticType, Workbook,
+    XlsxError,
+};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Show the phonetic text as small centered Hiragana.
+    let font = Format::new().set_font_name("Meiryo").set_font_size(6);
+    let properties = PhoneticProperties::new()
+        .set_font(&font)
+        .set_type(PhoneticType::Hiragana)
+        .set_alignment(PhoneticAlignment::Center);
+
+    worksheet.set_phonetic_properties(&properties);
+
+    let string = PhoneticString::new("漢字")
+        .add_run(0, 2, "かんじ")
+        .set_visible(true);
+
+    worksheet.write_phonetic_string(0, 0, &string)?;
+
+    workbook.save("phonetic.xlsx")?;
+
+    Ok(())
+}
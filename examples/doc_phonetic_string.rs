@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a string with phonetic guides.
+
+use rust_xlsxwriter::{PhoneticString, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add phonetic guides for the characters "東京" and "都" of "東京都".
+    let string = PhoneticString::new("東京都")
+        .add_run(0, 2, "トウキョウ")
+        .add_run(2, 3, "ト")
+        .set_visible(true);
+
+    worksheet.write_phonetic_string(0, 0, &string)?;
+
+    workbook.save("phonetic.xlsx")?;
+
+    Ok(())
+}
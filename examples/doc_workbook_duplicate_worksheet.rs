@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating several worksheets from a
+//! template worksheet.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Create a template worksheet.
+    let bold = Format::new().set_bold();
+    let worksheet = workbook.add_worksheet().set_name("Template")?;
+    worksheet.write_row_with_format(0, 0, ["Region", "Sales"], &bold)?;
+    worksheet.set_column_width(0, 20)?;
+
+    // Create a worksheet for each quarter from the template.
+    for quarter in ["Q1", "Q2", "Q3", "Q4"] {
+        let worksheet = workbook.duplicate_worksheet("Template", quarter)?;
+        worksheet.write(1, 0, quarter)?;
+    }
+
+    // The template worksheet isn't required in the output.
+    workbook.remove_worksheet("Template")?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates moving a summary worksheet, that is
+//! added last, to the start of the workbook.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add some data worksheets.
+    for region in ["North", "South", "East", "West"] {
+        let worksheet = workbook.add_worksheet().set_name(region)?;
+        worksheet.write(0, 0, 1000)?;
+    }
+
+    // Add a summary worksheet.
+    let worksheet = workbook.add_worksheet().set_name("Summary")?;
+    worksheet.write_formula(0, 0, "=SUM(North:West!A1)")?;
+
+    // Move the summary to the first tab.
+    workbook.move_worksheet(4, 0)?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of setting a custom theme for a workbook.
+
+use rust_xlsxwriter::{Color, Format, Workbook, WorkbookTheme, WorkbookThemeColor, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Create a custom theme with some brand colors and fonts.
+    let theme = WorkbookTheme::new()
+        .set_name("Brand")
+        .set_color(WorkbookThemeColor::Accent1, "#00A3E0")
+        .set_color(WorkbookThemeColor::Accent2, "#E35205")
+        .set_major_font("Georgia")
+        .set_minor_font("Verdana");
+
+    workbook.set_theme(&theme);
+
+    // Theme colors in formats resolve to the custom palette.
+    let format = Format::new().set_background_color(Color::Theme(4, 0));
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_with_format(0, 0, "Accent 1", &format)?;
+
+    workbook.save("workbook_theme.xlsx")?;
+
+    Ok(())
+}
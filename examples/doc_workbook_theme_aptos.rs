@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of creating a workbook with the Excel 365 theme colors and Aptos
+//! fonts.
+
+use rust_xlsxwriter::{Workbook, WorkbookTheme, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Use the Excel 365 theme colors and Aptos Narrow default font.
+    workbook.set_theme(&WorkbookTheme::new_aptos());
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write(0, 0, "Hello")?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the workbook window view
+//! properties to hide the worksheet tabs and scroll bars.
+
+use rust_xlsxwriter::{Workbook, WorkbookView, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Create a view without sheet tabs or scroll bars.
+    let view = WorkbookView::new()
+        .set_window_size(800, 600)
+        .set_sheet_tabs(false)
+        .set_horizontal_scroll_bar(false)
+        .set_vertical_scroll_bar(false);
+
+    workbook.set_workbook_view(&view);
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write(0, 0, "Dashboard")?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates auto-fitting the worksheet row heights
+//! for wrapped text.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    let wrap = Format::new().set_text_wrap();
+
+    // Add some wrapped text to the worksheet.
+    worksheet.write_with_format(0, 0, "Hello\nWorld", &wrap)?;
+    worksheet.write_with_format(1, 0, "Some longer text that wraps", &wrap)?;
+
+    // Autofit the columns and then the rows.
+    worksheet.autofit_to_max_width(100);
+    worksheet.autofit_rows();
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
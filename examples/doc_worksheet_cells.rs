@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates iterating over the cells written to a
+//! worksheet.
+
+use rust_xlsxwriter::{CellValue, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_column(0, 0, [10, 20, 30])?;
+    worksheet.write(3, 0, "Done")?;
+
+    // Add up the numbers in the worksheet.
+    let total: f64 = worksheet
+        .cells()
+        .filter_map(|(_, _, value)| match value {
+            CellValue::Number(number) => Some(number),
+            _ => None,
+        })
+        .sum();
+
+    assert_eq!(total, 60.0);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
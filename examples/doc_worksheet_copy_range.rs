@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates copying a block of cells, with formulas,
+//! to build up a worksheet from a template range.
+
+use rust_xlsxwriter::{Format, Formula, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let bold = Format::new().set_bold();
+
+    // Create a block of data with a total formula.
+    worksheet.merge_range(0, 0, 0, 1, "Quarter 1", &bold)?;
+    worksheet.write_column(1, 0, ["North", "South"])?;
+    worksheet.write_column(1, 1, [1000, 2000])?;
+    worksheet.write_with_format(3, 0, "Total", &bold)?;
+    worksheet.write_formula(3, 1, Formula::new("=SUM(B2:B3)"))?;
+
+    // Copy the block to the right. The formula becomes "=SUM(E2:E3)".
+    worksheet.copy_range(0, 0, 3, 1, 0, 3)?;
+    worksheet.write(0, 3, "Quarter 2")?;
+    worksheet.write_column(1, 4, [1500, 2500])?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
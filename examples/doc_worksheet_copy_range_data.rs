@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates copying a range of cells from one
+//! worksheet to another.
+
+use rust_xlsxwriter::{Format, PasteOptions, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let bold = Format::new().set_bold();
+
+    // Write some data to the first worksheet.
+    let worksheet1 = workbook.add_worksheet();
+    worksheet1.write_with_format(0, 0, "Region", &bold)?;
+    worksheet1.write_with_format(0, 1, "Sales", &bold)?;
+    worksheet1.write_column(1, 0, ["North", "South"])?;
+    worksheet1.write_column(1, 1, [1000, 2000])?;
+
+    // Copy the data.
+    let data = worksheet1.copy_range_data(0, 0, 2, 1)?;
+
+    // Paste the data into a second worksheet.
+    let worksheet2 = workbook.add_worksheet();
+    worksheet2.paste_range(1, 1, &data, &PasteOptions::new())?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates copying a range of cells with the "values
+//! only" and "transpose" paste options.
+
+use rust_xlsxwriter::{Format, Formula, PasteOptions, PasteType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let bold = Format::new().set_bold();
+
+    // Write some data and a formula to sum it.
+    worksheet.write_with_format(0, 0, "Totals", &bold)?;
+    worksheet.write_column(1, 0, [10, 20, 30])?;
+    worksheet.write_formula(4, 0, Formula::new("=SUM(A2:A4)").set_result("60"))?;
+
+    // Copy the data as values, without the formula or formatting.
+    let options = PasteOptions::new().set_type(PasteType::Values);
+    worksheet.copy_range_with_options(0, 0, 4, 0, 0, 2, &options)?;
+
+    // Copy the data and transpose it from a column to a row.
+    let options = PasteOptions::new().set_transpose(true);
+    worksheet.copy_range_with_options(0, 0, 4, 0, 7, 0, &options)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
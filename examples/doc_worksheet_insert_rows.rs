@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates inserting rows into a worksheet to add a
+//! title above previously written data.
+
+use rust_xlsxwriter::{Format, Formula, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Write some data and a total.
+    worksheet.write_column(0, 0, [10, 20, 30])?;
+    worksheet.write_formula(3, 0, Formula::new("=SUM(A1:A3)"))?;
+
+    // Insert 2 rows at the top of the worksheet and add a title.
+    worksheet.insert_rows(0, 2)?;
+    worksheet.write_with_format(0, 0, "Sales", &Format::new().set_bold())?;
+
+    // The total formula in A6 is now "=SUM(A3:A5)".
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates merging ranges of cells that contain a
+//! number and a formula.
+
+use rust_xlsxwriter::{Format, FormatAlign, FormatBorder, Formula, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let format = Format::new()
+        .set_align(FormatAlign::Center)
+        .set_border(FormatBorder::Thin)
+        .set_num_format("#,##0.00");
+
+    // Write a number to a merged range.
+    worksheet.merge_range_with_data(1, 1, 1, 2, 12345.67, &format)?;
+
+    // Write a formula to a merged range.
+    worksheet.merge_range_with_data(3, 1, 3, 2, Formula::new("=B2*2"), &format)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
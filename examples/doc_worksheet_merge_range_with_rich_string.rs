@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates merging a range of cells that contains a
+//! rich string.
+
+use rust_xlsxwriter::{Color, Format, FormatAlign, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let default = Format::default();
+    let red = Format::new().set_font_color(Color::Red);
+    let center = Format::new().set_align(FormatAlign::Center);
+
+    // Write a rich string to a merged range.
+    let segments = [(&default, "Merged "), (&red, "rich"), (&default, " string")];
+    worksheet.merge_range_with_rich_string(1, 1, 1, 3, &segments, &center)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding font metrics for autofit.
+
+use rust_xlsxwriter::{FontMetrics, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add metrics for a font with narrow digits.
+    let metrics = FontMetrics::new("Arial Narrow")
+        .set_chars_width("0123456789", 456)
+        .set_default_width(460)
+        .set_line_height(1.25);
+
+    worksheet.set_autofit_font_metrics(&metrics);
+
+    let format = Format::new().set_font_name("Arial Narrow");
+    worksheet.write_with_format(0, 0, 1234567890, &format)?;
+    worksheet.write_with_format(1, 0, "Some narrow text", &format)?;
+
+    worksheet.autofit();
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
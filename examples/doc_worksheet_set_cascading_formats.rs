@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates combining row and cell formats.
+use rust_xlsxwriter::{Color, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Turn on format cascading.
+    worksheet.set_cascading_formats(true);
+
+    // Add some formats.
+    let row_format = Format::new().set_background_color(Color::Yellow);
+    let bold_format = Format::new().set_bold();
+
+    // Set the row format.
+    worksheet.set_row_format(1, &row_format)?;
+
+    // Add some unformatted text that adopts the row format.
+    worksheet.write_string(1, 0, "Hello")?;
+
+    // Add some bold text that also keeps the row background.
+    worksheet.write_string_with_format(1, 2, "Hello", &bold_format)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
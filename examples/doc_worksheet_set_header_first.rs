@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting different headers and footers for
+//! the first page and for the odd and even pages of a worksheet.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Set a header for the first page only.
+    worksheet.set_header_first("&CAccount Statement");
+
+    // Set mirrored footers for the odd and even pages.
+    worksheet.set_footer("&RPage &P");
+    worksheet.set_footer_even("&LPage &P");
+
+    worksheet.write_string(0, 0, "Hello")?;
+    worksheet.write_string(200, 0, "Hello")?;
+    worksheet.set_view_page_layout();
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
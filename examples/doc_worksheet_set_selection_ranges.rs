@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates selecting several ranges of cells in a
+//! worksheet.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Select the cells B2:C4, E2:E4 and G6.
+    worksheet.set_selection_ranges(&[(1, 1, 3, 2), (1, 4, 3, 4), (5, 6, 5, 6)])?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
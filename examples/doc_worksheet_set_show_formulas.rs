@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates displaying the formulas in a worksheet
+//! instead of their results, and hiding zero values.
+
+use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_column(0, 0, [10, 0, 30])?;
+    worksheet.write_formula(3, 0, Formula::new("=SUM(A1:A3)"))?;
+
+    // Display the formulas instead of the results.
+    worksheet.set_show_formulas(true);
+
+    // Hide the zero values.
+    worksheet.set_show_zero_values(false);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
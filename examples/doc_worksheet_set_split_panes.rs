@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates splitting a worksheet into panes.
+
+use rust_xlsxwriter::{PanePosition, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Split the worksheet below the fifth row and right of the second
+    // column.
+    worksheet.set_split_panes(100, 128);
+
+    // Scroll the bottom right pane to cell D21.
+    worksheet.set_split_panes_top_cell(20, 3)?;
+
+    // Select some cells in the bottom right pane.
+    worksheet.set_pane_selection(PanePosition::BottomRight, &[(20, 3, 22, 4)])?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
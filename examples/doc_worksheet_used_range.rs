@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates getting the used range of a worksheet
+//! and adding a row of totals below the data.
+
+use rust_xlsxwriter::{utility, Formula, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Write some data.
+    let data = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    worksheet.write_row_matrix(1, 1, data)?;
+
+    // Add a total formula below each column of the data.
+    if let Some((first_row, first_col, last_row, last_col)) = worksheet.used_range() {
+        for col in first_col..=last_col {
+            let range = utility::cell_range(first_row, col, last_row, col);
+            let formula = Formula::new(format!("=SUM({range})"));
+
+            worksheet.write_formula(last_row + 1, col, formula)?;
+        }
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
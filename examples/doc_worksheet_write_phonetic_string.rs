@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing strings with phonetic guides to
+//! a worksheet.
+
+use rust_xlsxwriter::{Format, PhoneticString, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Write a string with phonetic guides that are shown in the cell.
+    let string = PhoneticString::new("東京都")
+        .add_run(0, 2, "トウキョウ")
+        .add_run(2, 3, "ト")
+        .set_visible(true);
+
+    worksheet.write_phonetic_string(0, 0, &string)?;
+
+    // Write a rich string with phonetic guides.
+    let default = Format::default();
+    let bold = Format::new().set_bold();
+    let string = PhoneticString::new_rich(&[(&bold, "山田"), (&default, "太郎")])
+        .add_run(0, 2, "ヤマダ")
+        .add_run(2, 4, "タロウ")
+        .set_visible(true);
+
+    worksheet.write_phonetic_string(1, 0, &string)?;
+
+    // Save the file to disk.
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
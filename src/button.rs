@@ -79,6 +79,7 @@ pub struct Button {
     pub(crate) macro_name: String,
     pub(crate) alt_text: String,
     pub(crate) object_movement: ObjectMovement,
+    pub(crate) absolute_position: Option<(u32, u32)>,
     pub(crate) decorative: bool,
 }
 
@@ -105,6 +106,7 @@ impl Button {
             alt_text: String::new(),
             macro_name: String::new(),
             object_movement: ObjectMovement::MoveAndSizeWithCells,
+            absolute_position: None,
             decorative: false,
         }
     }
@@ -289,6 +291,10 @@ impl Button {
     /// be hidden in rows or columns. In Excel this equates to option 1 above
     /// but the internal button position calculations are handled differently.
     ///
+    /// Buttons are VML objects and don't support absolute anchors. The
+    /// [`ObjectMovement::AbsoluteAnchor`] option is written in the VML file
+    /// as "Don't move or size with cells".
+    ///
     /// # Parameters
     ///
     /// - `option`: A button/object positioning behavior defined by the
@@ -298,6 +304,32 @@ impl Button {
         self
     }
 
+    /// Set the absolute position of the button in the worksheet.
+    ///
+    /// Position the button at fixed `x` and `y` pixel coordinates from the top
+    /// left corner of the worksheet instead of relative to the cell that it is
+    /// inserted in. The row, column and any offsets used to insert the button
+    /// are ignored.
+    ///
+    /// Buttons are stored as VML shapes which, unlike images and charts, don't
+    /// have an absolute anchor. Instead the pixel coordinates are converted to
+    /// a VML cell anchor using the row heights and column widths at the time
+    /// the file is saved, and the button is marked as "Don't move or size with
+    /// cells" so that Excel keeps it in place if the cells underneath it
+    /// change. As a result the button will be drawn in a different position if
+    /// the row heights or column widths are changed after the file is saved.
+    ///
+    /// # Parameters
+    ///
+    /// - `x`: The horizontal position from the left of the worksheet in pixels.
+    /// - `y`: The vertical position from the top of the worksheet in pixels.
+    ///
+    pub fn set_absolute_position(mut self, x: u32, y: u32) -> Button {
+        self.absolute_position = Some((x, y));
+        self.object_movement = ObjectMovement::AbsoluteAnchor;
+        self
+    }
+
     // Buttons are stored in a vmlDrawing file. We create a struct to store the
     // required image information in that format.
     pub(crate) fn vml_info(&self) -> VmlInfo {
@@ -327,6 +359,10 @@ impl DrawingObject for Button {
         self.y_offset
     }
 
+    fn absolute_position(&self) -> Option<(u32, u32)> {
+        self.absolute_position
+    }
+
     fn width_scaled(&self) -> f64 {
         self.width
     }
@@ -0,0 +1,355 @@
+// cell_style - A module for representing Excel named cell styles.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+use crate::{Color, Format, FormatBorder};
+
+/// The `CellStyle` struct represents a named cell style.
+///
+/// Named cell styles are shown in the Excel "Home → Cell Styles" gallery and
+/// can be applied to cells by the user. A `CellStyle` can be a user defined
+/// style created from a [`Format`] or one of Excel's built-in styles such as
+/// "Heading 1", "Good" or "Total", defined by the [`BuiltinCellStyle`] enum.
+///
+/// Styles can be added to the workbook gallery with
+/// [`Workbook::add_cell_style()`](crate::Workbook::add_cell_style). Cell
+/// formats can inherit the properties of a style via
+/// [`Format::set_cell_style()`]. Any styles used in this way are added to the
+/// workbook automatically.
+///
+/// # Examples
+///
+/// An example of using built-in and user defined named cell styles.
+///
+/// ```
+/// # // This code is available in examples/doc_cell_style.rs
+/// #
+/// use rust_xlsxwriter::{BuiltinCellStyle, CellStyle, Format, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///
+///     // Create some built-in and user defined styles.
+///     let heading = CellStyle::new_builtin(BuiltinCellStyle::Heading1);
+///     let good = CellStyle::new_builtin(BuiltinCellStyle::Good);
+///     let house_style = CellStyle::new(
+///         "House Style",
+///         &Format::new().set_bold().set_font_color("#1F4E79"),
+///     );
+///
+///     // Add a style to the workbook without using it in a cell.
+///     workbook.add_cell_style(&house_style)?;
+///
+///     // Create formats that inherit from the styles.
+///     let heading_format = Format::new().set_cell_style(&heading);
+///     let good_format = Format::new().set_cell_style(&good).set_italic();
+///
+///     let worksheet = workbook.add_worksheet();
+///     worksheet.write_with_format(0, 0, "Results", &heading_format)?;
+///     worksheet.write_with_format(1, 0, "Passed", &good_format)?;
+///
+///     workbook.save("cell_styles.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct CellStyle {
+    pub(crate) name: String,
+    pub(crate) format: Format,
+    pub(crate) builtin_id: Option<u8>,
+    pub(crate) custom_builtin: bool,
+}
+
+impl CellStyle {
+    /// Create a new user defined `CellStyle` object.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the style as it appears in the Excel cell style
+    ///   gallery. The names "Normal" and "Hyperlink" are reserved for the
+    ///   styles that `rust_xlsxwriter` creates automatically.
+    /// - `format`: The [`Format`] properties of the style.
+    ///
+    pub fn new(name: impl Into<String>, format: &Format) -> CellStyle {
+        CellStyle {
+            name: name.into(),
+            format: Self::style_format(format),
+            builtin_id: None,
+            custom_builtin: false,
+        }
+    }
+
+    /// Create a new `CellStyle` object for one of Excel's built-in styles.
+    ///
+    /// The style uses the same formatting as the equivalent Excel style in
+    /// the default theme. Theme colors in the style, such as the heading
+    /// colors, follow the workbook theme.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: A [`BuiltinCellStyle`] enum value.
+    ///
+    pub fn new_builtin(style: BuiltinCellStyle) -> CellStyle {
+        CellStyle {
+            name: style.name().to_string(),
+            format: Self::style_format(&style.format()),
+            builtin_id: Some(style.builtin_id()),
+            custom_builtin: false,
+        }
+    }
+
+    /// Set the format properties of the style.
+    ///
+    /// This can be used to modify the format of a built-in style, in which
+    /// case Excel treats it as a customized built-in style.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] properties of the style.
+    ///
+    pub fn set_format(mut self, format: &Format) -> CellStyle {
+        self.format = Self::style_format(format);
+        self.custom_builtin = self.builtin_id.is_some();
+        self
+    }
+
+    /// Get the name of the style.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // Styles can't themselves be based on another style.
+    fn style_format(format: &Format) -> Format {
+        let mut format = format.clone();
+        format.cell_style = None;
+        format
+    }
+}
+
+/// The `BuiltinCellStyle` enum defines Excel's built-in named cell styles.
+///
+/// Used with [`CellStyle::new_builtin()`].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuiltinCellStyle {
+    /// The "Title" style.
+    Title,
+
+    /// The "Heading 1" style.
+    Heading1,
+
+    /// The "Heading 2" style.
+    Heading2,
+
+    /// The "Heading 3" style.
+    Heading3,
+
+    /// The "Heading 4" style.
+    Heading4,
+
+    /// The "Good" style.
+    Good,
+
+    /// The "Bad" style.
+    Bad,
+
+    /// The "Neutral" style.
+    Neutral,
+
+    /// The "Input" style.
+    Input,
+
+    /// The "Output" style.
+    Output,
+
+    /// The "Calculation" style.
+    Calculation,
+
+    /// The "Check Cell" style.
+    CheckCell,
+
+    /// The "Linked Cell" style.
+    LinkedCell,
+
+    /// The "Note" style.
+    Note,
+
+    /// The "Warning Text" style.
+    WarningText,
+
+    /// The "Explanatory Text" style.
+    ExplanatoryText,
+
+    /// The "Total" style.
+    Total,
+
+    /// The "Comma" number style.
+    Comma,
+
+    /// The "Comma \[0\]" number style.
+    CommaNoDecimals,
+
+    /// The "Currency" number style.
+    Currency,
+
+    /// The "Currency \[0\]" number style.
+    CurrencyNoDecimals,
+
+    /// The "Percent" number style.
+    Percent,
+}
+
+impl BuiltinCellStyle {
+    // The name of the style in Excel.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Title => "Title",
+            Self::Heading1 => "Heading 1",
+            Self::Heading2 => "Heading 2",
+            Self::Heading3 => "Heading 3",
+            Self::Heading4 => "Heading 4",
+            Self::Good => "Good",
+            Self::Bad => "Bad",
+            Self::Neutral => "Neutral",
+            Self::Input => "Input",
+            Self::Output => "Output",
+            Self::Calculation => "Calculation",
+            Self::CheckCell => "Check Cell",
+            Self::LinkedCell => "Linked Cell",
+            Self::Note => "Note",
+            Self::WarningText => "Warning Text",
+            Self::ExplanatoryText => "Explanatory Text",
+            Self::Total => "Total",
+            Self::Comma => "Comma",
+            Self::CommaNoDecimals => "Comma [0]",
+            Self::Currency => "Currency",
+            Self::CurrencyNoDecimals => "Currency [0]",
+            Self::Percent => "Percent",
+        }
+    }
+
+    // The builtinId of the style in the Excel file format.
+    pub(crate) fn builtin_id(self) -> u8 {
+        match self {
+            Self::Comma => 3,
+            Self::Currency => 4,
+            Self::Percent => 5,
+            Self::CommaNoDecimals => 6,
+            Self::CurrencyNoDecimals => 7,
+            Self::Note => 10,
+            Self::WarningText => 11,
+            Self::Title => 15,
+            Self::Heading1 => 16,
+            Self::Heading2 => 17,
+            Self::Heading3 => 18,
+            Self::Heading4 => 19,
+            Self::Input => 20,
+            Self::Output => 21,
+            Self::Calculation => 22,
+            Self::CheckCell => 23,
+            Self::LinkedCell => 24,
+            Self::Total => 25,
+            Self::Good => 26,
+            Self::Bad => 27,
+            Self::Neutral => 28,
+            Self::ExplanatoryText => 53,
+        }
+    }
+
+    // The format properties of the style in the default Excel theme.
+    pub(crate) fn format(self) -> Format {
+        // The theme "Text 2" and "Accent 1" colors used by the headings.
+        let text2 = Color::Theme(3, 0);
+        let accent1 = Color::Theme(4, 0);
+
+        match self {
+            Self::Title => {
+                // The default "Cambria" font name is replaced with the theme
+                // major font when the file is written.
+                let mut format = Format::new()
+                    .set_font_scheme("major")
+                    .set_font_size(18)
+                    .set_font_color(text2);
+                format.font.name = "Cambria".to_string();
+                format
+            }
+            Self::Heading1 => Format::new()
+                .set_bold()
+                .set_font_size(15)
+                .set_font_color(text2)
+                .set_border_bottom(FormatBorder::Thick)
+                .set_border_bottom_color(accent1),
+            Self::Heading2 => Format::new()
+                .set_bold()
+                .set_font_size(13)
+                .set_font_color(text2)
+                .set_border_bottom(FormatBorder::Thick)
+                .set_border_bottom_color(Color::ThemeTint(4, 0.499_984_740_745_262)),
+            Self::Heading3 => Format::new()
+                .set_bold()
+                .set_font_color(text2)
+                .set_border_bottom(FormatBorder::Medium)
+                .set_border_bottom_color(Color::Theme(4, 3)),
+            Self::Heading4 => Format::new().set_bold().set_font_color(text2),
+            Self::Good => Format::new()
+                .set_font_color("006100")
+                .set_background_color("C6EFCE"),
+            Self::Bad => Format::new()
+                .set_font_color("9C0006")
+                .set_background_color("FFC7CE"),
+            Self::Neutral => Format::new()
+                .set_font_color("9C6500")
+                .set_background_color("FFEB9C"),
+            Self::Input => Format::new()
+                .set_font_color("3F3F76")
+                .set_background_color("FFCC99")
+                .set_border(FormatBorder::Thin)
+                .set_border_color("7F7F7F"),
+            Self::Output => Format::new()
+                .set_bold()
+                .set_font_color("3F3F3F")
+                .set_background_color("F2F2F2")
+                .set_border(FormatBorder::Thin)
+                .set_border_color("3F3F3F"),
+            Self::Calculation => Format::new()
+                .set_bold()
+                .set_font_color("FA7D00")
+                .set_background_color("F2F2F2")
+                .set_border(FormatBorder::Thin)
+                .set_border_color("7F7F7F"),
+            Self::CheckCell => Format::new()
+                .set_bold()
+                .set_font_color(Color::Theme(0, 0))
+                .set_background_color("A5A5A5")
+                .set_border(FormatBorder::Double)
+                .set_border_color("3F3F3F"),
+            Self::LinkedCell => Format::new()
+                .set_font_color("FA7D00")
+                .set_border_bottom(FormatBorder::Double)
+                .set_border_bottom_color("FF8001"),
+            Self::Note => Format::new()
+                .set_background_color("FFFFCC")
+                .set_border(FormatBorder::Thin)
+                .set_border_color("B2B2B2"),
+            Self::WarningText => Format::new().set_font_color("FF0000"),
+            Self::ExplanatoryText => Format::new().set_italic().set_font_color("7F7F7F"),
+            Self::Total => Format::new()
+                .set_bold()
+                .set_font_color(Color::Theme(1, 0))
+                .set_border_top(FormatBorder::Thin)
+                .set_border_top_color(accent1)
+                .set_border_bottom(FormatBorder::Double)
+                .set_border_bottom_color(accent1),
+            Self::Comma => Format::new().set_num_format_index(43),
+            Self::CommaNoDecimals => Format::new().set_num_format_index(41),
+            Self::Currency => Format::new().set_num_format_index(44),
+            Self::CurrencyNoDecimals => Format::new().set_num_format_index(42),
+            Self::Percent => Format::new().set_num_format_index(9),
+        }
+    }
+}
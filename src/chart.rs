@@ -743,7 +743,11 @@ mod tests;
 use std::io::Cursor;
 use std::{fmt, mem, sync::OnceLock};
 
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize};
+
 use crate::drawing::{DrawingObject, DrawingType};
+use crate::shift::ReferenceChange;
 use crate::utility::{self, ToXmlBoolean};
 use crate::xmlwriter::{
     xml_data_element_only, xml_declaration, xml_empty_tag, xml_empty_tag_only, xml_end_tag,
@@ -751,7 +755,8 @@ use crate::xmlwriter::{
 };
 
 use crate::{
-    ColNum, Color, IntoExcelDateTime, ObjectMovement, RowNum, XlsxError, COL_MAX, ROW_MAX,
+    ColNum, Color, ExcelError, IntoExcelDateTime, ObjectMovement, RowNum, XlsxError, COL_MAX,
+    ROW_MAX,
 };
 
 #[derive(Clone)]
@@ -813,6 +818,7 @@ pub struct Chart {
     pub(crate) name: String,
     pub(crate) alt_text: String,
     pub(crate) object_movement: ObjectMovement,
+    pub(crate) absolute_position: Option<(u32, u32)>,
     pub(crate) decorative: bool,
     pub(crate) drawing_type: DrawingType,
     pub(crate) series: Vec<ChartSeries>,
@@ -939,6 +945,7 @@ impl Chart {
             name: String::new(),
             alt_text: String::new(),
             object_movement: ObjectMovement::MoveAndSizeWithCells,
+            absolute_position: None,
             decorative: false,
             drawing_type: DrawingType::Chart,
 
@@ -2656,6 +2663,10 @@ impl Chart {
     ///
     /// These values are defined in the [`ObjectMovement`] enum.
     ///
+    /// The [`ObjectMovement::AbsoluteAnchor`] option can also be used to
+    /// position the chart at a fixed location in the worksheet rather than
+    /// relative to a cell. See also [`Chart::set_absolute_position()`].
+    ///
     /// # Parameters
     ///
     /// `option` - A [`ObjectMovement`] enum value.
@@ -2665,6 +2676,29 @@ impl Chart {
         self
     }
 
+    /// Set the absolute position of the chart in the worksheet.
+    ///
+    /// Position the chart at fixed `x` and `y` pixel coordinates from the top
+    /// left corner of the worksheet instead of relative to the cell that it is
+    /// inserted in. The row, column and any offsets used to insert the chart
+    /// are ignored.
+    ///
+    /// This also sets the object movement to [`ObjectMovement::AbsoluteAnchor`]
+    /// so that Excel doesn't move or resize the chart when the cells
+    /// underneath it change. This option is ignored for charts in a
+    /// chartsheet.
+    ///
+    /// # Parameters
+    ///
+    /// - `x`: The horizontal position from the left of the worksheet in pixels.
+    /// - `y`: The vertical position from the top of the worksheet in pixels.
+    ///
+    pub fn set_absolute_position(&mut self, x: u32, y: u32) -> &mut Chart {
+        self.absolute_position = Some((x, y));
+        self.object_movement = ObjectMovement::AbsoluteAnchor;
+        self
+    }
+
     /// Check a chart instance for configuration errors.
     ///
     /// Charts are validated using this methods when they are added to a
@@ -2829,6 +2863,96 @@ impl Chart {
         }
     }
 
+    // Adjust the chart ranges that refer to a worksheet where rows or columns
+    // have been inserted or deleted, or where cells have been moved.
+    pub(crate) fn shift_ranges(&mut self, change: &ReferenceChange, sheet_name: &str) {
+        for range in self.ranges_mut() {
+            range.shift(change, sheet_name);
+        }
+    }
+
+    // Change the worksheet name of the chart ranges that refer to a worksheet.
+    // This is used for charts in duplicated worksheets.
+    pub(crate) fn rename_sheet_ranges(&mut self, old_name: &str, new_name: &str) {
+        for range in self.ranges_mut() {
+            range.rename_sheet(old_name, new_name);
+        }
+    }
+
+    // Check if any of the chart ranges refer to a worksheet.
+    pub(crate) fn has_sheet_ranges(&self, sheet_name: &str) -> bool {
+        self.ranges()
+            .iter()
+            .any(|range| range.has_data() && range.is_sheet(sheet_name))
+    }
+
+    // Get all the worksheet ranges in the chart and any combined chart.
+    fn ranges(&self) -> Vec<&ChartRange> {
+        let mut ranges = vec![
+            &self.title.range,
+            &self.x_axis.title.range,
+            &self.y_axis.title.range,
+        ];
+
+        for series in &self.series {
+            ranges.push(&series.title.range);
+            ranges.push(&series.value_range);
+            ranges.push(&series.category_range);
+
+            for data_label in &series.custom_data_labels {
+                ranges.push(&data_label.title.range);
+            }
+
+            for error_bars in [&series.y_error_bars, &series.x_error_bars]
+                .into_iter()
+                .flatten()
+            {
+                ranges.push(&error_bars.plus_range);
+                ranges.push(&error_bars.minus_range);
+            }
+        }
+
+        if let Some(chart) = &self.combined_chart {
+            ranges.extend(chart.ranges());
+        }
+
+        ranges
+    }
+
+    // Get mutable references to all the worksheet ranges in the chart and any
+    // combined chart.
+    fn ranges_mut(&mut self) -> Vec<&mut ChartRange> {
+        let mut ranges = vec![
+            &mut self.title.range,
+            &mut self.x_axis.title.range,
+            &mut self.y_axis.title.range,
+        ];
+
+        for series in &mut self.series {
+            ranges.push(&mut series.title.range);
+            ranges.push(&mut series.value_range);
+            ranges.push(&mut series.category_range);
+
+            for data_label in &mut series.custom_data_labels {
+                ranges.push(&mut data_label.title.range);
+            }
+
+            for error_bars in [&mut series.y_error_bars, &mut series.x_error_bars]
+                .into_iter()
+                .flatten()
+            {
+                ranges.push(&mut error_bars.plus_range);
+                ranges.push(&mut error_bars.minus_range);
+            }
+        }
+
+        if let Some(chart) = &mut self.combined_chart {
+            ranges.extend(chart.ranges_mut());
+        }
+
+        ranges
+    }
+
     // Check for any legend entries that have been hidden/deleted via the
     // ChartSeries::delete_from_legend() and
     // ChartTrendline::delete_from_legend() methods. These can in turn be
@@ -4121,12 +4245,13 @@ impl Chart {
         // Write the c:pt elements.
         for (index, value) in cache.data.iter().enumerate() {
             if !value.is_empty() {
-                // Non numeric values in value/number caches are treated as zero
-                // by Excel.
-                if value.parse::<f64>().is_err() {
-                    self.write_pt(index, "0");
-                } else {
+                // Error values such as #N/A are written as errors, like Excel.
+                // Other non numeric values in value/number caches are treated
+                // as zero by Excel.
+                if value.parse::<f64>().is_ok() || value.parse::<ExcelError>().is_ok() {
                     self.write_pt(index, value);
+                } else {
+                    self.write_pt(index, "0");
                 }
             }
         }
@@ -6062,7 +6187,7 @@ impl Chart {
     // Write the <a:srgbClr> element.
     fn write_color(&mut self, color: Color, transparency: u8) {
         match color {
-            Color::Theme(_, _) => {
+            Color::Theme(_, _) | Color::ThemeTint(_, _) => {
                 let (scheme, lum_mod, lum_off) = color.chart_scheme();
                 if !scheme.is_empty() {
                     // Write the a:schemeClr element.
@@ -6585,6 +6710,10 @@ impl DrawingObject for Chart {
         self.y_offset
     }
 
+    fn absolute_position(&self) -> Option<(u32, u32)> {
+        self.absolute_position
+    }
+
     fn width_scaled(&self) -> f64 {
         self.width * self.scale_width
     }
@@ -8288,6 +8417,7 @@ pub struct ChartRange {
     last_row: RowNum,
     last_col: ColNum,
     range_string: String,
+    is_deleted: bool,
     pub(crate) cache: ChartRangeCacheData,
 }
 
@@ -8338,6 +8468,7 @@ impl ChartRange {
             last_row,
             last_col,
             range_string: String::new(),
+            is_deleted: false,
             cache: ChartRangeCacheData::new(),
         }
     }
@@ -8431,12 +8562,55 @@ impl ChartRange {
             last_row,
             last_col,
             range_string: range_string.to_string(),
+            is_deleted: false,
             cache: ChartRangeCacheData::new(),
         }
     }
 
+    // Adjust the range if it refers to a worksheet where rows or columns have
+    // been inserted or deleted, or where cells have been moved. Ranges that are
+    // entirely deleted, or overwritten, are written as a `#REF!` error, like
+    // Excel.
+    pub(crate) fn shift(&mut self, change: &ReferenceChange, sheet_name: &str) {
+        if !self.has_data() || self.is_deleted || !self.is_sheet(sheet_name) {
+            return;
+        }
+
+        match change.range(self.first_row, self.first_col, self.last_row, self.last_col) {
+            Some((first_row, first_col, last_row, last_col)) => {
+                self.first_row = first_row;
+                self.first_col = first_col;
+                self.last_row = last_row;
+                self.last_col = last_col;
+            }
+            None => self.is_deleted = true,
+        }
+    }
+
+    // Check if the cells of the range have been deleted.
+    pub(crate) fn is_deleted(&self) -> bool {
+        self.is_deleted
+    }
+
+    // Change the worksheet name of the range if it matches `old_name`.
+    pub(crate) fn rename_sheet(&mut self, old_name: &str, new_name: &str) {
+        if self.has_data() && self.is_sheet(old_name) {
+            self.sheet_name = new_name.to_string();
+        }
+    }
+
+    // Check if the range refers to a worksheet. Excel sheet names are case
+    // insensitive.
+    pub(crate) fn is_sheet(&self, sheet_name: &str) -> bool {
+        self.sheet_name.to_lowercase() == sheet_name.to_lowercase()
+    }
+
     // Convert the row/col range into a chart range string.
     pub(crate) fn formula(&self) -> String {
+        if self.is_deleted {
+            return self.deleted_formula();
+        }
+
         utility::chart_range(
             &self.sheet_name,
             self.first_row,
@@ -8448,6 +8622,10 @@ impl ChartRange {
 
     // Convert the row/col range into an absolute chart range string.
     pub(crate) fn formula_abs(&self) -> String {
+        if self.is_deleted {
+            return self.deleted_formula();
+        }
+
         utility::chart_range_abs(
             &self.sheet_name,
             self.first_row,
@@ -8457,6 +8635,11 @@ impl ChartRange {
         )
     }
 
+    // Get the range string for a range with deleted cells.
+    fn deleted_formula(&self) -> String {
+        format!("{}!#REF!", utility::quote_sheet_name(&self.sheet_name))
+    }
+
     // Convert the row/col range into a range error string.
     pub(crate) fn error_range(&self) -> String {
         utility::chart_error_range(
@@ -13242,6 +13425,23 @@ impl fmt::Display for ChartEmptyCells {
 /// - [`ChartFormat::set_no_border()`]: Turn off the border for the chart
 ///   object.
 ///
+/// With the `serde` feature enabled `ChartFormat` and its fill and line
+/// structs implement the `Serialize` and `Deserialize` traits. The property
+/// names match the setter methods without the `set_` prefix, such as
+/// `solid_fill`, `line`, `dash_type`, `transparency` or, for gradient fills,
+/// `type` and `gradient_stops`. Gradient stops have the `color` and
+/// `position` parameters of [`ChartGradientStop::new()`]. The enum values are
+/// in "snake case" and colors are strings as described in [`Color`]. For
+/// example, in TOML:
+///
+/// ```text
+/// no_line = true
+/// solid_fill = { color = "#4F81BD", transparency = 50 }
+/// ```
+///
+/// Deserialized values are checked in the same way as the setter methods.
+/// Values outside the setter ranges and unknown properties are an error.
+///
 /// # Examples
 ///
 /// An example of accessing the [`ChartFormat`] for data series in a chart and
@@ -13321,12 +13521,17 @@ impl fmt::Display for ChartEmptyCells {
 ///
 /// <img src="https://rustxlsxwriter.github.io/images/app_chart_pattern.png">
 ///
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ChartFormat {
     no_fill: bool,
     no_line: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     line: Option<ChartLine>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     solid_fill: Option<ChartSolidFill>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pattern_fill: Option<ChartPatternFill>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     gradient_fill: Option<ChartGradientFill>,
 }
 
@@ -13888,8 +14093,10 @@ impl ChartFormat {
 /// src="https://rustxlsxwriter.github.io/images/chart_line_formatting.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ChartLine {
     color: Color,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     width: Option<f64>,
     transparency: u8,
     dash_type: ChartLineDashType,
@@ -14311,6 +14518,7 @@ pub type ChartBorder = ChartLine;
 /// <img src="https://rustxlsxwriter.github.io/images/chart_solid_fill.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ChartSolidFill {
     color: Color,
     transparency: u8,
@@ -14522,6 +14730,7 @@ impl ChartSolidFill {
 /// <img src="https://rustxlsxwriter.github.io/images/chart_pattern_fill.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ChartPatternFill {
     background_color: Color,
     foreground_color: Color,
@@ -14695,6 +14904,8 @@ impl ChartPatternFill {
 
 /// The `ChartLineDashType` enum defines the [`Chart`] line dash types.
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum ChartLineDashType {
     /// Solid - chart line/border dash type.
     ///
@@ -14754,6 +14965,8 @@ impl fmt::Display for ChartLineDashType {
 
 /// The `ChartPatternFillType` enum defines the [`Chart`] pattern fill types.
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum ChartPatternFillType {
     /// Dotted 5 percent - chart fill pattern.
     ///
@@ -16337,7 +16550,9 @@ impl fmt::Display for ChartTrendlineType {
 /// <img src="https://rustxlsxwriter.github.io/images/chart_gradient_fill.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ChartGradientFill {
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
     gradient_type: ChartGradientFillType,
     gradient_stops: Vec<ChartGradientStop>,
     angle: u16,
@@ -16661,6 +16876,7 @@ impl ChartGradientFill {
 ///
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ChartGradientStop {
     color: Color,
     position: u8,
@@ -16722,6 +16938,8 @@ impl ChartGradientStop {
 ///
 /// <img src="https://rustxlsxwriter.github.io/images/chart_gradient_fill_types.png">
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum ChartGradientFillType {
     /// The gradient runs linearly from the top of the area vertically to the
     /// bottom. This is the default.
@@ -18005,3 +18223,247 @@ impl ChartLayout {
             || self.height.is_some()
     }
 }
+
+// -----------------------------------------------------------------------
+// ChartFormat serde support.
+// -----------------------------------------------------------------------
+
+// The deserialized representations of the chart format structs. The field
+// names match the setter methods and the values are passed through the
+// setters after they are checked.
+#[cfg(feature = "serde")]
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ChartFormatConfig {
+    no_fill: bool,
+    no_line: bool,
+    line: Option<ChartLine>,
+    solid_fill: Option<ChartSolidFill>,
+    pattern_fill: Option<ChartPatternFill>,
+    gradient_fill: Option<ChartGradientFill>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ChartLineConfig {
+    color: Option<Color>,
+    width: Option<f64>,
+    transparency: Option<u8>,
+    dash_type: Option<ChartLineDashType>,
+    hidden: bool,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ChartSolidFillConfig {
+    color: Option<Color>,
+    transparency: Option<u8>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ChartPatternFillConfig {
+    background_color: Option<Color>,
+    foreground_color: Option<Color>,
+    pattern: Option<ChartPatternFillType>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChartGradientFillConfig {
+    #[serde(default, rename = "type")]
+    gradient_type: Option<ChartGradientFillType>,
+    gradient_stops: Vec<ChartGradientStopConfig>,
+    #[serde(default)]
+    angle: Option<u16>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChartGradientStopConfig {
+    color: Color,
+    position: u8,
+}
+
+// Check a transparency value in the same way as the setters.
+#[cfg(feature = "serde")]
+fn check_transparency<E: de::Error>(transparency: u8) -> Result<u8, E> {
+    if transparency > 100 {
+        return Err(E::custom(format!(
+            "transparency '{transparency}' outside range: 0 <= transparency <= 100"
+        )));
+    }
+
+    Ok(transparency)
+}
+
+/// Implementation of the `serde::Deserialize` trait for `ChartFormat`.
+///
+/// Unknown properties and values that the setter methods would reject are
+/// an error. See [`ChartFormat`] for details of the schema.
+///
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ChartFormat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let config = ChartFormatConfig::deserialize(deserializer)?;
+        let mut format = ChartFormat::new();
+
+        if config.no_fill {
+            format.set_no_fill();
+        }
+        if config.no_line {
+            format.set_no_line();
+        }
+        if let Some(line) = config.line {
+            format.set_line(&line);
+        }
+        if let Some(fill) = config.solid_fill {
+            format.set_solid_fill(&fill);
+        }
+        if let Some(fill) = config.pattern_fill {
+            format.set_pattern_fill(&fill);
+        }
+        if let Some(fill) = config.gradient_fill {
+            format.set_gradient_fill(&fill);
+        }
+
+        Ok(format)
+    }
+}
+
+/// Implementation of the `serde::Deserialize` trait for `ChartLine`.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ChartLine {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let config = ChartLineConfig::deserialize(deserializer)?;
+        let mut line = ChartLine::new();
+
+        if let Some(color) = config.color {
+            line.set_color(color);
+        }
+        if let Some(width) = config.width {
+            if !(0.0..=1584.0).contains(&width) {
+                return Err(de::Error::custom(format!(
+                    "line width '{width}' outside range: 0 <= width <= 1584"
+                )));
+            }
+            line.set_width(width);
+        }
+        if let Some(transparency) = config.transparency {
+            line.set_transparency(check_transparency(transparency)?);
+        }
+        if let Some(dash_type) = config.dash_type {
+            line.set_dash_type(dash_type);
+        }
+        line.set_hidden(config.hidden);
+
+        Ok(line)
+    }
+}
+
+/// Implementation of the `serde::Deserialize` trait for `ChartSolidFill`.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ChartSolidFill {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let config = ChartSolidFillConfig::deserialize(deserializer)?;
+        let mut fill = ChartSolidFill::new();
+
+        if let Some(color) = config.color {
+            fill.set_color(color);
+        }
+        if let Some(transparency) = config.transparency {
+            fill.set_transparency(check_transparency(transparency)?);
+        }
+
+        Ok(fill)
+    }
+}
+
+/// Implementation of the `serde::Deserialize` trait for `ChartPatternFill`.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ChartPatternFill {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let config = ChartPatternFillConfig::deserialize(deserializer)?;
+        let mut fill = ChartPatternFill::new();
+
+        if let Some(color) = config.background_color {
+            fill.set_background_color(color);
+        }
+        if let Some(color) = config.foreground_color {
+            fill.set_foreground_color(color);
+        }
+        if let Some(pattern) = config.pattern {
+            fill.set_pattern(pattern);
+        }
+
+        Ok(fill)
+    }
+}
+
+/// Implementation of the `serde::Deserialize` trait for `ChartGradientFill`.
+///
+/// The fill requires between 2 and 10 `gradient_stops`.
+///
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ChartGradientFill {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let config = ChartGradientFillConfig::deserialize(deserializer)?;
+        let mut fill = ChartGradientFill::new();
+
+        if let Some(gradient_type) = config.gradient_type {
+            fill.set_type(gradient_type);
+        }
+
+        if let Some(angle) = config.angle {
+            if angle >= 360 {
+                return Err(de::Error::custom(format!(
+                    "gradient angle '{angle}' outside range: 0 <= angle < 360"
+                )));
+            }
+            fill.set_angle(angle);
+        }
+
+        if !(2..=10).contains(&config.gradient_stops.len()) {
+            return Err(de::Error::custom(
+                "gradient stops must contain between 2 and 10 entries",
+            ));
+        }
+
+        let mut gradient_stops = vec![];
+        for stop in config.gradient_stops {
+            if !stop.color.is_valid() {
+                return Err(de::Error::custom("gradient stop color isn't valid"));
+            }
+            if stop.position > 100 {
+                return Err(de::Error::custom(format!(
+                    "gradient stop position '{}' outside range: 0 <= position <= 100",
+                    stop.position
+                )));
+            }
+            gradient_stops.push(ChartGradientStop::new(stop.color, stop.position));
+        }
+        fill.set_gradient_stops(&gradient_stops);
+
+        Ok(fill)
+    }
+}
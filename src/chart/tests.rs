@@ -9,7 +9,7 @@ mod chart_tests {
 
     use crate::chart::{Chart, ChartRange, ChartSeries, ChartType, XlsxError};
     use crate::test_functions::xml_to_vec;
-    use crate::{xmlwriter, ChartRangeCacheDataType};
+    use crate::{xmlwriter, ChartRangeCacheData, ChartRangeCacheDataType};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -294,4 +294,123 @@ mod chart_tests {
         assert_eq!("'Sheet 1'!$A$1:$A$5", range.formula_abs());
         assert_eq!("Sheet 1", range.sheet_name);
     }
+
+    #[test]
+    fn test_num_cache_with_errors() {
+        let mut chart = Chart::new(ChartType::Line);
+        let mut cache = ChartRangeCacheData::new();
+        cache.cache_type = ChartRangeCacheDataType::Number;
+        cache.data = vec![
+            "1".to_string(),
+            "#N/A".to_string(),
+            "#DIV/0!".to_string(),
+            "Text".to_string(),
+        ];
+
+        chart.write_num_cache(&cache);
+
+        let got = xmlwriter::cursor_to_str(&chart.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <c:numCache>
+              <c:formatCode>General</c:formatCode>
+              <c:ptCount val="4"/>
+              <c:pt idx="0">
+                <c:v>1</c:v>
+              </c:pt>
+              <c:pt idx="1">
+                <c:v>#N/A</c:v>
+              </c:pt>
+              <c:pt idx="2">
+                <c:v>#DIV/0!</c:v>
+              </c:pt>
+              <c:pt idx="3">
+                <c:v>0</c:v>
+              </c:pt>
+            </c:numCache>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_chart_format_serde() {
+        use crate::{
+            ChartFormat, ChartGradientFill, ChartGradientFillType, ChartGradientStop, ChartLine,
+            ChartLineDashType, ChartPatternFill, ChartPatternFillType, ChartSolidFill, Color,
+        };
+
+        let json = r#"{
+            "line": {"color": "red", "width": 2.5, "dash_type": "long_dash"},
+            "solid_fill": {"color": "theme(4, 0)", "transparency": 50}
+        }"#;
+
+        let mut expected = ChartFormat::new();
+        expected
+            .set_line(
+                ChartLine::new()
+                    .set_color(Color::Red)
+                    .set_width(2.5)
+                    .set_dash_type(ChartLineDashType::LongDash),
+            )
+            .set_solid_fill(
+                ChartSolidFill::new()
+                    .set_color(Color::Theme(4, 0))
+                    .set_transparency(50),
+            );
+
+        let format: ChartFormat = serde_json::from_str(json).unwrap();
+        assert!(format == expected);
+
+        // Test round trips.
+        let mut pattern = ChartFormat::new();
+        pattern.set_no_line().set_pattern_fill(
+            ChartPatternFill::new()
+                .set_pattern(ChartPatternFillType::DiagonalBrick)
+                .set_foreground_color("#FF0000"),
+        );
+
+        let mut gradient = ChartFormat::new();
+        gradient.set_gradient_fill(
+            ChartGradientFill::new()
+                .set_type(ChartGradientFillType::Radial)
+                .set_gradient_stops(&[
+                    ChartGradientStop::new(Color::White, 0),
+                    ChartGradientStop::new(Color::Blue, 100),
+                ]),
+        );
+
+        // The gradient type uses the setter name.
+        let json = serde_json::to_string(&gradient).unwrap();
+        assert!(json.contains(r#""type":"radial""#));
+
+        for format in [expected, pattern, gradient] {
+            let json = serde_json::to_string(&format).unwrap();
+            assert!(format == serde_json::from_str::<ChartFormat>(&json).unwrap());
+        }
+
+        // Test values that the setters reject and unknown properties.
+        let invalid = [
+            r#"{"solid_fill": {"transparency": 250}}"#,
+            r#"{"line": {"transparency": 101}}"#,
+            r#"{"line": {"width": 2000}}"#,
+            r#"{"gradient_fill": {"gradient_stops": []}}"#,
+            r#"{"gradient_fill": {"angle": 999, "gradient_stops": [
+                {"color": "white", "position": 0}, {"color": "blue", "position": 100}]}}"#,
+            r#"{"gradient_fill": {"gradient_stops": [
+                {"color": "white", "position": 0}, {"color": "blue", "position": 101}]}}"#,
+            r#"{"gradient_fill": {"gradient_type": "radial", "gradient_stops": [
+                {"color": "white", "position": 0}, {"color": "blue", "position": 100}]}}"#,
+            r#"{"typo": true}"#,
+            r#"{"pattern_fill": {"typo": true}}"#,
+        ];
+
+        for json in invalid {
+            assert!(serde_json::from_str::<ChartFormat>(json).is_err(), "{json}");
+        }
+    }
 }
@@ -8,10 +8,15 @@
 
 mod tests;
 
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
 /// The `Color` enum defines Excel colors that can be used throughout the
 /// `rust_xlsxwriter` APIs.
 ///
-/// There are 3 types of colors within the enum:
+/// There are 4 types of colors within the enum:
 ///
 /// 1. Predefined named colors like `Color::Green`.
 /// 2. User defined RGB colors such as `Color::RGB(0x4F026A)` using a format
@@ -27,9 +32,12 @@ mod tests;
 ///    variant in the associated column from 0-5. For example "White, background
 ///    1" in the top left is `Theme(0, 0)` and "Orange, Accent 6, Darker 50%" in
 ///    the bottom right is `Theme(9, 5)`.
+/// 4. Theme colors with an arbitrary tint like `Color::ThemeTint(4, 0.25)`.
+///    This allows any shade of a theme color to be used, such as the colors
+///    in files created by Excel that aren't on the standard palette.
 ///
-/// Note, there are no plans to support anything other than the default Excel
-/// "Office" theme.
+/// Theme colors are resolved by Excel using the theme of the workbook. See
+/// [`WorkbookTheme`](crate::WorkbookTheme) for setting a custom theme.
 ///
 /// # Examples
 ///
@@ -135,7 +143,19 @@ mod tests;
 ///
 /// <img src="https://rustxlsxwriter.github.io/images/into_color.png">
 ///
-#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
+/// # Serde support
+///
+/// With the `serde` feature enabled `Color` implements the `Serialize` and
+/// `Deserialize` traits. Colors are represented as strings so that they can be
+/// used in configuration files:
+///
+/// - RGB colors: `"#4F026A"`. The `#` is optional when deserializing. An
+///   integer RGB value is also accepted.
+/// - Named colors: `"red"`, `"green"`, `"automatic"`, `"default"`, etc.
+/// - Theme colors: `"theme(9, 4)"`.
+/// - Theme colors with a tint: `"theme_tint(4, 0.25)"`.
+///
+#[derive(Debug, Clone, Copy, Default)]
 pub enum Color {
     /// A user-defined RGB color in the range 0x000000 (black) to 0xFFFFFF
     /// (white). Any values outside this range will be ignored with a warning.
@@ -148,6 +168,26 @@ pub enum Color {
     /// warning.
     Theme(u8, u8),
 
+    /// A theme color with an arbitrary tint. The syntax is `ThemeTint(color,
+    /// tint)` where `color` is the theme color index and `tint` is a value in
+    /// the range -1.0 (darkest) to 1.0 (lightest), with 0.0 as the unmodified
+    /// theme color. The theme color indices are:
+    ///
+    /// | Index | Theme color                |
+    /// | :---- | :------------------------- |
+    /// | 0     | Background 1 (Light 1)     |
+    /// | 1     | Text 1 (Dark 1)            |
+    /// | 2     | Background 2 (Light 2)     |
+    /// | 3     | Text 2 (Dark 2)            |
+    /// | 4 - 9 | Accent 1 - Accent 6        |
+    /// | 10    | Hyperlink                  |
+    /// | 11    | Followed Hyperlink         |
+    ///
+    /// For example `ThemeTint(4, 0.4)` is "Accent 1, Lighter 60%" in the
+    /// Excel color picker and `ThemeTint(4, -0.25)` is "Accent 1, Darker
+    /// 25%". Any values outside these ranges will be ignored with a warning.
+    ThemeTint(u8, f64),
+
     /// The default color for an Excel property.
     #[default]
     Default,
@@ -227,9 +267,11 @@ impl Color {
             Color::RGB(color) => format!("{color:06X}"),
 
             // Default to black for non RGB colors.
-            Color::Theme(_, _) | Color::Default | Color::Automatic | Color::Black => {
-                "000000".to_string()
-            }
+            Color::Theme(_, _)
+            | Color::ThemeTint(_, _)
+            | Color::Default
+            | Color::Automatic
+            | Color::Black => "000000".to_string(),
         }
     }
 
@@ -237,7 +279,9 @@ impl Color {
     pub(crate) fn vml_rgb_hex_value(self) -> String {
         match self {
             // Use Comment default color for non RGB colors.
-            Color::Theme(_, _) | Color::Default | Color::Automatic => "#ffffe1".to_string(),
+            Color::Theme(_, _) | Color::ThemeTint(_, _) | Color::Default | Color::Automatic => {
+                "#ffffe1".to_string()
+            }
             _ => {
                 let rgb_color = Self::rgb_hex_value(self).to_lowercase();
                 format!("#{rgb_color}")
@@ -355,11 +399,46 @@ impl Color {
                 },
             },
 
+            // The 0 tint is omitted from the attributes.
+            Self::ThemeTint(color, tint) => {
+                if tint == 0.0 {
+                    vec![("theme", color.to_string())]
+                } else {
+                    vec![("theme", color.to_string()), ("tint", tint.to_string())]
+                }
+            }
+
             // Handle RGB color.
             _ => vec![("rgb", self.argb_hex_value())],
         }
     }
 
+    // Get the color code used in header/footer strings. RGB colors are written
+    // as `RRGGBB` and theme colors as `TT+NNN` or `TT-NNN` where `TT` is the
+    // theme color index and `NNN` is the tint as a lighter or darker percentage.
+    pub(crate) fn header_footer_value(self) -> String {
+        match self {
+            Color::Theme(_, _) | Color::ThemeTint(_, _) => {
+                let mut theme = 0;
+                let mut tint = 0.0;
+
+                for (name, value) in self.attributes() {
+                    match name {
+                        "theme" => theme = value.parse().unwrap_or_default(),
+                        "tint" => tint = value.parse().unwrap_or_default(),
+                        _ => {}
+                    }
+                }
+
+                let percent = (tint * 100.0_f64).round() as i32;
+                let sign = if percent < 0 { '-' } else { '+' };
+
+                format!("{theme:02}{sign}{:03}", percent.abs())
+            }
+            _ => self.rgb_hex_value(),
+        }
+    }
+
     // Convert theme colors into the luminance modulation and offset values used
     // in chart theme colors.
     pub(crate) fn chart_scheme(self) -> (String, u32, u32) {
@@ -458,6 +537,35 @@ impl Color {
                 _ => (String::new(), 0, 0),
             },
 
+            // Lighter tints are a luminance modulation plus an offset and
+            // darker tints are a modulation only.
+            Self::ThemeTint(color, tint) => {
+                let scheme = match color {
+                    0 => "bg1".to_string(),
+                    1 => "tx1".to_string(),
+                    2 => "bg2".to_string(),
+                    3 => "tx2".to_string(),
+                    4..=9 => format!("accent{}", color - 3),
+                    10 => "hlink".to_string(),
+                    11 => "folHlink".to_string(),
+                    _ => return (String::new(), 0, 0),
+                };
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let (lum_mod, lum_off) = if tint > 0.0 {
+                    (
+                        ((1.0 - tint) * 100_000.0).round() as u32,
+                        (tint * 100_000.0).round() as u32,
+                    )
+                } else if tint < 0.0 {
+                    (((1.0 + tint) * 100_000.0).round() as u32, 0)
+                } else {
+                    (0, 0)
+                };
+
+                (scheme, lum_mod, lum_off)
+            }
+
             // Handle RGB color with an empty default.
             _ => (String::new(), 0, 0),
         }
@@ -488,6 +596,17 @@ impl Color {
                 }
                 true
             }
+            Color::ThemeTint(color, tint) => {
+                if color > 11 {
+                    eprintln!("Theme color '{color}' must be in the the range 0 - 11.");
+                    return false;
+                }
+                if !(-1.0..=1.0).contains(&tint) {
+                    eprintln!("Theme tint '{tint}' must be in the the range -1.0 - 1.0.");
+                    return false;
+                }
+                true
+            }
             _ => true,
         }
     }
@@ -496,6 +615,108 @@ impl Color {
     pub(crate) fn is_auto_or_default(self) -> bool {
         self == Color::Automatic || self == Color::Default
     }
+
+    // Get the index of the nearest color in Excel's default 56 color palette.
+    // This is used for elements like the worksheet gridline color that only
+    // support indexed colors. The automatic/default color has index 64.
+    #[allow(clippy::unreadable_literal)]
+    pub(crate) fn palette_index(self) -> u8 {
+        const PALETTE: [u32; 56] = [
+            0x000000, 0xFFFFFF, 0xFF0000, 0x00FF00, 0x0000FF, 0xFFFF00, 0xFF00FF, 0x00FFFF,
+            0x800000, 0x008000, 0x000080, 0x808000, 0x800080, 0x008080, 0xC0C0C0, 0x808080,
+            0x9999FF, 0x993366, 0xFFFFCC, 0xCCFFFF, 0x660066, 0xFF8080, 0x0066CC, 0xCCCCFF,
+            0x000080, 0xFF00FF, 0xFFFF00, 0x00FFFF, 0x800080, 0x800000, 0x008080, 0x0000FF,
+            0x00CCFF, 0xCCFFFF, 0xCCFFCC, 0xFFFF99, 0x99CCFF, 0xFF99CC, 0xCC99FF, 0xFFCC99,
+            0x3366FF, 0x33CCCC, 0x99CC00, 0xFFCC00, 0xFF9900, 0xFF6600, 0x666699, 0x969696,
+            0x003366, 0x339966, 0x003300, 0x333300, 0x993300, 0x993366, 0x333399, 0x333333,
+        ];
+
+        if self.is_auto_or_default() {
+            return 64;
+        }
+
+        let rgb = u32::from_str_radix(&self.rgb_hex_value(), 16).unwrap_or_default();
+        let distance = |color: u32| -> i64 {
+            [16, 8, 0]
+                .iter()
+                .map(|shift| {
+                    let diff =
+                        i64::from((rgb >> shift) & 0xFF) - i64::from((color >> shift) & 0xFF);
+                    diff * diff
+                })
+                .sum()
+        };
+
+        // The first match is used for duplicate colors in the palette.
+        let mut index = 8;
+        let mut min_distance = i64::MAX;
+        for (palette_index, color) in (8..).zip(PALETTE) {
+            if distance(color) < min_distance {
+                min_distance = distance(color);
+                index = palette_index;
+            }
+        }
+
+        index
+    }
+}
+
+// Colors are compared and hashed for format deduplication. The tint of a
+// `ThemeTint` color is compared by value, with -0.0 treated as 0.0, which
+// isn't possible with a derive due to the f64 field.
+impl PartialEq for Color {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Color::RGB(a), Color::RGB(b)) => a == b,
+            (Color::Theme(a1, a2), Color::Theme(b1, b2)) => a1 == b1 && a2 == b2,
+            (Color::ThemeTint(a1, a2), Color::ThemeTint(b1, b2)) => {
+                a1 == b1 && Self::tint_bits(*a2) == Self::tint_bits(*b2)
+            }
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Color {}
+
+impl Hash for Color {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Color::RGB(color) => color.hash(state),
+            Color::Theme(color, shade) => {
+                color.hash(state);
+                shade.hash(state);
+            }
+            Color::ThemeTint(color, tint) => {
+                color.hash(state);
+                Self::tint_bits(*tint).hash(state);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Color {
+    // Get a hashable representation of a tint value.
+    fn tint_bits(tint: f64) -> u64 {
+        if tint == 0.0 {
+            0
+        } else {
+            tint.to_bits()
+        }
+    }
+
+    // Parse a hex RGB string like "6495ED". The digits are checked explicitly
+    // since from_str_radix() allows a leading sign.
+    fn parse_hex_rgb(hex_string: &str) -> Option<u32> {
+        if hex_string.is_empty() || !hex_string.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        u32::from_str_radix(hex_string, 16).ok()
+    }
 }
 
 /// Convert from a u32 RGB value line 0xDAA520 into a [`Color`] enum value.
@@ -508,18 +729,181 @@ impl From<u32> for Color {
 /// Convert from a Html style color string line "#6495ED" into a [`Color`] enum value.
 impl From<&str> for Color {
     fn from(value: &str) -> Color {
-        let color = if let Some(hex_string) = value.strip_prefix('#') {
-            u32::from_str_radix(hex_string, 16)
-        } else {
-            u32::from_str_radix(value, 16)
-        };
-
-        match color {
-            Ok(color) => Color::RGB(color),
-            Err(_) => {
+        match Color::parse_hex_rgb(value.strip_prefix('#').unwrap_or(value)) {
+            Some(color) => Color::RGB(color),
+            None => {
                 eprintln!("Error parsing '{value}' to RGB color.");
                 Color::Default
             }
         }
     }
 }
+
+// -----------------------------------------------------------------------
+// Serde support.
+// -----------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+impl Color {
+    // The string names of the named colors.
+    const NAMED_COLORS: [(&'static str, Color); 18] = [
+        ("default", Color::Default),
+        ("automatic", Color::Automatic),
+        ("black", Color::Black),
+        ("blue", Color::Blue),
+        ("brown", Color::Brown),
+        ("cyan", Color::Cyan),
+        ("gray", Color::Gray),
+        ("green", Color::Green),
+        ("lime", Color::Lime),
+        ("magenta", Color::Magenta),
+        ("navy", Color::Navy),
+        ("orange", Color::Orange),
+        ("pink", Color::Pink),
+        ("purple", Color::Purple),
+        ("red", Color::Red),
+        ("silver", Color::Silver),
+        ("white", Color::White),
+        ("yellow", Color::Yellow),
+    ];
+
+    // Convert a color to its serialized string representation.
+    fn to_serde_string(self) -> String {
+        match self {
+            Color::RGB(color) => format!("#{color:06X}"),
+            Color::Theme(color, shade) => format!("theme({color}, {shade})"),
+            Color::ThemeTint(color, tint) => format!("theme_tint({color}, {tint})"),
+            _ => Self::NAMED_COLORS
+                .iter()
+                .find(|(_, named_color)| *named_color == self)
+                .map(|(name, _)| (*name).to_string())
+                .unwrap_or_default(),
+        }
+    }
+
+    // Parse a color from its serialized string representation.
+    fn from_serde_str(value: &str) -> Result<Color, String> {
+        let value = value.trim();
+        let lowercase = value.to_lowercase();
+
+        if let Some((_, color)) = Self::NAMED_COLORS
+            .iter()
+            .find(|(name, _)| *name == lowercase)
+        {
+            return Ok(*color);
+        }
+
+        if let Some(args) = Self::theme_args(&lowercase, "theme_tint(") {
+            let color = args.0.parse::<u8>().ok().filter(|color| *color <= 11);
+            let tint = args
+                .1
+                .parse::<f64>()
+                .ok()
+                .filter(|tint| (-1.0..=1.0).contains(tint));
+
+            return match (color, tint) {
+                (Some(color), Some(tint)) => Ok(Color::ThemeTint(color, tint)),
+                _ => Err(format!(
+                    "theme tint color '{value}' must be in the range (0-11, -1.0-1.0)"
+                )),
+            };
+        }
+
+        if let Some(args) = Self::theme_args(&lowercase, "theme(") {
+            let color = args.0.parse::<u8>().ok().filter(|color| *color <= 9);
+            let shade = args.1.parse::<u8>().ok().filter(|shade| *shade <= 5);
+
+            return match (color, shade) {
+                (Some(color), Some(shade)) => Ok(Color::Theme(color, shade)),
+                _ => Err(format!(
+                    "theme color '{value}' must be in the range (0-9, 0-5)"
+                )),
+            };
+        }
+
+        let hex_string = value.strip_prefix('#').unwrap_or(value);
+        if hex_string.len() != 6 {
+            return Err(format!("unknown color '{value}'"));
+        }
+
+        match Self::parse_hex_rgb(hex_string) {
+            Some(color) => Ok(Color::RGB(color)),
+            None => Err(format!("unknown color '{value}'")),
+        }
+    }
+
+    // Split the arguments of a "theme(a, b)" style string.
+    fn theme_args<'a>(value: &'a str, prefix: &str) -> Option<(&'a str, &'a str)> {
+        let args = value.strip_prefix(prefix)?.strip_suffix(')')?;
+        let (first, second) = args.split_once(',')?;
+
+        Some((first.trim(), second.trim()))
+    }
+}
+
+/// Implementation of the `serde::Serialize` trait for `Color`.
+///
+/// Colors are serialized as strings like `"#4F026A"`, `"red"`, `"theme(9, 4)"`
+/// or `"theme_tint(4, 0.25)"`.
+///
+#[cfg(feature = "serde")]
+impl Serialize for Color {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_serde_string())
+    }
+}
+
+/// Implementation of the `serde::Deserialize` trait for `Color`.
+///
+/// Colors are deserialized from the strings shown above, or from an integer
+/// RGB value.
+///
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ColorVisitor;
+
+        impl de::Visitor<'_> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a color string like \"#4F026A\" or \"theme(9, 4)\"")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Color, E>
+            where
+                E: de::Error,
+            {
+                Color::from_serde_str(value).map_err(E::custom)
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Color, E>
+            where
+                E: de::Error,
+            {
+                match u32::try_from(value) {
+                    Ok(color) if color <= 0xFF_FFFF => Ok(Color::RGB(color)),
+                    _ => Err(E::custom(format!("RGB color '{value:#X}' is out of range"))),
+                }
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Color, E>
+            where
+                E: de::Error,
+            {
+                match u64::try_from(value) {
+                    Ok(value) => self.visit_u64(value),
+                    Err(_) => Err(E::custom(format!("RGB color '{value}' is out of range"))),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
@@ -8,6 +8,7 @@
 mod format_tests {
 
     use crate::Color;
+    use std::collections::HashSet;
 
     #[test]
     fn test_hex_value() {
@@ -31,4 +32,147 @@ mod format_tests {
         assert_eq!("FFABCDEF", Color::RGB(0xABCDEF).argb_hex_value());
         assert_eq!("FF000000", Color::Theme(2, 1).argb_hex_value());
     }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Color::RGB(0x6495ED), Color::from("#6495ED"));
+        assert_eq!(Color::RGB(0x6495ED), Color::from("6495ed"));
+
+        // Signs and non-hex characters aren't valid.
+        assert_eq!(Color::Default, Color::from("#+12345"));
+        assert_eq!(Color::Default, Color::from("-12345"));
+        assert_eq!(Color::Default, Color::from("#12 345"));
+        assert_eq!(Color::Default, Color::from("#"));
+    }
+
+    #[test]
+    fn test_palette_index() {
+        assert_eq!(64, Color::Default.palette_index());
+        assert_eq!(64, Color::Automatic.palette_index());
+        assert_eq!(8, Color::Black.palette_index());
+        assert_eq!(10, Color::Red.palette_index());
+        assert_eq!(12, Color::Blue.palette_index());
+        assert_eq!(22, Color::Silver.palette_index());
+        assert_eq!(53, Color::Orange.palette_index());
+        assert_eq!(45, Color::Pink.palette_index());
+        assert_eq!(10, Color::RGB(0xFE0101).palette_index());
+        assert_eq!(8, Color::Theme(2, 1).palette_index());
+    }
+
+    #[test]
+    fn test_theme_tint_attributes() {
+        assert_eq!(
+            vec![("theme", "4".to_string())],
+            Color::ThemeTint(4, 0.0).attributes()
+        );
+        assert_eq!(
+            vec![("theme", "4".to_string()), ("tint", "0.4".to_string())],
+            Color::ThemeTint(4, 0.4).attributes()
+        );
+        assert_eq!(
+            vec![("theme", "11".to_string()), ("tint", "-0.25".to_string())],
+            Color::ThemeTint(11, -0.25).attributes()
+        );
+        assert_eq!("FF000000", Color::ThemeTint(4, 0.4).argb_hex_value());
+    }
+
+    #[test]
+    fn test_theme_tint_chart_scheme() {
+        // Tints equivalent to the theme palette shades.
+        assert_eq!(
+            Color::Theme(4, 1).chart_scheme(),
+            Color::ThemeTint(4, 0.8).chart_scheme()
+        );
+        assert_eq!(
+            Color::Theme(9, 5).chart_scheme(),
+            Color::ThemeTint(9, -0.5).chart_scheme()
+        );
+        assert_eq!(
+            Color::Theme(1, 0).chart_scheme(),
+            Color::ThemeTint(1, 0.0).chart_scheme()
+        );
+
+        assert_eq!(
+            ("hlink".to_string(), 90000, 0),
+            Color::ThemeTint(10, -0.1).chart_scheme()
+        );
+        assert_eq!(
+            ("folHlink".to_string(), 67000, 33000),
+            Color::ThemeTint(11, 0.33).chart_scheme()
+        );
+    }
+
+    #[test]
+    fn test_theme_tint_validation() {
+        assert!(Color::ThemeTint(11, 1.0).is_valid());
+        assert!(Color::ThemeTint(0, -1.0).is_valid());
+        assert!(!Color::ThemeTint(12, 0.0).is_valid());
+        assert!(!Color::ThemeTint(4, 1.5).is_valid());
+        assert!(!Color::ThemeTint(4, f64::NAN).is_valid());
+    }
+
+    #[test]
+    fn test_theme_tint_equality() {
+        assert_eq!(Color::ThemeTint(4, 0.0), Color::ThemeTint(4, -0.0));
+        assert_ne!(Color::ThemeTint(4, 0.4), Color::ThemeTint(5, 0.4));
+        assert_ne!(Color::ThemeTint(4, 0.4), Color::ThemeTint(4, 0.5));
+        assert_ne!(Color::ThemeTint(4, 0.0), Color::Theme(4, 0));
+
+        let colors: HashSet<Color> = [
+            Color::ThemeTint(4, 0.0),
+            Color::ThemeTint(4, -0.0),
+            Color::ThemeTint(4, 0.4),
+            Color::Theme(4, 0),
+            Color::Default,
+            Color::Default,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(4, colors.len());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let colors = [
+            (Color::RGB(0x4F026A), r##""#4F026A""##),
+            (Color::Red, r#""red""#),
+            (Color::Automatic, r#""automatic""#),
+            (Color::Theme(9, 4), r#""theme(9, 4)""#),
+            (Color::ThemeTint(4, -0.25), r#""theme_tint(4, -0.25)""#),
+        ];
+
+        for (color, json) in colors {
+            assert_eq!(json, serde_json::to_string(&color).unwrap());
+            assert_eq!(color, serde_json::from_str::<Color>(json).unwrap());
+        }
+
+        // Alternative input formats.
+        let color: Color = serde_json::from_str(r#""dcdcdc""#).unwrap();
+        assert_eq!(Color::RGB(0xDCDCDC), color);
+
+        let color: Color = serde_json::from_str(r#"" Theme( 4 , 1 ) ""#).unwrap();
+        assert_eq!(Color::Theme(4, 1), color);
+
+        let color: Color = serde_json::from_str("14423100").unwrap();
+        assert_eq!(Color::RGB(0xDC143C), color);
+
+        // Invalid colors.
+        for json in [
+            r#""theme(10, 0)""#,
+            r#""theme(4, 6)""#,
+            r#""theme_tint(4, 1.5)""#,
+            r##""#12345""##,
+            r##""#+FFFFF""##,
+            r##""#+12345""##,
+            r##""+12345""##,
+            r#""-12345""#,
+            r#""12 345""#,
+            r#""reddish""#,
+            "16777216",
+            "-1",
+        ] {
+            assert!(serde_json::from_str::<Color>(json).is_err());
+        }
+    }
 }
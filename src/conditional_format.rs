@@ -966,6 +966,18 @@ pub trait ConditionalFormat {
 
     /// Clone a reference into a concrete Box type.
     fn box_clone(&self) -> Box<dyn ConditionalFormat + Sync + Send>;
+
+    /// Adjust the cell references in the conditional format formulas. This is
+    /// used when rows or columns are inserted into, or deleted from, a
+    /// worksheet.
+    #[doc(hidden)]
+    fn shift_formulas(&mut self, _shift: &dyn Fn(&str) -> String) {}
+}
+
+impl Clone for Box<dyn ConditionalFormat + Sync + Send> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
 }
 
 macro_rules! generate_conditional_format_impls {
@@ -1009,6 +1021,10 @@ macro_rules! generate_conditional_format_impls {
             fn box_clone(&self) -> Box<dyn ConditionalFormat + Sync + Send> {
                 Box::new(self.clone())
             }
+
+            fn shift_formulas(&mut self, shift: &dyn Fn(&str) -> String) {
+                self.shift_formulas(shift);
+            }
         }
     )*)
 }
@@ -1028,6 +1044,26 @@ generate_conditional_format_impls!(
     ConditionalFormatIconSet
 );
 
+// Conditional formats without user formulas don't need to be adjusted when
+// rows or columns are inserted or deleted.
+macro_rules! generate_conditional_format_without_formulas {
+    ($($t:ty)*) => ($(
+        impl $t {
+            #[allow(clippy::unused_self)]
+            pub(crate) fn shift_formulas(&mut self, _shift: &dyn Fn(&str) -> String) {}
+        }
+    )*)
+}
+generate_conditional_format_without_formulas!(
+    ConditionalFormatAverage
+    ConditionalFormatBlank
+    ConditionalFormatDate
+    ConditionalFormatDuplicate
+    ConditionalFormatError
+    ConditionalFormatText
+    ConditionalFormatTop
+);
+
 // -----------------------------------------------------------------------
 // ConditionalFormatCell
 // -----------------------------------------------------------------------
@@ -1464,6 +1500,28 @@ impl ConditionalFormatCell {
     pub(crate) fn x14_rule(&self, _priority: u32, _guid: &str) -> String {
         String::new()
     }
+
+    // Adjust the cell references in the conditional format formulas when rows
+    // or columns are inserted or deleted in the worksheet.
+    pub(crate) fn shift_formulas(&mut self, shift: &dyn Fn(&str) -> String) {
+        if let Some(rule) = &mut self.rule {
+            match rule {
+                ConditionalFormatCellRule::EqualTo(value)
+                | ConditionalFormatCellRule::NotEqualTo(value)
+                | ConditionalFormatCellRule::LessThan(value)
+                | ConditionalFormatCellRule::LessThanOrEqualTo(value)
+                | ConditionalFormatCellRule::GreaterThan(value)
+                | ConditionalFormatCellRule::GreaterThanOrEqualTo(value) => {
+                    value.shift_formula(shift);
+                }
+                ConditionalFormatCellRule::Between(min, max)
+                | ConditionalFormatCellRule::NotBetween(min, max) => {
+                    min.shift_formula(shift);
+                    max.shift_formula(shift);
+                }
+            }
+        }
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -2331,6 +2389,12 @@ impl ConditionalFormatFormula {
     pub(crate) fn x14_rule(&self, _priority: u32, _guid: &str) -> String {
         String::new()
     }
+
+    // Adjust the cell references in the conditional format formulas when rows
+    // or columns are inserted or deleted in the worksheet.
+    pub(crate) fn shift_formulas(&mut self, shift: &dyn Fn(&str) -> String) {
+        self.formula.formula_string = shift(&self.formula.formula_string);
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -3781,6 +3845,13 @@ impl ConditionalFormat2ColorScale {
     pub(crate) fn x14_rule(&self, _priority: u32, _guid: &str) -> String {
         String::new()
     }
+
+    // Adjust the cell references in the conditional format formulas when rows
+    // or columns are inserted or deleted in the worksheet.
+    pub(crate) fn shift_formulas(&mut self, shift: &dyn Fn(&str) -> String) {
+        self.min_value.shift_formula(shift);
+        self.max_value.shift_formula(shift);
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -4318,6 +4389,14 @@ impl ConditionalFormat3ColorScale {
     pub(crate) fn x14_rule(&self, _priority: u32, _guid: &str) -> String {
         String::new()
     }
+
+    // Adjust the cell references in the conditional format formulas when rows
+    // or columns are inserted or deleted in the worksheet.
+    pub(crate) fn shift_formulas(&mut self, shift: &dyn Fn(&str) -> String) {
+        self.min_value.shift_formula(shift);
+        self.mid_value.shift_formula(shift);
+        self.max_value.shift_formula(shift);
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -5430,6 +5509,13 @@ impl ConditionalFormatDataBar {
         cursor_to_string(&writer)
     }
 
+    // Adjust the cell references in the conditional format formulas when rows
+    // or columns are inserted or deleted in the worksheet.
+    pub(crate) fn shift_formulas(&mut self, shift: &dyn Fn(&str) -> String) {
+        self.min_value.shift_formula(shift);
+        self.max_value.shift_formula(shift);
+    }
+
     // Write the <x14:dataBar> element.
     fn write_data_bar(writer: &mut Cursor<Vec<u8>>, data_bar: ConditionalFormatDataBar) {
         let mut attributes = vec![
@@ -6200,6 +6286,14 @@ impl ConditionalFormatIconSet {
         cursor_to_string(&writer)
     }
 
+    // Adjust the cell references in the conditional format formulas when rows
+    // or columns are inserted or deleted in the worksheet.
+    pub(crate) fn shift_formulas(&mut self, shift: &dyn Fn(&str) -> String) {
+        for icon in &mut self.icons {
+            icon.value.shift_formula(shift);
+        }
+    }
+
     // Write the <cfvo> element.
     fn write_type(writer: &mut Cursor<Vec<u8>>, icon: &ConditionalFormatCustomIcon) {
         let mut attributes = vec![];
@@ -6610,6 +6704,14 @@ impl ConditionalFormatValue {
         }
     }
 
+    // Adjust the cell references in a value that isn't a string, such as a
+    // formula, when rows or columns are inserted or deleted.
+    pub(crate) fn shift_formula(&mut self, shift: &dyn Fn(&str) -> String) {
+        if !self.is_string {
+            self.value = shift(&self.value);
+        }
+    }
+
     // Helper method to account for the fact that Excel requires that strings in
     // Cell formats are quoted.
     pub(crate) fn quote_string(&mut self) {
@@ -121,6 +121,14 @@ impl ContentTypes {
         self.add_override(&part_name, content_type);
     }
 
+    // Add the name of an embedded OLE object to the ContentTypes overrides.
+    pub(crate) fn add_ole_object_name(&mut self, index: u16) {
+        let content_type = "application/vnd.openxmlformats-officedocument.oleObject";
+        let part_name = format!("/xl/embeddings/oleObject{index}.bin");
+
+        self.add_override(&part_name, content_type);
+    }
+
     // Add the sharedStrings link to the ContentTypes overrides.
     pub(crate) fn add_share_strings(&mut self) {
         self.add_override(
@@ -151,6 +159,11 @@ impl ContentTypes {
             "/xl/richData/rdrichvaluestructure.xml",
             "application/vnd.ms-excel.rdrichvaluestructure+xml",
         );
+    }
+
+    // Add the richValueRel file for embedded images to the ContentTypes
+    // overrides.
+    pub(crate) fn add_rich_value_rel(&mut self) {
         self.add_override(
             "/xl/richData/richValueRel.xml",
             "application/vnd.ms-excel.richvaluerel+xml",
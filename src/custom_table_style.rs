@@ -0,0 +1,341 @@
+// custom_table_style - A module for representing user defined table styles.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use crate::Format;
+
+/// The `CustomTableStyle` struct represents a user defined table style.
+///
+/// The [`TableStyle`](crate::TableStyle) enum covers the built-in Excel table
+/// styles. A `CustomTableStyle` can be used to define a new style where the
+/// formatting of each element of the table, such as the header row, the total
+/// row or the banded rows, is set using a [`Format`]. Custom styles are shown
+/// in the "Custom" section of the Excel "Table Styles" gallery.
+///
+/// The style is applied to a table with
+/// [`Table::set_custom_style()`](crate::Table::set_custom_style) and is added
+/// to the workbook automatically. Styles that aren't used by a table can be
+/// added to the gallery with
+/// [`Workbook::add_table_style()`](crate::Workbook::add_table_style).
+///
+/// Only the font, fill, border and number format properties of the element
+/// formats are used by Excel. The formats are stored in the same way as
+/// conditional format "differential" formats, so font names and sizes are
+/// ignored.
+///
+/// Two styles are equal if they have the same name, type and element formats.
+///
+/// # Examples
+///
+/// An example of creating a custom table style and applying it to a table.
+///
+/// ```
+/// # // This code is available in examples/doc_custom_table_style.rs
+/// #
+/// use rust_xlsxwriter::{
+///     CustomTableStyle, Format, FormatBorder, Table, TableStyleElement, Workbook, XlsxError,
+/// };
+///
+/// fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Some sample data for the table.
+///     let items = ["Apples", "Pears", "Bananas", "Oranges"];
+///     let data = [
+///         [10000, 5000, 8000, 6000],
+///         [2000, 3000, 4000, 5000],
+///         [6000, 6000, 6500, 6000],
+///         [500, 300, 200, 700],
+///     ];
+///
+///     // Write the table data.
+///     worksheet.write_column(3, 1, items)?;
+///     worksheet.write_row_matrix(3, 2, data)?;
+///     worksheet.set_column_range_width(1, 6, 12)?;
+///
+///     // Create a custom table style from formats for the table elements.
+///     let style = CustomTableStyle::new("Company Style")
+///         .set_format(
+///             TableStyleElement::WholeTable,
+///             &Format::new()
+///                 .set_border(FormatBorder::Thin)
+///                 .set_border_color("#1F4E79"),
+///         )
+///         .set_format(
+///             TableStyleElement::HeaderRow,
+///             &Format::new()
+///                 .set_bold()
+///                 .set_font_color("#FFFFFF")
+///                 .set_background_color("#1F4E79"),
+///         )
+///         .set_format(
+///             TableStyleElement::FirstRowStripe,
+///             &Format::new().set_background_color("#DDEBF7"),
+///         );
+///
+///     // Create a new table and apply the custom style.
+///     let table = Table::new().set_custom_style(&style);
+///
+///     // Add the table to the worksheet.
+///     worksheet.add_table(2, 1, 6, 5, &table)?;
+///
+///     workbook.save("tables.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CustomTableStyle {
+    pub(crate) name: String,
+    pub(crate) elements: Vec<(TableStyleElement, Format)>,
+    pub(crate) is_pivot_style: bool,
+    pub(crate) dxf_indices: Vec<u32>,
+}
+
+impl CustomTableStyle {
+    /// Create a new `CustomTableStyle` object for a worksheet table.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the style as it appears in the Excel table style
+    ///   gallery. The name cannot be the same as one of the built-in Excel
+    ///   styles such as "TableStyleMedium9".
+    ///
+    pub fn new(name: impl Into<String>) -> CustomTableStyle {
+        CustomTableStyle {
+            name: name.into(),
+            elements: vec![],
+            is_pivot_style: false,
+            dxf_indices: vec![],
+        }
+    }
+
+    /// Create a new `CustomTableStyle` object for a pivot table.
+    ///
+    /// Pivot styles are shown in the Excel "PivotTable Styles" gallery instead
+    /// of the "Table Styles" gallery. Note, `rust_xlsxwriter` doesn't create
+    /// pivot tables but a pivot style can be added to a workbook with
+    /// [`Workbook::add_table_style()`](crate::Workbook::add_table_style) so
+    /// that it is available to the end user.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the style as it appears in the Excel pivot table
+    ///   style gallery.
+    ///
+    pub fn new_pivot(name: impl Into<String>) -> CustomTableStyle {
+        CustomTableStyle {
+            is_pivot_style: true,
+            ..CustomTableStyle::new(name)
+        }
+    }
+
+    /// Set the format for an element of the table style.
+    ///
+    /// Setting the format for an element that already has a format replaces
+    /// the previous format.
+    ///
+    /// # Parameters
+    ///
+    /// - `element`: A [`TableStyleElement`] enum value.
+    /// - `format`: The [`Format`] property for the element.
+    ///
+    pub fn set_format(mut self, element: TableStyleElement, format: &Format) -> CustomTableStyle {
+        let mut format = format.clone();
+        format.is_dxf_format = true;
+
+        match self.elements.iter_mut().find(|(e, _)| *e == element) {
+            Some(existing) => existing.1 = format,
+            None => {
+                self.elements.push((element, format));
+
+                // Excel stores the elements in a fixed order.
+                self.elements.sort_by_key(|(e, _)| *e);
+            }
+        }
+
+        self
+    }
+
+    /// Get the name of the style.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // Check if the name clashes with one of the Excel built-in style names,
+    // like "TableStyleMedium9" or "PivotStyleLight16".
+    pub(crate) fn is_builtin_name(&self) -> bool {
+        let name = self.name.to_lowercase();
+
+        for prefix in ["tablestyle", "pivotstyle"] {
+            let Some(name) = name.strip_prefix(prefix) else {
+                continue;
+            };
+
+            for variant in ["light", "medium", "dark"] {
+                if let Some(number) = name.strip_prefix(variant) {
+                    if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+}
+
+// The DXF indices are assigned by the workbook when the file is saved so they
+// aren't part of the user visible equality of the style.
+impl PartialEq for CustomTableStyle {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.is_pivot_style == other.is_pivot_style
+            && self.elements == other.elements
+    }
+}
+
+impl Eq for CustomTableStyle {}
+
+/// The `TableStyleElement` enum defines the table elements that can be
+/// formatted in a [`CustomTableStyle`].
+///
+/// The elements are shown in the Excel "Modify Table Style" dialog. Where
+/// elements overlap Excel applies the formatting of the more specific element,
+/// so, for example, the `HeaderRow` format takes precedence over the
+/// `WholeTable` format.
+///
+/// The subtotal, subheading, blank row and page field elements only apply to
+/// pivot styles created with
+/// [`CustomTableStyle::new_pivot()`](CustomTableStyle::new_pivot). They are
+/// ignored by Excel in worksheet table styles.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TableStyleElement {
+    /// The whole table.
+    WholeTable,
+
+    /// The header row.
+    HeaderRow,
+
+    /// The total row.
+    TotalRow,
+
+    /// The first column.
+    FirstColumn,
+
+    /// The last column.
+    LastColumn,
+
+    /// The first (odd) row stripe for banded rows.
+    FirstRowStripe,
+
+    /// The second (even) row stripe for banded rows.
+    SecondRowStripe,
+
+    /// The first (odd) column stripe for banded columns.
+    FirstColumnStripe,
+
+    /// The second (even) column stripe for banded columns.
+    SecondColumnStripe,
+
+    /// The first cell of the header row.
+    FirstHeaderCell,
+
+    /// The last cell of the header row.
+    LastHeaderCell,
+
+    /// The first cell of the total row.
+    FirstTotalCell,
+
+    /// The last cell of the total row.
+    LastTotalCell,
+
+    /// The first subtotal column of a pivot table.
+    FirstSubtotalColumn,
+
+    /// The second subtotal column of a pivot table.
+    SecondSubtotalColumn,
+
+    /// The third subtotal column of a pivot table.
+    ThirdSubtotalColumn,
+
+    /// The first subtotal row of a pivot table.
+    FirstSubtotalRow,
+
+    /// The second subtotal row of a pivot table.
+    SecondSubtotalRow,
+
+    /// The third subtotal row of a pivot table.
+    ThirdSubtotalRow,
+
+    /// The blank rows of a pivot table.
+    BlankRow,
+
+    /// The first column subheading of a pivot table.
+    FirstColumnSubheading,
+
+    /// The second column subheading of a pivot table.
+    SecondColumnSubheading,
+
+    /// The third column subheading of a pivot table.
+    ThirdColumnSubheading,
+
+    /// The first row subheading of a pivot table.
+    FirstRowSubheading,
+
+    /// The second row subheading of a pivot table.
+    SecondRowSubheading,
+
+    /// The third row subheading of a pivot table.
+    ThirdRowSubheading,
+
+    /// The report filter labels of a pivot table.
+    PageFieldLabels,
+
+    /// The report filter values of a pivot table.
+    PageFieldValues,
+}
+
+impl TableStyleElement {
+    // The element type name used in the `<tableStyleElement>` XML element.
+    pub(crate) fn xml_name(self) -> &'static str {
+        match self {
+            Self::WholeTable => "wholeTable",
+            Self::HeaderRow => "headerRow",
+            Self::TotalRow => "totalRow",
+            Self::FirstColumn => "firstColumn",
+            Self::LastColumn => "lastColumn",
+            Self::FirstRowStripe => "firstRowStripe",
+            Self::SecondRowStripe => "secondRowStripe",
+            Self::FirstColumnStripe => "firstColumnStripe",
+            Self::SecondColumnStripe => "secondColumnStripe",
+            Self::FirstHeaderCell => "firstHeaderCell",
+            Self::LastHeaderCell => "lastHeaderCell",
+            Self::FirstTotalCell => "firstTotalCell",
+            Self::LastTotalCell => "lastTotalCell",
+            Self::FirstSubtotalColumn => "firstSubtotalColumn",
+            Self::SecondSubtotalColumn => "secondSubtotalColumn",
+            Self::ThirdSubtotalColumn => "thirdSubtotalColumn",
+            Self::FirstSubtotalRow => "firstSubtotalRow",
+            Self::SecondSubtotalRow => "secondSubtotalRow",
+            Self::ThirdSubtotalRow => "thirdSubtotalRow",
+            Self::BlankRow => "blankRow",
+            Self::FirstColumnSubheading => "firstColumnSubheading",
+            Self::SecondColumnSubheading => "secondColumnSubheading",
+            Self::ThirdColumnSubheading => "thirdColumnSubheading",
+            Self::FirstRowSubheading => "firstRowSubheading",
+            Self::SecondRowSubheading => "secondRowSubheading",
+            Self::ThirdRowSubheading => "thirdRowSubheading",
+            Self::PageFieldLabels => "pageFieldLabels",
+            Self::PageFieldValues => "pageFieldValues",
+        }
+    }
+}
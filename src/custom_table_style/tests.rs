@@ -0,0 +1,203 @@
+// Custom table style unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod custom_table_style_tests {
+
+    use crate::styles::Styles;
+    use crate::test_functions::xml_to_vec;
+    use crate::{xmlwriter, CustomTableStyle, Format, Table, TableStyleElement, Workbook};
+    use pretty_assertions::assert_eq;
+
+    // Get the <tableStyles> section of a styles.xml file.
+    fn table_styles_xml(table_styles: &[CustomTableStyle]) -> Vec<String> {
+        let mut xf_format = Format::new();
+        xf_format.set_font_index(0, true);
+        xf_format.set_border_index(0, true);
+
+        let xf_formats = vec![xf_format];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(
+            &xf_formats,
+            &dxf_formats,
+            1,
+            2,
+            1,
+            vec![],
+            false,
+            false,
+            false,
+        );
+        styles.table_styles = table_styles;
+
+        styles.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&styles.writer);
+
+        xml_to_vec(got)
+            .into_iter()
+            .skip_while(|element| !element.starts_with("<tableStyles"))
+            .take_while(|element| {
+                !element.starts_with("<colors") && !element.starts_with("<extLst")
+            })
+            .filter(|element| element != "</styleSheet>")
+            .collect()
+    }
+
+    #[test]
+    fn test_set_format() {
+        let style = CustomTableStyle::new("Style 1")
+            .set_format(TableStyleElement::TotalRow, &Format::new().set_italic())
+            .set_format(TableStyleElement::HeaderRow, &Format::new().set_italic())
+            .set_format(TableStyleElement::WholeTable, &Format::new().set_bold())
+            .set_format(TableStyleElement::HeaderRow, &Format::new().set_bold());
+
+        let elements: Vec<TableStyleElement> = style.elements.iter().map(|(e, _)| *e).collect();
+
+        assert_eq!(
+            vec![
+                TableStyleElement::WholeTable,
+                TableStyleElement::HeaderRow,
+                TableStyleElement::TotalRow,
+            ],
+            elements
+        );
+
+        assert!(style.elements[1].1.font.bold);
+        assert!(!style.elements[1].1.font.italic);
+        assert!(style
+            .elements
+            .iter()
+            .all(|(_, format)| format.is_dxf_format));
+        assert_eq!("Style 1", style.name());
+    }
+
+    #[test]
+    fn test_builtin_names() {
+        let builtin_names = [
+            "TableStyleLight1",
+            "TableStyleMedium9",
+            "TableStyleDark11",
+            "tablestylemedium28",
+            "PivotStyleLight16",
+            "PIVOTSTYLEDARK3",
+        ];
+
+        for name in builtin_names {
+            assert!(CustomTableStyle::new(name).is_builtin_name(), "{name}");
+        }
+
+        let user_names = [
+            "TableStyleMedium",
+            "TableStyleMedium9a",
+            "TableStyleExtra1",
+            "PivotStyle",
+            "My TableStyleMedium9",
+            "Style 1",
+        ];
+
+        for name in user_names {
+            assert!(!CustomTableStyle::new(name).is_builtin_name(), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_equality_ignores_dxf_indices() {
+        let style1 = CustomTableStyle::new("Style 1")
+            .set_format(TableStyleElement::HeaderRow, &Format::new().set_bold());
+        let mut style2 = style1.clone();
+        style2.dxf_indices = vec![3];
+
+        assert_eq!(style1, style2);
+
+        let style3 = CustomTableStyle::new_pivot("Style 1")
+            .set_format(TableStyleElement::HeaderRow, &Format::new().set_bold());
+        assert_ne!(style1, style3);
+
+        let style4 = CustomTableStyle::new("Style 1")
+            .set_format(TableStyleElement::HeaderRow, &Format::new().set_italic());
+        assert_ne!(style1, style4);
+    }
+
+    #[test]
+    fn test_table_style_xml() {
+        let mut table_style = CustomTableStyle::new("Table Style")
+            .set_format(TableStyleElement::FirstRowStripe, &Format::new().set_bold())
+            .set_format(TableStyleElement::HeaderRow, &Format::new().set_italic());
+        table_style.dxf_indices = vec![1, 0];
+
+        let mut pivot_style = CustomTableStyle::new_pivot("Pivot Style")
+            .set_format(
+                TableStyleElement::PageFieldValues,
+                &Format::new().set_bold(),
+            )
+            .set_format(TableStyleElement::BlankRow, &Format::new().set_italic())
+            .set_format(
+                TableStyleElement::FirstSubtotalColumn,
+                &Format::new().set_italic(),
+            );
+        pivot_style.dxf_indices = vec![1, 1, 0];
+
+        let empty_style = CustomTableStyle::new("Empty Style");
+
+        let got = table_styles_xml(&[table_style, pivot_style, empty_style]);
+
+        let expected = xml_to_vec(
+            r#"
+                <tableStyles count="3" defaultTableStyle="TableStyleMedium9" defaultPivotStyle="PivotStyleLight16">
+                  <tableStyle name="Table Style" pivot="0" count="2">
+                    <tableStyleElement type="headerRow" dxfId="1"/>
+                    <tableStyleElement type="firstRowStripe" dxfId="0"/>
+                  </tableStyle>
+                  <tableStyle name="Pivot Style" table="0" count="3">
+                    <tableStyleElement type="firstSubtotalColumn" dxfId="1"/>
+                    <tableStyleElement type="blankRow" dxfId="1"/>
+                    <tableStyleElement type="pageFieldValues" dxfId="0"/>
+                  </tableStyle>
+                  <tableStyle name="Empty Style" pivot="0" count="0"/>
+                </tableStyles>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_dxf_mapping() {
+        let mut workbook = Workbook::new();
+
+        // Styles that share an element format share the same DXF.
+        let style1 = CustomTableStyle::new("Style 1")
+            .set_format(TableStyleElement::HeaderRow, &Format::new().set_bold())
+            .set_format(TableStyleElement::TotalRow, &Format::new().set_italic());
+
+        let style2 = CustomTableStyle::new("Style 2")
+            .set_format(TableStyleElement::WholeTable, &Format::new().set_italic())
+            .set_format(
+                TableStyleElement::HeaderRow,
+                &Format::new().set_background_color("DDEBF7"),
+            );
+
+        workbook.add_table_style(&style1).unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        let table = Table::new().set_custom_style(&style2);
+        worksheet.add_table(0, 0, 2, 2, &table).unwrap();
+
+        // The same style used by a table and the workbook is only added once.
+        let table = Table::new().set_custom_style(&style1);
+        worksheet.add_table(5, 0, 7, 2, &table).unwrap();
+
+        workbook.save_to_buffer().unwrap();
+
+        assert_eq!(2, workbook.table_style_dxfs.len());
+        assert_eq!("Style 1", workbook.table_style_dxfs[0].name);
+        assert_eq!(vec![0, 1], workbook.table_style_dxfs[0].dxf_indices);
+        assert_eq!("Style 2", workbook.table_style_dxfs[1].name);
+        assert_eq!(vec![1, 2], workbook.table_style_dxfs[1].dxf_indices);
+        assert_eq!(3, workbook.dxf_formats.len());
+    }
+}
@@ -0,0 +1,419 @@
+// custom_view - A module for representing Excel custom worksheet views.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use crate::{ColNum, RowNum, Worksheet};
+
+/// The `CustomView` struct represents a named custom view of a worksheet.
+///
+/// Custom views are a feature of Excel that allows a user to save a set of
+/// display and print settings, such as the zoom level, the visibility of
+/// gridlines and headings, the page orientation and the headers and footers,
+/// and switch between them via the `View -> Custom Views` menu.
+///
+/// A `CustomView` is added to one or more worksheets with the
+/// [`Worksheet::add_custom_view()`](crate::Worksheet::add_custom_view) method.
+/// Views with the same name in different worksheets are treated as the same
+/// workbook level view by Excel, so that switching to the view applies the
+/// settings for each worksheet.
+///
+/// Note, Excel disables custom views in workbooks that contain worksheet
+/// tables.
+///
+/// # Examples
+///
+/// The following example demonstrates adding two custom views to a worksheet,
+/// one for working with the data on screen and one for printing it.
+///
+/// ```
+/// # // This code is available in examples/doc_custom_view.rs
+/// #
+/// # use rust_xlsxwriter::{CustomView, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     worksheet.write_column(0, 0, [10, 20, 0, 40])?;
+///
+///     // Create a view for editing the data on screen.
+///     let edit_view = CustomView::new("Edit")
+///         .set_zoom(150)
+///         .set_show_zero_values(false);
+///
+///     // Create a view for printing the data.
+///     let print_view = CustomView::new("Print")
+///         .set_view_page_layout()
+///         .set_landscape()
+///         .set_print_gridlines(true)
+///         .set_header("&CSales Data");
+///
+///     worksheet.add_custom_view(&edit_view);
+///     worksheet.add_custom_view(&print_view);
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomView {
+    pub(crate) name: String,
+    pub(crate) zoom: u16,
+    pub(crate) page_view: CustomViewType,
+    pub(crate) top_left_cell: Option<(RowNum, ColNum)>,
+    pub(crate) hidden: bool,
+    pub(crate) screen_gridlines: bool,
+    pub(crate) screen_headings: bool,
+    pub(crate) show_formulas: bool,
+    pub(crate) show_zero_values: bool,
+    pub(crate) show_outline_symbols: bool,
+    pub(crate) page_layout_ruler: bool,
+    pub(crate) is_portrait: bool,
+    pub(crate) paper_size: u8,
+    pub(crate) print_scale: u16,
+    pub(crate) fit_to_pages: Option<(u16, u16)>,
+    pub(crate) print_gridlines: bool,
+    pub(crate) print_headings: bool,
+    pub(crate) header: String,
+    pub(crate) footer: String,
+}
+
+impl CustomView {
+    /// Create a new `CustomView` object.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the view as it appears in the Excel `Custom
+    ///   Views` dialog.
+    ///
+    pub fn new(name: impl Into<String>) -> CustomView {
+        CustomView {
+            name: name.into(),
+            zoom: 100,
+            page_view: CustomViewType::Normal,
+            top_left_cell: None,
+            hidden: false,
+            screen_gridlines: true,
+            screen_headings: true,
+            show_formulas: false,
+            show_zero_values: true,
+            show_outline_symbols: true,
+            page_layout_ruler: true,
+            is_portrait: true,
+            paper_size: 0,
+            print_scale: 100,
+            fit_to_pages: None,
+            print_gridlines: false,
+            print_headings: false,
+            header: String::new(),
+            footer: String::new(),
+        }
+    }
+
+    /// Set the worksheet zoom factor for the view.
+    ///
+    /// See [`Worksheet::set_zoom()`](crate::Worksheet::set_zoom) for details.
+    ///
+    /// # Parameters
+    ///
+    /// - `zoom`: The worksheet zoom level. The range is 10 <= zoom <= 400.
+    ///
+    pub fn set_zoom(mut self, zoom: u16) -> CustomView {
+        if !(10..=400).contains(&zoom) {
+            eprintln!("Zoom factor {zoom} outside Excel range: 10 <= zoom <= 400.");
+            return self;
+        }
+
+        self.zoom = zoom;
+        self
+    }
+
+    /// Set the view to "Page Layout" mode.
+    ///
+    /// See [`Worksheet::set_view_page_layout()`](crate::Worksheet::set_view_page_layout)
+    /// for details.
+    ///
+    pub fn set_view_page_layout(mut self) -> CustomView {
+        self.page_view = CustomViewType::PageLayout;
+        self
+    }
+
+    /// Set the view to "Page Break Preview" mode.
+    ///
+    /// See
+    /// [`Worksheet::set_view_page_break_preview()`](crate::Worksheet::set_view_page_break_preview)
+    /// for details.
+    ///
+    pub fn set_view_page_break_preview(mut self) -> CustomView {
+        self.page_view = CustomViewType::PageBreaks;
+        self
+    }
+
+    /// Set the top and leftmost visible cell of the view.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    ///
+    pub fn set_top_left_cell(mut self, row: RowNum, col: ColNum) -> CustomView {
+        self.top_left_cell = Some((row, col));
+        self
+    }
+
+    /// Hide the worksheet in the view.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_hidden(mut self, enable: bool) -> CustomView {
+        self.hidden = enable;
+        self
+    }
+
+    /// Turn on/off the screen gridlines in the view.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_screen_gridlines(mut self, enable: bool) -> CustomView {
+        self.screen_gridlines = enable;
+        self
+    }
+
+    /// Turn on/off the screen row and column headings in the view.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_screen_headings(mut self, enable: bool) -> CustomView {
+        self.screen_headings = enable;
+        self
+    }
+
+    /// Show formulas instead of their results in the view.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_show_formulas(mut self, enable: bool) -> CustomView {
+        self.show_formulas = enable;
+        self
+    }
+
+    /// Turn on/off the display of zero values in the view.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_show_zero_values(mut self, enable: bool) -> CustomView {
+        self.show_zero_values = enable;
+        self
+    }
+
+    /// Turn on/off the outline symbols in the view.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_show_outline_symbols(mut self, enable: bool) -> CustomView {
+        self.show_outline_symbols = enable;
+        self
+    }
+
+    /// Turn on/off the ruler in the "Page Layout" view.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_page_layout_ruler(mut self, enable: bool) -> CustomView {
+        self.page_layout_ruler = enable;
+        self
+    }
+
+    /// Set the printed page orientation to landscape for the view.
+    ///
+    pub fn set_landscape(mut self) -> CustomView {
+        self.is_portrait = false;
+        self
+    }
+
+    /// Set the printed page orientation to portrait for the view. This is the
+    /// default.
+    ///
+    pub fn set_portrait(mut self) -> CustomView {
+        self.is_portrait = true;
+        self
+    }
+
+    /// Set the paper type/size when printing the view.
+    ///
+    /// See [`Worksheet::set_paper_size()`](crate::Worksheet::set_paper_size)
+    /// for the list of paper sizes.
+    ///
+    /// # Parameters
+    ///
+    /// - `paper_size`: The paper size index from the list above.
+    ///
+    pub fn set_paper_size(mut self, paper_size: u8) -> CustomView {
+        self.paper_size = paper_size;
+        self
+    }
+
+    /// Set the print scale for the view.
+    ///
+    /// # Parameters
+    ///
+    /// - `scale`: The print scale factor. The range is 10 <= scale <= 400.
+    ///
+    pub fn set_print_scale(mut self, scale: u16) -> CustomView {
+        if !(10..=400).contains(&scale) {
+            eprintln!("Scale factor {scale} outside Excel range: 10 <= zoom <= 400.");
+            return self;
+        }
+
+        // This property is mutually exclusive with fit to page.
+        self.fit_to_pages = None;
+
+        self.print_scale = scale;
+        self
+    }
+
+    /// Fit the printed area of the view to a specific number of pages both
+    /// vertically and horizontally.
+    ///
+    /// See
+    /// [`Worksheet::set_print_fit_to_pages()`](crate::Worksheet::set_print_fit_to_pages)
+    /// for details.
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: Number of pages horizontally.
+    /// - `height`: Number of pages vertically.
+    ///
+    pub fn set_print_fit_to_pages(mut self, width: u16, height: u16) -> CustomView {
+        self.fit_to_pages = Some((width, height));
+        self.print_scale = 100;
+        self
+    }
+
+    /// Turn on/off the printed gridlines for the view.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_gridlines(mut self, enable: bool) -> CustomView {
+        self.print_gridlines = enable;
+        self
+    }
+
+    /// Turn on/off the printed row and column headings for the view.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_headings(mut self, enable: bool) -> CustomView {
+        self.print_headings = enable;
+        self
+    }
+
+    /// Set the printed page header caption for the view.
+    ///
+    /// See [`Worksheet::set_header()`](crate::Worksheet::set_header) for the
+    /// syntax of the header string. Header images aren't supported in custom
+    /// views.
+    ///
+    /// # Parameters
+    ///
+    /// - `header`: The header string with optional control characters.
+    ///
+    pub fn set_header(mut self, header: impl Into<String>) -> CustomView {
+        let header = Worksheet::expand_header_footer(&header.into());
+
+        if header.chars().count() > 255 {
+            eprintln!("Header string exceeds Excel's limit of 255 characters.");
+            return self;
+        }
+
+        self.header = header;
+        self
+    }
+
+    /// Set the printed page footer caption for the view.
+    ///
+    /// See [`Worksheet::set_header()`](crate::Worksheet::set_header) for the
+    /// syntax of the footer string.
+    ///
+    /// # Parameters
+    ///
+    /// - `footer`: The footer string with optional control characters.
+    ///
+    pub fn set_footer(mut self, footer: impl Into<String>) -> CustomView {
+        let footer = Worksheet::expand_header_footer(&footer.into());
+
+        if footer.chars().count() > 255 {
+            eprintln!("Footer string exceeds Excel's limit of 255 characters.");
+            return self;
+        }
+
+        self.footer = footer;
+        self
+    }
+
+    // Check if the view has any page setup properties.
+    pub(crate) fn has_page_setup(&self) -> bool {
+        !self.is_portrait
+            || self.paper_size > 0
+            || self.print_scale != 100
+            || self.fit_to_pages.is_some()
+    }
+
+    // Get a GUID for the view. Excel links the views in each worksheet to the
+    // workbook view via the GUID so it is generated deterministically from the
+    // name. The 128 bits are made from two 64 bit FNV-1a hashes with different
+    // initial values.
+    pub(crate) fn guid(&self) -> String {
+        let fnv_hash = |offset: u64| -> u64 {
+            self.name.bytes().fold(offset, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+        };
+
+        let high = fnv_hash(0xcbf2_9ce4_8422_2325);
+        let low = fnv_hash(0x6c62_272e_07bb_0142);
+
+        format!(
+            "{{{:08X}-{:04X}-{:04X}-{:04X}-{:012X}}}",
+            high >> 32,
+            (high >> 16) & 0xFFFF,
+            high & 0xFFFF,
+            low >> 48,
+            low & 0xFFFF_FFFF_FFFF
+        )
+    }
+}
+
+// The view type of a custom view.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CustomViewType {
+    Normal,
+    PageLayout,
+    PageBreaks,
+}
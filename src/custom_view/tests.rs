@@ -0,0 +1,50 @@
+// Custom view unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod custom_view_tests {
+
+    use crate::CustomView;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_guid() {
+        let view1 = CustomView::new("Edit");
+        let view2 = CustomView::new("Edit").set_zoom(200);
+        let view3 = CustomView::new("Print");
+
+        let guid = view1.guid();
+
+        assert_eq!(38, guid.len());
+        assert!(guid.starts_with('{') && guid.ends_with('}'));
+        assert_eq!(
+            vec![8, 4, 4, 4, 12],
+            guid[1..37].split('-').map(str::len).collect::<Vec<_>>()
+        );
+
+        assert_eq!(guid, view2.guid());
+        assert_ne!(guid, view3.guid());
+    }
+
+    #[test]
+    fn test_validation() {
+        let view = CustomView::new("View")
+            .set_zoom(500)
+            .set_print_scale(5)
+            .set_header("&[Page]".repeat(128))
+            .set_footer("&L&[Page] of &[Pages]");
+
+        assert_eq!(100, view.zoom);
+        assert_eq!(100, view.print_scale);
+        assert_eq!("", view.header);
+        assert_eq!("&L&P of &N", view.footer);
+
+        // Escaped ampersands aren't the start of a control character.
+        let view = CustomView::new("View").set_header("&C&&[Page] &[Page]");
+
+        assert_eq!("&C&&[Page] &P", view.header);
+    }
+}
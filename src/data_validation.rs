@@ -1326,6 +1326,29 @@ impl DataValidation {
             && self.error_title.is_empty()
             && self.error_message.is_empty()
     }
+
+    // Adjust the cell references in the rule values when rows or columns are
+    // inserted into, or deleted from, the worksheet, or when a worksheet is
+    // removed from the workbook.
+    pub(crate) fn shift_formulas(&mut self, shift: &dyn Fn(&str) -> String) {
+        let shift_value = |value: &mut String| *value = shift(value);
+
+        match &mut self.rule {
+            DataValidationRuleInternal::EqualTo(value)
+            | DataValidationRuleInternal::NotEqualTo(value)
+            | DataValidationRuleInternal::GreaterThan(value)
+            | DataValidationRuleInternal::GreaterThanOrEqualTo(value)
+            | DataValidationRuleInternal::LessThan(value)
+            | DataValidationRuleInternal::LessThanOrEqualTo(value)
+            | DataValidationRuleInternal::CustomFormula(value)
+            | DataValidationRuleInternal::ListSource(value) => shift_value(value),
+            DataValidationRuleInternal::Between(min, max)
+            | DataValidationRuleInternal::NotBetween(min, max) => {
+                shift_value(min);
+                shift_value(max);
+            }
+        }
+    }
 }
 
 /// Trait to map rust types into data validation types
@@ -57,7 +57,11 @@ impl Drawing {
         for drawing in &self.drawings.clone() {
             if drawing.drawing_type == DrawingType::ChartSheet {
                 // Write the <xdr:absoluteAnchor> element.
-                self.write_absolute_anchor(drawing);
+                self.write_absolute_anchor(1, drawing);
+            } else if drawing.object_movement == ObjectMovement::AbsoluteAnchor {
+                // Write the <xdr:absoluteAnchor> element.
+                self.write_absolute_anchor(index, drawing);
+                index += 1;
             } else {
                 // Write the <xdr:twoCellAnchor> element.
                 self.write_two_cell_anchor(index, drawing);
@@ -96,7 +100,9 @@ impl Drawing {
             ObjectMovement::DontMoveOrSizeWithCells => {
                 attributes.push(("editAs", "absolute".to_string()));
             }
-            ObjectMovement::MoveAndSizeWithCells | ObjectMovement::MoveAndSizeWithCellsAfter => (),
+            ObjectMovement::MoveAndSizeWithCells
+            | ObjectMovement::MoveAndSizeWithCellsAfter
+            | ObjectMovement::AbsoluteAnchor => (),
         }
 
         xml_start_tag(&mut self.writer, "xdr:twoCellAnchor", &attributes);
@@ -105,6 +111,16 @@ impl Drawing {
         self.write_from(&drawing_info.from);
         self.write_to(&drawing_info.to);
 
+        // Write the anchored drawing object.
+        self.write_anchored_object(index, drawing_info);
+
+        xml_empty_tag_only(&mut self.writer, "xdr:clientData");
+        xml_end_tag(&mut self.writer, "xdr:twoCellAnchor");
+    }
+
+    // Write the drawing object, such as an image, chart or shape, within an
+    // anchor element.
+    fn write_anchored_object(&mut self, index: u32, drawing_info: &DrawingInfo) {
         match drawing_info.drawing_type {
             DrawingType::Image => self.write_pic(index, drawing_info),
             DrawingType::Chart => self.write_graphic_frame(index, drawing_info),
@@ -114,11 +130,9 @@ impl Drawing {
 
                 self.write_sp(index, drawing_info, &shape);
             }
-            DrawingType::ChartSheet | DrawingType::Vml => {}
+            DrawingType::ChartSheet => self.write_graphic_frame(index, drawing_info),
+            DrawingType::Vml => {}
         }
-
-        xml_empty_tag_only(&mut self.writer, "xdr:clientData");
-        xml_end_tag(&mut self.writer, "xdr:twoCellAnchor");
     }
 
     // Write the <xdr:from> element.
@@ -796,7 +810,7 @@ impl Drawing {
     // Write the <a:srgbClr> element.
     fn write_color(&mut self, color: Color, transparency: u8) {
         match color {
-            Color::Theme(_, _) => {
+            Color::Theme(_, _) | Color::ThemeTint(_, _) => {
                 let (scheme, lum_mod, lum_off) = color.chart_scheme();
                 if !scheme.is_empty() {
                     // Write the <a:schemeClr> element.
@@ -1182,7 +1196,7 @@ impl Drawing {
     }
 
     // Write the <xdr:absoluteAnchor> element.
-    fn write_absolute_anchor(&mut self, drawing_info: &DrawingInfo) {
+    fn write_absolute_anchor(&mut self, index: u32, drawing_info: &DrawingInfo) {
         xml_start_tag_only(&mut self.writer, "xdr:absoluteAnchor");
 
         // Write the <xdr:pos> element.
@@ -1191,7 +1205,8 @@ impl Drawing {
         // Write the <xdr:ext> element.
         self.write_ext(drawing_info);
 
-        self.write_graphic_frame(1, drawing_info);
+        // Write the anchored drawing object.
+        self.write_anchored_object(index, drawing_info);
 
         xml_empty_tag_only(&mut self.writer, "xdr:clientData");
         xml_end_tag(&mut self.writer, "xdr:absoluteAnchor");
@@ -1201,12 +1216,15 @@ impl Drawing {
     fn write_pos(&mut self, drawing_info: &DrawingInfo) {
         let mut attributes = vec![];
 
-        if drawing_info.is_portrait {
-            attributes.push(("x", "0"));
-            attributes.push(("y", "-47625"));
+        if drawing_info.drawing_type != DrawingType::ChartSheet {
+            attributes.push(("x", drawing_info.col_absolute.to_string()));
+            attributes.push(("y", drawing_info.row_absolute.to_string()));
+        } else if drawing_info.is_portrait {
+            attributes.push(("x", "0".to_string()));
+            attributes.push(("y", "-47625".to_string()));
         } else {
-            attributes.push(("x", "0"));
-            attributes.push(("y", "0"));
+            attributes.push(("x", "0".to_string()));
+            attributes.push(("y", "0".to_string()));
         }
 
         xml_empty_tag(&mut self.writer, "xdr:pos", &attributes);
@@ -1216,12 +1234,15 @@ impl Drawing {
     fn write_ext(&mut self, drawing_info: &DrawingInfo) {
         let mut attributes = vec![];
 
-        if drawing_info.is_portrait {
-            attributes.push(("cx", "6162675"));
-            attributes.push(("cy", "6124575"));
+        if drawing_info.drawing_type != DrawingType::ChartSheet {
+            attributes.push(("cx", drawing_info.width.to_string()));
+            attributes.push(("cy", drawing_info.height.to_string()));
+        } else if drawing_info.is_portrait {
+            attributes.push(("cx", "6162675".to_string()));
+            attributes.push(("cy", "6124575".to_string()));
         } else {
-            attributes.push(("cx", "9308969"));
-            attributes.push(("cy", "6078325"));
+            attributes.push(("cx", "9308969".to_string()));
+            attributes.push(("cy", "6078325".to_string()));
         }
 
         xml_empty_tag(&mut self.writer, "xdr:ext", &attributes);
@@ -1305,6 +1326,7 @@ pub(crate) enum DrawingType {
 pub(crate) trait DrawingObject {
     fn x_offset(&self) -> u32;
     fn y_offset(&self) -> u32;
+    fn absolute_position(&self) -> Option<(u32, u32)>;
     fn width_scaled(&self) -> f64;
     fn height_scaled(&self) -> f64;
     fn object_movement(&self) -> ObjectMovement;
@@ -8,7 +8,7 @@
 mod drawing_tests {
 
     use crate::test_functions::xml_to_vec;
-    use crate::{drawing::*, xmlwriter};
+    use crate::{drawing::*, xmlwriter, Shape};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -101,4 +101,209 @@ mod drawing_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_absolute_anchor() {
+        let mut drawing = Drawing::new();
+
+        let drawing_info = DrawingInfo {
+            col_absolute: 1219200,
+            row_absolute: 190500,
+            width: 1142857.0,
+            height: 1142857.0,
+            name: "Picture 1".to_string(),
+            description: "rust.png".to_string(),
+            rel_id: 1,
+            object_movement: ObjectMovement::AbsoluteAnchor,
+            drawing_type: DrawingType::Image,
+            ..Default::default()
+        };
+
+        drawing.drawings.push(drawing_info);
+
+        drawing.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&drawing.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:absoluteAnchor>
+                    <xdr:pos x="1219200" y="190500"/>
+                    <xdr:ext cx="1142857" cy="1142857"/>
+                    <xdr:pic>
+                    <xdr:nvPicPr>
+                        <xdr:cNvPr id="2" name="Picture 1" descr="rust.png"/>
+                        <xdr:cNvPicPr>
+                        <a:picLocks noChangeAspect="1"/>
+                        </xdr:cNvPicPr>
+                    </xdr:nvPicPr>
+                    <xdr:blipFill>
+                        <a:blip xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:embed="rId1"/>
+                        <a:stretch>
+                        <a:fillRect/>
+                        </a:stretch>
+                    </xdr:blipFill>
+                    <xdr:spPr>
+                        <a:xfrm>
+                        <a:off x="1219200" y="190500"/>
+                        <a:ext cx="1142857" cy="1142857"/>
+                        </a:xfrm>
+                        <a:prstGeom prst="rect">
+                        <a:avLst/>
+                        </a:prstGeom>
+                    </xdr:spPr>
+                    </xdr:pic>
+                    <xdr:clientData/>
+                </xdr:absoluteAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_absolute_anchor_chart() {
+        let mut drawing = Drawing::new();
+
+        let drawing_info = DrawingInfo {
+            col_absolute: 457200,
+            row_absolute: 285750,
+            width: 4572000.0,
+            height: 2743200.0,
+            name: "Chart 1".to_string(),
+            rel_id: 1,
+            object_movement: ObjectMovement::AbsoluteAnchor,
+            drawing_type: DrawingType::Chart,
+            ..Default::default()
+        };
+
+        drawing.drawings.push(drawing_info);
+
+        drawing.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&drawing.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:absoluteAnchor>
+                  <xdr:pos x="457200" y="285750"/>
+                  <xdr:ext cx="4572000" cy="2743200"/>
+                  <xdr:graphicFrame macro="">
+                    <xdr:nvGraphicFramePr>
+                      <xdr:cNvPr id="2" name="Chart 1"/>
+                      <xdr:cNvGraphicFramePr/>
+                    </xdr:nvGraphicFramePr>
+                    <xdr:xfrm>
+                      <a:off x="0" y="0"/>
+                      <a:ext cx="0" cy="0"/>
+                    </xdr:xfrm>
+                    <a:graphic>
+                      <a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/chart">
+                        <c:chart xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:id="rId1"/>
+                      </a:graphicData>
+                    </a:graphic>
+                  </xdr:graphicFrame>
+                  <xdr:clientData/>
+                </xdr:absoluteAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_absolute_anchor_shape() {
+        let mut drawing = Drawing::new();
+
+        let drawing_info = DrawingInfo {
+            col_absolute: 1219200,
+            row_absolute: 381000,
+            width: 1371600.0,
+            height: 952500.0,
+            name: "TextBox 1".to_string(),
+            object_movement: ObjectMovement::AbsoluteAnchor,
+            drawing_type: DrawingType::Shape,
+            ..Default::default()
+        };
+
+        drawing.drawings.push(drawing_info);
+        drawing.shapes.push(Shape::textbox().set_text("Hello"));
+
+        drawing.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&drawing.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:absoluteAnchor>
+                  <xdr:pos x="1219200" y="381000"/>
+                  <xdr:ext cx="1371600" cy="952500"/>
+                  <xdr:sp macro="" textlink="">
+                    <xdr:nvSpPr>
+                      <xdr:cNvPr id="2" name="TextBox 1"/>
+                      <xdr:cNvSpPr txBox="1"/>
+                    </xdr:nvSpPr>
+                    <xdr:spPr>
+                      <a:xfrm>
+                        <a:off x="1219200" y="381000"/>
+                        <a:ext cx="1371600" cy="952500"/>
+                      </a:xfrm>
+                      <a:prstGeom prst="rect">
+                        <a:avLst/>
+                      </a:prstGeom>
+                      <a:solidFill>
+                        <a:schemeClr val="lt1"/>
+                      </a:solidFill>
+                      <a:ln w="9525" cmpd="sng">
+                        <a:solidFill>
+                          <a:schemeClr val="lt1">
+                            <a:shade val="50000"/>
+                          </a:schemeClr>
+                        </a:solidFill>
+                      </a:ln>
+                    </xdr:spPr>
+                    <xdr:style>
+                      <a:lnRef idx="0">
+                        <a:scrgbClr r="0" g="0" b="0"/>
+                      </a:lnRef>
+                      <a:fillRef idx="0">
+                        <a:scrgbClr r="0" g="0" b="0"/>
+                      </a:fillRef>
+                      <a:effectRef idx="0">
+                        <a:scrgbClr r="0" g="0" b="0"/>
+                      </a:effectRef>
+                      <a:fontRef idx="minor">
+                        <a:schemeClr val="dk1"/>
+                      </a:fontRef>
+                    </xdr:style>
+                    <xdr:txBody>
+                      <a:bodyPr wrap="square" rtlCol="0" anchor="t"/>
+                      <a:lstStyle/>
+                      <a:p>
+                        <a:r>
+                          <a:rPr lang="en-US" sz="1100"/>
+                          <a:t>TextBox 1</a:t>
+                        </a:r>
+                      </a:p>
+                    </xdr:txBody>
+                  </xdr:sp>
+                  <xdr:clientData/>
+                </xdr:absoluteAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
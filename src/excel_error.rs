@@ -0,0 +1,225 @@
+// excel_error - A module for representing Excel error values.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::XlsxError;
+
+/// The `ExcelError` enum defines the error values that can be written to an
+/// Excel worksheet cell.
+///
+/// Excel error values such as `#N/A` are a separate data type in Excel and
+/// aren't the same as a string with the same text. For example the Excel
+/// `ISNA()` function only returns `TRUE` for a `#N/A` error value and charts
+/// display a gap, or an interpolated line, for `#N/A` values.
+///
+/// The dynamic array errors `#SPILL!` and `#CALC!` are stored by Excel as
+/// `#VALUE!` errors with additional rich value metadata. This is handled
+/// automatically when they are written as cell values. They aren't supported
+/// as formula results.
+///
+/// `ExcelError` implements [`IntoExcelData`](crate::IntoExcelData) so it can
+/// be written with [`Worksheet::write()`](crate::Worksheet::write) and it can
+/// also be used as the result of a formula with
+/// [`Formula::set_result()`](crate::Formula::set_result). With the `serde`
+/// feature it can also be used as a field in serialized structs.
+///
+/// # Examples
+///
+/// The following example demonstrates writing Excel error values to a
+/// worksheet.
+///
+/// ```
+/// # // This code is available in examples/doc_excel_error.rs
+/// #
+/// # use rust_xlsxwriter::{ExcelError, Formula, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     // Write some error values.
+///     worksheet.write(0, 0, ExcelError::NA)?;
+///     worksheet.write(1, 0, ExcelError::Div0)?;
+///
+///     // Use an error value as the result of a formula.
+///     worksheet.write(2, 0, Formula::new("=1/0").set_result(ExcelError::Div0))?;
+///
+///     // The error value is recognized by Excel functions.
+///     worksheet.write(3, 0, Formula::new("=ISNA(A1)"))?;
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExcelError {
+    /// The `#N/A` error. A value isn't available.
+    NA,
+
+    /// The `#DIV/0!` error. A number is divided by zero.
+    Div0,
+
+    /// The `#VALUE!` error. A value has the wrong type.
+    Value,
+
+    /// The `#REF!` error. A cell reference isn't valid.
+    Ref,
+
+    /// The `#NAME?` error. A formula contains an unrecognized name.
+    Name,
+
+    /// The `#NUM!` error. A numeric value isn't valid.
+    Num,
+
+    /// The `#NULL!` error. Two ranges don't intersect.
+    Null,
+
+    /// The `#SPILL!` error. A dynamic array formula can't spill its results
+    /// into the adjacent cells.
+    Spill,
+
+    /// The `#CALC!` error. A dynamic array formula can't be calculated, for
+    /// example if it returns an empty array.
+    Calc,
+}
+
+impl ExcelError {
+    // The error values and their string representation.
+    const ERRORS: [(ExcelError, &'static str); 9] = [
+        (ExcelError::NA, "#N/A"),
+        (ExcelError::Div0, "#DIV/0!"),
+        (ExcelError::Value, "#VALUE!"),
+        (ExcelError::Ref, "#REF!"),
+        (ExcelError::Name, "#NAME?"),
+        (ExcelError::Num, "#NUM!"),
+        (ExcelError::Null, "#NULL!"),
+        (ExcelError::Spill, "#SPILL!"),
+        (ExcelError::Calc, "#CALC!"),
+    ];
+
+    // The errors that are stored as rich values, in the order of the rich
+    // values in the file, along with their rich value error type.
+    pub(crate) const RICH_VALUE_ERRORS: [(ExcelError, u32); 2] =
+        [(ExcelError::Spill, 8), (ExcelError::Calc, 13)];
+
+    // The name used to identify the type when it is serialized to a worksheet.
+    // The "$" prefix ensures that it can't clash with a Rust type name.
+    #[cfg(feature = "serde")]
+    pub(crate) const SERDE_NAME: &'static str = "$rust_xlsxwriter::ExcelError";
+
+    // Get the string representation of the error.
+    pub(crate) fn as_str(self) -> &'static str {
+        Self::ERRORS
+            .iter()
+            .find(|(error, _)| *error == self)
+            .map_or("#N/A", |(_, string)| string)
+    }
+
+    // Get the string value of the error in the file. The rich value errors are
+    // stored as #VALUE! errors.
+    pub(crate) fn file_str(self) -> &'static str {
+        if self.value_metadata_id().is_some() {
+            ExcelError::Value.as_str()
+        } else {
+            self.as_str()
+        }
+    }
+
+    // Get the 1-based index of the value metadata for errors that are stored
+    // as rich values. The rich value errors are written before any embedded
+    // images so that the index is the same in all worksheets.
+    pub(crate) fn value_metadata_id(self) -> Option<u32> {
+        Self::RICH_VALUE_ERRORS
+            .iter()
+            .position(|(error, _)| *error == self)
+            .map(|index| index as u32 + 1)
+    }
+
+    // Get the error from the variant index used for serialization.
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_variant_index(index: u32) -> Option<ExcelError> {
+        Self::ERRORS.get(index as usize).map(|(error, _)| *error)
+    }
+
+    // Get the variant index used for serialization.
+    #[cfg(feature = "serde")]
+    fn variant_index(self) -> u32 {
+        Self::ERRORS
+            .iter()
+            .position(|(error, _)| *error == self)
+            .unwrap_or_default() as u32
+    }
+}
+
+impl fmt::Display for ExcelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Convert an Excel error string such as `"#N/A"` into an [`ExcelError`]
+/// value.
+///
+/// # Errors
+///
+/// - [`XlsxError::ParameterError`] - The string isn't an Excel error value.
+///
+impl FromStr for ExcelError {
+    type Err = XlsxError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ERRORS
+            .iter()
+            .find(|(_, string)| *string == value.trim())
+            .map(|(error, _)| *error)
+            .ok_or_else(|| {
+                XlsxError::ParameterError(format!("Unknown Excel error value '{value}'"))
+            })
+    }
+}
+
+// -----------------------------------------------------------------------
+// Serde support.
+// -----------------------------------------------------------------------
+
+/// Implementation of the `serde::Serialize` trait for `ExcelError`.
+///
+/// The error is serialized to a worksheet as an Excel error value, and to
+/// other formats as its string representation like `"#N/A"`.
+///
+#[cfg(feature = "serde")]
+impl Serialize for ExcelError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_unit_variant(Self::SERDE_NAME, self.variant_index(), self.as_str())
+    }
+}
+
+/// Implementation of the `serde::Deserialize` trait for `ExcelError` from its
+/// string representation.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ExcelError {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
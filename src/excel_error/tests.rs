@@ -0,0 +1,49 @@
+// Excel error unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod excel_error_tests {
+
+    use crate::ExcelError;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display_and_from_str() {
+        let errors = [
+            (ExcelError::NA, "#N/A"),
+            (ExcelError::Div0, "#DIV/0!"),
+            (ExcelError::Value, "#VALUE!"),
+            (ExcelError::Ref, "#REF!"),
+            (ExcelError::Name, "#NAME?"),
+            (ExcelError::Num, "#NUM!"),
+            (ExcelError::Null, "#NULL!"),
+            (ExcelError::Spill, "#SPILL!"),
+            (ExcelError::Calc, "#CALC!"),
+        ];
+
+        for (error, expected) in errors {
+            assert_eq!(expected, error.to_string());
+            assert_eq!(error, expected.parse::<ExcelError>().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        assert!("#FOO!".parse::<ExcelError>().is_err());
+        assert!("N/A".parse::<ExcelError>().is_err());
+    }
+
+    #[test]
+    fn test_rich_value_errors() {
+        assert_eq!(Some(1), ExcelError::Spill.value_metadata_id());
+        assert_eq!(Some(2), ExcelError::Calc.value_metadata_id());
+        assert_eq!(None, ExcelError::Value.value_metadata_id());
+
+        assert_eq!("#VALUE!", ExcelError::Spill.file_str());
+        assert_eq!("#VALUE!", ExcelError::Calc.file_str());
+        assert_eq!("#N/A", ExcelError::NA.file_str());
+    }
+}
@@ -0,0 +1,582 @@
+// font_metrics - A module for representing font character widths used when
+// autofitting cells.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::collections::HashMap;
+
+use crate::utility::pixel_width;
+use crate::Format;
+use crate::MAX_AUTOFIT_WIDTH_PIXELS;
+
+// The first and last characters of the built-in character width tables.
+const FIRST_CHAR: char = ' ';
+const LAST_CHAR: char = '~';
+
+// Character widths, in 1/1000 em units, for the printable ASCII characters
+// from " " to "~". Arial is metrically compatible with Helvetica so the
+// widths are taken from the standard Helvetica font metrics.
+#[rustfmt::skip]
+const ARIAL_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+#[rustfmt::skip]
+const ARIAL_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+// Times New Roman is metrically compatible with Times Roman.
+#[rustfmt::skip]
+const TIMES_WIDTHS: [u16; 95] = [
+    250, 333, 408, 500, 500, 833, 778, 180, 333, 333, 500, 564, 250, 333, 250, 278,
+    500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 278, 278, 564, 564, 564, 444,
+    921, 722, 667, 667, 722, 611, 556, 722, 722, 333, 389, 722, 611, 889, 722, 722,
+    556, 722, 667, 556, 611, 722, 722, 944, 722, 722, 611, 333, 278, 333, 469, 500,
+    333, 444, 500, 444, 500, 444, 333, 500, 500, 278, 278, 500, 278, 778, 500, 500,
+    500, 500, 333, 389, 278, 500, 500, 722, 500, 500, 444, 480, 200, 480, 541,
+];
+
+// The approximate increase in character width for bold fonts that don't have
+// bold character widths.
+const BOLD_SCALE: f64 = 1.07;
+
+// The approximate overhang of the last character of italic text, as a
+// fraction of the font size.
+const ITALIC_OVERHANG: f64 = 0.1;
+
+// The width of a character in 1/1000 em units that isn't in the width table.
+const DEFAULT_CHAR_WIDTH: f64 = 556.0;
+
+// The default row height as a multiple of the font size.
+const DEFAULT_LINE_HEIGHT: f64 = 1.25;
+
+// The maximum digit width and the cell padding, in pixels at 96 dpi, that
+// Excel uses to convert column widths for the default Calibri 11 font.
+const CALIBRI_11_METRICS: (f64, f64) = (7.0, 5.0);
+
+/// The `FontMetrics` struct represents the character widths of a font for use
+/// with autofit.
+///
+/// The [`Worksheet::autofit()`](crate::Worksheet::autofit) method calculates
+/// the width of cell data, and the height of wrapped and rotated text, from
+/// the character widths of the cell font. `rust_xlsxwriter` has built-in
+/// metrics for the following fonts:
+///
+/// - Calibri, the default Excel font.
+/// - Arial (and Helvetica).
+/// - Times New Roman (and Times).
+/// - Courier New (and Courier).
+///
+/// Other fonts are measured using the Calibri metrics scaled to the font size.
+/// For other fonts, or to fine tune the built-in metrics, you can define the
+/// character widths with `FontMetrics` and add them to a worksheet with
+/// [`Worksheet::set_autofit_font_metrics()`](crate::Worksheet::set_autofit_font_metrics).
+///
+/// Character widths are in units of 1/1000 of the font size (the "em"), which
+/// is the scale used in most font metric files. For example a character with a
+/// width of 500 in an 11 point font is 5.5 points, or 7.33 pixels, wide.
+///
+/// # Examples
+///
+/// The following example demonstrates adding font metrics for a monospaced
+/// font that isn't one of the built-in fonts.
+///
+/// ```
+/// # // This code is available in examples/doc_font_metrics.rs
+/// #
+/// use rust_xlsxwriter::{FontMetrics, Format, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///
+///     // All of the characters in the font have the same width.
+///     let metrics = FontMetrics::new("Consolas")
+///         .set_default_width(550)
+///         .set_line_height(1.3);
+///
+///     worksheet.set_autofit_font_metrics(&metrics);
+///
+///     // Write some data in the font.
+///     let format = Format::new().set_font_name("Consolas").set_font_size(9);
+///     worksheet.write_with_format(0, 0, "Monospaced text", &format)?;
+///     worksheet.write_with_format(1, 0, "in Consolas 9", &format)?;
+///
+///     // Autofit the column using the font metrics.
+///     worksheet.autofit();
+///
+///     workbook.save("font_metrics.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontMetrics {
+    pub(crate) name: String,
+    widths: HashMap<char, f64>,
+    bold_widths: HashMap<char, f64>,
+    default_width: f64,
+    line_height: f64,
+}
+
+impl FontMetrics {
+    /// Create a new `FontMetrics` object for a font.
+    ///
+    /// The metrics are used for cells with a format that has a matching font
+    /// name, see [`Format::set_font_name()`]. The name match is case
+    /// insensitive.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The font name.
+    ///
+    pub fn new(name: impl Into<String>) -> FontMetrics {
+        FontMetrics {
+            name: name.into(),
+            widths: HashMap::new(),
+            bold_widths: HashMap::new(),
+            default_width: DEFAULT_CHAR_WIDTH,
+            line_height: DEFAULT_LINE_HEIGHT,
+        }
+    }
+
+    /// Set the width of a character.
+    ///
+    /// # Parameters
+    ///
+    /// - `char`: The character.
+    /// - `width`: The width of the character in 1/1000 em units.
+    ///
+    pub fn set_char_width(mut self, char: char, width: u16) -> FontMetrics {
+        self.widths.insert(char, f64::from(width));
+        self
+    }
+
+    /// Set the width of several characters that have the same width.
+    ///
+    /// This is a shortcut for calling
+    /// [`FontMetrics::set_char_width()`] for each character in a string, which
+    /// is useful for groups of characters such as digits that usually have
+    /// the same width.
+    ///
+    /// # Parameters
+    ///
+    /// - `chars`: A string of characters.
+    /// - `width`: The width of each character in 1/1000 em units.
+    ///
+    pub fn set_chars_width(mut self, chars: &str, width: u16) -> FontMetrics {
+        for char in chars.chars() {
+            self.widths.insert(char, f64::from(width));
+        }
+        self
+    }
+
+    /// Set the width of a character in the bold version of the font.
+    ///
+    /// Characters that don't have a bold width use the regular width
+    /// increased by approximately 7%.
+    ///
+    /// # Parameters
+    ///
+    /// - `char`: The character.
+    /// - `width`: The width of the bold character in 1/1000 em units.
+    ///
+    pub fn set_bold_char_width(mut self, char: char, width: u16) -> FontMetrics {
+        self.bold_widths.insert(char, f64::from(width));
+        self
+    }
+
+    /// Set the width of characters that don't have a defined width.
+    ///
+    /// The default is 556, the width of a digit in Arial.
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: The default character width in 1/1000 em units.
+    ///
+    pub fn set_default_width(mut self, width: u16) -> FontMetrics {
+        self.default_width = f64::from(width);
+        self
+    }
+
+    /// Set the height of a line of text as a multiple of the font size.
+    ///
+    /// This is used to calculate the row height of wrapped and rotated text.
+    /// It is the default Excel row height for the font divided by the font
+    /// size. For example, Calibri 11 has a default row height of 15 points so
+    /// the line height is 15/11 = 1.364. The default is 1.25.
+    ///
+    /// # Parameters
+    ///
+    /// - `height`: The line height as a multiple of the font size. It must be
+    ///   greater than 0.
+    ///
+    pub fn set_line_height(mut self, height: f64) -> FontMetrics {
+        if height <= 0.0 || !height.is_finite() {
+            eprintln!("Font line height '{height}' must be greater than 0.");
+            return self;
+        }
+
+        self.line_height = height;
+        self
+    }
+
+    /// Get the name of the font.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // -----------------------------------------------------------------------
+    // Crate level helper methods.
+    // -----------------------------------------------------------------------
+
+    // Get the built-in metrics for a font, if available.
+    pub(crate) fn builtin(name: &str) -> Option<FontMetrics> {
+        match name.to_lowercase().as_str() {
+            "calibri" => Some(Self::calibri()),
+            "arial" | "helvetica" => Some(
+                Self::from_table(name, &ARIAL_WIDTHS, 1.275).set_bold_table(&ARIAL_BOLD_WIDTHS),
+            ),
+            "times new roman" | "times" => Some(Self::from_table(name, &TIMES_WIDTHS, 1.3125)),
+            "courier new" | "courier" => {
+                Some(Self::new(name).set_default_width(600).set_line_height(1.32))
+            }
+            _ => None,
+        }
+    }
+
+    // The Calibri widths are derived from the Calibri 11 pixel widths used by
+    // `utility::pixel_width()` so that the default font gives the same results.
+    pub(crate) fn calibri() -> FontMetrics {
+        let mut metrics = Self::new("Calibri").set_line_height(15.0 / 11.0);
+        let pixels_to_units = 1000.0 / points_to_pixels(11.0);
+
+        for char in FIRST_CHAR..=LAST_CHAR {
+            let pixels = pixel_width(&char.to_string());
+            metrics
+                .widths
+                .insert(char, f64::from(pixels) * pixels_to_units);
+        }
+        metrics.default_width = 8.0 * pixels_to_units;
+
+        metrics
+    }
+
+    // Create metrics from a table of ASCII character widths.
+    fn from_table(name: &str, widths: &[u16; 95], line_height: f64) -> FontMetrics {
+        let mut metrics = Self::new(name).set_line_height(line_height);
+
+        for (char, width) in (FIRST_CHAR..=LAST_CHAR).zip(widths) {
+            metrics.widths.insert(char, f64::from(*width));
+        }
+
+        metrics
+    }
+
+    // Add a table of ASCII bold character widths.
+    fn set_bold_table(mut self, widths: &[u16; 95]) -> FontMetrics {
+        for (char, width) in (FIRST_CHAR..=LAST_CHAR).zip(widths) {
+            self.bold_widths.insert(char, f64::from(*width));
+        }
+
+        self
+    }
+
+    // Get the width of a character in 1/1000 em units.
+    fn char_width(&self, char: char, bold: bool) -> f64 {
+        let width = self
+            .widths
+            .get(&char)
+            .copied()
+            .unwrap_or(self.default_width);
+
+        if bold {
+            self.bold_widths
+                .get(&char)
+                .copied()
+                .unwrap_or(width * BOLD_SCALE)
+        } else {
+            width
+        }
+    }
+}
+
+// The workbook theme fonts. The default font is the theme minor, body, font
+// at size 11 and its metrics are used to convert column widths between
+// character units and pixels. Only the Calibri 11 metrics are currently
+// supported and they are used for all theme fonts.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ThemeFont {
+    pub(crate) name: String,
+    pub(crate) major_name: String,
+    max_digit_width: f64,
+    padding: f64,
+}
+
+impl ThemeFont {
+    pub(crate) fn new(name: &str, major_name: &str) -> ThemeFont {
+        let (max_digit_width, padding) = CALIBRI_11_METRICS;
+
+        ThemeFont {
+            name: name.to_string(),
+            major_name: major_name.to_string(),
+            max_digit_width,
+            padding,
+        }
+    }
+
+    // Convert a column width in character units to pixels. Excel rounds the
+    // column width to the nearest pixel.
+    pub(crate) fn width_to_pixels(&self, width: f64) -> u32 {
+        if width < 1.0 {
+            (width * (self.max_digit_width + self.padding)).round() as u32
+        } else {
+            (width * self.max_digit_width).round() as u32 + self.padding as u32
+        }
+    }
+
+    // Convert a column width in pixels to character units.
+    pub(crate) fn pixels_to_width(&self, pixels: u16) -> f64 {
+        let width = f64::from(pixels);
+
+        if width < 12.0 {
+            width / (self.max_digit_width + self.padding)
+        } else {
+            (width - self.padding) / self.max_digit_width
+        }
+    }
+
+    // Convert a column width in character units to the width stored in the
+    // file, which is the pixel width, including the padding, in units of the
+    // maximum digit width truncated to 1/256 of a character.
+    pub(crate) fn stored_width(&self, width: f64) -> f64 {
+        let pixels = f64::from(self.width_to_pixels(width));
+
+        (pixels / self.max_digit_width * 256.0).floor() / 256.0
+    }
+}
+
+impl Default for ThemeFont {
+    fn default() -> Self {
+        ThemeFont::new("Calibri", "Cambria")
+    }
+}
+
+// The font properties of a cell format that affect the size of the displayed
+// cell data.
+#[derive(Clone, Debug)]
+pub(crate) struct AutofitFont {
+    metrics: FontMetrics,
+    size: f64,
+    bold: bool,
+    italic: bool,
+    rotation: i16,
+    pub(crate) text_wrap: bool,
+    pub(crate) is_default: bool,
+}
+
+impl AutofitFont {
+    // Get the autofit font for a cell format, using any user defined font
+    // metrics, then the built-in metrics, then the default Calibri metrics.
+    // The default fonts are measured as the theme fonts.
+    pub(crate) fn new(
+        format: Option<&Format>,
+        user_metrics: &HashMap<String, FontMetrics>,
+        theme_font: &ThemeFont,
+    ) -> AutofitFont {
+        let default_format = Format::default();
+        let format = format.unwrap_or(&default_format);
+        let font = &format.font;
+
+        let name = font
+            .theme_name(&theme_font.name, &theme_font.major_name)
+            .to_lowercase();
+        let size = font.size.parse::<f64>().unwrap_or(11.0);
+        let size = if size > 0.0 { size } else { 11.0 };
+
+        let metrics = user_metrics
+            .get(&name)
+            .cloned()
+            .or_else(|| FontMetrics::builtin(&name))
+            .unwrap_or_else(FontMetrics::calibri);
+
+        let is_default = name == "calibri"
+            && size == 11.0
+            && !font.bold
+            && !font.italic
+            && format.alignment.rotation == 0
+            && !user_metrics.contains_key(&name);
+
+        AutofitFont {
+            metrics,
+            size,
+            bold: font.bold,
+            italic: font.italic,
+            rotation: format.alignment.rotation,
+            text_wrap: format.alignment.text_wrap,
+            is_default,
+        }
+    }
+
+    // Get the width in pixels of a single line of text.
+    pub(crate) fn text_width(&self, text: &str) -> f64 {
+        if text.is_empty() {
+            return 0.0;
+        }
+
+        let units: f64 = text
+            .chars()
+            .map(|char| self.metrics.char_width(char, self.bold))
+            .sum();
+
+        let mut width = units * points_to_pixels(self.size) / 1000.0;
+
+        if self.italic {
+            width += points_to_pixels(self.size) * ITALIC_OVERHANG;
+        }
+
+        width
+    }
+
+    // Get the height in pixels of a line of text.
+    pub(crate) fn line_height(&self) -> f64 {
+        points_to_pixels(self.size * self.metrics.line_height)
+    }
+
+    // Check if the text is rotated.
+    pub(crate) fn is_rotated(&self) -> bool {
+        self.rotation != 0
+    }
+
+    // Get the width in pixels of the cell text, taking the rotation into
+    // account. Multi-line text is measured by the longest line.
+    pub(crate) fn cell_width(&self, text: &str) -> u16 {
+        let (width, height) = self.text_extent(text);
+        let width = match self.rotation_radians() {
+            Some(angle) => width * angle.cos().abs() + height * angle.sin().abs(),
+            None => self.stacked_width(text),
+        };
+
+        clamp_pixels(width)
+    }
+
+    // Get the height in pixels of the cell text for a column width in pixels,
+    // taking the text wrap and rotation into account.
+    pub(crate) fn cell_height(&self, text: &str, column_width: f64) -> f64 {
+        let line_height = self.line_height();
+
+        match self.rotation_radians() {
+            Some(angle) if self.rotation != 0 => {
+                let (width, height) = self.text_extent(text);
+                width * angle.sin().abs() + height * angle.cos().abs()
+            }
+            Some(_) => {
+                let lines = if self.text_wrap {
+                    self.wrapped_lines(text, column_width)
+                } else {
+                    1
+                };
+                lines as f64 * line_height
+            }
+            None => text.chars().filter(|char| *char != '\n').count() as f64 * line_height,
+        }
+    }
+
+    // Get the width of the longest line of the text and the height of the
+    // lines, in pixels. Newlines are only displayed for wrapped text.
+    fn text_extent(&self, text: &str) -> (f64, f64) {
+        let width = text
+            .lines()
+            .map(|line| self.text_width(line))
+            .fold(0.0, f64::max);
+
+        let lines = if self.text_wrap {
+            text.lines().count().max(1)
+        } else {
+            1
+        };
+
+        (width, lines as f64 * self.line_height())
+    }
+
+    // Get the width of stacked, vertical, text, which is the widest character.
+    fn stacked_width(&self, text: &str) -> f64 {
+        text.chars()
+            .map(|char| self.text_width(&char.to_string()))
+            .fold(0.0, f64::max)
+    }
+
+    // Get the text rotation angle in radians, or None for stacked text. Excel
+    // stores angles in the range -1 to -90 as 91 to 180.
+    fn rotation_radians(&self) -> Option<f64> {
+        match self.rotation {
+            255 => None,
+            91..=180 => Some(f64::from(90 - self.rotation).to_radians()),
+            _ => Some(f64::from(self.rotation).to_radians()),
+        }
+    }
+
+    // Count the number of lines of text when it is word wrapped to a column
+    // width.
+    fn wrapped_lines(&self, text: &str, column_width: f64) -> usize {
+        let space_width = self.text_width(" ");
+        let mut lines = 0;
+
+        for segment in text.split('\n') {
+            let mut line_width = 0.0;
+            lines += 1;
+
+            for word in segment.split(' ') {
+                let word_width = self.text_width(word);
+
+                if line_width > 0.0 && line_width + space_width + word_width > column_width {
+                    lines += 1;
+                    line_width = 0.0;
+                }
+
+                if line_width > 0.0 {
+                    line_width += space_width;
+                }
+
+                // Words that are longer than the column are broken across lines.
+                if word_width > column_width && column_width > 0.0 {
+                    let extra_lines = (word_width / column_width).ceil() as usize - 1;
+                    lines += extra_lines;
+                    line_width = word_width - extra_lines as f64 * column_width;
+                } else {
+                    line_width += word_width;
+                }
+            }
+        }
+
+        lines
+    }
+}
+
+// Convert a font size in points to pixels at 96 dpi.
+fn points_to_pixels(points: f64) -> f64 {
+    points * 4.0 / 3.0
+}
+
+// Round a width to whole pixels within the Excel autofit limit.
+fn clamp_pixels(width: f64) -> u16 {
+    width.round().min(f64::from(MAX_AUTOFIT_WIDTH_PIXELS)) as u16
+}
@@ -0,0 +1,189 @@
+// FontMetrics unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod font_metrics_tests {
+
+    use crate::font_metrics::{AutofitFont, ThemeFont};
+    use crate::utility::pixel_width;
+    use crate::{FontMetrics, Format};
+    use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
+
+    fn autofit_font(format: &Format) -> AutofitFont {
+        AutofitFont::new(Some(format), &HashMap::new(), &ThemeFont::default())
+    }
+
+    #[test]
+    fn test_calibri_widths() {
+        let font = AutofitFont::new(None, &HashMap::new(), &ThemeFont::default());
+        assert!(font.is_default);
+
+        // The Calibri metrics match the default Calibri 11 pixel widths.
+        for string in [
+            "Hello",
+            "Some text with spaces",
+            "1234.5678",
+            "!@#$%^&*()",
+            "Ωμέγα",
+        ] {
+            assert_eq!(
+                pixel_width(string),
+                font.cell_width(string),
+                "string: {string}"
+            );
+        }
+
+        // Calibri is scaled to the font size.
+        let font = autofit_font(&Format::new().set_font_size(22));
+        assert!(!font.is_default);
+        assert_eq!(2 * pixel_width("Hello"), font.cell_width("Hello"));
+
+        // Unknown fonts use the Calibri metrics.
+        let font = autofit_font(&Format::new().set_font_name("Unknown Font"));
+        assert_eq!(pixel_width("Hello"), font.cell_width("Hello"));
+    }
+
+    #[test]
+    fn test_builtin_widths() {
+        // Arial 10: H + e + l + l + o = 2278 units.
+        let font = autofit_font(&Format::new().set_font_name("Arial").set_font_size(10));
+        assert_eq!(30, font.cell_width("Hello"));
+
+        // Arial 10 bold uses the bold widths: 2446 units.
+        let font = autofit_font(
+            &Format::new()
+                .set_font_name("arial")
+                .set_font_size(10)
+                .set_bold(),
+        );
+        assert_eq!(33, font.cell_width("Hello"));
+
+        // Times New Roman 12: 2222 units.
+        let font = autofit_font(
+            &Format::new()
+                .set_font_name("Times New Roman")
+                .set_font_size(12),
+        );
+        assert_eq!(36, font.cell_width("Hello"));
+
+        // Courier New is monospaced.
+        let font = autofit_font(&Format::new().set_font_name("Courier New").set_font_size(10));
+        assert_eq!(40, font.cell_width("Hello"));
+        assert_eq!(40, font.cell_width("iiiii"));
+    }
+
+    #[test]
+    fn test_user_metrics() {
+        let metrics = FontMetrics::new("My Font")
+            .set_default_width(500)
+            .set_chars_width("il", 250)
+            .set_char_width('W', 1000)
+            .set_bold_char_width('W', 1200)
+            .set_line_height(1.5);
+
+        let mut user_metrics = HashMap::new();
+        user_metrics.insert("my font".to_string(), metrics);
+
+        // 12pt is 16 pixels per em.
+        let format = Format::new().set_font_name("My Font").set_font_size(12);
+        let font = AutofitFont::new(Some(&format), &user_metrics, &ThemeFont::default());
+        assert_eq!(8 + 4 + 4 + 16, font.cell_width("xilW"));
+        assert_eq!(24.0, font.line_height());
+
+        // Bold widths default to the regular widths increased by 7%.
+        let format = format.set_bold();
+        let font = AutofitFont::new(Some(&format), &user_metrics, &ThemeFont::default());
+        assert_eq!(19, font.cell_width("W"));
+        assert_eq!(9, font.cell_width("x"));
+
+        // User metrics override the built-in metrics.
+        let mut user_metrics = HashMap::new();
+        user_metrics.insert(
+            "calibri".to_string(),
+            FontMetrics::new("Calibri").set_default_width(1000),
+        );
+        let font = AutofitFont::new(None, &user_metrics, &ThemeFont::default());
+        assert!(!font.is_default);
+        assert_eq!(44, font.cell_width("abc"));
+
+        // Invalid line heights are ignored.
+        let metrics = FontMetrics::new("My Font").set_line_height(-1.0);
+        assert_eq!(metrics, FontMetrics::new("My Font"));
+    }
+
+    #[test]
+    fn test_theme_font() {
+        // The default font is measured as the theme minor font.
+        let mut user_metrics = HashMap::new();
+        user_metrics.insert(
+            "aptos narrow".to_string(),
+            FontMetrics::new("Aptos Narrow").set_default_width(1000),
+        );
+        let aptos = ThemeFont::new("Aptos Narrow", "Aptos Display");
+        let font = AutofitFont::new(None, &user_metrics, &aptos);
+        assert!(!font.is_default);
+        assert_eq!(44, font.cell_width("abc"));
+
+        // A font explicitly set to Calibri isn't replaced by the theme font.
+        let format = Format::new().set_font_name("Calibri");
+        let font = AutofitFont::new(Some(&format), &user_metrics, &aptos);
+        assert!(font.is_default);
+        assert_eq!(pixel_width("abc"), font.cell_width("abc"));
+
+        // Column width conversions for the theme fonts.
+        for name in ["Calibri", "Aptos Narrow", "Verdana"] {
+            let theme_font = ThemeFont::new(name, "Cambria");
+
+            assert_eq!(64, theme_font.width_to_pixels(8.43));
+            assert_eq!(6, theme_font.width_to_pixels(0.5));
+            assert_eq!(9.140625, theme_font.stored_width(8.43));
+            assert_eq!(10.0, theme_font.pixels_to_width(75));
+            assert_eq!(0.5, theme_font.pixels_to_width(6));
+        }
+    }
+
+    #[test]
+    fn test_cell_heights() {
+        // Calibri 11 has a line height of 20 pixels, 15 points.
+        let font = AutofitFont::new(None, &HashMap::new(), &ThemeFont::default());
+        assert_eq!(20.0, font.line_height().round());
+
+        // Newlines only add lines to wrapped text.
+        assert_eq!(20.0, font.cell_height("Hello\nWorld", 100.0).round());
+
+        let wrap = Format::new().set_text_wrap();
+        let font = autofit_font(&wrap);
+        assert_eq!(40.0, font.cell_height("Hello\nWorld", 100.0).round());
+        assert_eq!(pixel_width("Hello"), font.cell_width("Hello\nHi"));
+
+        // Words are wrapped at the column width. "Hello" is 33 pixels wide.
+        assert_eq!(20.0, font.cell_height("Hello Hello", 80.0).round());
+        assert_eq!(40.0, font.cell_height("Hello Hello", 60.0).round());
+        assert_eq!(60.0, font.cell_height("Hello Hello Hello", 40.0).round());
+
+        // Long words are broken across lines.
+        assert_eq!(60.0, font.cell_height("HelloHelloHello", 40.0).round());
+
+        // Rotated text swaps the width and height.
+        let font = autofit_font(&Format::new().set_rotation(90));
+        assert_eq!(20, font.cell_width("Hello"));
+        assert_eq!(33.0, font.cell_height("Hello", 64.0).round());
+
+        let font = autofit_font(&Format::new().set_rotation(-90));
+        assert_eq!(20, font.cell_width("Hello"));
+        assert_eq!(33.0, font.cell_height("Hello", 64.0).round());
+
+        // Rotated at 45 degrees.
+        let font = autofit_font(&Format::new().set_rotation(45));
+        assert_eq!(37, font.cell_width("Hello"));
+
+        // Stacked text is the width of the widest character.
+        let font = autofit_font(&Format::new().set_rotation(270));
+        assert_eq!(9, font.cell_width("Hello"));
+        assert_eq!(100.0, font.cell_height("Hello", 64.0).round());
+    }
+}
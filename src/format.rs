@@ -8,9 +8,13 @@
 
 mod tests;
 
-use std::{collections::HashMap, fmt, hash::Hash, sync::OnceLock};
+use std::{fmt, hash::Hash, sync::OnceLock};
 
-use crate::Color;
+use crate::num_format::BUILTIN_NUM_FORMATS;
+use crate::{CellStyle, Color};
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// The `Format` struct is used to define cell formatting for data in a
 /// worksheet.
@@ -85,6 +89,7 @@ use crate::Color;
 /// - [Number Format Categories](#number-format-categories)
 /// - [Number Formats in Different
 ///   Locales](#number-formats-in-different-locales)
+/// - [Serde support](#serde-support)
 /// - [API](#implementations)
 ///
 /// # Creating and using a Format object
@@ -524,6 +529,33 @@ use crate::Color;
 /// use US locale formatting which will then be rendered in the settings of your
 /// host OS.
 ///
+/// # Serde support
+///
+/// With the `serde` feature enabled `Format` implements the `Serialize` and
+/// `Deserialize` traits so that formats can be stored in configuration files
+/// such as TOML or JSON. The property names are the same as the `Format`
+/// setter methods without the `set_` prefix, and the enum values, such as
+/// [`FormatBorder`] or [`FormatAlign`], are in "snake case". Colors are
+/// strings as described in [`Color`]. For example:
+///
+/// ```text
+/// num_format = "#,##0.00"
+/// bold = true
+/// font_color = "#1F497D"
+/// background_color = "theme(4, 1)"
+/// align = ["center", "vertical_center"]
+/// border = "thin"
+/// border_bottom_color = "red"
+/// ```
+///
+/// Properties that aren't set have their default values and only
+/// non-default properties are serialized. The `align` property is a list of
+/// the horizontal and vertical alignments and `rotation` is the angle passed
+/// to [`Format::set_rotation()`]. The `border` and `border_color` properties
+/// set all four sides of the cell and can be overridden by properties like
+/// `border_top`. Named cell styles set with [`Format::set_cell_style()`]
+/// aren't serialized.
+///
 #[derive(Debug, Clone, Eq)]
 
 pub struct Format {
@@ -561,6 +593,10 @@ pub struct Format {
     pub(crate) checkbox: bool,
     pub(crate) quote_prefix: bool,
     pub(crate) is_dxf_format: bool,
+
+    // Named cell style properties.
+    pub(crate) cell_style: Option<Box<CellStyle>>,
+    pub(crate) style_xf_id: u32,
 }
 
 impl Hash for Format {
@@ -576,6 +612,7 @@ impl Hash for Format {
         self.locked.hash(state);
         self.checkbox.hash(state);
         self.quote_prefix.hash(state);
+        self.cell_style.hash(state);
     }
 }
 
@@ -591,6 +628,7 @@ impl PartialEq for Format {
             && self.locked == other.locked
             && self.checkbox == other.checkbox
             && self.quote_prefix == other.quote_prefix
+            && self.cell_style == other.cell_style
     }
 }
 
@@ -644,6 +682,9 @@ impl Format {
             hidden: false,
             checkbox: false,
             quote_prefix: false,
+
+            cell_style: None,
+            style_xf_id: 0,
         }
     }
 
@@ -768,7 +809,8 @@ impl Format {
     /// format.
     ///
     /// See also [Number Format Categories] and [Number Formats in Different
-    /// Locales].
+    /// Locales]. Number format codes can also be created, and validated, with
+    /// the [`NumFormat`](crate::NumFormat) builder.
     ///
     /// [Number Format Categories]: crate::Format#number-format-categories
     /// [Number Formats in Different Locales]:
@@ -932,45 +974,10 @@ impl Format {
         self.num_format_index = u16::from(num_format_index);
 
         // Also map the index to a format string. Mainly for DXF formats.
-        let num_formats = HashMap::from([
-            (1, "0"),
-            (2, "0.00"),
-            (3, "#,##0"),
-            (4, "#,##0.00"),
-            (5, "($#,##0_);($#,##0)"),
-            (6, "($#,##0_);[Red]($#,##0)"),
-            (7, "($#,##0.00_);($#,##0.00)"),
-            (8, "($#,##0.00_);[Red]($#,##0.00)"),
-            (9, "0%"),
-            (10, "0.00%"),
-            (11, "0.00E+00"),
-            (12, "# ?/?"),
-            (13, "# ??/??"),
-            (14, "m/d/yy"),
-            (15, "d-mmm-yy"),
-            (16, "d-mmm"),
-            (17, "mmm-yy"),
-            (18, "h:mm AM/PM"),
-            (19, "h:mm:ss AM/PM"),
-            (20, "h:mm"),
-            (21, "h:mm:ss"),
-            (22, "m/d/yy h:mm"),
-            (37, "(#,##0_);(#,##0)"),
-            (38, "(#,##0_);[Red](#,##0)"),
-            (39, "(#,##0.00_);(#,##0.00)"),
-            (40, "(#,##0.00_);[Red](#,##0.00)"),
-            (41, "_(* #,##0_);_(* (#,##0);_(* \"-\"_);_(_)"),
-            (42, "_($* #,##0_);_($* (#,##0);_($* \"-\"_);_(_)"),
-            (43, "_(* #,##0.00_);_(* (#,##0.00);_(* \"-\"??_);_(_)"),
-            (44, "_($* #,##0.00_);_($* (#,##0.00);_($* \"-\"??_);_(_)"),
-            (45, "mm:ss"),
-            (46, "[h]:mm:ss"),
-            (47, "mm:ss.0"),
-            (48, "##0.0E+0"),
-            (49, "@"),
-        ]);
-
-        if let Some(num_format) = num_formats.get(&num_format_index) {
+        if let Some((_, num_format)) = BUILTIN_NUM_FORMATS
+            .iter()
+            .find(|(index, _)| *index == num_format_index)
+        {
             self.num_format = (*num_format).to_string();
         }
 
@@ -1137,6 +1144,7 @@ impl Format {
     ///
     pub fn set_font_name(mut self, font_name: impl Into<String>) -> Format {
         self.font.name = font_name.into();
+        self.font.has_name = true;
 
         if self.font.name != "Calibri" {
             self.font.scheme = String::new();
@@ -1740,6 +1748,7 @@ impl Format {
     ///
     pub fn set_pattern(mut self, pattern: FormatPattern) -> Format {
         self.fill.pattern = pattern;
+        self.fill.gradient = None;
         self
     }
 
@@ -1793,6 +1802,7 @@ impl Format {
         let color = color.into();
         if color.is_valid() {
             self.fill.background_color = color;
+            self.fill.gradient = None;
         }
 
         self
@@ -1849,6 +1859,74 @@ impl Format {
         let color = color.into();
         if color.is_valid() {
             self.fill.foreground_color = color;
+            self.fill.gradient = None;
+        }
+
+        self
+    }
+
+    /// Set the Format gradient fill property.
+    ///
+    /// Set a linear or path gradient fill for a cell, as defined by a
+    /// [`FormatGradient`]. A gradient fill replaces any pattern fill set with
+    /// [`Format::set_pattern()`], [`Format::set_background_color()`] or
+    /// [`Format::set_foreground_color()`]. Similarly, setting any of those
+    /// properties after a gradient fill replaces the gradient.
+    ///
+    /// Excel requires a gradient to have at least two color stops. Gradients
+    /// with fewer stops are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `gradient`: A [`FormatGradient`] reference.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting linear and path gradient
+    /// fills for cells.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_format_set_gradient_fill.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, FormatGradient, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // A linear gradient from top to bottom.
+    ///     let gradient1 = FormatGradient::new()
+    ///         .set_angle(90)
+    ///         .add_stop(0.0, "#FFFFFF")
+    ///         .add_stop(1.0, "#4472C4");
+    ///
+    ///     // A path gradient radiating from the center of the cell.
+    ///     let gradient2 = FormatGradient::new()
+    ///         .set_path(0.5, 0.5, 0.5, 0.5)
+    ///         .add_stop(0.0, "#FFFFFF")
+    ///         .add_stop(1.0, "#ED7D31");
+    ///
+    ///     let format1 = Format::new().set_gradient_fill(&gradient1);
+    ///     let format2 = Format::new().set_gradient_fill(&gradient2);
+    ///
+    ///     worksheet.write_with_format(1, 1, "Linear", &format1)?;
+    ///     worksheet.write_with_format(3, 1, "Path", &format2)?;
+    /// #
+    /// #     workbook.save("formats.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_gradient_fill(mut self, gradient: &FormatGradient) -> Format {
+        if gradient.stops.len() >= 2 {
+            self.fill = Fill {
+                gradient: Some(gradient.clone()),
+                ..Fill::default()
+            };
         }
 
         self
@@ -2216,6 +2294,66 @@ impl Format {
         self
     }
 
+    /// Set the named cell style that the format is based on.
+    ///
+    /// Base the format on a named [`CellStyle`] such as one of Excel's
+    /// built-in "Heading 1", "Good" or "Total" styles, or a user defined
+    /// style. The format inherits any properties of the style that haven't
+    /// been set in the format, and cells with the format are shown in Excel as
+    /// using the style. The style is added to the workbook automatically.
+    ///
+    /// Style names are case insensitive and must be unique in the workbook.
+    /// Saving a workbook where formats use different styles with the same
+    /// name returns an [`XlsxError::ParameterError`](crate::XlsxError)
+    /// error.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: The [`CellStyle`] to base the format on.
+    ///
+    /// # Examples
+    ///
+    /// An example of using built-in and user defined named cell styles.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_cell_style.rs
+    /// #
+    /// # use rust_xlsxwriter::{BuiltinCellStyle, CellStyle, Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Create some built-in and user defined styles.
+    ///     let heading = CellStyle::new_builtin(BuiltinCellStyle::Heading1);
+    ///     let good = CellStyle::new_builtin(BuiltinCellStyle::Good);
+    /// #     let house_style = CellStyle::new(
+    /// #         "House Style",
+    /// #         &Format::new().set_bold().set_font_color("#1F4E79"),
+    /// #     );
+    /// #
+    /// #     // Add a style to the workbook without using it in a cell.
+    /// #     workbook.add_cell_style(&house_style)?;
+    ///
+    ///     // Create formats that inherit from the styles.
+    ///     let heading_format = Format::new().set_cell_style(&heading);
+    ///     let good_format = Format::new().set_cell_style(&good).set_italic();
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_with_format(0, 0, "Results", &heading_format)?;
+    ///     worksheet.write_with_format(1, 0, "Passed", &good_format)?;
+    /// #
+    /// #     workbook.save("cell_styles.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_cell_style(self, style: &CellStyle) -> Format {
+        let mut format = self.merge(&style.format);
+        format.cell_style = Some(Box::new(style.clone()));
+        format
+    }
+
     /// Set the Format cell unlocked state.
     ///
     /// This method can be used to allow modification of a cell in a protected
@@ -2461,6 +2599,7 @@ impl Format {
         self.fill.pattern != FormatPattern::None
             || !self.fill.background_color.is_auto_or_default()
             || !self.fill.foreground_color.is_auto_or_default()
+            || self.fill.gradient.is_some()
     }
 
     pub(crate) fn set_fill_index(&mut self, fill_index: u16, has_fill: bool) {
@@ -2544,6 +2683,16 @@ impl Format {
 
     // Merge the fill properties of two formats.
     fn merge_fill(&mut self, other: &Fill) {
+        // A gradient fill replaces a pattern fill, so a format with either type
+        // of fill doesn't inherit the other type.
+        if self.fill.gradient.is_some() {
+            return;
+        }
+
+        let has_pattern_fill = self.fill.pattern != FormatPattern::None
+            || self.fill.foreground_color != Color::Default
+            || self.fill.background_color != Color::Default;
+
         if self.fill.foreground_color == Color::Default {
             self.fill.foreground_color = other.foreground_color;
         }
@@ -2555,6 +2704,10 @@ impl Format {
         if self.fill.pattern == FormatPattern::None {
             self.fill.pattern = other.pattern;
         }
+
+        if !has_pattern_fill {
+            self.fill.gradient.clone_from(&other.gradient);
+        }
     }
 
     // Merge the font properties of two formats.
@@ -2575,8 +2728,9 @@ impl Format {
             self.font.charset = other.charset;
         }
 
-        if self.font.name == default.name {
+        if !self.font.has_name {
             self.font.name.clone_from(&other.name);
+            self.font.has_name = other.has_name;
         }
 
         if self.font.size == default.size {
@@ -2734,6 +2888,10 @@ pub(crate) struct Font {
     pub(crate) condense: bool,
     pub(crate) extend: bool,
     pub(crate) is_hyperlink: bool,
+
+    // The font name was set by the user, rather than being the default font
+    // which is replaced by the theme minor font.
+    pub(crate) has_name: bool,
 }
 
 impl Default for Font {
@@ -2753,6 +2911,24 @@ impl Default for Font {
             condense: Default::default(),
             extend: Default::default(),
             is_hyperlink: Default::default(),
+            has_name: Default::default(),
+        }
+    }
+}
+
+impl Font {
+    // Get the name of the font as displayed by Excel. The default fonts use
+    // the theme minor and major fonts, which are "Calibri" and "Cambria" in
+    // the default theme.
+    pub(crate) fn theme_name<'a>(&'a self, minor_font: &'a str, major_font: &'a str) -> &'a str {
+        if self.has_name {
+            return &self.name;
+        }
+
+        match (self.scheme.as_str(), self.name.as_str()) {
+            ("minor", "Calibri") => minor_font,
+            ("major", "Cambria") => major_font,
+            _ => &self.name,
         }
     }
 }
@@ -2762,6 +2938,7 @@ pub(crate) struct Fill {
     pub(crate) foreground_color: Color,
     pub(crate) background_color: Color,
     pub(crate) pattern: FormatPattern,
+    pub(crate) gradient: Option<FormatGradient>,
 }
 
 // -----------------------------------------------------------------------
@@ -2807,6 +2984,8 @@ impl From<String> for Format {
 /// The `FormatPattern` enum defines the Excel pattern types that can be added to
 /// a [`Format`].
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FormatPattern {
     /// Automatic or Empty pattern.
     #[default]
@@ -2893,9 +3072,158 @@ impl fmt::Display for FormatPattern {
     }
 }
 
+/// The `FormatGradient` struct represents a gradient fill for a [`Format`].
+///
+/// Excel cell fills can be a solid color, a pattern, or a gradient between two
+/// or more colors. The gradient can be linear, at a given angle, or a "path"
+/// gradient that radiates out from a rectangle defined by its left, right, top
+/// and bottom edges. The colors of the gradient are defined by "stops" at a
+/// position from 0.0 (the start of the gradient) to 1.0 (the end of the
+/// gradient).
+///
+/// A gradient fill is added to a format with [`Format::set_gradient_fill()`].
+///
+/// # Examples
+///
+/// The following example demonstrates setting linear and path gradient fills
+/// for cells.
+///
+/// ```
+/// # // This code is available in examples/doc_format_set_gradient_fill.rs
+/// #
+/// # use rust_xlsxwriter::{Format, FormatGradient, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     // Create a new Excel file object.
+/// #     let mut workbook = Workbook::new();
+/// #
+/// #     // Add a worksheet.
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     // A linear gradient from top to bottom.
+///     let gradient1 = FormatGradient::new()
+///         .set_angle(90)
+///         .add_stop(0.0, "#FFFFFF")
+///         .add_stop(1.0, "#4472C4");
+///
+///     // A path gradient radiating from the center of the cell.
+///     let gradient2 = FormatGradient::new()
+///         .set_path(0.5, 0.5, 0.5, 0.5)
+///         .add_stop(0.0, "#FFFFFF")
+///         .add_stop(1.0, "#ED7D31");
+///
+///     let format1 = Format::new().set_gradient_fill(&gradient1);
+///     let format2 = Format::new().set_gradient_fill(&gradient2);
+///
+///     worksheet.write_with_format(1, 1, "Linear", &format1)?;
+///     worksheet.write_with_format(3, 1, "Path", &format2)?;
+/// #
+/// #     workbook.save("formats.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+pub struct FormatGradient {
+    pub(crate) angle: u16,
+    pub(crate) is_path: bool,
+    pub(crate) left: String,
+    pub(crate) right: String,
+    pub(crate) top: String,
+    pub(crate) bottom: String,
+    pub(crate) stops: Vec<(String, Color)>,
+}
+
+impl FormatGradient {
+    /// Create a new `FormatGradient` object.
+    ///
+    /// The default gradient is linear with an angle of 0 degrees, i.e., from
+    /// left to right. At least two color stops should be added with
+    /// [`FormatGradient::add_stop()`].
+    ///
+    pub fn new() -> FormatGradient {
+        FormatGradient::default()
+    }
+
+    /// Set the angle of a linear gradient.
+    ///
+    /// Set the angle of the gradient in degrees, in the range 0 to 359. An
+    /// angle of 0 is a left to right gradient and an angle of 90 is a top to
+    /// bottom gradient. Values outside the range are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `angle`: The angle of the gradient in degrees.
+    ///
+    pub fn set_angle(mut self, angle: u16) -> FormatGradient {
+        if angle < 360 {
+            self.angle = angle;
+            self.is_path = false;
+        }
+
+        self
+    }
+
+    /// Set the gradient to a path gradient.
+    ///
+    /// A path gradient radiates out from a rectangle within the cell. The
+    /// edges of the rectangle are given as a fraction of the cell width or
+    /// height in the range 0.0 to 1.0. For example, `(0.5, 0.5, 0.5, 0.5)`
+    /// gives a gradient from the center of the cell and `(0.0, 0.0, 0.0,
+    /// 0.0)` gives a gradient from the top left corner. This matches the
+    /// "From center" and "From corner" options in the Excel "Fill Effects"
+    /// dialog. Values outside the range are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `left`: The left edge of the rectangle.
+    /// - `right`: The right edge of the rectangle.
+    /// - `top`: The top edge of the rectangle.
+    /// - `bottom`: The bottom edge of the rectangle.
+    ///
+    pub fn set_path(mut self, left: f64, right: f64, top: f64, bottom: f64) -> FormatGradient {
+        if [left, right, top, bottom]
+            .iter()
+            .all(|value| (0.0..=1.0).contains(value))
+        {
+            self.is_path = true;
+            self.angle = 0;
+            self.left = left.to_string();
+            self.right = right.to_string();
+            self.top = top.to_string();
+            self.bottom = bottom.to_string();
+        }
+
+        self
+    }
+
+    /// Add a color stop to the gradient.
+    ///
+    /// The stops should be added in order of position. Stops with a position
+    /// outside the range 0.0 to 1.0, or with an invalid color, are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: The position of the stop in the range 0.0 to 1.0.
+    /// - `color`: The color of the stop defined by a [`Color`] enum value or
+    ///   a type that can convert [`Into`] a [`Color`].
+    ///
+    pub fn add_stop(mut self, position: f64, color: impl Into<Color>) -> FormatGradient {
+        let color = color.into();
+        if color.is_valid() && (0.0..=1.0).contains(&position) {
+            self.stops.push((position.to_string(), color));
+        }
+
+        self
+    }
+}
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
 /// The `FormatBorder` enum defines the Excel border types that can be added to
 /// a [`Format`] pattern.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FormatBorder {
     /// No border.
     #[default]
@@ -2967,6 +3295,8 @@ impl fmt::Display for FormatBorder {
 /// This is used with the [`Format::set_border_diagonal()`] method.
 ///
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FormatDiagonalBorder {
     /// The default/automatic format for an Excel font.
     #[default]
@@ -3026,6 +3356,8 @@ pub enum FormatDiagonalBorder {
 /// <img src="https://rustxlsxwriter.github.io/images/format_set_underline.png">
 ///
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FormatUnderline {
     /// The default/automatic underline for an Excel font.
     #[default]
@@ -3048,6 +3380,8 @@ pub enum FormatUnderline {
 /// properties.
 ///
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FormatScript {
     /// The default/automatic format for an Excel font.
     #[default]
@@ -3064,6 +3398,8 @@ pub enum FormatScript {
 /// The `FormatAlign` enum defines the vertical and horizontal alignment properties
 /// of a [`Format`].
 ///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FormatAlign {
     /// General/default alignment. The cell will use Excel's default for the
     /// data type, for example Left for text and Right for numbers.
@@ -3109,3 +3445,501 @@ pub enum FormatAlign {
     /// Distribute the words in the text evenly from top to bottom in the cell.
     VerticalDistributed,
 }
+
+// -----------------------------------------------------------------------
+// Serde support.
+// -----------------------------------------------------------------------
+
+// The serialized representation of a `Format`. The field names match the
+// `Format` setter methods and only non-default properties are serialized.
+#[cfg(feature = "serde")]
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FormatConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_format_index: Option<u8>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    bold: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    italic: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    underline: Option<FormatUnderline>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    hyperlink: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font_size: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font_color: Option<Color>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    font_strikethrough: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font_script: Option<FormatScript>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font_scheme: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font_family: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font_charset: Option<u8>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    align: Vec<FormatAlign>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    text_wrap: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    indent: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reading_direction: Option<u8>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    shrink: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pattern: Option<FormatPattern>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    background_color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foreground_color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gradient_fill: Option<FormatGradient>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border: Option<FormatBorder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_top: Option<FormatBorder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_top_color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_bottom: Option<FormatBorder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_bottom_color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_left: Option<FormatBorder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_left_color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_right: Option<FormatBorder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_right_color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_diagonal: Option<FormatBorder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_diagonal_color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_diagonal_type: Option<FormatDiagonalBorder>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    unlocked: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    hidden: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    quote_prefix: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    checkbox: bool,
+}
+
+// Return a property value if it differs from the default.
+#[cfg(feature = "serde")]
+fn non_default<T: PartialEq>(value: T, default: T) -> Option<T> {
+    if value == default {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<&Format> for FormatConfig {
+    fn from(format: &Format) -> FormatConfig {
+        let font = &format.font;
+        let alignment = &format.alignment;
+        let borders = &format.borders;
+        let fill = &format.fill;
+
+        // The font scheme is cleared by a non-default font name or a hyperlink.
+        let default_scheme = if font.name != "Calibri" || font.is_hyperlink {
+            ""
+        } else {
+            "minor"
+        };
+
+        // The hyperlink style has its own default font color and underline.
+        let (default_color, default_underline) = if font.is_hyperlink {
+            (Color::Theme(10, 0), FormatUnderline::Single)
+        } else {
+            (Color::Default, FormatUnderline::None)
+        };
+
+        // Convert the stored rotation back to the user angle.
+        let rotation = match alignment.rotation {
+            255 => 270,
+            91..=180 => 90 - alignment.rotation,
+            _ => alignment.rotation,
+        };
+
+        // Use the shorthand border properties if all sides are the same.
+        let sides = [
+            borders.top_style,
+            borders.bottom_style,
+            borders.left_style,
+            borders.right_style,
+        ];
+        let colors = [
+            borders.top_color,
+            borders.bottom_color,
+            borders.left_color,
+            borders.right_color,
+        ];
+        let same_sides = sides.iter().all(|side| *side == sides[0]);
+        let same_colors = colors.iter().all(|color| *color == colors[0]);
+        let side = |index: usize| non_default(sides[index], FormatBorder::None);
+        let color = |index: usize| non_default(colors[index], Color::Default);
+
+        let num_format_index = u8::try_from(format.num_format_index)
+            .ok()
+            .filter(|index| *index > 0);
+
+        FormatConfig {
+            num_format: if num_format_index.is_none() && !format.num_format.is_empty() {
+                Some(format.num_format.clone())
+            } else {
+                None
+            },
+            num_format_index,
+            bold: font.bold,
+            italic: font.italic,
+            underline: non_default(font.underline, default_underline),
+            hyperlink: font.is_hyperlink,
+            font_name: font.has_name.then(|| font.name.clone()),
+            font_size: non_default(font.size.as_str(), "11").and_then(|size| size.parse().ok()),
+            font_color: non_default(font.color, default_color),
+            font_strikethrough: font.strikethrough,
+            font_script: non_default(font.script, FormatScript::None),
+            font_scheme: non_default(font.scheme.as_str(), default_scheme).map(str::to_string),
+            font_family: non_default(font.family, 2),
+            font_charset: non_default(font.charset, 0),
+            align: [alignment.horizontal, alignment.vertical]
+                .into_iter()
+                .filter(|align| *align != FormatAlign::General)
+                .collect(),
+            text_wrap: alignment.text_wrap,
+            indent: non_default(alignment.indent, 0),
+            rotation: non_default(rotation, 0),
+            reading_direction: non_default(alignment.reading_direction, 0),
+            shrink: alignment.shrink,
+            pattern: non_default(fill.pattern, FormatPattern::None),
+            background_color: non_default(fill.background_color, Color::Default),
+            foreground_color: non_default(fill.foreground_color, Color::Default),
+            gradient_fill: fill.gradient.clone(),
+            border: if same_sides { side(0) } else { None },
+            border_color: if same_colors { color(0) } else { None },
+            border_top: if same_sides { None } else { side(0) },
+            border_top_color: if same_colors { None } else { color(0) },
+            border_bottom: if same_sides { None } else { side(1) },
+            border_bottom_color: if same_colors { None } else { color(1) },
+            border_left: if same_sides { None } else { side(2) },
+            border_left_color: if same_colors { None } else { color(2) },
+            border_right: if same_sides { None } else { side(3) },
+            border_right_color: if same_colors { None } else { color(3) },
+            border_diagonal: non_default(borders.diagonal_style, FormatBorder::None),
+            border_diagonal_color: non_default(borders.diagonal_color, Color::Default),
+            border_diagonal_type: non_default(borders.diagonal_type, FormatDiagonalBorder::None),
+            unlocked: !format.locked,
+            hidden: format.hidden,
+            quote_prefix: format.quote_prefix,
+            checkbox: format.checkbox,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<FormatConfig> for Format {
+    type Error = String;
+
+    fn try_from(config: FormatConfig) -> Result<Format, String> {
+        // Reject the values that the setter methods would ignore.
+        if let Some(font_size) = config.font_size {
+            if !(1.0..=409.0).contains(&font_size) {
+                return Err(format!(
+                    "font_size '{font_size}' must be in the range 1-409"
+                ));
+            }
+        }
+        if let Some(rotation) = config.rotation {
+            if !(-90..=90).contains(&rotation) && rotation != 270 {
+                return Err(format!(
+                    "rotation '{rotation}' must be in the range -90 to 90, or 270"
+                ));
+            }
+        }
+        if let Some(reading_direction) = config.reading_direction {
+            if reading_direction > 2 {
+                return Err(format!(
+                    "reading_direction '{reading_direction}' must be 0, 1 or 2"
+                ));
+            }
+        }
+
+        let mut format = Format::new();
+
+        // Number properties.
+        if let Some(num_format_index) = config.num_format_index {
+            format = format.set_num_format_index(num_format_index);
+        }
+        if let Some(num_format) = config.num_format {
+            format = format.set_num_format(num_format);
+        }
+
+        // Font properties. The hyperlink and font name are set first since
+        // they change the font scheme and other defaults.
+        if config.hyperlink {
+            format = format.set_hyperlink();
+        }
+        if let Some(font_name) = config.font_name {
+            format = format.set_font_name(font_name);
+        }
+        if let Some(font_scheme) = config.font_scheme {
+            format = format.set_font_scheme(font_scheme);
+        }
+        if let Some(font_size) = config.font_size {
+            format = format.set_font_size(font_size);
+        }
+        if let Some(color) = config.font_color {
+            format = format.set_font_color(color);
+        }
+        if let Some(underline) = config.underline {
+            format = format.set_underline(underline);
+        }
+        if let Some(font_script) = config.font_script {
+            format = format.set_font_script(font_script);
+        }
+        if let Some(font_family) = config.font_family {
+            format = format.set_font_family(font_family);
+        }
+        if let Some(font_charset) = config.font_charset {
+            format = format.set_font_charset(font_charset);
+        }
+        if config.bold {
+            format = format.set_bold();
+        }
+        if config.italic {
+            format = format.set_italic();
+        }
+        if config.font_strikethrough {
+            format = format.set_font_strikethrough();
+        }
+
+        // Alignment properties.
+        for align in config.align {
+            format = format.set_align(align);
+        }
+        if config.text_wrap {
+            format = format.set_text_wrap();
+        }
+        if let Some(indent) = config.indent {
+            format = format.set_indent(indent);
+        }
+        if let Some(rotation) = config.rotation {
+            format = format.set_rotation(rotation);
+        }
+        if let Some(reading_direction) = config.reading_direction {
+            format = format.set_reading_direction(reading_direction);
+        }
+        if config.shrink {
+            format = format.set_shrink();
+        }
+
+        // Fill properties. The gradient fill is set first since it resets the
+        // other fill properties.
+        if let Some(gradient) = config.gradient_fill {
+            format = format.set_gradient_fill(&gradient);
+        }
+        if let Some(pattern) = config.pattern {
+            format = format.set_pattern(pattern);
+        }
+        if let Some(color) = config.background_color {
+            format = format.set_background_color(color);
+        }
+        if let Some(color) = config.foreground_color {
+            format = format.set_foreground_color(color);
+        }
+
+        // Border properties. The shorthand properties are set first so that
+        // they can be overridden for individual sides.
+        if let Some(border) = config.border {
+            format = format.set_border(border);
+        }
+        if let Some(color) = config.border_color {
+            format = format.set_border_color(color);
+        }
+        if let Some(border) = config.border_top {
+            format = format.set_border_top(border);
+        }
+        if let Some(color) = config.border_top_color {
+            format = format.set_border_top_color(color);
+        }
+        if let Some(border) = config.border_bottom {
+            format = format.set_border_bottom(border);
+        }
+        if let Some(color) = config.border_bottom_color {
+            format = format.set_border_bottom_color(color);
+        }
+        if let Some(border) = config.border_left {
+            format = format.set_border_left(border);
+        }
+        if let Some(color) = config.border_left_color {
+            format = format.set_border_left_color(color);
+        }
+        if let Some(border) = config.border_right {
+            format = format.set_border_right(border);
+        }
+        if let Some(color) = config.border_right_color {
+            format = format.set_border_right_color(color);
+        }
+        if let Some(border) = config.border_diagonal {
+            format = format.set_border_diagonal(border);
+        }
+        if let Some(color) = config.border_diagonal_color {
+            format = format.set_border_diagonal_color(color);
+        }
+        if let Some(border_type) = config.border_diagonal_type {
+            format = format.set_border_diagonal_type(border_type);
+        }
+
+        // Protection and non-UI properties.
+        if config.unlocked {
+            format = format.set_unlocked();
+        }
+        if config.hidden {
+            format = format.set_hidden();
+        }
+        if config.quote_prefix {
+            format = format.set_quote_prefix();
+        }
+        if config.checkbox {
+            format = format.set_checkbox();
+        }
+
+        Ok(format)
+    }
+}
+
+/// Implementation of the `serde::Serialize` trait for `Format`.
+///
+/// Only the properties that differ from the default format are serialized.
+/// See [Serde support](Format#serde-support) for details of the schema.
+///
+#[cfg(feature = "serde")]
+impl Serialize for Format {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        FormatConfig::from(self).serialize(serializer)
+    }
+}
+
+/// Implementation of the `serde::Deserialize` trait for `Format`.
+///
+/// Missing properties have their default values. Unknown properties and
+/// values that are outside the range allowed by the setter methods, such as a
+/// `rotation` of 500 or a `font_size` of 0, are an error. See [Serde
+/// support](Format#serde-support) for details of the schema.
+///
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Format {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let config = FormatConfig::deserialize(deserializer)?;
+
+        Format::try_from(config).map_err(de::Error::custom)
+    }
+}
+
+// The serialized representation of a `FormatGradient`.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FormatGradientConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    angle: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    path: Option<[f64; 4]>,
+    stops: Vec<FormatGradientStopConfig>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FormatGradientStopConfig {
+    position: f64,
+    color: Color,
+}
+
+/// Implementation of the `serde::Serialize` trait for `FormatGradient`.
+///
+/// A gradient is serialized with an optional `angle`, an optional `path` of
+/// `[left, right, top, bottom]` values, and a list of `stops` with a
+/// `position` and `color`.
+///
+#[cfg(feature = "serde")]
+impl Serialize for FormatGradient {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = |value: &str| value.parse::<f64>().unwrap_or_default();
+
+        let config = FormatGradientConfig {
+            angle: non_default(self.angle, 0),
+            path: if self.is_path {
+                Some([
+                    value(&self.left),
+                    value(&self.right),
+                    value(&self.top),
+                    value(&self.bottom),
+                ])
+            } else {
+                None
+            },
+            stops: self
+                .stops
+                .iter()
+                .map(|(position, color)| FormatGradientStopConfig {
+                    position: value(position),
+                    color: *color,
+                })
+                .collect(),
+        };
+
+        config.serialize(serializer)
+    }
+}
+
+/// Implementation of the `serde::Deserialize` trait for `FormatGradient`.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for FormatGradient {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let config = FormatGradientConfig::deserialize(deserializer)?;
+        let mut gradient = FormatGradient::new();
+
+        if let Some(angle) = config.angle {
+            gradient = gradient.set_angle(angle);
+        }
+        if let Some([left, right, top, bottom]) = config.path {
+            gradient = gradient.set_path(left, right, top, bottom);
+        }
+        for stop in config.stops {
+            gradient = gradient.add_stop(stop.position, stop.color);
+        }
+
+        Ok(gradient)
+    }
+}
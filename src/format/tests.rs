@@ -8,8 +8,8 @@
 mod format_tests {
 
     use crate::{
-        Color, Format, FormatAlign, FormatBorder, FormatDiagonalBorder, FormatPattern,
-        FormatUnderline,
+        Color, Format, FormatAlign, FormatBorder, FormatDiagonalBorder, FormatGradient,
+        FormatPattern, FormatUnderline,
     };
     use pretty_assertions::assert_eq;
 
@@ -85,6 +85,78 @@ mod format_tests {
         assert_eq!(merged, has_value);
     }
 
+    #[test]
+    fn test_merge_gradient_fill() {
+        let default = Format::new();
+        let gradient = FormatGradient::new()
+            .add_stop(0.0, Color::White)
+            .add_stop(1.0, Color::Blue);
+        let has_value = Format::new().set_gradient_fill(&gradient);
+
+        // Test an overwriting merge.
+        let merged = default.merge(&has_value);
+        assert_eq!(merged, has_value);
+
+        // Test a non-overwriting merge.
+        let merged = has_value.merge(&default);
+        assert_eq!(merged, has_value);
+
+        // A pattern fill isn't replaced by a merged gradient fill, and vice
+        // versa.
+        let pattern = Format::new().set_background_color(Color::Red);
+        let merged = pattern.merge(&has_value);
+        assert_eq!(merged, pattern);
+
+        let pattern = Format::new().set_pattern(FormatPattern::Gray125);
+        let merged = pattern.merge(&has_value);
+        assert_eq!(merged, pattern);
+
+        let merged = has_value.merge(&pattern);
+        assert_eq!(merged, has_value);
+    }
+
+    #[test]
+    fn test_gradient_fill_validation() {
+        // Invalid stops are ignored.
+        let gradient = FormatGradient::new()
+            .add_stop(-0.1, Color::White)
+            .add_stop(0.0, Color::White)
+            .add_stop(1.5, Color::Blue);
+        assert_eq!(gradient.stops.len(), 1);
+
+        // A gradient with less than 2 stops is ignored.
+        let format = Format::new()
+            .set_background_color(Color::Red)
+            .set_gradient_fill(&gradient);
+        assert_eq!(format, Format::new().set_background_color(Color::Red));
+
+        // A valid gradient replaces the pattern fill.
+        let gradient = gradient.add_stop(1.0, Color::Blue);
+        let format = Format::new()
+            .set_background_color(Color::Red)
+            .set_gradient_fill(&gradient);
+        assert_eq!(format.fill.background_color, Color::Default);
+        assert_eq!(format.fill.gradient, Some(gradient.clone()));
+
+        // A pattern fill replaces the gradient fill.
+        let format = Format::new()
+            .set_gradient_fill(&gradient)
+            .set_background_color(Color::Red);
+        assert_eq!(format, Format::new().set_background_color(Color::Red));
+
+        let format = Format::new()
+            .set_gradient_fill(&gradient)
+            .set_pattern(FormatPattern::Solid);
+        assert_eq!(format, Format::new().set_pattern(FormatPattern::Solid));
+
+        // Out of range angles and path values are ignored.
+        let gradient = FormatGradient::new().set_angle(360);
+        assert_eq!(gradient.angle, 0);
+
+        let gradient = FormatGradient::new().set_path(0.5, 0.5, 1.1, 0.5);
+        assert!(!gradient.is_path);
+    }
+
     #[test]
     fn test_merge_font() {
         let default = Format::new();
@@ -173,4 +245,109 @@ mod format_tests {
         let merged = has_value.merge(&default);
         assert_eq!(merged, has_value);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        // An empty config is the default format.
+        let format: Format = serde_json::from_str("{}").unwrap();
+        assert_eq!(Format::default(), format);
+        assert_eq!("{}", serde_json::to_string(&Format::default()).unwrap());
+
+        let json = r##"{
+            "num_format": "0.00",
+            "bold": true,
+            "font_name": "Arial",
+            "font_size": 12.5,
+            "font_color": "theme(4, 1)",
+            "underline": "single_accounting",
+            "align": ["center", "vertical_center"],
+            "rotation": -45,
+            "pattern": "light_grid",
+            "background_color": "red",
+            "border": "thin",
+            "border_top": "double",
+            "border_color": "#00FF00",
+            "unlocked": true
+        }"##;
+
+        let expected = Format::new()
+            .set_num_format("0.00")
+            .set_bold()
+            .set_font_name("Arial")
+            .set_font_size(12.5)
+            .set_font_color(Color::Theme(4, 1))
+            .set_underline(FormatUnderline::SingleAccounting)
+            .set_align(FormatAlign::Center)
+            .set_align(FormatAlign::VerticalCenter)
+            .set_rotation(-45)
+            .set_pattern(FormatPattern::LightGrid)
+            .set_background_color(Color::Red)
+            .set_border(FormatBorder::Thin)
+            .set_border_top(FormatBorder::Double)
+            .set_border_color("#00FF00")
+            .set_unlocked();
+
+        let format: Format = serde_json::from_str(json).unwrap();
+        assert_eq!(expected, format);
+
+        // Unknown properties are an error.
+        assert!(serde_json::from_str::<Format>(r#"{"blod": true}"#).is_err());
+
+        // Values that the setter methods would ignore are an error.
+        for json in [
+            r#"{"rotation": 500}"#,
+            r#"{"font_size": 0}"#,
+            r#"{"font_size": 410}"#,
+            r#"{"reading_direction": 3}"#,
+        ] {
+            assert!(serde_json::from_str::<Format>(json).is_err());
+        }
+
+        let format: Format = serde_json::from_str(r#"{"rotation": 270}"#).unwrap();
+        assert_eq!(Format::new().set_rotation(270), format);
+
+        // Test round trips.
+        let formats = [
+            expected,
+            Format::new().set_hyperlink(),
+            Format::new().set_num_format_index(14).set_rotation(270),
+            Format::new()
+                .set_font_scheme("major")
+                .set_font_family(3)
+                .set_font_charset(1)
+                .set_font_script(crate::FormatScript::Superscript)
+                .set_indent(2)
+                .set_reading_direction(2)
+                .set_text_wrap()
+                .set_shrink()
+                .set_hidden()
+                .set_quote_prefix()
+                .set_checkbox(),
+            Format::new()
+                .set_border_diagonal(FormatBorder::Dashed)
+                .set_border_diagonal_color(Color::Blue)
+                .set_border_diagonal_type(FormatDiagonalBorder::BorderUpDown)
+                .set_border_left(FormatBorder::Thick)
+                .set_border_right_color(Color::ThemeTint(5, 0.4)),
+            Format::new().set_gradient_fill(
+                &FormatGradient::new()
+                    .set_path(0.5, 0.5, 0.5, 0.5)
+                    .add_stop(0.0, Color::White)
+                    .add_stop(1.0, "#4F81BD"),
+            ),
+            Format::new().set_gradient_fill(
+                &FormatGradient::new()
+                    .set_angle(90)
+                    .add_stop(0.0, Color::Theme(4, 0))
+                    .add_stop(0.5, Color::White)
+                    .add_stop(1.0, Color::Theme(4, 0)),
+            ),
+        ];
+
+        for format in formats {
+            let json = serde_json::to_string(&format).unwrap();
+            assert_eq!(format, serde_json::from_str::<Format>(&json).unwrap());
+        }
+    }
 }
@@ -10,6 +10,8 @@ mod tests;
 
 use std::{collections::HashMap, sync::OnceLock};
 
+use crate::ExcelError;
+
 /// The `Formula` struct is used to define a worksheet formula.
 ///
 /// The `Formula` struct creates a formula type that can be used to write
@@ -790,7 +792,11 @@ impl Formula {
     ///
     /// # Parameters
     ///
-    /// `result` - The formula result, as a string or string like type.
+    /// `result` - The formula result, as a string or string like type. An
+    /// [`ExcelError`] value such as `#N/A` can also be used to set an error
+    /// result. The dynamic array errors `#SPILL!` and `#CALC!` aren't
+    /// supported as formula results and are ignored with a warning. See
+    /// [`IntoFormulaResult`].
     ///
     /// # Examples
     ///
@@ -829,8 +835,10 @@ impl Formula {
     /// <img
     /// src="https://rustxlsxwriter.github.io/images/worksheet_set_formula_result.png">
     ///
-    pub fn set_result(mut self, result: impl Into<String>) -> Formula {
-        self.result = Box::from(result.into());
+    pub fn set_result(mut self, result: impl IntoFormulaResult) -> Formula {
+        if let Some(result) = result.into_formula_result() {
+            self.result = Box::from(result);
+        }
         self
     }
 
@@ -1191,3 +1199,41 @@ impl From<&Formula> for Formula {
         (*value).clone()
     }
 }
+
+/// Trait to map different Rust types into formula results.
+///
+/// The trait is implemented for string like types and for [`ExcelError`]
+/// values. It is used by [`Formula::set_result()`] and
+/// [`Worksheet::set_formula_result()`](crate::Worksheet::set_formula_result).
+///
+/// An [`ExcelError`] result is written as an Excel error value. Only the
+/// classic errors such as `#N/A` or `#DIV/0!` are supported. The dynamic
+/// array errors `#SPILL!` and `#CALC!` require additional rich value data in
+/// the file when they are formula results so they are ignored with a
+/// warning.
+///
+pub trait IntoFormulaResult {
+    /// Types/objects supporting this trait must be able to convert to a
+    /// formula result string, or `None` if they aren't a valid result.
+    fn into_formula_result(self) -> Option<String>;
+}
+
+impl<T> IntoFormulaResult for T
+where
+    T: Into<String>,
+{
+    fn into_formula_result(self) -> Option<String> {
+        Some(self.into())
+    }
+}
+
+impl IntoFormulaResult for ExcelError {
+    fn into_formula_result(self) -> Option<String> {
+        if self.value_metadata_id().is_some() {
+            eprintln!("Excel error '{self}' isn't supported as a formula result. Ignoring.");
+            return None;
+        }
+
+        Some(self.to_string())
+    }
+}
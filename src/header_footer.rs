@@ -0,0 +1,463 @@
+// header_footer - A module for building worksheet header and footer strings.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::fmt::Write;
+
+use crate::format::Font;
+use crate::{Color, FormatUnderline, XlsxError};
+
+/// The `HeaderFooter` struct is used to build a worksheet header or footer
+/// string.
+///
+/// Excel headers and footers are defined by strings with embedded control
+/// characters such as `&L&"Arial,Bold"&P of &N`, which are described in the
+/// documentation for [`Worksheet::set_header()`](crate::Worksheet::set_header).
+/// `HeaderFooter` is a builder that creates these strings from the text and
+/// fields in each of the left, center and right sections of the header or
+/// footer, with optional font properties for each piece of text.
+///
+/// The resulting string is generated by [`HeaderFooter::build()`] and can be
+/// used with [`Worksheet::set_header()`](crate::Worksheet::set_header),
+/// [`Worksheet::set_footer()`](crate::Worksheet::set_footer) and the
+/// equivalent first and even page methods.
+///
+/// # Examples
+///
+/// The following example demonstrates creating a worksheet header and footer
+/// with the `HeaderFooter` builder.
+///
+/// ```
+/// # // This code is available in examples/doc_header_footer.rs
+/// #
+/// # use rust_xlsxwriter::{
+/// #     HeaderFooter, HeaderFooterField, HeaderFooterText, Workbook, XlsxError,
+/// # };
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     // Create a header with a bold title in the center section and the
+///     // sheet name on the right.
+///     let header = HeaderFooter::new()
+///         .add_center(
+///             HeaderFooterText::new("Sales & Marketing")
+///                 .set_bold()
+///                 .set_font_size(14),
+///         )
+///         .add_right(HeaderFooterField::SheetName);
+///
+///     // Create a "Page N of M" footer.
+///     let footer = HeaderFooter::new()
+///         .add_center("Page ")
+///         .add_center(HeaderFooterField::Page)
+///         .add_center(" of ")
+///         .add_center(HeaderFooterField::Pages);
+///
+///     worksheet.set_header(header.build()?);
+///     worksheet.set_footer(footer.build()?);
+/// #
+/// #     worksheet.write_string(0, 0, "Hello")?;
+/// #     worksheet.set_view_page_layout();
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HeaderFooter {
+    left: Vec<HeaderFooterText>,
+    center: Vec<HeaderFooterText>,
+    right: Vec<HeaderFooterText>,
+}
+
+impl HeaderFooter {
+    /// Create a new `HeaderFooter` object.
+    ///
+    pub fn new() -> HeaderFooter {
+        HeaderFooter::default()
+    }
+
+    /// Add text or a field to the left section of the header/footer.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: A [`HeaderFooterText`] object or a type that can convert
+    ///   [`Into`] a [`HeaderFooterText`] such as a string or a
+    ///   [`HeaderFooterField`].
+    ///
+    pub fn add_left(mut self, text: impl Into<HeaderFooterText>) -> HeaderFooter {
+        self.left.push(text.into());
+        self
+    }
+
+    /// Add text or a field to the center section of the header/footer.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: A [`HeaderFooterText`] object or a type that can convert
+    ///   [`Into`] a [`HeaderFooterText`] such as a string or a
+    ///   [`HeaderFooterField`].
+    ///
+    pub fn add_center(mut self, text: impl Into<HeaderFooterText>) -> HeaderFooter {
+        self.center.push(text.into());
+        self
+    }
+
+    /// Add text or a field to the right section of the header/footer.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: A [`HeaderFooterText`] object or a type that can convert
+    ///   [`Into`] a [`HeaderFooterText`] such as a string or a
+    ///   [`HeaderFooterField`].
+    ///
+    pub fn add_right(mut self, text: impl Into<HeaderFooterText>) -> HeaderFooter {
+        self.right.push(text.into());
+        self
+    }
+
+    /// Build the header/footer string.
+    ///
+    /// Convert the sections of the header/footer into a string with Excel
+    /// control characters that can be used with
+    /// [`Worksheet::set_header()`](crate::Worksheet::set_header) and
+    /// [`Worksheet::set_footer()`](crate::Worksheet::set_footer). Literal `&`
+    /// characters in the text are escaped as `&&` so text such as `&[Page]` is
+    /// displayed as it is written and isn't converted to a field.
+    ///
+    /// Within a section the font properties only apply to the text they are
+    /// set for. Text without an explicit font size uses the size of the
+    /// default cell font, 11.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The header/footer string exceeds
+    ///   Excel's limit of 255 characters.
+    ///
+    pub fn build(&self) -> Result<String, XlsxError> {
+        let mut string = String::new();
+        let sections: Vec<(&str, &Vec<HeaderFooterText>)> = [
+            ("&L", &self.left),
+            ("&C", &self.center),
+            ("&R", &self.right),
+        ]
+        .into_iter()
+        .filter(|(_, section)| !section.is_empty())
+        .collect();
+
+        for (index, (code, section)) in sections.iter().enumerate() {
+            string.push_str(code);
+
+            // Write each text run with the codes for the changes in the font
+            // properties from the previous run.
+            let mut previous = &HeaderFooterText::default();
+            for text in *section {
+                Self::write_font_changes(&mut string, previous, text);
+                string.push_str(&text.content());
+                previous = text;
+            }
+
+            // Reset the font properties before the next section.
+            if index + 1 < sections.len() {
+                Self::write_font_changes(&mut string, previous, &HeaderFooterText::default());
+            }
+        }
+
+        if string.chars().count() > 255 {
+            return Err(XlsxError::ParameterError(format!(
+                "Header/footer string exceeds Excel's limit of 255 characters: '{string}'"
+            )));
+        }
+
+        Ok(string)
+    }
+
+    // Write the control codes for the font properties that change between two
+    // text runs.
+    fn write_font_changes(
+        string: &mut String,
+        previous: &HeaderFooterText,
+        text: &HeaderFooterText,
+    ) {
+        let mut size_is_last = false;
+
+        if text.font_size != previous.font_size {
+            match text.font_size {
+                Some(font_size) => {
+                    let _ = write!(string, "&{font_size}");
+                }
+                // Revert to the size of the default cell font, which Excel
+                // also uses for headers and footers.
+                None => {
+                    let _ = write!(string, "&{}", Font::default().size);
+                }
+            }
+            size_is_last = true;
+        }
+
+        if text.font_name != previous.font_name
+            || text.bold != previous.bold
+            || text.italic != previous.italic
+        {
+            string.push_str(&text.font_code());
+            size_is_last = false;
+        }
+
+        if text.font_color != previous.font_color {
+            match text.font_color {
+                Some(color) => {
+                    let _ = write!(string, "&K{}", color.header_footer_value());
+                }
+                None => string.push_str("&K01+000"),
+            }
+            size_is_last = false;
+        }
+
+        if text.underline_code() != previous.underline_code() {
+            // The underline codes toggle the property on and off.
+            string.push_str(previous.underline_code());
+            string.push_str(text.underline_code());
+            size_is_last = false;
+        }
+
+        // A font size code followed by a digit would be read as a larger size
+        // so the unchanged font code is added as a separator.
+        if size_is_last && text.content().starts_with(|c: char| c.is_ascii_digit()) {
+            string.push_str(&text.font_code());
+        }
+    }
+}
+
+/// The `HeaderFooterText` struct represents a piece of text or a field in a
+/// [`HeaderFooter`] section along with its font properties.
+///
+/// Plain strings and [`HeaderFooterField`] values can be converted directly
+/// into a `HeaderFooterText` without any font properties. See
+/// [`HeaderFooter`] for an example.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HeaderFooterText {
+    text: String,
+    field: Option<HeaderFooterField>,
+    font_name: Option<String>,
+    font_size: Option<u16>,
+    font_color: Option<Color>,
+    bold: bool,
+    italic: bool,
+    underline: FormatUnderline,
+}
+
+impl HeaderFooterText {
+    /// Create a new `HeaderFooterText` object from a text string.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The text string. Any `&` characters are escaped when the
+    ///   header/footer string is built.
+    ///
+    pub fn new(text: impl Into<String>) -> HeaderFooterText {
+        HeaderFooterText {
+            text: text.into(),
+            ..HeaderFooterText::default()
+        }
+    }
+
+    /// Create a new `HeaderFooterText` object from a field such as the page
+    /// number.
+    ///
+    /// # Parameters
+    ///
+    /// - `field`: A [`HeaderFooterField`] enum value.
+    ///
+    pub fn new_field(field: HeaderFooterField) -> HeaderFooterText {
+        HeaderFooterText {
+            field: Some(field),
+            ..HeaderFooterText::default()
+        }
+    }
+
+    /// Set the font name of the text.
+    ///
+    /// # Parameters
+    ///
+    /// - `font_name`: The font name, for example "Arial".
+    ///
+    pub fn set_font_name(mut self, font_name: impl Into<String>) -> HeaderFooterText {
+        self.font_name = Some(font_name.into());
+        self
+    }
+
+    /// Set the font size of the text.
+    ///
+    /// # Parameters
+    ///
+    /// - `font_size`: The font size in points, in the Excel range 1-409.
+    ///   Values outside this range are ignored.
+    ///
+    pub fn set_font_size(mut self, font_size: u16) -> HeaderFooterText {
+        if !(1..=409).contains(&font_size) {
+            eprintln!("Header/footer font size must be in the Excel range 1-409: {font_size}");
+            return self;
+        }
+
+        self.font_size = Some(font_size);
+        self
+    }
+
+    /// Set the font color of the text.
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: The font color property defined by a [`Color`] enum value or
+    ///   a type that can convert [`Into`] a [`Color`].
+    ///
+    pub fn set_font_color(mut self, color: impl Into<Color>) -> HeaderFooterText {
+        let color = color.into();
+
+        // Check that the color is valid.
+        if !color.is_valid() {
+            return self;
+        }
+
+        if color.is_auto_or_default() {
+            self.font_color = None;
+        } else {
+            self.font_color = Some(color);
+        }
+
+        self
+    }
+
+    /// Set the text to bold.
+    ///
+    pub fn set_bold(mut self) -> HeaderFooterText {
+        self.bold = true;
+        self
+    }
+
+    /// Set the text to italic.
+    ///
+    pub fn set_italic(mut self) -> HeaderFooterText {
+        self.italic = true;
+        self
+    }
+
+    /// Set the underline style of the text.
+    ///
+    /// # Parameters
+    ///
+    /// - `underline`: A [`FormatUnderline`] enum value. The accounting styles
+    ///   are treated as the equivalent single and double underlines.
+    ///
+    pub fn set_underline(mut self, underline: FormatUnderline) -> HeaderFooterText {
+        self.underline = underline;
+        self
+    }
+
+    // Get the escaped text or field code.
+    fn content(&self) -> String {
+        match self.field {
+            Some(field) => field.code().to_string(),
+            None => self.text.replace('&', "&&"),
+        }
+    }
+
+    // Get the font name and style code, like `&"Arial,Bold"`.
+    fn font_code(&self) -> String {
+        let font_name = self.font_name.as_deref().unwrap_or("-");
+
+        let style = match (self.bold, self.italic) {
+            (false, false) => "Regular",
+            (true, false) => "Bold",
+            (false, true) => "Italic",
+            (true, true) => "Bold Italic",
+        };
+
+        format!("&\"{font_name},{style}\"")
+    }
+
+    // Get the underline code.
+    fn underline_code(&self) -> &'static str {
+        match self.underline {
+            FormatUnderline::None => "",
+            FormatUnderline::Single | FormatUnderline::SingleAccounting => "&U",
+            FormatUnderline::Double | FormatUnderline::DoubleAccounting => "&E",
+        }
+    }
+}
+
+impl From<&str> for HeaderFooterText {
+    fn from(text: &str) -> HeaderFooterText {
+        HeaderFooterText::new(text)
+    }
+}
+
+impl From<String> for HeaderFooterText {
+    fn from(text: String) -> HeaderFooterText {
+        HeaderFooterText::new(text)
+    }
+}
+
+impl From<HeaderFooterField> for HeaderFooterText {
+    fn from(field: HeaderFooterField) -> HeaderFooterText {
+        HeaderFooterText::new_field(field)
+    }
+}
+
+/// The `HeaderFooterField` enum defines the fields that can be inserted into
+/// a [`HeaderFooter`] section.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderFooterField {
+    /// The current page number.
+    Page,
+
+    /// The total number of pages.
+    Pages,
+
+    /// The current date.
+    Date,
+
+    /// The current time.
+    Time,
+
+    /// The workbook file name.
+    File,
+
+    /// The workbook file path.
+    Path,
+
+    /// The worksheet name.
+    SheetName,
+
+    /// A picture. This requires a corresponding image to be added with
+    /// [`Worksheet::set_header_image()`](crate::Worksheet::set_header_image) or
+    /// a similar method.
+    Picture,
+}
+
+impl HeaderFooterField {
+    // Get the Excel control code for the field.
+    fn code(self) -> &'static str {
+        match self {
+            HeaderFooterField::Page => "&P",
+            HeaderFooterField::Pages => "&N",
+            HeaderFooterField::Date => "&D",
+            HeaderFooterField::Time => "&T",
+            HeaderFooterField::File => "&F",
+            HeaderFooterField::Path => "&Z",
+            HeaderFooterField::SheetName => "&A",
+            HeaderFooterField::Picture => "&G",
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Header/footer unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod header_footer_tests {
+
+    use crate::{
+        Color, FormatUnderline, HeaderFooter, HeaderFooterField, HeaderFooterText, XlsxError,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_build() {
+        let tests = [
+            (HeaderFooter::new(), ""),
+            (HeaderFooter::new().add_center("Hello"), "&CHello"),
+            (
+                HeaderFooter::new().add_left("Cats & Dogs"),
+                "&LCats && Dogs",
+            ),
+            (
+                HeaderFooter::new()
+                    .add_center("Page ")
+                    .add_center(HeaderFooterField::Page)
+                    .add_center(" of ")
+                    .add_center(HeaderFooterField::Pages),
+                "&CPage &P of &N",
+            ),
+            (
+                HeaderFooter::new()
+                    .add_left(HeaderFooterField::SheetName)
+                    .add_center(HeaderFooterField::Date)
+                    .add_center(HeaderFooterField::Time)
+                    .add_right(HeaderFooterField::File)
+                    .add_right(HeaderFooterField::Path)
+                    .add_right(HeaderFooterField::Picture),
+                "&L&A&C&D&T&R&F&Z&G",
+            ),
+            (
+                HeaderFooter::new()
+                    .add_center(HeaderFooterText::new("Title").set_bold())
+                    .add_center(" text"),
+                r#"&C&"-,Bold"Title&"-,Regular" text"#,
+            ),
+            (
+                HeaderFooter::new()
+                    .add_left(
+                        HeaderFooterText::new("Note")
+                            .set_font_name("Arial")
+                            .set_italic()
+                            .set_font_color(Color::Red)
+                            .set_underline(FormatUnderline::Double),
+                    )
+                    .add_right("End"),
+                r#"&L&"Arial,Italic"&KFF0000&ENote&"-,Regular"&K01+000&E&REnd"#,
+            ),
+            (
+                HeaderFooter::new()
+                    .add_center(HeaderFooterText::new("2025").set_font_size(20))
+                    .add_center(
+                        HeaderFooterText::new("Report").set_underline(FormatUnderline::Single),
+                    ),
+                r#"&C&20&"-,Regular"2025&11&UReport"#,
+            ),
+            (
+                HeaderFooter::new()
+                    .add_left(HeaderFooterText::new("Red").set_font_color(Color::RGB(0xC00000)))
+                    .add_center(HeaderFooterText::new("Blue").set_font_color(Color::Theme(4, 0)))
+                    .add_right(HeaderFooterText::new("Dark").set_font_color(Color::Theme(9, 5))),
+                "&L&KC00000Red&K01+000&C&K04+000Blue&K01+000&R&K09-050Dark",
+            ),
+            (
+                HeaderFooter::new()
+                    .add_center(HeaderFooterText::new("A").set_font_color(Color::Theme(0, 1)))
+                    .add_center(
+                        HeaderFooterText::new("B").set_font_color(Color::ThemeTint(5, 0.4)),
+                    ),
+                "&C&K00-005A&K05+040B",
+            ),
+            (HeaderFooter::new().add_center("&[Page]"), "&C&&[Page]"),
+            (
+                HeaderFooter::new()
+                    .add_center(HeaderFooterText::new("Big").set_font_size(410))
+                    .add_center(HeaderFooterText::new("Small").set_font_size(0)),
+                "&CBigSmall",
+            ),
+        ];
+
+        for (header_footer, expected) in tests {
+            assert_eq!(expected, header_footer.build().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_build_length() {
+        let header_footer = HeaderFooter::new().add_center("&".repeat(126));
+        assert!(header_footer.build().is_ok());
+
+        let header_footer = HeaderFooter::new().add_center("&".repeat(127));
+        let result = header_footer.build();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+}
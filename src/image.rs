@@ -68,6 +68,7 @@ pub struct Image {
     pub(crate) vml_name: String,
     pub(crate) header_position: HeaderImagePosition,
     pub(crate) object_movement: ObjectMovement,
+    pub(crate) absolute_position: Option<(u32, u32)>,
     pub(crate) is_header: bool,
     pub(crate) decorative: bool,
     pub(crate) hash: String,
@@ -259,6 +260,7 @@ impl Image {
             vml_name: "image".to_string(),
             header_position: HeaderImagePosition::Center,
             object_movement: ObjectMovement::MoveButDontSizeWithCells,
+            absolute_position: None,
             is_header: true,
             decorative: false,
             hash: String::new(),
@@ -684,6 +686,10 @@ impl Image {
     /// to be hidden in rows or columns. In Excel this equates to option 1 above
     /// but the internal image position calculations are handled differently.
     ///
+    /// For pixel-exact layouts the [`ObjectMovement::AbsoluteAnchor`] option
+    /// positions the image at a fixed location in the worksheet rather than
+    /// relative to a cell. See also [`Image::set_absolute_position()`].
+    ///
     /// # Parameters
     ///
     /// - `option`: An image/object positioning behavior defined by the
@@ -730,6 +736,58 @@ impl Image {
         self
     }
 
+    /// Set the absolute position of the image in the worksheet.
+    ///
+    /// Position the image at fixed `x` and `y` pixel coordinates from the top
+    /// left corner of the worksheet instead of relative to the cell that it is
+    /// inserted in. The row, column and any offsets used to insert the image
+    /// are ignored.
+    ///
+    /// This also sets the object movement to [`ObjectMovement::AbsoluteAnchor`]
+    /// so that Excel doesn't move or resize the image when the cells
+    /// underneath it change.
+    ///
+    /// # Parameters
+    ///
+    /// - `x`: The horizontal position from the left of the worksheet in pixels.
+    /// - `y`: The vertical position from the top of the worksheet in pixels.
+    ///
+    /// # Examples
+    ///
+    /// This example shows how to position an image at absolute coordinates in
+    /// the worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_image_set_absolute_position.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a new image and set its absolute position in pixels.
+    ///     let image = Image::new("examples/rust_logo.png")?.set_absolute_position(150, 60);
+    ///
+    ///     // Insert the image. The cell position is ignored.
+    ///     worksheet.insert_image(0, 0, &image)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("image.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_absolute_position(mut self, x: u32, y: u32) -> Image {
+        self.absolute_position = Some((x, y));
+        self.object_movement = ObjectMovement::AbsoluteAnchor;
+        self
+    }
+
     /// Set a Url/Hyperlink for an image.
     ///
     /// Set a Url/Hyperlink for an image so that when the user clicks on it they
@@ -1082,6 +1140,10 @@ impl DrawingObject for Image {
         self.y_offset
     }
 
+    fn absolute_position(&self) -> Option<(u32, u32)> {
+        self.absolute_position
+    }
+
     fn width_scaled(&self) -> f64 {
         self.width * self.scale_width * 96.0 / self.width_dpi
     }
@@ -1143,6 +1205,24 @@ pub enum ObjectMovement {
     /// object is inserted. This allows the insertion of objects into hidden
     /// rows or columns.
     MoveAndSizeWithCellsAfter,
+
+    /// Position the worksheet object at a fixed location in the worksheet
+    /// using an absolute anchor (`xdr:absoluteAnchor`) instead of a cell
+    /// anchor.
+    ///
+    /// The absolute position is calculated from the row, column and pixel
+    /// offsets that the object is inserted with, based on the row heights and
+    /// column widths at the time the file is saved. Excel doesn't recalculate
+    /// the position or size of the object when the cells underneath it change.
+    /// To position an object at exact pixel coordinates use the
+    /// `set_absolute_position()` method of the object, for example
+    /// [`Image::set_absolute_position()`].
+    ///
+    /// This option applies to [Image], [`Chart`](crate::Chart) and
+    /// [`Shape`](crate::Shape) objects. VML objects such as
+    /// [`Button`](crate::Button) and [`Note`](crate::Note) don't support
+    /// absolute anchors and are treated as `DontMoveOrSizeWithCells`.
+    AbsoluteAnchor,
 }
 
 /// The `HeaderImagePosition` enum defines the image position in a header or footer.
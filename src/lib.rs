@@ -205,23 +205,33 @@
 //!
 mod app;
 mod button;
+mod cell_style;
 mod color;
 mod comment;
 mod content_types;
 mod core;
 mod custom;
+mod custom_table_style;
+mod custom_view;
 mod data_validation;
 mod datetime;
 mod drawing;
 mod error;
+mod excel_error;
 mod feature_property_bag;
 mod filter;
+mod font_metrics;
 mod format;
 mod formula;
+mod header_footer;
 mod image;
 mod metadata;
 mod note;
+mod num_format;
+mod ole_object;
 mod packager;
+mod paste;
+mod phonetic;
 mod properties;
 mod protection;
 mod relationship;
@@ -232,11 +242,13 @@ mod rich_value_types;
 mod shape;
 mod shared_strings;
 mod shared_strings_table;
+mod shift;
 mod styles;
 mod table;
 mod theme;
 mod url;
 mod vml;
+mod workbook_view;
 mod xmlwriter;
 
 #[cfg(feature = "serde")]
@@ -260,20 +272,32 @@ mod test_functions;
 
 // Re-export the public APIs.
 pub use button::*;
+pub use cell_style::*;
 pub use color::*;
+pub use custom_table_style::*;
+pub use custom_view::*;
 pub use data_validation::*;
 pub use datetime::*;
 pub use error::*;
+pub use excel_error::*;
 pub use filter::*;
+pub use font_metrics::*;
 pub use format::*;
 pub use formula::*;
+pub use header_footer::*;
 pub use image::*;
 pub use note::*;
+pub use num_format::*;
+pub use ole_object::*;
+pub use paste::*;
+pub use phonetic::*;
 pub use properties::*;
 pub use protection::*;
 pub use shape::*;
 pub use table::*;
+pub use theme::*;
 pub use url::*;
+pub use workbook_view::*;
 
 #[doc(hidden)]
 pub use chart::*;
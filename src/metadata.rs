@@ -13,8 +13,8 @@ use crate::xmlwriter::{
 pub struct Metadata {
     pub(crate) writer: Cursor<Vec<u8>>,
     pub(crate) has_dynamic_functions: bool,
-    pub(crate) has_embedded_images: bool,
-    pub(crate) num_embedded_images: u32,
+    pub(crate) has_rich_values: bool,
+    pub(crate) num_rich_values: u32,
 }
 
 impl Metadata {
@@ -29,8 +29,8 @@ impl Metadata {
         Metadata {
             writer,
             has_dynamic_functions: false,
-            has_embedded_images: false,
-            num_embedded_images: 0,
+            has_rich_values: false,
+            num_rich_values: 0,
         }
     }
 
@@ -52,7 +52,7 @@ impl Metadata {
         if self.has_dynamic_functions {
             self.write_cell_future_metadata();
         }
-        if self.has_embedded_images {
+        if self.has_rich_values {
             self.write_value_future_metadata();
         }
 
@@ -60,7 +60,7 @@ impl Metadata {
         if self.has_dynamic_functions {
             self.write_cell_metadata();
         }
-        if self.has_embedded_images {
+        if self.has_rich_values {
             self.write_value_metadata();
         }
 
@@ -75,7 +75,7 @@ impl Metadata {
             "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
         )];
 
-        if self.has_embedded_images {
+        if self.has_rich_values {
             attributes.push((
                 "xmlns:xlrd",
                 "http://schemas.microsoft.com/office/spreadsheetml/2017/richdata",
@@ -100,7 +100,7 @@ impl Metadata {
             count += 1;
         }
 
-        if self.has_embedded_images {
+        if self.has_rich_values {
             count += 1;
         }
 
@@ -112,7 +112,7 @@ impl Metadata {
         if self.has_dynamic_functions {
             self.write_cell_metadata_type();
         }
-        if self.has_embedded_images {
+        if self.has_rich_values {
             self.write_value_metadata_type();
         }
 
@@ -181,13 +181,13 @@ impl Metadata {
     fn write_value_future_metadata(&mut self) {
         let attributes = [
             ("name", "XLRICHVALUE".to_string()),
-            ("count", self.num_embedded_images.to_string()),
+            ("count", self.num_rich_values.to_string()),
         ];
 
         xml_start_tag(&mut self.writer, "futureMetadata", &attributes);
 
-        // Write the <ext> element for each embedded image.
-        for index in 0..self.num_embedded_images {
+        // Write the <ext> element for each rich value.
+        for index in 0..self.num_rich_values {
             xml_start_tag_only(&mut self.writer, "bk");
             xml_start_tag_only(&mut self.writer, "extLst");
             self.write_value_ext(index);
@@ -252,13 +252,13 @@ impl Metadata {
 
     // Write the <valueMetadata> element.
     fn write_value_metadata(&mut self) {
-        let attributes = [("count", self.num_embedded_images.to_string())];
+        let attributes = [("count", self.num_rich_values.to_string())];
         let rc_type = if self.has_dynamic_functions { 2 } else { 1 };
 
         xml_start_tag(&mut self.writer, "valueMetadata", &attributes);
 
-        // Write the <rc> element for each embedded image.
-        for index in 0..self.num_embedded_images {
+        // Write the <rc> element for each rich value.
+        for index in 0..self.num_rich_values {
             xml_start_tag_only(&mut self.writer, "bk");
             self.write_rc(rc_type, index);
             xml_end_tag(&mut self.writer, "bk");
@@ -519,6 +519,7 @@ impl Note {
     ///
     pub fn set_font_name(mut self, font_name: impl Into<String>) -> Note {
         self.format.font.name = font_name.into();
+        self.format.font.has_name = true;
 
         if self.format.font.name != "Calibri" {
             self.format.font.scheme = String::new();
@@ -724,6 +725,10 @@ impl DrawingObject for Note {
         }
     }
 
+    fn absolute_position(&self) -> Option<(u32, u32)> {
+        None
+    }
+
     fn width_scaled(&self) -> f64 {
         self.width
     }
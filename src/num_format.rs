@@ -0,0 +1,1996 @@
+// num_format - A module for building Excel number format codes.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::fmt;
+
+use crate::{Format, XlsxError};
+
+// The Excel built-in number formats and their indexes.
+pub(crate) const BUILTIN_NUM_FORMATS: [(u8, &str); 35] = [
+    (1, "0"),
+    (2, "0.00"),
+    (3, "#,##0"),
+    (4, "#,##0.00"),
+    (5, "($#,##0_);($#,##0)"),
+    (6, "($#,##0_);[Red]($#,##0)"),
+    (7, "($#,##0.00_);($#,##0.00)"),
+    (8, "($#,##0.00_);[Red]($#,##0.00)"),
+    (9, "0%"),
+    (10, "0.00%"),
+    (11, "0.00E+00"),
+    (12, "# ?/?"),
+    (13, "# ??/??"),
+    (14, "m/d/yy"),
+    (15, "d-mmm-yy"),
+    (16, "d-mmm"),
+    (17, "mmm-yy"),
+    (18, "h:mm AM/PM"),
+    (19, "h:mm:ss AM/PM"),
+    (20, "h:mm"),
+    (21, "h:mm:ss"),
+    (22, "m/d/yy h:mm"),
+    (37, "(#,##0_);(#,##0)"),
+    (38, "(#,##0_);[Red](#,##0)"),
+    (39, "(#,##0.00_);(#,##0.00)"),
+    (40, "(#,##0.00_);[Red](#,##0.00)"),
+    (41, "_(* #,##0_);_(* (#,##0);_(* \"-\"_);_(_)"),
+    (42, "_($* #,##0_);_($* (#,##0);_($* \"-\"_);_(_)"),
+    (43, "_(* #,##0.00_);_(* (#,##0.00);_(* \"-\"??_);_(_)"),
+    (44, "_($* #,##0.00_);_($* (#,##0.00);_($* \"-\"??_);_(_)"),
+    (45, "mm:ss"),
+    (46, "[h]:mm:ss"),
+    (47, "mm:ss.0"),
+    (48, "##0.0E+0"),
+    (49, "@"),
+];
+
+// The maximum length of a number format code in Excel.
+const MAX_NUM_FORMAT_LENGTH: usize = 255;
+
+/// The `NumFormat` struct is a builder for Excel number format codes.
+///
+/// Excel number formats are strings such as `"#,##0.00"` or
+/// `"[$€-x-euro2] #,##0.00;[Red]-[$€-x-euro2] #,##0.00"` which are set with
+/// [`Format::set_num_format()`]. These strings are easy to get wrong, so
+/// `NumFormat` provides a typed way to build the common format types:
+///
+/// - Numbers, with decimal places, thousands separators and thousands scaling.
+/// - Currency and accounting formats, with optional locale tags.
+/// - Percentages, scientific notation and fractions.
+/// - Dates, times and elapsed times like `[h]:mm`.
+/// - Text, and raw format strings as an escape hatch.
+///
+/// Formats can also have a color, a condition, and up to four sections
+/// separated by `;` in the format code.
+///
+/// A `NumFormat` converts to a [`Format`] via the [`TryFrom`]/[`TryInto`]
+/// traits, which return an error if the format code is invalid, see
+/// [`NumFormat::validate()`]. If the format code matches one of the Excel
+/// built-in formats then the
+/// built-in index is used, in the same way as
+/// [`Format::set_num_format_index()`]. The result can be combined with other
+/// format properties using [`Format::merge()`]. The format code is available via
+/// [`NumFormat::to_string()`](std::string::ToString::to_string) and can be
+/// checked with [`NumFormat::validate()`].
+///
+/// # Examples
+///
+/// The following example demonstrates creating number formats with the
+/// `NumFormat` builder.
+///
+/// ```
+/// # // This code is available in examples/doc_num_format.rs
+/// #
+/// use rust_xlsxwriter::{
+///     Format, NumFormat, NumFormatColor, NumFormatElapsed, NumFormatNegative, Workbook,
+///     XlsxError,
+/// };
+///
+/// fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///     worksheet.set_column_width(0, 20)?;
+///
+///     // A number with a thousands separator and red negative numbers.
+///     let num_format = NumFormat::number(2)
+///         .set_thousands_separator()
+///         .set_negative_style(NumFormatNegative::Red);
+///     worksheet.write_with_format(0, 0, -1234.5, &Format::try_from(&num_format)?)?;
+///
+///     // A Euro currency format with an ISO locale tag.
+///     let num_format = NumFormat::currency("€", 2).set_locale("x-euro2");
+///     worksheet.write_with_format(1, 0, 1234.5, &Format::try_from(&num_format)?)?;
+///
+///     // Numbers scaled to thousands.
+///     let num_format = NumFormat::number(1).set_scale(1).set_suffix("K");
+///     worksheet.write_with_format(2, 0, 123456, &Format::try_from(&num_format)?)?;
+///
+///     // Conditional sections with colors.
+///     let num_format = NumFormat::number(0)
+///         .set_condition_greater_than(100.0)
+///         .set_color(NumFormatColor::Blue)
+///         .add_section(&NumFormat::number(0).set_color(NumFormatColor::Red));
+///     worksheet.write_with_format(3, 0, 200, &Format::try_from(&num_format)?)?;
+///
+///     // An elapsed time format.
+///     let num_format = NumFormat::elapsed_time(NumFormatElapsed::HoursMinutes);
+///     worksheet.write_with_format(4, 0, 1.25, &Format::try_from(&num_format)?)?;
+///
+///     workbook.save("num_formats.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumFormat {
+    kind: NumFormatKind,
+    thousands_separator: bool,
+    scale: u8,
+    currency_symbol: String,
+    locale: String,
+    is_symbol_after: bool,
+    suffix: String,
+    color: Option<NumFormatColor>,
+    condition: Option<(&'static str, f64)>,
+    negative_style: NumFormatNegative,
+    sections: Vec<NumFormat>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum NumFormatKind {
+    General,
+    Number(u8),
+    Percentage(u8),
+    Scientific(u8),
+    Fraction(String),
+    Accounting(u8),
+    DateTime(String),
+    Text,
+    Raw(String),
+}
+
+impl NumFormat {
+    // -----------------------------------------------------------------------
+    // Constructors.
+    // -----------------------------------------------------------------------
+
+    /// Create a new `NumFormat` with the Excel "General" format.
+    ///
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> NumFormat {
+        Self::from_kind(NumFormatKind::General)
+    }
+
+    /// Create a number format with a number of decimal places.
+    ///
+    /// For example `NumFormat::number(2)` gives the format `0.00`.
+    ///
+    /// # Parameters
+    ///
+    /// - `decimals`: The number of decimal places, in the range 0-30.
+    ///
+    pub fn number(decimals: u8) -> NumFormat {
+        Self::from_kind(NumFormatKind::Number(decimals))
+    }
+
+    /// Create a currency number format.
+    ///
+    /// This is a number format with a thousands separator and a currency
+    /// symbol. For example `NumFormat::currency("$", 2)` gives the format
+    /// `$#,##0.00`. Symbols other than `$` are written as an Excel currency
+    /// tag like `[$€]`, which can have a locale, see
+    /// [`NumFormat::set_locale()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `symbol`: The currency symbol, such as `$`, `€` or `CHF`.
+    /// - `decimals`: The number of decimal places, in the range 0-30.
+    ///
+    pub fn currency(symbol: impl Into<String>, decimals: u8) -> NumFormat {
+        Self::number(decimals)
+            .set_thousands_separator()
+            .set_currency_symbol(symbol)
+    }
+
+    /// Create an accounting number format.
+    ///
+    /// Accounting formats align the currency symbols and decimal points in a
+    /// column, show negative numbers in parentheses and show zero as a dash.
+    /// For example `NumFormat::accounting(2).set_currency_symbol("$")` gives
+    /// the format `_($* #,##0.00_);_($* (#,##0.00);_($* "-"??_);_(@_)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `decimals`: The number of decimal places, in the range 0-30.
+    ///
+    pub fn accounting(decimals: u8) -> NumFormat {
+        Self::from_kind(NumFormatKind::Accounting(decimals))
+    }
+
+    /// Create a percentage number format.
+    ///
+    /// For example `NumFormat::percentage(1)` gives the format `0.0%`.
+    ///
+    /// # Parameters
+    ///
+    /// - `decimals`: The number of decimal places, in the range 0-30.
+    ///
+    pub fn percentage(decimals: u8) -> NumFormat {
+        Self::from_kind(NumFormatKind::Percentage(decimals))
+    }
+
+    /// Create a scientific notation number format.
+    ///
+    /// For example `NumFormat::scientific(2)` gives the format `0.00E+00`.
+    ///
+    /// # Parameters
+    ///
+    /// - `decimals`: The number of decimal places, in the range 0-30.
+    ///
+    pub fn scientific(decimals: u8) -> NumFormat {
+        Self::from_kind(NumFormatKind::Scientific(decimals))
+    }
+
+    /// Create a fraction number format with a maximum number of digits.
+    ///
+    /// For example `NumFormat::fraction(2)` gives the format `# ??/??` which
+    /// displays fractions with up to 2 digits, like `3/16`.
+    ///
+    /// # Parameters
+    ///
+    /// - `digits`: The maximum number of digits in the numerator and
+    ///   denominator, in the range 1-5.
+    ///
+    pub fn fraction(digits: u8) -> NumFormat {
+        let digits = "?".repeat(usize::from(digits));
+        Self::from_kind(NumFormatKind::Fraction(format!("# {digits}/{digits}")))
+    }
+
+    /// Create a fraction number format with a fixed denominator.
+    ///
+    /// For example `NumFormat::fraction_with_denominator(16)` gives the format
+    /// `# ??/16`, which displays numbers in sixteenths.
+    ///
+    /// # Parameters
+    ///
+    /// - `denominator`: The denominator of the fraction, in the range
+    ///   2-99999.
+    ///
+    pub fn fraction_with_denominator(denominator: u32) -> NumFormat {
+        let digits = "?".repeat(denominator.to_string().len());
+        Self::from_kind(NumFormatKind::Fraction(format!("# {digits}/{denominator}")))
+    }
+
+    /// Create a date and/or time number format.
+    ///
+    /// The format code uses the standard Excel date and time tokens such as
+    /// `yyyy`, `mm`, `dd`, `hh`, `ss` and `AM/PM`. For example
+    /// `NumFormat::date_time("yyyy-mm-dd hh:mm")`.
+    ///
+    /// # Parameters
+    ///
+    /// - `format_code`: The date/time format code.
+    ///
+    pub fn date_time(format_code: impl Into<String>) -> NumFormat {
+        Self::from_kind(NumFormatKind::DateTime(format_code.into()))
+    }
+
+    /// Create an elapsed time number format.
+    ///
+    /// Elapsed time formats display durations that can exceed 24 hours, or
+    /// 60 minutes or seconds, such as `[h]:mm`.
+    ///
+    /// # Parameters
+    ///
+    /// - `elapsed`: A [`NumFormatElapsed`] enum value.
+    ///
+    pub fn elapsed_time(elapsed: NumFormatElapsed) -> NumFormat {
+        Self::from_kind(NumFormatKind::DateTime(elapsed.to_string()))
+    }
+
+    /// Create a text number format.
+    ///
+    /// This is the Excel `@` format which displays numbers as text.
+    ///
+    pub fn text() -> NumFormat {
+        Self::from_kind(NumFormatKind::Text)
+    }
+
+    /// Create a number format from a raw Excel format code.
+    ///
+    /// This is an escape hatch for formats that aren't covered by the other
+    /// constructors. The format code is used as is, except that the other
+    /// sections can still be added with [`NumFormat::add_section()`]. The
+    /// format code is checked by [`NumFormat::validate()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `format_code`: The Excel number format code.
+    ///
+    pub fn raw(format_code: impl Into<String>) -> NumFormat {
+        Self::from_kind(NumFormatKind::Raw(format_code.into()))
+    }
+
+    // -----------------------------------------------------------------------
+    // Property setters.
+    // -----------------------------------------------------------------------
+
+    /// Add a thousands separator to a number or currency format.
+    ///
+    /// For example `NumFormat::number(2).set_thousands_separator()` gives the
+    /// format `#,##0.00`.
+    ///
+    pub fn set_thousands_separator(mut self) -> NumFormat {
+        self.thousands_separator = true;
+        self
+    }
+
+    /// Scale a number or currency format by multiples of 1,000.
+    ///
+    /// Excel divides the displayed number by 1,000 for each trailing comma in
+    /// a number format. For example `NumFormat::number(1).set_scale(2)` gives
+    /// the format `0.0,,` which displays 1,234,567 as `1.2`. See also
+    /// [`NumFormat::set_suffix()`] to add a unit like "K" or "M".
+    ///
+    /// # Parameters
+    ///
+    /// - `scale`: The number of multiples of 1,000 to divide by, in the range
+    ///   0-3.
+    ///
+    pub fn set_scale(mut self, scale: u8) -> NumFormat {
+        if scale <= 3 {
+            self.scale = scale;
+        } else {
+            eprintln!("Number format scale '{scale}' must be in the range 0-3.");
+        }
+
+        self
+    }
+
+    /// Add a literal text suffix to a number, percentage or currency format.
+    ///
+    /// The suffix is quoted in the format code. For example
+    /// `NumFormat::number(0).set_suffix(" units")` gives the format
+    /// `0" units"`.
+    ///
+    /// # Parameters
+    ///
+    /// - `suffix`: The suffix text.
+    ///
+    pub fn set_suffix(mut self, suffix: impl Into<String>) -> NumFormat {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Set the currency symbol of a number or accounting format.
+    ///
+    /// # Parameters
+    ///
+    /// - `symbol`: The currency symbol, such as `$`, `€` or `CHF`.
+    ///
+    pub fn set_currency_symbol(mut self, symbol: impl Into<String>) -> NumFormat {
+        self.currency_symbol = symbol.into();
+        self
+    }
+
+    /// Set the locale of the currency symbol.
+    ///
+    /// Excel currency symbols can have a locale tag that controls how the
+    /// symbol is displayed and interpreted. The tag can be either the newer
+    /// ISO style, such as `x-euro2` or `en-US`, or a legacy hexadecimal
+    /// Windows LCID like `407` (German). For example
+    /// `NumFormat::currency("€", 2).set_locale("x-euro2")` gives the format
+    /// `[$€-x-euro2]#,##0.00`.
+    ///
+    /// # Parameters
+    ///
+    /// - `locale`: The locale tag.
+    ///
+    pub fn set_locale(mut self, locale: impl Into<String>) -> NumFormat {
+        self.locale = locale.into();
+        self
+    }
+
+    /// Display the currency symbol after the number.
+    ///
+    /// For example `NumFormat::currency("€", 2).set_symbol_after()` gives the
+    /// format `#,##0.00 [$€]`.
+    ///
+    pub fn set_symbol_after(mut self) -> NumFormat {
+        self.is_symbol_after = true;
+        self
+    }
+
+    /// Set the style of negative numbers in a number or currency format.
+    ///
+    /// This adds a negative number section to the format, in the same way as
+    /// the options in the Excel "Format Cells" dialog.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: A [`NumFormatNegative`] enum value.
+    ///
+    pub fn set_negative_style(mut self, style: NumFormatNegative) -> NumFormat {
+        self.negative_style = style;
+        self
+    }
+
+    /// Set the font color of the format section.
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: A [`NumFormatColor`] enum value.
+    ///
+    pub fn set_color(mut self, color: NumFormatColor) -> NumFormat {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set a greater than condition for the format section.
+    ///
+    /// Conditions replace the default positive/negative/zero meaning of the
+    /// sections of a format. Only the first two sections of a format can
+    /// have a condition.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to compare against.
+    ///
+    pub fn set_condition_greater_than(self, value: f64) -> NumFormat {
+        self.set_condition(">", value)
+    }
+
+    /// Set a greater than or equal to condition for the format section.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to compare against.
+    ///
+    pub fn set_condition_greater_than_or_equal_to(self, value: f64) -> NumFormat {
+        self.set_condition(">=", value)
+    }
+
+    /// Set a less than condition for the format section.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to compare against.
+    ///
+    pub fn set_condition_less_than(self, value: f64) -> NumFormat {
+        self.set_condition("<", value)
+    }
+
+    /// Set a less than or equal to condition for the format section.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to compare against.
+    ///
+    pub fn set_condition_less_than_or_equal_to(self, value: f64) -> NumFormat {
+        self.set_condition("<=", value)
+    }
+
+    /// Set an equal to condition for the format section.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to compare against.
+    ///
+    pub fn set_condition_equal_to(self, value: f64) -> NumFormat {
+        self.set_condition("=", value)
+    }
+
+    /// Set a not equal to condition for the format section.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to compare against.
+    ///
+    pub fn set_condition_not_equal_to(self, value: f64) -> NumFormat {
+        self.set_condition("<>", value)
+    }
+
+    /// Add another section to the format.
+    ///
+    /// Excel number formats can have up to four sections, separated by `;`.
+    /// Without conditions the sections apply to positive numbers, negative
+    /// numbers, zero, and text, in that order. The sections of the `section`
+    /// format are appended to the sections of this format.
+    ///
+    /// # Parameters
+    ///
+    /// - `section`: The [`NumFormat`] for the next section.
+    ///
+    pub fn add_section(mut self, section: &NumFormat) -> NumFormat {
+        let mut section = section.clone();
+        let sections = std::mem::take(&mut section.sections);
+
+        self.sections.push(section);
+        self.sections.extend(sections);
+        self
+    }
+
+    // -----------------------------------------------------------------------
+    // Other public methods.
+    // -----------------------------------------------------------------------
+
+    /// Get the Excel built-in index of the format, if there is one.
+    ///
+    /// Excel has a number of built-in number formats, such as `0.00` or `0%`,
+    /// that are referred to by index instead of by format code. See
+    /// [`Format::set_num_format_index()`] for the list.
+    ///
+    pub fn builtin_index(&self) -> Option<u8> {
+        let format_code = self.to_string();
+
+        if format_code == "General" {
+            return Some(0);
+        }
+
+        BUILTIN_NUM_FORMATS
+            .iter()
+            .find(|(_, builtin)| *builtin == format_code)
+            .map(|(index, _)| *index)
+    }
+
+    /// Validate the number format code.
+    ///
+    /// Check the format code for the errors that would cause Excel to report
+    /// a corrupt file or to reject the format.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The format code is empty or longer
+    ///   than 255 characters, has unbalanced quotes or brackets, has more than
+    ///   4 sections, has a condition after the second section, or has an
+    ///   unknown `[...]` color, condition, or locale code. Decimal places,
+    ///   fraction digits and fraction denominators are also checked.
+    ///
+    pub fn validate(&self) -> Result<(), XlsxError> {
+        for section in std::iter::once(self).chain(self.sections.iter()) {
+            section.validate_properties()?;
+        }
+
+        Self::validate_format_code(&self.to_string())
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal methods.
+    // -----------------------------------------------------------------------
+
+    fn from_kind(kind: NumFormatKind) -> NumFormat {
+        NumFormat {
+            kind,
+            thousands_separator: false,
+            scale: 0,
+            currency_symbol: String::new(),
+            locale: String::new(),
+            is_symbol_after: false,
+            suffix: String::new(),
+            color: None,
+            condition: None,
+            negative_style: NumFormatNegative::Minus,
+            sections: vec![],
+        }
+    }
+
+    fn set_condition(mut self, operator: &'static str, value: f64) -> NumFormat {
+        self.condition = Some((operator, value));
+        self
+    }
+
+    // Check the properties that can't be checked from the format code.
+    fn validate_properties(&self) -> Result<(), XlsxError> {
+        let decimals = match &self.kind {
+            NumFormatKind::Number(decimals)
+            | NumFormatKind::Percentage(decimals)
+            | NumFormatKind::Scientific(decimals)
+            | NumFormatKind::Accounting(decimals) => *decimals,
+            NumFormatKind::Fraction(fraction) => {
+                let (numerator, denominator) = fraction.split_once('/').unwrap_or_default();
+                let digits = numerator.trim_start_matches("# ").len();
+
+                if !(1..=5).contains(&digits)
+                    || denominator.len() > 5
+                    || denominator == "0"
+                    || denominator == "1"
+                {
+                    return Err(XlsxError::ParameterError(format!(
+                        "Invalid fraction number format '{fraction}'"
+                    )));
+                }
+
+                0
+            }
+            _ => 0,
+        };
+
+        if decimals > 30 {
+            return Err(XlsxError::ParameterError(format!(
+                "Number format decimal places '{decimals}' must be in the range 0-30"
+            )));
+        }
+
+        if let Some((_, value)) = self.condition {
+            if !value.is_finite() {
+                return Err(XlsxError::ParameterError(format!(
+                    "Number format condition value '{value}' must be a finite number"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Check the structure of a format code.
+    fn validate_format_code(format_code: &str) -> Result<(), XlsxError> {
+        let error = |message: &str| {
+            Err(XlsxError::ParameterError(format!(
+                "Number format '{format_code}' {message}"
+            )))
+        };
+
+        if format_code.is_empty() {
+            return error("cannot be empty");
+        }
+
+        if format_code.chars().count() > MAX_NUM_FORMAT_LENGTH {
+            return error("exceeds Excel's limit of 255 characters");
+        }
+
+        let mut sections = 1;
+        let mut chars = format_code.chars();
+        while let Some(char) = chars.next() {
+            match char {
+                // Escaped characters are literal.
+                '\\' => {
+                    let escaped = chars.next();
+                    if escaped.is_none() {
+                        return error("ends with an escape character");
+                    }
+                }
+
+                // Quoted strings are literal.
+                '"' => {
+                    let is_closed = chars.any(|char| char == '"');
+                    if !is_closed {
+                        return error("has an unterminated quoted string");
+                    }
+                }
+
+                // Bracketed codes must be closed and known.
+                '[' => {
+                    let mut code = String::new();
+                    let mut is_closed = false;
+                    for char in chars.by_ref() {
+                        if char == ']' {
+                            is_closed = true;
+                            break;
+                        }
+                        code.push(char);
+                    }
+
+                    if !is_closed {
+                        return error("has an unterminated '[' bracket");
+                    }
+
+                    if Self::is_condition_code(&code) {
+                        if sections > 2 {
+                            return error("has a condition after the second section");
+                        }
+                    } else if !Self::is_bracket_code(&code) {
+                        return error(&format!("has an unknown code '[{code}]'"));
+                    }
+                }
+
+                ']' => return error("has an unmatched ']' bracket"),
+
+                ';' => {
+                    sections += 1;
+                    if sections > 4 {
+                        return error("has more than 4 sections");
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    // Check for a condition code like "[>=100]".
+    fn is_condition_code(code: &str) -> bool {
+        let value = ["<=", ">=", "<>", "<", ">", "="]
+            .iter()
+            .find_map(|operator| code.strip_prefix(operator));
+
+        match value {
+            Some(value) => value.trim().parse::<f64>().is_ok(),
+            None => false,
+        }
+    }
+
+    // Check for a color, locale/currency or elapsed time code.
+    fn is_bracket_code(code: &str) -> bool {
+        let lowercase = code.to_lowercase();
+
+        if NumFormatColor::NAMES.contains(&lowercase.as_str()) {
+            return true;
+        }
+
+        if let Some(index) = lowercase.strip_prefix("color") {
+            return matches!(index.parse::<u8>(), Ok(1..=56));
+        }
+
+        // Currency symbols and locales like "[$€-x-euro2]" or "[$-409]".
+        if code.starts_with('$') {
+            return code.len() > 1;
+        }
+
+        // Elapsed time like "[h]", "[mm]" or "[ss]".
+        for elapsed in ['h', 'm', 's'] {
+            if lowercase.chars().all(|char| char == elapsed) {
+                return true;
+            }
+        }
+
+        // Alternative numeral systems, mainly for CJK locales.
+        lowercase.starts_with("dbnum") || lowercase.starts_with("natnum")
+    }
+
+    // Get the number part of a format like "#,##0.00,,".
+    fn number_code(&self, decimals: u8, thousands_separator: bool) -> String {
+        let mut code = if thousands_separator {
+            String::from("#,##0")
+        } else {
+            String::from("0")
+        };
+
+        if decimals > 0 {
+            code.push('.');
+            code.push_str(&"0".repeat(usize::from(decimals)));
+        }
+
+        code.push_str(&",".repeat(usize::from(self.scale)));
+        code
+    }
+
+    // Get the currency symbol code like "$" or "[$€-x-euro2]".
+    fn currency_code(&self) -> String {
+        if self.currency_symbol.is_empty() && self.locale.is_empty() {
+            String::new()
+        } else if self.locale.is_empty() && self.currency_symbol == "$" {
+            String::from("$")
+        } else if self.locale.is_empty() {
+            format!("[${}]", self.currency_symbol)
+        } else {
+            format!("[${}-{}]", self.currency_symbol, self.locale)
+        }
+    }
+
+    // Add the currency symbol and suffix to a number code.
+    fn decorate(&self, code: &str) -> String {
+        let currency = self.currency_code();
+        let mut code = if currency.is_empty() {
+            code.to_string()
+        } else if self.is_symbol_after {
+            format!("{code} {currency}")
+        } else {
+            format!("{currency}{code}")
+        };
+
+        if !self.suffix.is_empty() {
+            code.push_str(&format!("\"{}\"", self.suffix.replace('"', "")));
+        }
+
+        code
+    }
+
+    // Get the format code for the main section, and any negative section.
+    fn section_codes(&self) -> Vec<String> {
+        let code = match &self.kind {
+            NumFormatKind::General => String::from("General"),
+            NumFormatKind::Number(decimals) => {
+                self.decorate(&self.number_code(*decimals, self.thousands_separator))
+            }
+            NumFormatKind::Percentage(decimals) => {
+                self.decorate(&format!("{}%", self.number_code(*decimals, false)))
+            }
+            NumFormatKind::Scientific(decimals) => {
+                let mut code = self.number_code(*decimals, false);
+                code.push_str("E+00");
+                code
+            }
+            NumFormatKind::Fraction(code) | NumFormatKind::DateTime(code) => code.clone(),
+            NumFormatKind::Text => String::from("@"),
+            NumFormatKind::Raw(code) => return vec![code.clone()],
+            NumFormatKind::Accounting(decimals) => return self.accounting_codes(*decimals),
+        };
+
+        let prefix = self.section_prefix();
+
+        let is_number = matches!(
+            self.kind,
+            NumFormatKind::Number(_) | NumFormatKind::Percentage(_)
+        );
+        if !is_number {
+            return vec![format!("{prefix}{code}")];
+        }
+
+        match self.negative_style {
+            NumFormatNegative::Minus => vec![format!("{prefix}{code}")],
+            NumFormatNegative::Red => vec![format!("{prefix}{code}"), format!("[Red]{code}")],
+            NumFormatNegative::Parentheses => {
+                vec![format!("{prefix}{code}_)"), format!("({code})")]
+            }
+            NumFormatNegative::RedParentheses => {
+                vec![format!("{prefix}{code}_)"), format!("[Red]({code})")]
+            }
+        }
+    }
+
+    // Get the four sections of an accounting format.
+    fn accounting_codes(&self, decimals: u8) -> Vec<String> {
+        let number = self.number_code(decimals, true);
+        let currency = self.currency_code();
+        let zero = if decimals > 0 {
+            format!("\"-\"{}", "?".repeat(usize::from(decimals)))
+        } else {
+            String::from("\"-\"")
+        };
+
+        // Formats with a trailing symbol use the European style of negative
+        // numbers and padding.
+        let (positive, negative, zero, text) = if self.is_symbol_after {
+            (
+                format!("_-* {number} {currency}_-"),
+                format!("-* {number} {currency}_-"),
+                format!("_-* {zero} {currency}_-"),
+                String::from("_-@_-"),
+            )
+        } else {
+            (
+                format!("_({currency}* {number}_)"),
+                format!("_({currency}* ({number})"),
+                format!("_({currency}* {zero}_)"),
+                String::from("_(@_)"),
+            )
+        };
+
+        vec![
+            format!("{}{positive}", self.section_prefix()),
+            negative,
+            zero,
+            text,
+        ]
+    }
+
+    // Get the color and condition prefix of the section.
+    fn section_prefix(&self) -> String {
+        let mut prefix = String::new();
+
+        if let Some(color) = self.color {
+            prefix.push_str(&format!("[{color}]"));
+        }
+
+        if let Some((operator, value)) = self.condition {
+            prefix.push_str(&format!("[{operator}{value}]"));
+        }
+
+        prefix
+    }
+}
+
+impl fmt::Display for NumFormat {
+    /// Get the Excel number format code.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut codes = self.section_codes();
+
+        for section in &self.sections {
+            codes.extend(section.section_codes());
+        }
+
+        write!(f, "{}", codes.join(";"))
+    }
+}
+
+/// Convert a [`NumFormat`] into a [`Format`] object.
+///
+/// This is the equivalent of `Format::new().set_num_format(code)`, or of
+/// [`Format::set_num_format_index()`] for Excel built-in formats.
+///
+/// # Errors
+///
+/// - [`XlsxError::ParameterError`] - The format code is invalid. See
+///   [`NumFormat::validate()`].
+///
+impl TryFrom<&NumFormat> for Format {
+    type Error = XlsxError;
+
+    fn try_from(num_format: &NumFormat) -> Result<Format, XlsxError> {
+        num_format.validate()?;
+
+        let format = match num_format.builtin_index() {
+            Some(index) => Format::new().set_num_format_index(index),
+            None => Format::new().set_num_format(num_format.to_string()),
+        };
+
+        Ok(format)
+    }
+}
+
+/// Convert a [`NumFormat`] into a [`Format`] object.
+///
+/// # Errors
+///
+/// - [`XlsxError::ParameterError`] - The format code is invalid. See
+///   [`NumFormat::validate()`].
+///
+impl TryFrom<NumFormat> for Format {
+    type Error = XlsxError;
+
+    fn try_from(num_format: NumFormat) -> Result<Format, XlsxError> {
+        Format::try_from(&num_format)
+    }
+}
+
+/// The `NumFormatNegative` enum defines the display of negative numbers in a
+/// [`NumFormat`].
+///
+/// These are the options in the Excel "Format Cells" dialog for numbers and
+/// currency.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum NumFormatNegative {
+    /// Negative numbers have a minus sign, like `-1234.10`. This is the
+    /// default.
+    #[default]
+    Minus,
+
+    /// Negative numbers are shown in red without a minus sign, like
+    /// `1234.10`.
+    Red,
+
+    /// Negative numbers are shown in parentheses, like `(1234.10)`.
+    Parentheses,
+
+    /// Negative numbers are shown in red and in parentheses, like
+    /// `(1234.10)`.
+    RedParentheses,
+}
+
+/// The `NumFormatColor` enum defines the font colors that can be used in a
+/// [`NumFormat`] section.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NumFormatColor {
+    /// The color black.
+    Black,
+
+    /// The color blue.
+    Blue,
+
+    /// The color cyan.
+    Cyan,
+
+    /// The color green.
+    Green,
+
+    /// The color magenta.
+    Magenta,
+
+    /// The color red.
+    Red,
+
+    /// The color white.
+    White,
+
+    /// The color yellow.
+    Yellow,
+
+    /// A color from the Excel legacy 56 color palette, in the range 1-56.
+    Indexed(u8),
+}
+
+impl NumFormatColor {
+    // The color names allowed in a number format.
+    const NAMES: [&'static str; 8] = [
+        "black", "blue", "cyan", "green", "magenta", "red", "white", "yellow",
+    ];
+}
+
+impl fmt::Display for NumFormatColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Black => write!(f, "Black"),
+            Self::Blue => write!(f, "Blue"),
+            Self::Cyan => write!(f, "Cyan"),
+            Self::Green => write!(f, "Green"),
+            Self::Magenta => write!(f, "Magenta"),
+            Self::Red => write!(f, "Red"),
+            Self::White => write!(f, "White"),
+            Self::Yellow => write!(f, "Yellow"),
+            Self::Indexed(index) => write!(f, "Color{index}"),
+        }
+    }
+}
+
+/// The `NumFormatElapsed` enum defines the elapsed time formats of a
+/// [`NumFormat`].
+///
+/// Elapsed time formats display durations that can exceed 24 hours, 60
+/// minutes or 60 seconds.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NumFormatElapsed {
+    /// Elapsed hours, minutes and seconds: `[h]:mm:ss`.
+    Hours,
+
+    /// Elapsed hours and minutes: `[h]:mm`.
+    HoursMinutes,
+
+    /// Elapsed minutes and seconds: `[mm]:ss`.
+    Minutes,
+
+    /// Elapsed seconds: `[ss]`.
+    Seconds,
+}
+
+impl fmt::Display for NumFormatElapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hours => write!(f, "[h]:mm:ss"),
+            Self::HoursMinutes => write!(f, "[h]:mm"),
+            Self::Minutes => write!(f, "[mm]:ss"),
+            Self::Seconds => write!(f, "[ss]"),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------
+// Number format renderer.
+// -----------------------------------------------------------------------
+
+// The string displayed by Excel for values that can't be shown in a format,
+// such as negative dates. Excel fills the cell with "#" characters.
+const INVALID_DISPLAY: &str = "########";
+
+// The largest date supported by Excel: 9999-12-31 23:59:59.
+const MAX_EXCEL_DATE: f64 = 2_958_466.0;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+// The tokens of a number format section.
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Literal(String),
+    Digit(char),
+    Point,
+    Comma,
+    Percent,
+    Slash,
+    Exponent(char),
+    General,
+    Text,
+    Date(char, usize),
+    Elapsed(char, usize),
+    AmPm(String, String),
+}
+
+impl Token {
+    // The text of the token when it is displayed as a literal.
+    fn literal(&self) -> String {
+        match self {
+            Token::Literal(literal) => literal.clone(),
+            Token::Digit(char) => char.to_string(),
+            Token::Point => ".".to_string(),
+            Token::Comma => ",".to_string(),
+            Token::Percent => "%".to_string(),
+            Token::Slash => "/".to_string(),
+            Token::Exponent(sign) => format!("E{sign}"),
+            _ => String::new(),
+        }
+    }
+}
+
+// A parsed section of a number format and its optional condition.
+struct Section {
+    tokens: Vec<Token>,
+    condition: Option<(String, f64)>,
+}
+
+// Render a number using an Excel number format code, as Excel would display
+// it. See `utility::format_number()` for the public interface.
+pub(crate) fn render_number(number: f64, format_code: &str) -> String {
+    if !number.is_finite() {
+        return "#NUM!".to_string();
+    }
+
+    let sections: Vec<Section> = split_sections(format_code)
+        .iter()
+        .map(|code| parse_section(code))
+        .collect();
+
+    match select_section(&sections, number) {
+        Some((section, number)) => render_section(section, number),
+        None => INVALID_DISPLAY.to_string(),
+    }
+}
+
+// Split a format code into sections separated by ";", ignoring separators in
+// quoted strings, escapes and bracketed codes.
+fn split_sections(format_code: &str) -> Vec<String> {
+    let mut sections = vec![];
+    let mut section = String::new();
+    let mut in_quotes = false;
+    let mut in_brackets = false;
+    let mut is_escaped = false;
+
+    for char in format_code.chars() {
+        if is_escaped {
+            is_escaped = false;
+        } else if in_quotes {
+            in_quotes = char != '"';
+        } else if in_brackets {
+            in_brackets = char != ']';
+        } else {
+            match char {
+                '\\' => is_escaped = true,
+                '"' => in_quotes = true,
+                '[' => in_brackets = true,
+                ';' => {
+                    sections.push(std::mem::take(&mut section));
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        section.push(char);
+    }
+
+    sections.push(section);
+    sections
+}
+
+// Convert a format section into tokens.
+fn parse_section(code: &str) -> Section {
+    let chars: Vec<char> = code.chars().collect();
+    let mut tokens = vec![];
+    let mut condition = None;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let char = chars[i];
+        let lower = char.to_ascii_lowercase();
+        i += 1;
+
+        match char {
+            // Quoted strings are literal.
+            '"' => {
+                let mut literal = String::new();
+                while i < chars.len() && chars[i] != '"' {
+                    literal.push(chars[i]);
+                    i += 1;
+                }
+                i += 1;
+                tokens.push(Token::Literal(literal));
+            }
+
+            // Escaped characters are literal.
+            '\\' => {
+                if let Some(escaped) = chars.get(i) {
+                    tokens.push(Token::Literal(escaped.to_string()));
+                }
+                i += 1;
+            }
+
+            // Padding the width of the next character is shown as a space.
+            '_' => {
+                tokens.push(Token::Literal(" ".to_string()));
+                i += 1;
+            }
+
+            // Repeated fill characters depend on the cell width so they are
+            // ignored.
+            '*' => i += 1,
+
+            // Bracketed colors, conditions, locales and elapsed times.
+            '[' => {
+                let mut bracket_code = String::new();
+                while i < chars.len() && chars[i] != ']' {
+                    bracket_code.push(chars[i]);
+                    i += 1;
+                }
+                i += 1;
+
+                if let Some(currency) = bracket_code.strip_prefix('$') {
+                    let symbol = currency.split('-').next().unwrap_or_default();
+                    tokens.push(Token::Literal(symbol.to_string()));
+                } else if let Some(parsed) = parse_condition(&bracket_code) {
+                    condition = Some(parsed);
+                } else {
+                    let bracket_code = bracket_code.to_ascii_lowercase();
+                    if let Some(first) = bracket_code.chars().next() {
+                        if matches!(first, 'h' | 'm' | 's')
+                            && bracket_code.chars().all(|char| char == first)
+                        {
+                            tokens.push(Token::Elapsed(first, bracket_code.len()));
+                        }
+                    }
+                }
+            }
+
+            '0' | '#' | '?' => tokens.push(Token::Digit(char)),
+            '.' => tokens.push(Token::Point),
+            ',' => tokens.push(Token::Comma),
+            '%' => tokens.push(Token::Percent),
+            '/' => tokens.push(Token::Slash),
+            '@' => tokens.push(Token::Text),
+
+            'E' | 'e' if matches!(chars.get(i), Some('+' | '-')) => {
+                tokens.push(Token::Exponent(chars[i]));
+                i += 1;
+            }
+
+            _ if starts_with_ignore_case(&chars[i - 1..], "general") => {
+                tokens.push(Token::General);
+                i += 6;
+            }
+
+            _ if starts_with_ignore_case(&chars[i - 1..], "am/pm") => {
+                tokens.push(Token::AmPm("AM".to_string(), "PM".to_string()));
+                i += 4;
+            }
+
+            _ if starts_with_ignore_case(&chars[i - 1..], "a/p") => {
+                tokens.push(Token::AmPm(char.to_string(), chars[i + 1].to_string()));
+                i += 2;
+            }
+
+            _ if matches!(lower, 'y' | 'm' | 'd' | 'h' | 's') => {
+                let mut count = 1;
+                while i < chars.len() && chars[i].to_ascii_lowercase() == lower {
+                    count += 1;
+                    i += 1;
+                }
+                tokens.push(Token::Date(lower, count));
+            }
+
+            _ => tokens.push(Token::Literal(char.to_string())),
+        }
+    }
+
+    Section { tokens, condition }
+}
+
+// Check for a case insensitive ascii prefix in a slice of chars.
+fn starts_with_ignore_case(chars: &[char], prefix: &str) -> bool {
+    prefix.chars().count() <= chars.len()
+        && prefix
+            .chars()
+            .zip(chars)
+            .all(|(prefix, char)| char.to_ascii_lowercase() == prefix)
+}
+
+// Parse a condition code like ">100" or "<=0".
+fn parse_condition(code: &str) -> Option<(String, f64)> {
+    for operator in ["<=", ">=", "<>", "<", ">", "="] {
+        if let Some(value) = code.strip_prefix(operator) {
+            return value
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .map(|value| (operator.to_string(), value));
+        }
+    }
+
+    None
+}
+
+// Check if a number matches a section condition.
+fn matches_condition(operator: &str, value: f64, number: f64) -> bool {
+    match operator {
+        "<=" => number <= value,
+        ">=" => number >= value,
+        "<>" => number != value,
+        "<" => number < value,
+        ">" => number > value,
+        _ => number == value,
+    }
+}
+
+// Select the format section for a number. The sign of the number is dropped
+// for the negative section since the section controls the display of negative
+// numbers.
+fn select_section(sections: &[Section], number: f64) -> Option<(&Section, f64)> {
+    // The fourth section is only used for text.
+    let sections = &sections[..sections.len().min(3)];
+
+    if sections.iter().any(|section| section.condition.is_some()) {
+        // The first section without a condition is used for any values that
+        // don't match the conditional sections.
+        return sections
+            .iter()
+            .find(|section| match &section.condition {
+                Some((operator, value)) => matches_condition(operator, *value, number),
+                None => true,
+            })
+            .map(|section| (section, number));
+    }
+
+    let section = match sections.len() {
+        1 => return Some((&sections[0], number)),
+        2 if number >= 0.0 => &sections[0],
+        _ if number > 0.0 => &sections[0],
+        _ if number < 0.0 => &sections[1],
+        _ => &sections[2],
+    };
+
+    Some((section, number.abs()))
+}
+
+// Render a number with a format section.
+fn render_section(section: &Section, number: f64) -> String {
+    let tokens = &section.tokens;
+
+    if tokens.iter().any(|token| {
+        matches!(
+            token,
+            Token::Date(..) | Token::Elapsed(..) | Token::AmPm(..)
+        )
+    }) {
+        return render_date_time(tokens, number);
+    }
+
+    if !tokens.iter().any(|token| matches!(token, Token::Digit(_))) {
+        // Sections without digits are literals, "General" or "@" text.
+        return tokens
+            .iter()
+            .map(|token| match token {
+                Token::General | Token::Text => render_general(number),
+                _ => token.literal(),
+            })
+            .collect();
+    }
+
+    match tokens.iter().position(|token| *token == Token::Slash) {
+        Some(slash) if slash > 0 && matches!(tokens[slash - 1], Token::Digit(_)) => {
+            render_fraction(tokens, slash, number)
+        }
+        _ => render_decimal(tokens, number),
+    }
+}
+
+// Render a number in the "General" format. Excel shows up to 11 characters, or
+// 10 significant digits, and switches to scientific notation for large and
+// small numbers.
+fn render_general(number: f64) -> String {
+    if number == 0.0 {
+        return "0".to_string();
+    }
+
+    let sign = if number < 0.0 { "-" } else { "" };
+    let number = number.abs();
+
+    if (1e-9..1e11).contains(&number) {
+        let integer_digits = (number.log10().floor() as i32 + 1).max(1);
+        let decimals = (10 - integer_digits).max(0) as usize;
+        let (integer, fraction) = round_decimal(number, decimals);
+        let fraction = fraction.trim_end_matches('0');
+
+        if fraction.is_empty() {
+            format!("{sign}{integer}")
+        } else {
+            format!("{sign}{integer}.{fraction}")
+        }
+    } else {
+        let mut exponent = number.log10().floor() as i32;
+        let (mut integer, mut fraction) = round_decimal(number / 10f64.powi(exponent), 5);
+        if integer.len() > 1 {
+            exponent += 1;
+            (integer, fraction) = round_decimal(number / 10f64.powi(exponent), 5);
+        }
+        let fraction = fraction.trim_end_matches('0');
+        let exponent_sign = if exponent < 0 { '-' } else { '+' };
+        let exponent = exponent.abs();
+
+        if fraction.is_empty() {
+            format!("{sign}{integer}E{exponent_sign}{exponent:02}")
+        } else {
+            format!("{sign}{integer}.{fraction}E{exponent_sign}{exponent:02}")
+        }
+    }
+}
+
+// Round a positive number to a number of decimal places and return the integer
+// and fractional digits. Excel uses 15 significant digits and rounds half away
+// from zero, unlike the Rust float formatting.
+fn round_decimal(number: f64, decimals: usize) -> (String, String) {
+    let scientific = format!("{number:.14e}");
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or(("0", "0"));
+    let mut exponent: i64 = exponent.parse().unwrap_or(0);
+    let mut digits: Vec<u8> = mantissa
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .map(|byte| byte - b'0')
+        .collect();
+
+    // The digit at index i has a place value of 10^(exponent - i).
+    let keep = exponent + 1 + decimals as i64;
+    if keep < 0 {
+        digits.clear();
+    } else if (keep as usize) < digits.len() {
+        let round_up = digits[keep as usize] >= 5;
+        digits.truncate(keep as usize);
+
+        if round_up {
+            let mut i = digits.len();
+            loop {
+                if i == 0 {
+                    digits.insert(0, 1);
+                    exponent += 1;
+                    break;
+                }
+                i -= 1;
+                if digits[i] == 9 {
+                    digits[i] = 0;
+                } else {
+                    digits[i] += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    let digit_at = |place: i64| -> char {
+        let index = exponent - place;
+        let digit = if index >= 0 {
+            digits.get(index as usize).copied().unwrap_or(0)
+        } else {
+            0
+        };
+        char::from(b'0' + digit)
+    };
+
+    let integer = (0..=exponent.max(0)).rev().map(digit_at).collect();
+    let fraction = (1..=decimals as i64)
+        .map(|place| digit_at(-place))
+        .collect();
+
+    (integer, fraction)
+}
+
+// Get the displayed text for each integer digit placeholder. Digits that don't
+// fit in the placeholders are displayed with the first placeholder.
+fn render_integer(placeholders: &[char], digits: &str, has_separator: bool) -> Vec<String> {
+    let digits: Vec<char> = digits.chars().collect();
+    let count = placeholders.len();
+    let mut output = vec![String::new(); count];
+
+    // Add a thousands separator after a digit, if required.
+    let push_digit = |string: &mut String, digit: char, place: usize| {
+        string.push(digit);
+        if has_separator && place > 0 && place % 3 == 0 {
+            string.push(',');
+        }
+    };
+
+    for (i, placeholder) in placeholders.iter().enumerate() {
+        let place = count - 1 - i;
+
+        if i == 0 && digits.len() > count {
+            for (j, digit) in digits[..=digits.len() - count].iter().enumerate() {
+                push_digit(&mut output[i], *digit, digits.len() - 1 - j);
+            }
+        } else if place < digits.len() {
+            push_digit(&mut output[i], digits[digits.len() - 1 - place], place);
+        } else {
+            match placeholder {
+                '0' => push_digit(&mut output[i], '0', place),
+                '?' => output[i].push(' '),
+                _ => {}
+            }
+        }
+    }
+
+    output
+}
+
+// Render a number with a decimal or scientific format section.
+fn render_decimal(tokens: &[Token], number: f64) -> String {
+    let is_negative = number < 0.0;
+    let exponent_index = tokens
+        .iter()
+        .position(|token| matches!(token, Token::Exponent(_)))
+        .unwrap_or(tokens.len());
+    let point_index = tokens[..exponent_index]
+        .iter()
+        .position(|token| *token == Token::Point)
+        .unwrap_or(exponent_index);
+
+    let placeholders = |range: std::ops::Range<usize>| -> Vec<char> {
+        tokens[range]
+            .iter()
+            .filter_map(|token| match token {
+                Token::Digit(char) => Some(*char),
+                _ => None,
+            })
+            .collect()
+    };
+    let integer_placeholders = placeholders(0..point_index);
+    let decimal_placeholders = placeholders(point_index..exponent_index);
+    let exponent_placeholders = placeholders(exponent_index..tokens.len());
+
+    // Commas between integer digits are thousands separators. Commas after the
+    // last digit scale the number by 1000.
+    let last_digit = tokens[..exponent_index]
+        .iter()
+        .rposition(|token| matches!(token, Token::Digit(_)))
+        .unwrap_or(0);
+    let scale_commas = tokens[last_digit + 1..]
+        .iter()
+        .take_while(|token| **token == Token::Comma)
+        .count();
+    let is_separator = |index: usize| {
+        index < point_index
+            && tokens[..index]
+                .iter()
+                .any(|token| matches!(token, Token::Digit(_)))
+            && tokens[index + 1..point_index]
+                .iter()
+                .any(|token| matches!(token, Token::Digit(_)))
+    };
+    let has_separator =
+        (0..point_index).any(|index| tokens[index] == Token::Comma && is_separator(index));
+
+    let percents = tokens
+        .iter()
+        .filter(|token| **token == Token::Percent)
+        .count();
+    let mut value = number.abs() * 100f64.powi(percents as i32) / 1000f64.powi(scale_commas as i32);
+
+    // Get the mantissa and exponent for scientific formats.
+    let mut exponent = 0;
+    if exponent_index < tokens.len() && value != 0.0 {
+        let integer_count = integer_placeholders.len() as i32;
+        let is_engineering = integer_count > 1 && integer_placeholders[0] == '#';
+        let step = if is_engineering { integer_count } else { 1 };
+        let magnitude = value.log10().floor() as i32;
+
+        exponent = if is_engineering {
+            magnitude.div_euclid(integer_count) * integer_count
+        } else {
+            magnitude + 1 - integer_count
+        };
+
+        // Check for rounding up to the next power of 10.
+        let (integer, _) = round_decimal(value / 10f64.powi(exponent), decimal_placeholders.len());
+        if integer.len() as i32 > integer_count.max(1) {
+            exponent += step;
+        }
+
+        value /= 10f64.powi(exponent);
+    }
+
+    let (integer, fraction) = round_decimal(value, decimal_placeholders.len());
+    let integer = if integer == "0" {
+        String::new()
+    } else {
+        integer
+    };
+    let integer_output = render_integer(&integer_placeholders, &integer, has_separator);
+
+    // Trailing zeros are hidden for "#" and replaced with spaces for "?".
+    let fraction: Vec<char> = fraction.chars().collect();
+    let mut significant = fraction.len();
+    while significant > 0
+        && decimal_placeholders[significant - 1] != '0'
+        && fraction[significant - 1] == '0'
+    {
+        significant -= 1;
+    }
+
+    let exponent_digits = format!(
+        "{:0width$}",
+        exponent.abs(),
+        width = exponent_placeholders
+            .iter()
+            .filter(|char| **char == '0')
+            .count()
+    );
+
+    let mut output = String::new();
+    if is_negative {
+        output.push('-');
+    }
+
+    let mut integer_count = 0;
+    let mut decimal_count = 0;
+    let mut exponent_count = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Digit(_) if index < point_index => {
+                output.push_str(&integer_output[integer_count]);
+                integer_count += 1;
+            }
+            Token::Digit(placeholder) if index < exponent_index => {
+                if decimal_count < significant {
+                    output.push(fraction[decimal_count]);
+                } else if *placeholder == '?' {
+                    output.push(' ');
+                }
+                decimal_count += 1;
+            }
+            Token::Digit(_) => {
+                if exponent_count == 0 {
+                    output.push_str(&exponent_digits);
+                }
+                exponent_count += 1;
+            }
+            Token::Point if index == point_index => {
+                if integer_placeholders.is_empty() {
+                    output.push_str(&integer);
+                }
+                output.push('.');
+            }
+            Token::Exponent(sign) => {
+                output.push('E');
+                if exponent < 0 {
+                    output.push('-');
+                } else if *sign == '+' {
+                    output.push('+');
+                }
+            }
+            Token::Comma
+                if is_separator(index)
+                    || (index > last_digit && index <= last_digit + scale_commas) => {}
+            Token::General | Token::Text => output.push_str(&render_general(value)),
+            _ => output.push_str(&token.literal()),
+        }
+    }
+
+    output
+}
+
+// Render a number with a fraction format section like "# ??/??".
+fn render_fraction(tokens: &[Token], slash: usize, number: f64) -> String {
+    let numerator_start = tokens[..slash]
+        .iter()
+        .rposition(|token| !matches!(token, Token::Digit(_)))
+        .map_or(0, |index| index + 1);
+    let integer_end = tokens[..numerator_start]
+        .iter()
+        .rposition(|token| matches!(token, Token::Digit(_)))
+        .map(|index| index + 1);
+
+    // The denominator is either digit placeholders or a fixed number.
+    let mut denominator_end = slash + 1;
+    let mut denominator_placeholders = 0;
+    let mut fixed_denominator = String::new();
+    while let Some(token) = tokens.get(denominator_end) {
+        match token {
+            Token::Digit(_) if fixed_denominator.is_empty() => denominator_placeholders += 1,
+            Token::Literal(literal)
+                if denominator_placeholders == 0
+                    && !literal.is_empty()
+                    && literal.chars().all(|char| char.is_ascii_digit()) =>
+            {
+                fixed_denominator.push_str(literal);
+            }
+            _ => break,
+        }
+        denominator_end += 1;
+    }
+
+    let value = number.abs();
+    let (mut integer, fraction) = match integer_end {
+        Some(_) => (value.trunc(), value.fract()),
+        None => (0.0, value),
+    };
+
+    let (mut numerator, denominator) = match fixed_denominator.parse::<u64>() {
+        Ok(denominator) if denominator > 0 => {
+            ((fraction * denominator as f64).round() as u64, denominator)
+        }
+        _ => {
+            let max_denominator = 10u64.pow(denominator_placeholders.min(5) as u32) - 1;
+            best_fraction(fraction, max_denominator.max(1))
+        }
+    };
+
+    if integer_end.is_some() && numerator == denominator {
+        integer += 1.0;
+        numerator = 0;
+    }
+
+    let is_blank_fraction = integer_end.is_some() && numerator == 0;
+    let integer_digits = if integer > 0.0 {
+        format!("{integer:.0}")
+    } else if numerator == 0 {
+        "0".to_string()
+    } else {
+        String::new()
+    };
+
+    let digit_placeholders = |range: std::ops::Range<usize>| -> Vec<char> {
+        tokens[range]
+            .iter()
+            .filter_map(|token| match token {
+                Token::Digit(char) => Some(*char),
+                _ => None,
+            })
+            .collect()
+    };
+    let integer_output = render_integer(
+        &digit_placeholders(0..integer_end.unwrap_or(0)),
+        &integer_digits,
+        false,
+    );
+    let numerator_output = render_integer(
+        &digit_placeholders(numerator_start..slash),
+        &numerator.to_string(),
+        false,
+    );
+    let denominator_digits: Vec<char> = denominator.to_string().chars().collect();
+
+    let mut output = String::new();
+    if number < 0.0 {
+        output.push('-');
+    }
+
+    let mut integer_count = 0;
+    let mut numerator_count = 0;
+    let mut denominator_count = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        let is_fraction_part = (numerator_start..denominator_end).contains(&index);
+
+        if is_blank_fraction && is_fraction_part {
+            for _ in 0..token.literal().chars().count() {
+                output.push(' ');
+            }
+            continue;
+        }
+
+        match token {
+            Token::Digit(_) if index < numerator_start => {
+                output.push_str(&integer_output[integer_count]);
+                integer_count += 1;
+            }
+            Token::Digit(_) if index < slash => {
+                output.push_str(&numerator_output[numerator_count]);
+                numerator_count += 1;
+            }
+            Token::Digit(placeholder) if index < denominator_end => {
+                match denominator_digits.get(denominator_count) {
+                    Some(digit) => output.push(*digit),
+                    None if *placeholder == '?' => output.push(' '),
+                    None => {}
+                }
+                denominator_count += 1;
+            }
+            _ => output.push_str(&token.literal()),
+        }
+    }
+
+    output
+}
+
+// Find the closest fraction to a number with a maximum denominator, using
+// continued fractions.
+fn best_fraction(number: f64, max_denominator: u64) -> (u64, u64) {
+    let (mut p0, mut q0, mut p1, mut q1) = (0u64, 1u64, 1u64, 0u64);
+    let mut remainder = number;
+
+    loop {
+        let term = remainder.floor();
+        let q2 = q0 + term as u64 * q1;
+        if q2 > max_denominator {
+            break;
+        }
+
+        (p0, q0, p1, q1) = (p1, q1, p0 + term as u64 * p1, q2);
+
+        let fraction = remainder - term;
+        if fraction < 1e-10 {
+            return (p1, q1);
+        }
+        remainder = 1.0 / fraction;
+    }
+
+    // Check the semi-convergent against the last convergent.
+    let k = (max_denominator - q0) / q1;
+    let (p2, q2) = (p0 + k * p1, q0 + k * q1);
+    let error1 = (number - p1 as f64 / q1 as f64).abs();
+    let error2 = (number - p2 as f64 / q2 as f64).abs();
+
+    if error2 < error1 {
+        (p2, q2)
+    } else {
+        (p1, q1)
+    }
+}
+
+// Render a date and/or time with a format section.
+fn render_date_time(tokens: &[Token], number: f64) -> String {
+    if !(0.0..MAX_EXCEL_DATE).contains(&number) {
+        return INVALID_DISPLAY.to_string();
+    }
+
+    // Get the precision of any sub-second, ".00", digits.
+    let mut precision = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        if *token == Token::Point {
+            let digits = tokens[index + 1..]
+                .iter()
+                .take_while(|token| **token == Token::Digit('0'))
+                .count();
+            precision = precision.max(digits.min(3));
+        }
+    }
+
+    let units = 10u64.pow(precision as u32);
+    let total = (number * 86_400.0 * units as f64).round() as u64;
+    let days = total / (86_400 * units);
+    let seconds = total / units;
+    let sub_seconds = format!("{:0precision$}", total % units);
+    let day_seconds = seconds % 86_400;
+
+    let hour = day_seconds / 3600;
+    let minute = day_seconds % 3600 / 60;
+    let second = day_seconds % 60;
+    let (year, month, day) = excel_date_parts(days);
+    let weekday = ((days + 6) % 7) as usize;
+    let is_12_hour = tokens.iter().any(|token| matches!(token, Token::AmPm(..)));
+
+    // An "m" is a minute instead of a month if it follows an hour or precedes
+    // a second.
+    let is_minute = |index: usize| {
+        let previous = tokens[..index].iter().rev().find_map(|token| match token {
+            Token::Date(char, _) | Token::Elapsed(char, _) => Some(*char),
+            _ => None,
+        });
+        let next = tokens[index + 1..].iter().find_map(|token| match token {
+            Token::Date(char, _) | Token::Elapsed(char, _) => Some(*char),
+            _ => None,
+        });
+
+        previous == Some('h') || next == Some('s')
+    };
+
+    let mut output = String::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        match &tokens[index] {
+            Token::Date('y', count) => {
+                if *count <= 2 {
+                    output.push_str(&format!("{:02}", year % 100));
+                } else {
+                    output.push_str(&year.to_string());
+                }
+            }
+            Token::Date('m', count) if is_minute(index) => {
+                output.push_str(&format!("{minute:0width$}", width = (*count).min(2)));
+            }
+            Token::Date('m', count) => {
+                let name = MONTH_NAMES[month - 1];
+                match count {
+                    1 | 2 => output.push_str(&format!("{month:0width$}", width = *count)),
+                    3 => output.push_str(&name[..3]),
+                    4 => output.push_str(name),
+                    _ => output.push_str(&name[..1]),
+                }
+            }
+            Token::Date('d', count) => {
+                let name = DAY_NAMES[weekday];
+                match count {
+                    1 | 2 => output.push_str(&format!("{day:0width$}", width = *count)),
+                    3 => output.push_str(&name[..3]),
+                    _ => output.push_str(name),
+                }
+            }
+            Token::Date('h', count) => {
+                let hour = if is_12_hour {
+                    (hour + 11) % 12 + 1
+                } else {
+                    hour
+                };
+                output.push_str(&format!("{hour:0width$}", width = (*count).min(2)));
+            }
+            Token::Date(_, count) => {
+                output.push_str(&format!("{second:0width$}", width = (*count).min(2)));
+            }
+            Token::Elapsed(char, count) => {
+                let elapsed = match char {
+                    'h' => seconds / 3600,
+                    'm' => seconds / 60,
+                    _ => seconds,
+                };
+                output.push_str(&format!("{elapsed:0width$}", width = *count));
+            }
+            Token::AmPm(am, pm) => {
+                if hour < 12 {
+                    output.push_str(am);
+                } else {
+                    output.push_str(pm);
+                }
+            }
+            Token::Point => {
+                let digits = tokens[index + 1..]
+                    .iter()
+                    .take_while(|token| **token == Token::Digit('0'))
+                    .count();
+                output.push('.');
+                if digits > 0 {
+                    output.push_str(&sub_seconds[..digits.min(precision)]);
+                    index += digits;
+                }
+            }
+            token => output.push_str(&token.literal()),
+        }
+
+        index += 1;
+    }
+
+    output
+}
+
+// Convert Excel serial days to a year, month and day. Excel treats 1900 as a
+// leap year so day 60 is the non-existent 1900-02-29 and day 0 is 1900-01-00.
+fn excel_date_parts(days: u64) -> (i64, usize, u64) {
+    match days {
+        0 => return (1900, 1, 0),
+        60 => return (1900, 2, 29),
+        _ => {}
+    }
+
+    // Get the days relative to the Unix epoch and convert to a civil date.
+    let days = days as i64 - if days < 60 { 25_568 } else { 25_569 } + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month as usize, day as u64)
+}
@@ -0,0 +1,354 @@
+// NumFormat unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod num_format_tests {
+
+    use crate::{
+        Format, NumFormat, NumFormatColor, NumFormatElapsed, NumFormatNegative, XlsxError,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_number_formats() {
+        let tests = [
+            (NumFormat::new(), "General"),
+            (NumFormat::number(0), "0"),
+            (NumFormat::number(2), "0.00"),
+            (NumFormat::number(2).set_thousands_separator(), "#,##0.00"),
+            (NumFormat::number(1).set_scale(1), "0.0,"),
+            (
+                NumFormat::number(1).set_scale(2).set_suffix("M"),
+                r#"0.0,,"M""#,
+            ),
+            (NumFormat::percentage(0), "0%"),
+            (NumFormat::percentage(2), "0.00%"),
+            (NumFormat::scientific(2), "0.00E+00"),
+            (NumFormat::fraction(1), "# ?/?"),
+            (NumFormat::fraction(3), "# ???/???"),
+            (NumFormat::fraction_with_denominator(4), "# ?/4"),
+            (NumFormat::fraction_with_denominator(16), "# ??/16"),
+            (NumFormat::date_time("yyyy-mm-dd"), "yyyy-mm-dd"),
+            (
+                NumFormat::elapsed_time(NumFormatElapsed::Hours),
+                "[h]:mm:ss",
+            ),
+            (
+                NumFormat::elapsed_time(NumFormatElapsed::HoursMinutes),
+                "[h]:mm",
+            ),
+            (
+                NumFormat::elapsed_time(NumFormatElapsed::Minutes),
+                "[mm]:ss",
+            ),
+            (NumFormat::elapsed_time(NumFormatElapsed::Seconds), "[ss]"),
+            (NumFormat::text(), "@"),
+            (NumFormat::raw("0.0##"), "0.0##"),
+        ];
+
+        for (num_format, expected) in tests {
+            assert_eq!(expected, num_format.to_string());
+            assert!(num_format.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_currency_formats() {
+        let tests = [
+            (NumFormat::currency("$", 2), "$#,##0.00"),
+            (NumFormat::currency("€", 0), "[$€]#,##0"),
+            (
+                NumFormat::currency("€", 2).set_locale("x-euro2"),
+                "[$€-x-euro2]#,##0.00",
+            ),
+            (
+                NumFormat::currency("€", 2)
+                    .set_locale("407")
+                    .set_symbol_after(),
+                "#,##0.00 [$€-407]",
+            ),
+            (
+                NumFormat::currency("$", 2).set_negative_style(NumFormatNegative::RedParentheses),
+                "$#,##0.00_);[Red]($#,##0.00)",
+            ),
+            (
+                NumFormat::accounting(2),
+                r#"_(* #,##0.00_);_(* (#,##0.00);_(* "-"??_);_(@_)"#,
+            ),
+            (
+                NumFormat::accounting(0).set_currency_symbol("$"),
+                r#"_($* #,##0_);_($* (#,##0);_($* "-"_);_(@_)"#,
+            ),
+            (
+                NumFormat::accounting(2)
+                    .set_currency_symbol("€")
+                    .set_locale("x-euro2")
+                    .set_symbol_after(),
+                r#"_-* #,##0.00 [$€-x-euro2]_-;-* #,##0.00 [$€-x-euro2]_-;_-* "-"?? [$€-x-euro2]_-;_-@_-"#,
+            ),
+        ];
+
+        for (num_format, expected) in tests {
+            assert_eq!(expected, num_format.to_string());
+            assert!(num_format.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_sections() {
+        let tests = [
+            (
+                NumFormat::number(2).set_negative_style(NumFormatNegative::Red),
+                "0.00;[Red]0.00",
+            ),
+            (
+                NumFormat::number(0).set_negative_style(NumFormatNegative::Parentheses),
+                "0_);(0)",
+            ),
+            (
+                NumFormat::number(0)
+                    .set_condition_greater_than(100.0)
+                    .set_color(NumFormatColor::Blue)
+                    .add_section(
+                        &NumFormat::number(0)
+                            .set_condition_less_than_or_equal_to(-1.5)
+                            .set_color(NumFormatColor::Indexed(10)),
+                    )
+                    .add_section(&NumFormat::number(0)),
+                "[Blue][>100]0;[Color10][<=-1.5]0;0",
+            ),
+            (
+                NumFormat::number(2)
+                    .add_section(&NumFormat::number(2).set_color(NumFormatColor::Red))
+                    .add_section(&NumFormat::raw(r#""-""#))
+                    .add_section(&NumFormat::text()),
+                r#"0.00;[Red]0.00;"-";@"#,
+            ),
+        ];
+
+        for (num_format, expected) in tests {
+            assert_eq!(expected, num_format.to_string());
+            assert!(num_format.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_builtin_index() {
+        assert_eq!(Some(0), NumFormat::new().builtin_index());
+        assert_eq!(Some(2), NumFormat::number(2).builtin_index());
+        assert_eq!(
+            Some(4),
+            NumFormat::number(2)
+                .set_thousands_separator()
+                .builtin_index()
+        );
+        assert_eq!(Some(10), NumFormat::percentage(2).builtin_index());
+        assert_eq!(Some(11), NumFormat::scientific(2).builtin_index());
+        assert_eq!(Some(13), NumFormat::fraction(2).builtin_index());
+        assert_eq!(
+            Some(46),
+            NumFormat::elapsed_time(NumFormatElapsed::Hours).builtin_index()
+        );
+        assert_eq!(Some(49), NumFormat::text().builtin_index());
+        assert_eq!(None, NumFormat::number(3).builtin_index());
+
+        // Built-in formats are converted to an index.
+        let format = Format::try_from(&NumFormat::percentage(2)).unwrap();
+        assert_eq!(format, Format::new().set_num_format_index(10));
+
+        let format = Format::try_from(NumFormat::number(3)).unwrap();
+        assert_eq!(format, Format::new().set_num_format("0.000"));
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let invalid = [
+            NumFormat::raw(""),
+            NumFormat::raw("0.00\""),
+            NumFormat::raw("[Red0.00"),
+            NumFormat::raw("0.00]"),
+            NumFormat::raw("0.00\\"),
+            NumFormat::raw("[Purple]0.00"),
+            NumFormat::raw("0;0;0;0;0"),
+            NumFormat::raw("0;0;[>1]0"),
+            NumFormat::raw("[>abc]0"),
+            NumFormat::raw("x".repeat(256)),
+            NumFormat::number(31),
+            NumFormat::fraction(0),
+            NumFormat::fraction(6),
+            NumFormat::fraction_with_denominator(1),
+            NumFormat::fraction_with_denominator(100_000),
+            NumFormat::number(0).set_color(NumFormatColor::Indexed(57)),
+            NumFormat::number(0).set_condition_equal_to(f64::NAN),
+            NumFormat::number(0)
+                .add_section(&NumFormat::number(0).set_negative_style(NumFormatNegative::Red))
+                .add_section(&NumFormat::number(0))
+                .add_section(&NumFormat::text()),
+        ];
+
+        for num_format in invalid {
+            let result = num_format.validate();
+            assert!(
+                matches!(result, Err(XlsxError::ParameterError(_))),
+                "{num_format}"
+            );
+        }
+
+        // Invalid formats return an error in the conversion to a Format.
+        let result = Format::try_from(&NumFormat::raw("[Purple]0"));
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // Escaped and quoted brackets and separators are literals.
+        let valid = [
+            r#"0.00" [x];"@"#,
+            r"0\;0\[",
+            r#"[$-409]dddd, mmmm dd, yyyy"#,
+            "[DBNum1]General",
+            "[hh]:mm",
+        ];
+
+        for format_code in valid {
+            assert!(
+                NumFormat::raw(format_code).validate().is_ok(),
+                "{format_code}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_number() {
+        let tests: Vec<(f64, &str, &str)> = vec![
+            // General.
+            (0.0, "General", "0"),
+            (123.0, "General", "123"),
+            (-1.5, "General", "-1.5"),
+            (0.1 + 0.2, "General", "0.3"),
+            (1.0 / 3.0, "General", "0.333333333"),
+            (123_456_789_012.0, "General", "1.23457E+11"),
+            (0.000_000_000_1, "General", "1E-10"),
+            (1234.5, "", "1234.5"),
+            // Digit placeholders and decimals.
+            (1234.5, "0", "1235"),
+            (2.5, "0", "3"),
+            (1.005, "0.00", "1.01"),
+            (0.5, "#.##", ".5"),
+            (0.0, "#", ""),
+            (5.0, "000", "005"),
+            (1.5, "0.0?", "1.5 "),
+            (-3.0, "0.00", "-3.00"),
+            (1_234_567.891, "#,##0.00", "1,234,567.89"),
+            (12.0, "#,##0", "12"),
+            (5.0, "0,000", "0,005"),
+            (123_456_789.0, "000-00-0000", "123-45-6789"),
+            // Scaling, percentages and scientific.
+            (1_234_567.0, "0.0,", "1234.6"),
+            (1_234_567.0, "0.0,,\"M\"", "1.2M"),
+            (0.256, "0%", "26%"),
+            (0.256, "0.00%", "25.60%"),
+            (12345.0, "0.00E+00", "1.23E+04"),
+            (0.000_123, "0.00E+00", "1.23E-04"),
+            (0.0, "0.00E+00", "0.00E+00"),
+            (99999.0, "0.00E+00", "1.00E+05"),
+            (12345.0, "##0.0E+0", "12.3E+3"),
+            (1_234_567.0, "##0.0E+0", "1.2E+6"),
+            // Sections and conditions.
+            (-5.0, "0;(0)", "(5)"),
+            (0.0, "0;(0);\"zero\"", "zero"),
+            (-5.0, "0;;", ""),
+            (5.0, "[>3]\"High\";\"Low\"", "High"),
+            (2.0, "[>3]\"High\";\"Low\"", "Low"),
+            (150.0, "[Blue][>100]0;[Red][<0]0", "150"),
+            (50.0, "[Blue][>100]0;[Red][<0]0", "########"),
+            (5.0, "[Red]0.00", "5.00"),
+            (-5.0, "0.00;[Red]0.00", "5.00"),
+            (5.0, "@", "5"),
+            (5.0, "\"Total: \"General", "Total: 5"),
+            // Currency, accounting and literals.
+            (1234.5, "$#,##0.00", "$1,234.50"),
+            (-1234.5, "$#,##0.00", "-$1,234.50"),
+            (1234.5, "[$€-x-euro2] #,##0.00", "€ 1,234.50"),
+            (1234.0, "_($* #,##0_)", " $1,234 "),
+            (-1234.0, "_($* #,##0_);_($* (#,##0)", " $(1,234)"),
+            (0.0, "_($* #,##0_);_($* (#,##0);_($* \"-\"_)", " $- "),
+            (5.0, "0\" units\"", "5 units"),
+            (5.0, "0\\x", "5x"),
+            // Fractions.
+            (1.25, "# ?/?", "1 1/4"),
+            (0.5, "# ?/?", " 1/2"),
+            (3.0, "# ?/?", "3    "),
+            (0.0, "# ?/?", "0    "),
+            (1.333, "# ??/??", "1  1/3 "),
+            (std::f64::consts::PI, "# ???/???", "3  16/113"),
+            (1.3, "# ?/4", "1 1/4"),
+            (1.4, "# ??/16", "1  6/16"),
+            (1.25, "?/?", "5/4"),
+            (-0.75, "# ?/?", "- 3/4"),
+            (0.99, "# ?/?", "1    "),
+        ];
+
+        for (number, num_format, expected) in tests {
+            assert_eq!(
+                expected,
+                crate::format_number(number, num_format),
+                "format: {num_format}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_datetime() {
+        let tests = [
+            // Dates.
+            (44_951.604_687_5, "yyyy-mm-dd", "2023-01-25"),
+            (44_951.604_687_5, "m/d/yy", "1/25/23"),
+            (44_951.604_687_5, "mm/dd/yyyy", "01/25/2023"),
+            (44_951.604_687_5, "d-mmm-yy", "25-Jan-23"),
+            (44_951.604_687_5, "mmmm d, yyyy", "January 25, 2023"),
+            (44_951.604_687_5, "mmmmm", "J"),
+            (44_951.604_687_5, "ddd dd", "Wed 25"),
+            (44_951.604_687_5, "dddd", "Wednesday"),
+            (44_951.604_687_5, "[$-409]mmm yyyy", "Jan 2023"),
+            (1.0, "yyyy-mm-dd", "1900-01-01"),
+            (59.0, "yyyy-mm-dd", "1900-02-28"),
+            (60.0, "yyyy-mm-dd", "1900-02-29"),
+            (61.0, "yyyy-mm-dd dddd", "1900-03-01 Thursday"),
+            (0.0, "m/d/yyyy", "1/0/1900"),
+            (2_958_465.0, "yyyy-mm-dd", "9999-12-31"),
+            (36_526.0, "DD/MM/YYYY", "01/01/2000"),
+            // Times.
+            (44_951.604_687_5, "h:mm", "14:30"),
+            (44_951.604_687_5, "hh:mm:ss", "14:30:45"),
+            (44_951.604_687_5, "h:mm AM/PM", "2:30 PM"),
+            (44_951.604_687_5, "h:mm:ss a/p", "2:30:45 p"),
+            (0.0, "h:mm AM/PM", "12:00 AM"),
+            (0.5, "h AM/PM", "12 PM"),
+            (0.000_005_8, "mm:ss.0", "00:00.5"),
+            (0.999_999_999, "hh:mm:ss", "00:00:00"),
+            (44_951.604_687_5, "m/d/yy h:mm", "1/25/23 14:30"),
+            (
+                44_951.604_687_5,
+                "yyyy-mm-ddThh:mm:ss",
+                "2023-01-25T14:30:45",
+            ),
+            // Elapsed times.
+            (1.25, "[h]:mm", "30:00"),
+            (1.25, "[hh]:mm:ss", "30:00:00"),
+            (0.125, "[mm]:ss", "180:00"),
+            (0.001, "[ss]", "86"),
+            // Out of range dates.
+            (-1.0, "yyyy-mm-dd", "########"),
+            (2_958_466.0, "yyyy-mm-dd", "########"),
+        ];
+
+        for (number, num_format, expected) in tests {
+            assert_eq!(
+                expected,
+                crate::format_number(number, num_format),
+                "format: {num_format}"
+            );
+        }
+    }
+}
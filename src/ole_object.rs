@@ -0,0 +1,701 @@
+// ole_object - A module for handling embedded OLE objects.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::fs::File;
+use std::io::BufReader;
+use std::io::Read;
+use std::path::Path;
+
+use crate::drawing::{DrawingObject, DrawingType};
+use crate::vml::VmlInfo;
+use crate::{Image, ObjectMovement, XlsxError};
+
+// Compound File Binary constants. See [MS-CFB].
+const SECTOR_SIZE: usize = 512;
+const MINI_SECTOR_SIZE: usize = 64;
+const MINI_STREAM_CUTOFF: usize = 4096;
+const NUM_HEADER_DIFAT_ENTRIES: usize = 109;
+const ENTRIES_PER_SECTOR: usize = SECTOR_SIZE / 4;
+const END_OF_CHAIN: u32 = 0xFFFF_FFFE;
+const FREE_SECTOR: u32 = 0xFFFF_FFFF;
+const FAT_SECTOR: u32 = 0xFFFF_FFFD;
+const DIFAT_SECTOR: u32 = 0xFFFF_FFFC;
+const NO_STREAM: u32 = 0xFFFF_FFFF;
+
+// The CLSID for an OLE Package object: {0003000C-0000-0000-C000-000000000046}.
+const PACKAGE_CLSID: [u8; 16] = [
+    0x0C, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46,
+];
+
+/// The `OleObject` struct represents a file embedded in a worksheet.
+///
+/// The `OleObject` struct is used to embed an arbitrary file, such as a CSV,
+/// PDF or text file, into a worksheet as an OLE "Package" object. The object
+/// is displayed in the worksheet as an icon or preview image and the user can
+/// double-click on it in Excel to open the embedded file with its associated
+/// application.
+///
+/// The display image is a standard [`Image`] and it is required. Excel doesn't
+/// generate an icon for the object when the file is opened so the image is
+/// what the user will see in the worksheet.
+///
+/// It is used in conjunction with the
+/// [`Worksheet::insert_object()`](crate::Worksheet::insert_object) method.
+///
+/// # Examples
+///
+/// An example of embedding a file in a worksheet as an icon.
+///
+/// ```
+/// # // This code is available in examples/doc_ole_object.rs
+/// #
+/// use rust_xlsxwriter::{Image, OleObject, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     // Create a new Excel file object.
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///
+///     worksheet.write(0, 0, "Double-click the icon to open the source data:")?;
+///
+///     // Create an image to display the embedded object.
+///     let image = Image::new("examples/rust_logo.png")?
+///         .set_scale_width(0.5)
+///         .set_scale_height(0.5);
+///
+///     // Create an object to embed some CSV data.
+///     let object = OleObject::new_from_buffer(b"Region,Sales\nEast,100\n", "sales.csv")
+///         .set_image(&image);
+///
+///     // Insert the object into the worksheet.
+///     worksheet.insert_object(1, 1, &object)?;
+///
+///     // Save the file to disk.
+///     workbook.save("ole_object.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct OleObject {
+    pub(crate) x_offset: u32,
+    pub(crate) y_offset: u32,
+    pub(crate) data: Vec<u8>,
+    pub(crate) filename: String,
+    pub(crate) image: Option<Image>,
+    pub(crate) display_as_icon: bool,
+    pub(crate) alt_text: String,
+    pub(crate) object_movement: ObjectMovement,
+}
+
+impl OleObject {
+    // -----------------------------------------------------------------------
+    // Public (and crate public) methods.
+    // -----------------------------------------------------------------------
+
+    /// Create a new `OleObject` from a file.
+    ///
+    /// Create an `OleObject` from a path to a file. The contents of the file
+    /// are read and stored in the object. The file name, without the
+    /// directory, is used as the label of the embedded file in Excel.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the file to embed, as a `&str` or as a
+    ///   [`std::path`] `Path` or `PathBuf` instance.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::IoError`] - I/O errors if the path doesn't exist or is
+    ///   restricted.
+    ///
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<OleObject, XlsxError> {
+        let path_buf = path.as_ref().to_path_buf();
+
+        let filename = match path_buf.file_name() {
+            Some(filename) => filename.to_string_lossy().to_string(),
+            None => "object".to_string(),
+        };
+
+        let file = File::open(path_buf)?;
+        let mut reader = BufReader::new(file);
+        let mut data = vec![];
+        reader.read_to_end(&mut data)?;
+
+        Ok(Self::new_from_buffer(&data, filename))
+    }
+
+    /// Create a new `OleObject` from a `u8` buffer.
+    ///
+    /// This method is similar to [`OleObject::new()`], see above, except the
+    /// file data can be in a buffer instead of a file path.
+    ///
+    /// # Parameters
+    ///
+    /// - `buffer`: The file data as a u8 buffer.
+    /// - `filename`: The file name used to label the embedded file. Excel uses
+    ///   the extension of this name to determine the application that opens
+    ///   the file.
+    ///
+    pub fn new_from_buffer(buffer: &[u8], filename: impl Into<String>) -> OleObject {
+        OleObject {
+            x_offset: 0,
+            y_offset: 0,
+            data: buffer.to_vec(),
+            filename: filename.into(),
+            image: None,
+            display_as_icon: true,
+            alt_text: String::new(),
+            object_movement: ObjectMovement::MoveButDontSizeWithCells,
+        }
+    }
+
+    /// Set the image used to display the object in the worksheet.
+    ///
+    /// The image is displayed in the worksheet in place of the embedded file.
+    /// It is generally an icon representing the file type or a preview of the
+    /// contents of the file. The size of the object in the worksheet is taken
+    /// from the image, including any scaling set with
+    /// [`Image::set_scale_width()`] and [`Image::set_scale_height()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `image`: The [`Image`] used to display the object.
+    ///
+    pub fn set_image(mut self, image: &Image) -> OleObject {
+        self.image = Some(image.clone());
+        self
+    }
+
+    /// Display the object as an icon or as content.
+    ///
+    /// By default the object is displayed as an icon, which is the Excel
+    /// default for embedded files. If the image set with
+    /// [`OleObject::set_image()`] is a preview of the file contents then this
+    /// option can be turned off to tell Excel that the object is displayed as
+    /// content.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_display_as_icon(mut self, enable: bool) -> OleObject {
+        self.display_as_icon = enable;
+        self
+    }
+
+    /// Set the alt text for the object to help accessibility.
+    ///
+    /// The alt text is used with screen readers to help people with visual
+    /// disabilities.
+    ///
+    /// # Parameters
+    ///
+    /// - `alt_text`: The alt text string to add to the object.
+    ///
+    pub fn set_alt_text(mut self, alt_text: impl Into<String>) -> OleObject {
+        let alt_text = alt_text.into();
+        if alt_text.chars().count() > 255 {
+            eprintln!("Alternative text is greater than Excel's limit of 255 characters.");
+            return self;
+        }
+
+        self.alt_text = alt_text;
+        self
+    }
+
+    /// Set the object's movement options when cells are resized.
+    ///
+    /// Set the option to define how an object will behave in Excel if the cells
+    /// under the object are moved, deleted, or have their size changed. See
+    /// [`ObjectMovement`] for the available options. The default for embedded
+    /// objects is [`ObjectMovement::MoveButDontSizeWithCells`].
+    ///
+    /// # Parameters
+    ///
+    /// - `option`: An [`ObjectMovement`] enum value.
+    ///
+    pub fn set_object_movement(mut self, option: ObjectMovement) -> OleObject {
+        self.object_movement = option;
+        self
+    }
+
+    // OLE objects are stored in a vmlDrawing file. We create a struct to store
+    // the required information in that format.
+    pub(crate) fn vml_info(&self) -> VmlInfo {
+        VmlInfo {
+            width: self.width_scaled(),
+            height: self.height_scaled(),
+            alt_text: self.alt_text.clone(),
+            ..Default::default()
+        }
+    }
+
+    // Get the Excel "dvAspect" value for the object.
+    pub(crate) fn dv_aspect(&self) -> &str {
+        if self.display_as_icon {
+            "DVASPECT_ICON"
+        } else {
+            "DVASPECT_CONTENT"
+        }
+    }
+
+    // Create the binary data for the xl/embeddings/oleObjectN.bin file. This is
+    // a Compound File Binary container with the OLE Package object streams.
+    pub(crate) fn ole_data(&self) -> Result<Vec<u8>, XlsxError> {
+        let streams = vec![
+            ("\u{1}CompObj", Self::comp_obj_stream()),
+            ("\u{1}Ole10Native", self.ole10_native_stream()?),
+        ];
+
+        Ok(compound_file(&PACKAGE_CLSID, streams))
+    }
+
+    // Check that the size of the embedded file fits in the 32 bit size fields
+    // of the OLE Package streams.
+    pub(crate) fn check_size(&self) -> Result<(), XlsxError> {
+        self.ole10_native_stream().map(|_| ())
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal methods.
+    // -----------------------------------------------------------------------
+
+    // Create the "\x01CompObj" stream that identifies the object as an OLE
+    // Package. See [MS-OLEDS] 2.3.8 CompObjStream.
+    fn comp_obj_stream() -> Vec<u8> {
+        let mut stream = vec![];
+
+        // Header: reserved, version, reserved and the object CLSID.
+        stream.extend_from_slice(&[0x01, 0x00, 0xFE, 0xFF, 0x03, 0x0A, 0x00, 0x00]);
+        stream.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF]);
+        stream.extend_from_slice(&PACKAGE_CLSID);
+
+        // AnsiUserType.
+        push_ansi_string(&mut stream, "OLE Package");
+
+        // AnsiClipboardFormat: not present.
+        stream.extend_from_slice(&0u32.to_le_bytes());
+
+        // Reserved1: the ProgID.
+        push_ansi_string(&mut stream, "Package");
+
+        // UnicodeMarker, followed by empty UnicodeUserType,
+        // UnicodeClipboardFormat and Reserved2 fields.
+        stream.extend_from_slice(&0x71B2_39F4_u32.to_le_bytes());
+        stream.extend_from_slice(&[0; 12]);
+
+        stream
+    }
+
+    // Create the "\x01Ole10Native" stream that contains the embedded file.
+    fn ole10_native_stream(&self) -> Result<Vec<u8>, XlsxError> {
+        let filename = ansi_bytes(&self.filename);
+
+        let mut native = vec![];
+
+        // Header/type marker.
+        native.extend_from_slice(&2u16.to_le_bytes());
+
+        // The label and the original file path.
+        native.extend_from_slice(&filename);
+        native.push(0);
+        native.extend_from_slice(&filename);
+        native.push(0);
+
+        // Reserved marker.
+        native.extend_from_slice(&[0x00, 0x00, 0x03, 0x00]);
+
+        // The temporary file path used when the object is activated.
+        native.extend_from_slice(&self.ole10_native_size(filename.len() + 1)?.to_le_bytes());
+        native.extend_from_slice(&filename);
+        native.push(0);
+
+        // The embedded file data.
+        native.extend_from_slice(&self.ole10_native_size(self.data.len())?.to_le_bytes());
+        native.extend_from_slice(&self.data);
+
+        // The stream is prefixed with the total size of the native data.
+        let mut stream = Vec::with_capacity(native.len() + 4);
+        stream.extend_from_slice(&self.ole10_native_size(native.len())?.to_le_bytes());
+        stream.extend_from_slice(&native);
+
+        Ok(stream)
+    }
+
+    // Convert a size in the "\x01Ole10Native" stream to the 32 bit value
+    // stored in the file.
+    fn ole10_native_size(&self, size: usize) -> Result<u32, XlsxError> {
+        u32::try_from(size).map_err(|_| {
+            XlsxError::ParameterError(format!(
+                "OleObject '{}' data size exceeds the 4GB limit of an OLE object",
+                self.filename
+            ))
+        })
+    }
+}
+
+// Trait for objects that have a component stored in the drawing.xml file.
+impl DrawingObject for OleObject {
+    fn x_offset(&self) -> u32 {
+        self.x_offset
+    }
+
+    fn y_offset(&self) -> u32 {
+        self.y_offset
+    }
+
+    fn absolute_position(&self) -> Option<(u32, u32)> {
+        None
+    }
+
+    fn width_scaled(&self) -> f64 {
+        match &self.image {
+            Some(image) => image.width_scaled(),
+            None => 0.0,
+        }
+    }
+
+    fn height_scaled(&self) -> f64 {
+        match &self.image {
+            Some(image) => image.height_scaled(),
+            None => 0.0,
+        }
+    }
+
+    fn object_movement(&self) -> ObjectMovement {
+        self.object_movement
+    }
+
+    fn name(&self) -> String {
+        self.filename.clone()
+    }
+
+    fn alt_text(&self) -> String {
+        self.alt_text.clone()
+    }
+
+    // OLE objects are only stored in the VML file, which doesn't support the
+    // decorative property.
+    fn decorative(&self) -> bool {
+        false
+    }
+
+    fn drawing_type(&self) -> DrawingType {
+        DrawingType::Vml
+    }
+}
+
+// -----------------------------------------------------------------------
+// Helper functions.
+// -----------------------------------------------------------------------
+
+// Convert a string to single byte "ANSI" characters. Characters outside the
+// Latin-1 range are replaced with "_".
+fn ansi_bytes(string: &str) -> Vec<u8> {
+    string
+        .chars()
+        .map(|c| u8::try_from(u32::from(c)).unwrap_or(b'_'))
+        .collect()
+}
+
+// Write a length prefixed, null terminated, ANSI string.
+fn push_ansi_string(buffer: &mut Vec<u8>, string: &str) {
+    let bytes = ansi_bytes(string);
+    buffer.extend_from_slice(&(bytes.len() as u32 + 1).to_le_bytes());
+    buffer.extend_from_slice(&bytes);
+    buffer.push(0);
+}
+
+// Create a Compound File Binary (version 3) file with a root storage and a
+// number of streams. The streams are written to the mini stream if they are
+// less than the mini stream cutoff size, otherwise they are written to
+// standard sectors. See [MS-CFB] for details of the format.
+pub(crate) fn compound_file(clsid: &[u8; 16], mut streams: Vec<(&str, Vec<u8>)>) -> Vec<u8> {
+    // Directory entries are stored in a tree ordered by name length and then
+    // by uppercase name.
+    streams.sort_by_key(|(name, _)| (name.encode_utf16().count(), name.to_uppercase()));
+
+    let mut fat: Vec<u32> = vec![];
+    let mut sectors: Vec<u8> = vec![];
+
+    // Write the small streams to the mini stream and the mini FAT.
+    let mut mini_fat: Vec<u32> = vec![];
+    let mut mini_stream: Vec<u8> = vec![];
+    let mut stream_starts = vec![];
+
+    for (_, data) in &streams {
+        if data.len() < MINI_STREAM_CUTOFF {
+            let start = allocate_chain(&mut mini_fat, &mut mini_stream, data, MINI_SECTOR_SIZE);
+            stream_starts.push(start);
+        } else {
+            stream_starts.push(0);
+        }
+    }
+
+    // The mini stream is stored as the root entry stream.
+    let mini_stream_size = mini_stream.len();
+    let mini_stream_start = allocate_chain(&mut fat, &mut sectors, &mini_stream, SECTOR_SIZE);
+
+    // Write the large streams to standard sectors.
+    for (i, (_, data)) in streams.iter().enumerate() {
+        if data.len() >= MINI_STREAM_CUTOFF {
+            stream_starts[i] = allocate_chain(&mut fat, &mut sectors, data, SECTOR_SIZE);
+        }
+    }
+
+    // Write the mini FAT sectors.
+    let num_mini_fat_sectors = mini_fat.len().div_ceil(ENTRIES_PER_SECTOR);
+    mini_fat.resize(num_mini_fat_sectors * ENTRIES_PER_SECTOR, FREE_SECTOR);
+    let mini_fat_bytes: Vec<u8> = mini_fat.iter().flat_map(|id| id.to_le_bytes()).collect();
+    let mini_fat_start = allocate_chain(&mut fat, &mut sectors, &mini_fat_bytes, SECTOR_SIZE);
+
+    // Write the directory sectors.
+    let directory = directory_entries(
+        clsid,
+        &streams,
+        &stream_starts,
+        mini_stream_start,
+        mini_stream_size,
+    );
+    let directory_start = allocate_chain(&mut fat, &mut sectors, &directory, SECTOR_SIZE);
+
+    // Calculate the number of FAT and DIFAT sectors required to map all the
+    // sectors, including themselves.
+    let num_data_sectors = fat.len();
+    let mut num_fat_sectors: usize = 1;
+    let mut num_difat_sectors;
+    loop {
+        num_difat_sectors = num_fat_sectors
+            .saturating_sub(NUM_HEADER_DIFAT_ENTRIES)
+            .div_ceil(ENTRIES_PER_SECTOR - 1);
+
+        if num_fat_sectors * ENTRIES_PER_SECTOR
+            >= num_data_sectors + num_fat_sectors + num_difat_sectors
+        {
+            break;
+        }
+        num_fat_sectors += 1;
+    }
+
+    let fat_sector_ids: Vec<u32> = (0..num_fat_sectors)
+        .map(|i| (num_data_sectors + i) as u32)
+        .collect();
+    let difat_sector_ids: Vec<u32> = (0..num_difat_sectors)
+        .map(|i| (num_data_sectors + num_fat_sectors + i) as u32)
+        .collect();
+
+    fat.resize(num_data_sectors + num_fat_sectors, FAT_SECTOR);
+    fat.resize(
+        num_data_sectors + num_fat_sectors + num_difat_sectors,
+        DIFAT_SECTOR,
+    );
+    fat.resize(num_fat_sectors * ENTRIES_PER_SECTOR, FREE_SECTOR);
+
+    for id in &fat {
+        sectors.extend_from_slice(&id.to_le_bytes());
+    }
+
+    // Write any DIFAT sectors for FAT sectors that don't fit in the header.
+    let overflow_fat_ids: Vec<u32> = fat_sector_ids
+        .iter()
+        .skip(NUM_HEADER_DIFAT_ENTRIES)
+        .copied()
+        .collect();
+
+    for (i, chunk) in overflow_fat_ids.chunks(ENTRIES_PER_SECTOR - 1).enumerate() {
+        let mut difat = chunk.to_vec();
+        difat.resize(ENTRIES_PER_SECTOR - 1, FREE_SECTOR);
+        difat.push(*difat_sector_ids.get(i + 1).unwrap_or(&END_OF_CHAIN));
+
+        for id in &difat {
+            sectors.extend_from_slice(&id.to_le_bytes());
+        }
+    }
+
+    // Write the header sector.
+    let mut header = Vec::with_capacity(SECTOR_SIZE + sectors.len());
+    header.extend_from_slice(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]);
+    header.extend_from_slice(&[0; 16]);
+    header.extend_from_slice(&0x003E_u16.to_le_bytes());
+    header.extend_from_slice(&0x0003_u16.to_le_bytes());
+    header.extend_from_slice(&0xFFFE_u16.to_le_bytes());
+    header.extend_from_slice(&0x0009_u16.to_le_bytes());
+    header.extend_from_slice(&0x0006_u16.to_le_bytes());
+    header.extend_from_slice(&[0; 6]);
+    header.extend_from_slice(&0u32.to_le_bytes());
+    header.extend_from_slice(&(num_fat_sectors as u32).to_le_bytes());
+    header.extend_from_slice(&directory_start.to_le_bytes());
+    header.extend_from_slice(&0u32.to_le_bytes());
+    header.extend_from_slice(&(MINI_STREAM_CUTOFF as u32).to_le_bytes());
+    header.extend_from_slice(&mini_fat_start.to_le_bytes());
+    header.extend_from_slice(&(num_mini_fat_sectors as u32).to_le_bytes());
+    header.extend_from_slice(
+        &difat_sector_ids
+            .first()
+            .unwrap_or(&END_OF_CHAIN)
+            .to_le_bytes(),
+    );
+    header.extend_from_slice(&(num_difat_sectors as u32).to_le_bytes());
+
+    for i in 0..NUM_HEADER_DIFAT_ENTRIES {
+        let id = fat_sector_ids.get(i).unwrap_or(&FREE_SECTOR);
+        header.extend_from_slice(&id.to_le_bytes());
+    }
+
+    header.extend_from_slice(&sectors);
+    header
+}
+
+// Append data to a sector buffer, padded to the sector size, and add the
+// corresponding chain to the allocation table. Returns the start sector.
+fn allocate_chain(table: &mut Vec<u32>, buffer: &mut Vec<u8>, data: &[u8], size: usize) -> u32 {
+    if data.is_empty() {
+        return END_OF_CHAIN;
+    }
+
+    let start = table.len();
+    let num_sectors = data.len().div_ceil(size);
+
+    for i in 0..num_sectors {
+        if i == num_sectors - 1 {
+            table.push(END_OF_CHAIN);
+        } else {
+            table.push((start + i + 1) as u32);
+        }
+    }
+
+    buffer.extend_from_slice(data);
+    buffer.resize(buffer.len() + (num_sectors * size - data.len()), 0);
+
+    start as u32
+}
+
+// Create the directory entries for the root storage and the streams. The
+// streams are arranged as a balanced binary tree which is colored as a valid
+// red-black tree by making the nodes on the lowest level red.
+fn directory_entries(
+    clsid: &[u8; 16],
+    streams: &[(&str, Vec<u8>)],
+    stream_starts: &[u32],
+    mini_stream_start: u32,
+    mini_stream_size: usize,
+) -> Vec<u8> {
+    let num_streams = streams.len();
+    let mut left = vec![NO_STREAM; num_streams];
+    let mut right = vec![NO_STREAM; num_streams];
+    let mut depth = vec![0; num_streams];
+
+    let root_child = build_tree(0, num_streams, 0, &mut left, &mut right, &mut depth);
+    let max_depth = depth.iter().copied().max().unwrap_or(0);
+
+    let mut directory = vec![];
+
+    directory_entry(
+        &mut directory,
+        "Root Entry",
+        5,
+        true,
+        (NO_STREAM, NO_STREAM, root_child),
+        clsid,
+        mini_stream_start,
+        mini_stream_size,
+    );
+
+    for (i, (name, data)) in streams.iter().enumerate() {
+        let is_black = depth[i] == 0 || depth[i] < max_depth;
+
+        directory_entry(
+            &mut directory,
+            name,
+            2,
+            is_black,
+            (left[i], right[i], NO_STREAM),
+            &[0; 16],
+            stream_starts[i],
+            data.len(),
+        );
+    }
+
+    // Pad the directory sector with unused entries.
+    while directory.len() % SECTOR_SIZE != 0 {
+        directory_entry(
+            &mut directory,
+            "",
+            0,
+            false,
+            (NO_STREAM, NO_STREAM, NO_STREAM),
+            &[0; 16],
+            0,
+            0,
+        );
+    }
+
+    directory
+}
+
+// Build a balanced binary tree from a range of sorted stream indices. The
+// directory ids are offset by 1 for the root entry.
+fn build_tree(
+    first: usize,
+    last: usize,
+    level: usize,
+    left: &mut [u32],
+    right: &mut [u32],
+    depth: &mut [usize],
+) -> u32 {
+    if first >= last {
+        return NO_STREAM;
+    }
+
+    let middle = (first + last) / 2;
+    depth[middle] = level;
+    left[middle] = build_tree(first, middle, level + 1, left, right, depth);
+    right[middle] = build_tree(middle + 1, last, level + 1, left, right, depth);
+
+    middle as u32 + 1
+}
+
+// Write a 128 byte directory entry.
+#[allow(clippy::too_many_arguments)]
+fn directory_entry(
+    directory: &mut Vec<u8>,
+    name: &str,
+    object_type: u8,
+    is_black: bool,
+    siblings: (u32, u32, u32),
+    clsid: &[u8; 16],
+    start: u32,
+    size: usize,
+) {
+    let mut name_bytes: Vec<u8> = name.encode_utf16().flat_map(u16::to_le_bytes).collect();
+    let name_length = if name.is_empty() {
+        0
+    } else {
+        name_bytes.len() as u16 + 2
+    };
+    name_bytes.resize(64, 0);
+
+    directory.extend_from_slice(&name_bytes);
+    directory.extend_from_slice(&name_length.to_le_bytes());
+    directory.push(object_type);
+    directory.push(u8::from(is_black));
+    directory.extend_from_slice(&siblings.0.to_le_bytes());
+    directory.extend_from_slice(&siblings.1.to_le_bytes());
+    directory.extend_from_slice(&siblings.2.to_le_bytes());
+    directory.extend_from_slice(clsid);
+
+    // State bits, creation and modification times.
+    directory.extend_from_slice(&[0; 20]);
+
+    directory.extend_from_slice(&start.to_le_bytes());
+    directory.extend_from_slice(&(size as u64).to_le_bytes());
+}
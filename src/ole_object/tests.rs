@@ -0,0 +1,334 @@
+// OleObject unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod ole_object_tests {
+
+    use crate::ole_object::compound_file;
+    use crate::{OleObject, XlsxError};
+    use pretty_assertions::assert_eq;
+
+    fn read_u32(data: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+    }
+
+    // Simple Compound File reader used to verify the structure of the files
+    // created by compound_file(). Returns the stream names and data.
+    fn read_compound_file(data: &[u8]) -> Vec<(String, Vec<u8>)> {
+        assert_eq!(
+            &data[0..8],
+            &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]
+        );
+        assert_eq!(0, (data.len() - 512) % 512);
+
+        let sector = |id: u32| &data[512 + id as usize * 512..512 + (id as usize + 1) * 512];
+
+        // Read the FAT sector ids from the header and DIFAT sectors.
+        let num_fat_sectors = read_u32(data, 0x2C) as usize;
+        let mut fat_sector_ids = vec![];
+        for i in 0..109 {
+            fat_sector_ids.push(read_u32(data, 0x4C + i * 4));
+        }
+        let mut difat_id = read_u32(data, 0x44);
+        while difat_id != 0xFFFF_FFFE {
+            let difat = sector(difat_id);
+            for i in 0..127 {
+                fat_sector_ids.push(read_u32(difat, i * 4));
+            }
+            difat_id = read_u32(difat, 127 * 4);
+        }
+        fat_sector_ids.truncate(num_fat_sectors);
+
+        let mut fat = vec![];
+        for id in fat_sector_ids {
+            let fat_sector = sector(id);
+            for i in 0..128 {
+                fat.push(read_u32(fat_sector, i * 4));
+            }
+        }
+
+        let read_chain = |start: u32, fat: &[u32]| {
+            let mut buffer = vec![];
+            let mut id = start;
+            while id != 0xFFFF_FFFE {
+                buffer.extend_from_slice(sector(id));
+                id = fat[id as usize];
+            }
+            buffer
+        };
+
+        // Read the mini FAT.
+        let mini_fat_bytes = read_chain(read_u32(data, 0x3C), &fat);
+        let mini_fat: Vec<u32> = (0..mini_fat_bytes.len() / 4)
+            .map(|i| read_u32(&mini_fat_bytes, i * 4))
+            .collect();
+
+        // Read the directory entries.
+        let directory = read_chain(read_u32(data, 0x30), &fat);
+        let root_start = read_u32(&directory, 0x74);
+        let mini_stream = read_chain(root_start, &fat);
+
+        let mut streams = vec![];
+        for entry in directory.chunks(128).skip(1) {
+            if entry[0x42] != 2 {
+                continue;
+            }
+
+            let name_length = u16::from_le_bytes([entry[0x40], entry[0x41]]) as usize;
+            let name: Vec<u16> = (0..name_length / 2 - 1)
+                .map(|i| u16::from_le_bytes([entry[i * 2], entry[i * 2 + 1]]))
+                .collect();
+            let name = String::from_utf16(&name).unwrap();
+
+            let start = read_u32(entry, 0x74);
+            let size = read_u32(entry, 0x78) as usize;
+
+            let mut stream = if size < 4096 {
+                let mut buffer = vec![];
+                let mut id = start;
+                while id != 0xFFFF_FFFE {
+                    let offset = id as usize * 64;
+                    buffer.extend_from_slice(&mini_stream[offset..offset + 64]);
+                    id = mini_fat[id as usize];
+                }
+                buffer
+            } else {
+                read_chain(start, &fat)
+            };
+
+            stream.truncate(size);
+            streams.push((name, stream));
+        }
+
+        streams
+    }
+
+    #[test]
+    fn test_compound_file_small_streams() {
+        let streams = vec![("Bbb", vec![2u8; 100]), ("Aa", vec![1u8; 65])];
+
+        let data = compound_file(&[0; 16], streams);
+        let got = read_compound_file(&data);
+
+        // Streams are sorted by name length and then by name.
+        let expected = vec![
+            ("Aa".to_string(), vec![1u8; 65]),
+            ("Bbb".to_string(), vec![2u8; 100]),
+        ];
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_compound_file_large_streams() {
+        let large: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        let streams = vec![("Small", vec![7u8; 10]), ("Large", large.clone())];
+
+        let data = compound_file(&[0; 16], streams);
+        let got = read_compound_file(&data);
+
+        let expected = vec![
+            ("Large".to_string(), large),
+            ("Small".to_string(), vec![7u8; 10]),
+        ];
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_compound_file_difat_sectors() {
+        // Data that requires more than 109 FAT sectors.
+        let large: Vec<u8> = (0..8_000_000u32).map(|i| (i % 253) as u8).collect();
+        let streams = vec![("Large", large.clone())];
+
+        let data = compound_file(&[0; 16], streams);
+        assert!(read_u32(&data, 0x48) > 0);
+
+        let got = read_compound_file(&data);
+        assert_eq!(vec![("Large".to_string(), large)], got);
+    }
+
+    #[test]
+    fn test_compound_file_structure() {
+        let large: Vec<u8> = (0..5_000u32).map(|i| (i % 249) as u8).collect();
+        let streams = vec![
+            ("Ccc", vec![3u8; 70]),
+            ("Large", large),
+            ("B", vec![2u8; 10]),
+            ("Aaa", vec![1u8; 200]),
+        ];
+
+        let data = compound_file(&[0; 16], streams);
+        let num_sectors = (data.len() - 512) / 512;
+
+        // Check the header fields.
+        let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+        assert_eq!(0x003E, read_u16(0x18)); // Minor version.
+        assert_eq!(0x0003, read_u16(0x1A)); // Major version.
+        assert_eq!(0xFFFE, read_u16(0x1C)); // Byte order.
+        assert_eq!(9, read_u16(0x1E)); // Sector shift: 512 bytes.
+        assert_eq!(6, read_u16(0x20)); // Mini sector shift: 64 bytes.
+        assert_eq!(0, read_u32(&data, 0x28)); // Directory sectors, 0 for v3.
+        assert_eq!(4096, read_u32(&data, 0x38)); // Mini stream cutoff.
+        assert_eq!(0, read_u32(&data, 0x48)); // DIFAT sectors.
+        assert_eq!(0xFFFF_FFFE, read_u32(&data, 0x44));
+
+        // Read the FAT and check that it covers all the sectors and that the
+        // FAT sectors are marked as FATSECT.
+        let num_fat_sectors = read_u32(&data, 0x2C) as usize;
+        let fat_sector_ids: Vec<u32> = (0..num_fat_sectors)
+            .map(|i| read_u32(&data, 0x4C + i * 4))
+            .collect();
+        assert_eq!(0xFFFF_FFFF, read_u32(&data, 0x4C + num_fat_sectors * 4));
+
+        let fat: Vec<u32> = fat_sector_ids
+            .iter()
+            .flat_map(|id| {
+                let offset = 512 + *id as usize * 512;
+                (0..128).map(move |i| offset + i * 4)
+            })
+            .map(|offset| read_u32(&data, offset))
+            .collect();
+
+        assert!(fat.len() >= num_sectors);
+        assert!(fat[num_sectors..].iter().all(|id| *id == 0xFFFF_FFFF));
+        for id in &fat_sector_ids {
+            assert_eq!(0xFFFF_FFFD, fat[*id as usize]);
+        }
+
+        // Check that each sector is used by one chain, and that the chains end.
+        let mut is_used = vec![false; num_sectors];
+        let mut mark_chain = |start: u32| {
+            let mut id = start;
+            let mut length = 0;
+            while id != 0xFFFF_FFFE {
+                assert!(
+                    !is_used[id as usize],
+                    "sector {id} is in more than one chain"
+                );
+                is_used[id as usize] = true;
+                id = fat[id as usize];
+                length += 1;
+            }
+            length
+        };
+
+        let directory_start = read_u32(&data, 0x30);
+        let num_directory_sectors = mark_chain(directory_start);
+        assert_eq!(read_u32(&data, 0x40), mark_chain(read_u32(&data, 0x3C)));
+
+        let directory: Vec<u8> = {
+            let mut directory = vec![];
+            let mut id = directory_start;
+            while id != 0xFFFF_FFFE {
+                let offset = 512 + id as usize * 512;
+                directory.extend_from_slice(&data[offset..offset + 512]);
+                id = fat[id as usize];
+            }
+            directory
+        };
+        assert_eq!(num_directory_sectors as usize * 512, directory.len());
+
+        let entries: Vec<&[u8]> = directory.chunks(128).collect();
+        for entry in &entries {
+            let size = read_u32(entry, 0x78);
+            if entry[0x42] == 5 || (entry[0x42] == 2 && size >= 4096) {
+                let num_chain_sectors = mark_chain(read_u32(entry, 0x74));
+                assert_eq!(size.div_ceil(512), num_chain_sectors);
+            }
+        }
+
+        for id in fat_sector_ids {
+            is_used[id as usize] = true;
+        }
+        assert!(is_used.iter().all(|is_used| *is_used));
+
+        // Check the root entry and the directory tree. The entries must be in
+        // order by name length and then uppercase name for an in-order walk.
+        let name = |entry: &[u8]| {
+            let name_length = u16::from_le_bytes([entry[0x40], entry[0x41]]) as usize;
+            let name: Vec<u16> = (0..name_length / 2 - 1)
+                .map(|i| u16::from_le_bytes([entry[i * 2], entry[i * 2 + 1]]))
+                .collect();
+            String::from_utf16(&name).unwrap()
+        };
+
+        assert_eq!("Root Entry", name(entries[0]));
+        assert_eq!(5, entries[0][0x42]);
+        assert_eq!(0xFFFF_FFFF, read_u32(entries[0], 0x44));
+        assert_eq!(0xFFFF_FFFF, read_u32(entries[0], 0x48));
+
+        fn walk(entries: &[&[u8]], id: u32, ids: &mut Vec<u32>) {
+            if id == 0xFFFF_FFFF {
+                return;
+            }
+            let entry = entries[id as usize];
+            walk(entries, read_u32(entry, 0x44), ids);
+            ids.push(id);
+            walk(entries, read_u32(entry, 0x48), ids);
+        }
+
+        let mut ids = vec![];
+        walk(&entries, read_u32(entries[0], 0x4C), &mut ids);
+
+        let names: Vec<String> = ids.iter().map(|id| name(entries[*id as usize])).collect();
+        assert_eq!(vec!["B", "Aaa", "Ccc", "Large"], names);
+
+        // The remaining entries are unused.
+        for (id, entry) in entries.iter().enumerate().skip(ids.len() + 1) {
+            assert_eq!(0, entry[0x42], "entry {id} should be unused");
+        }
+    }
+
+    #[test]
+    fn test_ole_object_data() {
+        let object = OleObject::new_from_buffer(b"a,b\n1,2\n", "data.csv");
+
+        let data = object.ole_data().unwrap();
+        let streams = read_compound_file(&data);
+
+        assert_eq!("\u{1}CompObj", streams[0].0);
+        assert_eq!("\u{1}Ole10Native", streams[1].0);
+
+        // Check the root entry CLSID for the OLE Package object.
+        let directory_start = read_u32(&data, 0x30) as usize;
+        let root_entry = &data[512 + directory_start * 512..];
+        assert_eq!(
+            &[
+                0x0C, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x46
+            ],
+            &root_entry[0x50..0x60]
+        );
+
+        let native = &streams[1].1;
+        let mut expected = vec![];
+        expected.extend_from_slice(&2u16.to_le_bytes());
+        expected.extend_from_slice(b"data.csv\0data.csv\0");
+        expected.extend_from_slice(&[0x00, 0x00, 0x03, 0x00]);
+        expected.extend_from_slice(&9u32.to_le_bytes());
+        expected.extend_from_slice(b"data.csv\0");
+        expected.extend_from_slice(&8u32.to_le_bytes());
+        expected.extend_from_slice(b"a,b\n1,2\n");
+
+        assert_eq!(expected.len() as u32, read_u32(native, 0));
+        assert_eq!(expected, native[4..].to_vec());
+    }
+
+    #[test]
+    fn test_ole10_native_size_limit() {
+        let object = OleObject::new_from_buffer(b"", "data.csv");
+
+        assert_eq!(
+            u32::MAX,
+            object.ole10_native_size(u32::MAX as usize).unwrap()
+        );
+        assert!(matches!(
+            object.ole10_native_size(u32::MAX as usize + 1),
+            Err(XlsxError::ParameterError(_))
+        ));
+    }
+}
@@ -58,6 +58,7 @@ use crate::content_types::ContentTypes;
 use crate::core::Core;
 use crate::custom::Custom;
 use crate::error::XlsxError;
+use crate::excel_error::ExcelError;
 use crate::feature_property_bag::{FeaturePropertyBag, FeaturePropertyBagTypes};
 use crate::metadata::Metadata;
 use crate::relationship::Relationship;
@@ -116,7 +117,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         self.write_content_types_file(options)?;
         self.write_root_rels_file(options)?;
         self.write_workbook_rels_file(workbook, options)?;
-        self.write_theme_file()?;
+        self.write_theme_file(workbook)?;
         self.write_styles_file(workbook)?;
         self.write_workbook_file(workbook)?;
 
@@ -188,6 +189,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         self.write_image_files(workbook)?;
         self.write_chart_files(workbook)?;
         self.write_table_files(workbook)?;
+        self.write_ole_object_files(workbook)?;
         self.write_vba_project(workbook)?;
 
         let mut rel_index = 0;
@@ -208,6 +210,9 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         if options.has_embedded_images {
             self.write_rich_value_rels_file(workbook)?;
+        }
+
+        if options.has_embedded_images || options.has_rich_value_errors {
             self.write_rich_value_files(workbook, options)?;
         }
 
@@ -274,6 +279,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             content_types.add_comments_name(i + 1);
         }
 
+        for i in 0..options.num_ole_objects {
+            content_types.add_ole_object_name(i + 1);
+        }
+
         if options.has_sst_table {
             content_types.add_share_strings();
         }
@@ -282,10 +291,14 @@ impl<W: Write + Seek + Send> Packager<W> {
             content_types.add_metadata();
         }
 
-        if options.has_embedded_images {
+        if options.has_embedded_images || options.has_rich_value_errors {
             content_types.add_rich_value();
         }
 
+        if options.has_embedded_images {
+            content_types.add_rich_value_rel();
+        }
+
         if !options.feature_property_bags.is_empty() {
             content_types.add_feature_bag_property();
         }
@@ -399,7 +412,9 @@ impl<W: Write + Seek + Send> Packager<W> {
                 "richData/richValueRel.xml",
                 "",
             );
+        }
 
+        if options.has_embedded_images || options.has_rich_value_errors {
             rels.add_office_relationship("2017/06", "rdRichValue", "richData/rdrichvalue.xml", "");
 
             rels.add_office_relationship(
@@ -515,6 +530,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
         }
 
+        for relationship in &worksheet.ole_object_relationships {
+            rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
+        }
+
         for relationship in &worksheet.table_relationships {
             rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
         }
@@ -678,6 +697,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             workbook.has_comments,
             false,
         );
+        styles.minor_font.clone_from(&workbook.theme.minor_font);
+        styles.major_font.clone_from(&workbook.theme.major_font);
+        styles.cell_styles = &workbook.cell_style_xfs;
+        styles.table_styles = &workbook.table_style_dxfs;
 
         self.zip.start_file("xl/styles.xml", self.zip_options)?;
 
@@ -688,13 +711,13 @@ impl<W: Write + Seek + Send> Packager<W> {
     }
 
     // Write the theme.xml file.
-    fn write_theme_file(&mut self) -> Result<(), XlsxError> {
+    fn write_theme_file(&mut self, workbook: &Workbook) -> Result<(), XlsxError> {
         let mut theme = Theme::new();
 
         self.zip
             .start_file("xl/theme/theme1.xml", self.zip_options)?;
 
-        theme.assemble_xml_file();
+        theme.assemble_xml_file(&workbook.theme);
         self.zip.write_all(theme.writer.get_ref())?;
 
         Ok(())
@@ -787,8 +810,12 @@ impl<W: Write + Seek + Send> Packager<W> {
     fn write_metadata_file(&mut self, options: &PackagerOptions) -> Result<(), XlsxError> {
         let mut metadata = Metadata::new();
         metadata.has_dynamic_functions = options.has_dynamic_functions;
-        metadata.has_embedded_images = options.has_embedded_images;
-        metadata.num_embedded_images = options.num_embedded_images;
+        metadata.has_rich_values = options.has_embedded_images || options.has_rich_value_errors;
+        metadata.num_rich_values = options.num_embedded_images;
+
+        if options.has_rich_value_errors {
+            metadata.num_rich_values += ExcelError::RICH_VALUE_ERRORS.len() as u32;
+        }
 
         self.zip.start_file("xl/metadata.xml", self.zip_options)?;
 
@@ -804,17 +831,25 @@ impl<W: Write + Seek + Send> Packager<W> {
         workbook: &Workbook,
         options: &PackagerOptions,
     ) -> Result<(), XlsxError> {
-        self.write_rich_value_file(workbook)?;
+        self.write_rich_value_file(workbook, options)?;
         self.write_rich_value_types_file()?;
         self.write_rich_value_structure_file(options)?;
-        self.write_rich_value_rel_file(options)?;
+
+        if options.has_embedded_images {
+            self.write_rich_value_rel_file(options)?;
+        }
 
         Ok(())
     }
 
     // Write the rdrichvalue.xml file.
-    fn write_rich_value_file(&mut self, workbook: &Workbook) -> Result<(), XlsxError> {
+    fn write_rich_value_file(
+        &mut self,
+        workbook: &Workbook,
+        options: &PackagerOptions,
+    ) -> Result<(), XlsxError> {
         let mut rich_value = RichValue::new(&workbook.embedded_images);
+        rich_value.has_rich_value_errors = options.has_rich_value_errors;
 
         self.zip
             .start_file("xl/richData/rdrichvalue.xml", self.zip_options)?;
@@ -844,6 +879,8 @@ impl<W: Write + Seek + Send> Packager<W> {
         options: &PackagerOptions,
     ) -> Result<(), XlsxError> {
         let mut rich_value_structure = RichValueStructure::new();
+        rich_value_structure.has_embedded_images = options.has_embedded_images;
+        rich_value_structure.has_rich_value_errors = options.has_rich_value_errors;
         rich_value_structure.has_embedded_image_descriptions =
             options.has_embedded_image_descriptions;
 
@@ -920,6 +957,7 @@ impl<W: Write + Seek + Send> Packager<W> {
                     self.zip.start_file(filename, self.zip_options)?;
 
                     let mut vml = Vml::new();
+                    vml.ole_objects.append(&mut worksheet.ole_objects_vml_info);
                     vml.buttons.append(&mut worksheet.buttons_vml_info);
                     vml.comments.append(&mut worksheet.comments_vml_info);
 
@@ -929,6 +967,15 @@ impl<W: Write + Seek + Send> Packager<W> {
                     vml.assemble_xml_file();
 
                     self.zip.write_all(vml.writer.get_ref())?;
+
+                    // Write the rels file for any OLE object images.
+                    if !worksheet.vml_object_relationships.is_empty() {
+                        self.write_vml_drawing_rels_file(
+                            &worksheet.vml_object_relationships,
+                            index,
+                        )?;
+                    }
+
                     index += 1;
                 }
 
@@ -965,6 +1012,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         let mut unique_worksheet_images = HashSet::new();
         let mut unique_header_footer_images = HashSet::new();
         let mut unique_background_images = HashSet::new();
+        let mut unique_ole_object_images = HashSet::new();
 
         for image in &workbook.embedded_images {
             let filename = format!("xl/media/image{index}.{}", image.image_type.extension());
@@ -1014,6 +1062,21 @@ impl<W: Write + Seek + Send> Packager<W> {
                     }
                 }
             }
+
+            for object in worksheet.ole_objects.values() {
+                if let Some(image) = &object.image {
+                    if !unique_ole_object_images.contains(&image.hash) {
+                        let filename =
+                            format!("xl/media/image{index}.{}", image.image_type.extension());
+                        self.zip
+                            .start_file(filename, self.zip_options_for_binary_files)?;
+
+                        self.zip.write_all(&image.data)?;
+                        unique_ole_object_images.insert(image.hash.clone());
+                        index += 1;
+                    }
+                }
+            }
         }
 
         Ok(())
@@ -1053,6 +1116,23 @@ impl<W: Write + Seek + Send> Packager<W> {
         Ok(())
     }
 
+    // Write the embedded OLE object files.
+    fn write_ole_object_files(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
+        let mut index = 1;
+
+        for worksheet in &workbook.worksheets {
+            for object in worksheet.ole_objects.values() {
+                let filename = format!("xl/embeddings/oleObject{index}.bin");
+                self.zip
+                    .start_file(filename, self.zip_options_for_binary_files)?;
+                self.zip.write_all(&object.ole_data()?)?;
+                index += 1;
+            }
+        }
+
+        Ok(())
+    }
+
     // Write the VBA project file.
     fn write_vba_project(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
         if !workbook.is_xlsm_file {
@@ -1113,6 +1193,7 @@ pub(crate) struct PackagerOptions {
     pub(crate) has_metadata: bool,
     pub(crate) has_dynamic_functions: bool,
     pub(crate) has_embedded_images: bool,
+    pub(crate) has_rich_value_errors: bool,
     pub(crate) has_vml: bool,
     pub(crate) is_xlsm_file: bool,
     pub(crate) has_vba_signature: bool,
@@ -1122,6 +1203,7 @@ pub(crate) struct PackagerOptions {
     pub(crate) num_charts: u16,
     pub(crate) num_tables: u16,
     pub(crate) num_comments: u16,
+    pub(crate) num_ole_objects: u16,
     pub(crate) doc_security: u8,
     pub(crate) worksheet_names: Vec<String>,
     pub(crate) defined_names: Vec<String>,
@@ -1140,6 +1222,7 @@ impl PackagerOptions {
             has_metadata: false,
             has_dynamic_functions: false,
             has_embedded_images: false,
+            has_rich_value_errors: false,
             has_vml: false,
             is_xlsm_file: false,
             has_vba_signature: false,
@@ -1149,6 +1232,7 @@ impl PackagerOptions {
             num_charts: 0,
             num_tables: 0,
             num_comments: 0,
+            num_ole_objects: 0,
             doc_security: 0,
             worksheet_names: vec![],
             defined_names: vec![],
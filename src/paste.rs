@@ -0,0 +1,126 @@
+// paste - A module for representing the options used when copying and pasting
+// a range of worksheet cells.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+/// The `PasteOptions` struct is used to set the options for copying a range of
+/// cells.
+///
+/// `PasteOptions` is used with the
+/// [`Worksheet::copy_range_with_options()`](crate::Worksheet::copy_range_with_options)
+/// and [`Worksheet::paste_range()`](crate::Worksheet::paste_range) methods to
+/// control which parts of the copied cells are pasted, in the same way as
+/// Excel's "Paste Special" dialog.
+///
+/// # Examples
+///
+/// The following example demonstrates copying a range of cells with the
+/// "values only" and "transpose" paste options.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_copy_range_with_options.rs
+/// #
+/// # use rust_xlsxwriter::{Format, Formula, PasteOptions, PasteType, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     let bold = Format::new().set_bold();
+///
+///     // Write some data and a formula to sum it.
+///     worksheet.write_with_format(0, 0, "Totals", &bold)?;
+///     worksheet.write_column(1, 0, [10, 20, 30])?;
+///     worksheet.write_formula(4, 0, Formula::new("=SUM(A2:A4)").set_result("60"))?;
+///
+///     // Copy the data as values, without the formula or formatting.
+///     let options = PasteOptions::new().set_type(PasteType::Values);
+///     worksheet.copy_range_with_options(0, 0, 4, 0, 0, 2, &options)?;
+///
+///     // Copy the data and transpose it from a column to a row.
+///     let options = PasteOptions::new().set_transpose(true);
+///     worksheet.copy_range_with_options(0, 0, 4, 0, 7, 0, &options)?;
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PasteOptions {
+    pub(crate) paste_type: PasteType,
+    pub(crate) transpose: bool,
+}
+
+impl PasteOptions {
+    /// Create a new `PasteOptions` object to use with the
+    /// [`Worksheet::copy_range_with_options()`](crate::Worksheet::copy_range_with_options)
+    /// method.
+    ///
+    /// The default options paste the values, formats, merged ranges, notes,
+    /// hyperlinks and conditional formats of the copied cells, like a standard
+    /// Excel paste.
+    ///
+    pub fn new() -> PasteOptions {
+        PasteOptions::default()
+    }
+
+    /// Set the parts of the copied cells that are pasted.
+    ///
+    /// # Parameters
+    ///
+    /// - `paste_type`: A [`PasteType`] enum value.
+    ///
+    pub fn set_type(mut self, paste_type: PasteType) -> PasteOptions {
+        self.paste_type = paste_type;
+        self
+    }
+
+    /// Transpose the rows and columns of the copied cells.
+    ///
+    /// When this option is on the first row of the copied range is pasted as
+    /// the first column of the target range, and so on, like Excel's
+    /// "Transpose" paste option. References in copied formulas to cells within
+    /// the copied range are changed to the transposed cells.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_transpose(mut self, enable: bool) -> PasteOptions {
+        self.transpose = enable;
+        self
+    }
+}
+
+/// The `PasteType` enum defines the parts of a copied range of cells that are
+/// pasted.
+///
+/// It is used with [`PasteOptions::set_type()`].
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PasteType {
+    /// Paste the cell values, formats, merged ranges, notes, hyperlinks and
+    /// conditional formats. This is the default.
+    #[default]
+    All,
+
+    /// Paste the cell values only. Formulas are replaced by their results and
+    /// the formatting of the target cells isn't changed.
+    ///
+    /// **Note**: `rust_xlsxwriter` doesn't calculate formulas so the result of
+    /// a formula is the value set with
+    /// [`Formula::set_result()`](crate::Formula::set_result) or, if a result
+    /// isn't set, the default result of 0. Set the results of formulas that
+    /// are pasted as values.
+    Values,
+
+    /// Paste the cell formats, merged ranges and conditional formats only. The
+    /// values of the target cells aren't changed.
+    Formats,
+}
@@ -0,0 +1,380 @@
+// phonetic - A module for representing Excel phonetic guides, such as
+// Japanese furigana, for worksheet strings.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::fmt;
+use std::io::Cursor;
+
+use crate::xmlwriter::{self, xml_data_element, xml_end_tag, xml_start_tag};
+use crate::{Format, XlsxError};
+
+/// The `PhoneticString` struct represents a worksheet string with phonetic
+/// guides.
+///
+/// Excel can store phonetic guides, also known as "furigana" or "ruby text",
+/// for runs of characters in a string. These are generally used to show the
+/// pronunciation of Japanese Kanji characters, for example in names. The
+/// phonetic text is displayed above the base text when the "Show Phonetic
+/// Field" option is turned on for the cell, see
+/// [`PhoneticString::set_visible()`].
+///
+/// A `PhoneticString` is written to a worksheet with
+/// [`Worksheet::write_phonetic_string()`](crate::Worksheet::write_phonetic_string).
+/// The font, type and alignment of the phonetic text are set for the worksheet
+/// with [`Worksheet::set_phonetic_properties()`](crate::Worksheet::set_phonetic_properties).
+///
+/// # Examples
+///
+/// The following example demonstrates writing a string with phonetic guides.
+///
+/// ```
+/// # // This code is available in examples/doc_phonetic_string.rs
+/// #
+/// # use rust_xlsxwriter::{PhoneticString, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     // Add phonetic guides for the characters "東京" and "都" of "東京都".
+///     let string = PhoneticString::new("東京都")
+///         .add_run(0, 2, "トウキョウ")
+///         .add_run(2, 3, "ト")
+///         .set_visible(true);
+///
+///     worksheet.write_phonetic_string(0, 0, &string)?;
+/// #
+/// #     workbook.save("phonetic.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhoneticString {
+    pub(crate) segments: Vec<(Format, String)>,
+    pub(crate) runs: Vec<(u32, u32, String)>,
+    pub(crate) is_rich: bool,
+    pub(crate) visible: bool,
+}
+
+impl PhoneticString {
+    /// Create a new `PhoneticString` object from a base string.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The base text that the phonetic guides apply to.
+    ///
+    pub fn new(text: impl Into<String>) -> PhoneticString {
+        PhoneticString {
+            segments: vec![(Format::default(), text.into())],
+            runs: vec![],
+            is_rich: false,
+            visible: false,
+        }
+    }
+
+    /// Create a new `PhoneticString` object from a rich string.
+    ///
+    /// The base text can be a rich string made up of formatted segments, in
+    /// the same way as
+    /// [`Worksheet::write_rich_string()`](crate::Worksheet::write_rich_string).
+    /// The character positions of the phonetic runs refer to the combined
+    /// text of the segments.
+    ///
+    /// # Parameters
+    ///
+    /// - `segments`: An array reference of `(&Format, &str)` tuples.
+    ///
+    pub fn new_rich(segments: &[(&Format, &str)]) -> PhoneticString {
+        PhoneticString {
+            segments: segments
+                .iter()
+                .map(|(format, text)| ((*format).clone(), (*text).to_string()))
+                .collect(),
+            runs: vec![],
+            is_rich: true,
+            visible: false,
+        }
+    }
+
+    /// Add phonetic text for a run of characters in the base text.
+    ///
+    /// The runs should be added in order and shouldn't overlap. The positions
+    /// are checked when the string is written to the worksheet. The positions
+    /// are in characters and are converted to the UTF-16 positions that Excel
+    /// uses when the file is written.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: The zero indexed position of the first character of the run
+    ///   in the base text.
+    /// - `end`: The position after the last character of the run.
+    /// - `phonetic_text`: The phonetic text for the run.
+    ///
+    pub fn add_run(
+        mut self,
+        start: u32,
+        end: u32,
+        phonetic_text: impl Into<String>,
+    ) -> PhoneticString {
+        self.runs.push((start, end, phonetic_text.into()));
+        self
+    }
+
+    /// Show the phonetic text above the base text.
+    ///
+    /// This is the equivalent of the Excel "Show Phonetic Field" option for
+    /// the cell. The phonetic text is stored but not displayed by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_visible(mut self, enable: bool) -> PhoneticString {
+        self.visible = enable;
+        self
+    }
+
+    // Get the base text of the string without formatting.
+    pub(crate) fn text(&self) -> String {
+        self.segments
+            .iter()
+            .map(|(_, text)| text.as_str())
+            .collect()
+    }
+
+    // Check that the runs are in order and within the base text.
+    pub(crate) fn validate(&self) -> Result<(), XlsxError> {
+        let length = self.text().chars().count() as u32;
+        let mut previous_end = 0;
+
+        for (start, end, phonetic_text) in &self.runs {
+            if start >= end || *end > length || *start < previous_end {
+                return Err(XlsxError::ParameterError(format!(
+                    "Phonetic run ({start}, {end}) must be in order and within the {length} characters of the base string"
+                )));
+            }
+
+            if phonetic_text.is_empty() {
+                return Err(XlsxError::ParameterError(
+                    "Phonetic run text cannot be blank".to_string(),
+                ));
+            }
+
+            previous_end = *end;
+        }
+
+        Ok(())
+    }
+
+    // Get the <rPh> elements for the phonetic runs. Excel stores the run
+    // positions as UTF-16 offsets so the character positions are converted,
+    // for example "𠮷" counts as 2.
+    pub(crate) fn runs_xml(&self) -> String {
+        let mut writer = Cursor::new(Vec::with_capacity(128));
+        let text = self.text();
+        let utf16_offset = |position: u32| -> usize {
+            text.chars()
+                .take(position as usize)
+                .map(char::len_utf16)
+                .sum()
+        };
+
+        for (start, end, phonetic_text) in &self.runs {
+            let attributes = [
+                ("sb", utf16_offset(*start).to_string()),
+                ("eb", utf16_offset(*end).to_string()),
+            ];
+
+            xml_start_tag(&mut writer, "rPh", &attributes);
+            xml_data_element(&mut writer, "t", phonetic_text, &[] as &[(&str, &str)]);
+            xml_end_tag(&mut writer, "rPh");
+        }
+
+        xmlwriter::cursor_to_string(&writer)
+    }
+}
+
+/// The `PhoneticProperties` struct represents the phonetic settings of a
+/// worksheet.
+///
+/// The phonetic properties set the font, character type and alignment of the
+/// phonetic text of [`PhoneticString`] strings. They are set for a worksheet
+/// with
+/// [`Worksheet::set_phonetic_properties()`](crate::Worksheet::set_phonetic_properties).
+///
+/// # Examples
+///
+/// The following example demonstrates setting the phonetic properties of a
+/// worksheet.
+///
+/// ```
+/// # // This code is available in examples/doc_phonetic_properties.rs
+/// #
+/// # use rust_xlsxwriter::{
+/// #     Format, PhoneticAlignment, PhoneticProperties, PhoneticString, PhoneticType, Workbook,
+/// #     XlsxError,
+/// # };
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     // Show the phonetic text as small centered Hiragana.
+///     let font = Format::new().set_font_name("Meiryo").set_font_size(6);
+///     let properties = PhoneticProperties::new()
+///         .set_font(&font)
+///         .set_type(PhoneticType::Hiragana)
+///         .set_alignment(PhoneticAlignment::Center);
+///
+///     worksheet.set_phonetic_properties(&properties);
+///
+///     let string = PhoneticString::new("漢字")
+///         .add_run(0, 2, "かんじ")
+///         .set_visible(true);
+///
+///     worksheet.write_phonetic_string(0, 0, &string)?;
+/// #
+/// #     workbook.save("phonetic.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PhoneticProperties {
+    pub(crate) font: Option<Format>,
+    pub(crate) phonetic_type: PhoneticType,
+    pub(crate) alignment: PhoneticAlignment,
+}
+
+impl PhoneticProperties {
+    /// Create a new `PhoneticProperties` object.
+    ///
+    pub fn new() -> PhoneticProperties {
+        PhoneticProperties::default()
+    }
+
+    /// Set the font of the phonetic text.
+    ///
+    /// Only the font properties of the format, such as the font name, size and
+    /// color, are used. The default is the workbook default font.
+    ///
+    /// # Parameters
+    ///
+    /// - `font`: A [`Format`] with the font properties of the phonetic text.
+    ///
+    pub fn set_font(mut self, font: &Format) -> PhoneticProperties {
+        self.font = Some(font.clone());
+        self
+    }
+
+    /// Set the character type of the phonetic text.
+    ///
+    /// # Parameters
+    ///
+    /// - `phonetic_type`: A [`PhoneticType`] enum value.
+    ///
+    pub fn set_type(mut self, phonetic_type: PhoneticType) -> PhoneticProperties {
+        self.phonetic_type = phonetic_type;
+        self
+    }
+
+    /// Set the alignment of the phonetic text.
+    ///
+    /// # Parameters
+    ///
+    /// - `alignment`: A [`PhoneticAlignment`] enum value.
+    ///
+    pub fn set_alignment(mut self, alignment: PhoneticAlignment) -> PhoneticProperties {
+        self.alignment = alignment;
+        self
+    }
+
+    // Get the attributes for the <phoneticPr> element.
+    pub(crate) fn attributes(&self, font_id: u16) -> Vec<(&'static str, String)> {
+        let mut attributes = vec![("fontId", font_id.to_string())];
+
+        if self.phonetic_type != PhoneticType::FullwidthKatakana {
+            attributes.push(("type", self.phonetic_type.to_string()));
+        }
+
+        if self.alignment != PhoneticAlignment::Left {
+            attributes.push(("alignment", self.alignment.to_string()));
+        }
+
+        attributes
+    }
+}
+
+/// The `PhoneticType` enum defines the character type of phonetic text.
+///
+/// It is used with [`PhoneticProperties::set_type()`].
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PhoneticType {
+    /// Half-width Katakana characters.
+    HalfwidthKatakana,
+
+    /// Full-width Katakana characters. This is the default.
+    #[default]
+    FullwidthKatakana,
+
+    /// Hiragana characters.
+    Hiragana,
+
+    /// The phonetic text is displayed as entered, without conversion.
+    NoConversion,
+}
+
+impl fmt::Display for PhoneticType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HalfwidthKatakana => write!(f, "halfwidthKatakana"),
+            Self::FullwidthKatakana => write!(f, "fullwidthKatakana"),
+            Self::Hiragana => write!(f, "Hiragana"),
+            Self::NoConversion => write!(f, "noConversion"),
+        }
+    }
+}
+
+/// The `PhoneticAlignment` enum defines the alignment of phonetic text
+/// relative to the base text.
+///
+/// It is used with [`PhoneticProperties::set_alignment()`].
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PhoneticAlignment {
+    /// No alignment control.
+    NoControl,
+
+    /// Align the phonetic text to the left of the base text. This is the
+    /// default.
+    #[default]
+    Left,
+
+    /// Center the phonetic text over the base text.
+    Center,
+
+    /// Distribute the phonetic text over the base text.
+    Distributed,
+}
+
+impl fmt::Display for PhoneticAlignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoControl => write!(f, "noControl"),
+            Self::Left => write!(f, "left"),
+            Self::Center => write!(f, "center"),
+            Self::Distributed => write!(f, "distributed"),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// Phonetic unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod phonetic_tests {
+
+    use crate::{
+        Format, PhoneticAlignment, PhoneticProperties, PhoneticString, PhoneticType, XlsxError,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_phonetic_runs() {
+        let string = PhoneticString::new("東京都")
+            .add_run(0, 2, "トウキョウ")
+            .add_run(2, 3, "ト");
+
+        assert!(string.validate().is_ok());
+        assert_eq!(string.text(), "東京都");
+
+        let expected = concat!(
+            r#"<rPh sb="0" eb="2"><t>トウキョウ</t></rPh>"#,
+            r#"<rPh sb="2" eb="3"><t>ト</t></rPh>"#
+        );
+        assert_eq!(string.runs_xml(), expected);
+
+        let bold = Format::new().set_bold();
+        let default = Format::default();
+        let string = PhoneticString::new_rich(&[(&bold, "山田"), (&default, "太郎")]).add_run(
+            2,
+            4,
+            "タロウ",
+        );
+
+        assert!(string.validate().is_ok());
+        assert_eq!(string.text(), "山田太郎");
+    }
+
+    #[test]
+    fn test_phonetic_runs_utf16() {
+        // The "𠮷" character is outside the Basic Multilingual Plane and is 2
+        // UTF-16 code units long.
+        let string = PhoneticString::new("𠮷野家")
+            .add_run(0, 1, "ヨシ")
+            .add_run(1, 3, "ノヤ");
+
+        assert!(string.validate().is_ok());
+
+        let expected = concat!(
+            r#"<rPh sb="0" eb="2"><t>ヨシ</t></rPh>"#,
+            r#"<rPh sb="2" eb="4"><t>ノヤ</t></rPh>"#
+        );
+        assert_eq!(string.runs_xml(), expected);
+
+        // Run positions are in characters, not UTF-16 code units.
+        let string = PhoneticString::new("𠮷野家").add_run(3, 4, "ヤ");
+        let result = string.validate();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_phonetic_run_errors() {
+        let strings = [
+            // Empty run.
+            PhoneticString::new("東京").add_run(1, 1, "キョウ"),
+            // Run past the end of the string.
+            PhoneticString::new("東京").add_run(1, 3, "キョウ"),
+            // Overlapping runs.
+            PhoneticString::new("東京都")
+                .add_run(0, 2, "トウキョウ")
+                .add_run(1, 3, "キョウト"),
+            // Runs out of order.
+            PhoneticString::new("東京都")
+                .add_run(2, 3, "ト")
+                .add_run(0, 2, "トウキョウ"),
+            // Empty phonetic text.
+            PhoneticString::new("東京").add_run(0, 2, ""),
+        ];
+
+        for string in strings {
+            let result = string.validate();
+            assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        }
+    }
+
+    #[test]
+    fn test_phonetic_properties() {
+        let properties = PhoneticProperties::new();
+        assert_eq!(properties.attributes(1), vec![("fontId", "1".to_string())]);
+
+        let properties = PhoneticProperties::new()
+            .set_type(PhoneticType::Hiragana)
+            .set_alignment(PhoneticAlignment::Distributed);
+        assert_eq!(
+            properties.attributes(2),
+            vec![
+                ("fontId", "2".to_string()),
+                ("type", "Hiragana".to_string()),
+                ("alignment", "distributed".to_string()),
+            ]
+        );
+
+        let properties = PhoneticProperties::new()
+            .set_type(PhoneticType::HalfwidthKatakana)
+            .set_alignment(PhoneticAlignment::NoControl);
+        assert_eq!(
+            properties.attributes(0),
+            vec![
+                ("fontId", "0".to_string()),
+                ("type", "halfwidthKatakana".to_string()),
+                ("alignment", "noControl".to_string()),
+            ]
+        );
+    }
+}
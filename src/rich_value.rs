@@ -6,13 +6,14 @@
 
 use std::io::Cursor;
 
-use crate::Image;
+use crate::{ExcelError, Image};
 
 use crate::xmlwriter::{xml_data_element_only, xml_declaration, xml_end_tag, xml_start_tag};
 
 pub struct RichValue<'a> {
     pub(crate) writer: Cursor<Vec<u8>>,
     pub(crate) embedded_images: &'a Vec<Image>,
+    pub(crate) has_rich_value_errors: bool,
 }
 
 impl RichValue<'_> {
@@ -27,6 +28,7 @@ impl RichValue<'_> {
         RichValue {
             writer,
             embedded_images,
+            has_rich_value_errors: false,
         }
     }
 
@@ -47,25 +49,52 @@ impl RichValue<'_> {
 
     // Write the <rvData> element.
     fn write_rv_data(&mut self) {
+        let mut count = self.embedded_images.len();
+        if self.has_rich_value_errors {
+            count += ExcelError::RICH_VALUE_ERRORS.len();
+        }
+
         let attributes = [
             (
                 "xmlns",
                 "http://schemas.microsoft.com/office/spreadsheetml/2017/richdata".to_string(),
             ),
-            ("count", self.embedded_images.len().to_string()),
+            ("count", count.to_string()),
         ];
 
         xml_start_tag(&mut self.writer, "rvData", &attributes);
 
+        // The error values are written before the images with the first
+        // structure.
+        if self.has_rich_value_errors {
+            for (_, error_type) in ExcelError::RICH_VALUE_ERRORS {
+                // Write the <rv> element.
+                self.write_error_rv(error_type);
+            }
+        }
+
         for (index, image) in self.embedded_images.iter().enumerate() {
             // Write the <rv> element.
             self.write_rv(index, image);
         }
     }
 
+    // Write the <rv> element for an error value.
+    fn write_error_rv(&mut self, error_type: u32) {
+        let attributes = [("s", "0")];
+
+        xml_start_tag(&mut self.writer, "rv", &attributes);
+
+        // Write the <v> element.
+        self.write_v(&error_type.to_string());
+
+        xml_end_tag(&mut self.writer, "rv");
+    }
+
     // Write the <rv> element.
     fn write_rv(&mut self, index: usize, image: &Image) {
-        let attributes = [("s", "0")];
+        let structure = if self.has_rich_value_errors { "1" } else { "0" };
+        let attributes = [("s", structure)];
         let mut value = "5";
 
         if image.decorative {
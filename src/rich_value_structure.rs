@@ -10,6 +10,8 @@ use crate::xmlwriter::{xml_declaration, xml_empty_tag, xml_end_tag, xml_start_ta
 
 pub struct RichValueStructure {
     pub(crate) writer: Cursor<Vec<u8>>,
+    pub(crate) has_embedded_images: bool,
+    pub(crate) has_rich_value_errors: bool,
     pub(crate) has_embedded_image_descriptions: bool,
 }
 
@@ -24,6 +26,8 @@ impl RichValueStructure {
 
         RichValueStructure {
             writer,
+            has_embedded_images: false,
+            has_rich_value_errors: false,
             has_embedded_image_descriptions: false,
         }
     }
@@ -45,21 +49,42 @@ impl RichValueStructure {
 
     // Write the <rvStructures> element.
     fn write_rv_structures(&mut self) {
+        let count = u8::from(self.has_embedded_images) + u8::from(self.has_rich_value_errors);
+
         let attributes = [
             (
                 "xmlns",
-                "http://schemas.microsoft.com/office/spreadsheetml/2017/richdata",
+                "http://schemas.microsoft.com/office/spreadsheetml/2017/richdata".to_string(),
             ),
-            ("count", "1"),
+            ("count", count.to_string()),
         ];
 
         xml_start_tag(&mut self.writer, "rvStructures", &attributes);
 
-        // Write the <s> element.
-        self.write_s();
+        // Write the <s> element for error values.
+        if self.has_rich_value_errors {
+            self.write_error_s();
+        }
+
+        // Write the <s> element for embedded images.
+        if self.has_embedded_images {
+            self.write_s();
+        }
+    }
+
+    // Write the <s> element for error values.
+    fn write_error_s(&mut self) {
+        let attributes = [("t", "_error")];
+
+        xml_start_tag(&mut self.writer, "s", &attributes);
+
+        // Write the <k> element.
+        self.write_k("errorType", "i");
+
+        xml_end_tag(&mut self.writer, "s");
     }
 
-    // Write the <s> element.
+    // Write the <s> element for embedded images.
     fn write_s(&mut self) {
         let attributes = [("t", "_localImage")];
 
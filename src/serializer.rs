@@ -1958,7 +1958,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::{ColNum, Format, RowNum, Table, TableStyle, Worksheet, XlsxError};
+use crate::{ColNum, ExcelError, Format, RowNum, Table, TableStyle, Worksheet, XlsxError};
 use serde::de::Visitor;
 use serde::{ser, Deserialize, Deserializer, Serialize};
 
@@ -3647,10 +3647,17 @@ impl ser::Serializer for &mut Worksheet {
     #[doc(hidden)]
     fn serialize_unit_variant(
         self,
-        _name: &'static str,
-        _variant_index: u32,
+        name: &'static str,
+        variant_index: u32,
         variant: &'static str,
     ) -> Result<(), XlsxError> {
+        // Write ExcelError values as error cells instead of strings.
+        if name == ExcelError::SERDE_NAME {
+            if let Some(error) = ExcelError::from_variant_index(variant_index) {
+                return self.serialize_to_worksheet_cell(error);
+            }
+        }
+
         variant.serialize(self)
     }
 
@@ -87,6 +87,7 @@ pub struct Shape {
     pub(crate) text_link: Option<Formula>,
     pub(crate) alt_text: String,
     pub(crate) object_movement: ObjectMovement,
+    pub(crate) absolute_position: Option<(u32, u32)>,
     pub(crate) decorative: bool,
     pub(crate) format: ShapeFormat,
     pub(crate) font: ShapeFont,
@@ -156,6 +157,7 @@ impl Shape {
             text_link: None,
             alt_text: String::new(),
             object_movement: ObjectMovement::MoveAndSizeWithCells,
+            absolute_position: None,
             decorative: false,
             format: ShapeFormat::default(),
             font: ShapeFont::default(),
@@ -610,6 +612,10 @@ impl Shape {
     /// be hidden in rows or columns. In Excel this equates to option 1 above
     /// but the internal shape position calculations are handled differently.
     ///
+    /// There is also an [`ObjectMovement::AbsoluteAnchor`] option to fix the
+    /// shape at an absolute position in the worksheet. See also
+    /// [`Shape::set_absolute_position()`].
+    ///
     /// # Parameters
     ///
     /// - `option`: An shape/object positioning behavior defined by the
@@ -618,6 +624,28 @@ impl Shape {
         self.object_movement = option;
         self
     }
+
+    /// Set the absolute position of the shape in the worksheet.
+    ///
+    /// Position the shape at fixed `x` and `y` pixel coordinates from the top
+    /// left corner of the worksheet instead of relative to the cell that it is
+    /// inserted in. The row, column and any offsets used to insert the shape
+    /// are ignored.
+    ///
+    /// This also sets the object movement to [`ObjectMovement::AbsoluteAnchor`]
+    /// so that Excel doesn't move or resize the shape when the cells
+    /// underneath it change.
+    ///
+    /// # Parameters
+    ///
+    /// - `x`: The horizontal position from the left of the worksheet in pixels.
+    /// - `y`: The vertical position from the top of the worksheet in pixels.
+    ///
+    pub fn set_absolute_position(mut self, x: u32, y: u32) -> Shape {
+        self.absolute_position = Some((x, y));
+        self.object_movement = ObjectMovement::AbsoluteAnchor;
+        self
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -3222,6 +3250,10 @@ impl DrawingObject for Shape {
         self.y_offset
     }
 
+    fn absolute_position(&self) -> Option<(u32, u32)> {
+        self.absolute_position
+    }
+
     fn width_scaled(&self) -> f64 {
         self.width
     }
@@ -70,12 +70,14 @@ impl SharedStrings {
         insertion_order_strings.sort_by_key(|x| x.1);
         let whitespace = ['\t', '\n', ' '];
 
-        for (string, _) in insertion_order_strings {
+        for (string, index) in insertion_order_strings {
             let preserve_whitespace =
                 string.starts_with(whitespace) || string.ends_with(whitespace);
 
-            // Check if the string is a rich text element.
-            if string.starts_with("<r>") && string.ends_with("</r>") {
+            // Check if the string is a rich text or phonetic element.
+            if string_table.xml_strings.contains(index)
+                || (string.starts_with("<r>") && string.ends_with("</r>"))
+            {
                 xml_rich_si_element(&mut self.writer, string);
             } else {
                 xml_si_element(&mut self.writer, string, preserve_whitespace);
@@ -92,4 +92,43 @@ mod shared_strings_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_shared_string_table_with_xml_strings() {
+        let mut string_table = SharedStringsTable::new();
+
+        let mut shared_strings = SharedStrings::new();
+
+        string_table.shared_string_index("<t>mars</t>".into());
+        string_table.shared_xml_string_index(
+            r#"<t>火星</t><rPh sb="0" eb="2"><t>カセイ</t></rPh><phoneticPr fontId="1"/>"#.into(),
+        );
+
+        let string_table = Arc::new(Mutex::new(string_table));
+
+        shared_strings.assemble_xml_file(string_table);
+
+        let got = xmlwriter::cursor_to_str(&shared_strings.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <sst xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" count="2" uniqueCount="2">
+                  <si>
+                    <t>&lt;t&gt;mars&lt;/t&gt;</t>
+                  </si>
+                  <si>
+                    <t>火星</t>
+                    <rPh sb="0" eb="2">
+                      <t>カセイ</t>
+                    </rPh>
+                    <phoneticPr fontId="1"/>
+                  </si>
+                </sst>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
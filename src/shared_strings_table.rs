@@ -10,7 +10,10 @@
 
 mod tests;
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 //
 // A metadata struct to store Excel unique strings between worksheets.
@@ -19,6 +22,7 @@ pub struct SharedStringsTable {
     pub count: u32,
     pub unique_count: u32,
     pub strings: HashMap<Arc<str>, u32>,
+    pub xml_strings: HashSet<u32>,
 }
 
 impl SharedStringsTable {
@@ -32,6 +36,7 @@ impl SharedStringsTable {
             count: 0,
             unique_count: 0,
             strings: HashMap::new(),
+            xml_strings: HashSet::new(),
         }
     }
 
@@ -46,6 +51,14 @@ impl SharedStringsTable {
         index
     }
 
+    // Get the index of a string that contains pre-formatted xml, such as a
+    // rich or phonetic string, in the Shared String table.
+    pub(crate) fn shared_xml_string_index(&mut self, key: Arc<str>) -> u32 {
+        let index = self.shared_string_index(key);
+        self.xml_strings.insert(index);
+        index
+    }
+
     // Add a string to the Shared String table to get a consistent ordering with
     // files generated by Excel, when testing. This doesn't increment the count.
     pub(crate) fn populate_string_index(&mut self, key: Arc<str>, index: u32) {
@@ -0,0 +1,915 @@
+// shift - A module for adjusting cell references when rows or columns are
+// inserted into, or deleted from, a worksheet, when cells are copied, or when
+// worksheets are duplicated or removed.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+mod tests;
+
+use crate::worksheet::{CellRange, COL_MAX, ROW_MAX};
+use crate::{utility, ColNum, ObjectMovement, RowNum};
+
+// A change to the cells of a worksheet that the references to the cells are
+// adjusted for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ReferenceChange {
+    // Rows or columns inserted into, or deleted from, the worksheet.
+    Shift(ReferenceShift),
+
+    // A range of cells moved to another position in the worksheet.
+    Move(RangeMove),
+}
+
+impl ReferenceChange {
+    // Get the new extent of a cell range, or None if it was deleted.
+    pub(crate) fn range(
+        &self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Option<(RowNum, ColNum, RowNum, ColNum)> {
+        match self {
+            ReferenceChange::Shift(shift) => shift.range(first_row, first_col, last_row, last_col),
+            ReferenceChange::Move(range_move) => {
+                range_move.range(first_row, first_col, last_row, last_col)
+            }
+        }
+    }
+
+    // Adjust the cell references in a formula. See ReferenceShift::formula().
+    pub(crate) fn formula(&self, formula: &str, sheet_name: &str, is_local: bool) -> String {
+        match self {
+            ReferenceChange::Shift(shift) => shift.formula(formula, sheet_name, is_local),
+            ReferenceChange::Move(range_move) => range_move.formula(formula, sheet_name, is_local),
+        }
+    }
+}
+
+// The worksheet axis that is shifted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ShiftAxis {
+    Rows,
+    Columns,
+}
+
+// A struct to represent the insertion or deletion of a number of rows or
+// columns at a zero indexed position. It is used to adjust the cell data and
+// any references to the cells in the worksheet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ReferenceShift {
+    pub(crate) axis: ShiftAxis,
+    pub(crate) position: u32,
+    pub(crate) count: u32,
+    pub(crate) is_insert: bool,
+}
+
+impl ReferenceShift {
+    // -----------------------------------------------------------------------
+    // Crate public methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new shift for inserted or deleted rows or columns.
+    pub(crate) fn new(axis: ShiftAxis, position: u32, count: u32, is_insert: bool) -> Self {
+        ReferenceShift {
+            axis,
+            position,
+            count,
+            is_insert,
+        }
+    }
+
+    // Get the new row number of a row, or None if it was deleted or shifted
+    // past the end of the worksheet.
+    pub(crate) fn row(&self, row: RowNum) -> Option<RowNum> {
+        match self.axis {
+            ShiftAxis::Rows => self.index(row, ROW_MAX),
+            ShiftAxis::Columns => Some(row),
+        }
+    }
+
+    // Get the new column number of a column, or None if it was deleted or
+    // shifted past the end of the worksheet.
+    pub(crate) fn col(&self, col: ColNum) -> Option<ColNum> {
+        match self.axis {
+            ShiftAxis::Rows => Some(col),
+            ShiftAxis::Columns => self
+                .index(u32::from(col), u32::from(COL_MAX))
+                .map(|col| col as ColNum),
+        }
+    }
+
+    // Get the new position of a cell, or None if it was deleted.
+    pub(crate) fn cell(&self, row: RowNum, col: ColNum) -> Option<(RowNum, ColNum)> {
+        Some((self.row(row)?, self.col(col)?))
+    }
+
+    // Get the new extent of a cell range, or None if all of it was deleted.
+    // Ranges are expanded by rows or columns inserted within them and
+    // contracted by deletions that overlap them.
+    pub(crate) fn range(
+        &self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Option<(RowNum, ColNum, RowNum, ColNum)> {
+        match self.axis {
+            ShiftAxis::Rows => {
+                let (first_row, last_row) = self.span(first_row, last_row, ROW_MAX)?;
+                Some((first_row, first_col, last_row, last_col))
+            }
+            ShiftAxis::Columns => {
+                let (first_col, last_col) = self.span(
+                    u32::from(first_col),
+                    u32::from(last_col),
+                    u32::from(COL_MAX),
+                )?;
+                Some((first_row, first_col as ColNum, last_row, last_col as ColNum))
+            }
+        }
+    }
+
+    // Get the new extent of a CellRange, or None if all of it was deleted.
+    pub(crate) fn cell_range(&self, cell_range: &CellRange) -> Option<CellRange> {
+        let (first_row, first_col, last_row, last_col) = self.range(
+            cell_range.first_row,
+            cell_range.first_col,
+            cell_range.last_row,
+            cell_range.last_col,
+        )?;
+
+        Some(CellRange::new(first_row, first_col, last_row, last_col))
+    }
+
+    // Get the new position of an object anchored to a cell such as an image
+    // or chart, or None if the anchor cell was deleted. Objects with an
+    // absolute anchor keep their position in the worksheet.
+    pub(crate) fn anchor(
+        &self,
+        key: (RowNum, ColNum, u32, u32),
+        object_movement: ObjectMovement,
+    ) -> Option<(RowNum, ColNum, u32, u32)> {
+        if object_movement == ObjectMovement::AbsoluteAnchor {
+            return Some(key);
+        }
+
+        let (row, col) = self.cell(key.0, key.1)?;
+        Some((row, col, key.2, key.3))
+    }
+
+    // Adjust a space separated list of ranges, such as the "A1:B3 D5" sqref
+    // ranges used by conditional formats. Deleted ranges are removed from the
+    // list and None is returned if all of them were deleted.
+    pub(crate) fn sqref(&self, sqref: &str) -> Option<String> {
+        let ranges: Vec<String> = sqref
+            .split_whitespace()
+            .filter_map(|range| {
+                let range = self.formula(range, "", true);
+                if range.contains("#REF!") {
+                    None
+                } else {
+                    Some(range)
+                }
+            })
+            .collect();
+
+        if ranges.is_empty() {
+            None
+        } else {
+            Some(ranges.join(" "))
+        }
+    }
+
+    // Adjust the cell references in a formula. References with a sheet name
+    // are adjusted if the name matches `sheet_name`. References without a
+    // sheet name are adjusted if `is_local` is true, i.e., if the formula is
+    // in the shifted worksheet. References to deleted cells are replaced with
+    // Excel's `#REF!` error.
+    pub(crate) fn formula(&self, formula: &str, sheet_name: &str, is_local: bool) -> String {
+        map_references(formula, |name, reference| {
+            let is_match = match name {
+                Some(name) => matches_sheet(name, sheet_name),
+                None => is_local,
+            };
+
+            if is_match {
+                Some(
+                    self.shift_reference_string(reference)
+                        .unwrap_or_else(|| "#REF!".to_string()),
+                )
+            } else {
+                None
+            }
+        })
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal methods.
+    // -----------------------------------------------------------------------
+
+    // Shift a zero indexed row or column number.
+    fn index(&self, index: u32, max: u32) -> Option<u32> {
+        if index < self.position {
+            Some(index)
+        } else if self.is_insert {
+            let index = index + self.count;
+            if index < max {
+                Some(index)
+            } else {
+                None
+            }
+        } else if index < self.position + self.count {
+            None
+        } else {
+            Some(index - self.count)
+        }
+    }
+
+    // Shift a first/last span of rows or columns.
+    fn span(&self, first: u32, last: u32, max: u32) -> Option<(u32, u32)> {
+        let position = self.position;
+
+        if self.is_insert {
+            let first = self.index(first, max)?;
+            let last = if last < position {
+                last
+            } else {
+                (last + self.count).min(max - 1)
+            };
+
+            return Some((first, last));
+        }
+
+        let end = position + self.count;
+        if first >= position && last < end {
+            return None;
+        }
+
+        let first = if first < position {
+            first
+        } else if first < end {
+            position
+        } else {
+            first - self.count
+        };
+
+        let last = if last < position {
+            last
+        } else if last < end {
+            position - 1
+        } else {
+            last - self.count
+        };
+
+        Some((first, last))
+    }
+
+    // Shift a cell, range, row range or column range reference string such as
+    // "$A$1", "A1:B2", "1:3" or "A:C".
+    fn shift_reference_string(&self, reference: &str) -> Option<String> {
+        let (first, last) = match reference.split_once(':') {
+            Some((first, last)) => (first, Some(last)),
+            None => (reference, None),
+        };
+
+        let first = ReferencePart::parse(first)?;
+        let last = match last {
+            Some(last) => ReferencePart::parse(last)?,
+            None => first.clone(),
+        };
+
+        let (first_row, first_col, last_row, last_col) = self.range(
+            first.row.unwrap_or(0),
+            first.col.unwrap_or(0),
+            last.row.unwrap_or(ROW_MAX - 1),
+            last.col.unwrap_or(COL_MAX - 1),
+        )?;
+
+        let first = first.with_position(first_row, first_col);
+        if reference.contains(':') {
+            let last = last.with_position(last_row, last_col);
+            Some(format!("{first}:{last}"))
+        } else {
+            Some(first)
+        }
+    }
+
+    // Check if the characters at position `i` are a cell, range, row range or
+    // column range reference, and return the end position if so.
+    fn reference_end(chars: &[char], i: usize) -> Option<usize> {
+        let (first_end, first) = ReferencePart::scan(chars, i)?;
+
+        let end = if chars.get(first_end) == Some(&':') {
+            match ReferencePart::scan(chars, first_end + 1) {
+                Some((last_end, last)) if first.is_same_type(&last) => last_end,
+                _ if first.is_cell() => first_end,
+                _ => return None,
+            }
+        } else if first.is_cell() {
+            first_end
+        } else {
+            return None;
+        };
+
+        // The reference must not be part of a longer name or a function name.
+        match chars.get(end) {
+            Some(char) if Self::is_name_char(*char) || ['(', '!', '['].contains(char) => None,
+            _ => Some(end),
+        }
+    }
+
+    // Check if a character can be part of an unquoted name.
+    fn is_name_char(char: char) -> bool {
+        char.is_alphanumeric() || char == '_' || char == '.' || char == '\\'
+    }
+
+    // Find the end of a run of name characters, including "$" anchors.
+    fn name_end(chars: &[char], i: usize) -> usize {
+        let mut end = i;
+        while end < chars.len() && (Self::is_name_char(chars[end]) || chars[end] == '$') {
+            end += 1;
+        }
+        end
+    }
+
+    // Find the end of a quoted string, handling doubled quote escapes.
+    fn quoted_end(chars: &[char], i: usize, quote: char) -> usize {
+        let mut end = i + 1;
+        while end < chars.len() {
+            if chars[end] == quote {
+                if chars.get(end + 1) == Some(&quote) {
+                    end += 2;
+                    continue;
+                }
+                return end + 1;
+            }
+            end += 1;
+        }
+        end
+    }
+
+    // Find the end of a, possibly nested, bracketed reference.
+    fn bracket_end(chars: &[char], i: usize) -> usize {
+        let mut depth = 0;
+        let mut end = i;
+        while end < chars.len() {
+            match chars[end] {
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return end + 1;
+                    }
+                }
+                _ => {}
+            }
+            end += 1;
+        }
+        end
+    }
+}
+
+// A struct to represent a range of cells that is moved to another position in
+// a worksheet, like an Excel cut and paste. The references to the moved cells
+// are moved with them and the references to the overwritten cells are deleted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RangeMove {
+    first_row: RowNum,
+    first_col: ColNum,
+    last_row: RowNum,
+    last_col: ColNum,
+    row: RowNum,
+    col: ColNum,
+}
+
+impl RangeMove {
+    // Create a new move of a range to a target cell.
+    pub(crate) fn new(
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        row: RowNum,
+        col: ColNum,
+    ) -> Self {
+        RangeMove {
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            row,
+            col,
+        }
+    }
+
+    // Get the new extent of a cell range. Ranges that are entirely within the
+    // moved cells are moved with them, ranges that are entirely within the
+    // overwritten cells are deleted, and other ranges are unchanged, like
+    // Excel.
+    pub(crate) fn range(
+        &self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Option<(RowNum, ColNum, RowNum, ColNum)> {
+        if first_row >= self.first_row
+            && last_row <= self.last_row
+            && first_col >= self.first_col
+            && last_col <= self.last_col
+        {
+            return Some((
+                first_row - self.first_row + self.row,
+                first_col - self.first_col + self.col,
+                last_row - self.first_row + self.row,
+                last_col - self.first_col + self.col,
+            ));
+        }
+
+        if first_row >= self.row
+            && last_row <= self.row + (self.last_row - self.first_row)
+            && first_col >= self.col
+            && last_col <= self.col + (self.last_col - self.first_col)
+        {
+            return None;
+        }
+
+        Some((first_row, first_col, last_row, last_col))
+    }
+
+    // Adjust the cell and range references in a formula, with or without "$"
+    // anchors. The sheet names are matched in the same way as
+    // ReferenceShift::formula(). References to deleted cells are replaced with
+    // Excel's `#REF!` error.
+    pub(crate) fn formula(&self, formula: &str, sheet_name: &str, is_local: bool) -> String {
+        map_references(formula, |name, reference| {
+            let is_match = match name {
+                Some(name) => matches_sheet(name, sheet_name),
+                None => is_local,
+            };
+
+            if !is_match {
+                return None;
+            }
+
+            let parts: Vec<ReferencePart> = reference
+                .split(':')
+                .map(ReferencePart::parse)
+                .collect::<Option<_>>()?;
+            let (first, last) = (parts.first()?, parts.last()?);
+
+            // Row and column range references aren't changed by a move.
+            let range = (first.row?, first.col?, last.row?, last.col?);
+
+            match self.range(range.0, range.1, range.2, range.3) {
+                Some(moved) if moved == range => None,
+                Some((first_row, first_col, last_row, last_col)) => {
+                    let first = first.with_position(first_row, first_col);
+                    if parts.len() > 1 {
+                        let last = last.with_position(last_row, last_col);
+                        Some(format!("{first}:{last}"))
+                    } else {
+                        Some(first)
+                    }
+                }
+                None => Some("#REF!".to_string()),
+            }
+        })
+    }
+}
+
+// Check if a sheet name in a reference refers to a worksheet. Excel sheet names
+// are case insensitive.
+fn matches_sheet(name: &str, sheet_name: &str) -> bool {
+    !sheet_name.is_empty() && name.to_lowercase() == sheet_name.to_lowercase()
+}
+
+// Adjust the relative cell references in a formula that is copied from one
+// cell to another, in the same way as Excel's paste. The absolute, "$"
+// anchored, parts of the references aren't changed. References that are moved
+// outside the worksheet are replaced with Excel's `#REF!` error.
+pub(crate) fn offset_formula(formula: &str, row_offset: i64, col_offset: i64) -> String {
+    map_references(formula, |_, reference| {
+        let parts: Option<Vec<String>> = reference
+            .split(':')
+            .map(|part| {
+                let part = ReferencePart::parse(part)?;
+
+                let row = match part.row {
+                    Some(row) if !part.row_absolute => offset_index(row, row_offset, ROW_MAX)?,
+                    Some(row) => row,
+                    None => 0,
+                };
+
+                let col = match part.col {
+                    Some(col) if !part.col_absolute => {
+                        offset_index(u32::from(col), col_offset, u32::from(COL_MAX))?
+                    }
+                    Some(col) => u32::from(col),
+                    None => 0,
+                };
+
+                Some(part.with_position(row, col as ColNum))
+            })
+            .collect();
+
+        Some(parts.map_or_else(|| "#REF!".to_string(), |parts| parts.join(":")))
+    })
+}
+
+// Adjust the references in a formula that is copied and transposed, with the
+// first copied cell pasted at `row` and `col`. References to cells within the
+// copied range are moved to the transposed position of the cells, like Excel.
+// Other references are adjusted in the same way as `offset_formula()`.
+pub(crate) fn transpose_formula(
+    formula: &str,
+    copied_range: &CellRange,
+    row: RowNum,
+    col: ColNum,
+    row_offset: i64,
+    col_offset: i64,
+) -> String {
+    map_references(formula, |name, reference| {
+        let parts: Option<Vec<ReferencePart>> =
+            reference.split(':').map(ReferencePart::parse).collect();
+
+        if let (None, Some(parts)) = (name, parts) {
+            let is_copied = parts.iter().all(|part| match (part.row, part.col) {
+                (Some(part_row), Some(part_col)) => copied_range.contains(part_row, part_col),
+                _ => false,
+            });
+
+            if is_copied {
+                let parts: Vec<String> =
+                    parts
+                        .iter()
+                        .filter_map(|part| {
+                            let part_row = part.row? - copied_range.first_row;
+                            let part_col = part.col? - copied_range.first_col;
+                            Some(part.with_position(
+                                row + RowNum::from(part_col),
+                                col + part_row as ColNum,
+                            ))
+                        })
+                        .collect();
+
+                return Some(parts.join(":"));
+            }
+        }
+
+        Some(offset_formula(reference, row_offset, col_offset))
+    })
+}
+
+// Parse a cell or cell range reference, such as "B3" or "$A$1:C4", into its
+// zero indexed (first_row, first_col, last_row, last_col) cells. Returns None
+// for other types of references.
+pub(crate) fn parse_range(range: &str) -> Option<(RowNum, ColNum, RowNum, ColNum)> {
+    let (first, last) = range.split_once(':').unwrap_or((range, range));
+    let first = ReferencePart::parse(first)?;
+    let last = ReferencePart::parse(last)?;
+
+    if !first.is_cell() || !last.is_cell() {
+        return None;
+    }
+
+    Some((first.row?, first.col?, last.row?, last.col?))
+}
+
+// Offset a zero indexed row or column number, or return None if it is outside
+// the worksheet.
+fn offset_index(index: u32, offset: i64, max: u32) -> Option<u32> {
+    let index = i64::from(index) + offset;
+
+    if (0..i64::from(max)).contains(&index) {
+        Some(index as u32)
+    } else {
+        None
+    }
+}
+
+// Find the cell, range, row range and column range references in a formula
+// and replace them with the result of the `map_reference` function. It is
+// called with the sheet name of the reference, if any, and the reference, and
+// returns None if the reference is unchanged.
+fn map_references(
+    formula: &str,
+    mut map_reference: impl FnMut(Option<&str>, &str) -> Option<String>,
+) -> String {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut mapped = String::with_capacity(formula.len());
+    let mut i = 0;
+
+    // Map the reference, if any, at position `i` and append it to the output
+    // string. Returns the position after the reference.
+    let mut push_reference = |i: usize, name: Option<&str>, mapped: &mut String| -> usize {
+        let Some(end) = ReferenceShift::reference_end(&chars, i) else {
+            return i;
+        };
+
+        let reference: String = chars[i..end].iter().collect();
+        let reference = map_reference(name, &reference).unwrap_or(reference);
+        mapped.push_str(&reference);
+
+        end
+    };
+
+    while i < chars.len() {
+        let char = chars[i];
+
+        // Copy string literals, with "" escapes, unchanged.
+        if char == '"' {
+            let end = ReferenceShift::quoted_end(&chars, i, '"');
+            mapped.extend(&chars[i..end]);
+            i = end;
+            continue;
+        }
+
+        // Copy structured and external workbook references unchanged.
+        if char == '[' {
+            let end = ReferenceShift::bracket_end(&chars, i);
+            mapped.extend(&chars[i..end]);
+            i = end;
+            continue;
+        }
+
+        // Check for a quoted sheet name like 'Sheet 1'!A1.
+        if char == '\'' {
+            let end = ReferenceShift::quoted_end(&chars, i, '\'');
+            if chars.get(end) == Some(&'!') {
+                let name: String = chars[i + 1..end - 1].iter().collect();
+                let name = name.replace("''", "'");
+                mapped.extend(&chars[i..=end]);
+                i = push_reference(end + 1, Some(&name), &mut mapped);
+            } else {
+                mapped.extend(&chars[i..end]);
+                i = end;
+            }
+            continue;
+        }
+
+        // Other characters that can't start a reference or name.
+        if !ReferenceShift::is_name_char(char) && char != '$' {
+            mapped.push(char);
+            i += 1;
+            continue;
+        }
+
+        // Names, function names and numbers are copied unchanged. Sheet names
+        // are followed by "!" and precede a reference.
+        let end = ReferenceShift::name_end(&chars, i);
+        if chars.get(end) == Some(&'!') {
+            let name: String = chars[i..end].iter().collect();
+            mapped.extend(&chars[i..=end]);
+            i = push_reference(end + 1, Some(&name), &mut mapped);
+            continue;
+        }
+
+        if ReferenceShift::reference_end(&chars, i).is_some() {
+            i = push_reference(i, None, &mut mapped);
+            continue;
+        }
+
+        let end = end.max(i + 1);
+        mapped.extend(&chars[i..end]);
+        i = end;
+    }
+
+    mapped
+}
+
+// Change the sheet name in the references of a formula, such as `Sheet1!A1` or
+// `'Sheet 1'!A1`, when a worksheet is duplicated or removed. If `new_name` is
+// None the sheet name is replaced with Excel's `#REF!` error, like Excel does
+// for references to deleted worksheets.
+pub(crate) fn rename_sheet_references(
+    formula: &str,
+    old_name: &str,
+    new_name: Option<&str>,
+) -> String {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut renamed = String::with_capacity(formula.len());
+    let old_name = old_name.to_lowercase();
+    let mut i = 0;
+
+    let replacement = match new_name {
+        Some(new_name) => format!("{}!", utility::quote_sheet_name(new_name)),
+        None => "#REF!".to_string(),
+    };
+
+    while i < chars.len() {
+        let char = chars[i];
+
+        // Find the end of the current token and the sheet name, if it could
+        // be one. String literals and bracketed references are copied
+        // unchanged.
+        let (end, name) = match char {
+            '"' => (ReferenceShift::quoted_end(&chars, i, '"'), None),
+            '[' => (ReferenceShift::bracket_end(&chars, i), None),
+            '\'' => {
+                let end = ReferenceShift::quoted_end(&chars, i, '\'');
+                let name: String = chars[i + 1..end.max(i + 2) - 1].iter().collect();
+                (end, Some(name.replace("''", "'")))
+            }
+            _ if ReferenceShift::is_name_char(char) || char == '$' => {
+                let end = ReferenceShift::name_end(&chars, i);
+                (end, Some(chars[i..end].iter().collect()))
+            }
+            _ => (i + 1, None),
+        };
+
+        if let Some(name) = name {
+            if chars.get(end) == Some(&'!') && name.to_lowercase() == old_name {
+                renamed.push_str(&replacement);
+                i = end + 1;
+                continue;
+            }
+        }
+
+        renamed.extend(&chars[i..end]);
+        i = end;
+    }
+
+    renamed
+}
+
+// Find the 3D references in a formula, such as `Sheet1:Sheet3!A1` or
+// `'Sheet 1:Sheet 3'!A1`, and return the first and last worksheet names of
+// the spanned worksheets.
+pub(crate) fn sheet_spans(formula: &str) -> Vec<(String, String)> {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut spans = vec![];
+    let mut first_name: Option<String> = None;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let char = chars[i];
+
+        // Find the end of the current token and the sheet name, if it could
+        // be one, in the same way as rename_sheet_references().
+        let (end, name) = match char {
+            '"' => (ReferenceShift::quoted_end(&chars, i, '"'), None),
+            '[' => (ReferenceShift::bracket_end(&chars, i), None),
+            '\'' => {
+                let end = ReferenceShift::quoted_end(&chars, i, '\'');
+                let name: String = chars[i + 1..end.max(i + 2) - 1].iter().collect();
+                (end, Some(name.replace("''", "'")))
+            }
+            _ if ReferenceShift::is_name_char(char) || char == '$' => {
+                let end = ReferenceShift::name_end(&chars, i);
+                (end, Some(chars[i..end].iter().collect()))
+            }
+            _ => (i + 1, None),
+        };
+
+        // An unquoted span is made up of the first name, a ":" and the last
+        // name before the "!". A quoted span contains the ":".
+        match (name, chars.get(end)) {
+            (Some(name), Some('!')) => {
+                if let Some((first, last)) = name.split_once(':') {
+                    spans.push((first.to_string(), last.to_string()));
+                } else if let Some(first) = first_name.take() {
+                    spans.push((first, name));
+                }
+                first_name = None;
+                i = end + 1;
+            }
+            (Some(name), Some(':')) => {
+                first_name = Some(name);
+                i = end + 1;
+            }
+            _ => {
+                first_name = None;
+                i = end;
+            }
+        }
+    }
+
+    spans
+}
+
+// A struct to represent one side of a reference, such as "$A1", "B" or "3",
+// with optional row and column parts.
+#[derive(Clone)]
+struct ReferencePart {
+    row: Option<RowNum>,
+    col: Option<ColNum>,
+    row_absolute: bool,
+    col_absolute: bool,
+}
+
+impl ReferencePart {
+    // Scan a reference part at position `i` and return its end position.
+    fn scan(chars: &[char], i: usize) -> Option<(usize, ReferencePart)> {
+        let mut end = i;
+
+        let col_absolute = chars.get(end) == Some(&'$');
+        if col_absolute {
+            end += 1;
+        }
+
+        let col_start = end;
+        while end < chars.len() && chars[end].is_ascii_alphabetic() && end - col_start < 3 {
+            end += 1;
+        }
+        let col_end = end;
+
+        let row_absolute = col_end > col_start && chars.get(end) == Some(&'$');
+        if row_absolute {
+            end += 1;
+        }
+
+        let row_start = end;
+        while end < chars.len() && chars[end].is_ascii_digit() {
+            end += 1;
+        }
+        let row_end = end;
+
+        // A "$" on its own, or a "$" between the column and a missing row.
+        if row_end == row_start && (col_end == col_start || row_absolute) {
+            return None;
+        }
+
+        // Row only references can have a "$" anchor in the column position.
+        let (row_absolute, col_absolute) = if col_end == col_start {
+            (col_absolute, false)
+        } else {
+            (row_absolute, col_absolute)
+        };
+
+        let col = if col_end > col_start {
+            let name: String = chars[col_start..col_end].iter().collect();
+            let col = utility::column_name_to_number(&name.to_uppercase());
+            if col >= COL_MAX {
+                return None;
+            }
+            Some(col)
+        } else {
+            None
+        };
+
+        let row = if row_end > row_start {
+            let row: String = chars[row_start..row_end].iter().collect();
+            let row = row.parse::<RowNum>().ok()?;
+            if row == 0 || row > ROW_MAX {
+                return None;
+            }
+            Some(row - 1)
+        } else {
+            None
+        };
+
+        Some((
+            end,
+            ReferencePart {
+                row,
+                col,
+                row_absolute,
+                col_absolute,
+            },
+        ))
+    }
+
+    // Parse a reference part string.
+    fn parse(reference: &str) -> Option<ReferencePart> {
+        let chars: Vec<char> = reference.chars().collect();
+        match Self::scan(&chars, 0) {
+            Some((end, part)) if end == chars.len() => Some(part),
+            _ => None,
+        }
+    }
+
+    // Check if the part is a cell reference with a row and column.
+    fn is_cell(&self) -> bool {
+        self.row.is_some() && self.col.is_some()
+    }
+
+    // Check if two parts are the same type of reference.
+    fn is_same_type(&self, other: &ReferencePart) -> bool {
+        self.row.is_some() == other.row.is_some() && self.col.is_some() == other.col.is_some()
+    }
+
+    // Get the reference string for the part at a new position.
+    fn with_position(&self, row: RowNum, col: ColNum) -> String {
+        let mut reference = String::new();
+
+        if self.col.is_some() {
+            if self.col_absolute {
+                reference.push('$');
+            }
+            reference.push_str(&utility::column_number_to_name(col));
+        }
+
+        if self.row.is_some() {
+            if self.row_absolute {
+                reference.push('$');
+            }
+            reference.push_str(&(row + 1).to_string());
+        }
+
+        reference
+    }
+}
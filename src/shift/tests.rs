@@ -0,0 +1,221 @@
+// Reference shift unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod shift_tests {
+
+    use crate::shift::{
+        offset_formula, parse_range, rename_sheet_references, sheet_spans, transpose_formula,
+        RangeMove, ReferenceShift, ShiftAxis,
+    };
+    use crate::worksheet::CellRange;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_shift_rows() {
+        // Insert 2 rows before row 3 (zero indexed 2).
+        let shift = ReferenceShift::new(ShiftAxis::Rows, 2, 2, true);
+
+        assert_eq!(shift.row(1), Some(1));
+        assert_eq!(shift.row(2), Some(4));
+        assert_eq!(shift.row(1_048_575), None);
+        assert_eq!(shift.col(5), Some(5));
+        assert_eq!(shift.range(0, 0, 5, 1), Some((0, 0, 7, 1)));
+        assert_eq!(shift.range(3, 0, 5, 1), Some((5, 0, 7, 1)));
+
+        // Delete 2 rows from row 3 (zero indexed 2).
+        let shift = ReferenceShift::new(ShiftAxis::Rows, 2, 2, false);
+
+        assert_eq!(shift.row(1), Some(1));
+        assert_eq!(shift.row(2), None);
+        assert_eq!(shift.row(3), None);
+        assert_eq!(shift.row(4), Some(2));
+        assert_eq!(shift.range(0, 0, 5, 1), Some((0, 0, 3, 1)));
+        assert_eq!(shift.range(2, 0, 3, 1), None);
+        assert_eq!(shift.range(3, 0, 6, 1), Some((2, 0, 4, 1)));
+        assert_eq!(shift.range(0, 0, 2, 1), Some((0, 0, 1, 1)));
+    }
+
+    #[test]
+    fn test_shift_columns() {
+        // Insert 1 column before column B.
+        let shift = ReferenceShift::new(ShiftAxis::Columns, 1, 1, true);
+
+        assert_eq!(shift.col(0), Some(0));
+        assert_eq!(shift.col(1), Some(2));
+        assert_eq!(shift.col(16_383), None);
+        assert_eq!(shift.row(5), Some(5));
+        assert_eq!(shift.range(0, 0, 5, 1), Some((0, 0, 5, 2)));
+
+        // Delete columns B:C.
+        let shift = ReferenceShift::new(ShiftAxis::Columns, 1, 2, false);
+
+        assert_eq!(shift.col(1), None);
+        assert_eq!(shift.col(3), Some(1));
+        assert_eq!(shift.range(0, 1, 5, 2), None);
+        assert_eq!(shift.range(0, 0, 5, 4), Some((0, 0, 5, 2)));
+    }
+
+    #[test]
+    fn test_shift_formulas() {
+        let shift = ReferenceShift::new(ShiftAxis::Rows, 2, 2, true);
+
+        let tests = [
+            ("A1+A3", "A1+A5"),
+            ("$A$3*2", "$A$5*2"),
+            ("SUM(B2:B10)", "SUM(B2:B12)"),
+            ("SUM(B3:B10)", "SUM(B5:B12)"),
+            ("SUM(A:A)", "SUM(A:A)"),
+            ("SUM(3:4)", "SUM(5:6)"),
+            ("SUM($1:$3)", "SUM($1:$5)"),
+            ("Sheet1!A3+Sheet2!A3", "Sheet1!A5+Sheet2!A3"),
+            ("'Sheet1'!A3", "'Sheet1'!A5"),
+            (r#""A3"&A3"#, r#""A3"&A5"#),
+            ("LOG10(A3)", "LOG10(A5)"),
+            ("Table1[Column1]+A3", "Table1[Column1]+A5"),
+            ("MyName+A3", "MyName+A5"),
+            ("1.5E+3+A3", "1.5E+3+A5"),
+            (
+                "_xlfn.XLOOKUP(A3,B1:B9,C1:C9)",
+                "_xlfn.XLOOKUP(A5,B1:B11,C1:C11)",
+            ),
+        ];
+
+        for (formula, expected) in tests {
+            assert_eq!(shift.formula(formula, "Sheet1", true), expected);
+        }
+
+        // References without a sheet name in other worksheets aren't shifted.
+        assert_eq!(
+            shift.formula("A3+Sheet1!A3", "Sheet1", false),
+            "A3+Sheet1!A5"
+        );
+
+        let shift = ReferenceShift::new(ShiftAxis::Columns, 1, 1, false);
+
+        let tests = [
+            ("A1+B1+C1", "A1+#REF!+B1"),
+            ("SUM(A1:C1)", "SUM(A1:B1)"),
+            ("SUM(B:B)", "SUM(#REF!)"),
+            ("Sheet1!B1", "Sheet1!#REF!"),
+            ("SUM(1:1)", "SUM(1:1)"),
+        ];
+
+        for (formula, expected) in tests {
+            assert_eq!(shift.formula(formula, "Sheet1", true), expected);
+        }
+    }
+
+    #[test]
+    fn test_move_range() {
+        // Move A1:B2 to D4:E5.
+        let range_move = RangeMove::new(0, 0, 1, 1, 3, 3);
+
+        assert_eq!(range_move.range(0, 0, 1, 1), Some((3, 3, 4, 4)));
+        assert_eq!(range_move.range(1, 0, 1, 0), Some((4, 3, 4, 3)));
+        assert_eq!(range_move.range(0, 0, 2, 1), Some((0, 0, 2, 1)));
+        assert_eq!(range_move.range(4, 4, 4, 4), None);
+        assert_eq!(range_move.range(4, 4, 5, 5), Some((4, 4, 5, 5)));
+
+        let tests = [
+            ("A1+$B$2", "D4+$E$5"),
+            ("SUM(A1:B2)", "SUM(D4:E5)"),
+            ("SUM(A1:B3)", "SUM(A1:B3)"),
+            ("E5+C3", "#REF!+C3"),
+            ("SUM(A:A)+SUM(1:2)", "SUM(A:A)+SUM(1:2)"),
+            ("Sheet1!A1+Sheet2!A1", "Sheet1!D4+Sheet2!A1"),
+        ];
+
+        for (formula, expected) in tests {
+            assert_eq!(range_move.formula(formula, "Sheet1", true), expected);
+        }
+
+        // References without a sheet name in other worksheets aren't moved.
+        assert_eq!(
+            range_move.formula("A1+Sheet1!A1", "Sheet1", false),
+            "A1+Sheet1!D4"
+        );
+    }
+
+    #[test]
+    fn test_shift_sqref() {
+        let shift = ReferenceShift::new(ShiftAxis::Rows, 0, 1, false);
+
+        assert_eq!(shift.sqref("A1:B3 D1 E5"), Some("A1:B2 E4".to_string()));
+        assert_eq!(shift.sqref("A1 B1"), None);
+    }
+
+    #[test]
+    fn test_rename_sheet_references() {
+        let formula = "SUM(Sheet1!A1:B2)+'Sheet1'!C3+Sheet10!A1+\"Sheet1!A1\"";
+
+        assert_eq!(
+            rename_sheet_references(formula, "sheet1", Some("Sheet 2")),
+            "SUM('Sheet 2'!A1:B2)+'Sheet 2'!C3+Sheet10!A1+\"Sheet1!A1\""
+        );
+
+        assert_eq!(
+            rename_sheet_references(formula, "Sheet1", None),
+            "SUM(#REF!A1:B2)+#REF!C3+Sheet10!A1+\"Sheet1!A1\""
+        );
+
+        assert_eq!(
+            rename_sheet_references("'It''s'!A1+A2", "It's", Some("Data")),
+            "Data!A1+A2"
+        );
+    }
+
+    #[test]
+    fn test_sheet_spans() {
+        let formula = "SUM(Sheet1:Sheet3!A1)+'Sheet 1:Sheet 3'!A1:B2+Sheet1!A1:B2+\"A:B!A1\"";
+
+        assert_eq!(
+            sheet_spans(formula),
+            vec![
+                ("Sheet1".to_string(), "Sheet3".to_string()),
+                ("Sheet 1".to_string(), "Sheet 3".to_string()),
+            ]
+        );
+
+        assert!(sheet_spans("SUM(A1:B2)+A:B+Sheet1!A1:Sheet2").is_empty());
+    }
+
+    #[test]
+    fn test_offset_formula() {
+        assert_eq!(
+            offset_formula("SUM(A1:B2)+$A$1+A$1+$A1", 2, 1),
+            "SUM(B3:C4)+$A$1+B$1+$A3"
+        );
+
+        assert_eq!(
+            offset_formula("Sheet2!B2+\"A1\"+1:2+C:D", 1, 1),
+            "Sheet2!C3+\"A1\"+2:3+D:E"
+        );
+
+        assert_eq!(
+            offset_formula("A1+$B$2+SUM(A1:B2)", -1, 0),
+            "#REF!+$B$2+SUM(#REF!)"
+        );
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("B3"), Some((2, 1, 2, 1)));
+        assert_eq!(parse_range("$A$1:C4"), Some((0, 0, 3, 2)));
+        assert_eq!(parse_range("A:B"), None);
+        assert_eq!(parse_range("Name"), None);
+    }
+
+    #[test]
+    fn test_transpose_formula() {
+        let copied_range = CellRange::new(0, 0, 4, 0);
+
+        assert_eq!(
+            transpose_formula("SUM(A2:A4)+B1+$C$1", &copied_range, 7, 0, 3, 4),
+            "SUM(B8:D8)+F4+$C$1"
+        );
+    }
+}
@@ -15,8 +15,8 @@ use crate::xmlwriter::{
     xml_start_tag_only,
 };
 use crate::{
-    Alignment, Border, Color, Fill, Font, FormatAlign, FormatBorder, FormatDiagonalBorder,
-    FormatPattern, FormatScript, FormatUnderline,
+    Alignment, Border, CellStyle, Color, CustomTableStyle, Fill, Font, FormatAlign, FormatBorder,
+    FormatDiagonalBorder, FormatGradient, FormatPattern, FormatScript, FormatUnderline,
 };
 
 pub struct Styles<'a> {
@@ -31,6 +31,10 @@ pub struct Styles<'a> {
     has_comments: bool,
     is_rich_string_style: bool,
     hyperlink_font_id: u16,
+    pub(crate) minor_font: String,
+    pub(crate) major_font: String,
+    pub(crate) cell_styles: &'a [CellStyle],
+    pub(crate) table_styles: &'a [CustomTableStyle],
 }
 
 impl<'a> Styles<'a> {
@@ -66,6 +70,10 @@ impl<'a> Styles<'a> {
             has_comments,
             is_rich_string_style,
             hyperlink_font_id: 0,
+            minor_font: "Calibri".to_string(),
+            major_font: "Cambria".to_string(),
+            cell_styles: &[],
+            table_styles: &[],
         }
     }
 
@@ -147,6 +155,13 @@ impl<'a> Styles<'a> {
             }
         }
 
+        // Write the named cell style font elements.
+        for cell_style in self.cell_styles {
+            if cell_style.format.has_font {
+                self.write_font(&cell_style.format.font, false);
+            }
+        }
+
         // Add the additional font for cell comments/notes. This isn't currently
         // configurable and probably won't be.
         if self.has_comments {
@@ -246,7 +261,10 @@ impl<'a> Styles<'a> {
 
     // Write the <name> element.
     fn write_font_name(&mut self, font: &Font) {
-        let attributes = [("val", font.name.as_ref())];
+        // The default fonts are written with the names of the theme fonts.
+        // Fonts that the user has set explicitly, even to "Calibri", are
+        // written unchanged.
+        let attributes = [("val", font.theme_name(&self.minor_font, &self.major_font))];
 
         if self.is_rich_string_style {
             xml_empty_tag(&mut self.writer, "rFont", &attributes);
@@ -346,6 +364,13 @@ impl<'a> Styles<'a> {
             }
         }
 
+        // Write the named cell style fill elements.
+        for cell_style in self.cell_styles {
+            if cell_style.format.has_fill {
+                self.write_fill(&cell_style.format.fill, false);
+            }
+        }
+
         xml_end_tag(&mut self.writer, "fills");
     }
 
@@ -360,6 +385,14 @@ impl<'a> Styles<'a> {
 
     // Write the user-defined <fill> element.
     fn write_fill(&mut self, fill: &Fill, dxf_format: bool) {
+        // Gradient fills take precedence over pattern fills.
+        if let Some(gradient) = &fill.gradient {
+            xml_start_tag_only(&mut self.writer, "fill");
+            self.write_gradient_fill(gradient);
+            xml_end_tag(&mut self.writer, "fill");
+            return;
+        }
+
         // Special handling for pattern only case.
         if fill.pattern != FormatPattern::None
             && (fill.background_color == Color::Default
@@ -417,6 +450,40 @@ impl<'a> Styles<'a> {
         xml_end_tag(&mut self.writer, "fill");
     }
 
+    // Write the <gradientFill> element.
+    fn write_gradient_fill(&mut self, gradient: &FormatGradient) {
+        let mut attributes = vec![];
+
+        if gradient.is_path {
+            attributes.push(("type", "path".to_string()));
+
+            // Excel omits the default 0 values for the path rectangle.
+            for (name, value) in [
+                ("left", &gradient.left),
+                ("right", &gradient.right),
+                ("top", &gradient.top),
+                ("bottom", &gradient.bottom),
+            ] {
+                if value != "0" {
+                    attributes.push((name, value.clone()));
+                }
+            }
+        } else if gradient.angle != 0 {
+            attributes.push(("degree", gradient.angle.to_string()));
+        }
+
+        xml_start_tag(&mut self.writer, "gradientFill", &attributes);
+
+        for (position, color) in &gradient.stops {
+            let attributes = [("position", position.clone())];
+            xml_start_tag(&mut self.writer, "stop", &attributes);
+            xml_empty_tag(&mut self.writer, "color", &color.attributes());
+            xml_end_tag(&mut self.writer, "stop");
+        }
+
+        xml_end_tag(&mut self.writer, "gradientFill");
+    }
+
     // Write the <borders> element.
     fn write_borders(&mut self) {
         let attributes = [("count", self.border_count.to_string())];
@@ -431,6 +498,13 @@ impl<'a> Styles<'a> {
             }
         }
 
+        // Write the named cell style border elements.
+        for cell_style in self.cell_styles {
+            if cell_style.format.has_border {
+                self.write_border(&cell_style.format.borders, false);
+            }
+        }
+
         xml_end_tag(&mut self.writer, "borders");
     }
 
@@ -500,9 +574,9 @@ impl<'a> Styles<'a> {
 
     // Write the <cellStyleXfs> element.
     fn write_cell_style_xfs(&mut self) {
-        let mut count = 1;
+        let mut count = 1 + self.cell_styles.len();
         if self.has_hyperlink_style {
-            count = 2;
+            count += 1;
         }
 
         let attributes = [("count", count.to_string())];
@@ -516,9 +590,69 @@ impl<'a> Styles<'a> {
             self.write_hyperlink_style_xf();
         }
 
+        for cell_style in self.cell_styles {
+            self.write_named_style_xf(&cell_style.format);
+        }
+
         xml_end_tag(&mut self.writer, "cellStyleXfs");
     }
 
+    // Write the style <xf> element for a user defined or built-in named style.
+    // The "apply" attributes are turned off for the format categories that
+    // aren't included in the style.
+    fn write_named_style_xf(&mut self, style_format: &Format) {
+        let has_alignment = style_format.has_alignment();
+        let has_protection = style_format.has_protection();
+        let default_format = Format::new();
+
+        let mut attributes = vec![
+            ("numFmtId", style_format.num_format_index.to_string()),
+            ("fontId", style_format.font_index.to_string()),
+            ("fillId", style_format.fill_index.to_string()),
+            ("borderId", style_format.border_index.to_string()),
+        ];
+
+        if style_format.num_format_index == 0 {
+            attributes.push(("applyNumberFormat", "0".to_string()));
+        }
+
+        if style_format.font == default_format.font {
+            attributes.push(("applyFont", "0".to_string()));
+        }
+
+        if style_format.fill_index == 0 {
+            attributes.push(("applyFill", "0".to_string()));
+        }
+
+        if style_format.borders == default_format.borders {
+            attributes.push(("applyBorder", "0".to_string()));
+        }
+
+        if !has_alignment {
+            attributes.push(("applyAlignment", "0".to_string()));
+        }
+
+        if !has_protection {
+            attributes.push(("applyProtection", "0".to_string()));
+        }
+
+        if has_alignment || has_protection {
+            xml_start_tag(&mut self.writer, "xf", &attributes);
+
+            if has_alignment {
+                self.write_alignment(style_format.alignment);
+            }
+
+            if has_protection {
+                self.write_protection(style_format);
+            }
+
+            xml_end_tag(&mut self.writer, "xf");
+        } else {
+            xml_empty_tag(&mut self.writer, "xf", &attributes);
+        }
+    }
+
     // Write the style <xf> element for the "Normal" style.
     fn write_normal_style_xf(&mut self) {
         let attributes = [
@@ -587,7 +721,11 @@ impl<'a> Styles<'a> {
         let has_alignment = xf_format.has_alignment();
         let has_protection = xf_format.has_protection();
         let apply_alignment = xf_format.apply_alignment();
-        let xf_id = i32::from(is_hyperlink);
+        let xf_id = if xf_format.cell_style.is_some() {
+            xf_format.style_xf_id
+        } else {
+            u32::from(is_hyperlink)
+        };
 
         let mut attributes = vec![
             ("numFmtId", xf_format.num_format_index.to_string()),
@@ -762,34 +900,57 @@ impl<'a> Styles<'a> {
 
     // Write the <cellStyles> element.
     fn write_cell_styles(&mut self) {
-        let mut count = 1;
+        // The cell styles are written in name order, like Excel. The style xf
+        // ids of the user styles follow the "Normal" and "Hyperlink" styles.
+        let mut cell_styles = vec![("Normal", 0, Some(0), false)];
+        let mut xf_id = 1;
+
         if self.has_hyperlink_style {
-            count = 2;
+            cell_styles.push(("Hyperlink", 1, Some(8), false));
+            xf_id = 2;
         }
 
-        let attributes = [("count", count.to_string())];
+        for cell_style in self.cell_styles {
+            cell_styles.push((
+                cell_style.name.as_str(),
+                xf_id,
+                cell_style.builtin_id,
+                cell_style.custom_builtin,
+            ));
+            xf_id += 1;
+        }
+
+        cell_styles.sort_by_key(|(name, _, _, _)| name.to_lowercase());
+
+        let attributes = [("count", cell_styles.len().to_string())];
 
         xml_start_tag(&mut self.writer, "cellStyles", &attributes);
 
         // Write the cellStyle elements.
-        if self.has_hyperlink_style {
-            self.write_hyperlink_cell_style();
+        for (name, xf_id, builtin_id, custom_builtin) in cell_styles {
+            self.write_cell_style(name, xf_id, builtin_id, custom_builtin);
         }
-        self.write_normal_cell_style();
 
         xml_end_tag(&mut self.writer, "cellStyles");
     }
 
-    // Write the <cellStyle> element for the "Normal" style.
-    fn write_normal_cell_style(&mut self) {
-        let attributes = [("name", "Normal"), ("xfId", "0"), ("builtinId", "0")];
+    // Write the <cellStyle> element.
+    fn write_cell_style(
+        &mut self,
+        name: &str,
+        xf_id: u32,
+        builtin_id: Option<u8>,
+        custom_builtin: bool,
+    ) {
+        let mut attributes = vec![("name", name.to_string()), ("xfId", xf_id.to_string())];
 
-        xml_empty_tag(&mut self.writer, "cellStyle", &attributes);
-    }
+        if let Some(builtin_id) = builtin_id {
+            attributes.push(("builtinId", builtin_id.to_string()));
+        }
 
-    // Write the <cellStyle> element for the "Hyperlink" style.
-    fn write_hyperlink_cell_style(&mut self) {
-        let attributes = [("name", "Hyperlink"), ("xfId", "1"), ("builtinId", "8")];
+        if custom_builtin {
+            attributes.push(("customBuiltin", "1".to_string()));
+        }
 
         xml_empty_tag(&mut self.writer, "cellStyle", &attributes);
     }
@@ -836,12 +997,57 @@ impl<'a> Styles<'a> {
     // Write the <tableStyles> element.
     fn write_table_styles(&mut self) {
         let attributes = [
-            ("count", "0"),
-            ("defaultTableStyle", "TableStyleMedium9"),
-            ("defaultPivotStyle", "PivotStyleLight16"),
+            ("count", self.table_styles.len().to_string()),
+            ("defaultTableStyle", "TableStyleMedium9".to_string()),
+            ("defaultPivotStyle", "PivotStyleLight16".to_string()),
         ];
 
-        xml_empty_tag(&mut self.writer, "tableStyles", &attributes);
+        if self.table_styles.is_empty() {
+            xml_empty_tag(&mut self.writer, "tableStyles", &attributes);
+        } else {
+            xml_start_tag(&mut self.writer, "tableStyles", &attributes);
+
+            for table_style in self.table_styles {
+                self.write_table_style(table_style);
+            }
+
+            xml_end_tag(&mut self.writer, "tableStyles");
+        }
+    }
+
+    // Write the <tableStyle> element.
+    fn write_table_style(&mut self, table_style: &CustomTableStyle) {
+        let mut attributes = vec![("name", table_style.name.clone())];
+
+        if table_style.is_pivot_style {
+            attributes.push(("table", "0".to_string()));
+        } else {
+            attributes.push(("pivot", "0".to_string()));
+        }
+
+        attributes.push(("count", table_style.elements.len().to_string()));
+
+        if table_style.elements.is_empty() {
+            xml_empty_tag(&mut self.writer, "tableStyle", &attributes);
+            return;
+        }
+
+        xml_start_tag(&mut self.writer, "tableStyle", &attributes);
+
+        for ((element, _), dxf_index) in table_style
+            .elements
+            .iter()
+            .zip(table_style.dxf_indices.iter())
+        {
+            let attributes = [
+                ("type", element.xml_name().to_string()),
+                ("dxfId", dxf_index.to_string()),
+            ];
+
+            xml_empty_tag(&mut self.writer, "tableStyleElement", &attributes);
+        }
+
+        xml_end_tag(&mut self.writer, "tableStyle");
     }
 
     // Write the <numFmts> element.
@@ -9,7 +9,10 @@ mod styles_tests {
 
     use crate::styles::Styles;
     use crate::test_functions::xml_to_vec;
-    use crate::{xmlwriter, Format};
+    use crate::{
+        xmlwriter, BuiltinCellStyle, CellStyle, Color, CustomTableStyle, Format, FormatGradient,
+        FormatPattern, TableStyleElement,
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -84,4 +87,352 @@ mod styles_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_with_theme_minor_font() {
+        let mut xf_format = Format::new();
+        xf_format.set_font_index(0, true);
+        xf_format.set_border_index(0, true);
+
+        let mut font_format = Format::new().set_font_name("Arial");
+        font_format.set_font_index(1, true);
+
+        let mut calibri_format = Format::new().set_font_name("Calibri");
+        calibri_format.set_font_index(2, true);
+
+        let xf_formats = vec![xf_format, font_format, calibri_format];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(
+            &xf_formats,
+            &dxf_formats,
+            3,
+            2,
+            1,
+            vec![],
+            false,
+            false,
+            false,
+        );
+        styles.minor_font = "Aptos Narrow".to_string();
+
+        styles.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&styles.writer);
+
+        // Only the default font is changed, not a font explicitly set to the
+        // default font name.
+        assert!(got.contains(r#"<name val="Aptos Narrow"/><family val="2"/><scheme val="minor"/>"#));
+        assert!(got.contains(r#"<name val="Arial"/><family val="2"/></font>"#));
+        assert!(got.contains(r#"<name val="Calibri"/><family val="2"/><scheme val="minor"/>"#));
+        assert_eq!(1, got.matches("Aptos Narrow").count());
+    }
+
+    #[test]
+    fn test_assemble_with_cell_styles() {
+        let mut normal_format = Format::new();
+        normal_format.set_font_index(0, true);
+        normal_format.set_border_index(0, true);
+
+        // A user defined style and a built-in style, with the solid fill that
+        // the workbook creates from the style background color.
+        let mut house_style = CellStyle::new("House Style", &Format::new().set_bold());
+        house_style.format.set_font_index(2, true);
+
+        let mut good_style = CellStyle::new_builtin(BuiltinCellStyle::Good);
+        good_style.format.fill.foreground_color = Color::RGB(0xC6EFCE);
+        good_style.format.fill.background_color = Color::Default;
+        good_style.format.fill.pattern = FormatPattern::Solid;
+        good_style.format.set_font_index(3, true);
+        good_style.format.set_fill_index(2, false);
+
+        let mut good_format = Format::new().set_cell_style(&good_style).set_italic();
+        good_format.set_font_index(1, true);
+        good_format.set_fill_index(2, true);
+        good_format.style_xf_id = 2;
+
+        let cell_styles = vec![house_style, good_style];
+        let xf_formats = vec![normal_format, good_format];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(
+            &xf_formats,
+            &dxf_formats,
+            4,
+            3,
+            1,
+            vec![],
+            false,
+            false,
+            false,
+        );
+        styles.cell_styles = &cell_styles;
+
+        styles.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&styles.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+                  <fonts count="4">
+                    <font><sz val="11"/><color theme="1"/><name val="Calibri"/><family val="2"/><scheme val="minor"/></font>
+                    <font><i/><sz val="11"/><color rgb="FF006100"/><name val="Calibri"/><family val="2"/><scheme val="minor"/></font>
+                    <font><b/><sz val="11"/><color theme="1"/><name val="Calibri"/><family val="2"/><scheme val="minor"/></font>
+                    <font><sz val="11"/><color rgb="FF006100"/><name val="Calibri"/><family val="2"/><scheme val="minor"/></font>
+                  </fonts>
+                  <fills count="3">
+                    <fill><patternFill patternType="none"/></fill>
+                    <fill><patternFill patternType="gray125"/></fill>
+                    <fill><patternFill patternType="solid"><fgColor rgb="FFC6EFCE"/><bgColor indexed="64"/></patternFill></fill>
+                  </fills>
+                  <borders count="1">
+                    <border><left/><right/><top/><bottom/><diagonal/></border>
+                  </borders>
+                  <cellStyleXfs count="3">
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0"/>
+                    <xf numFmtId="0" fontId="2" fillId="0" borderId="0" applyNumberFormat="0" applyFill="0" applyBorder="0" applyAlignment="0" applyProtection="0"/>
+                    <xf numFmtId="0" fontId="3" fillId="2" borderId="0" applyNumberFormat="0" applyBorder="0" applyAlignment="0" applyProtection="0"/>
+                  </cellStyleXfs>
+                  <cellXfs count="2">
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>
+                    <xf numFmtId="0" fontId="1" fillId="2" borderId="0" xfId="2" applyFont="1" applyFill="1"/>
+                  </cellXfs>
+                  <cellStyles count="3">
+                    <cellStyle name="Good" xfId="2" builtinId="26"/>
+                    <cellStyle name="House Style" xfId="1"/>
+                    <cellStyle name="Normal" xfId="0" builtinId="0"/>
+                  </cellStyles>
+                  <dxfs count="0"/>
+                  <tableStyles count="0" defaultTableStyle="TableStyleMedium9" defaultPivotStyle="PivotStyleLight16"/>
+                </styleSheet>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_with_title_style_theme_font() {
+        let mut normal_format = Format::new();
+        normal_format.set_font_index(0, true);
+        normal_format.set_border_index(0, true);
+
+        let mut title_style = CellStyle::new_builtin(BuiltinCellStyle::Title);
+        title_style.format.set_font_index(1, false);
+
+        let mut title_format = Format::new().set_cell_style(&title_style);
+        title_format.set_font_index(1, true);
+        title_format.style_xf_id = 1;
+
+        let mut cambria_format = Format::new()
+            .set_cell_style(&title_style)
+            .set_font_name("Cambria");
+        cambria_format.set_font_index(2, true);
+        cambria_format.style_xf_id = 1;
+
+        let cell_styles = vec![title_style];
+        let xf_formats = vec![normal_format, title_format, cambria_format];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(
+            &xf_formats,
+            &dxf_formats,
+            3,
+            2,
+            1,
+            vec![],
+            false,
+            false,
+            false,
+        );
+        styles.minor_font = "Aptos Narrow".to_string();
+        styles.major_font = "Aptos Display".to_string();
+        styles.cell_styles = &cell_styles;
+
+        styles.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&styles.writer);
+
+        // The style uses the theme major font, unless the font is set
+        // explicitly.
+        assert!(
+            got.contains(r#"<name val="Aptos Display"/><family val="2"/><scheme val="major"/>"#)
+        );
+        assert!(got.contains(r#"<name val="Cambria"/><family val="2"/></font>"#));
+        assert_eq!(1, got.matches("Aptos Display").count());
+    }
+
+    #[test]
+    fn test_assemble_with_table_styles() {
+        let mut normal_format = Format::new();
+        normal_format.set_font_index(0, true);
+        normal_format.set_border_index(0, true);
+
+        let mut pivot_style = CustomTableStyle::new_pivot("Pivot Style")
+            .set_format(TableStyleElement::HeaderRow, &Format::new().set_bold());
+        pivot_style.dxf_indices = vec![0];
+
+        // The elements are written in Excel order, not the order they are set.
+        let mut table_style = CustomTableStyle::new("Table Style")
+            .set_format(
+                TableStyleElement::FirstRowStripe,
+                &Format::new().set_background_color("DDEBF7"),
+            )
+            .set_format(TableStyleElement::HeaderRow, &Format::new().set_bold());
+        table_style.dxf_indices = vec![0, 1];
+
+        let table_styles = vec![pivot_style, table_style];
+        let xf_formats = vec![normal_format];
+        let dxf_formats = vec![
+            Format::new().set_bold(),
+            Format::new().set_background_color("DDEBF7"),
+        ];
+        let mut styles = Styles::new(
+            &xf_formats,
+            &dxf_formats,
+            1,
+            2,
+            1,
+            vec![],
+            false,
+            false,
+            false,
+        );
+        styles.table_styles = &table_styles;
+
+        styles.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&styles.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+                  <fonts count="1">
+                    <font><sz val="11"/><color theme="1"/><name val="Calibri"/><family val="2"/><scheme val="minor"/></font>
+                  </fonts>
+                  <fills count="2">
+                    <fill><patternFill patternType="none"/></fill>
+                    <fill><patternFill patternType="gray125"/></fill>
+                  </fills>
+                  <borders count="1">
+                    <border><left/><right/><top/><bottom/><diagonal/></border>
+                  </borders>
+                  <cellStyleXfs count="1">
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0"/>
+                  </cellStyleXfs>
+                  <cellXfs count="1">
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>
+                  </cellXfs>
+                  <cellStyles count="1">
+                    <cellStyle name="Normal" xfId="0" builtinId="0"/>
+                  </cellStyles>
+                  <dxfs count="2">
+                    <dxf><font><b/></font></dxf>
+                    <dxf><fill><patternFill><bgColor rgb="FFDDEBF7"/></patternFill></fill></dxf>
+                  </dxfs>
+                  <tableStyles count="2" defaultTableStyle="TableStyleMedium9" defaultPivotStyle="PivotStyleLight16">
+                    <tableStyle name="Pivot Style" table="0" count="1">
+                      <tableStyleElement type="headerRow" dxfId="0"/>
+                    </tableStyle>
+                    <tableStyle name="Table Style" pivot="0" count="2">
+                      <tableStyleElement type="headerRow" dxfId="0"/>
+                      <tableStyleElement type="firstRowStripe" dxfId="1"/>
+                    </tableStyle>
+                  </tableStyles>
+                </styleSheet>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_with_gradient_fills() {
+        let linear = FormatGradient::new()
+            .set_angle(90)
+            .add_stop(0.0, "#FFFFFF")
+            .add_stop(1.0, "#4472C4");
+        let path = FormatGradient::new()
+            .set_path(0.5, 0.5, 0.5, 0.5)
+            .add_stop(0.0, "#FFFFFF")
+            .add_stop(0.5, "#ED7D31")
+            .add_stop(1.0, "#FFFFFF");
+        let corner = FormatGradient::new()
+            .set_path(0.0, 0.0, 0.0, 0.0)
+            .add_stop(0.0, "#FFFFFF")
+            .add_stop(1.0, "#70AD47");
+
+        let mut normal_format = Format::new();
+        normal_format.set_font_index(0, true);
+        normal_format.set_border_index(0, true);
+
+        let mut format1 = Format::new().set_gradient_fill(&linear);
+        format1.set_fill_index(2, true);
+
+        let mut format2 = Format::new().set_gradient_fill(&path);
+        format2.set_fill_index(3, true);
+
+        let mut format3 = Format::new().set_gradient_fill(&corner);
+        format3.set_fill_index(4, true);
+
+        let xf_formats = vec![normal_format, format1, format2, format3];
+        let dxf_formats = vec![Format::new().set_gradient_fill(&linear)];
+        let mut styles = Styles::new(
+            &xf_formats,
+            &dxf_formats,
+            1,
+            5,
+            1,
+            vec![],
+            false,
+            false,
+            false,
+        );
+
+        styles.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&styles.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+                  <fonts count="1">
+                    <font><sz val="11"/><color theme="1"/><name val="Calibri"/><family val="2"/><scheme val="minor"/></font>
+                  </fonts>
+                  <fills count="5">
+                    <fill><patternFill patternType="none"/></fill>
+                    <fill><patternFill patternType="gray125"/></fill>
+                    <fill><gradientFill degree="90"><stop position="0"><color rgb="FFFFFFFF"/></stop><stop position="1"><color rgb="FF4472C4"/></stop></gradientFill></fill>
+                    <fill><gradientFill type="path" left="0.5" right="0.5" top="0.5" bottom="0.5"><stop position="0"><color rgb="FFFFFFFF"/></stop><stop position="0.5"><color rgb="FFED7D31"/></stop><stop position="1"><color rgb="FFFFFFFF"/></stop></gradientFill></fill>
+                    <fill><gradientFill type="path"><stop position="0"><color rgb="FFFFFFFF"/></stop><stop position="1"><color rgb="FF70AD47"/></stop></gradientFill></fill>
+                  </fills>
+                  <borders count="1">
+                    <border><left/><right/><top/><bottom/><diagonal/></border>
+                  </borders>
+                  <cellStyleXfs count="1">
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0"/>
+                  </cellStyleXfs>
+                  <cellXfs count="4">
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>
+                    <xf numFmtId="0" fontId="0" fillId="2" borderId="0" xfId="0" applyFill="1"/>
+                    <xf numFmtId="0" fontId="0" fillId="3" borderId="0" xfId="0" applyFill="1"/>
+                    <xf numFmtId="0" fontId="0" fillId="4" borderId="0" xfId="0" applyFill="1"/>
+                  </cellXfs>
+                  <cellStyles count="1">
+                    <cellStyle name="Normal" xfId="0" builtinId="0"/>
+                  </cellStyles>
+                  <dxfs count="1">
+                    <dxf><fill><gradientFill degree="90"><stop position="0"><color rgb="FFFFFFFF"/></stop><stop position="1"><color rgb="FF4472C4"/></stop></gradientFill></fill></dxf>
+                  </dxfs>
+                  <tableStyles count="0" defaultTableStyle="TableStyleMedium9" defaultPivotStyle="PivotStyleLight16"/>
+                </styleSheet>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
@@ -11,10 +11,15 @@ mod tests;
 use std::io::Cursor;
 use std::{collections::HashSet, fmt};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::xmlwriter::{
     xml_data_element_only, xml_declaration, xml_empty_tag, xml_end_tag, xml_start_tag,
 };
-use crate::{utility::ToXmlBoolean, CellRange, Format, Formula, RowNum, XlsxError};
+use crate::{
+    utility::ToXmlBoolean, CellRange, CustomTableStyle, Format, Formula, RowNum, XlsxError,
+};
 
 /// The `Table` struct represents a worksheet table.
 ///
@@ -116,6 +121,7 @@ pub struct Table {
     pub(crate) index: u32,
     pub(crate) name: String,
     pub(crate) style: TableStyle,
+    pub(crate) custom_style: Option<CustomTableStyle>,
 
     pub(crate) cell_range: CellRange,
 
@@ -203,6 +209,7 @@ impl Table {
             index: 0,
             name: String::new(),
             style: TableStyle::Medium9,
+            custom_style: None,
             cell_range: CellRange::default(),
             show_first_column: false,
             show_last_column: false,
@@ -1111,6 +1118,87 @@ impl Table {
     ///
     pub fn set_style(mut self, style: TableStyle) -> Table {
         self.style = style;
+        self.custom_style = None;
+        self
+    }
+
+    /// Set a user defined style for the table.
+    ///
+    /// Set the table style to a [`CustomTableStyle`] where the formatting of
+    /// the table elements is defined using [`Format`] objects. The style is
+    /// added to the workbook automatically and overrides any style set with
+    /// [`Table::set_style()`].
+    ///
+    /// Custom styles should have unique names within a workbook. It is an
+    /// error, at save time, to use two different styles with the same name.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: A [`CustomTableStyle`] reference.
+    ///
+    /// # Examples
+    ///
+    /// An example of creating a custom table style and applying it to a table.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_custom_table_style.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     CustomTableStyle, Format, FormatBorder, Table, TableStyleElement, Workbook, XlsxError,
+    /// # };
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Some sample data for the table.
+    /// #     let items = ["Apples", "Pears", "Bananas", "Oranges"];
+    /// #     let data = [
+    /// #         [10000, 5000, 8000, 6000],
+    /// #         [2000, 3000, 4000, 5000],
+    /// #         [6000, 6000, 6500, 6000],
+    /// #         [500, 300, 200, 700],
+    /// #     ];
+    /// #
+    /// #     // Write the table data.
+    /// #     worksheet.write_column(3, 1, items)?;
+    /// #     worksheet.write_row_matrix(3, 2, data)?;
+    /// #     worksheet.set_column_range_width(1, 6, 12)?;
+    /// #
+    ///     // Create a custom table style from formats for the table elements.
+    ///     let style = CustomTableStyle::new("Company Style")
+    ///         .set_format(
+    ///             TableStyleElement::WholeTable,
+    ///             &Format::new()
+    ///                 .set_border(FormatBorder::Thin)
+    ///                 .set_border_color("#1F4E79"),
+    ///         )
+    ///         .set_format(
+    ///             TableStyleElement::HeaderRow,
+    ///             &Format::new()
+    ///                 .set_bold()
+    ///                 .set_font_color("#FFFFFF")
+    ///                 .set_background_color("#1F4E79"),
+    ///         )
+    ///         .set_format(
+    ///             TableStyleElement::FirstRowStripe,
+    ///             &Format::new().set_background_color("#DDEBF7"),
+    ///         );
+    ///
+    ///     // Create a new table and apply the custom style.
+    ///     let table = Table::new().set_custom_style(&style);
+    ///
+    ///     // Add the table to the worksheet.
+    ///     worksheet.add_table(2, 1, 6, 5, &table)?;
+    /// #
+    /// #     workbook.save("tables.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_custom_style(mut self, style: &CustomTableStyle) -> Table {
+        self.custom_style = Some(style.clone());
         self
     }
 
@@ -1314,7 +1402,9 @@ impl Table {
     fn write_table_style_info(&mut self) {
         let mut attributes = vec![];
 
-        if self.style != TableStyle::None {
+        if let Some(style) = &self.custom_style {
+            attributes.push(("name", style.name.clone()));
+        } else if self.style != TableStyle::None {
             attributes.push(("name", self.style.to_string()));
         }
 
@@ -2039,6 +2129,9 @@ impl fmt::Display for TableFunction {
 /// The style is set via the [`Table::set_style()`] method. The default table
 /// style in Excel is equivalent to [`TableStyle::Medium9`].
 ///
+/// With the `serde` feature enabled the style can be serialized and
+/// deserialized as a "snake case" string like `"medium9"` or `"light15"`.
+///
 /// # Examples
 ///
 /// Example of setting the style of a worksheet table.
@@ -2089,6 +2182,8 @@ impl fmt::Display for TableFunction {
 /// <img src="https://rustxlsxwriter.github.io/images/table_set_style.png">
 ///
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum TableStyle {
     /// No table style.
     None,
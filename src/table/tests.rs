@@ -9,7 +9,10 @@ mod table_tests {
 
     use crate::table::Table;
     use crate::test_functions::xml_to_vec;
-    use crate::{xmlwriter, TableColumn, TableFunction, Worksheet, XlsxError};
+    use crate::{
+        xmlwriter, CustomTableStyle, Format, TableColumn, TableFunction, TableStyleElement,
+        Worksheet, XlsxError,
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -580,4 +583,61 @@ mod table_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble11() {
+        let style = CustomTableStyle::new("Table Style")
+            .set_format(TableStyleElement::HeaderRow, &Format::new().set_bold());
+        let mut table = Table::new()
+            .set_style(crate::TableStyle::Light17)
+            .set_custom_style(&style);
+        let default_headers = vec![String::from("Column1"), String::from("Column2")];
+
+        table.cell_range.first_row = 0;
+        table.cell_range.first_col = 0;
+        table.cell_range.last_row = 2;
+        table.cell_range.last_col = 1;
+        table.index = 1;
+
+        table.initialize_columns(&default_headers).unwrap();
+        table.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&table.writer);
+        let got = xml_to_vec(got);
+
+        // The custom style overrides the built-in style.
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <table xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" id="1" name="Table1" displayName="Table1" ref="A1:B3" totalsRowShown="0">
+                <autoFilter ref="A1:B3"/>
+                <tableColumns count="2">
+                    <tableColumn id="1" name="Column1"/>
+                    <tableColumn id="2" name="Column2"/>
+                </tableColumns>
+                <tableStyleInfo name="Table Style" showFirstColumn="0" showLastColumn="0" showRowStripes="1" showColumnStripes="0"/>
+                </table>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_table_style_serde() {
+        use crate::TableStyle;
+
+        let styles = [
+            (TableStyle::None, r#""none""#),
+            (TableStyle::Light15, r#""light15""#),
+            (TableStyle::Medium9, r#""medium9""#),
+            (TableStyle::Dark11, r#""dark11""#),
+        ];
+
+        for (style, json) in styles {
+            assert_eq!(json, serde_json::to_string(&style).unwrap());
+            assert!(style == serde_json::from_str::<TableStyle>(json).unwrap());
+        }
+    }
 }
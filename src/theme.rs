@@ -4,13 +4,564 @@
 //
 // Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
 
+#![warn(missing_docs)]
+
 mod tests;
 
-use std::io::Cursor;
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read};
+use std::path::Path;
+
+use crate::xmlwriter::{escape_attributes, xml_theme};
+use crate::{Color, XlsxError};
+
+/// The `WorkbookTheme` struct represents a custom theme for a workbook.
+///
+/// A workbook theme defines the color palette and the heading and body fonts
+/// that are used by the Excel theme colors and theme fonts. Any colors
+/// defined with [`Color::Theme`] in a [`Format`](crate::Format), or theme
+/// colors and fonts in charts and shapes, will resolve to the values in the
+/// theme. This can be used, for example, to apply corporate branding to a
+/// workbook.
+///
+/// A theme contains 12 colors, defined by the [`WorkbookThemeColor`] enum, and
+/// a "major" font for headings and a "minor" font for body text. Themes can
+/// also be loaded from an existing `theme1.xml` or `.thmx` file created by
+/// Excel, see [`WorkbookTheme::new_from_file()`].
+///
+/// The theme is added to a workbook with
+/// [`Workbook::set_theme()`](crate::Workbook::set_theme).
+///
+/// The theme minor font is also used as the default font for cell text, i.e.,
+/// for any [`Format`](crate::Format) that doesn't set an explicit font name.
+///
+/// # Examples
+///
+/// An example of setting a custom theme for a workbook.
+///
+/// ```
+/// # // This code is available in examples/doc_workbook_theme.rs
+/// #
+/// use rust_xlsxwriter::{Color, Format, Workbook, WorkbookTheme, WorkbookThemeColor, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     // Create a new Excel file object.
+///     let mut workbook = Workbook::new();
+///
+///     // Create a custom theme with some brand colors and fonts.
+///     let theme = WorkbookTheme::new()
+///         .set_name("Brand")
+///         .set_color(WorkbookThemeColor::Accent1, "#00A3E0")
+///         .set_color(WorkbookThemeColor::Accent2, "#E35205")
+///         .set_major_font("Georgia")
+///         .set_minor_font("Verdana");
+///
+///     workbook.set_theme(&theme);
+///
+///     // Theme colors in formats resolve to the custom palette.
+///     let format = Format::new().set_background_color(Color::Theme(4, 0));
+///
+///     let worksheet = workbook.add_worksheet();
+///     worksheet.write_with_format(0, 0, "Accent 1", &format)?;
+///
+///     workbook.save("workbook_theme.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorkbookTheme {
+    pub(crate) name: String,
+    pub(crate) scheme_name: String,
+    pub(crate) colors: [Color; 12],
+    pub(crate) major_font: String,
+    pub(crate) minor_font: String,
+    pub(crate) xml: Option<String>,
+    pub(crate) version: u32,
+}
+
+impl Default for WorkbookTheme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorkbookTheme {
+    /// Create a new `WorkbookTheme` object.
+    ///
+    /// Create a new theme based on the default Excel 2007-2010 "Office" theme
+    /// used by `rust_xlsxwriter`. The colors and fonts can then be changed
+    /// with the methods below.
+    ///
+    pub fn new() -> WorkbookTheme {
+        WorkbookTheme {
+            name: "Office Theme".to_string(),
+            scheme_name: "Office".to_string(),
+            // The dark 1 and light 1 colors default to the system "windowText"
+            // and "window" colors.
+            colors: [
+                Color::Default,
+                Color::Default,
+                Color::RGB(0x1F497D),
+                Color::RGB(0xEEECE1),
+                Color::RGB(0x4F81BD),
+                Color::RGB(0xC0504D),
+                Color::RGB(0x9BBB59),
+                Color::RGB(0x8064A2),
+                Color::RGB(0x4BACC6),
+                Color::RGB(0xF79646),
+                Color::RGB(0x0000FF),
+                Color::RGB(0x800080),
+            ],
+            major_font: "Cambria".to_string(),
+            minor_font: "Calibri".to_string(),
+            xml: None,
+            version: 124_226,
+        }
+    }
+
+    /// Create a new `WorkbookTheme` object with the Excel 365 colors and Aptos
+    /// fonts.
+    ///
+    /// Excel 365 creates new files with the Office 2023 theme which uses a
+    /// different color palette and the "Aptos Display" and "Aptos Narrow"
+    /// fonts for headings and body text. This method creates a variant of the
+    /// default Office 2007 theme used by `rust_xlsxwriter` with the same
+    /// palette and Latin heading and body fonts as the Office 2023 theme.
+    ///
+    /// It isn't the Office 2023 theme. The format scheme, with the line, fill
+    /// and effect styles used by shapes, and the fonts for other scripts are
+    /// those of the default theme. To use the Office 2023 theme, or any other
+    /// theme, use [`WorkbookTheme::new_from_file()`] with the `theme1.xml`
+    /// file from a file created by Excel or with an Office `.thmx` file.
+    ///
+    /// Setting this theme with
+    /// [`Workbook::set_theme()`](crate::Workbook::set_theme) changes the
+    /// default cell font to "Aptos Narrow" 11.
+    ///
+    /// The column width conversions used by
+    /// [`Worksheet::set_column_width()`](crate::Worksheet::set_column_width),
+    /// [`Worksheet::autofit()`](crate::Worksheet::autofit) and the object
+    /// positioning calculations use the "Calibri" 11 metrics, with a 7 pixel
+    /// maximum digit width, for all theme fonts so the default column width is
+    /// still 8.43 characters or 64 pixels. Cells with the default font are autofitted using any metrics
+    /// added for "Aptos Narrow" with
+    /// [`Worksheet::set_autofit_font_metrics()`](crate::Worksheet::set_autofit_font_metrics).
+    ///
+    /// The returned theme can be modified further with the methods below.
+    ///
+    /// # Examples
+    ///
+    /// An example of creating a workbook with the Excel 365 theme colors and
+    /// Aptos fonts.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_theme_aptos.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, WorkbookTheme, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Use the Excel 365 theme colors and Aptos Narrow default font.
+    ///     workbook.set_theme(&WorkbookTheme::new_aptos());
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write(0, 0, "Hello")?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn new_aptos() -> WorkbookTheme {
+        WorkbookTheme {
+            colors: [
+                Color::Default,
+                Color::Default,
+                Color::RGB(0x0E2841),
+                Color::RGB(0xE8E8E8),
+                Color::RGB(0x156082),
+                Color::RGB(0xE97132),
+                Color::RGB(0x196B24),
+                Color::RGB(0x0F9ED5),
+                Color::RGB(0xA02B93),
+                Color::RGB(0x4EA72E),
+                Color::RGB(0x467886),
+                Color::RGB(0x96607D),
+            ],
+            major_font: "Aptos Display".to_string(),
+            minor_font: "Aptos Narrow".to_string(),
+            version: 202_300,
+            ..WorkbookTheme::new()
+        }
+    }
+
+    /// Create a new `WorkbookTheme` object from a theme file.
+    ///
+    /// Load a theme from a `theme1.xml` file extracted from an Excel xlsx file,
+    /// or from an Office `.thmx` theme file. The theme is written to the
+    /// output file unchanged, including any fonts and effects that aren't
+    /// supported by the `WorkbookTheme` methods.
+    ///
+    /// The theme name and the Latin major and minor fonts are read from the
+    /// file. The minor font is used as the default cell font, in the same way
+    /// as [`WorkbookTheme::set_minor_font()`]. The
+    /// [`WorkbookTheme::set_name()`], [`WorkbookTheme::set_color()`] and font
+    /// methods can be used to modify the loaded theme.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the theme file to read.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::IoError`] - I/O errors when reading the file.
+    /// - [`XlsxError::ZipError`] - Errors when reading a `.thmx` file.
+    /// - [`XlsxError::ParameterError`] - The file doesn't contain a valid
+    ///   theme.
+    ///
+    pub fn new_from_file<P: AsRef<Path>>(path: P) -> Result<WorkbookTheme, XlsxError> {
+        let file = File::open(path.as_ref())?;
+        let mut reader = BufReader::new(file);
+        let mut data = vec![];
+        reader.read_to_end(&mut data)?;
+
+        WorkbookTheme::new_from_buffer(&data)
+    }
+
+    /// Create a new `WorkbookTheme` object from a buffer.
+    ///
+    /// Load a theme from the contents of a `theme1.xml` or `.thmx` file. See
+    /// [`WorkbookTheme::new_from_file()`] above.
+    ///
+    /// # Parameters
+    ///
+    /// - `buffer`: The theme file data.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ZipError`] - Errors when reading `.thmx` data.
+    /// - [`XlsxError::ParameterError`] - The data doesn't contain a valid
+    ///   theme.
+    ///
+    pub fn new_from_buffer(buffer: &[u8]) -> Result<WorkbookTheme, XlsxError> {
+        // A .thmx file is a zip container with the theme in a sub-directory.
+        let xml = if buffer.starts_with(b"PK\x03\x04") {
+            let mut archive = zip::ZipArchive::new(Cursor::new(buffer))?;
+            let mut file = archive.by_name("theme/theme/theme1.xml")?;
+            let mut xml = String::new();
+            file.read_to_string(&mut xml)?;
+            xml
+        } else {
+            String::from_utf8(buffer.to_vec()).map_err(|_| {
+                XlsxError::ParameterError("Theme data isn't valid UTF-8 XML".to_string())
+            })?
+        };
+
+        if !xml.contains("<a:theme ") || !xml.contains("</a:theme>") {
+            return Err(XlsxError::ParameterError(
+                "Theme data doesn't contain an <a:theme> element".to_string(),
+            ));
+        }
+
+        let mut theme = WorkbookTheme::new();
+
+        // Read the theme properties that are also used in other parts of the
+        // workbook.
+        if let Some(name) = Self::xml_attribute(&xml, "<a:theme ", "name") {
+            theme.name = name;
+        }
+        if let Some(name) = Self::xml_attribute(&xml, "<a:clrScheme ", "name") {
+            theme.scheme_name = name;
+        }
+        if let Some(font) = Self::latin_font(&xml, "<a:majorFont>") {
+            theme.major_font = font;
+        }
+        if let Some(font) = Self::latin_font(&xml, "<a:minorFont>") {
+            theme.minor_font = font;
+        }
+
+        theme.version = Self::theme_version(&xml, &theme.minor_font);
+        theme.xml = Some(xml);
+
+        Ok(theme)
+    }
+
+    /// Set the name of the theme.
+    ///
+    /// Set the name of the theme and its color and font schemes. This is the
+    /// name that is displayed in the Excel "Page Layout" theme menus. The
+    /// default is "Office Theme".
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The theme name.
+    ///
+    pub fn set_name(mut self, name: impl Into<String>) -> WorkbookTheme {
+        self.name = name.into();
+        self.scheme_name.clone_from(&self.name);
+
+        // Change the names in a theme loaded from a file.
+        if let Some(xml) = &mut self.xml {
+            let name = escape_attributes(&self.name).to_string();
+            for element in ["<a:theme ", "<a:clrScheme ", "<a:fontScheme "] {
+                if let Some(range) = Self::attribute_range(xml, 0, element, "name") {
+                    xml.replace_range(range, &name);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Set one of the theme colors.
+    ///
+    /// Set one of the 12 colors in the theme color palette. Only RGB colors,
+    /// or the named colors, are valid in a theme. Invalid colors, and theme
+    /// and automatic colors, are ignored with a warning like the other color
+    /// setters in the library.
+    ///
+    /// # Parameters
+    ///
+    /// - `theme_color`: The theme color to set, defined by the
+    ///   [`WorkbookThemeColor`] enum.
+    /// - `color`: The color property defined by a [`Color`] enum value or a
+    ///   type that can convert [`Into`] a [`Color`].
+    ///
+    pub fn set_color(
+        mut self,
+        theme_color: WorkbookThemeColor,
+        color: impl Into<Color>,
+    ) -> WorkbookTheme {
+        let color = color.into();
+
+        if !color.is_valid() {
+            return self;
+        }
+
+        if matches!(color, Color::Theme(_, _) | Color::ThemeTint(_, _))
+            || color.is_auto_or_default()
+        {
+            eprintln!("Theme color '{theme_color:?}' must be set to an RGB or named color.");
+            return self;
+        }
+
+        // Change the color in a theme loaded from a file.
+        if let Some(xml) = &mut self.xml {
+            let element = THEME_COLOR_ELEMENTS[theme_color as usize];
+            let start_tag = format!("<a:{element}>");
+            let end_tag = format!("</a:{element}>");
+
+            let range = xml.find(&start_tag).and_then(|start| {
+                xml[start..]
+                    .find(&end_tag)
+                    .map(|end| start + start_tag.len()..start + end)
+            });
+
+            let Some(range) = range else {
+                eprintln!("Theme color '{theme_color:?}' not found in the theme file.");
+                return self;
+            };
+
+            let value = format!(r#"<a:srgbClr val="{}"/>"#, color.rgb_hex_value());
+            xml.replace_range(range, &value);
+        }
+
+        self.colors[theme_color as usize] = color;
+
+        self
+    }
+
+    /// Set the theme major (heading) font.
+    ///
+    /// Set the Latin font used for headings in the theme. The default is
+    /// "Cambria".
+    ///
+    /// # Parameters
+    ///
+    /// - `font_name`: The font name.
+    ///
+    pub fn set_major_font(mut self, font_name: impl Into<String>) -> WorkbookTheme {
+        self.major_font = font_name.into();
+        self.replace_latin_font("<a:majorFont>", &self.major_font.clone());
+        self
+    }
+
+    /// Set the theme minor (body) font.
+    ///
+    /// Set the Latin font used for body text in the theme. This is the default
+    /// font for chart and shape text. The default is "Calibri".
+    ///
+    /// # Parameters
+    ///
+    /// - `font_name`: The font name.
+    ///
+    pub fn set_minor_font(mut self, font_name: impl Into<String>) -> WorkbookTheme {
+        self.minor_font = font_name.into();
+        self.replace_latin_font("<a:minorFont>", &self.minor_font.clone());
+        self
+    }
+
+    // Replace the Latin font of the major or minor font in a theme loaded
+    // from a file. Any panose data is removed since it applies to the
+    // original font.
+    fn replace_latin_font(&mut self, element: &str, font_name: &str) {
+        let Some(xml) = &mut self.xml else {
+            return;
+        };
+
+        let Some(offset) = xml.find(element) else {
+            return;
+        };
+
+        if let Some(start) = xml[offset..].find("<a:latin ").map(|start| offset + start) {
+            if let Some(end) = xml[start..].find("/>") {
+                let latin = format!(r#"<a:latin typeface="{}"/>"#, escape_attributes(font_name));
+                xml.replace_range(start..start + end + 2, &latin);
+            }
+        }
+    }
+
+    // Find the byte range of an attribute value in the first instance of an
+    // element in the theme XML, starting from an offset.
+    fn attribute_range(
+        xml: &str,
+        offset: usize,
+        element: &str,
+        attribute: &str,
+    ) -> Option<std::ops::Range<usize>> {
+        let start = offset + xml[offset..].find(element)?;
+        let tag = &xml[start..start + xml[start..].find('>')?];
+        let value_start = start + tag.find(&format!(" {attribute}=\""))? + attribute.len() + 3;
+        let value_end = value_start + xml[value_start..].find('"')?;
+
+        Some(value_start..value_end)
+    }
+
+    // Get the value of an attribute from the first instance of an element in
+    // the theme XML.
+    fn xml_attribute(xml: &str, element: &str, attribute: &str) -> Option<String> {
+        let range = Self::attribute_range(xml, 0, element, attribute)?;
 
-use crate::xmlwriter::xml_theme;
+        Some(unescape_attribute(&xml[range]))
+    }
+
+    // Get the Latin typeface of the theme major or minor font.
+    fn latin_font(xml: &str, element: &str) -> Option<String> {
+        let start = xml.find(element)?;
+        let range = Self::attribute_range(xml, start, "<a:latin ", "typeface")?;
+        let font = unescape_attribute(&xml[range]);
+
+        if font.is_empty() {
+            None
+        } else {
+            Some(font)
+        }
+    }
+
+    // Get the "defaultThemeVersion" that Excel uses for a theme. This is
+    // based on the Office theme that the file was created from.
+    fn theme_version(xml: &str, minor_font: &str) -> u32 {
+        if minor_font.starts_with("Aptos") {
+            202_300
+        } else if xml.contains("<thm15:themeFamily ") {
+            164_011
+        } else {
+            124_226
+        }
+    }
+
+    // Write the <a:clrScheme> element.
+    fn color_scheme_xml(&self) -> String {
+        let mut xml = format!(
+            r#"<a:clrScheme name="{}">"#,
+            escape_attributes(&self.scheme_name)
+        );
+
+        for (index, color) in self.colors.iter().enumerate() {
+            let element = THEME_COLOR_ELEMENTS[index];
+
+            let value = match (index, color) {
+                (0, Color::Default) => {
+                    r#"<a:sysClr val="windowText" lastClr="000000"/>"#.to_string()
+                }
+                (1, Color::Default) => r#"<a:sysClr val="window" lastClr="FFFFFF"/>"#.to_string(),
+                _ => format!(r#"<a:srgbClr val="{}"/>"#, color.rgb_hex_value()),
+            };
+
+            xml.push_str(&format!("<a:{element}>{value}</a:{element}>"));
+        }
+
+        xml.push_str("</a:clrScheme>");
+        xml
+    }
+}
+
+/// The `WorkbookThemeColor` enum defines the colors in a [`WorkbookTheme`].
+///
+/// These are the 12 colors of a theme palette. The first 10 are shown in the
+/// Excel color picker dialog and correspond to the [`Color::Theme`] color
+/// indexes 0-9, although Excel swaps the order of the dark/light pairs in the
+/// dialog. The hyperlink colors are only used for hyperlinks.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkbookThemeColor {
+    /// Dark text/background color 1. Defaults to the system text color.
+    Dark1,
+
+    /// Light text/background color 1. Defaults to the system window color.
+    Light1,
+
+    /// Dark text/background color 2.
+    Dark2,
+
+    /// Light text/background color 2.
+    Light2,
+
+    /// Accent color 1.
+    Accent1,
+
+    /// Accent color 2.
+    Accent2,
+
+    /// Accent color 3.
+    Accent3,
+
+    /// Accent color 4.
+    Accent4,
+
+    /// Accent color 5.
+    Accent5,
+
+    /// Accent color 6.
+    Accent6,
 
-pub struct Theme {
+    /// Hyperlink color.
+    Hyperlink,
+
+    /// Followed hyperlink color.
+    FollowedHyperlink,
+}
+
+// The theme color element names in the order of WorkbookThemeColor.
+const THEME_COLOR_ELEMENTS: [&str; 12] = [
+    "dk1", "lt1", "dk2", "lt2", "accent1", "accent2", "accent3", "accent4", "accent5", "accent6",
+    "hlink", "folHlink",
+];
+
+// Unescape the XML entities in an attribute value read from a theme file.
+fn unescape_attribute(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+pub(crate) struct Theme {
     pub(crate) writer: Cursor<Vec<u8>>,
 }
 
@@ -23,28 +574,45 @@ impl Theme {
     }
 
     // Assemble and generate the XML file.
-    #[allow(clippy::too_many_lines)]
-    pub(crate) fn assemble_xml_file(&mut self) {
-        xml_theme(&mut self.writer,
+    pub(crate) fn assemble_xml_file(&mut self, workbook_theme: &WorkbookTheme) {
+        // Write themes loaded from a file unchanged.
+        if let Some(xml) = &workbook_theme.xml {
+            xml_theme(&mut self.writer, xml.trim_end());
+            return;
+        }
+
+        let scheme_name = escape_attributes(&workbook_theme.scheme_name);
+
+        let mut xml = format!(
             "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
-             <a:theme xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\" name=\"Office Theme\">\
-             <a:themeElements>\
-             <a:clrScheme name=\"Office\"><a:dk1>\
-             <a:sysClr val=\"windowText\" lastClr=\"000000\"/>\
-             </a:dk1><a:lt1>\
-             <a:sysClr val=\"window\" lastClr=\"FFFFFF\"/></a:lt1><a:dk2>\
-             <a:srgbClr val=\"1F497D\"/></a:dk2><a:lt2>\
-             <a:srgbClr val=\"EEECE1\"/></a:lt2><a:accent1>\
-             <a:srgbClr val=\"4F81BD\"/></a:accent1><a:accent2>\
-             <a:srgbClr val=\"C0504D\"/></a:accent2><a:accent3>\
-             <a:srgbClr val=\"9BBB59\"/></a:accent3><a:accent4>\
-             <a:srgbClr val=\"8064A2\"/></a:accent4><a:accent5>\
-             <a:srgbClr val=\"4BACC6\"/></a:accent5><a:accent6>\
-             <a:srgbClr val=\"F79646\"/></a:accent6><a:hlink>\
-             <a:srgbClr val=\"0000FF\"/></a:hlink><a:folHlink>\
-             <a:srgbClr val=\"800080\"/></a:folHlink></a:clrScheme>\
-             <a:fontScheme name=\"Office\"><a:majorFont>\
-             <a:latin typeface=\"Cambria\"/><a:ea typeface=\"\"/>\
+             <a:theme xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\" name=\"{}\">\
+             <a:themeElements>",
+            escape_attributes(&workbook_theme.name)
+        );
+
+        xml.push_str(&workbook_theme.color_scheme_xml());
+
+        xml.push_str(&format!(
+            "<a:fontScheme name=\"{scheme_name}\"><a:majorFont><a:latin typeface=\"{}\"/>",
+            escape_attributes(&workbook_theme.major_font)
+        ));
+        xml.push_str(THEME_MAJOR_FONTS);
+
+        xml.push_str(&format!(
+            "<a:minorFont><a:latin typeface=\"{}\"/>",
+            escape_attributes(&workbook_theme.minor_font)
+        ));
+        xml.push_str(THEME_MINOR_FONTS);
+
+        xml.push_str(THEME_FORMAT_SCHEME);
+
+        xml_theme(&mut self.writer, &xml);
+    }
+}
+
+// The non-Latin major fonts in the default theme.
+const THEME_MAJOR_FONTS: &str = "\
+             <a:ea typeface=\"\"/>\
              <a:cs typeface=\"\"/>\
              <a:font script=\"Jpan\" typeface=\"ＭＳ Ｐゴシック\"/>\
              <a:font script=\"Hang\" typeface=\"맑은 고딕\"/>\
@@ -75,9 +643,10 @@ impl Theme {
              <a:font script=\"Mong\" typeface=\"Mongolian Baiti\"/>\
              <a:font script=\"Viet\" typeface=\"Times New Roman\"/>\
              <a:font script=\"Uigh\" typeface=\"Microsoft Uighur\"/>\
-             </a:majorFont>\
-             <a:minorFont>\
-             <a:latin typeface=\"Calibri\"/>\
+             </a:majorFont>";
+
+// The non-Latin minor fonts in the default theme.
+const THEME_MINOR_FONTS: &str = "\
              <a:ea typeface=\"\"/>\
              <a:cs typeface=\"\"/>\
              <a:font script=\"Jpan\" typeface=\"ＭＳ Ｐゴシック\"/>\
@@ -110,7 +679,11 @@ impl Theme {
              <a:font script=\"Viet\" typeface=\"Arial\"/>\
              <a:font script=\"Uigh\" typeface=\"Microsoft Uighur\"/>\
              </a:minorFont>\
-             </a:fontScheme><a:fmtScheme name=\"Office\">\
+             </a:fontScheme>";
+
+// The format scheme and trailing elements of the default theme.
+const THEME_FORMAT_SCHEME: &str = "\
+             <a:fmtScheme name=\"Office\">\
              <a:fillStyleLst>\
              <a:solidFill>\
              <a:schemeClr val=\"phClr\"/>\
@@ -282,7 +855,4 @@ impl Theme {
              </a:themeElements>\
              <a:objectDefaults/>\
              <a:extraClrSchemeLst/>\
-             </a:theme>"
-        );
-    }
-}
+             </a:theme>";
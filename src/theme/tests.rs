@@ -8,15 +8,17 @@
 mod theme_tests {
 
     use crate::test_functions::xml_to_vec;
-    use crate::theme::Theme;
+    use crate::theme::{Theme, WorkbookTheme};
     use crate::xmlwriter;
+    use crate::{Color, WorkbookThemeColor, XlsxError};
     use pretty_assertions::assert_eq;
+    use std::io::{Cursor, Write};
 
     #[test]
     fn test_assemble() {
         let mut theme = Theme::new();
 
-        theme.assemble_xml_file();
+        theme.assemble_xml_file(&WorkbookTheme::new());
 
         let got = xmlwriter::cursor_to_str(&theme.writer);
         let got = xml_to_vec(got);
@@ -283,4 +285,188 @@ mod theme_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_custom_theme() {
+        let workbook_theme = WorkbookTheme::new()
+            .set_name("Brand & Co")
+            .set_color(WorkbookThemeColor::Dark1, "#101010")
+            .set_color(WorkbookThemeColor::Accent1, 0x00A3E0)
+            .set_color(WorkbookThemeColor::FollowedHyperlink, "#E35205")
+            .set_major_font("Georgia")
+            .set_minor_font("Verdana");
+
+        let mut theme = Theme::new();
+        theme.assemble_xml_file(&workbook_theme);
+
+        let got = xmlwriter::cursor_to_str(&theme.writer);
+
+        assert!(got.contains(r#"<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Brand &amp; Co">"#));
+
+        let expected = concat!(
+            r#"<a:clrScheme name="Brand &amp; Co">"#,
+            r#"<a:dk1><a:srgbClr val="101010"/></a:dk1>"#,
+            r#"<a:lt1><a:sysClr val="window" lastClr="FFFFFF"/></a:lt1>"#,
+            r#"<a:dk2><a:srgbClr val="1F497D"/></a:dk2>"#,
+            r#"<a:lt2><a:srgbClr val="EEECE1"/></a:lt2>"#,
+            r#"<a:accent1><a:srgbClr val="00A3E0"/></a:accent1>"#,
+            r#"<a:accent2><a:srgbClr val="C0504D"/></a:accent2>"#,
+            r#"<a:accent3><a:srgbClr val="9BBB59"/></a:accent3>"#,
+            r#"<a:accent4><a:srgbClr val="8064A2"/></a:accent4>"#,
+            r#"<a:accent5><a:srgbClr val="4BACC6"/></a:accent5>"#,
+            r#"<a:accent6><a:srgbClr val="F79646"/></a:accent6>"#,
+            r#"<a:hlink><a:srgbClr val="0000FF"/></a:hlink>"#,
+            r#"<a:folHlink><a:srgbClr val="E35205"/></a:folHlink>"#,
+            r#"</a:clrScheme>"#,
+            r#"<a:fontScheme name="Brand &amp; Co">"#,
+            r#"<a:majorFont><a:latin typeface="Georgia"/>"#,
+        );
+        assert!(got.contains(expected));
+
+        assert!(got.contains(r#"<a:minorFont><a:latin typeface="Verdana"/>"#));
+    }
+
+    #[test]
+    fn test_invalid_theme_colors() {
+        let default_theme = WorkbookTheme::new();
+
+        // Invalid, theme and automatic colors are ignored.
+        let workbook_theme = WorkbookTheme::new()
+            .set_color(WorkbookThemeColor::Accent1, 0x1000000)
+            .set_color(WorkbookThemeColor::Accent2, "#+12345")
+            .set_color(WorkbookThemeColor::Accent3, Color::Theme(4, 1))
+            .set_color(WorkbookThemeColor::Accent4, Color::ThemeTint(4, 0.5))
+            .set_color(WorkbookThemeColor::Accent5, Color::Automatic)
+            .set_color(WorkbookThemeColor::Accent6, Color::RGB(0xFFFF_FFFF));
+
+        assert_eq!(default_theme.colors, workbook_theme.colors);
+
+        // Colors that aren't in a loaded theme file are ignored.
+        let xml = concat!(
+            r#"<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Custom">"#,
+            r#"<a:themeElements><a:clrScheme name="Custom">"#,
+            r#"<a:dk1><a:srgbClr val="000000"/></a:dk1>"#,
+            r#"</a:clrScheme></a:themeElements></a:theme>"#,
+        );
+
+        let workbook_theme = WorkbookTheme::new_from_buffer(xml.as_bytes())
+            .unwrap()
+            .set_color(WorkbookThemeColor::Accent1, "#00A3E0");
+
+        assert_eq!(Some(xml.to_string()), workbook_theme.xml);
+        assert_eq!(default_theme.colors, workbook_theme.colors);
+    }
+
+    #[test]
+    fn test_theme_from_buffer() {
+        let xml = "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+                   <a:theme xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\" name=\"Custom\">\
+                   <a:themeElements/></a:theme>";
+
+        // Load the theme from a theme1.xml file.
+        let workbook_theme = WorkbookTheme::new_from_buffer(xml.as_bytes()).unwrap();
+
+        let mut theme = Theme::new();
+        theme.assemble_xml_file(&workbook_theme);
+
+        let got = xmlwriter::cursor_to_str(&theme.writer);
+        assert_eq!(format!("{xml}\n"), got);
+
+        // Load the theme from a .thmx file.
+        let mut zip = zip::ZipWriter::new(Cursor::new(vec![]));
+        let options = zip::write::SimpleFileOptions::default();
+        zip.start_file("theme/theme/theme1.xml", options).unwrap();
+        zip.write_all(xml.as_bytes()).unwrap();
+        let data = zip.finish().unwrap().into_inner();
+
+        let workbook_theme = WorkbookTheme::new_from_buffer(&data).unwrap();
+        assert_eq!(Some(xml.to_string()), workbook_theme.xml);
+
+        // Invalid theme data.
+        let result = WorkbookTheme::new_from_buffer(b"<xml/>");
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_modify_theme_from_buffer() {
+        let xml = concat!(
+            r#"<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Office Theme">"#,
+            r#"<a:themeElements>"#,
+            r#"<a:clrScheme name="Office">"#,
+            r#"<a:dk1><a:sysClr val="windowText" lastClr="000000"/></a:dk1>"#,
+            r#"<a:accent1><a:srgbClr val="156082"/></a:accent1>"#,
+            r#"</a:clrScheme>"#,
+            r#"<a:fontScheme name="Office">"#,
+            r#"<a:majorFont><a:latin typeface="Aptos Display" panose="02110004020202020204"/></a:majorFont>"#,
+            r#"<a:minorFont><a:latin typeface="Aptos Narrow" panose="02110004020202020204"/></a:minorFont>"#,
+            r#"</a:fontScheme>"#,
+            r#"</a:themeElements>"#,
+            r#"</a:theme>"#,
+        );
+
+        // The fonts and names are read from the theme file.
+        let workbook_theme = WorkbookTheme::new_from_buffer(xml.as_bytes()).unwrap();
+
+        assert_eq!("Office Theme", workbook_theme.name);
+        assert_eq!("Office", workbook_theme.scheme_name);
+        assert_eq!("Aptos Display", workbook_theme.major_font);
+        assert_eq!("Aptos Narrow", workbook_theme.minor_font);
+        assert_eq!(202_300, workbook_theme.version);
+
+        // The setters modify the theme file.
+        let workbook_theme = workbook_theme
+            .set_name("Brand & Co")
+            .set_color(WorkbookThemeColor::Dark1, "#101010")
+            .set_color(WorkbookThemeColor::Accent1, "#00A3E0")
+            .set_major_font("Georgia")
+            .set_minor_font("Verdana");
+
+        let expected = concat!(
+            r#"<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Brand &amp; Co">"#,
+            r#"<a:themeElements>"#,
+            r#"<a:clrScheme name="Brand &amp; Co">"#,
+            r#"<a:dk1><a:srgbClr val="101010"/></a:dk1>"#,
+            r#"<a:accent1><a:srgbClr val="00A3E0"/></a:accent1>"#,
+            r#"</a:clrScheme>"#,
+            r#"<a:fontScheme name="Brand &amp; Co">"#,
+            r#"<a:majorFont><a:latin typeface="Georgia"/></a:majorFont>"#,
+            r#"<a:minorFont><a:latin typeface="Verdana"/></a:minorFont>"#,
+            r#"</a:fontScheme>"#,
+            r#"</a:themeElements>"#,
+            r#"</a:theme>"#,
+        );
+
+        assert_eq!(Some(expected.to_string()), workbook_theme.xml);
+        assert_eq!("Verdana", workbook_theme.minor_font);
+    }
+
+    #[test]
+    fn test_assemble_aptos_theme() {
+        let mut theme = Theme::new();
+        theme.assemble_xml_file(&WorkbookTheme::new_aptos());
+
+        let got = xmlwriter::cursor_to_str(&theme.writer);
+
+        let expected = concat!(
+            r#"<a:clrScheme name="Office">"#,
+            r#"<a:dk1><a:sysClr val="windowText" lastClr="000000"/></a:dk1>"#,
+            r#"<a:lt1><a:sysClr val="window" lastClr="FFFFFF"/></a:lt1>"#,
+            r#"<a:dk2><a:srgbClr val="0E2841"/></a:dk2>"#,
+            r#"<a:lt2><a:srgbClr val="E8E8E8"/></a:lt2>"#,
+            r#"<a:accent1><a:srgbClr val="156082"/></a:accent1>"#,
+            r#"<a:accent2><a:srgbClr val="E97132"/></a:accent2>"#,
+            r#"<a:accent3><a:srgbClr val="196B24"/></a:accent3>"#,
+            r#"<a:accent4><a:srgbClr val="0F9ED5"/></a:accent4>"#,
+            r#"<a:accent5><a:srgbClr val="A02B93"/></a:accent5>"#,
+            r#"<a:accent6><a:srgbClr val="4EA72E"/></a:accent6>"#,
+            r#"<a:hlink><a:srgbClr val="467886"/></a:hlink>"#,
+            r#"<a:folHlink><a:srgbClr val="96607D"/></a:folHlink>"#,
+            r#"</a:clrScheme>"#,
+            r#"<a:fontScheme name="Office">"#,
+            r#"<a:majorFont><a:latin typeface="Aptos Display"/>"#,
+        );
+        assert!(got.contains(expected));
+
+        assert!(got.contains(r#"<a:minorFont><a:latin typeface="Aptos Narrow"/>"#));
+    }
 }
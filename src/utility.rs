@@ -31,7 +31,7 @@ use crate::COL_MAX;
 use crate::MAX_AUTOFIT_WIDTH_PIXELS;
 use crate::ROW_MAX;
 
-#[cfg(feature = "serde")]
+use crate::num_format::render_number;
 use crate::IntoExcelDateTime;
 
 #[cfg(feature = "serde")]
@@ -962,6 +962,104 @@ pub(crate) fn validate_vba_name(name: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+/// Format a number as it would be displayed by Excel.
+///
+/// This function takes a number and an Excel number format code, like the
+/// codes used with [`Format::set_num_format()`](crate::Format::set_num_format),
+/// and returns the text that Excel would display in the cell. This is useful
+/// for estimating cell widths, exporting worksheet data as text, or for
+/// testing.
+///
+/// The renderer supports:
+///
+/// - Format sections, `positive;negative;zero`, and conditional sections like
+///   `[>100]`.
+/// - Digit placeholders `0`, `#` and `?`, decimal places, thousands separators
+///   and thousands scaling with trailing commas.
+/// - Percentages, scientific notation and fractions.
+/// - Dates and times, including AM/PM and elapsed times like `[h]:mm`.
+/// - Quoted strings, escaped characters and currency/locale codes like
+///   `[$€-x-euro2]`.
+///
+/// Color codes like `[Red]` are ignored since they don't affect the text.
+/// Padding with `_` is shown as a single space and repeated fill characters,
+/// `*`, are ignored since they depend on the cell width. Locale dependent
+/// formats are displayed using the US English defaults. Values that Excel
+/// can't display, such as negative dates, are shown as `########`.
+///
+/// # Parameters
+///
+/// - `number`: The number to format.
+/// - `num_format`: The Excel number format code. An empty string is treated as
+///   the `General` format.
+///
+/// # Examples
+///
+/// The following example demonstrates formatting numbers with Excel number
+/// formats.
+///
+/// ```
+/// use rust_xlsxwriter::format_number;
+///
+/// assert_eq!(format_number(1234.5, "#,##0.00"), "1,234.50");
+/// assert_eq!(format_number(-1234.5, "#,##0.00;(#,##0.00)"), "(1,234.50)");
+/// assert_eq!(format_number(0.256, "0.0%"), "25.6%");
+/// assert_eq!(format_number(1234567, "0.0,,\"M\""), "1.2M");
+/// assert_eq!(format_number(1.25, "# ?/?"), "1 1/4");
+/// assert_eq!(format_number(12345, "0.00E+00"), "1.23E+04");
+/// assert_eq!(format_number(5, "[Red][>3]\"High\";\"Low\""), "High");
+/// assert_eq!(format_number(45000.75, "yyyy-mm-dd hh:mm"), "2023-03-15 18:00");
+/// ```
+///
+pub fn format_number(number: impl Into<f64>, num_format: &str) -> String {
+    let num_format = num_format.trim();
+
+    if num_format.is_empty() {
+        render_number(number.into(), "General")
+    } else {
+        render_number(number.into(), num_format)
+    }
+}
+
+/// Format a date or time as it would be displayed by Excel.
+///
+/// This is the date and time equivalent of [`format_number()`]. It takes an
+/// [`ExcelDateTime`](crate::ExcelDateTime) or, if the `chrono` feature is
+/// enabled, a [`Chrono`] date or time and returns the text that Excel would
+/// display for the date with the number format.
+///
+/// [`Chrono`]: https://docs.rs/chrono/latest/chrono
+///
+/// # Parameters
+///
+/// - `datetime`: A date/time instance that implements [`IntoExcelDateTime`].
+/// - `num_format`: The Excel number format code.
+///
+/// # Examples
+///
+/// The following example demonstrates formatting dates with Excel number
+/// formats.
+///
+/// ```
+/// # use rust_xlsxwriter::{format_datetime, ExcelDateTime, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+///     let datetime = ExcelDateTime::parse_from_str("2023-01-25 14:30:45")?;
+///
+///     assert_eq!(format_datetime(&datetime, "yyyy-mm-dd"), "2023-01-25");
+///     assert_eq!(format_datetime(&datetime, "d mmmm yyyy"), "25 January 2023");
+///     assert_eq!(format_datetime(&datetime, "dddd, mmm d"), "Wednesday, Jan 25");
+///     assert_eq!(format_datetime(&datetime, "h:mm AM/PM"), "2:30 PM");
+///     assert_eq!(format_datetime(&datetime, "hh:mm:ss"), "14:30:45");
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+pub fn format_datetime(datetime: impl IntoExcelDateTime, num_format: &str) -> String {
+    format_number(datetime.to_excel_serial_date(), num_format)
+}
+
 /// Calculate the width required to auto-fit a string in a cell.
 ///
 /// The [`Worksheet::autofit()`](crate::Worksheet::autofit) method can be used
@@ -982,7 +1080,9 @@ pub(crate) fn validate_vba_name(name: &str) -> Result<(), XlsxError> {
 ///
 /// - The width calculation is based on the default Excel font type of Calibri
 ///   and character size of 11. It will not give correct results for other fonts
-///   or font sizes.
+///   or font sizes. The [`Worksheet::autofit()`](crate::Worksheet::autofit)
+///   method takes the cell font into account, see
+///   [`FontMetrics`](crate::FontMetrics).
 ///
 /// - If you are autofitting a header with an autofilter dropdown you should add
 ///   an additional 6 pixels to account for the dropdown symbol.
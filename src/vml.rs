@@ -8,7 +8,7 @@ mod tests;
 
 use std::io::Cursor;
 
-use crate::{drawing::DrawingInfo, ColNum, RowNum};
+use crate::{drawing::DrawingInfo, ColNum, ObjectMovement, RowNum};
 
 use crate::xmlwriter::{
     xml_data_element, xml_data_element_only, xml_empty_tag, xml_empty_tag_only, xml_end_tag,
@@ -20,6 +20,7 @@ pub struct Vml {
     pub(crate) writer: Cursor<Vec<u8>>,
     pub(crate) buttons: Vec<VmlInfo>,
     pub(crate) header_images: Vec<VmlInfo>,
+    pub(crate) ole_objects: Vec<VmlInfo>,
     pub(crate) data_id: String,
     pub(crate) shape_id: u32,
 }
@@ -38,6 +39,7 @@ impl Vml {
             buttons: vec![],
             comments: vec![],
             header_images: vec![],
+            ole_objects: vec![],
             data_id: String::new(),
             shape_id: 0,
         }
@@ -63,6 +65,19 @@ impl Vml {
         // Write the o:shapelayout element.
         self.write_shapelayout();
 
+        if !self.ole_objects.is_empty() {
+            // Write the v:shapetype element.
+            self.write_image_shapetype();
+
+            for vml_info in &self.ole_objects.clone() {
+                self.shape_id += 1;
+                z_index += 1;
+
+                // Write the v:shape element.
+                self.write_ole_object_shape(self.shape_id, z_index, vml_info);
+            }
+        }
+
         if !self.buttons.is_empty() {
             // Write the v:shapetype element.
             self.write_button_shapetype();
@@ -433,6 +448,44 @@ impl Vml {
         xml_end_tag(&mut self.writer, "v:shape");
     }
 
+    // Write the <v:shape> element for OLE object shapes.
+    #[allow(clippy::cast_precision_loss)]
+    fn write_ole_object_shape(&mut self, vml_shape_id: u32, z_index: u32, vml_info: &VmlInfo) {
+        let top = Self::vml_dpi_size(vml_info.drawing_info.row_absolute as f64);
+        let left = Self::vml_dpi_size(vml_info.drawing_info.col_absolute as f64);
+        let width = Self::vml_dpi_size(vml_info.drawing_info.width);
+        let height = Self::vml_dpi_size(vml_info.drawing_info.height);
+
+        let style = format!(
+            "position:absolute;\
+             margin-left:{left}pt;\
+             margin-top:{top}pt;\
+             width:{width}pt;\
+             height:{height}pt;\
+             z-index:{z_index}"
+        );
+
+        let shape_id = format!("_x0000_s{vml_shape_id}");
+
+        let mut attributes = vec![("id", shape_id), ("type", "#_x0000_t75".to_string())];
+
+        if !vml_info.alt_text.is_empty() {
+            attributes.push(("alt", vml_info.alt_text.clone()));
+        }
+
+        attributes.push(("style", style));
+
+        xml_start_tag(&mut self.writer, "v:shape", &attributes);
+
+        // Write the v:imagedata element.
+        self.write_imagedata(vml_info);
+
+        // Write the x:ClientData element.
+        self.write_ole_object_client_data(vml_info);
+
+        xml_end_tag(&mut self.writer, "v:shape");
+    }
+
     // Write the <v:imagedata> element for image data.
     fn write_imagedata(&mut self, vml_info: &VmlInfo) {
         let attributes = [
@@ -512,6 +565,13 @@ impl Vml {
 
         xml_start_tag(&mut self.writer, "x:ClientData", &attributes);
 
+        // VML doesn't support absolute anchors so the nearest equivalent is
+        // used, i.e., don't move or size with cells.
+        if vml_info.drawing_info.object_movement == ObjectMovement::AbsoluteAnchor {
+            xml_empty_tag_only(&mut self.writer, "x:MoveWithCells");
+            xml_empty_tag_only(&mut self.writer, "x:SizeWithCells");
+        }
+
         // Write the x:Anchor element.
         self.write_anchor(vml_info);
 
@@ -582,6 +642,34 @@ impl Vml {
         xml_end_tag(&mut self.writer, "x:ClientData");
     }
 
+    // Write the <x:ClientData> element for OLE object client data.
+    fn write_ole_object_client_data(&mut self, vml_info: &VmlInfo) {
+        let attributes = [("ObjectType", "Pict")];
+
+        xml_start_tag(&mut self.writer, "x:ClientData", &attributes);
+
+        // Note, the VML move/size elements indicate that the object does *not*
+        // move or size with the cells.
+        match vml_info.drawing_info.object_movement {
+            ObjectMovement::MoveButDontSizeWithCells => {
+                xml_empty_tag_only(&mut self.writer, "x:SizeWithCells");
+            }
+            ObjectMovement::DontMoveOrSizeWithCells | ObjectMovement::AbsoluteAnchor => {
+                xml_empty_tag_only(&mut self.writer, "x:MoveWithCells");
+                xml_empty_tag_only(&mut self.writer, "x:SizeWithCells");
+            }
+            ObjectMovement::MoveAndSizeWithCells | ObjectMovement::MoveAndSizeWithCellsAfter => {}
+        }
+
+        // Write the x:Anchor element.
+        self.write_anchor(vml_info);
+
+        xml_data_element_only(&mut self.writer, "x:CF", "Pict");
+        xml_empty_tag_only(&mut self.writer, "x:AutoPict");
+
+        xml_end_tag(&mut self.writer, "x:ClientData");
+    }
+
     // Write the <x:Anchor> element for shape anchoring.
     fn write_anchor(&mut self, vml_info: &VmlInfo) {
         let anchor = format!(
@@ -234,16 +234,18 @@ use std::path::PathBuf;
 
 use crate::error::XlsxError;
 use crate::feature_property_bag::FeaturePropertyBagTypes;
+use crate::font_metrics::ThemeFont;
 use crate::format::Format;
 use crate::packager::Packager;
 use crate::packager::PackagerOptions;
 use crate::shared_strings_table::SharedStringsTable;
+use crate::shift;
 use crate::worksheet::Worksheet;
 
 use crate::{
-    utility, Border, Chart, ChartRange, ChartRangeCacheData, ColNum, Color, DefinedName,
-    DefinedNameType, DocProperties, Fill, Font, FormatPattern, Image, RowNum, Visible,
-    NUM_IMAGE_FORMATS,
+    utility, Border, CellStyle, Chart, ChartRange, ChartRangeCacheData, ColNum, Color,
+    CustomTableStyle, CustomView, DefinedName, DefinedNameType, DocProperties, ExcelError, Fill,
+    Font, FormatPattern, Image, RowNum, Visible, WorkbookTheme, WorkbookView, NUM_IMAGE_FORMATS,
 };
 
 use crate::xmlwriter::{
@@ -339,6 +341,12 @@ pub struct Workbook {
     pub(crate) has_comments: bool,
     pub(crate) string_table: Arc<Mutex<SharedStringsTable>>,
     pub(crate) feature_property_bags: HashSet<FeaturePropertyBagTypes>,
+    pub(crate) theme: WorkbookTheme,
+    pub(crate) workbook_view: WorkbookView,
+    pub(crate) cell_styles: Vec<CellStyle>,
+    pub(crate) cell_style_xfs: Vec<CellStyle>,
+    pub(crate) table_styles: Vec<CustomTableStyle>,
+    pub(crate) table_style_dxfs: Vec<CustomTableStyle>,
 
     xf_indices: Arc<RwLock<HashMap<Format, u32>>>,
     dxf_indices: HashMap<Format, u32>,
@@ -433,6 +441,12 @@ impl Workbook {
             num_chartsheets: 0,
             use_large_file: false,
             feature_property_bags: HashSet::new(),
+            theme: WorkbookTheme::new(),
+            workbook_view: WorkbookView::new(),
+            cell_styles: vec![],
+            cell_style_xfs: vec![],
+            table_styles: vec![],
+            table_style_dxfs: vec![],
 
             #[cfg(feature = "constant_memory")]
             tempdir: None,
@@ -494,11 +508,14 @@ impl Workbook {
     /// src="https://rustxlsxwriter.github.io/images/workbook_add_worksheet.png">
     ///
     pub fn add_worksheet(&mut self) -> &mut Worksheet {
+        self.apply_reference_changes();
+
         let name = format!("Sheet{}", self.num_worksheets + 1);
         self.num_worksheets += 1;
 
         let mut worksheet = Worksheet::new();
         worksheet.set_name(&name).unwrap();
+        worksheet.theme_font = self.theme_font();
 
         self.worksheets.push(worksheet);
         let worksheet = self.worksheets.last_mut().unwrap();
@@ -557,11 +574,14 @@ impl Workbook {
     #[cfg(feature = "constant_memory")]
     #[cfg_attr(docsrs, doc(cfg(feature = "constant_memory")))]
     pub fn add_worksheet_with_constant_memory(&mut self) -> &mut Worksheet {
+        self.apply_reference_changes();
+
         let name = format!("Sheet{}", self.num_worksheets + 1);
         self.num_worksheets += 1;
 
         let mut worksheet = Worksheet::new();
         worksheet.set_name(&name).unwrap();
+        worksheet.theme_font = self.theme_font();
 
         if let Some(tempdir) = &self.tempdir {
             worksheet.file_writer = BufWriter::new(tempfile_in(tempdir).unwrap());
@@ -630,11 +650,14 @@ impl Workbook {
     #[cfg(feature = "constant_memory")]
     #[cfg_attr(docsrs, doc(cfg(feature = "constant_memory")))]
     pub fn add_worksheet_with_low_memory(&mut self) -> &mut Worksheet {
+        self.apply_reference_changes();
+
         let name = format!("Sheet{}", self.num_worksheets + 1);
         self.num_worksheets += 1;
 
         let mut worksheet = Worksheet::new();
         worksheet.set_name(&name).unwrap();
+        worksheet.theme_font = self.theme_font();
 
         if let Some(tempdir) = &self.tempdir {
             worksheet.file_writer = BufWriter::new(tempfile_in(tempdir).unwrap());
@@ -683,6 +706,7 @@ impl Workbook {
     #[cfg_attr(docsrs, doc(cfg(feature = "constant_memory")))]
     pub fn new_worksheet_with_constant_memory(&mut self) -> Worksheet {
         let mut worksheet = Worksheet::new();
+        worksheet.theme_font = self.theme_font();
 
         if let Some(tempdir) = &self.tempdir {
             worksheet.file_writer = BufWriter::new(tempfile_in(tempdir).unwrap());
@@ -725,6 +749,7 @@ impl Workbook {
     #[cfg_attr(docsrs, doc(cfg(feature = "constant_memory")))]
     pub fn new_worksheet_with_low_memory(&mut self) -> Worksheet {
         let mut worksheet = Worksheet::new();
+        worksheet.theme_font = self.theme_font();
 
         if let Some(tempdir) = &self.tempdir {
             worksheet.file_writer = BufWriter::new(tempfile_in(tempdir).unwrap());
@@ -880,11 +905,14 @@ impl Workbook {
     /// <img src="https://rustxlsxwriter.github.io/images/chartsheet.png">
     ///
     pub fn add_chartsheet(&mut self) -> &mut Worksheet {
+        self.apply_reference_changes();
+
         let name = format!("Chart{}", self.num_chartsheets + 1);
         self.num_chartsheets += 1;
 
         let mut worksheet = Worksheet::new_chartsheet();
         worksheet.set_name(&name).unwrap();
+        worksheet.theme_font = self.theme_font();
 
         self.worksheets.push(worksheet);
         let worksheet = self.worksheets.last_mut().unwrap();
@@ -964,6 +992,10 @@ impl Workbook {
     /// src="https://rustxlsxwriter.github.io/images/workbook_worksheet_from_index.png">
     ///
     pub fn worksheet_from_index(&mut self, index: usize) -> Result<&mut Worksheet, XlsxError> {
+        // Adjust the references in the worksheets for any changes in the
+        // previously accessed worksheet.
+        self.apply_reference_changes();
+
         match self.worksheets.get_mut(index) {
             Some(worksheet) => Ok(worksheet),
             _ => Err(XlsxError::UnknownWorksheetNameOrIndex(index.to_string())),
@@ -1114,6 +1146,8 @@ impl Workbook {
     /// src="https://rustxlsxwriter.github.io/images/workbook_worksheets_mut.png">
     ///
     pub fn worksheets_mut(&mut self) -> &mut Vec<Worksheet> {
+        self.apply_reference_changes();
+
         &mut self.worksheets
     }
 
@@ -1154,6 +1188,8 @@ impl Workbook {
     /// ```
     ///
     pub fn worksheets(&mut self) -> &Vec<Worksheet> {
+        self.apply_reference_changes();
+
         &self.worksheets
     }
 
@@ -1214,14 +1250,401 @@ impl Workbook {
     /// src="https://rustxlsxwriter.github.io/images/workbook_push_worksheet.png">
     ///
     pub fn push_worksheet(&mut self, mut worksheet: Worksheet) {
+        self.apply_reference_changes();
+
         if worksheet.name().is_empty() {
             let name = format!("Sheet{}", self.worksheets.len() + 1);
             worksheet.set_name(&name).unwrap();
         }
 
+        worksheet.theme_font = self.theme_font();
         self.worksheets.push(worksheet);
     }
 
+    /// Add a copy of a worksheet to the workbook.
+    ///
+    /// The `duplicate_worksheet()` method adds a copy of an existing worksheet
+    /// to the end of the workbook. This is useful when a template worksheet is
+    /// used as the basis for several other worksheets. The copy contains the
+    /// cell data and formatting, row and column settings, drawing objects such
+    /// as images and charts, tables, and the page setup of the original
+    /// worksheet.
+    ///
+    /// The copy is adjusted in the same way as a worksheet copied in Excel:
+    ///
+    /// - Charts in the copy that refer to data in the original worksheet refer
+    ///   to the same data in the new worksheet.
+    /// - Tables with user defined names are given a new unique name by
+    ///   changing the number at the end of the name, for example "Sales" to
+    ///   "Sales2" or "Table1" to "Table2". Tables with default names are
+    ///   numbered when the file is saved.
+    /// - Worksheet scoped names, like `"Sheet1!Rate"`, created with
+    ///   [`Workbook::define_name()`] are copied to the new worksheet.
+    /// - A VBA codename set with [`Worksheet::set_vba_name()`] is given a new
+    ///   unique name in the same way as the tables.
+    ///
+    /// The new worksheet isn't active or selected.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the worksheet to copy.
+    /// - `new_name`: The name of the new worksheet. See
+    ///   [`Worksheet::set_name()`] for the restrictions on worksheet names.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::UnknownWorksheetNameOrIndex`] - The worksheet name
+    ///   wasn't found.
+    /// - [`XlsxError::SheetnameReused`] - A worksheet with the new name
+    ///   already exists in the workbook.
+    /// - [`XlsxError::ParameterError`] - The worksheet uses "constant memory"
+    ///   or "low memory" mode and can't be copied.
+    /// - The sheet name errors returned by [`Worksheet::set_name()`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating several worksheets from a
+    /// template worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_duplicate_worksheet.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Create a template worksheet.
+    ///     let bold = Format::new().set_bold();
+    ///     let worksheet = workbook.add_worksheet().set_name("Template")?;
+    ///     worksheet.write_row_with_format(0, 0, ["Region", "Sales"], &bold)?;
+    ///     worksheet.set_column_width(0, 20)?;
+    ///
+    ///     // Create a worksheet for each quarter from the template.
+    ///     for quarter in ["Q1", "Q2", "Q3", "Q4"] {
+    ///         let worksheet = workbook.duplicate_worksheet("Template", quarter)?;
+    ///         worksheet.write(1, 0, quarter)?;
+    ///     }
+    ///
+    ///     // The template worksheet isn't required in the output.
+    ///     workbook.remove_worksheet("Template")?;
+    /// #
+    /// #     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn duplicate_worksheet(
+        &mut self,
+        name: &str,
+        new_name: &str,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let index = self.worksheet_index(name)?;
+
+        if self.worksheets[index].use_constant_memory {
+            return Err(XlsxError::ParameterError(format!(
+                "Worksheet '{name}' in constant memory mode can't be duplicated"
+            )));
+        }
+
+        // Bring the worksheets and names up to date with the row and column
+        // shifts so that the copy starts from the current references.
+        self.apply_reference_changes();
+
+        let mut worksheet = self.worksheets[index].duplicate();
+        worksheet.set_name(new_name)?;
+
+        // Connect the copy to the workbook formats and string table in the
+        // same way as the original.
+        let source = &self.worksheets[index];
+        worksheet.theme_font = self.theme_font();
+        if source.has_workbook_global_xfs {
+            worksheet.workbook_xf_indices = Arc::clone(&self.xf_indices);
+            worksheet.has_workbook_global_xfs = true;
+        }
+        if source.has_workbook_global_sst {
+            worksheet.string_table = Arc::clone(&self.string_table);
+            worksheet.has_workbook_global_sst = true;
+        }
+
+        if self.worksheet_index(new_name).is_ok() {
+            return Err(XlsxError::SheetnameReused(new_name.to_string()));
+        }
+
+        // Charts in the copy refer to the data in the copy, like Excel.
+        let old_name = self.worksheets[index].name.clone();
+        for chart in worksheet.charts.values_mut() {
+            chart.rename_sheet_ranges(&old_name, new_name);
+        }
+
+        // Give the named tables a new unique name.
+        let mut table_names: HashSet<String> = self
+            .worksheets
+            .iter()
+            .flat_map(|worksheet| &worksheet.tables)
+            .map(|table| table.name.to_lowercase())
+            .collect();
+
+        for table in &mut worksheet.tables {
+            if table.name.is_empty() {
+                continue;
+            }
+
+            let base_name = table.name.trim_end_matches(|c: char| c.is_ascii_digit());
+            let mut number = 2;
+            while table_names.contains(&format!("{base_name}{number}").to_lowercase()) {
+                number += 1;
+            }
+
+            table.name = format!("{base_name}{number}");
+            table_names.insert(table.name.to_lowercase());
+        }
+
+        // Give the VBA codename, if there is one, a new unique name in the same
+        // way as the tables.
+        if let Some(codename) = &worksheet.vba_codename {
+            let codenames: HashSet<String> = self
+                .worksheets
+                .iter()
+                .filter_map(|worksheet| worksheet.vba_codename.as_ref())
+                .map(|codename| codename.to_lowercase())
+                .collect();
+
+            let base_name = codename.trim_end_matches(|c: char| c.is_ascii_digit());
+            let mut number = 2;
+            while codenames.contains(&format!("{base_name}{number}").to_lowercase()) {
+                number += 1;
+            }
+
+            worksheet.vba_codename = Some(format!("{base_name}{number}"));
+        }
+
+        // Copy the worksheet scoped defined names.
+        let quoted_new_name = utility::quote_sheet_name(new_name);
+        let local_names: Vec<DefinedName> = self
+            .user_defined_names
+            .iter()
+            .filter(|defined_name| Self::is_local_name(defined_name, &old_name))
+            .map(|defined_name| {
+                let mut defined_name = defined_name.clone();
+                defined_name.quoted_sheet_name.clone_from(&quoted_new_name);
+                defined_name.range =
+                    shift::rename_sheet_references(&defined_name.range, &old_name, Some(new_name));
+                defined_name
+            })
+            .collect();
+        self.user_defined_names.extend(local_names);
+
+        self.worksheets.push(worksheet);
+        let worksheet = self.worksheets.last_mut().unwrap();
+
+        Ok(worksheet)
+    }
+
+    /// Move a worksheet to a new position in the workbook.
+    ///
+    /// The `move_worksheet()` method changes the order of the worksheet tabs
+    /// in the workbook. The worksheet is removed from its current position and
+    /// inserted at the new position.
+    ///
+    /// The active worksheet, the first visible tab, and the references to the
+    /// worksheet in formulas, charts and defined names stay the same since
+    /// they refer to worksheets by name.
+    ///
+    /// However, 3D references like `Sheet1:Sheet3!A1` refer to all the
+    /// worksheets between the first and last worksheet, by position. Excel
+    /// changes the worksheets that these references span when a worksheet is
+    /// moved into or out of the span. `rust_xlsxwriter` doesn't adjust the
+    /// references for this so a move that would change the worksheets spanned
+    /// by a 3D reference in a worksheet formula, conditional format, data
+    /// validation or defined name returns an error. Moves of worksheets
+    /// outside the span, or within it, are allowed.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`: The zero indexed position of the worksheet to move.
+    /// - `to`: The zero indexed position to move the worksheet to.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::UnknownWorksheetNameOrIndex`] - One of the positions is
+    ///   outside the range of worksheets in the workbook.
+    /// - [`XlsxError::ParameterError`] - The move would change the worksheets
+    ///   spanned by a 3D reference.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates moving a summary worksheet, that is
+    /// added last, to the start of the workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_move_worksheet.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Add some data worksheets.
+    ///     for region in ["North", "South", "East", "West"] {
+    ///         let worksheet = workbook.add_worksheet().set_name(region)?;
+    ///         worksheet.write(0, 0, 1000)?;
+    ///     }
+    ///
+    ///     // Add a summary worksheet.
+    ///     let worksheet = workbook.add_worksheet().set_name("Summary")?;
+    ///     worksheet.write_formula(0, 0, "=SUM(North:West!A1)")?;
+    ///
+    ///     // Move the summary to the first tab.
+    ///     workbook.move_worksheet(4, 0)?;
+    /// #
+    /// #     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn move_worksheet(&mut self, from: usize, to: usize) -> Result<&mut Workbook, XlsxError> {
+        for index in [from, to] {
+            if index >= self.worksheets.len() {
+                return Err(XlsxError::UnknownWorksheetNameOrIndex(index.to_string()));
+            }
+        }
+
+        // Check that the move doesn't change the worksheets in any 3D
+        // reference span.
+        let names: Vec<String> = self
+            .worksheets
+            .iter()
+            .map(|worksheet| worksheet.name.to_lowercase())
+            .collect();
+        let mut moved_names = names.clone();
+        let name = moved_names.remove(from);
+        moved_names.insert(to, name);
+
+        let mut spans: Vec<(String, String)> = self
+            .user_defined_names
+            .iter()
+            .flat_map(|defined_name| shift::sheet_spans(&defined_name.range))
+            .collect();
+        for worksheet in &mut self.worksheets {
+            spans.extend(worksheet.sheet_spans());
+        }
+
+        for (first, last) in spans {
+            if Self::spanned_sheets(&names, &first, &last)
+                != Self::spanned_sheets(&moved_names, &first, &last)
+            {
+                return Err(XlsxError::ParameterError(format!(
+                    "Moving worksheet '{}' would change the worksheets in the 3D reference '{first}:{last}'",
+                    self.worksheets[from].name
+                )));
+            }
+        }
+
+        let worksheet = self.worksheets.remove(from);
+        self.worksheets.insert(to, worksheet);
+
+        Ok(self)
+    }
+
+    // Get the lowercase names of the worksheets spanned by the first and last
+    // worksheets of a 3D reference, in name order, for a worksheet order.
+    fn spanned_sheets(names: &[String], first: &str, last: &str) -> Vec<String> {
+        let position = |name: &str| names.iter().position(|n| *n == name.to_lowercase());
+
+        let (Some(first), Some(last)) = (position(first), position(last)) else {
+            return vec![];
+        };
+
+        let mut spanned = names[first.min(last)..=first.max(last)].to_vec();
+        spanned.sort();
+        spanned
+    }
+
+    /// Remove a worksheet from the workbook.
+    ///
+    /// The `remove_worksheet()` method removes a worksheet from the workbook
+    /// and returns it. The returned worksheet can be discarded, or added back
+    /// to the workbook with [`Workbook::push_worksheet()`].
+    ///
+    /// The references to the worksheet in the rest of the workbook are
+    /// adjusted in the same way as Excel:
+    ///
+    /// - Worksheet scoped names, like `"Sheet1!Rate"`, created with
+    ///   [`Workbook::define_name()`] are removed.
+    /// - References to the worksheet in the other defined names are replaced
+    ///   with the `#REF!` error.
+    /// - References to the worksheet in the other worksheets are replaced with
+    ///   the `#REF!` error. This includes cell formulas, table column formulas,
+    ///   conditional format and data validation rules, and the locations of
+    ///   internal hyperlinks.
+    /// - Sparklines in the other worksheets that display data from the
+    ///   worksheet are removed.
+    ///
+    /// Charts in the other worksheets can't refer to the data in the removed
+    /// worksheet, see the errors below.
+    ///
+    /// If the removed worksheet was the active worksheet then the first
+    /// worksheet is made active when the file is saved.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the worksheet to remove.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::UnknownWorksheetNameOrIndex`] - The worksheet name
+    ///   wasn't found.
+    /// - [`XlsxError::ChartError`] - A chart in another worksheet refers to
+    ///   data in the worksheet. Excel requires chart data to be in the
+    ///   workbook.
+    ///
+    /// # Examples
+    ///
+    /// See the example for [`Workbook::duplicate_worksheet()`] above.
+    ///
+    pub fn remove_worksheet(&mut self, name: &str) -> Result<Worksheet, XlsxError> {
+        let index = self.worksheet_index(name)?;
+        let name = self.worksheets[index].name.clone();
+
+        // Check that charts in the other worksheets don't refer to the data.
+        for (i, worksheet) in self.worksheets.iter().enumerate() {
+            if i == index {
+                continue;
+            }
+
+            for chart in worksheet.charts.values() {
+                if chart.has_sheet_ranges(&name) {
+                    return Err(XlsxError::ChartError(format!(
+                        "Worksheet '{name}' can't be removed since it contains data for a chart in worksheet '{}'",
+                        worksheet.name
+                    )));
+                }
+            }
+        }
+
+        // Remove the worksheet scoped names and adjust the other names. The
+        // pending shifts are applied first so that shifts in the removed
+        // worksheet don't apply to a later worksheet with the same name.
+        self.apply_reference_changes();
+        self.user_defined_names
+            .retain(|defined_name| !Self::is_local_name(defined_name, &name));
+
+        for defined_name in &mut self.user_defined_names {
+            defined_name.range = shift::rename_sheet_references(&defined_name.range, &name, None);
+        }
+
+        let worksheet = self.worksheets.remove(index);
+
+        for worksheet in &mut self.worksheets {
+            worksheet.rename_sheet_references(&name, None);
+        }
+
+        Ok(worksheet)
+    }
+
     /// Save the Workbook as an xlsx file.
     ///
     /// The workbook `save()` method writes all the Workbook data to a new xlsx
@@ -1246,6 +1669,8 @@ impl Workbook {
     /// - [`XlsxError::TableNameReused`] - Worksheet Table name is already in
     ///   use in the workbook.
     /// - [`XlsxError::ChartError`] - A Chartsheet doesn't contain a chart.
+    /// - [`XlsxError::ParameterError`] - Formats use different cell styles
+    ///   with the same name.
     /// - [`XlsxError::IoError`] - A wrapper for various IO errors when creating
     ///   the xlsx file, or its sub-files.
     /// - [`XlsxError::ZipError`] - A wrapper for various zip errors when
@@ -1578,6 +2003,10 @@ impl Workbook {
         defined_name.range = utility::formula_to_string(formula);
         defined_name.set_sort_name();
 
+        // Adjust the existing references for any changes in the worksheets so
+        // that only the changes after this point apply to the new name.
+        self.apply_reference_changes();
+
         self.user_defined_names.push(defined_name);
 
         Ok(self)
@@ -1983,6 +2412,227 @@ impl Workbook {
         self
     }
 
+    /// Set a custom theme for the workbook.
+    ///
+    /// Replace the default Excel "Office" theme with a custom
+    /// [`WorkbookTheme`]. The theme defines the colors used by
+    /// [`Color::Theme`] colors in formats, and the theme colors and fonts in
+    /// charts and shapes.
+    ///
+    /// The theme body font is the default font of the cells in the workbook
+    /// and it is used to convert column widths between pixels and character
+    /// units. The theme should be set before any worksheet data is written
+    /// since pixel column widths and rich strings added before the theme is
+    /// set use the default "Calibri" font.
+    ///
+    /// # Parameters
+    ///
+    /// - `theme`: The [`WorkbookTheme`] to use in the workbook.
+    ///
+    /// # Examples
+    ///
+    /// An example of setting a custom theme for a workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_theme.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Format, Workbook, WorkbookTheme, WorkbookThemeColor, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Create a custom theme with some brand colors and fonts.
+    ///     let theme = WorkbookTheme::new()
+    ///         .set_name("Brand")
+    ///         .set_color(WorkbookThemeColor::Accent1, "#00A3E0")
+    ///         .set_color(WorkbookThemeColor::Accent2, "#E35205")
+    ///         .set_major_font("Georgia")
+    ///         .set_minor_font("Verdana");
+    ///
+    ///     workbook.set_theme(&theme);
+    /// #
+    /// #     // Theme colors in formats resolve to the custom palette.
+    /// #     let format = Format::new().set_background_color(Color::Theme(4, 0));
+    /// #
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.write_with_format(0, 0, "Accent 1", &format)?;
+    /// #
+    /// #     workbook.save("workbook_theme.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_theme(&mut self, theme: &WorkbookTheme) -> &mut Workbook {
+        self.theme = theme.clone();
+
+        let theme_font = self.theme_font();
+        for worksheet in &mut self.worksheets {
+            worksheet.theme_font.clone_from(&theme_font);
+        }
+
+        self
+    }
+
+    /// Set the window and display properties of the workbook view.
+    ///
+    /// The `set_workbook_view()` method is used to set the position and size
+    /// of the workbook window and to turn on/off the worksheet tabs, the
+    /// scroll bars and other parts of the Excel user interface. See
+    /// [`WorkbookView`] for the available properties and an example.
+    ///
+    /// # Parameters
+    ///
+    /// - `view`: A [`WorkbookView`] struct reference.
+    ///
+    pub fn set_workbook_view(&mut self, view: &WorkbookView) -> &mut Workbook {
+        self.workbook_view = view.clone();
+        self
+    }
+
+    /// Add a named cell style to the workbook.
+    ///
+    /// Add a [`CellStyle`] to the workbook so that it is shown in the Excel
+    /// "Cell Styles" gallery, even if it isn't used by any cell. Styles that
+    /// are used via [`Format::set_cell_style()`] are added to the workbook
+    /// automatically.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: The [`CellStyle`] to add to the workbook.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The style name is blank, is one of
+    ///   the reserved names "Normal" or "Hyperlink", or a style with the same
+    ///   name has already been added. Style names are case insensitive.
+    ///
+    /// # Examples
+    ///
+    /// An example of using built-in and user defined named cell styles.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_cell_style.rs
+    /// #
+    /// # use rust_xlsxwriter::{BuiltinCellStyle, CellStyle, Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Create some built-in and user defined styles.
+    /// #     let heading = CellStyle::new_builtin(BuiltinCellStyle::Heading1);
+    /// #     let good = CellStyle::new_builtin(BuiltinCellStyle::Good);
+    ///     let house_style = CellStyle::new(
+    ///         "House Style",
+    ///         &Format::new().set_bold().set_font_color("#1F4E79"),
+    ///     );
+    ///
+    ///     // Add a style to the workbook without using it in a cell.
+    ///     workbook.add_cell_style(&house_style)?;
+    /// #
+    /// #     // Create formats that inherit from the styles.
+    /// #     let heading_format = Format::new().set_cell_style(&heading);
+    /// #     let good_format = Format::new().set_cell_style(&good).set_italic();
+    /// #
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.write_with_format(0, 0, "Results", &heading_format)?;
+    /// #     worksheet.write_with_format(1, 0, "Passed", &good_format)?;
+    /// #
+    /// #     workbook.save("cell_styles.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_cell_style(&mut self, style: &CellStyle) -> Result<&mut Workbook, XlsxError> {
+        let name = style.name.to_lowercase();
+
+        if name.trim().is_empty() {
+            return Err(XlsxError::ParameterError(
+                "Cell style name cannot be blank".to_string(),
+            ));
+        }
+
+        if name == "normal" || name == "hyperlink" {
+            return Err(XlsxError::ParameterError(format!(
+                "Cell style name '{}' is reserved",
+                style.name
+            )));
+        }
+
+        if self
+            .cell_styles
+            .iter()
+            .any(|existing| existing.name.to_lowercase() == name)
+        {
+            return Err(XlsxError::ParameterError(format!(
+                "Cell style name '{}' has already been added to the workbook",
+                style.name
+            )));
+        }
+
+        self.cell_styles.push(style.clone());
+
+        Ok(self)
+    }
+
+    /// Add a custom table style to the workbook.
+    ///
+    /// Add a [`CustomTableStyle`] to the workbook so that it is shown in the
+    /// Excel "Table Styles" or "PivotTable Styles" gallery, even if it isn't
+    /// used by any table. Styles that are used via
+    /// [`Table::set_custom_style()`](crate::Table::set_custom_style) are added
+    /// to the workbook automatically.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: The [`CustomTableStyle`] to add to the workbook.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The style name is blank, is the name
+    ///   of an Excel built-in style such as "TableStyleMedium9", or is already
+    ///   used by a different style. Style names are case insensitive.
+    ///
+    pub fn add_table_style(
+        &mut self,
+        style: &CustomTableStyle,
+    ) -> Result<&mut Workbook, XlsxError> {
+        if style.name.trim().is_empty() {
+            return Err(XlsxError::ParameterError(
+                "Table style name cannot be blank".to_string(),
+            ));
+        }
+
+        if style.is_builtin_name() {
+            return Err(XlsxError::ParameterError(format!(
+                "Table style name '{}' is reserved for an Excel built-in style",
+                style.name
+            )));
+        }
+
+        let name = style.name.to_lowercase();
+        if let Some(existing) = self
+            .table_styles
+            .iter()
+            .find(|existing| existing.name.to_lowercase() == name)
+        {
+            if existing != style {
+                return Err(XlsxError::ParameterError(format!(
+                    "Table style name '{}' is already used by a different style",
+                    style.name
+                )));
+            }
+
+            return Ok(self);
+        }
+
+        self.table_styles.push(style.clone());
+
+        Ok(self)
+    }
+
     /// Set the order/index for the format.
     ///
     /// This is currently only used in testing to ensure the same format order
@@ -2125,6 +2775,10 @@ impl Workbook {
             worksheet_dxf_indices.push(indices);
         }
 
+        // Add the DXF formats used by custom table styles after the worksheet
+        // DXF formats.
+        self.prepare_table_styles()?;
+
         // We extract the XF Formats used as keys in the index lookup to a
         // vector of formats sorted by their index number.
         let xf_indices = self.xf_indices.read().expect("RwLock poisoned");
@@ -2173,17 +2827,25 @@ impl Workbook {
         // Prepare the worksheet VML elements such as buttons and header images.
         self.prepare_vml();
 
+        // Adjust the references between worksheets for any inserted or deleted
+        // rows and columns. This is needed before the chart data is read.
+        self.apply_reference_changes();
+
         // Fill the chart data caches from worksheet data.
         self.prepare_chart_cache_data()?;
 
         // Prepare the formats for writing with styles.rs.
-        self.prepare_format_properties();
+        self.prepare_format_properties()?;
 
         // Prepare worksheet tables.
         self.prepare_tables()?;
 
         // Update the shared string table in each worksheet.
         for worksheet in &mut self.worksheets {
+            // Set the font for any phonetic strings. This requires the font
+            // indices from prepare_format_properties().
+            worksheet.set_phonetic_font_id(&self.xf_formats);
+
             if !worksheet.has_workbook_global_sst {
                 let string_table = self.string_table.clone();
                 worksheet.update_string_table_ids(string_table);
@@ -2201,6 +2863,24 @@ impl Workbook {
         Ok(())
     }
 
+    // Get the index of a worksheet from its name. Excel worksheet names are
+    // case insensitive.
+    fn worksheet_index(&self, sheetname: &str) -> Result<usize, XlsxError> {
+        let lowercase_name = sheetname.to_lowercase();
+
+        self.worksheets
+            .iter()
+            .position(|worksheet| worksheet.name.to_lowercase() == lowercase_name)
+            .ok_or_else(|| XlsxError::UnknownWorksheetNameOrIndex(sheetname.to_string()))
+    }
+
+    // Check if a user defined name is scoped to a worksheet.
+    fn is_local_name(defined_name: &DefinedName, sheetname: &str) -> bool {
+        matches!(defined_name.name_type, DefinedNameType::Local)
+            && utility::unquote_sheetname(&defined_name.quoted_sheet_name).to_lowercase()
+                == sheetname.to_lowercase()
+    }
+
     // Iterates through the worksheets and finds which is the user-defined Active
     // sheet. If none has been set, then defaults to the first sheet, like Excel.
     fn set_active_worksheets(&mut self) {
@@ -2227,7 +2907,7 @@ impl Workbook {
 
         for worksheet in &mut self.worksheets {
             if worksheet.has_vml {
-                let note_count = worksheet.prepare_vml_objects(vml_data_id, vml_shape_id);
+                let shape_count = worksheet.prepare_vml_objects(vml_data_id, vml_shape_id);
                 worksheet.add_vml_drawing_rel_link(vml_drawing_id);
                 vml_drawing_id += 1;
 
@@ -2238,8 +2918,8 @@ impl Workbook {
                 }
 
                 // Each VML should start with a shape id incremented by 1024.
-                vml_data_id += (1024 + note_count) / 1024;
-                vml_shape_id += 1024 * ((1024 + note_count) / 1024);
+                vml_data_id += (1024 + shape_count) / 1024;
+                vml_shape_id += 1024 * ((1024 + shape_count) / 1024);
             }
 
             if worksheet.has_header_footer_images() {
@@ -2258,6 +2938,16 @@ impl Workbook {
         let mut image_ids: HashMap<String, u32> = HashMap::new();
         let mut global_image_id = 0;
 
+        // The #SPILL! and #CALC! errors are stored as rich values before the
+        // embedded images.
+        if self
+            .worksheets
+            .iter()
+            .any(|worksheet| worksheet.has_rich_value_errors)
+        {
+            global_image_id = ExcelError::RICH_VALUE_ERRORS.len() as u32;
+        }
+
         for worksheet in &mut self.worksheets {
             if worksheet.embedded_images.is_empty() {
                 continue;
@@ -2295,6 +2985,8 @@ impl Workbook {
         let mut worksheet_image_ids: HashMap<String, u32> = HashMap::new();
         let mut header_footer_image_ids: HashMap<String, u32> = HashMap::new();
         let mut background_image_ids: HashMap<String, u32> = HashMap::new();
+        let mut ole_object_image_ids: HashMap<String, u32> = HashMap::new();
+        let mut ole_object_id = 1;
 
         for worksheet in &mut self.worksheets {
             if let Some(image) = &worksheet.background_image {
@@ -2341,6 +3033,15 @@ impl Workbook {
             if worksheet.has_header_footer_images() {
                 worksheet.prepare_header_footer_images(&mut header_footer_image_ids, &mut image_id);
             }
+
+            if !worksheet.ole_objects.is_empty() {
+                worksheet.prepare_ole_objects(
+                    &mut ole_object_image_ids,
+                    &mut image_id,
+                    ole_object_id,
+                );
+                ole_object_id += worksheet.ole_objects.len() as u32;
+            }
         }
     }
 
@@ -2471,9 +3172,7 @@ impl Workbook {
             Self::update_range_cache(&mut series.category_range, chart_caches);
 
             for data_label in &mut series.custom_data_labels {
-                if let Some(cache) = chart_caches.get(&data_label.title.range.key()) {
-                    data_label.title.range.cache = cache.clone();
-                }
+                Self::update_range_cache(&mut data_label.title.range, chart_caches);
             }
 
             if let Some(error_bars) = &mut series.y_error_bars {
@@ -2494,7 +3193,7 @@ impl Workbook {
         range: &ChartRange,
         chart_caches: &mut HashMap<(String, RowNum, ColNum, RowNum, ColNum), ChartRangeCacheData>,
     ) {
-        if range.has_data() {
+        if range.has_data() && !range.is_deleted() {
             chart_caches.insert(range.key(), ChartRangeCacheData::new());
         }
     }
@@ -2504,6 +3203,10 @@ impl Workbook {
         range: &mut ChartRange,
         chart_caches: &mut HashMap<(String, RowNum, ColNum, RowNum, ColNum), ChartRangeCacheData>,
     ) {
+        if range.is_deleted() {
+            return;
+        }
+
         if let Some(cache) = chart_caches.get(&range.key()) {
             range.cache = cache.clone();
         }
@@ -2543,8 +3246,16 @@ impl Workbook {
         }
     }
 
+    // Get the theme fonts used by the worksheets.
+    fn theme_font(&self) -> ThemeFont {
+        ThemeFont::new(&self.theme.minor_font, &self.theme.major_font)
+    }
+
     // Prepare all Format properties prior to passing them to styles.rs.
-    fn prepare_format_properties(&mut self) {
+    fn prepare_format_properties(&mut self) -> Result<(), XlsxError> {
+        // Collect the named cell styles used by the format objects.
+        self.prepare_cell_styles()?;
+
         // Set the font index for the format objects.
         self.prepare_fonts();
 
@@ -2560,6 +3271,105 @@ impl Workbook {
         // Check for any format properties that require a feature bag. Currently,
         // this only applies to checkboxes.
         self.prepare_feature_property_bags();
+
+        Ok(())
+    }
+
+    // Collect the user registered named cell styles and any styles used by the
+    // format objects, and set the index of the style xf for the formats.
+    fn prepare_cell_styles(&mut self) -> Result<(), XlsxError> {
+        let mut cell_styles = self.cell_styles.clone();
+        let mut style_names: Vec<String> = cell_styles
+            .iter()
+            .map(|style| style.name.to_lowercase())
+            .collect();
+
+        for xf_format in &self.xf_formats {
+            if let Some(style) = &xf_format.cell_style {
+                let name = style.name.to_lowercase();
+                match style_names.iter().position(|n| *n == name) {
+                    Some(index) => {
+                        if cell_styles[index] != **style {
+                            return Err(XlsxError::ParameterError(format!(
+                                "Cell style name '{}' is already used by a different style",
+                                style.name
+                            )));
+                        }
+                    }
+                    None => {
+                        style_names.push(name);
+                        cell_styles.push(style.as_ref().clone());
+                    }
+                }
+            }
+        }
+
+        // The user styles follow the "Normal" and optional "Hyperlink" styles.
+        let offset = 1 + u32::from(self.has_hyperlink_style);
+
+        for xf_format in &mut self.xf_formats {
+            if let Some(style) = &xf_format.cell_style {
+                let name = style.name.to_lowercase();
+                if let Some(index) = style_names.iter().position(|n| *n == name) {
+                    xf_format.style_xf_id = offset + index as u32;
+                }
+            }
+        }
+
+        self.cell_style_xfs = cell_styles;
+
+        Ok(())
+    }
+
+    // Collect the user registered custom table styles and any styles used by
+    // worksheet tables, and add the DXF formats for their elements.
+    fn prepare_table_styles(&mut self) -> Result<(), XlsxError> {
+        let mut table_styles = self.table_styles.clone();
+
+        for worksheet in &self.worksheets {
+            for table in &worksheet.tables {
+                let Some(style) = &table.custom_style else {
+                    continue;
+                };
+
+                let name = style.name.to_lowercase();
+                match table_styles
+                    .iter()
+                    .find(|existing| existing.name.to_lowercase() == name)
+                {
+                    Some(existing) => {
+                        if existing != style {
+                            return Err(XlsxError::ParameterError(format!(
+                                "Table style name '{}' is already used by a different style",
+                                style.name
+                            )));
+                        }
+                    }
+                    None => {
+                        if style.name.trim().is_empty() || style.is_builtin_name() {
+                            return Err(XlsxError::ParameterError(format!(
+                                "Invalid custom table style name '{}'",
+                                style.name
+                            )));
+                        }
+
+                        table_styles.push(style.clone());
+                    }
+                }
+            }
+        }
+
+        for style in &mut table_styles {
+            style.dxf_indices = style
+                .elements
+                .iter()
+                .map(|(_, format)| self.format_dxf_index(format))
+                .collect();
+        }
+
+        self.table_style_dxfs = table_styles;
+
+        Ok(())
     }
 
     // Set the font index for the format objects. This only needs to be done for
@@ -2568,7 +3378,11 @@ impl Workbook {
         let mut font_count: u16 = 0;
         let mut font_indices: HashMap<Font, u16> = HashMap::new();
 
-        for xf_format in &mut self.xf_formats {
+        let style_formats = self
+            .cell_style_xfs
+            .iter_mut()
+            .map(|style| &mut style.format);
+        for xf_format in self.xf_formats.iter_mut().chain(style_formats) {
             match font_indices.get(&xf_format.font) {
                 Some(font_index) => {
                     xf_format.set_font_index(*font_index, false);
@@ -2601,12 +3415,17 @@ impl Workbook {
             ),
         ]);
 
-        for xf_format in &mut self.xf_formats {
+        let style_formats = self
+            .cell_style_xfs
+            .iter_mut()
+            .map(|style| &mut style.format);
+        for xf_format in self.xf_formats.iter_mut().chain(style_formats) {
             let fill = &mut xf_format.fill;
             // If the user specifies a foreground or background color without a
             // pattern they probably wanted a solid fill, so we fill in the
-            // defaults.
-            if (fill.pattern == FormatPattern::None || fill.pattern == FormatPattern::Solid)
+            // defaults. This doesn't apply to gradient fills.
+            if fill.gradient.is_none()
+                && (fill.pattern == FormatPattern::None || fill.pattern == FormatPattern::Solid)
                 && fill.background_color != Color::Default
                 && fill.foreground_color == Color::Default
             {
@@ -2615,7 +3434,8 @@ impl Workbook {
                 fill.pattern = FormatPattern::Solid;
             }
 
-            if (fill.pattern == FormatPattern::None || fill.pattern == FormatPattern::Solid)
+            if fill.gradient.is_none()
+                && (fill.pattern == FormatPattern::None || fill.pattern == FormatPattern::Solid)
                 && fill.background_color == Color::Default
                 && fill.foreground_color != Color::Default
             {
@@ -2643,7 +3463,11 @@ impl Workbook {
         let mut border_count: u16 = 0;
         let mut border_indices: HashMap<Border, u16> = HashMap::new();
 
-        for xf_format in &mut self.xf_formats {
+        let style_formats = self
+            .cell_style_xfs
+            .iter_mut()
+            .map(|style| &mut style.format);
+        for xf_format in self.xf_formats.iter_mut().chain(style_formats) {
             match border_indices.get(&xf_format.borders) {
                 Some(border_index) => {
                     xf_format.set_border_index(*border_index, false);
@@ -2668,10 +3492,18 @@ impl Workbook {
         let mut unique_num_formats: HashMap<String, u16> = HashMap::new();
         // User defined number formats in Excel start from index 164.
         let mut index = 164;
-        let xf_formats = [&mut self.xf_formats, &mut self.dxf_formats];
+        let style_formats = self
+            .cell_style_xfs
+            .iter_mut()
+            .map(|style| &mut style.format);
+        let xf_formats = self
+            .xf_formats
+            .iter_mut()
+            .chain(style_formats)
+            .chain(self.dxf_formats.iter_mut());
         self.num_formats = vec![];
 
-        for xf_format in xf_formats.into_iter().flatten() {
+        for xf_format in xf_formats {
             if xf_format.num_format_index > 0 {
                 continue;
             }
@@ -2720,6 +3552,30 @@ impl Workbook {
         }
     }
 
+    // Adjust the references to each worksheet in the other worksheets, and in
+    // the defined names, for the rows or columns that have been inserted into,
+    // or deleted from, the worksheet, and for the ranges that have been moved,
+    // since the last update. The changes are stored in the worksheets and are
+    // applied whenever a worksheet is accessed via the workbook so that only
+    // the references written before a change are adjusted.
+    fn apply_reference_changes(&mut self) {
+        for index in 0..self.worksheets.len() {
+            let changes = std::mem::take(&mut self.worksheets[index].reference_changes);
+
+            for (sheet_name, change) in &changes {
+                for (i, worksheet) in self.worksheets.iter_mut().enumerate() {
+                    if i != index {
+                        worksheet.apply_external_change(sheet_name, change);
+                    }
+                }
+
+                for defined_name in &mut self.user_defined_names {
+                    defined_name.range = change.formula(&defined_name.range, sheet_name, false);
+                }
+            }
+        }
+    }
+
     // Collect some workbook-level metadata to help generate the xlsx
     // package/file.
     fn set_package_options(
@@ -2766,6 +3622,11 @@ impl Workbook {
                 package_options.has_dynamic_functions = true;
             }
 
+            if worksheet.has_rich_value_errors {
+                package_options.has_metadata = true;
+                package_options.has_rich_value_errors = true;
+            }
+
             if !worksheet.embedded_images.is_empty() {
                 package_options.has_metadata = true;
                 package_options.has_embedded_images = true;
@@ -2794,6 +3655,10 @@ impl Workbook {
                 package_options.num_comments += 1;
             }
 
+            if !worksheet.ole_objects.is_empty() {
+                package_options.num_ole_objects += worksheet.ole_objects.len() as u16;
+            }
+
             // Store the autofilter areas which are a category of defined name.
             if worksheet.autofilter_defined_name.in_use {
                 let mut defined_name = worksheet.autofilter_defined_name.clone();
@@ -2895,6 +3760,11 @@ impl Workbook {
         // Write the calcPr element.
         self.write_calc_pr();
 
+        // Write the customWorkbookViews element.
+        if self.worksheets.iter().any(|ws| !ws.custom_views.is_empty()) {
+            self.write_custom_workbook_views();
+        }
+
         // Close the workbook tag.
         xml_end_tag(&mut self.writer, "workbook");
     }
@@ -2940,7 +3810,7 @@ impl Workbook {
             attributes.push(("codeName", codename.clone()));
         }
 
-        attributes.push(("defaultThemeVersion", "124226".to_string()));
+        attributes.push(("defaultThemeVersion", self.theme.version.to_string()));
 
         xml_empty_tag(&mut self.writer, "workbookPr", &attributes);
     }
@@ -2957,12 +3827,33 @@ impl Workbook {
 
     // Write the <workbookView> element.
     fn write_workbook_view(&mut self) {
-        let mut attributes = vec![
-            ("xWindow", "240".to_string()),
-            ("yWindow", "15".to_string()),
-            ("windowWidth", "16095".to_string()),
-            ("windowHeight", "9660".to_string()),
-        ];
+        let view = &self.workbook_view;
+        let mut attributes = vec![];
+
+        if view.minimized {
+            attributes.push(("minimized", "1".to_string()));
+        }
+
+        if !view.horizontal_scroll_bar {
+            attributes.push(("showHorizontalScroll", "0".to_string()));
+        }
+
+        if !view.vertical_scroll_bar {
+            attributes.push(("showVerticalScroll", "0".to_string()));
+        }
+
+        if !view.sheet_tabs {
+            attributes.push(("showSheetTabs", "0".to_string()));
+        }
+
+        attributes.push(("xWindow", view.x_window.to_string()));
+        attributes.push(("yWindow", view.y_window.to_string()));
+        attributes.push(("windowWidth", view.window_width.to_string()));
+        attributes.push(("windowHeight", view.window_height.to_string()));
+
+        if view.tab_ratio != 600 {
+            attributes.push(("tabRatio", view.tab_ratio.to_string()));
+        }
 
         // Store the firstSheet attribute when it isn't the first sheet.
         if self.first_sheet > 0 {
@@ -2975,6 +3866,10 @@ impl Workbook {
             attributes.push(("activeTab", self.active_tab.to_string()));
         }
 
+        if !self.workbook_view.autofilter_date_grouping {
+            attributes.push(("autoFilterDateGrouping", "0".to_string()));
+        }
+
         xml_empty_tag(&mut self.writer, "workbookView", &attributes);
     }
 
@@ -3048,4 +3943,39 @@ impl Workbook {
 
         xml_empty_tag(&mut self.writer, "calcPr", &attributes);
     }
+
+    // Write the <customWorkbookViews> element.
+    fn write_custom_workbook_views(&mut self) {
+        // Get the unique custom views, in worksheet order, and the 1-based
+        // index of the first worksheet that contains each view.
+        let mut views: Vec<(&CustomView, usize)> = vec![];
+        for (index, worksheet) in self.worksheets.iter().enumerate() {
+            for view in &worksheet.custom_views {
+                if !views.iter().any(|(v, _)| v.name == view.name) {
+                    views.push((view, index + 1));
+                }
+            }
+        }
+
+        let views: Vec<(String, String, usize)> = views
+            .iter()
+            .map(|(view, sheet_id)| (view.name.clone(), view.guid(), *sheet_id))
+            .collect();
+
+        xml_start_tag_only(&mut self.writer, "customWorkbookViews");
+
+        for (name, guid, sheet_id) in views {
+            let attributes = [
+                ("name", name),
+                ("guid", guid),
+                ("windowWidth", self.workbook_view.window_width.to_string()),
+                ("windowHeight", self.workbook_view.window_height.to_string()),
+                ("activeSheetId", sheet_id.to_string()),
+            ];
+
+            xml_empty_tag(&mut self.writer, "customWorkbookView", &attributes);
+        }
+
+        xml_end_tag(&mut self.writer, "customWorkbookViews");
+    }
 }
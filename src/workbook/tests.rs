@@ -8,7 +8,11 @@
 mod workbook_tests {
 
     use crate::{test_functions::xml_to_vec, XlsxError};
-    use crate::{xmlwriter, Table, Workbook};
+    use crate::{
+        xmlwriter, BuiltinCellStyle, Button, CellStyle, CellValue, Chart, ChartType,
+        CustomTableStyle, CustomView, Format, FormatGradient, FormatPattern, Note, Table,
+        TableStyleElement, Workbook, WorkbookTheme, WorkbookView, Worksheet,
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -41,6 +45,85 @@ mod workbook_tests {
         assert_eq!(expected, got);
     }
 
+    #[test]
+    fn workbook_view() {
+        let mut workbook = Workbook::default();
+
+        let view = WorkbookView::new()
+            .set_window_position(20, 10)
+            .set_window_position(u32::MAX, 0)
+            .set_window_size(800, 600)
+            .set_window_size(0, u32::MAX)
+            .set_minimized(true)
+            .set_tab_ratio(1001)
+            .set_tab_ratio(250)
+            .set_sheet_tabs(false)
+            .set_horizontal_scroll_bar(false)
+            .set_vertical_scroll_bar(false)
+            .set_autofilter_date_grouping(false);
+
+        workbook.set_workbook_view(&view);
+        workbook.write_workbook_view();
+
+        let got = xmlwriter::cursor_to_str(&workbook.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <workbookView minimized="1" showHorizontalScroll="0" showVerticalScroll="0" showSheetTabs="0" xWindow="300" yWindow="150" windowWidth="12000" windowHeight="9000" tabRatio="250" autoFilterDateGrouping="0"/>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn custom_workbook_views() {
+        let mut workbook = Workbook::default();
+
+        let view1 = CustomView::new("Edit").set_zoom(150);
+        let view2 = CustomView::new("Print").set_landscape();
+
+        workbook.add_worksheet();
+        workbook.add_worksheet().add_custom_view(&view2);
+        workbook
+            .add_worksheet()
+            .add_custom_view(&view1)
+            .add_custom_view(&view2);
+
+        workbook.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&workbook.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(&format!(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <fileVersion appName="xl" lastEdited="4" lowestEdited="4" rupBuild="4505"/>
+              <workbookPr defaultThemeVersion="124226"/>
+              <bookViews>
+                <workbookView xWindow="240" yWindow="15" windowWidth="16095" windowHeight="9660"/>
+              </bookViews>
+              <sheets>
+                <sheet name="Sheet1" sheetId="1" r:id="rId1"/>
+                <sheet name="Sheet2" sheetId="2" r:id="rId2"/>
+                <sheet name="Sheet3" sheetId="3" r:id="rId3"/>
+              </sheets>
+              <calcPr calcId="124519" fullCalcOnLoad="1"/>
+              <customWorkbookViews>
+                <customWorkbookView name="Print" guid="{}" windowWidth="16095" windowHeight="9660" activeSheetId="2"/>
+                <customWorkbookView name="Edit" guid="{}" windowWidth="16095" windowHeight="9660" activeSheetId="3"/>
+              </customWorkbookViews>
+            </workbook>
+            "#,
+            view2.guid(),
+            view1.guid()
+        ));
+
+        assert_eq!(expected, got);
+    }
+
     #[test]
     fn define_name() {
         let mut workbook = Workbook::default();
@@ -59,6 +142,124 @@ mod workbook_tests {
         }
     }
 
+    #[test]
+    fn define_name_with_shifted_rows() {
+        let mut workbook = Workbook::default();
+        let _ = workbook.add_worksheet().set_name("Data").unwrap();
+        let _ = workbook.add_worksheet().set_name("Other").unwrap();
+
+        workbook.define_name("Sales", "=Data!$A$5:$A$9").unwrap();
+        workbook.define_name("Other!Rate", "=Other!$B$2").unwrap();
+        workbook.define_name("Header", "=Data!$A$1").unwrap();
+
+        // Add a chart with a range that is deleted.
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Data!$A$1");
+
+        let worksheet = workbook.worksheet_from_name("Data").unwrap();
+        worksheet.write_number(0, 0, 1).unwrap();
+        worksheet.insert_chart(12, 0, &chart).unwrap();
+        worksheet.insert_rows(2, 3).unwrap();
+        worksheet.delete_rows(0, 1).unwrap();
+
+        // Names defined after the shifts aren't adjusted.
+        workbook.define_name("Total", "=Data!$A$10").unwrap();
+
+        workbook.save_to_buffer().unwrap();
+
+        let ranges: Vec<&str> = workbook
+            .user_defined_names
+            .iter()
+            .map(|defined_name| defined_name.range.as_str())
+            .collect();
+
+        assert_eq!(
+            ranges,
+            vec!["Data!$A$7:$A$11", "Other!$B$2", "Data!#REF!", "Data!$A$10"]
+        );
+
+        // Check that the names aren't adjusted again in a second save.
+        workbook.save_to_buffer().unwrap();
+        assert_eq!(workbook.user_defined_names[0].range, "Data!$A$7:$A$11");
+    }
+
+    #[test]
+    fn shift_references_in_other_worksheets() {
+        let mut workbook = Workbook::default();
+        let _ = workbook.add_worksheet().set_name("Data").unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_formula(0, 0, "=SUM(Data!A1:A3)").unwrap();
+
+        let worksheet = workbook.worksheet_from_name("Data").unwrap();
+        worksheet.insert_rows(0, 2).unwrap();
+
+        // Formulas written after the shift aren't adjusted.
+        let worksheet = workbook.worksheet_from_name("Sheet2").unwrap();
+        worksheet.write_formula(2, 0, "=Data!A1").unwrap();
+
+        // Check that the references aren't adjusted again in a second save.
+        for _ in 0..2 {
+            workbook.save_to_buffer().unwrap();
+
+            let worksheet = workbook.worksheet_from_name("Sheet2").unwrap();
+            assert_eq!(
+                worksheet.cell_value(0, 0),
+                Some(CellValue::Formula {
+                    formula: "SUM(Data!A3:A5)".to_string(),
+                    result: "0".to_string()
+                })
+            );
+            assert_eq!(
+                worksheet.cell_value(2, 0),
+                Some(CellValue::Formula {
+                    formula: "Data!A1".to_string(),
+                    result: "0".to_string()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn move_range_references() {
+        let mut workbook = Workbook::default();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_column(0, 0, [10, 20, 30]).unwrap();
+        worksheet.write_formula(4, 0, "=SUM(A1:A3)").unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_formula(0, 0, "=Sheet1!A2").unwrap();
+
+        workbook.define_name("Sales", "=Sheet1!$A$1:$A$3").unwrap();
+
+        // Move the data. The formulas and the name are adjusted.
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+        worksheet.move_range(0, 0, 2, 0, 0, 2).unwrap();
+
+        workbook.save_to_buffer().unwrap();
+
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+        assert_eq!(
+            worksheet.cell_value(4, 0),
+            Some(CellValue::Formula {
+                formula: "SUM(C1:C3)".to_string(),
+                result: "0".to_string()
+            })
+        );
+
+        let worksheet = workbook.worksheet_from_index(1).unwrap();
+        assert_eq!(
+            worksheet.cell_value(0, 0),
+            Some(CellValue::Formula {
+                formula: "Sheet1!C2".to_string(),
+                result: "0".to_string()
+            })
+        );
+
+        assert_eq!(workbook.user_defined_names[0].range, "Sheet1!$C$1:$C$3");
+    }
+
     #[test]
     fn duplicate_worksheets() {
         let mut workbook = Workbook::default();
@@ -97,4 +298,361 @@ mod workbook_tests {
 
         assert!(matches!(result, Err(XlsxError::TableNameReused(_))));
     }
+
+    #[test]
+    fn vml_shape_id_blocks() {
+        let mut workbook = Workbook::default();
+        let note = Note::new("Note");
+
+        // A worksheet with a full block of notes.
+        let worksheet = workbook.add_worksheet();
+        for row in 0..1024 {
+            worksheet.insert_note(row, 0, &note).unwrap();
+        }
+
+        // A worksheet with a single button.
+        let worksheet = workbook.add_worksheet();
+        worksheet.insert_button(0, 0, &Button::new()).unwrap();
+
+        // A worksheet where the notes and buttons fill a block.
+        let worksheet = workbook.add_worksheet();
+        for row in 0..1023 {
+            worksheet.insert_note(row, 0, &note).unwrap();
+        }
+        worksheet.insert_button(0, 2, &Button::new()).unwrap();
+
+        // A worksheet with a single note.
+        let worksheet = workbook.add_worksheet();
+        worksheet.insert_note(0, 0, &note).unwrap();
+
+        workbook.prepare_vml();
+
+        let ids: Vec<(&str, u32)> = workbook
+            .worksheets
+            .iter()
+            .map(|worksheet| (worksheet.vml_data_id.as_str(), worksheet.vml_shape_id))
+            .collect();
+
+        assert_eq!(
+            ids,
+            vec![("1,2", 1024), ("3", 3072), ("4,5", 4096), ("6", 6144)]
+        );
+    }
+
+    #[test]
+    fn move_worksheet_with_3d_references() {
+        let mut workbook = Workbook::default();
+
+        for name in ["North", "South", "East", "West", "Notes"] {
+            let _ = workbook.add_worksheet().set_name(name).unwrap();
+        }
+
+        let worksheet = workbook.add_worksheet().set_name("Summary").unwrap();
+        worksheet
+            .write_formula(0, 0, "=SUM(North:East!A1)")
+            .unwrap();
+
+        workbook.define_name("Total", "='South:West'!$A$1").unwrap();
+
+        // Moves outside the spans are allowed.
+        workbook.move_worksheet(5, 0).unwrap();
+        workbook.move_worksheet(5, 1).unwrap();
+
+        let names: Vec<&str> = workbook
+            .worksheets
+            .iter()
+            .map(|worksheet| worksheet.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["Summary", "Notes", "North", "South", "East", "West"]
+        );
+
+        // Moving a worksheet into or out of a span, or moving the end of a
+        // span, isn't allowed.
+        for (from, to) in [(1, 3), (3, 0), (5, 2), (2, 5)] {
+            let result = workbook.move_worksheet(from, to);
+            assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        }
+    }
+
+    #[test]
+    fn duplicate_move_and_remove_worksheet() {
+        let mut workbook = Workbook::default();
+
+        let worksheet = workbook.add_worksheet().set_name("Template").unwrap();
+        worksheet.write_column(0, 0, [1, 2, 3]).unwrap();
+        worksheet
+            .add_table(4, 0, 6, 1, &Table::new().set_name("Sales"))
+            .unwrap();
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Template!$A$1:$A$3");
+        worksheet.insert_chart(8, 0, &chart).unwrap();
+
+        worksheet.set_default_note_author("Sales");
+        worksheet.set_vba_name("Template1").unwrap();
+
+        let worksheet = workbook.add_worksheet().set_name("Other").unwrap();
+        worksheet.write_formula(0, 0, "=Template!A1+A2").unwrap();
+        worksheet.insert_chart(8, 0, &chart).unwrap();
+
+        workbook
+            .define_name("Template!Rate", "=Template!$B$1")
+            .unwrap();
+        workbook.define_name("Total", "=Template!$A$1").unwrap();
+
+        // Duplicate the worksheet.
+        let worksheet = workbook.duplicate_worksheet("template", "Copy").unwrap();
+        assert_eq!(worksheet.name(), "Copy");
+        assert_eq!(worksheet.tables[0].name, "Sales2");
+        assert!(worksheet
+            .charts
+            .values()
+            .all(|chart| chart.has_sheet_ranges("Copy")));
+        assert_eq!(workbook.user_defined_names.len(), 3);
+        assert_eq!(workbook.user_defined_names[2].range, "Copy!$B$1");
+        assert_eq!(workbook.worksheets[2].note_authors.get("Sales"), Some(&0));
+        assert_eq!(
+            workbook.worksheets[2].vba_codename,
+            Some("Template2".to_string())
+        );
+
+        let result = workbook.duplicate_worksheet("Template", "copy");
+        assert!(matches!(result, Err(XlsxError::SheetnameReused(_))));
+
+        let result = workbook.duplicate_worksheet("Missing", "Copy2");
+        assert!(matches!(
+            result,
+            Err(XlsxError::UnknownWorksheetNameOrIndex(_))
+        ));
+
+        // Move the worksheet.
+        workbook.move_worksheet(2, 0).unwrap();
+        let names: Vec<String> = workbook.worksheets.iter().map(|ws| ws.name()).collect();
+        assert_eq!(names, vec!["Copy", "Template", "Other"]);
+
+        let result = workbook.move_worksheet(0, 3);
+        assert!(matches!(
+            result,
+            Err(XlsxError::UnknownWorksheetNameOrIndex(_))
+        ));
+
+        // Remove the worksheet.
+        let result = workbook.remove_worksheet("Template");
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+
+        workbook.worksheets[2].charts.clear();
+        let worksheet = workbook.remove_worksheet("Template").unwrap();
+        assert_eq!(worksheet.name(), "Template");
+
+        let names: Vec<String> = workbook.worksheets.iter().map(|ws| ws.name()).collect();
+        assert_eq!(names, vec!["Copy", "Other"]);
+        assert_eq!(workbook.user_defined_names.len(), 2);
+        assert_eq!(workbook.user_defined_names[0].range, "#REF!$A$1");
+        assert_eq!(
+            workbook.worksheets[1].cell_value(0, 0),
+            Some(CellValue::Formula {
+                formula: "#REF!A1+A2".to_string(),
+                result: "0".to_string()
+            })
+        );
+
+        workbook.save_to_buffer().unwrap();
+    }
+
+    #[test]
+    fn duplicate_worksheet_is_independent() {
+        let mut workbook = Workbook::default();
+
+        let worksheet = workbook.add_worksheet().set_name("Template").unwrap();
+        worksheet.write(0, 0, "Region").unwrap();
+        worksheet.set_active(true);
+
+        // Save once so that the per-save state is populated.
+        workbook.save_to_buffer().unwrap();
+
+        let worksheet = workbook.duplicate_worksheet("Template", "Copy").unwrap();
+        worksheet.write(0, 0, "Total").unwrap();
+        worksheet.write(1, 0, "North").unwrap();
+
+        let original = &workbook.worksheets[0];
+        let copy = &workbook.worksheets[1];
+
+        assert_eq!(
+            original.cell_value(0, 0),
+            Some(CellValue::String("Region".to_string()))
+        );
+        assert_eq!(original.cell_value(1, 0), None);
+        assert_eq!(
+            copy.cell_value(0, 0),
+            Some(CellValue::String("Total".to_string()))
+        );
+
+        // The copy doesn't share the state of the original worksheet.
+        assert!(!std::sync::Arc::ptr_eq(
+            &original.string_table,
+            &copy.string_table
+        ));
+        assert!(!std::sync::Arc::ptr_eq(
+            &original.workbook_xf_indices,
+            &copy.workbook_xf_indices
+        ));
+        assert!(!copy.active);
+        assert!(copy.writer.get_ref().is_empty());
+
+        workbook.save_to_buffer().unwrap();
+    }
+
+    #[test]
+    fn add_cell_style_errors() {
+        let mut workbook = Workbook::default();
+        let format = Format::new().set_bold();
+
+        let result = workbook.add_cell_style(&CellStyle::new("", &format));
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.add_cell_style(&CellStyle::new("normal", &format));
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.add_cell_style(&CellStyle::new("Style 1", &format));
+        assert!(result.is_ok());
+
+        // Adding a style with the same name again isn't allowed.
+        let result = workbook.add_cell_style(&CellStyle::new("Style 1", &format));
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.add_cell_style(&CellStyle::new("style 1", &Format::new()));
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // Formats can't use different styles with the same name.
+        let format1 = Format::new().set_cell_style(&CellStyle::new("Style 2", &format));
+        let format2 = Format::new().set_cell_style(&CellStyle::new("Style 2", &Format::new()));
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_with_format(0, 0, "A", &format1).unwrap();
+        worksheet.write_with_format(1, 0, "B", &format2).unwrap();
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn cell_style_indices() {
+        let mut workbook = Workbook::default();
+
+        let house_style = CellStyle::new("House Style", &Format::new().set_bold());
+        workbook.add_cell_style(&house_style).unwrap();
+
+        let good = CellStyle::new_builtin(BuiltinCellStyle::Good);
+        let format = Format::new().set_cell_style(&good).set_italic();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_with_format(0, 0, "Good", &format).unwrap();
+
+        workbook.save_to_buffer().unwrap();
+
+        // The registered styles come before the styles used by formats.
+        assert_eq!(2, workbook.cell_style_xfs.len());
+        assert_eq!("House Style", workbook.cell_style_xfs[0].name);
+        assert_eq!("Good", workbook.cell_style_xfs[1].name);
+
+        assert_eq!(2, workbook.xf_formats.len());
+        assert_eq!(0, workbook.xf_formats[0].style_xf_id);
+        assert_eq!(2, workbook.xf_formats[1].style_xf_id);
+
+        assert_eq!(4, workbook.font_count);
+        assert_eq!(3, workbook.fill_count);
+        assert_eq!(2, workbook.cell_style_xfs[0].format.font_index);
+        assert_eq!(3, workbook.cell_style_xfs[1].format.font_index);
+        assert_eq!(2, workbook.cell_style_xfs[1].format.fill_index);
+    }
+
+    #[test]
+    fn add_table_style_errors() {
+        let mut workbook = Workbook::default();
+
+        let result = workbook.add_table_style(&CustomTableStyle::new(" "));
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.add_table_style(&CustomTableStyle::new("TableStyleMedium9"));
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.add_table_style(&CustomTableStyle::new("pivotstylelight16"));
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // Names that only look similar to the built-in names are allowed.
+        let result = workbook.add_table_style(&CustomTableStyle::new("TableStyleMedium"));
+        assert!(result.is_ok());
+
+        let style = CustomTableStyle::new("Style 1")
+            .set_format(TableStyleElement::HeaderRow, &Format::new().set_bold());
+        let result = workbook.add_table_style(&style);
+        assert!(result.is_ok());
+
+        // Adding the same style again is allowed.
+        let result = workbook.add_table_style(&style);
+        assert!(result.is_ok());
+
+        // A different style with the same name isn't allowed.
+        let result = workbook.add_table_style(&CustomTableStyle::new("style 1"));
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // Or a table using a different style with the same name.
+        let table = Table::new().set_custom_style(&CustomTableStyle::new("Style 1"));
+        let worksheet = workbook.add_worksheet();
+        worksheet.add_table(0, 0, 2, 2, &table).unwrap();
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn gradient_fill_indices() {
+        let mut workbook = Workbook::default();
+
+        let gradient = FormatGradient::new()
+            .add_stop(0.0, "#FFFFFF")
+            .add_stop(1.0, "#4472C4");
+
+        // A gradient fill replaces a background color and isn't turned into a
+        // solid fill. Repeated gradients share a fill.
+        let format1 = Format::new().set_gradient_fill(&gradient);
+        let format2 = Format::new()
+            .set_background_color("FF0000")
+            .set_gradient_fill(&gradient);
+        let format3 = Format::new().set_gradient_fill(&gradient).set_bold();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_with_format(0, 0, 1, &format1).unwrap();
+        worksheet.write_with_format(1, 0, 2, &format2).unwrap();
+        worksheet.write_with_format(2, 0, 3, &format3).unwrap();
+
+        workbook.save_to_buffer().unwrap();
+
+        assert_eq!(3, workbook.fill_count);
+        for xf_format in &workbook.xf_formats[1..] {
+            assert_eq!(2, xf_format.fill_index);
+            assert_eq!(FormatPattern::None, xf_format.fill.pattern);
+        }
+    }
+
+    #[test]
+    fn theme_font() {
+        let mut workbook = Workbook::new();
+        workbook.add_worksheet();
+        workbook.set_theme(&WorkbookTheme::new_aptos());
+        workbook.add_worksheet();
+        workbook.push_worksheet(Worksheet::new());
+
+        for worksheet in &workbook.worksheets {
+            assert_eq!("Aptos Narrow", worksheet.theme_font.name);
+        }
+
+        workbook.set_theme(&WorkbookTheme::new());
+
+        for worksheet in &workbook.worksheets {
+            assert_eq!("Calibri", worksheet.theme_font.name);
+        }
+    }
 }
@@ -0,0 +1,220 @@
+// workbook_view - A module for representing the Excel workbook window view.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+// The window position and size are stored in twips, 1/15 of a pixel, as a
+// signed 32 bit integer in the file.
+const MAX_WINDOW_PIXELS: u32 = i32::MAX as u32 / 15;
+
+/// The `WorkbookView` struct represents the properties of the Excel application
+/// window that a workbook is displayed in.
+///
+/// `WorkbookView` can be used to set the position and size of the workbook
+/// window and to turn on/off elements of the Excel user interface such as the
+/// worksheet tabs and the scroll bars. This can be useful, for example, to
+/// create a dashboard workbook with a clean and simple user interface.
+///
+/// The view is added to a workbook with
+/// [`Workbook::set_workbook_view()`](crate::Workbook::set_workbook_view).
+///
+/// # Examples
+///
+/// The following example demonstrates setting the workbook window view
+/// properties to hide the worksheet tabs and scroll bars.
+///
+/// ```
+/// # // This code is available in examples/doc_workbook_view.rs
+/// #
+/// # use rust_xlsxwriter::{Workbook, WorkbookView, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #
+///     // Create a view without sheet tabs or scroll bars.
+///     let view = WorkbookView::new()
+///         .set_window_size(800, 600)
+///         .set_sheet_tabs(false)
+///         .set_horizontal_scroll_bar(false)
+///         .set_vertical_scroll_bar(false);
+///
+///     workbook.set_workbook_view(&view);
+///
+///     let worksheet = workbook.add_worksheet();
+///     worksheet.write(0, 0, "Dashboard")?;
+/// #
+/// #     workbook.save("workbook.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkbookView {
+    pub(crate) x_window: u32,
+    pub(crate) y_window: u32,
+    pub(crate) window_width: u32,
+    pub(crate) window_height: u32,
+    pub(crate) minimized: bool,
+    pub(crate) tab_ratio: u16,
+    pub(crate) sheet_tabs: bool,
+    pub(crate) horizontal_scroll_bar: bool,
+    pub(crate) vertical_scroll_bar: bool,
+    pub(crate) autofilter_date_grouping: bool,
+}
+
+impl Default for WorkbookView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorkbookView {
+    /// Create a new `WorkbookView` object with the default Excel window
+    /// properties.
+    ///
+    pub fn new() -> WorkbookView {
+        WorkbookView {
+            x_window: 240,
+            y_window: 15,
+            window_width: 16095,
+            window_height: 9660,
+            minimized: false,
+            tab_ratio: 600,
+            sheet_tabs: true,
+            horizontal_scroll_bar: true,
+            vertical_scroll_bar: true,
+            autofilter_date_grouping: true,
+        }
+    }
+
+    /// Set the position of the workbook window.
+    ///
+    /// The position of the top left corner of the window is set in pixels
+    /// relative to the Excel application window.
+    ///
+    /// # Parameters
+    ///
+    /// - `x`: The horizontal position in pixels.
+    /// - `y`: The vertical position in pixels.
+    ///
+    /// Positions greater than 143,165,576 pixels, Excel's internal limit, are
+    /// ignored.
+    ///
+    pub fn set_window_position(mut self, x: u32, y: u32) -> WorkbookView {
+        if x > MAX_WINDOW_PIXELS || y > MAX_WINDOW_PIXELS {
+            eprintln!(
+                "Window position ({x}, {y}) outside Excel range: 0 <= x, y <= {MAX_WINDOW_PIXELS}."
+            );
+            return self;
+        }
+
+        self.x_window = x * 15;
+        self.y_window = y * 15;
+        self
+    }
+
+    /// Set the size of the workbook window.
+    ///
+    /// The default size is approximately 1073 x 644 pixels.
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: The window width in pixels.
+    /// - `height`: The window height in pixels.
+    ///
+    /// Sizes greater than 143,165,576 pixels, Excel's internal limit, are
+    /// ignored.
+    ///
+    pub fn set_window_size(mut self, width: u32, height: u32) -> WorkbookView {
+        if width > MAX_WINDOW_PIXELS || height > MAX_WINDOW_PIXELS {
+            eprintln!(
+                "Window size ({width}, {height}) outside Excel range: 0 <= width, height <= {MAX_WINDOW_PIXELS}."
+            );
+            return self;
+        }
+
+        self.window_width = width * 15;
+        self.window_height = height * 15;
+        self
+    }
+
+    /// Set the workbook window to be minimized when the file is opened.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_minimized(mut self, enable: bool) -> WorkbookView {
+        self.minimized = enable;
+        self
+    }
+
+    /// Set the ratio of the width of the worksheet tabs area to the width of
+    /// the horizontal scroll bar.
+    ///
+    /// # Parameters
+    ///
+    /// - `ratio`: The tab ratio in thousandths, in the range 0 <= ratio <=
+    ///   1000. The Excel default is 600.
+    ///
+    pub fn set_tab_ratio(mut self, ratio: u16) -> WorkbookView {
+        if ratio > 1000 {
+            eprintln!("Tab ratio {ratio} outside Excel range: 0 <= ratio <= 1000.");
+            return self;
+        }
+
+        self.tab_ratio = ratio;
+        self
+    }
+
+    /// Turn on/off the worksheet tabs.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_sheet_tabs(mut self, enable: bool) -> WorkbookView {
+        self.sheet_tabs = enable;
+        self
+    }
+
+    /// Turn on/off the horizontal scroll bar.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_horizontal_scroll_bar(mut self, enable: bool) -> WorkbookView {
+        self.horizontal_scroll_bar = enable;
+        self
+    }
+
+    /// Turn on/off the vertical scroll bar.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_vertical_scroll_bar(mut self, enable: bool) -> WorkbookView {
+        self.vertical_scroll_bar = enable;
+        self
+    }
+
+    /// Turn on/off the grouping of dates in the autofilter menus.
+    ///
+    /// By default Excel groups the dates in an autofilter list by year, month
+    /// and day. Turning this option off displays each date as a separate item.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_autofilter_date_grouping(mut self, enable: bool) -> WorkbookView {
+        self.autofilter_date_grouping = enable;
+        self
+    }
+}
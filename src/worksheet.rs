@@ -1382,9 +1382,11 @@ use crate::{
 
 use crate::drawing::{Drawing, DrawingCoordinates, DrawingInfo, DrawingObject, DrawingType};
 use crate::error::XlsxError;
-use crate::format::Format;
+use crate::font_metrics::{AutofitFont, ThemeFont};
+use crate::format::{Font, Format};
 use crate::formula::Formula;
 use crate::shared_strings_table::SharedStringsTable;
+use crate::shift::{self, RangeMove, ReferenceChange, ReferenceShift, ShiftAxis};
 use crate::styles::Styles;
 use crate::vml::VmlInfo;
 use crate::xmlwriter::{
@@ -1393,10 +1395,12 @@ use crate::xmlwriter::{
 };
 use crate::{
     utility, xmlwriter, Button, Chart, ChartEmptyCells, ChartRangeCacheData,
-    ChartRangeCacheDataType, Color, ConditionalFormat, DataValidation, DataValidationErrorStyle,
-    DataValidationRuleInternal, DataValidationType, ExcelDateTime, FilterCondition, FilterCriteria,
-    FilterData, FilterDataType, HeaderImagePosition, HyperlinkType, Image, IntoExcelDateTime, Note,
-    ObjectMovement, ProtectionOptions, Shape, Sparkline, SparklineType, Table, TableFunction, Url,
+    ChartRangeCacheDataType, Color, ConditionalFormat, CustomView, CustomViewType, DataValidation,
+    DataValidationErrorStyle, DataValidationRuleInternal, DataValidationType, ExcelDateTime,
+    ExcelError, FilterCondition, FilterCriteria, FilterData, FilterDataType, FontMetrics,
+    HeaderImagePosition, HyperlinkType, Image, IntoExcelDateTime, IntoFormulaResult, Note,
+    ObjectMovement, OleObject, PasteOptions, PasteType, PhoneticProperties, PhoneticString,
+    ProtectionOptions, Shape, Sparkline, SparklineType, Table, TableFunction, Url,
 };
 
 /// Integer type to represent a zero indexed row number. Excel's limit for rows
@@ -1498,6 +1502,7 @@ pub struct Worksheet {
     pub(crate) first_sheet: bool,
     pub(crate) has_local_string_table: bool,
     pub(crate) has_dynamic_arrays: bool,
+    pub(crate) has_rich_value_errors: bool,
     pub(crate) print_area_defined_name: DefinedName,
     pub(crate) repeat_row_cols_defined_name: DefinedName,
     pub(crate) autofilter_defined_name: DefinedName,
@@ -1509,18 +1514,22 @@ pub struct Worksheet {
     pub(crate) images: BTreeMap<(RowNum, ColNum, u32, u32), Image>,
     pub(crate) buttons_vml_info: Vec<VmlInfo>,
     pub(crate) comments_vml_info: Vec<VmlInfo>,
+    pub(crate) ole_objects_vml_info: Vec<VmlInfo>,
     pub(crate) drawing: Drawing,
     pub(crate) image_types: [bool; NUM_IMAGE_FORMATS],
-    pub(crate) header_footer_images: [Option<Image>; 6],
+    pub(crate) header_footer_images: [Option<Image>; 18],
     pub(crate) charts: BTreeMap<(RowNum, ColNum, u32, u32), Chart>,
     pub(crate) buttons: BTreeMap<(RowNum, ColNum, u32, u32), Button>,
     pub(crate) notes: BTreeMap<RowNum, BTreeMap<ColNum, Note>>,
     pub(crate) shapes: BTreeMap<(RowNum, ColNum, u32, u32), Shape>,
+    pub(crate) ole_objects: BTreeMap<(RowNum, ColNum, u32, u32), OleObject>,
     pub(crate) tables: Vec<Table>,
+    pub(crate) custom_views: Vec<CustomView>,
     pub(crate) has_embedded_image_descriptions: bool,
     pub(crate) embedded_images: Vec<Image>,
     pub(crate) global_embedded_image_indices: Vec<u32>,
     pub(crate) vba_codename: Option<String>,
+    pub(crate) reference_changes: Vec<(String, ReferenceChange)>,
     pub(crate) note_authors: BTreeMap<String, usize>,
     pub(crate) vml_data_id: String,
     pub(crate) vml_shape_id: u32,
@@ -1545,6 +1554,8 @@ pub struct Worksheet {
     pub(crate) table_relationships: Vec<(String, String, String)>,
     pub(crate) vml_drawing_relationships: Vec<(String, String, String)>,
     pub(crate) background_relationships: Vec<(String, String, String)>,
+    pub(crate) ole_object_relationships: Vec<(String, String, String)>,
+    pub(crate) vml_object_relationships: Vec<(String, String, String)>,
 
     data_table: BTreeMap<RowNum, BTreeMap<ColNum, CellType>>,
     is_writing_ahead: bool,
@@ -1576,12 +1587,22 @@ pub struct Worksheet {
     center_horizontally: bool,
     center_vertically: bool,
     screen_gridlines: bool,
+    screen_gridlines_color: Color,
+    screen_headings: bool,
+    show_formulas: bool,
+    show_zero_values: bool,
+    show_outline_symbols: bool,
+    page_layout_ruler: bool,
     print_gridlines: bool,
     print_black_and_white: bool,
     print_draft: bool,
     print_headings: bool,
     header: String,
     footer: String,
+    header_even: String,
+    footer_even: String,
+    header_first: String,
+    footer_first: String,
     head_footer_changed: bool,
     header_footer_scale_with_doc: bool,
     header_footer_align_with_page: bool,
@@ -1601,6 +1622,7 @@ pub struct Worksheet {
     protection_options: ProtectionOptions,
     unprotected_ranges: Vec<(String, String, u16)>,
     selected_range: (String, String),
+    pane_selections: HashMap<PanePosition, (String, String)>,
     top_left_cell: String,
     horizontal_breaks: Vec<u32>,
     vertical_breaks: Vec<u32>,
@@ -1619,6 +1641,17 @@ pub struct Worksheet {
     embedded_image_ids: HashMap<String, u32>,
     show_all_notes: bool,
     user_default_row_height: f64,
+    font_metrics: HashMap<String, FontMetrics>,
+    pub(crate) theme_font: ThemeFont,
+    cascading_formats: bool,
+    cascading_layers: Vec<Format>,
+    cascading_layer_indices: HashMap<Format, u32>,
+    cascading_cell_formats: HashMap<(RowNum, ColNum), u32>,
+    cascading_range_formats: HashMap<(RowNum, ColNum), u32>,
+    phonetic_properties: Option<PhoneticProperties>,
+    phonetic_font_xf_index: Option<u32>,
+    phonetic_font_id: u16,
+    has_phonetic_strings: bool,
     hide_unused_rows: bool,
     has_sheet_data: bool,
     nan: String,
@@ -1727,6 +1760,7 @@ impl Worksheet {
         let panes = Panes {
             freeze_cell: (0, 0),
             top_cell: (0, 0),
+            split_size: (0, 0),
         };
 
         #[cfg(feature = "constant_memory")]
@@ -1743,6 +1777,7 @@ impl Worksheet {
             has_local_string_table: false,
             has_vml: false,
             has_dynamic_arrays: false,
+            has_rich_value_errors: false,
             print_area_defined_name: DefinedName::new(),
             repeat_row_cols_defined_name: DefinedName::new(),
             autofilter_defined_name: DefinedName::new(),
@@ -1779,12 +1814,23 @@ impl Worksheet {
             center_horizontally: false,
             center_vertically: false,
             screen_gridlines: true,
+            screen_gridlines_color: Color::Default,
+            screen_headings: true,
+            show_formulas: false,
+            show_zero_values: true,
+            show_outline_symbols: true,
+            page_layout_ruler: true,
+            custom_views: vec![],
             print_gridlines: false,
             print_black_and_white: false,
             print_draft: false,
             print_headings: false,
             header: String::new(),
             footer: String::new(),
+            header_even: String::new(),
+            footer_even: String::new(),
+            header_first: String::new(),
+            footer_first: String::new(),
             head_footer_changed: false,
             header_footer_scale_with_doc: true,
             header_footer_align_with_page: true,
@@ -1801,17 +1847,20 @@ impl Worksheet {
             hyperlinks: BTreeMap::new(),
             images: BTreeMap::new(),
             shapes: BTreeMap::new(),
+            ole_objects: BTreeMap::new(),
             drawing: Drawing::new(),
             image_types: [false; NUM_IMAGE_FORMATS],
-            header_footer_images: [None, None, None, None, None, None],
+            header_footer_images: Default::default(),
             buttons_vml_info: vec![],
             comments_vml_info: vec![],
+            ole_objects_vml_info: vec![],
             rel_count: 0,
             protection_on: false,
             protection_hash: 0,
             protection_options: ProtectionOptions::new(),
             unprotected_ranges: vec![],
             selected_range: (String::new(), String::new()),
+            pane_selections: HashMap::new(),
             top_left_cell: String::new(),
             horizontal_breaks: vec![],
             vertical_breaks: vec![],
@@ -1835,11 +1884,23 @@ impl Worksheet {
             has_sparklines: false,
             sparklines: vec![],
             vba_codename: None,
+            reference_changes: vec![],
             note_authors: BTreeMap::from([("Author".to_string(), 0)]),
             show_all_notes: false,
             vml_data_id: String::new(),
             vml_shape_id: 0,
             user_default_row_height: DEFAULT_ROW_HEIGHT,
+            font_metrics: HashMap::new(),
+            theme_font: ThemeFont::default(),
+            cascading_formats: false,
+            cascading_layers: vec![],
+            cascading_layer_indices: HashMap::new(),
+            cascading_cell_formats: HashMap::new(),
+            cascading_range_formats: HashMap::new(),
+            phonetic_properties: None,
+            phonetic_font_xf_index: None,
+            phonetic_font_id: 0,
+            has_phonetic_strings: false,
             hide_unused_rows: false,
             nan: "NAN".to_string(),
             infinity: "INF".to_string(),
@@ -1862,6 +1923,8 @@ impl Worksheet {
             table_relationships: vec![],
             vml_drawing_relationships: vec![],
             background_relationships: vec![],
+            ole_object_relationships: vec![],
+            vml_object_relationships: vec![],
             is_chartsheet: false,
             use_constant_memory: false,
             use_inline_strings: false,
@@ -3123,9 +3186,9 @@ impl Worksheet {
         col: ColNum,
         rich_string: &[(&Format, &str)],
     ) -> Result<&mut Worksheet, XlsxError> {
-        let (string, raw_string) = Self::get_rich_string(rich_string)?;
+        let (string, raw_string) = self.get_rich_string(rich_string)?;
 
-        self.store_rich_string(row, col, &string, &raw_string, None)
+        self.store_rich_string(row, col, &string, &raw_string, None, None)
     }
 
     /// Write a "rich" string with multiple formats to a worksheet cell, with an
@@ -3224,9 +3287,213 @@ impl Worksheet {
         rich_string: &[(&Format, &str)],
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
-        let (string, raw_string) = Self::get_rich_string(rich_string)?;
+        let (string, raw_string) = self.get_rich_string(rich_string)?;
 
-        self.store_rich_string(row, col, &string, &raw_string, Some(format))
+        self.store_rich_string(row, col, &string, &raw_string, Some(format), None)
+    }
+
+    /// Write a string with phonetic guides to a worksheet cell.
+    ///
+    /// The `write_phonetic_string()` method is used to write strings with
+    /// phonetic guides, also known as "furigana", such as the pronunciation
+    /// of Japanese Kanji characters. See [`PhoneticString`] for details on how
+    /// to create the string and its phonetic runs.
+    ///
+    /// The font, character type and alignment of the phonetic text are set
+    /// for the worksheet with [`Worksheet::set_phonetic_properties()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `string`: The [`PhoneticString`] to write to the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - The following error cases will raise a
+    ///   `ParameterError` error:
+    ///   * If a phonetic run is out of order, overlaps a previous run or is
+    ///     outside the characters of the base string.
+    ///   * If the phonetic text of a run is empty.
+    ///   * If the string is a rich string with an empty segment, as for
+    ///     [`Worksheet::write_rich_string()`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing strings with phonetic guides
+    /// to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_phonetic_string.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, PhoneticString, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write a string with phonetic guides that are shown in the cell.
+    ///     let string = PhoneticString::new("東京都")
+    ///         .add_run(0, 2, "トウキョウ")
+    ///         .add_run(2, 3, "ト")
+    ///         .set_visible(true);
+    ///
+    ///     worksheet.write_phonetic_string(0, 0, &string)?;
+    ///
+    ///     // Write a rich string with phonetic guides.
+    ///     let default = Format::default();
+    ///     let bold = Format::new().set_bold();
+    ///     let string = PhoneticString::new_rich(&[(&bold, "山田"), (&default, "太郎")])
+    ///         .add_run(0, 2, "ヤマダ")
+    ///         .add_run(2, 4, "タロウ")
+    ///         .set_visible(true);
+    ///
+    ///     worksheet.write_phonetic_string(1, 0, &string)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_phonetic_string(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        string: &PhoneticString,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let (xml_string, raw_string) = self.get_phonetic_string(string)?;
+
+        self.store_rich_string(row, col, &xml_string, &raw_string, None, Some(string))
+    }
+
+    /// Write a string with phonetic guides to a worksheet cell, with
+    /// formatting.
+    ///
+    /// The `write_phonetic_string_with_format()` method is the same as
+    /// [`Worksheet::write_phonetic_string()`] except that it also applies a
+    /// [`Format`] to the cell.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `string`: The [`PhoneticString`] to write to the cell.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - If the phonetic runs or rich string
+    ///   segments are invalid. See [`Worksheet::write_phonetic_string()`].
+    ///
+    pub fn write_phonetic_string_with_format(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        string: &PhoneticString,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let (xml_string, raw_string) = self.get_phonetic_string(string)?;
+
+        self.store_rich_string(
+            row,
+            col,
+            &xml_string,
+            &raw_string,
+            Some(format),
+            Some(string),
+        )
+    }
+
+    /// Set the phonetic properties for the worksheet.
+    ///
+    /// The `set_phonetic_properties()` method sets the font, character type
+    /// and alignment of the phonetic text of strings written with
+    /// [`Worksheet::write_phonetic_string()`]. See [`PhoneticProperties`] for
+    /// the available options.
+    ///
+    /// Note, for worksheets in "constant memory" or "low memory" mode the
+    /// phonetic properties are added to each phonetic string when it is
+    /// written. Properties that are set later don't apply to the strings that
+    /// have already been written, so they should be set before any phonetic
+    /// strings are written.
+    ///
+    /// # Parameters
+    ///
+    /// - `properties`: A [`PhoneticProperties`] object.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the phonetic properties of a
+    /// worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_phonetic_properties.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     Format, PhoneticAlignment, PhoneticProperties, PhoneticString, PhoneticType, Workbook,
+    /// #     XlsxError,
+    /// # };
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Show the phonetic text as centered Hiragana in a smaller font.
+    ///     let font = Format::new().set_font_size(8);
+    ///     let properties = PhoneticProperties::new()
+    ///         .set_font(&font)
+    ///         .set_type(PhoneticType::Hiragana)
+    ///         .set_alignment(PhoneticAlignment::Center);
+    ///
+    ///     worksheet.set_phonetic_properties(&properties);
+    ///
+    ///     let string = PhoneticString::new("漢字")
+    ///         .add_run(0, 2, "かんじ")
+    ///         .set_visible(true);
+    ///
+    ///     worksheet.write_phonetic_string(0, 0, &string)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_phonetic_properties(&mut self, properties: &PhoneticProperties) -> &mut Worksheet {
+        // Store the font as a cell format so that it is added to the workbook
+        // fonts. The font id is resolved when the workbook is saved, apart from
+        // worksheets that use the workbook formats, where the strings may be
+        // completed before then.
+        self.phonetic_font_xf_index = properties
+            .font
+            .as_ref()
+            .map(|font| self.format_xf_index(font));
+
+        if self.has_workbook_global_xfs {
+            self.phonetic_font_id = self
+                .phonetic_font_xf_index
+                .map_or(0, |xf_index| self.global_font_id(xf_index));
+        }
+
+        self.phonetic_properties = Some(properties.clone());
+        self
     }
 
     /// Write an unformatted formula to a worksheet cell.
@@ -4883,7 +5150,9 @@ impl Worksheet {
     /// they act as a single area.
     ///
     /// The `merge_range()` method writes a string to the merged cells. In order
-    /// to write other data types, such as a number or a formula, you can
+    /// to write other data types, such as a number or a formula, you can use
+    /// [`Worksheet::merge_range_with_data()`]. For rich strings you can use
+    /// [`Worksheet::merge_range_with_rich_string()`]. Alternatively, you can
     /// overwrite the first cell with a call to one of the other
     /// `worksheet.write_*()` functions. The same [`Format`] instance should be
     /// used as was used in the merged range, see the example below.
@@ -4971,6 +5240,182 @@ impl Worksheet {
         string: &str,
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_merge_range(first_row, first_col, last_row, last_col)?;
+
+        // Write the first cell in the range.
+        self.write_string_with_format(first_row, first_col, string, format)?;
+
+        self.store_merge_range(first_row, first_col, last_row, last_col, format)
+    }
+
+    /// Merge a range of cells and write any supported data type to it.
+    ///
+    /// The `merge_range_with_data()` method is similar to
+    /// [`Worksheet::merge_range()`] except that it can write any type that
+    /// implements [`IntoExcelData`], such as numbers, dates, formulas and urls,
+    /// to the merged range. The data is written to the first cell in the range
+    /// with the same format as the rest of the range.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `data`: An type that implements the [`IntoExcelData`] trait.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    /// - [`XlsxError::MergeRangeSingleCell`] - A merge range cannot be a single
+    ///   cell in Excel.
+    /// - [`XlsxError::MergeRangeOverlaps`] - The merge range overlaps a
+    ///   previous merge range.
+    /// - Other errors for the data type being written. See
+    ///   [`Worksheet::write()`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates merging ranges of cells that contain
+    /// a number and a formula.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_merge_range_with_data.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, FormatAlign, FormatBorder, Formula, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let format = Format::new()
+    ///         .set_align(FormatAlign::Center)
+    ///         .set_border(FormatBorder::Thin)
+    ///         .set_num_format("#,##0.00");
+    ///
+    ///     // Write a number to a merged range.
+    ///     worksheet.merge_range_with_data(1, 1, 1, 2, 12345.67, &format)?;
+    ///
+    ///     // Write a formula to a merged range.
+    ///     worksheet.merge_range_with_data(3, 1, 3, 2, Formula::new("=B2*2"), &format)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn merge_range_with_data<T>(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        data: T,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: IntoExcelData,
+    {
+        self.check_merge_range(first_row, first_col, last_row, last_col)?;
+
+        // Write the first cell in the range.
+        self.write_with_format(first_row, first_col, data, format)?;
+
+        self.store_merge_range(first_row, first_col, last_row, last_col, format)
+    }
+
+    /// Merge a range of cells and write a "rich" string to it.
+    ///
+    /// The `merge_range_with_rich_string()` method is similar to
+    /// [`Worksheet::merge_range()`] except that it writes a rich string with
+    /// multiple formats to the merged range. See
+    /// [`Worksheet::write_rich_string()`] for details on rich strings. The
+    /// `format` parameter is used as the cell format for the range.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `rich_string`: An array reference of `(&Format, &str)` tuples. See
+    ///   [`Worksheet::write_rich_string()`] for the restrictions.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    /// - [`XlsxError::MergeRangeSingleCell`] - A merge range cannot be a single
+    ///   cell in Excel.
+    /// - [`XlsxError::MergeRangeOverlaps`] - The merge range overlaps a
+    ///   previous merge range.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - One of the str elements is empty or
+    ///   there isn't at least one `(&Format, &str)` tuple element.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates merging a range of cells that
+    /// contains a rich string.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_merge_range_with_rich_string.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Format, FormatAlign, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let default = Format::default();
+    ///     let red = Format::new().set_font_color(Color::Red);
+    ///     let center = Format::new().set_align(FormatAlign::Center);
+    ///
+    ///     // Write a rich string to a merged range.
+    ///     let segments = [(&default, "Merged "), (&red, "rich"), (&default, " string")];
+    ///     worksheet.merge_range_with_rich_string(1, 1, 1, 3, &segments, &center)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn merge_range_with_rich_string(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        rich_string: &[(&Format, &str)],
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_merge_range(first_row, first_col, last_row, last_col)?;
+
+        // Write the first cell in the range.
+        self.write_rich_string_with_format(first_row, first_col, rich_string, format)?;
+
+        self.store_merge_range(first_row, first_col, last_row, last_col, format)
+    }
+
+    // Check that a merge range is valid. The range is checked before the first
+    // cell is written.
+    fn check_merge_range(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<(), XlsxError> {
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions(first_row, first_col)
             || !self.check_dimensions(last_row, last_col)
@@ -4988,9 +5433,19 @@ impl Worksheet {
             return Err(XlsxError::MergeRangeSingleCell);
         }
 
-        // Write the first cell in the range.
-        self.write_string_with_format(first_row, first_col, string, format)?;
+        Ok(())
+    }
 
+    // Pad out a merge range, after the first cell has been written, and store
+    // the range.
+    fn store_merge_range(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
         // Pad out the rest of the range with formatted blanks cells. We split
         // this into the first row and subsequent rows to allow us to handle
         // "constant mode" write-ahead.
@@ -5655,6 +6110,7 @@ impl Worksheet {
             col = 0;
             chart.x_offset = 0;
             chart.y_offset = 0;
+            chart.absolute_position = None;
         }
 
         // Store workbook objects in row by column position order.
@@ -6178,6 +6634,127 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Embed a file in a worksheet as an OLE object.
+    ///
+    /// Add an [`OleObject`] to a worksheet at a cell location. The object
+    /// embeds an arbitrary file, such as a CSV or PDF file, in the workbook as
+    /// an OLE "Package" object. It is displayed in the worksheet using the
+    /// image set with [`OleObject::set_image()`] and the user can double-click
+    /// on it in Excel to open the embedded file.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `object`: The [`OleObject`] to insert into the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The object doesn't have a display
+    ///   image or the file data is larger than the 4GB limit of an OLE object.
+    ///
+    /// # Examples
+    ///
+    /// An example of embedding a file in a worksheet as an icon.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_ole_object.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, OleObject, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     worksheet.write(0, 0, "Double-click the icon to open the source data:")?;
+    /// #
+    ///     // Create an image to display the embedded object.
+    ///     let image = Image::new("examples/rust_logo.png")?
+    ///         .set_scale_width(0.5)
+    ///         .set_scale_height(0.5);
+    ///
+    ///     // Create an object to embed some CSV data.
+    ///     let object = OleObject::new_from_buffer(b"Region,Sales\nEast,100\n", "sales.csv")
+    ///         .set_image(&image);
+    ///
+    ///     // Insert the object into the worksheet.
+    ///     worksheet.insert_object(1, 1, &object)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("ole_object.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn insert_object(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        object: &OleObject,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.insert_object_with_offset(row, col, object, 0, 0)?;
+
+        Ok(self)
+    }
+
+    /// Embed a file in a worksheet as an OLE object at an offset.
+    ///
+    /// Add an [`OleObject`] to a worksheet at a pixel offset within a cell
+    /// location. See [`Worksheet::insert_object()`] above.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `object`: The [`OleObject`] to insert into the cell.
+    /// - `x_offset`: The horizontal offset within the cell in pixels.
+    /// - `y_offset`: The vertical offset within the cell in pixels.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The object doesn't have a display
+    ///   image or the file data is larger than the 4GB limit of an OLE object.
+    ///
+    pub fn insert_object_with_offset(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        object: &OleObject,
+        x_offset: u32,
+        y_offset: u32,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and columns are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        if object.image.is_none() {
+            return Err(XlsxError::ParameterError(format!(
+                "OleObject '{}' requires an image set with OleObject::set_image()",
+                object.filename
+            )));
+        }
+
+        object.check_size()?;
+
+        let mut object = object.clone();
+        object.x_offset = x_offset;
+        object.y_offset = y_offset;
+
+        // Store workbook objects in row by column position order.
+        self.ole_objects
+            .insert((row, col, y_offset, x_offset), object);
+        self.has_vml = true;
+
+        Ok(self)
+    }
+
     /// Insert a boolean checkbox in a worksheet cell.
     ///
     /// Checkboxes are a [new feature] added to Excel in 2024. They are a way of
@@ -6437,7 +7014,10 @@ impl Worksheet {
 
         // Update an existing row metadata object or create a new one.
         match self.changed_rows.get_mut(&row) {
-            Some(row_options) => row_options.height = height,
+            Some(row_options) => {
+                row_options.height = height;
+                row_options.autofit = false;
+            }
             None => {
                 let row_options = RowOptions {
                     height,
@@ -6446,6 +7026,7 @@ impl Worksheet {
                     level: 0,
                     collapsed: false,
                     format: None,
+                    autofit: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -6521,9 +7102,9 @@ impl Worksheet {
     /// format. Formatted data written to the row will maintain its own cell
     /// format. See the example below.
     ///
-    /// A future version of this library may support automatic merging of
-    /// explicit cell formatting with the row formatting but that isn't
-    /// currently supported.
+    /// Excel doesn't merge explicit cell formatting with the row
+    /// formatting. However, the formats can be combined automatically by
+    /// enabling [`Worksheet::set_cascading_formats()`].
     ///
     /// # Parameters
     ///
@@ -6591,7 +7172,10 @@ impl Worksheet {
 
         // Update an existing row metadata object or create a new one.
         match self.changed_rows.get_mut(&row) {
-            Some(row_options) => row_options.xf_index = xf_index,
+            Some(row_options) => {
+                row_options.xf_index = xf_index;
+                row_options.format = Some(format.clone());
+            }
             None => {
                 let row_options = RowOptions {
                     height: self.user_default_row_height,
@@ -6600,11 +7184,17 @@ impl Worksheet {
                     level: 0,
                     collapsed: false,
                     format: Some(format.clone()),
+                    autofit: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
         }
 
+        // Layer the row format onto any existing cells in the row.
+        if self.cascading_formats {
+            self.update_cascading_formats(row, 0, row, COL_MAX - 1);
+        }
+
         Ok(self)
     }
 
@@ -7623,6 +8213,7 @@ impl Worksheet {
                     level: 0,
                     collapsed: false,
                     format: None,
+                    autofit: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -7940,7 +8531,7 @@ impl Worksheet {
         col: ColNum,
         width: u16,
     ) -> Result<&mut Worksheet, XlsxError> {
-        self.set_column_width_internal(col, Self::pixels_to_width(width), false)
+        self.set_column_width_internal(col, self.theme_font.pixels_to_width(width), false)
     }
 
     /// Set the column pixel width in auto-fit mode.
@@ -7974,7 +8565,7 @@ impl Worksheet {
         col: ColNum,
         width: u16,
     ) -> Result<&mut Worksheet, XlsxError> {
-        self.set_column_width_internal(col, Self::pixels_to_width(width), true)
+        self.set_column_width_internal(col, self.theme_font.pixels_to_width(width), true)
     }
 
     /// Set the format for a column of cells.
@@ -7984,9 +8575,9 @@ impl Worksheet {
     /// format. Formatted data written to the column will maintain its own cell
     /// format. See the example below.
     ///
-    /// A future version of this library may support automatic merging of
-    /// explicit cell formatting with the column formatting but that isn't
-    /// currently supported.
+    /// Excel doesn't merge explicit cell formatting with the column
+    /// formatting. However, the formats can be combined automatically by
+    /// enabling [`Worksheet::set_cascading_formats()`].
     ///
     /// # Parameters
     ///
@@ -8054,7 +8645,10 @@ impl Worksheet {
 
         // Update an existing col metadata object or create a new one.
         match self.changed_cols.get_mut(&col) {
-            Some(col_options) => col_options.xf_index = xf_index,
+            Some(col_options) => {
+                col_options.xf_index = xf_index;
+                col_options.format = Some(format.clone());
+            }
             None => {
                 let col_options = ColOptions {
                     width: DEFAULT_COL_WIDTH,
@@ -8069,6 +8663,11 @@ impl Worksheet {
             }
         }
 
+        // Layer the column format onto any existing cells in the column.
+        if self.cascading_formats {
+            self.update_cascading_formats(0, col, ROW_MAX - 1, col);
+        }
+
         Ok(self)
     }
 
@@ -9028,7 +9627,7 @@ impl Worksheet {
             return Err(XlsxError::RowColumnOrderError);
         }
 
-        let mut conditional_format = conditional_format.box_clone();
+        let conditional_format = conditional_format.box_clone();
 
         // Store the conditional formats based on their range.
         let mut cell_range = utility::cell_range(first_row, first_col, last_row, last_col);
@@ -9040,38 +9639,7 @@ impl Worksheet {
         // Validate the conditional format.
         conditional_format.validate()?;
 
-        // Check for extended Excel 2010 data bars/icons.
-        if conditional_format.has_x14_extensions() {
-            self.use_x14_extensions = true;
-            self.has_x14_conditional_formats = true;
-        }
-
-        // Only write standard cond formats for non-x14 icons.
-        if !conditional_format.has_x14_only() {
-            self.has_conditional_formats = true;
-        }
-
-        // Set the dxf format local index if required.
-        if let Some(format) = conditional_format.format_as_mut() {
-            format.dxf_index = self.format_dxf_index(format);
-        }
-
-        match self.conditional_formats.entry(cell_range.clone()) {
-            Entry::Occupied(mut entry) => {
-                // The conditional format range already exists. Append the rule.
-                let rules = entry.get_mut();
-                rules.push(conditional_format);
-            }
-            Entry::Vacant(entry) => {
-                // The row doesn't exist, create a new row with columns and insert
-                // the cell value.
-                let rules = vec![conditional_format];
-                entry.insert(rules);
-
-                // Also store the order that the ranges are added in.
-                self.conditional_format_order.push(cell_range);
-            }
-        }
+        self.store_conditional_format(cell_range, conditional_format);
 
         Ok(self)
     }
@@ -9854,8 +10422,8 @@ impl Worksheet {
     /// The active cell within a selected range is determined by the order in
     /// which `first_` and `last_` are specified.
     ///
-    /// Only one range of cells can be selected. The default cell selection is
-    /// (0, 0, 0, 0), "A1".
+    /// The default cell selection is (0, 0, 0, 0), "A1". To select more than
+    /// one range of cells see [`Worksheet::set_selection_ranges()`].
     ///
     /// # Parameters
     ///
@@ -9907,34 +10475,97 @@ impl Worksheet {
         last_row: RowNum,
         last_col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows and cols are in the allowed range.
-        if !self.check_dimensions_only(first_row, first_col)
-            || !self.check_dimensions_only(last_row, last_col)
-        {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // The first/last order can be reversed to allow a selection to go from
-        // the end to the start. We take the active cell from the user first
-        // row/col and then reverse them as required for the full range.
-        let active_cell = utility::row_col_to_cell(first_row, first_col);
+        self.selected_range = self.selection(&[(first_row, first_col, last_row, last_col)])?;
 
-        let mut first_row = first_row;
-        let mut first_col = first_col;
-        let mut last_row = last_row;
-        let mut last_col = last_col;
+        Ok(self)
+    }
 
-        if first_row > last_row {
-            std::mem::swap(&mut first_row, &mut last_row);
-        }
+    /// Set several selected ranges of cells in a worksheet.
+    ///
+    /// The `set_selection_ranges()` method is like
+    /// [`Worksheet::set_selection()`] but it can be used to select several
+    /// non-contiguous ranges of cells, like selecting ranges in Excel with the
+    /// Ctrl key held down. Each range is a `(first_row, first_col, last_row,
+    /// last_col)` tuple. The active cell is the `first_row`/`first_col` cell of
+    /// the first range.
+    ///
+    /// # Parameters
+    ///
+    /// - `ranges`: A slice of `(first_row, first_col, last_row, last_col)`
+    ///   range tuples. (All zero indexed.)
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The slice of ranges is empty.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates selecting several ranges of cells in
+    /// a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_selection_ranges.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Select the cells B2:C4, E2:E4 and G6.
+    ///     worksheet.set_selection_ranges(&[(1, 1, 3, 2), (1, 4, 3, 4), (5, 6, 5, 6)])?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_selection_ranges(
+        &mut self,
+        ranges: &[(RowNum, ColNum, RowNum, ColNum)],
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.selected_range = self.selection(ranges)?;
 
-        if first_col > last_col {
-            std::mem::swap(&mut first_col, &mut last_col);
-        }
+        Ok(self)
+    }
 
-        let range = utility::cell_range(first_row, first_col, last_row, last_col);
+    /// Set the selected cells in one of the panes of a worksheet.
+    ///
+    /// When a worksheet is divided into panes with
+    /// [`Worksheet::set_freeze_panes()`] or [`Worksheet::set_split_panes()`]
+    /// each pane has its own selection of cells. The `set_pane_selection()`
+    /// method can be used to set the selected ranges of cells in one of the
+    /// panes, in the same way as [`Worksheet::set_selection_ranges()`]. The
+    /// [`PanePosition::TopLeft`] selection is the same as the worksheet
+    /// selection.
+    ///
+    /// # Parameters
+    ///
+    /// - `pane`: The [`PanePosition`] of the pane.
+    /// - `ranges`: A slice of `(first_row, first_col, last_row, last_col)`
+    ///   range tuples. (All zero indexed.)
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The slice of ranges is empty.
+    ///
+    pub fn set_pane_selection(
+        &mut self,
+        pane: PanePosition,
+        ranges: &[(RowNum, ColNum, RowNum, ColNum)],
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let selection = self.selection(ranges)?;
 
-        self.selected_range = (active_cell, range);
+        if pane == PanePosition::TopLeft {
+            self.selected_range = selection;
+        } else {
+            self.pane_selections.insert(pane, selection);
+        }
 
         Ok(self)
     }
@@ -10027,12 +10658,15 @@ impl Worksheet {
     ///
     /// - `row`: The zero indexed row number.
     /// - `col`: The zero indexed column number.
-    /// - `result`: The formula result to write to the cell.
+    /// - `result`: The formula result to write to the cell. An [`ExcelError`]
+    ///   value such as `#N/A` can also be used. See [`IntoFormulaResult`].
     ///
     /// # Warnings
     ///
     /// You will get a warning if you try to set a formula result for a cell
-    /// that doesn't have a formula.
+    /// that doesn't have a formula. You will also get a warning, and the
+    /// result is ignored, for the dynamic array errors `#SPILL!` and `#CALC!`
+    /// which aren't supported as formula results.
     ///
     /// # Examples
     ///
@@ -10070,8 +10704,12 @@ impl Worksheet {
         &mut self,
         row: RowNum,
         col: ColNum,
-        result: impl Into<String>,
+        result: impl IntoFormulaResult,
     ) -> &mut Worksheet {
+        let Some(result) = result.into_formula_result() else {
+            return self;
+        };
+
         let lookup_row = if self.use_constant_memory {
             self.current_row
         } else {
@@ -10089,7 +10727,7 @@ impl Worksheet {
                         result: cell_result,
                         ..
                     } => {
-                        *cell_result = Box::from(result.into());
+                        *cell_result = Box::from(result);
                     }
                     _ => {
                         eprintln!("Cell ({row}, {col}) doesn't contain a formula.");
@@ -10148,73 +10786,76 @@ impl Worksheet {
         self
     }
 
-    // -----------------------------------------------------------------------
-    // Worksheet overlay/formatting methods.
-    // -----------------------------------------------------------------------
-
-    /// Add formatting to a cell without overwriting the cell data.
-    ///
-    /// In Excel the data in a worksheet cell is comprised of a type, a value
-    /// and a format. When using `rust_xlsxwriter` the type is inferred and the
-    /// value and format are generally written at the same time using methods
-    /// like [`Worksheet::write_with_format()`]. However, if required you can
-    /// write the data separately and then add the format using methods like
-    /// `set_cell_format()`.
-    ///
-    /// Although this method requires an additional step it allows for use cases
-    /// where it is easier to write a large amount of data in one go and then
-    /// figure out where formatting should be applied. See also the
-    /// documentation section on [Worksheet Cell
-    /// formatting](../worksheet/index.html#cell-formatting).
-    ///
-    /// For use cases where the cell formatting changes based on cell values
-    /// Conditional Formatting may be a better option (see [Working with
-    /// Conditional Formats](../conditional_format/index.html)).
+    /// Insert rows into a worksheet.
+    ///
+    /// The `insert_rows()` method inserts blank rows into a worksheet and
+    /// shifts the existing cells, and the row heights and formats, down. The
+    /// references to the shifted cells are adjusted in the same way as Excel
+    /// does it. This includes:
+    ///
+    /// - Merged ranges, tables, autofilters, conditional formats and their
+    ///   rule formulas, data validations, sparklines, ignored errors and notes.
+    /// - Images, charts, shapes and buttons, which stay with their anchor cell.
+    /// - The print area, repeat rows and page breaks.
+    /// - Formula references, chart ranges and internal hyperlink locations
+    ///   that refer to the worksheet, in the worksheet and in the other
+    ///   worksheets of the workbook. In the other worksheets this also
+    ///   includes the conditional format and data validation formulas, table
+    ///   formulas and sparkline data ranges.
+    /// - Defined names created with
+    ///   [`Workbook::define_name()`](crate::Workbook::define_name) that refer
+    ///   to the worksheet.
+    ///
+    /// The references in the other worksheets and in the defined names are
+    /// adjusted by the workbook the next time that a worksheet is accessed via
+    /// the workbook, for example with
+    /// [`Workbook::worksheet_from_name()`](crate::Workbook::worksheet_from_name),
+    /// or when the workbook is saved. Formulas, charts and defined names that
+    /// are added after that are used as they are written.
+    ///
+    /// Ranges that span the inserted rows are expanded to include them.
+    /// References to deleted cells, and chart ranges that are deleted entirely,
+    /// are replaced with Excel's `#REF!` error.
+    ///
+    /// Note, rows of other worksheets that have already been written in
+    /// "constant memory" mode aren't adjusted. This method isn't supported for
+    /// worksheets in "constant memory" or "low memory" mode.
     ///
     /// # Parameters
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
-    /// - `format`: The [`Format`] property for the cell.
+    /// - `row`: The zero indexed row number to insert the rows before.
+    /// - `num_rows`: The number of rows to insert.
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits, or the worksheet data would be shifted past the limits.
+    /// - [`XlsxError::ParameterError`] - The worksheet is in constant memory
+    ///   mode.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the format of a worksheet
-    /// cell separately from writing the cell data.
+    /// The following example demonstrates inserting rows into a worksheet to
+    /// add a title above previously written data.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_cell_format.rs
+    /// # // This code is available in examples/doc_worksheet_insert_rows.rs
     /// #
-    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Format, Formula, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Add some formats.
-    /// #     let red = Format::new().set_font_color("#FF0000");
-    /// #     let green = Format::new().set_font_color("#00FF00");
-    /// #
-    ///     // Write an array of data.
-    ///     let data = [
-    ///         [10, 11, 12, 13, 14],
-    ///         [20, 21, 22, 23, 24],
-    ///         [30, 31, 32, 33, 34],
-    ///     ];
-    ///     worksheet.write_row_matrix(1, 1, data)?;
+    ///     // Write some data and a total.
+    ///     worksheet.write_column(0, 0, [10, 20, 30])?;
+    ///     worksheet.write_formula(3, 0, Formula::new("=SUM(A1:A3)"))?;
     ///
-    ///     // Add formatting to some of the cells.
-    ///     worksheet.set_cell_format(1, 1, &red)?;
-    ///     worksheet.set_cell_format(2, 3, &green)?;
-    ///     worksheet.set_cell_format(3, 5, &red)?;
+    ///     // Insert 2 rows at the top of the worksheet and add a title.
+    ///     worksheet.insert_rows(0, 2)?;
+    ///     worksheet.write_with_format(0, 0, "Sales", &Format::new().set_bold())?;
+    ///
+    ///     // The total formula in A6 is now "=SUM(A3:A5)".
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -10222,102 +10863,184 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_cell_format.png">
-    ///
-    pub fn set_cell_format(
+    pub fn insert_rows(
         &mut self,
         row: RowNum,
-        col: ColNum,
-        format: &Format,
+        num_rows: RowNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check row and col are in the allowed range.
-        if !self.check_dimensions(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Get the index of the format object.
-        let xf_index = self.format_xf_index(format);
+        self.shift_cells(ShiftAxis::Rows, row, num_rows, true)
+    }
 
-        // Insert the format in a new or existing cell.
-        self.insert_cell_format(row, col, xf_index);
+    /// Delete rows from a worksheet.
+    ///
+    /// The `delete_rows()` method deletes rows, and the cells in them, from a
+    /// worksheet and shifts the rows below up. The references to the shifted
+    /// cells are adjusted as explained in [`Worksheet::insert_rows()`].
+    /// References to deleted cells in formulas are replaced with `#REF!`, like
+    /// Excel. Ranges that overlap the deleted rows are contracted, and objects
+    /// such as merged ranges, notes and images that are entirely in the
+    /// deleted rows are removed.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number of the first row to delete.
+    /// - `num_rows`: The number of rows to delete.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::ParameterError`] - The worksheet is in constant memory
+    ///   mode.
+    /// - [`XlsxError::TableError`] - The rows contain the header row of a table
+    ///   but not the entire table.
+    ///
+    pub fn delete_rows(
+        &mut self,
+        row: RowNum,
+        num_rows: RowNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.shift_cells(ShiftAxis::Rows, row, num_rows, false)
+    }
 
-        Ok(self)
+    /// Insert columns into a worksheet.
+    ///
+    /// The `insert_columns()` method inserts blank columns into a worksheet
+    /// and shifts the existing cells, and the column widths and formats, to
+    /// the right. The references to the shifted cells are adjusted as
+    /// explained in [`Worksheet::insert_rows()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `col`: The zero indexed column number to insert the columns before.
+    /// - `num_cols`: The number of columns to insert.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits, or the worksheet data would be shifted past the limits.
+    /// - [`XlsxError::ParameterError`] - The worksheet is in constant memory
+    ///   mode.
+    /// - [`XlsxError::TableError`] - The columns would be inserted within a
+    ///   table. This would require new table columns.
+    ///
+    pub fn insert_columns(
+        &mut self,
+        col: ColNum,
+        num_cols: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.shift_cells(
+            ShiftAxis::Columns,
+            u32::from(col),
+            u32::from(num_cols),
+            true,
+        )
     }
 
-    /// Add formatting to a range of cells without overwriting the cell data.
+    /// Delete columns from a worksheet.
     ///
+    /// The `delete_columns()` method deletes columns, and the cells in them,
+    /// from a worksheet and shifts the columns to the right of them to the
+    /// left. The references to the shifted cells are adjusted as explained in
+    /// [`Worksheet::insert_rows()`] and [`Worksheet::delete_rows()`].
     ///
-    /// In Excel the data in a worksheet cell is comprised of a type, a value
-    /// and a format. When using `rust_xlsxwriter` the type is inferred and the
-    /// value and format are generally written at the same time using methods
-    /// like [`Worksheet::write_with_format()`]. However, if required you can
-    /// write the data separately and then add the format using methods like
-    /// `set_range_format()` or [`Worksheet::set_cell_format()`] (see above).
+    /// # Parameters
     ///
-    /// Although this method requires an additional step it allows for use cases
-    /// where it is easier to write a large amount of data in one go and then
-    /// figure out where formatting should be applied. See also the
-    /// documentation section on [Worksheet Cell
-    /// formatting](../worksheet/index.html#cell-formatting).
+    /// - `col`: The zero indexed column number of the first column to delete.
+    /// - `num_cols`: The number of columns to delete.
     ///
-    /// For use cases where the cell formatting changes based on cell values
-    /// Conditional Formatting may be a better option (see [Working with
-    /// Conditional Formats](../conditional_format/index.html)).
+    /// # Errors
     ///
-    /// Note, this method should **not** be used to set the formatting for an
-    /// entire worksheet since it would add an XML element for each of the 34
-    /// billion cells in the worksheet which would result in a very large and
-    /// slow output file. To set the format for the entire worksheet see the
-    /// [`Worksheet::set_column_range_format()`] method.
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::ParameterError`] - The worksheet is in constant memory
+    ///   mode.
+    /// - [`XlsxError::TableError`] - The columns overlap a table but don't
+    ///   contain the entire table.
+    ///
+    pub fn delete_columns(
+        &mut self,
+        col: ColNum,
+        num_cols: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.shift_cells(
+            ShiftAxis::Columns,
+            u32::from(col),
+            u32::from(num_cols),
+            false,
+        )
+    }
+
+    /// Copy a range of cells to another position in the worksheet.
+    ///
+    /// The `copy_range()` method copies a range of cells to a new position in
+    /// the worksheet, in the same way as an Excel copy and paste. The cell
+    /// values and formats are copied along with any merged ranges, notes,
+    /// hyperlinks and conditional formats in the range.
+    ///
+    /// The relative cell references in copied formulas are adjusted for the new
+    /// position of the formula, in the same way as Excel. For example, if the
+    /// formula `=A1+$B$1` is copied down one row it becomes `=A2+$B$1`.
+    /// References that would be moved off the worksheet are replaced with
+    /// Excel's `#REF!` error.
     ///
+    /// Note, the cells in the target range are overwritten, including any
+    /// cells that are empty in the copied range. Tables, data validations,
+    /// images and charts aren't copied, and the formulas in conditional format
+    /// rules aren't adjusted, only their ranges. This method isn't supported
+    /// for worksheets in "constant memory" or "low memory" mode.
+    ///
+    /// To copy cells to another worksheet see [`Worksheet::copy_range_data()`]
+    /// and [`Worksheet::paste_range()`]. To copy only the values or formats of
+    /// the cells see [`Worksheet::copy_range_with_options()`].
     ///
     /// # Parameters
     ///
-    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_row`: The first row of the range to copy. (All zero indexed.)
     /// - `first_col`: The first column of the range.
     /// - `last_row`: The last row of the range.
     /// - `last_col`: The last column of the range.
-    /// - `format`: The [`Format`] property for the cell.
+    /// - `row`: The zero indexed row number of the target cell.
+    /// - `col`: The zero indexed column number of the target cell.
     ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
+    ///   worksheet limits, or the copied range would extend past the limits.
     /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
     ///   row.
+    /// - [`XlsxError::MergeRangeOverlaps`] - The target range overlaps part of
+    ///   a merged range.
+    /// - [`XlsxError::ParameterError`] - The worksheet is in constant memory
+    ///   mode.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the format of worksheet cells
-    /// separately from writing the cell data.
+    /// The following example demonstrates copying a block of cells, with
+    /// formulas, to build up a worksheet from a template range.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_range_format.rs
+    /// # // This code is available in examples/doc_worksheet_copy_range.rs
     /// #
-    /// # use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Format, Formula, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
-    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Add a format.
-    ///     let border = Format::new().set_border(FormatBorder::Thin);
+    ///     let bold = Format::new().set_bold();
     ///
-    ///     // Write an array of data.
-    ///     let data = [
-    ///         [10, 11, 12, 13, 14],
-    ///         [20, 21, 22, 23, 24],
-    ///         [30, 31, 32, 33, 34],
-    ///     ];
-    ///     worksheet.write_row_matrix(1, 1, data)?;
+    ///     // Create a block of data with a total formula.
+    ///     worksheet.merge_range(0, 0, 0, 1, "Quarter 1", &bold)?;
+    ///     worksheet.write_column(1, 0, ["North", "South"])?;
+    ///     worksheet.write_column(1, 1, [1000, 2000])?;
+    ///     worksheet.write_with_format(3, 0, "Total", &bold)?;
+    ///     worksheet.write_formula(3, 1, Formula::new("=SUM(B2:B3)"))?;
     ///
-    ///     // Add formatting to the cells.
-    ///     worksheet.set_range_format(1, 1, 3, 5, &border)?;
+    ///     // Copy the block to the right. The formula becomes "=SUM(E2:E3)".
+    ///     worksheet.copy_range(0, 0, 3, 1, 0, 3)?;
+    ///     worksheet.write(0, 3, "Quarter 2")?;
+    ///     worksheet.write_column(1, 4, [1500, 2500])?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -10325,119 +11048,83 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_range_format.png">
-    ///
-    pub fn set_range_format(
+    pub fn copy_range(
         &mut self,
         first_row: RowNum,
         first_col: ColNum,
         last_row: RowNum,
         last_col: ColNum,
-        format: &Format,
+        row: RowNum,
+        col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows and cols are in the allowed range.
-        if !self.check_dimensions_only(first_row, first_col)
-            || !self.check_dimensions_only(last_row, last_col)
-        {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Check order of first/last values.
-        if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
-        }
-
-        // Get the index of the format object.
-        let xf_index = self.format_xf_index(format);
-
-        // Insert the format in a new or existing cells.
-        for row in first_row..=last_row {
-            for col in first_col..=last_col {
-                self.insert_cell_format(row, col, xf_index);
-            }
-        }
-
-        Ok(self)
+        let data = self.copy_range_data(first_row, first_col, last_row, last_col)?;
+        self.paste_range(row, col, &data, &PasteOptions::default())
     }
 
-    /// Add formatting to a range of cells with an external border.
-    ///
-    /// This method is similar to the  [`Worksheet::set_range_format()`] method
-    /// (see above) except it also adds a border around the cell range.
-    ///
-    /// Add a border around a range of cells in Excel is generally easy to do
-    /// using the GUI interface. However, creating a border around a range of
-    /// cells programmatically is much harder since it requires the creation of
-    /// up to 9 separate formats and the tracking of where cells are relative to
-    /// the border.
-    ///
-    /// The `set_range_format_with_border()` method is provided to simplify this
-    /// task. It allows you to specify one format for the cells and another for
-    /// the border.
+    /// Copy a range of cells to another position in the worksheet, with paste
+    /// options.
     ///
-    /// You should also consider formatting a range of cells as a Worksheet
-    /// Table may be a better option than simple cell formatting (see the
-    /// [`Table`] section of the documentation).
+    /// The `copy_range_with_options()` method is like
+    /// [`Worksheet::copy_range()`] but it also takes a [`PasteOptions`]
+    /// parameter to copy only the values or formats of the cells, or to
+    /// transpose the rows and columns, like Excel's "Paste Special" dialog.
     ///
-    /// Note, this method should **not** be used to set the formatting for an
-    /// entire worksheet since it would add an XML element for each of the 34
-    /// billion cells in the worksheet which would result in a very large and
-    /// slow output file. To set the format for the entire worksheet see the
-    /// [`Worksheet::set_column_range_format()`] method.
+    /// **Note**: when the [`PasteType::Values`] option is used the copied
+    /// formulas are replaced by their results. Since `rust_xlsxwriter` doesn't
+    /// calculate formulas this is the value set with [`Formula::set_result()`]
+    /// or, if a result isn't set, the default result of 0. This is unlikely to
+    /// be what you want, so set the results of formulas that are copied as
+    /// values.
     ///
     /// # Parameters
     ///
-    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_row`: The first row of the range to copy. (All zero indexed.)
     /// - `first_col`: The first column of the range.
     /// - `last_row`: The last row of the range.
     /// - `last_col`: The last column of the range.
-    /// - `cell_format`: The [`Format`] property for the cells in the range. If
-    ///   you don't require internal formatting you can use `Format::default()`.
-    /// - `border_format`: The [`Format`] property for the border. Only the
-    ///   [`Format::set_border()`] and [`Format::set_border_color()`] properties
-    ///   are used.
+    /// - `row`: The zero indexed row number of the target cell.
+    /// - `col`: The zero indexed column number of the target cell.
+    /// - `options`: The [`PasteOptions`] to apply.
     ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
+    ///   worksheet limits, or the copied range would extend past the limits.
     /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
     ///   row.
+    /// - [`XlsxError::MergeRangeOverlaps`] - The target range overlaps part of
+    ///   a merged range.
+    /// - [`XlsxError::ParameterError`] - The worksheet is in constant memory
+    ///   mode.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the format of worksheet cells
-    /// separately from writing the cell data.
+    /// The following example demonstrates copying a range of cells with the
+    /// "values only" and "transpose" paste options.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_range_format_with_border.rs
+    /// # // This code is available in examples/doc_worksheet_copy_range_with_options.rs
     /// #
-    /// # use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Format, Formula, PasteOptions, PasteType, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Add some formats.
-    ///     let inner_border = Format::new().set_border(FormatBorder::Thin);
-    ///     let outer_border = Format::new().set_border(FormatBorder::Double);
+    ///     let bold = Format::new().set_bold();
     ///
-    ///     // Write an array of data.
-    ///     let data = [
-    ///         [10, 11, 12, 13, 14],
-    ///         [20, 21, 22, 23, 24],
-    ///         [30, 31, 32, 33, 34],
-    ///     ];
-    ///     worksheet.write_row_matrix(1, 1, data)?;
+    ///     // Write some data and a formula to sum it.
+    ///     worksheet.write_with_format(0, 0, "Totals", &bold)?;
+    ///     worksheet.write_column(1, 0, [10, 20, 30])?;
+    ///     worksheet.write_formula(4, 0, Formula::new("=SUM(A2:A4)").set_result("60"))?;
     ///
-    ///     // Add formatting to the cells.
-    ///     worksheet.set_range_format_with_border(1, 1, 3, 5, &inner_border, &outer_border)?;
+    ///     // Copy the data as values, without the formula or formatting.
+    ///     let options = PasteOptions::new().set_type(PasteType::Values);
+    ///     worksheet.copy_range_with_options(0, 0, 4, 0, 0, 2, &options)?;
+    ///
+    ///     // Copy the data and transpose it from a column to a row.
+    ///     let options = PasteOptions::new().set_transpose(true);
+    ///     worksheet.copy_range_with_options(0, 0, 4, 0, 7, 0, &options)?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -10445,106 +11132,161 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
+    #[allow(clippy::too_many_arguments)]
+    pub fn copy_range_with_options(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        row: RowNum,
+        col: ColNum,
+        options: &PasteOptions,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let data = self.copy_range_data(first_row, first_col, last_row, last_col)?;
+        self.paste_range(row, col, &data, options)
+    }
+
+    /// Move a range of cells to another position in the worksheet.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_range_format_with_border.png">
+    /// The `move_range()` method moves a range of cells to a new position in
+    /// the worksheet, in the same way as an Excel cut and paste. The cell
+    /// values and formats are moved along with any merged ranges, notes,
+    /// hyperlinks and conditional formats in the range, and the original cells
+    /// are cleared.
     ///
-    pub fn set_range_format_with_border(
+    /// Unlike [`Worksheet::copy_range()`] the relative references in the moved
+    /// formulas aren't offset, so they continue to refer to the same cells. As
+    /// in Excel, the cell and range references to the moved cells in the formulas,
+    /// charts and internal hyperlinks of the workbook, and in the defined
+    /// names, are changed to refer to the new position of the cells. References to the cells that
+    /// are overwritten by the move are changed to a `#REF!` error. References
+    /// to ranges that are only partly moved, and to rows or columns, aren't
+    /// changed. Formulas that are written after the move aren't adjusted.
+    ///
+    /// This method isn't supported for worksheets in "constant memory" or "low
+    /// memory" mode.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range to move. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `row`: The zero indexed row number of the target cell.
+    /// - `col`: The zero indexed column number of the target cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits, or the moved range would extend past the limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    /// - [`XlsxError::MergeRangeOverlaps`] - The target range overlaps part of
+    ///   a merged range.
+    /// - [`XlsxError::ParameterError`] - The worksheet is in constant memory
+    ///   mode.
+    ///
+    pub fn move_range(
         &mut self,
         first_row: RowNum,
         first_col: ColNum,
         last_row: RowNum,
         last_col: ColNum,
-        cell_format: &Format,
-        border_format: &Format,
+        row: RowNum,
+        col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows and cols are in the allowed range.
-        if !self.check_dimensions_only(first_row, first_col)
-            || !self.check_dimensions_only(last_row, last_col)
-        {
-            return Err(XlsxError::RowColumnLimitError);
-        }
+        let data = self.copy_range_data(first_row, first_col, last_row, last_col)?;
 
-        // Check order of first/last values.
-        if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+        // Check the target range before clearing the original cells.
+        let original_range = CellRange::new(first_row, first_col, last_row, last_col);
+        let cell_range = Self::paste_target_range(row, col, &data, false)?;
+        self.check_paste_merged_ranges(&cell_range, Some(&original_range))?;
+
+        self.clear_paste_range(&original_range);
+        self.paste_range_internal(row, col, &data, &PasteOptions::default(), false)?;
+
+        // Adjust the references to the moved, and overwritten, cells in the
+        // worksheet and log the move so that the references in the other
+        // worksheets and in the workbook defined names can be adjusted.
+        let change = ReferenceChange::Move(RangeMove::new(
+            first_row, first_col, last_row, last_col, row, col,
+        ));
+        let sheet_name = self.name.clone();
+
+        for columns in self.data_table.values_mut() {
+            for cell in columns.values_mut() {
+                if let CellType::Formula { formula, .. } | CellType::ArrayFormula { formula, .. } =
+                    cell
+                {
+                    *formula = change.formula(formula, &sheet_name, true).into();
+                }
+            }
         }
 
-        if first_row == last_row && first_col == last_col {
-            self.set_range_border_cell(first_row, first_col, cell_format, border_format)?;
-        } else if first_row == last_row {
-            self.set_range_border_row(first_row, first_col, last_col, cell_format, border_format)?;
-        } else if first_col == last_col {
-            self.set_range_border_col(first_row, last_row, first_col, cell_format, border_format)?;
-        } else {
-            self.set_range_border_range(
-                first_row,
-                last_row,
-                first_col,
-                last_col,
-                cell_format,
-                border_format,
-            )?;
+        for chart in self.charts.values_mut() {
+            chart.shift_ranges(&change, &sheet_name);
         }
 
+        self.shift_hyperlink_locations(|location| change.formula(location, &sheet_name, true));
+
+        self.reference_changes.push((sheet_name, change));
+
         Ok(self)
     }
 
-    /// Clear the data and formatting from a worksheet cell.
-    ///
-    /// This method can be used to clear data and formatting previously written
-    /// to a worksheet cell using one of the worksheet `write()` methods.
-    ///
-    /// This can occasionally be useful for scenarios where it is easier to add
-    /// data in bulk but then remove certain elements.
+    /// Copy a range of cells so that it can be pasted into another worksheet.
     ///
-    /// This method only clears data, it doesn't clear images or conditional
-    /// formatting, or other non-data elements.
-    ///
-    /// Note, this method doesn't return a [`Result`] or errors. Instructions to
-    /// clear non-existent cells are simply ignored.
+    /// The `copy_range_data()` method copies the values and formats of a range
+    /// of cells, and any merged ranges, notes, hyperlinks and conditional
+    /// formats in the range, into a [`CopiedRange`] struct. This can be pasted
+    /// into the same worksheet, or another worksheet, with
+    /// [`Worksheet::paste_range()`].
     ///
     /// # Parameters
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
+    /// - `first_row`: The first row of the range to copy. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
     ///
-    /// # Examples
+    /// # Errors
     ///
-    /// The following example demonstrates clearing some previously written cell
-    /// data and formatting from a worksheet.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    /// - [`XlsxError::ParameterError`] - The worksheet is in constant memory
+    ///   mode.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates copying a range of cells from one
+    /// worksheet to another.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_clear_cell.rs
+    /// # // This code is available in examples/doc_worksheet_copy_range_data.rs
     /// #
-    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Format, PasteOptions, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    /// #     // Add a format.
-    /// #     let format = Format::new().set_font_color("#FF0000");
-    /// #
-    ///     // Some array data to write.
-    ///     let data = [
-    ///         [10, 11, 12, 13, 14],
-    ///         [20, 21, 22, 23, 24],
-    ///         [30, 31, 32, 33, 34],
-    ///     ];
+    ///     let bold = Format::new().set_bold();
     ///
-    ///     // Write the array data as a series of rows.
-    ///     worksheet.write_row_with_format(0, 0, data[0], &format)?;
-    ///     worksheet.write_row_with_format(1, 0, data[1], &format)?;
-    ///     worksheet.write_row_with_format(2, 0, data[2], &format)?;
+    ///     // Write some data to the first worksheet.
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///     worksheet1.write_with_format(0, 0, "Region", &bold)?;
+    ///     worksheet1.write_with_format(0, 1, "Sales", &bold)?;
+    ///     worksheet1.write_column(1, 0, ["North", "South"])?;
+    ///     worksheet1.write_column(1, 1, [1000, 2000])?;
     ///
-    ///     // Clear the first and last cell in the written data.
-    ///     worksheet.clear_cell(0, 0);
-    ///     worksheet.clear_cell(2, 4);
+    ///     // Copy the data.
+    ///     let data = worksheet1.copy_range_data(0, 0, 2, 1)?;
+    ///
+    ///     // Paste the data into a second worksheet.
+    ///     let worksheet2 = workbook.add_worksheet();
+    ///     worksheet2.paste_range(1, 1, &data, &PasteOptions::new())?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -10552,49 +11294,225 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_clear_cell.png">
-    ///
-    pub fn clear_cell(&mut self, row: RowNum, col: ColNum) -> &mut Worksheet {
-        // If cell is outside the allowed range it doesn't need to be cleared.
-        if !self.check_dimensions_only(row, col) {
-            return self;
+    pub fn copy_range_data(
+        &self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<CopiedRange, XlsxError> {
+        if self.use_constant_memory {
+            return Err(XlsxError::ParameterError(
+                "Ranges can't be copied in constant memory mode".to_string(),
+            ));
         }
 
-        self.clear_cell_internal(row, col);
+        // Check rows and cols are in the allowed range.
+        if last_row >= ROW_MAX || last_col >= COL_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
 
-        self
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        let mut data = CopiedRange {
+            first_row,
+            first_col,
+            num_rows: last_row - first_row + 1,
+            num_cols: last_col - first_col + 1,
+            cells: vec![],
+            hyperlinks: vec![],
+            merged_ranges: vec![],
+            notes: vec![],
+            conditional_formats: vec![],
+        };
+
+        // Copy the cells with their formats and embedded images, if any.
+        for (row, columns) in self.data_table.range(first_row..=last_row) {
+            for (col, cell) in columns.range(first_col..=last_col) {
+                let image = match cell {
+                    CellType::Error {
+                        image_index: Some(index),
+                        ..
+                    } => self.embedded_images.get(*index as usize),
+                    _ => None,
+                };
+
+                // Copy the cascading cell and range formats, if any.
+                let cascading_formats = self.cascading_formats.then(|| {
+                    let layer = |index: Option<&u32>| {
+                        index.map(|index| self.cascading_layers[*index as usize].clone())
+                    };
+                    (
+                        layer(self.cascading_cell_formats.get(&(*row, *col))),
+                        layer(self.cascading_range_formats.get(&(*row, *col))),
+                    )
+                });
+
+                data.cells.push(CopiedCell {
+                    row: row - first_row,
+                    col: col - first_col,
+                    cell: cell.clone(),
+                    format: self.xf_format(CellValue::cell_xf_index(cell)),
+                    image: image.cloned(),
+                    cascading_formats,
+                });
+            }
+        }
+
+        for ((row, col), url) in &self.hyperlinks {
+            if (first_row..=last_row).contains(row) && (first_col..=last_col).contains(col) {
+                data.hyperlinks
+                    .push((row - first_row, col - first_col, url.clone()));
+            }
+        }
+
+        for (row, columns) in self.notes.range(first_row..=last_row) {
+            for (col, note) in columns.range(first_col..=last_col) {
+                data.notes
+                    .push((row - first_row, col - first_col, note.clone()));
+            }
+        }
+
+        // Copy the merged ranges that are entirely within the copied range.
+        for cell_range in &self.merged_ranges {
+            if cell_range.first_row >= first_row
+                && cell_range.last_row <= last_row
+                && cell_range.first_col >= first_col
+                && cell_range.last_col <= last_col
+            {
+                data.merged_ranges.push(CellRange::new(
+                    cell_range.first_row - first_row,
+                    cell_range.first_col - first_col,
+                    cell_range.last_row - first_row,
+                    cell_range.last_col - first_col,
+                ));
+            }
+        }
+
+        // Copy the parts of the conditional format ranges that overlap the
+        // copied range.
+        for sqref in &self.conditional_format_order {
+            let ranges: Vec<CellRange> = sqref
+                .split_whitespace()
+                .filter_map(shift::parse_range)
+                .filter_map(
+                    |(range_first_row, range_first_col, range_last_row, range_last_col)| {
+                        let overlap = CellRange::new(
+                            range_first_row.max(first_row),
+                            range_first_col.max(first_col),
+                            range_last_row.min(last_row),
+                            range_last_col.min(last_col),
+                        );
+
+                        if overlap.first_row > overlap.last_row
+                            || overlap.first_col > overlap.last_col
+                        {
+                            return None;
+                        }
+
+                        Some(CellRange::new(
+                            overlap.first_row - first_row,
+                            overlap.first_col - first_col,
+                            overlap.last_row - first_row,
+                            overlap.last_col - first_col,
+                        ))
+                    },
+                )
+                .collect();
+
+            if let (false, Some(rules)) = (ranges.is_empty(), self.conditional_formats.get(sqref)) {
+                for rule in rules {
+                    data.conditional_formats
+                        .push((ranges.clone(), rule.box_clone()));
+                }
+            }
+        }
+
+        Ok(data)
     }
 
-    /// Clear the formatting from a worksheet cell.
+    /// Paste a range of copied cells into the worksheet.
     ///
-    /// This method can be used to clear the formatting previously added to a
-    /// worksheet cell using one of the worksheet `write_with_format()` methods.
+    /// The `paste_range()` method pastes a range of cells, copied with
+    /// [`Worksheet::copy_range_data()`], into the worksheet. The cells can be
+    /// copied from the same worksheet or from another worksheet. The relative
+    /// cell references in copied formulas are adjusted for the new position of
+    /// the formula as explained in [`Worksheet::copy_range()`].
     ///
-    /// This can occasionally be useful for scenarios where it is easier to add
-    /// formatted data in bulk but then remove the formatting from certain
-    /// elements.
+    /// # Parameters
     ///
-    /// See also the [`Worksheet::set_cell_format()`] method for a similar
-    /// method to change the format of a cell.
+    /// - `row`: The zero indexed row number of the target cell.
+    /// - `col`: The zero indexed column number of the target cell.
+    /// - `data`: The [`CopiedRange`] to paste.
+    /// - `options`: The [`PasteOptions`] to apply.
     ///
-    /// Note, this method doesn't return a [`Result`] or errors. Instructions to
-    /// clear non-existent cells are simply ignored.
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits, or the pasted range would extend past the limits.
+    /// - [`XlsxError::MergeRangeOverlaps`] - The target range overlaps part of
+    ///   a merged range.
+    /// - [`XlsxError::ParameterError`] - The worksheet is in constant memory
+    ///   mode.
+    ///
+    /// # Examples
+    ///
+    /// See the example for [`Worksheet::copy_range_data()`].
+    ///
+    pub fn paste_range(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: &CopiedRange,
+        options: &PasteOptions,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.paste_range_internal(row, col, data, options, true)
+    }
+
+    // -----------------------------------------------------------------------
+    // Worksheet overlay/formatting methods.
+    // -----------------------------------------------------------------------
+
+    /// Add formatting to a cell without overwriting the cell data.
+    ///
+    /// In Excel the data in a worksheet cell is comprised of a type, a value
+    /// and a format. When using `rust_xlsxwriter` the type is inferred and the
+    /// value and format are generally written at the same time using methods
+    /// like [`Worksheet::write_with_format()`]. However, if required you can
+    /// write the data separately and then add the format using methods like
+    /// `set_cell_format()`.
+    ///
+    /// Although this method requires an additional step it allows for use cases
+    /// where it is easier to write a large amount of data in one go and then
+    /// figure out where formatting should be applied. See also the
+    /// documentation section on [Worksheet Cell
+    /// formatting](../worksheet/index.html#cell-formatting).
+    ///
+    /// For use cases where the cell formatting changes based on cell values
+    /// Conditional Formatting may be a better option (see [Working with
+    /// Conditional Formats](../conditional_format/index.html)).
     ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
     /// - `col`: The zero indexed column number.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates clearing the formatting from some
-    /// previously written cells in a worksheet.
+    /// The following example demonstrates setting the format of a worksheet
+    /// cell separately from writing the cell data.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_clear_cell_format.rs
+    /// # // This code is available in examples/doc_worksheet_set_cell_format.rs
     /// #
     /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
     /// #
@@ -10605,24 +11523,22 @@ impl Worksheet {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Add a format.
-    /// #     let format = Format::new().set_font_color("#FF0000");
+    /// #     // Add some formats.
+    /// #     let red = Format::new().set_font_color("#FF0000");
+    /// #     let green = Format::new().set_font_color("#00FF00");
     /// #
-    ///     // Some array data to write.
+    ///     // Write an array of data.
     ///     let data = [
     ///         [10, 11, 12, 13, 14],
     ///         [20, 21, 22, 23, 24],
     ///         [30, 31, 32, 33, 34],
     ///     ];
+    ///     worksheet.write_row_matrix(1, 1, data)?;
     ///
-    ///     // Write the array data as a series of rows.
-    ///     worksheet.write_row_with_format(0, 0, data[0], &format)?;
-    ///     worksheet.write_row_with_format(1, 0, data[1], &format)?;
-    ///     worksheet.write_row_with_format(2, 0, data[2], &format)?;
-    ///
-    ///     // Clear the format from the first and last cells in the data.
-    ///     worksheet.clear_cell_format(0, 0);
-    ///     worksheet.clear_cell_format(2, 4);
+    ///     // Add formatting to some of the cells.
+    ///     worksheet.set_cell_format(1, 1, &red)?;
+    ///     worksheet.set_cell_format(2, 3, &green)?;
+    ///     worksheet.set_cell_format(3, 5, &red)?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -10633,297 +11549,232 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_clear_cell_format.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_cell_format.png">
     ///
-    pub fn clear_cell_format(&mut self, row: RowNum, col: ColNum) -> &mut Worksheet {
-        // If cell is outside the allowed range it doesn't need to be cleared.
-        if !self.check_dimensions_only(row, col) {
-            return self;
+    pub fn set_cell_format(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
         }
 
-        self.clear_cell_format_internal(row, col);
+        // In cascading mode the format is layered with any other formats.
+        if self.cascading_formats {
+            return self.set_cascading_range_format(row, col, row, col, format);
+        }
 
-        self
-    }
+        // Get the index of the format object.
+        let xf_index = self.format_xf_index(format);
 
-    // -----------------------------------------------------------------------
-    // Worksheet serde methods.
-    // -----------------------------------------------------------------------
+        // Insert the format in a new or existing cell.
+        self.insert_cell_format(row, col, xf_index);
 
-    /// Write a Serde serializable struct to a worksheet.
+        Ok(self)
+    }
+
+    /// Add formatting to a range of cells without overwriting the cell data.
     ///
-    /// This method can be used to serialize [Serde](https://serde.rs) enabled
-    /// data structures into cells in a worksheet.
     ///
-    /// See [Working with Serde](crate::serializer#working-with-serde) for
-    /// background details on how serialization works with `rust_xlsxwriter`.
+    /// In Excel the data in a worksheet cell is comprised of a type, a value
+    /// and a format. When using `rust_xlsxwriter` the type is inferred and the
+    /// value and format are generally written at the same time using methods
+    /// like [`Worksheet::write_with_format()`]. However, if required you can
+    /// write the data separately and then add the format using methods like
+    /// `set_range_format()` or [`Worksheet::set_cell_format()`] (see above).
     ///
-    /// When serializing structs `rust_xlsxwriter` needs to know location where
-    /// the serialization starts and also the type and field names of the struct
-    /// being serialized. The field names are used as headers and the type name
-    /// allows for several distinct structs to be serialized to the same
-    /// worksheet.
+    /// Although this method requires an additional step it allows for use cases
+    /// where it is easier to write a large amount of data in one go and then
+    /// figure out where formatting should be applied. See also the
+    /// documentation section on [Worksheet Cell
+    /// formatting](../worksheet/index.html#cell-formatting).
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/serialize_intro1.png">
+    /// For use cases where the cell formatting changes based on cell values
+    /// Conditional Formatting may be a better option (see [Working with
+    /// Conditional Formats](../conditional_format/index.html)).
     ///
-    /// The worksheet methods that perform this function fall into two types:
-    /// methods which use deserialization to find the fields from the *type* and
-    /// methods that use serialization to find the fields from an *instance of
-    /// the type*. The deserialization methods are easier to use but require
-    /// that the struct derives the Serde [`Deserialize`] trait as well as the
-    /// [`Serialize`] trait. The serialization methods work for anything else.
+    /// Note, this method should **not** be used to set the formatting for an
+    /// entire worksheet since it would add an XML element for each of the 34
+    /// billion cells in the worksheet which would result in a very large and
+    /// slow output file. To set the format for the entire worksheet see the
+    /// [`Worksheet::set_column_range_format()`] method.
     ///
-    /// There available methods are.
     ///
-    /// - [`Worksheet::deserialize_headers()`]: The simplest most direct method.
-    ///   It only requires the type of struct that you wish to serialize and
-    ///   that it derives the [`Deserialize`] and [`Serialize`] traits. The
-    ///   library uses this to infer the struct name and fields (via
-    ///   deserialization).
+    /// # Parameters
     ///
-    /// - [`Worksheet::deserialize_headers_with_format()`]: This is similar to
-    ///   the previous method but it allows you to add a cell format for the
-    ///   headers.
-    ///
-    /// - [`Worksheet::deserialize_headers_with_options()`]: Similar to the
-    ///   previous methods but also allows configuration of the headers and
-    ///   fields via [`SerializeFieldOptions`].
-    ///
-    /// - [`Worksheet::serialize_headers()`]: Similar to the
-    ///   `deserialize_headers()` method but it requires a concrete instance of
-    ///   the type of struct that you wish to serialize. The library uses this
-    ///   to infer the struct name and fields (via serialization). This method
-    ///   only requires that the struct derives [`Serialize`].
-    ///
-    /// Once the headers are set up an subsequent calls to `serialize()` will
-    /// write the struct data in rows beneath the header.
-    ///
-    ///
-    /// # Parameters
-    ///
-    /// - `data_structure`: A reference to a struct that implements the
-    ///   [`serde::Serializer`] trait.
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `format`: The [`Format`] property for the cell.
     ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
-    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
-    ///   of 32,767 characters.
-    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
-    ///   serialization.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates serializing instances of a Serde derived
-    /// data structure to a worksheet.
+    /// The following example demonstrates setting the format of worksheet cells
+    /// separately from writing the cell data.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_serialize.rs
+    /// # // This code is available in examples/doc_worksheet_set_range_format.rs
     /// #
-    /// use rust_xlsxwriter::{Format, Workbook, XlsxError};
-    /// use serde::{Deserialize, Serialize};
-    ///
-    /// fn main() -> Result<(), XlsxError> {
-    ///     let mut workbook = Workbook::new();
-    ///
-    ///     // Add a worksheet to the workbook.
-    ///     let worksheet = workbook.add_worksheet();
-    ///
-    ///     // Add a simple format for the headers.
-    ///     let format = Format::new().set_bold();
-    ///
-    ///     // Create a serializable struct.
-    ///     #[derive(Deserialize, Serialize)]
-    ///     #[serde(rename_all = "PascalCase")]
-    ///     struct Produce {
-    ///         fruit: &'static str,
-    ///         cost: f64,
-    ///     }
-    ///
-    ///     // Create some data instances.
-    ///     let item1 = Produce {
-    ///         fruit: "Peach",
-    ///         cost: 1.05,
-    ///     };
-    ///     let item2 = Produce {
-    ///         fruit: "Plum",
-    ///         cost: 0.15,
-    ///     };
-    ///     let item3 = Produce {
-    ///         fruit: "Pear",
-    ///         cost: 0.75,
-    ///     };
-    ///
-    ///     // Set up the start location and headers of the data to be serialized.
-    ///     worksheet.deserialize_headers_with_format::<Produce>(0, 0, &format)?;
-    ///
-    ///     // Serialize the data.
-    ///     worksheet.serialize(&item1)?;
-    ///     worksheet.serialize(&item2)?;
-    ///     worksheet.serialize(&item3)?;
+    /// # use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Add a format.
+    ///     let border = Format::new().set_border(FormatBorder::Thin);
     ///
-    ///     // Save the file.
-    ///     workbook.save("serialize.xlsx")?;
+    ///     // Write an array of data.
+    ///     let data = [
+    ///         [10, 11, 12, 13, 14],
+    ///         [20, 21, 22, 23, 24],
+    ///         [30, 31, 32, 33, 34],
+    ///     ];
+    ///     worksheet.write_row_matrix(1, 1, data)?;
     ///
-    ///     Ok(())
-    /// }
+    ///     // Add formatting to the cells.
+    ///     worksheet.set_range_format(1, 1, 3, 5, &border)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
     /// ```
     ///
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_range_format.png">
     ///
-    #[cfg(feature = "serde")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn serialize<T>(&mut self, data_structure: &T) -> Result<&mut Worksheet, XlsxError>
-    where
-        T: Serialize,
-    {
-        self.serialize_data_structure(data_structure)?;
+    pub fn set_range_format(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // In cascading mode the format is layered with any other formats.
+        if self.cascading_formats {
+            return self
+                .set_cascading_range_format(first_row, first_col, last_row, last_col, format);
+        }
+
+        // Get the index of the format object.
+        let xf_index = self.format_xf_index(format);
+
+        // Insert the format in a new or existing cells.
+        for row in first_row..=last_row {
+            for col in first_col..=last_col {
+                self.insert_cell_format(row, col, xf_index);
+            }
+        }
 
         Ok(self)
     }
 
-    /// Write the location and headers for data serialization.
+    /// Add formatting to a range of cells with an external border.
     ///
-    /// The [`Worksheet::serialize()`] method, above, serializes Serde derived
-    /// structs to worksheet cells. However, before you serialize the data you
-    /// need to set the position in the worksheet where the headers will be
-    /// written and where serialized data will be written.
+    /// This method is similar to the  [`Worksheet::set_range_format()`] method
+    /// (see above) except it also adds a border around the cell range.
     ///
-    /// See [Setting serialization
-    /// headers](crate::serializer#setting-serialization-headers) for more
-    /// information.
+    /// Add a border around a range of cells in Excel is generally easy to do
+    /// using the GUI interface. However, creating a border around a range of
+    /// cells programmatically is much harder since it requires the creation of
+    /// up to 9 separate formats and the tracking of where cells are relative to
+    /// the border.
     ///
-    /// See also [`Worksheet::deserialize_headers()`] which only requires the
-    /// serializable type and not an actual instance. That method requires that
-    /// your struct also derives "Deserialize".
+    /// The `set_range_format_with_border()` method is provided to simplify this
+    /// task. It allows you to specify one format for the cells and another for
+    /// the border.
+    ///
+    /// You should also consider formatting a range of cells as a Worksheet
+    /// Table may be a better option than simple cell formatting (see the
+    /// [`Table`] section of the documentation).
+    ///
+    /// Note, this method should **not** be used to set the formatting for an
+    /// entire worksheet since it would add an XML element for each of the 34
+    /// billion cells in the worksheet which would result in a very large and
+    /// slow output file. To set the format for the entire worksheet see the
+    /// [`Worksheet::set_column_range_format()`] method.
     ///
     /// # Parameters
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
-    /// - `data_structure`: A reference to a struct that implements the
-    ///   [`serde::Serializer`] trait.
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `cell_format`: The [`Format`] property for the cells in the range. If
+    ///   you don't require internal formatting you can use `Format::default()`.
+    /// - `border_format`: The [`Format`] property for the border. Only the
+    ///   [`Format::set_border()`] and [`Format::set_border_color()`] properties
+    ///   are used.
     ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
-    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
-    ///   of 32,767 characters.
-    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
-    ///   serialization.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates serializing instances of a Serde
-    /// derived data structure to a worksheet.
-    ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_serialize_headers1.rs
-    /// #
-    /// use rust_xlsxwriter::{Workbook, XlsxError};
-    /// use serde::Serialize;
-    ///
-    /// fn main() -> Result<(), XlsxError> {
-    ///     let mut workbook = Workbook::new();
-    ///
-    ///     // Add a worksheet to the workbook.
-    ///     let worksheet = workbook.add_worksheet();
-    ///
-    ///     // Create a serializable struct.
-    ///     #[derive(Serialize)]
-    ///     #[serde(rename_all = "PascalCase")]
-    ///     struct Produce {
-    ///         fruit: &'static str,
-    ///         cost: f64,
-    ///     }
-    ///
-    ///     // Create some data instances.
-    ///     let item1 = Produce {
-    ///         fruit: "Peach",
-    ///         cost: 1.05,
-    ///     };
-    ///     let item2 = Produce {
-    ///         fruit: "Plum",
-    ///         cost: 0.15,
-    ///     };
-    ///     let item3 = Produce {
-    ///         fruit: "Pear",
-    ///         cost: 0.75,
-    ///     };
-    ///
-    ///     // Set up the start location and headers of the data to be serialized using
-    ///     // any temporary or valid instance.
-    ///     worksheet.serialize_headers(0, 0, &item1)?;
-    ///
-    ///     // Serialize the data.
-    ///     worksheet.serialize(&item1)?;
-    ///     worksheet.serialize(&item2)?;
-    ///     worksheet.serialize(&item3)?;
-    ///
-    ///     // Save the file.
-    ///     workbook.save("serialize.xlsx")?;
-    ///
-    ///     Ok(())
-    /// }
-    /// ```
-    ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers1.png">
-    ///
-    /// You can serialize the data to any valid region of the worksheet:
+    /// The following example demonstrates setting the format of worksheet cells
+    /// separately from writing the cell data.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_serialize_headers2.rs
+    /// # // This code is available in examples/doc_worksheet_set_range_format_with_border.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
-    /// # use serde::Serialize;
+    /// # use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Create a serializable struct.
-    /// #     #[derive(Serialize)]
-    /// #     #[serde(rename_all = "PascalCase")]
-    /// #     struct Produce {
-    /// #         fruit: &'static str,
-    /// #         cost: f64,
-    /// #     }
-    /// #
-    /// #     // Create some data instances.
-    /// #     let item1 = Produce {
-    /// #         fruit: "Peach",
-    /// #         cost: 1.05,
-    /// #     };
-    /// #     let item2 = Produce {
-    /// #         fruit: "Plum",
-    /// #         cost: 0.15,
-    /// #     };
-    /// #     let item3 = Produce {
-    /// #         fruit: "Pear",
-    /// #         cost: 0.75,
-    /// #     };
-    /// #
-    /// #     // Set up the start location and headers of the data to be serialized using
-    /// #     // any temporary or valid instance.
-    ///     worksheet.serialize_headers(1, 2, &item1)?;
-    /// #
-    /// #   // Serialize the data.
-    /// #   worksheet.serialize(&item1)?;
-    /// #   worksheet.serialize(&item2)?;
-    /// #   worksheet.serialize(&item3)?;
+    ///     // Add some formats.
+    ///     let inner_border = Format::new().set_border(FormatBorder::Thin);
+    ///     let outer_border = Format::new().set_border(FormatBorder::Double);
+    ///
+    ///     // Write an array of data.
+    ///     let data = [
+    ///         [10, 11, 12, 13, 14],
+    ///         [20, 21, 22, 23, 24],
+    ///         [30, 31, 32, 33, 34],
+    ///     ];
+    ///     worksheet.write_row_matrix(1, 1, data)?;
+    ///
+    ///     // Add formatting to the cells.
+    ///     worksheet.set_range_format_with_border(1, 1, 3, 5, &inner_border, &outer_border)?;
     /// #
-    /// #     // Save the file.
-    /// #     workbook.save("serialize.xlsx")?;
+    /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
@@ -10932,300 +11783,344 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers2.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_range_format_with_border.png">
     ///
-    #[cfg(feature = "serde")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn serialize_headers<T>(
+    pub fn set_range_format_with_border(
         &mut self,
-        row: RowNum,
-        col: ColNum,
-        data_structure: &T,
-    ) -> Result<&mut Worksheet, XlsxError>
-    where
-        T: Serialize,
-    {
-        self.serialize_headers_with_format(row, col, data_structure, &Format::default())
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        cell_format: &Format,
+        border_format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        if first_row == last_row && first_col == last_col {
+            self.set_range_border_cell(first_row, first_col, cell_format, border_format)?;
+        } else if first_row == last_row {
+            self.set_range_border_row(first_row, first_col, last_col, cell_format, border_format)?;
+        } else if first_col == last_col {
+            self.set_range_border_col(first_row, last_row, first_col, cell_format, border_format)?;
+        } else {
+            self.set_range_border_range(
+                first_row,
+                last_row,
+                first_col,
+                last_col,
+                cell_format,
+                border_format,
+            )?;
+        }
+
+        Ok(self)
     }
 
-    /// Write the location and headers for data serialization, with formatting.
-    ///
-    /// The [`Worksheet::serialize()`] method, above, serializes Serde derived
-    /// structs to worksheet cells. However, before you serialize the data you
-    /// need to set the position in the worksheet where the headers will be
-    /// written and where serialized data will be written. This method also
-    /// allows you to set the format for the headers.
-    ///
-    /// See [Setting serialization
-    /// headers](crate::serializer#setting-serialization-headers) for more
-    /// information.
-    ///
-    /// See also [`Worksheet::deserialize_headers_with_format()`] which only
-    /// requires the serializable type and not an actual instance. That method
-    /// requires that your struct also derives "Deserialize".
+    /// Combine column, row, range and cell formats when data is written.
+    ///
+    /// In Excel a cell has a single format. If a cell has its own format then
+    /// any row or column format is ignored for that cell. For example if a row
+    /// has a background color via [`Worksheet::set_row_format()`] and a cell in
+    /// the row is written with a bold format then the cell will be bold but it
+    /// won't have the row background color.
+    ///
+    /// The `set_cascading_formats()` method enables a mode where the effective
+    /// format of a cell is built by layering the following formats, from the
+    /// highest to the lowest precedence:
+    ///
+    /// 1. The cell format used with methods like
+    ///    [`Worksheet::write_with_format()`].
+    /// 2. Range formats added with [`Worksheet::set_range_format()`] or
+    ///    [`Worksheet::set_cell_format()`]. Later ranges take precedence over
+    ///    earlier overlapping ranges.
+    /// 3. The row format added with [`Worksheet::set_row_format()`].
+    /// 4. The column format added with [`Worksheet::set_column_format()`].
+    ///
+    /// The formats are combined using [`Format::merge()`] so a property that
+    /// is set in a higher precedence format overrides the same property in a
+    /// lower precedence format. The combined formats are deduplicated in the
+    /// same way as any other format.
+    ///
+    /// The mode should be enabled before any data or formats are added to the
+    /// worksheet. The effective format is updated for existing cells when a
+    /// row, column or range format is added. However, for worksheets created
+    /// with
+    /// [`Workbook::add_worksheet_with_constant_memory()`](crate::Workbook::add_worksheet_with_constant_memory)
+    /// only cells in the current row can be updated.
+    ///
+    /// Cells copied with [`Worksheet::copy_range()`],
+    /// [`Worksheet::move_range()`] or [`Worksheet::paste_range()`] keep their
+    /// cell and range formats so that they are layered with the row and column
+    /// formats at the new position when these are changed. The row and column
+    /// formats of the original position aren't copied as separate layers, they
+    /// are only part of the effective format of the copied cells.
     ///
     /// # Parameters
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
-    /// - `data_structure`: A reference to a struct that implements the
-    ///   [`serde::Serializer`] trait.
-    /// - `format`: The [`Format`] property for the cell.
-    ///
-    /// # Errors
-    ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
-    ///   of 32,767 characters.
-    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
-    ///   serialization.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates serializing instances of a Serde derived
-    /// data structure to a worksheet.
+    /// The following example demonstrates combining row and cell formats.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_serialize_headers4.rs
+    /// # // This code is available in examples/doc_worksheet_set_cascading_formats.rs
     /// #
-    /// use rust_xlsxwriter::{Format, Workbook, XlsxError};
-    /// use serde::Serialize;
-    ///
-    /// fn main() -> Result<(), XlsxError> {
-    ///     let mut workbook = Workbook::new();
-    ///
-    ///     // Add a worksheet to the workbook.
-    ///     let worksheet = workbook.add_worksheet();
-    ///
-    ///     // Add a simple format for the headers.
-    ///     let format = Format::new().set_bold();
-    ///
-    ///     // Create a serializable struct.
-    ///     #[derive(Serialize)]
-    ///     #[serde(rename_all = "PascalCase")]
-    ///     struct Produce {
-    ///         fruit: &'static str,
-    ///         cost: f64,
-    ///     }
-    ///
-    ///     // Create some data instances.
-    ///     let item1 = Produce {
-    ///         fruit: "Peach",
-    ///         cost: 1.05,
-    ///     };
-    ///     let item2 = Produce {
-    ///         fruit: "Plum",
-    ///         cost: 0.15,
-    ///     };
-    ///     let item3 = Produce {
-    ///         fruit: "Pear",
-    ///         cost: 0.75,
-    ///     };
+    /// # use rust_xlsxwriter::{Color, Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Turn on format cascading.
+    ///     worksheet.set_cascading_formats(true);
     ///
-    ///     // Set up the start location and headers of the data to be serialized using
-    ///     // any temporary or valid instance.
-    ///     worksheet.serialize_headers_with_format(0, 0, &item1, &format)?;
+    ///     // Add some formats.
+    ///     let row_format = Format::new().set_background_color(Color::Yellow);
+    ///     let bold_format = Format::new().set_bold();
     ///
-    ///     // Serialize the data.
-    ///     worksheet.serialize(&item1)?;
-    ///     worksheet.serialize(&item2)?;
-    ///     worksheet.serialize(&item3)?;
+    ///     // Set the row format.
+    ///     worksheet.set_row_format(1, &row_format)?;
     ///
-    ///     // Save the file.
-    ///     workbook.save("serialize.xlsx")?;
+    ///     // Add some unformatted text that adopts the row format.
+    ///     worksheet.write_string(1, 0, "Hello")?;
     ///
-    ///     Ok(())
-    /// }
+    ///     // Add some bold text that also keeps the row background.
+    ///     worksheet.write_string_with_format(1, 2, "Hello", &bold_format)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
     /// ```
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize.png">
     ///
-    #[cfg(feature = "serde")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn serialize_headers_with_format<T>(
-        &mut self,
-        row: RowNum,
-        col: ColNum,
-        data_structure: &T,
-        format: &Format,
-    ) -> Result<&mut Worksheet, XlsxError>
-    where
-        T: Serialize,
-    {
-        // Serialize the struct to determine the type name and the fields.
-        let mut headers = SerializerHeader {
-            struct_name: String::new(),
-            field_names: vec![],
-        };
-
-        data_structure.serialize(&mut headers)?;
-
-        self.store_serialization_headers(row, col, &headers, format)
+    pub fn set_cascading_formats(&mut self, enable: bool) -> &mut Worksheet {
+        self.cascading_formats = enable;
+        self
     }
 
-    /// Write the location and headers for data serialization, with additional
-    /// options.
-    ///
-    /// The [`Worksheet::serialize()`] and
-    /// [`Worksheet::serialize_headers_with_format()`] methods, above, set the
-    /// serialization headers and location via an instance of the structure to
-    /// be serialized. This will work for the majority of use cases, and for
-    /// other cases you can adjust the output by using Serde Container or Field
-    /// [Attributes]. See [Working with
-    /// Serde](crate::serializer#working-with-serde).
+    /// Clear the data and formatting from a worksheet cell.
     ///
-    /// [Attributes]: https://serde.rs/attributes.html
+    /// This method can be used to clear data and formatting previously written
+    /// to a worksheet cell using one of the worksheet `write()` methods.
     ///
-    /// If these methods don't give you the output or flexibility you require
-    /// you can use the `serialize_headers_with_options()` method with
-    /// [`SerializeFieldOptions`] and [`CustomSerializeField`] options. This
-    /// allows you to reorder, rename, format or skip headers and also define
-    /// formatting for field values.
+    /// This can occasionally be useful for scenarios where it is easier to add
+    /// data in bulk but then remove certain elements.
     ///
-    /// See [`SerializeFieldOptions`] and [`CustomSerializeField`] for
-    /// additional information and examples.
+    /// This method only clears data, it doesn't clear images or conditional
+    /// formatting, or other non-data elements.
     ///
-    /// See also [`Worksheet::deserialize_headers_with_options()`] which only
-    /// requires the serializable type and not an actual instance. That method
-    /// requires that your struct also derives "Deserialize".
+    /// Note, this method doesn't return a [`Result`] or errors. Instructions to
+    /// clear non-existent cells are simply ignored.
     ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
     /// - `col`: The zero indexed column number.
-    /// - `data_structure`: A reference to a struct that implements the
-    ///   [`serde::Serializer`] trait.
-    /// - `header_options`: A [`SerializeFieldOptions`] instance.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
-    ///   of 32,767 characters.
-    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
-    ///   serialization.
+    /// The following example demonstrates clearing some previously written cell
+    /// data and formatting from a worksheet.
     ///
-    /// # Examples
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_clear_cell.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add a format.
+    /// #     let format = Format::new().set_font_color("#FF0000");
+    /// #
+    ///     // Some array data to write.
+    ///     let data = [
+    ///         [10, 11, 12, 13, 14],
+    ///         [20, 21, 22, 23, 24],
+    ///         [30, 31, 32, 33, 34],
+    ///     ];
     ///
-    /// The following example demonstrates serializing instances of a Serde
-    /// derived data structure to a worksheet.
+    ///     // Write the array data as a series of rows.
+    ///     worksheet.write_row_with_format(0, 0, data[0], &format)?;
+    ///     worksheet.write_row_with_format(1, 0, data[1], &format)?;
+    ///     worksheet.write_row_with_format(2, 0, data[2], &format)?;
     ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_serialize_headers_with_options.rs
+    ///     // Clear the first and last cell in the written data.
+    ///     worksheet.clear_cell(0, 0);
+    ///     worksheet.clear_cell(2, 4);
     /// #
-    /// use rust_xlsxwriter::{
-    ///     CustomSerializeField, Format, SerializeFieldOptions, Workbook, XlsxError
-    /// };
-    /// use serde::Serialize;
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
     ///
-    /// fn main() -> Result<(), XlsxError> {
-    ///     let mut workbook = Workbook::new();
+    /// Output file:
     ///
-    ///     // Add a worksheet to the workbook.
-    ///     let worksheet = workbook.add_worksheet();
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_clear_cell.png">
     ///
-    ///     // Add some formats to use with the serialization data.
-    ///     let bold = Format::new().set_bold();
-    ///     let currency = Format::new().set_num_format("$0.00");
+    pub fn clear_cell(&mut self, row: RowNum, col: ColNum) -> &mut Worksheet {
+        // If cell is outside the allowed range it doesn't need to be cleared.
+        if !self.check_dimensions_only(row, col) {
+            return self;
+        }
+
+        self.clear_cell_internal(row, col);
+
+        self
+    }
+
+    /// Clear the formatting from a worksheet cell.
     ///
-    ///     // Create a serializable struct.
-    ///     #[derive(Serialize)]
-    ///     struct Produce {
-    ///         fruit: &'static str,
-    ///         cost: f64,
-    ///     }
+    /// This method can be used to clear the formatting previously added to a
+    /// worksheet cell using one of the worksheet `write_with_format()` methods.
     ///
-    ///     // Create some data instances.
-    ///     let items = [
-    ///         Produce {
-    ///             fruit: "Peach",
-    ///             cost: 1.05,
-    ///         },
-    ///         Produce {
-    ///             fruit: "Plum",
-    ///             cost: 0.15,
-    ///         },
-    ///         Produce {
-    ///             fruit: "Pear",
-    ///             cost: 0.75,
-    ///         },
-    ///     ];
+    /// This can occasionally be useful for scenarios where it is easier to add
+    /// formatted data in bulk but then remove the formatting from certain
+    /// elements.
     ///
-    ///     // Set up the start location and headers of the data to be serialized using
-    ///     // custom headers.
-    ///     let custom_headers = [
-    ///         CustomSerializeField::new("fruit")
-    ///             .rename("Fruit"),
-    ///         CustomSerializeField::new("cost")
-    ///             .rename("Price")
-    ///             .set_value_format(currency),
-    ///     ];
-    ///     let header_options = SerializeFieldOptions::new()
-    ///         .set_header_format(bold)
-    ///         .set_custom_headers(&custom_headers);
+    /// See also the [`Worksheet::set_cell_format()`] method for a similar
+    /// method to change the format of a cell.
     ///
-    ///     worksheet.serialize_headers_with_options(0, 0, &items[0], &header_options)?;
+    /// Note, this method doesn't return a [`Result`] or errors. Instructions to
+    /// clear non-existent cells are simply ignored.
     ///
-    ///     // Serialize the data.
-    ///     worksheet.serialize(&items)?;
+    /// # Parameters
     ///
-    ///     // Save the file.
-    ///     workbook.save("serialize.xlsx")?;
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
     ///
-    ///     Ok(())
-    /// }
+    /// # Examples
+    ///
+    /// The following example demonstrates clearing the formatting from some
+    /// previously written cells in a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_clear_cell_format.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add a format.
+    /// #     let format = Format::new().set_font_color("#FF0000");
+    /// #
+    ///     // Some array data to write.
+    ///     let data = [
+    ///         [10, 11, 12, 13, 14],
+    ///         [20, 21, 22, 23, 24],
+    ///         [30, 31, 32, 33, 34],
+    ///     ];
+    ///
+    ///     // Write the array data as a series of rows.
+    ///     worksheet.write_row_with_format(0, 0, data[0], &format)?;
+    ///     worksheet.write_row_with_format(1, 0, data[1], &format)?;
+    ///     worksheet.write_row_with_format(2, 0, data[2], &format)?;
+    ///
+    ///     // Clear the format from the first and last cells in the data.
+    ///     worksheet.clear_cell_format(0, 0);
+    ///     worksheet.clear_cell_format(2, 4);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
     /// ```
     ///
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers_with_options.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_clear_cell_format.png">
     ///
-    #[cfg(feature = "serde")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn serialize_headers_with_options<T>(
-        &mut self,
-        row: RowNum,
-        col: ColNum,
-        data_structure: &T,
-        header_options: &SerializeFieldOptions,
-    ) -> Result<&mut Worksheet, XlsxError>
-    where
-        T: Serialize,
-    {
-        // Serialize the struct to determine the type name and the fields.
-        let mut headers = SerializerHeader {
-            struct_name: String::new(),
-            field_names: vec![],
-        };
+    pub fn clear_cell_format(&mut self, row: RowNum, col: ColNum) -> &mut Worksheet {
+        // If cell is outside the allowed range it doesn't need to be cleared.
+        if !self.check_dimensions_only(row, col) {
+            return self;
+        }
 
-        data_structure.serialize(&mut headers)?;
-        self.store_serialization_headers_with_options(row, col, &headers, header_options)
+        self.clear_cell_format_internal(row, col);
+
+        self
     }
 
-    /// Write the location and headers for data serialization.
+    // -----------------------------------------------------------------------
+    // Worksheet serde methods.
+    // -----------------------------------------------------------------------
+
+    /// Write a Serde serializable struct to a worksheet.
     ///
-    /// The [`Worksheet::serialize()`] method, above, serializes Serde derived
-    /// structs to worksheet cells. However, before you serialize the data you
-    /// need to set the position in the worksheet where the headers will be
-    /// written and where serialized data will be written.
+    /// This method can be used to serialize [Serde](https://serde.rs) enabled
+    /// data structures into cells in a worksheet.
     ///
-    /// See [Setting serialization
-    /// headers](crate::serializer#setting-serialization-headers) for more
-    /// information.
+    /// See [Working with Serde](crate::serializer#working-with-serde) for
+    /// background details on how serialization works with `rust_xlsxwriter`.
+    ///
+    /// When serializing structs `rust_xlsxwriter` needs to know location where
+    /// the serialization starts and also the type and field names of the struct
+    /// being serialized. The field names are used as headers and the type name
+    /// allows for several distinct structs to be serialized to the same
+    /// worksheet.
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/serialize_intro1.png">
+    ///
+    /// The worksheet methods that perform this function fall into two types:
+    /// methods which use deserialization to find the fields from the *type* and
+    /// methods that use serialization to find the fields from an *instance of
+    /// the type*. The deserialization methods are easier to use but require
+    /// that the struct derives the Serde [`Deserialize`] trait as well as the
+    /// [`Serialize`] trait. The serialization methods work for anything else.
+    ///
+    /// There available methods are.
+    ///
+    /// - [`Worksheet::deserialize_headers()`]: The simplest most direct method.
+    ///   It only requires the type of struct that you wish to serialize and
+    ///   that it derives the [`Deserialize`] and [`Serialize`] traits. The
+    ///   library uses this to infer the struct name and fields (via
+    ///   deserialization).
+    ///
+    /// - [`Worksheet::deserialize_headers_with_format()`]: This is similar to
+    ///   the previous method but it allows you to add a cell format for the
+    ///   headers.
+    ///
+    /// - [`Worksheet::deserialize_headers_with_options()`]: Similar to the
+    ///   previous methods but also allows configuration of the headers and
+    ///   fields via [`SerializeFieldOptions`].
+    ///
+    /// - [`Worksheet::serialize_headers()`]: Similar to the
+    ///   `deserialize_headers()` method but it requires a concrete instance of
+    ///   the type of struct that you wish to serialize. The library uses this
+    ///   to infer the struct name and fields (via serialization). This method
+    ///   only requires that the struct derives [`Serialize`].
+    ///
+    /// Once the headers are set up an subsequent calls to `serialize()` will
+    /// write the struct data in rows beneath the header.
     ///
-    /// See also [`Worksheet::serialize_headers()`] which requires an instance
-    /// of the serializable type but doesn't require that your struct also
-    /// derives "Deserialize".
     ///
     /// # Parameters
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
+    /// - `data_structure`: A reference to a struct that implements the
+    ///   [`serde::Serializer`] trait.
     ///
     /// # Errors
     ///
@@ -11238,13 +12133,13 @@ impl Worksheet {
     ///
     /// # Examples
     ///
-    /// The following example demonstrates serializing instances of a Serde
-    /// derived data structure to a worksheet.
+    /// The following example demonstrates serializing instances of a Serde derived
+    /// data structure to a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_deserialize_headers1.rs
+    /// # // This code is available in examples/doc_worksheet_serialize.rs
     /// #
-    /// use rust_xlsxwriter::{Workbook, XlsxError};
+    /// use rust_xlsxwriter::{Format, Workbook, XlsxError};
     /// use serde::{Deserialize, Serialize};
     ///
     /// fn main() -> Result<(), XlsxError> {
@@ -11253,6 +12148,9 @@ impl Worksheet {
     ///     // Add a worksheet to the workbook.
     ///     let worksheet = workbook.add_worksheet();
     ///
+    ///     // Add a simple format for the headers.
+    ///     let format = Format::new().set_bold();
+    ///
     ///     // Create a serializable struct.
     ///     #[derive(Deserialize, Serialize)]
     ///     #[serde(rename_all = "PascalCase")]
@@ -11276,7 +12174,7 @@ impl Worksheet {
     ///     };
     ///
     ///     // Set up the start location and headers of the data to be serialized.
-    ///     worksheet.deserialize_headers::<Produce>(0, 0)?;
+    ///     worksheet.deserialize_headers_with_format::<Produce>(0, 0, &format)?;
     ///
     ///     // Serialize the data.
     ///     worksheet.serialize(&item1)?;
@@ -11293,42 +12191,40 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers1.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize.png">
     ///
     #[cfg(feature = "serde")]
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn deserialize_headers<'de, T>(
-        &mut self,
-        row: RowNum,
-        col: ColNum,
-    ) -> Result<&mut Worksheet, XlsxError>
+    pub fn serialize<T>(&mut self, data_structure: &T) -> Result<&mut Worksheet, XlsxError>
     where
-        T: Deserialize<'de>,
+        T: Serialize,
     {
-        self.deserialize_headers_with_format::<T>(row, col, &Format::default())
+        self.serialize_data_structure(data_structure)?;
+
+        Ok(self)
     }
 
-    /// Write the location and headers for data serialization, with formatting.
+    /// Write the location and headers for data serialization.
     ///
     /// The [`Worksheet::serialize()`] method, above, serializes Serde derived
     /// structs to worksheet cells. However, before you serialize the data you
     /// need to set the position in the worksheet where the headers will be
-    /// written and where serialized data will be written. This method also
-    /// allows you to set the format for the headers.
+    /// written and where serialized data will be written.
     ///
     /// See [Setting serialization
     /// headers](crate::serializer#setting-serialization-headers) for more
     /// information.
     ///
-    /// See also [`Worksheet::serialize_headers_with_format()`] which requires
-    /// an instance of the serializable type but doesn't require that your
-    /// struct also derives "Deserialize".
+    /// See also [`Worksheet::deserialize_headers()`] which only requires the
+    /// serializable type and not an actual instance. That method requires that
+    /// your struct also derives "Deserialize".
     ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
     /// - `col`: The zero indexed column number.
-    /// - `format`: The [`Format`] property for the cell.
+    /// - `data_structure`: A reference to a struct that implements the
+    ///   [`serde::Serializer`] trait.
     ///
     /// # Errors
     ///
@@ -11345,10 +12241,67 @@ impl Worksheet {
     /// derived data structure to a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_serialize.rs
+    /// # // This code is available in examples/doc_worksheet_serialize_headers1.rs
     /// #
-    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
-    /// # use serde::{Deserialize, Serialize};
+    /// use rust_xlsxwriter::{Workbook, XlsxError};
+    /// use serde::Serialize;
+    ///
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Create a serializable struct.
+    ///     #[derive(Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let item1 = Produce {
+    ///         fruit: "Peach",
+    ///         cost: 1.05,
+    ///     };
+    ///     let item2 = Produce {
+    ///         fruit: "Plum",
+    ///         cost: 0.15,
+    ///     };
+    ///     let item3 = Produce {
+    ///         fruit: "Pear",
+    ///         cost: 0.75,
+    ///     };
+    ///
+    ///     // Set up the start location and headers of the data to be serialized using
+    ///     // any temporary or valid instance.
+    ///     worksheet.serialize_headers(0, 0, &item1)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&item1)?;
+    ///     worksheet.serialize(&item2)?;
+    ///     worksheet.serialize(&item3)?;
+    ///
+    ///     // Save the file.
+    ///     workbook.save("serialize.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers1.png">
+    ///
+    /// You can serialize the data to any valid region of the worksheet:
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_headers2.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use serde::Serialize;
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
@@ -11356,11 +12309,8 @@ impl Worksheet {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Add a simple format for the headers.
-    /// #     let format = Format::new().set_bold();
-    /// #
     /// #     // Create a serializable struct.
-    /// #     #[derive(Deserialize, Serialize)]
+    /// #     #[derive(Serialize)]
     /// #     #[serde(rename_all = "PascalCase")]
     /// #     struct Produce {
     /// #         fruit: &'static str,
@@ -11381,13 +12331,14 @@ impl Worksheet {
     /// #         cost: 0.75,
     /// #     };
     /// #
-    /// #     // Set up the start location and headers of the data to be serialized.
-    /// #     worksheet.deserialize_headers_with_format::<Produce>(0, 0, &format)?;
+    /// #     // Set up the start location and headers of the data to be serialized using
+    /// #     // any temporary or valid instance.
+    ///     worksheet.serialize_headers(1, 2, &item1)?;
     /// #
-    /// #     // Serialize the data.
-    /// #     worksheet.serialize(&item1)?;
-    /// #     worksheet.serialize(&item2)?;
-    /// #     worksheet.serialize(&item3)?;
+    /// #   // Serialize the data.
+    /// #   worksheet.serialize(&item1)?;
+    /// #   worksheet.serialize(&item2)?;
+    /// #   worksheet.serialize(&item3)?;
     /// #
     /// #     // Save the file.
     /// #     workbook.save("serialize.xlsx")?;
@@ -11399,58 +12350,45 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize.png">
-    ///
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers2.png">
     ///
     #[cfg(feature = "serde")]
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn deserialize_headers_with_format<'de, T>(
+    pub fn serialize_headers<T>(
         &mut self,
         row: RowNum,
         col: ColNum,
-        format: &Format,
+        data_structure: &T,
     ) -> Result<&mut Worksheet, XlsxError>
     where
-        T: Deserialize<'de>,
+        T: Serialize,
     {
-        // Deserialize the struct to determine the type name and the fields.
-        let headers = deserialize_headers::<T>();
-
-        self.store_serialization_headers(row, col, &headers, format)
+        self.serialize_headers_with_format(row, col, data_structure, &Format::default())
     }
 
-    /// Write the location and headers for data serialization, with additional
-    /// options.
-    ///
-    /// The [`Worksheet::serialize()`] and
-    /// [`Worksheet::deserialize_headers_with_format()`] methods, above, set the
-    /// serialization headers and location via an instance of the structure to
-    /// be serialized. This will work for the majority of use cases, and for
-    /// other cases you can adjust the output by using Serde Container or Field
-    /// [Attributes]. [Working with
-    /// Serde](crate::serializer#working-with-serde).
-    ///
-    /// [Attributes]: https://serde.rs/attributes.html
-    ///
-    /// If these methods don't give you the output or flexibility you require
-    /// you can use the `deserialize_headers_with_options()` method with
-    /// [`SerializeFieldOptions`] and [`CustomSerializeField`] options. This
-    /// allows you to reorder, rename, format or skip headers and also define
-    /// formatting for field values.
+    /// Write the location and headers for data serialization, with formatting.
     ///
-    /// See [`SerializeFieldOptions`] and [`CustomSerializeField`] for
-    /// additional information and examples.
+    /// The [`Worksheet::serialize()`] method, above, serializes Serde derived
+    /// structs to worksheet cells. However, before you serialize the data you
+    /// need to set the position in the worksheet where the headers will be
+    /// written and where serialized data will be written. This method also
+    /// allows you to set the format for the headers.
     ///
-    /// See also [`Worksheet::serialize_headers_with_options()`] which requires
-    /// an instance of the serializable type but doesn't require that your
-    /// struct also derives "Deserialize".
+    /// See [Setting serialization
+    /// headers](crate::serializer#setting-serialization-headers) for more
+    /// information.
     ///
+    /// See also [`Worksheet::deserialize_headers_with_format()`] which only
+    /// requires the serializable type and not an actual instance. That method
+    /// requires that your struct also derives "Deserialize".
     ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
     /// - `col`: The zero indexed column number.
-    /// - `header_options`: A [`SerializeFieldOptions`] instance.
+    /// - `data_structure`: A reference to a struct that implements the
+    ///   [`serde::Serializer`] trait.
+    /// - `format`: The [`Format`] property for the cell.
     ///
     /// # Errors
     ///
@@ -11463,16 +12401,14 @@ impl Worksheet {
     ///
     /// # Examples
     ///
-    /// The following example demonstrates serializing instances of a Serde
-    /// derived data structure to a worksheet.
+    /// The following example demonstrates serializing instances of a Serde derived
+    /// data structure to a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_serialize_headers_with_options2.rs
+    /// # // This code is available in examples/doc_worksheet_serialize_headers4.rs
     /// #
-    /// use rust_xlsxwriter::{
-    ///     CustomSerializeField, Format, SerializeFieldOptions, Workbook, XlsxError
-    /// };
-    /// use serde::{Deserialize, Serialize};
+    /// use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// use serde::Serialize;
     ///
     /// fn main() -> Result<(), XlsxError> {
     ///     let mut workbook = Workbook::new();
@@ -11480,50 +12416,39 @@ impl Worksheet {
     ///     // Add a worksheet to the workbook.
     ///     let worksheet = workbook.add_worksheet();
     ///
-    ///     // Add some formats to use with the serialization data.
-    ///     let bold = Format::new().set_bold();
-    ///     let currency = Format::new().set_num_format("$0.00");
+    ///     // Add a simple format for the headers.
+    ///     let format = Format::new().set_bold();
     ///
     ///     // Create a serializable struct.
-    ///     #[derive(Deserialize, Serialize)]
+    ///     #[derive(Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
     ///     struct Produce {
     ///         fruit: &'static str,
     ///         cost: f64,
     ///     }
     ///
     ///     // Create some data instances.
-    ///     let items = [
-    ///         Produce {
-    ///             fruit: "Peach",
-    ///             cost: 1.05,
-    ///         },
-    ///         Produce {
-    ///             fruit: "Plum",
-    ///             cost: 0.15,
-    ///         },
-    ///         Produce {
-    ///             fruit: "Pear",
-    ///             cost: 0.75,
-    ///         },
-    ///     ];
+    ///     let item1 = Produce {
+    ///         fruit: "Peach",
+    ///         cost: 1.05,
+    ///     };
+    ///     let item2 = Produce {
+    ///         fruit: "Plum",
+    ///         cost: 0.15,
+    ///     };
+    ///     let item3 = Produce {
+    ///         fruit: "Pear",
+    ///         cost: 0.75,
+    ///     };
     ///
     ///     // Set up the start location and headers of the data to be serialized using
-    ///     // custom headers.
-    ///     let custom_headers = [
-    ///         CustomSerializeField::new("fruit")
-    ///             .rename("Fruit"),
-    ///         CustomSerializeField::new("cost")
-    ///             .rename("Price")
-    ///             .set_value_format(currency),
-    ///     ];
-    ///     let header_options = SerializeFieldOptions::new()
-    ///         .set_header_format(bold)
-    ///         .set_custom_headers(&custom_headers);
-    ///
-    ///     worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+    ///     // any temporary or valid instance.
+    ///     worksheet.serialize_headers_with_format(0, 0, &item1, &format)?;
     ///
     ///     // Serialize the data.
-    ///     worksheet.serialize(&items)?;
+    ///     worksheet.serialize(&item1)?;
+    ///     worksheet.serialize(&item2)?;
+    ///     worksheet.serialize(&item3)?;
     ///
     ///     // Save the file.
     ///     workbook.save("serialize.xlsx")?;
@@ -11531,27 +12456,173 @@ impl Worksheet {
     ///     Ok(())
     /// }
     /// ```
-    ///
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers_with_options.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize.png">
     ///
     #[cfg(feature = "serde")]
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn deserialize_headers_with_options<'de, T>(
+    pub fn serialize_headers_with_format<T>(
         &mut self,
         row: RowNum,
         col: ColNum,
-        header_options: &SerializeFieldOptions,
+        data_structure: &T,
+        format: &Format,
     ) -> Result<&mut Worksheet, XlsxError>
     where
-        T: Deserialize<'de>,
+        T: Serialize,
     {
-        // Deserialize the struct to determine the type name and the fields.
-        let headers = deserialize_headers::<T>();
+        // Serialize the struct to determine the type name and the fields.
+        let mut headers = SerializerHeader {
+            struct_name: String::new(),
+            field_names: vec![],
+        };
 
-        self.store_serialization_headers_with_options(row, col, &headers, header_options)
+        data_structure.serialize(&mut headers)?;
+
+        self.store_serialization_headers(row, col, &headers, format)
+    }
+
+    /// Write the location and headers for data serialization, with additional
+    /// options.
+    ///
+    /// The [`Worksheet::serialize()`] and
+    /// [`Worksheet::serialize_headers_with_format()`] methods, above, set the
+    /// serialization headers and location via an instance of the structure to
+    /// be serialized. This will work for the majority of use cases, and for
+    /// other cases you can adjust the output by using Serde Container or Field
+    /// [Attributes]. See [Working with
+    /// Serde](crate::serializer#working-with-serde).
+    ///
+    /// [Attributes]: https://serde.rs/attributes.html
+    ///
+    /// If these methods don't give you the output or flexibility you require
+    /// you can use the `serialize_headers_with_options()` method with
+    /// [`SerializeFieldOptions`] and [`CustomSerializeField`] options. This
+    /// allows you to reorder, rename, format or skip headers and also define
+    /// formatting for field values.
+    ///
+    /// See [`SerializeFieldOptions`] and [`CustomSerializeField`] for
+    /// additional information and examples.
+    ///
+    /// See also [`Worksheet::deserialize_headers_with_options()`] which only
+    /// requires the serializable type and not an actual instance. That method
+    /// requires that your struct also derives "Deserialize".
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `data_structure`: A reference to a struct that implements the
+    ///   [`serde::Serializer`] trait.
+    /// - `header_options`: A [`SerializeFieldOptions`] instance.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
+    ///   serialization.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing instances of a Serde
+    /// derived data structure to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_headers_with_options.rs
+    /// #
+    /// use rust_xlsxwriter::{
+    ///     CustomSerializeField, Format, SerializeFieldOptions, Workbook, XlsxError
+    /// };
+    /// use serde::Serialize;
+    ///
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Add some formats to use with the serialization data.
+    ///     let bold = Format::new().set_bold();
+    ///     let currency = Format::new().set_num_format("$0.00");
+    ///
+    ///     // Create a serializable struct.
+    ///     #[derive(Serialize)]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let items = [
+    ///         Produce {
+    ///             fruit: "Peach",
+    ///             cost: 1.05,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Plum",
+    ///             cost: 0.15,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Pear",
+    ///             cost: 0.75,
+    ///         },
+    ///     ];
+    ///
+    ///     // Set up the start location and headers of the data to be serialized using
+    ///     // custom headers.
+    ///     let custom_headers = [
+    ///         CustomSerializeField::new("fruit")
+    ///             .rename("Fruit"),
+    ///         CustomSerializeField::new("cost")
+    ///             .rename("Price")
+    ///             .set_value_format(currency),
+    ///     ];
+    ///     let header_options = SerializeFieldOptions::new()
+    ///         .set_header_format(bold)
+    ///         .set_custom_headers(&custom_headers);
+    ///
+    ///     worksheet.serialize_headers_with_options(0, 0, &items[0], &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&items)?;
+    ///
+    ///     // Save the file.
+    ///     workbook.save("serialize.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers_with_options.png">
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialize_headers_with_options<T>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data_structure: &T,
+        header_options: &SerializeFieldOptions,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: Serialize,
+    {
+        // Serialize the struct to determine the type name and the fields.
+        let mut headers = SerializerHeader {
+            struct_name: String::new(),
+            field_names: vec![],
+        };
+
+        data_structure.serialize(&mut headers)?;
+        self.store_serialization_headers_with_options(row, col, &headers, header_options)
     }
 
     /// Write the location and headers for data serialization.
@@ -11567,8 +12638,7 @@ impl Worksheet {
     ///
     /// See also [`Worksheet::serialize_headers()`] which requires an instance
     /// of the serializable type but doesn't require that your struct also
-    /// derives `Deserialize`, and [`Worksheet::deserialize_headers()`] which
-    /// does.
+    /// derives "Deserialize".
     ///
     /// # Parameters
     ///
@@ -11586,11 +12656,14 @@ impl Worksheet {
     ///
     /// # Examples
     ///
+    /// The following example demonstrates serializing instances of a Serde
+    /// derived data structure to a worksheet.
+    ///
     /// ```
-    /// # // This code is available in examples/doc_xlsxserialize_intro.rs
+    /// # // This code is available in examples/doc_worksheet_deserialize_headers1.rs
     /// #
-    /// use rust_xlsxwriter::{Workbook, XlsxError, XlsxSerialize};
-    /// use serde::Serialize;
+    /// use rust_xlsxwriter::{Workbook, XlsxError};
+    /// use serde::{Deserialize, Serialize};
     ///
     /// fn main() -> Result<(), XlsxError> {
     ///     let mut workbook = Workbook::new();
@@ -11599,14 +12672,10 @@ impl Worksheet {
     ///     let worksheet = workbook.add_worksheet();
     ///
     ///     // Create a serializable struct.
-    ///     #[derive(XlsxSerialize, Serialize)]
-    ///     #[xlsx(header_format = Format::new().set_bold())]
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
     ///     struct Produce {
-    ///         #[xlsx(rename = "Item")]
-    ///         #[xlsx(column_width = 12.0)]
     ///         fruit: &'static str,
-    ///
-    ///         #[xlsx(rename = "Price", num_format = "$0.00")]
     ///         cost: f64,
     ///     }
     ///
@@ -11615,26 +12684,24 @@ impl Worksheet {
     ///         fruit: "Peach",
     ///         cost: 1.05,
     ///     };
-    ///
     ///     let item2 = Produce {
     ///         fruit: "Plum",
     ///         cost: 0.15,
     ///     };
-    ///
     ///     let item3 = Produce {
     ///         fruit: "Pear",
     ///         cost: 0.75,
     ///     };
     ///
-    ///     // Set the serialization location and headers.
-    ///     worksheet.set_serialize_headers::<Produce>(0, 0)?;
+    ///     // Set up the start location and headers of the data to be serialized.
+    ///     worksheet.deserialize_headers::<Produce>(0, 0)?;
     ///
     ///     // Serialize the data.
     ///     worksheet.serialize(&item1)?;
     ///     worksheet.serialize(&item2)?;
     ///     worksheet.serialize(&item3)?;
     ///
-    ///     // Save the file to disk.
+    ///     // Save the file.
     ///     workbook.save("serialize.xlsx")?;
     ///
     ///     Ok(())
@@ -11643,54 +12710,63 @@ impl Worksheet {
     ///
     /// Output file:
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/xlsxserialize_intro.png">
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers1.png">
+    ///
     #[cfg(feature = "serde")]
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn set_serialize_headers<T>(
+    pub fn deserialize_headers<'de, T>(
         &mut self,
         row: RowNum,
         col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError>
     where
-        T: XlsxSerialize + Serialize,
+        T: Deserialize<'de>,
     {
-        let header_options = T::to_serialize_field_options();
-        self.store_custom_serialization_headers(row, col, &header_options)
+        self.deserialize_headers_with_format::<T>(row, col, &Format::default())
     }
 
-    /// Get the row/column dimensions of a serialized area.
+    /// Write the location and headers for data serialization, with formatting.
     ///
-    /// When serializing data it generally isn't necessary to track the row and
-    /// column range of the cells that are written since `rust_xlsxwriter` does
-    /// it automatically. However, it is sometimes useful to know the range of
-    /// the serialization after the data is written in order to refer to it in
-    /// another function such as a conditional format or a chart.
+    /// The [`Worksheet::serialize()`] method, above, serializes Serde derived
+    /// structs to worksheet cells. However, before you serialize the data you
+    /// need to set the position in the worksheet where the headers will be
+    /// written and where serialized data will be written. This method also
+    /// allows you to set the format for the headers.
     ///
-    /// The `get_serialize_dimensions()` function returns the row/column
-    /// dimensions of a serialized area for use cases where you need to know the
-    /// range of the data that was written. The dimensions are returned as a
-    /// `(min_row, min_col, max_row, max_col)` tuple in a `Result<>`.
+    /// See [Setting serialization
+    /// headers](crate::serializer#setting-serialization-headers) for more
+    /// information.
+    ///
+    /// See also [`Worksheet::serialize_headers_with_format()`] which requires
+    /// an instance of the serializable type but doesn't require that your
+    /// struct also derives "Deserialize".
     ///
     /// # Parameters
     ///
-    /// - `struct_name`: The name/type of the target struct as a string.
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `format`: The [`Format`] property for the cell.
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::ParameterError`] - Unknown or unserialized struct name.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
+    ///   serialization.
     ///
     /// # Examples
     ///
-    /// Example of getting the dimensions of some serialized data. In this
-    /// example we use the dimensions to set a conditional format range.
+    /// The following example demonstrates serializing instances of a Serde
+    /// derived data structure to a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_serialize_dimensions1.rs
+    /// # // This code is available in examples/doc_worksheet_serialize.rs
     /// #
-    /// # use rust_xlsxwriter::{
-    /// #     ConditionalFormatCell, ConditionalFormatCellRule, Format, Workbook, XlsxError,
-    /// # };
-    /// # use serde::Serialize;
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
@@ -11698,60 +12774,38 @@ impl Worksheet {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Create a serializable struct.
-    ///     #[derive(Serialize)]
-    ///     #[serde(rename_all = "PascalCase")]
-    ///     struct MyStruct {
-    ///         col1: u8,
-    ///         col2: u8,
-    ///         col3: u8,
-    ///         col4: u8,
-    ///     }
-    ///
-    ///     // Create some sample data.
-    /// #     #[rustfmt::skip]
-    ///     let data = [
-    ///         MyStruct {col1: 34,  col2: 73, col3: 39, col4: 32},
-    ///         MyStruct {col1: 5,   col2: 24, col3: 1,  col4: 84},
-    ///         MyStruct {col1: 28,  col2: 79, col3: 97, col4: 13},
-    ///         MyStruct {col1: 27,  col2: 71, col3: 40, col4: 17},
-    ///         MyStruct {col1: 88,  col2: 25, col3: 33, col4: 23},
-    ///         MyStruct {col1: 23,  col2: 99, col3: 20, col4: 88},
-    ///         MyStruct {col1: 7,   col2: 57, col3: 88, col4: 28},
-    ///         MyStruct {col1: 53,  col2: 78, col3: 1,  col4: 96},
-    ///         MyStruct {col1: 60,  col2: 54, col3: 81, col4: 66},
-    ///         MyStruct {col1: 70,  col2: 5,  col3: 46, col4: 14},
-    ///     ];
-    ///
-    ///     // Set the serialization location and headers.
-    ///     worksheet.serialize_headers(0, 0, &data[1])?;
-    ///
-    ///     // Serialize the data.
-    ///     worksheet.serialize(&data)?;
-    ///
-    ///     // Add a format. Green fill with dark green text.
-    ///     let format = Format::new()
-    ///         .set_font_color("006100")
-    ///         .set_background_color("C6EFCE");
-    ///
-    ///     // Create a conditional format.
-    ///     let conditional_format = ConditionalFormatCell::new()
-    ///         .set_rule(ConditionalFormatCellRule::GreaterThanOrEqualTo(50))
-    ///         .set_format(format);
-    ///
-    ///     // Get the range that the serialization applies to.
-    ///     let (min_row, min_col, max_row, max_col) =
-    ///         worksheet.get_serialize_dimensions("MyStruct")?;
-    ///
-    ///     // Write the conditional format to the serialization area. Note, we add 1 to
-    ///     // the minimum row number to skip the headers.
-    ///     worksheet.add_conditional_format(
-    ///         min_row + 1,
-    ///         min_col,
-    ///         max_row,
-    ///         max_col,
-    ///         &conditional_format,
-    ///     )?;
+    /// #     // Add a simple format for the headers.
+    /// #     let format = Format::new().set_bold();
+    /// #
+    /// #     // Create a serializable struct.
+    /// #     #[derive(Deserialize, Serialize)]
+    /// #     #[serde(rename_all = "PascalCase")]
+    /// #     struct Produce {
+    /// #         fruit: &'static str,
+    /// #         cost: f64,
+    /// #     }
+    /// #
+    /// #     // Create some data instances.
+    /// #     let item1 = Produce {
+    /// #         fruit: "Peach",
+    /// #         cost: 1.05,
+    /// #     };
+    /// #     let item2 = Produce {
+    /// #         fruit: "Plum",
+    /// #         cost: 0.15,
+    /// #     };
+    /// #     let item3 = Produce {
+    /// #         fruit: "Pear",
+    /// #         cost: 0.75,
+    /// #     };
+    /// #
+    /// #     // Set up the start location and headers of the data to be serialized.
+    /// #     worksheet.deserialize_headers_with_format::<Produce>(0, 0, &format)?;
+    /// #
+    /// #     // Serialize the data.
+    /// #     worksheet.serialize(&item1)?;
+    /// #     worksheet.serialize(&item2)?;
+    /// #     worksheet.serialize(&item3)?;
     /// #
     /// #     // Save the file.
     /// #     workbook.save("serialize.xlsx")?;
@@ -11763,482 +12817,362 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_dimensions1.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize.png">
+    ///
     ///
     #[cfg(feature = "serde")]
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn get_serialize_dimensions(
+    pub fn deserialize_headers_with_format<'de, T>(
         &mut self,
-        struct_name: &str,
-    ) -> Result<(RowNum, ColNum, RowNum, ColNum), XlsxError> {
-        self.serializer_state.get_dimensions(struct_name)
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: Deserialize<'de>,
+    {
+        // Deserialize the struct to determine the type name and the fields.
+        let headers = deserialize_headers::<T>();
+
+        self.store_serialization_headers(row, col, &headers, format)
     }
 
-    /// Get the row/column dimensions of a column in a serialized area.
+    /// Write the location and headers for data serialization, with additional
+    /// options.
     ///
-    /// When serializing data it generally isn't necessary to track the row and
-    /// column range of the cells that are written since `rust_xlsxwriter` does
-    /// it automatically. However, it is sometimes useful to know the range of
-    /// the serialization after the data is written in order to refer to it in
-    /// another function such as a conditional format or a chart.
+    /// The [`Worksheet::serialize()`] and
+    /// [`Worksheet::deserialize_headers_with_format()`] methods, above, set the
+    /// serialization headers and location via an instance of the structure to
+    /// be serialized. This will work for the majority of use cases, and for
+    /// other cases you can adjust the output by using Serde Container or Field
+    /// [Attributes]. [Working with
+    /// Serde](crate::serializer#working-with-serde).
+    ///
+    /// [Attributes]: https://serde.rs/attributes.html
+    ///
+    /// If these methods don't give you the output or flexibility you require
+    /// you can use the `deserialize_headers_with_options()` method with
+    /// [`SerializeFieldOptions`] and [`CustomSerializeField`] options. This
+    /// allows you to reorder, rename, format or skip headers and also define
+    /// formatting for field values.
+    ///
+    /// See [`SerializeFieldOptions`] and [`CustomSerializeField`] for
+    /// additional information and examples.
+    ///
+    /// See also [`Worksheet::serialize_headers_with_options()`] which requires
+    /// an instance of the serializable type but doesn't require that your
+    /// struct also derives "Deserialize".
     ///
-    /// The `get_serialize_column_dimensions()` function returns the row/column
-    /// dimensions of a field in a serialized area for use cases where you need to
-    /// know the range of the data that was written. The dimensions are returned
-    /// as a `(min_row, col, max_row, col)` tuple in a `Result<>`.
     ///
     /// # Parameters
     ///
-    /// - `struct_name`: The name/type of the target struct, as a string.
-    /// - `struct_name`: The name of the field in the target struct, as a
-    ///   string.
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `header_options`: A [`SerializeFieldOptions`] instance.
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::ParameterError`] - Unknown or unserialized struct name or
-    ///   field.
-    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
+    ///   serialization.
     ///
     /// # Examples
     ///
-    /// Example of getting the field/column dimensions of some serialized data. In
-    /// this example we use the dimensions to set a conditional format range.
+    /// The following example demonstrates serializing instances of a Serde
+    /// derived data structure to a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_serialize_dimensions2.rs
-    /// #
-    /// # use rust_xlsxwriter::{
-    /// #     ConditionalFormatCell, ConditionalFormatCellRule, Format, Workbook, XlsxError,
-    /// # };
-    /// # use serde::Serialize;
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
+    /// # // This code is available in examples/doc_worksheet_serialize_headers_with_options2.rs
     /// #
+    /// use rust_xlsxwriter::{
+    ///     CustomSerializeField, Format, SerializeFieldOptions, Workbook, XlsxError
+    /// };
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Add some formats to use with the serialization data.
+    ///     let bold = Format::new().set_bold();
+    ///     let currency = Format::new().set_num_format("$0.00");
+    ///
     ///     // Create a serializable struct.
-    ///     #[derive(Serialize)]
-    ///     #[serde(rename_all = "PascalCase")]
-    ///     struct MyStruct {
-    ///         col1: u8,
-    ///         col2: u8,
-    ///         col3: u8,
-    ///         col4: u8,
+    ///     #[derive(Deserialize, Serialize)]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
     ///     }
     ///
-    ///     // Create some sample data.
-    /// #     #[rustfmt::skip]
-    ///     let data = [
-    ///         MyStruct {col1: 34,  col2: 73, col3: 39, col4: 32},
-    ///         MyStruct {col1: 5,   col2: 24, col3: 1,  col4: 84},
-    ///         MyStruct {col1: 28,  col2: 79, col3: 97, col4: 13},
-    ///         MyStruct {col1: 27,  col2: 71, col3: 40, col4: 17},
-    ///         MyStruct {col1: 88,  col2: 25, col3: 33, col4: 23},
-    ///         MyStruct {col1: 23,  col2: 99, col3: 20, col4: 88},
-    ///         MyStruct {col1: 7,   col2: 57, col3: 88, col4: 28},
-    ///         MyStruct {col1: 53,  col2: 78, col3: 1,  col4: 96},
-    ///         MyStruct {col1: 60,  col2: 54, col3: 81, col4: 66},
-    ///         MyStruct {col1: 70,  col2: 5,  col3: 46, col4: 14},
+    ///     // Create some data instances.
+    ///     let items = [
+    ///         Produce {
+    ///             fruit: "Peach",
+    ///             cost: 1.05,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Plum",
+    ///             cost: 0.15,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Pear",
+    ///             cost: 0.75,
+    ///         },
     ///     ];
     ///
-    ///     // Set the serialization location and headers.
-    ///     worksheet.serialize_headers(0, 0, &data[1])?;
-    ///
-    ///     // Serialize the data.
-    ///     worksheet.serialize(&data)?;
+    ///     // Set up the start location and headers of the data to be serialized using
+    ///     // custom headers.
+    ///     let custom_headers = [
+    ///         CustomSerializeField::new("fruit")
+    ///             .rename("Fruit"),
+    ///         CustomSerializeField::new("cost")
+    ///             .rename("Price")
+    ///             .set_value_format(currency),
+    ///     ];
+    ///     let header_options = SerializeFieldOptions::new()
+    ///         .set_header_format(bold)
+    ///         .set_custom_headers(&custom_headers);
     ///
-    ///     // Add a format. Green fill with dark green text.
-    ///     let format = Format::new()
-    ///         .set_font_color("006100")
-    ///         .set_background_color("C6EFCE");
+    ///     worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
     ///
-    ///     // Create a conditional format.
-    ///     let conditional_format = ConditionalFormatCell::new()
-    ///         .set_rule(ConditionalFormatCellRule::GreaterThanOrEqualTo(50))
-    ///         .set_format(format);
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&items)?;
     ///
-    ///     // Get the range that the serialization field applies to. Note that we must
-    ///     // match the Serde field name which has been renamed in PascalCase to Col3
-    ///     // (not col3). Also note that min_col and max_col are the same in this case
-    ///     // but we give them separate names for the sake of the example.
-    ///     let (min_row, min_col, max_row, max_col) =
-    ///         worksheet.get_serialize_column_dimensions("MyStruct", "Col3")?;
+    ///     // Save the file.
+    ///     workbook.save("serialize.xlsx")?;
     ///
-    ///     // Write the conditional format to the serialization area. Note, we add 1 to
-    ///     // the minimum row number to skip the headers.
-    ///     worksheet.add_conditional_format(
-    ///         min_row + 1,
-    ///         min_col,
-    ///         max_row,
-    ///         max_col,
-    ///         &conditional_format,
-    ///     )?;
-    /// #
-    /// #     // Save the file.
-    /// #     workbook.save("serialize.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
+    ///     Ok(())
+    /// }
     /// ```
     ///
     /// Output file:
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_serialize_dimensions2.png">
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers_with_options.png">
     ///
     #[cfg(feature = "serde")]
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn get_serialize_column_dimensions(
-        &mut self,
-        struct_name: &str,
-        field_name: &str,
-    ) -> Result<(RowNum, ColNum, RowNum, ColNum), XlsxError> {
-        self.serializer_state
-            .get_column_dimensions(struct_name, field_name)
-    }
-
-    // Store serialization headers and options.
-    #[cfg(feature = "serde")]
-    fn store_serialization_headers_with_options(
+    pub fn deserialize_headers_with_options<'de, T>(
         &mut self,
         row: RowNum,
         col: ColNum,
-        headers: &SerializerHeader,
         header_options: &SerializeFieldOptions,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check that any custom field names match the actual field names.
-        let field_names: HashSet<String> = HashSet::from_iter(headers.field_names.clone());
-        for custom_header in &header_options.custom_headers {
-            if !field_names.contains(&custom_header.field_name) {
-                return Err(XlsxError::ParameterError(format!(
-                    "No custom field name '{}' found for struct '{}'",
-                    custom_header.field_name, headers.struct_name
-                )));
-            }
-        }
-
-        // Create a map of the user defined custom field settings to overwrite
-        // the default field settings.
-        let mut custom_fields: HashMap<&String, &CustomSerializeField> = HashMap::new();
-        for custom_header in &header_options.custom_headers {
-            custom_fields.insert(&custom_header.field_name, custom_header);
-        }
-
-        // Clone the header options to modify it and store it internally.
-        let mut header_options = header_options.clone();
-        header_options.struct_name.clone_from(&headers.struct_name);
-
-        // Create a "custom" header for default fields or replace them with user
-        // specified custom fields. The "use_custom_headers_only" overrides the
-        // default headers to allow users to skip fields.
-        if !header_options.use_custom_headers_only {
-            let mut custom_headers: Vec<CustomSerializeField> = vec![];
-
-            for field_name in &headers.field_names {
-                match custom_fields.get(field_name) {
-                    Some(custom_field) => {
-                        if !custom_field.skip {
-                            custom_headers.push((*custom_field).clone());
-                        }
-                    }
-                    None => custom_headers.push(CustomSerializeField::new(field_name)),
-                }
-            }
-
-            header_options.custom_headers = custom_headers;
-        }
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: Deserialize<'de>,
+    {
+        // Deserialize the struct to determine the type name and the fields.
+        let headers = deserialize_headers::<T>();
 
-        self.store_custom_serialization_headers(row, col, &header_options)
+        self.store_serialization_headers_with_options(row, col, &headers, header_options)
     }
 
-    // Store serialization headers with default options.
+    /// Write the location and headers for data serialization.
+    ///
+    /// The [`Worksheet::serialize()`] method, above, serializes Serde derived
+    /// structs to worksheet cells. However, before you serialize the data you
+    /// need to set the position in the worksheet where the headers will be
+    /// written and where serialized data will be written.
+    ///
+    /// See [Setting serialization
+    /// headers](crate::serializer#setting-serialization-headers) for more
+    /// information.
+    ///
+    /// See also [`Worksheet::serialize_headers()`] which requires an instance
+    /// of the serializable type but doesn't require that your struct also
+    /// derives `Deserialize`, and [`Worksheet::deserialize_headers()`] which
+    /// does.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
+    ///   serialization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_xlsxserialize_intro.rs
+    /// #
+    /// use rust_xlsxwriter::{Workbook, XlsxError, XlsxSerialize};
+    /// use serde::Serialize;
+    ///
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Create a serializable struct.
+    ///     #[derive(XlsxSerialize, Serialize)]
+    ///     #[xlsx(header_format = Format::new().set_bold())]
+    ///     struct Produce {
+    ///         #[xlsx(rename = "Item")]
+    ///         #[xlsx(column_width = 12.0)]
+    ///         fruit: &'static str,
+    ///
+    ///         #[xlsx(rename = "Price", num_format = "$0.00")]
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let item1 = Produce {
+    ///         fruit: "Peach",
+    ///         cost: 1.05,
+    ///     };
+    ///
+    ///     let item2 = Produce {
+    ///         fruit: "Plum",
+    ///         cost: 0.15,
+    ///     };
+    ///
+    ///     let item3 = Produce {
+    ///         fruit: "Pear",
+    ///         cost: 0.75,
+    ///     };
+    ///
+    ///     // Set the serialization location and headers.
+    ///     worksheet.set_serialize_headers::<Produce>(0, 0)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&item1)?;
+    ///     worksheet.serialize(&item2)?;
+    ///     worksheet.serialize(&item3)?;
+    ///
+    ///     // Save the file to disk.
+    ///     workbook.save("serialize.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/xlsxserialize_intro.png">
     #[cfg(feature = "serde")]
-    fn store_serialization_headers(
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn set_serialize_headers<T>(
         &mut self,
         row: RowNum,
         col: ColNum,
-        headers: &SerializerHeader,
-        header_format: &Format,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Convert the field names to custom header structs.
-        let custom_headers: Vec<CustomSerializeField> = headers
-            .field_names
-            .iter()
-            .map(CustomSerializeField::new)
-            .collect();
-
-        // Transfer the options to a default option struct.
-        let header_options = SerializeFieldOptions {
-            struct_name: headers.struct_name.clone(),
-            header_format: Some(header_format.clone()),
-            custom_headers,
-            ..Default::default()
-        };
-
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: XlsxSerialize + Serialize,
+    {
+        let header_options = T::to_serialize_field_options();
         self.store_custom_serialization_headers(row, col, &header_options)
     }
 
-    // Write serialization headers to the worksheet.
-    #[cfg(feature = "serde")]
-    fn store_custom_serialization_headers(
-        &mut self,
-        row: RowNum,
-        col: ColNum,
-        header_options: &SerializeFieldOptions,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check row and columns are in the allowed range.
-
-        if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Check for empty struct name.
-        if header_options.struct_name.is_empty() {
-            return Err(XlsxError::ParameterError(
-                "Struct not found or serialized/deserialized.".to_string(),
-            ));
-        }
-
-        // Check for empty struct members.
-        if header_options.custom_headers.is_empty() {
-            return Err(XlsxError::ParameterError(format!(
-                "No members found/specified for struct '{}'",
-                header_options.struct_name
-            )));
-        }
-
-        let mut fields = HashMap::new();
-        let min_row = row;
-        let min_col = col;
-        let mut max_row = row;
-        let mut max_col = col;
-
-        let col_initial = col;
-        let write_headers = header_options.has_headers;
-
-        let mut col_offset = 0;
-        for custom_header in &header_options.custom_headers {
-            if custom_header.skip {
-                continue;
-            }
-
-            let col = col_initial + col_offset as u16;
-            let mut custom_header = custom_header.clone();
-            custom_header.col = col;
-            max_col = col;
-            col_offset += 1;
-
-            // Set the column width if specified by user.
-            if let Some(width) = custom_header.width {
-                self.set_column_width(col, width)?;
-            } else if let Some(pixel_width) = custom_header.pixel_width {
-                self.set_column_width_pixels(col, pixel_width)?;
-            }
-
-            // Set the column format if specified by user.
-            if let Some(format) = &custom_header.column_format {
-                self.set_column_format(col, format)?;
-            }
-
-            // Use the column specific header format or else the header row
-            // format, and if neither of those have been specified then write
-            // without a format.
-            if write_headers {
-                if let Some(format) = &custom_header.header_format {
-                    self.write_string_with_format(
-                        max_row,
-                        col,
-                        &custom_header.header_name,
-                        format,
-                    )?;
-                } else if let Some(format) = &header_options.header_format {
-                    self.write_string_with_format(
-                        max_row,
-                        col,
-                        &custom_header.header_name,
-                        format,
-                    )?;
-                } else {
-                    self.write_string(max_row, col, &custom_header.header_name)?;
-                };
-            }
-
-            fields.insert(custom_header.field_name.clone(), custom_header);
-        }
-
-        // Start the data serialization one row down if headers were written.
-        if write_headers {
-            max_row += 1;
-        }
-
-        // If a previous serialization was carried out with the same struct name
-        // then write the previous table formatting.
-        if let Some(header_config) = self
-            .serializer_state
-            .structs
-            .get_mut(&header_options.struct_name)
-        {
-            if let Some(table_data) = header_config.get_table() {
-                self.write_serialized_table(&table_data)?;
-            }
-        }
-
-        // Clone the new user defined table format, if present.
-        let table = match &header_options.table {
-            Some(table) => {
-                let mut table = table.clone();
-                if !header_options.has_headers {
-                    table.show_header_row = false;
-                }
-                table.is_serde_table = true;
-                Some(table)
-            }
-            None => None,
-        };
-
-        // Store meta data for the struct/headers.
-        self.serializer_state.structs.insert(
-            header_options.struct_name.clone(),
-            SerializationHeaderConfig {
-                fields,
-                min_row,
-                min_col,
-                max_row,
-                max_col,
-                table,
-            },
-        );
-
-        Ok(self)
-    }
-
-    // Serialize the parent data structure to the worksheet.
-    #[cfg(feature = "serde")]
-    fn serialize_data_structure<T>(&mut self, data_structure: &T) -> Result<(), XlsxError>
-    where
-        T: Serialize,
-    {
-        data_structure.serialize(self)?;
-        Ok(())
-    }
-
-    // Serialize individual data items to a worksheet cell.
-    #[cfg(feature = "serde")]
-    pub(crate) fn serialize_to_worksheet_cell(
-        &mut self,
-        data: impl IntoExcelData,
-    ) -> Result<(), XlsxError> {
-        let result = self.serializer_state.current_state();
-
-        match result {
-            Ok(result) => {
-                let (row, col, value_format) = result;
-                match &*value_format {
-                    Some(format) => self.write_with_format(row, col, data, format).map(|_| ()),
-                    None => self.write(row, col, data).map(|_| ()),
-                }
-            }
-            Err(()) => Ok(()),
-        }
-    }
-
-    // Add any tables that were added as part of serialization formatting.
-    #[cfg(feature = "serde")]
-    pub(crate) fn store_serialized_tables(&mut self) -> Result<&mut Worksheet, XlsxError> {
-        let tables = self.serializer_state.get_tables();
-
-        for table_data in tables {
-            self.write_serialized_table(&table_data)?;
-        }
-
-        Ok(self)
-    }
-
-    // Write a table that is part of serialization formatting.
-    #[cfg(feature = "serde")]
-    pub(crate) fn write_serialized_table(
-        &mut self,
-        table_data: &TableData,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        let min_col = table_data.1;
-        let max_col = table_data.3;
-        let min_row = table_data.0;
-        let mut max_row = table_data.2;
-        let table = &table_data.4;
-
-        if table.show_total_row {
-            max_row += 1;
-        }
-
-        self.add_table(min_row, min_col, max_row, max_col, table)
-    }
-
-    // -----------------------------------------------------------------------
-    // Worksheet page setup methods.
-    // -----------------------------------------------------------------------
-
-    /// Display the worksheet cells from right to left for some versions of
-    /// Excel.
-    ///
-    /// The `set_right_to_left()` method is used to change the default direction
-    /// of the worksheet from left-to-right, with the A1 cell in the top left,
-    /// to right-to-left, with the A1 cell in the top right.
+    /// Get the row/column dimensions of a serialized area.
     ///
-    /// This is useful when creating Arabic, Hebrew or other near or far eastern
-    /// worksheets that use right-to-left as the default direction.
+    /// When serializing data it generally isn't necessary to track the row and
+    /// column range of the cells that are written since `rust_xlsxwriter` does
+    /// it automatically. However, it is sometimes useful to know the range of
+    /// the serialization after the data is written in order to refer to it in
+    /// another function such as a conditional format or a chart.
     ///
-    /// Depending on your use case, and text, you may also need to use the
-    /// [`Format::set_reading_direction()`](crate::Format::set_reading_direction)
-    /// method to set the direction of the text within the cells.
+    /// The `get_serialize_dimensions()` function returns the row/column
+    /// dimensions of a serialized area for use cases where you need to know the
+    /// range of the data that was written. The dimensions are returned as a
+    /// `(min_row, min_col, max_row, max_col)` tuple in a `Result<>`.
     ///
     /// # Parameters
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// - `struct_name`: The name/type of the target struct as a string.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Unknown or unserialized struct name.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates changing the default worksheet and
-    /// cell text direction changed from left-to-right to right-to-left, as
-    /// required by some middle eastern versions of Excel.
+    /// Example of getting the dimensions of some serialized data. In this
+    /// example we use the dimensions to set a conditional format range.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_right_to_left.rs
+    /// # // This code is available in examples/doc_worksheet_serialize_dimensions1.rs
     /// #
-    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{
+    /// #     ConditionalFormatCell, ConditionalFormatCellRule, Format, Workbook, XlsxError,
+    /// # };
+    /// # use serde::Serialize;
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
-    ///     // Add the cell formats.
-    ///     let format_left_to_right = Format::new().set_reading_direction(1);
-    ///     let format_right_to_left = Format::new().set_reading_direction(2);
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct MyStruct {
+    ///         col1: u8,
+    ///         col2: u8,
+    ///         col3: u8,
+    ///         col4: u8,
+    ///     }
     ///
-    ///     // Add a worksheet in the standard left to right direction.
-    ///     let worksheet1 = workbook.add_worksheet();
+    ///     // Create some sample data.
+    /// #     #[rustfmt::skip]
+    ///     let data = [
+    ///         MyStruct {col1: 34,  col2: 73, col3: 39, col4: 32},
+    ///         MyStruct {col1: 5,   col2: 24, col3: 1,  col4: 84},
+    ///         MyStruct {col1: 28,  col2: 79, col3: 97, col4: 13},
+    ///         MyStruct {col1: 27,  col2: 71, col3: 40, col4: 17},
+    ///         MyStruct {col1: 88,  col2: 25, col3: 33, col4: 23},
+    ///         MyStruct {col1: 23,  col2: 99, col3: 20, col4: 88},
+    ///         MyStruct {col1: 7,   col2: 57, col3: 88, col4: 28},
+    ///         MyStruct {col1: 53,  col2: 78, col3: 1,  col4: 96},
+    ///         MyStruct {col1: 60,  col2: 54, col3: 81, col4: 66},
+    ///         MyStruct {col1: 70,  col2: 5,  col3: 46, col4: 14},
+    ///     ];
     ///
-    ///     // Make the column wider for clarity.
-    ///     worksheet1.set_column_width(0,25)?;
+    ///     // Set the serialization location and headers.
+    ///     worksheet.serialize_headers(0, 0, &data[1])?;
     ///
-    ///     // Standard direction:         | A1 | B1 | C1 | ...
-    ///     worksheet1.write(0, 0, "نص عربي / English text")?;
-    ///     worksheet1.write_with_format(1, 0, "نص عربي / English text", &format_left_to_right)?;
-    ///     worksheet1.write_with_format(2, 0, "نص عربي / English text", &format_right_to_left)?;
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&data)?;
     ///
-    ///     // Add a worksheet and change it to right to left direction.
-    ///     let worksheet2 = workbook.add_worksheet();
-    ///     worksheet2.set_right_to_left(true);
+    ///     // Add a format. Green fill with dark green text.
+    ///     let format = Format::new()
+    ///         .set_font_color("006100")
+    ///         .set_background_color("C6EFCE");
     ///
-    ///     // Make the column wider for clarity.
-    ///     worksheet2.set_column_width(0, 25)?;
+    ///     // Create a conditional format.
+    ///     let conditional_format = ConditionalFormatCell::new()
+    ///         .set_rule(ConditionalFormatCellRule::GreaterThanOrEqualTo(50))
+    ///         .set_format(format);
     ///
-    ///     // Right to left direction:    ... | C1 | B1 | A1 |
-    ///     worksheet2.write(0, 0, "نص عربي / English text")?;
-    ///     worksheet2.write_with_format(1, 0, "نص عربي / English text", &format_left_to_right)?;
-    ///     worksheet2.write_with_format(2, 0, "نص عربي / English text", &format_right_to_left)?;
+    ///     // Get the range that the serialization applies to.
+    ///     let (min_row, min_col, max_row, max_col) =
+    ///         worksheet.get_serialize_dimensions("MyStruct")?;
+    ///
+    ///     // Write the conditional format to the serialization area. Note, we add 1 to
+    ///     // the minimum row number to skip the headers.
+    ///     worksheet.add_conditional_format(
+    ///         min_row + 1,
+    ///         min_col,
+    ///         max_row,
+    ///         max_col,
+    ///         &conditional_format,
+    ///     )?;
     /// #
-    /// #     workbook.save("worksheet.xlsx")?;
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
@@ -12246,167 +13180,122 @@ impl Worksheet {
     ///
     /// Output file:
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_right_to_left.png">
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_dimensions1.png">
     ///
-    pub fn set_right_to_left(&mut self, enable: bool) -> &mut Worksheet {
-        self.right_to_left = enable;
-        self
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn get_serialize_dimensions(
+        &mut self,
+        struct_name: &str,
+    ) -> Result<(RowNum, ColNum, RowNum, ColNum), XlsxError> {
+        self.serializer_state.get_dimensions(struct_name)
     }
 
-    /// Make a worksheet the active/initially visible worksheet in a workbook.
-    ///
-    /// The `set_active()` method is used to specify which worksheet is
-    /// initially visible in a multi-sheet workbook. If no worksheet is set then
-    /// the first worksheet is made the active worksheet, like in Excel.
-    ///
-    /// # Parameters
+    /// Get the row/column dimensions of a column in a serialized area.
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
-    ///
-    /// # Examples
-    ///
-    /// The following example demonstrates setting a worksheet as the visible
-    /// worksheet when a file is opened.
-    ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_set_active.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    ///     let worksheet1 = Worksheet::new();
-    ///     let worksheet3 = Worksheet::new();
-    ///     let mut worksheet2 = Worksheet::new();
-    ///
-    ///     worksheet2.set_active(true);
-    /// #
-    /// #   workbook.push_worksheet(worksheet1);
-    /// #   workbook.push_worksheet(worksheet2);
-    /// #   workbook.push_worksheet(worksheet3);
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
-    ///
-    /// Output file:
+    /// When serializing data it generally isn't necessary to track the row and
+    /// column range of the cells that are written since `rust_xlsxwriter` does
+    /// it automatically. However, it is sometimes useful to know the range of
+    /// the serialization after the data is written in order to refer to it in
+    /// another function such as a conditional format or a chart.
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_active.png">
+    /// The `get_serialize_column_dimensions()` function returns the row/column
+    /// dimensions of a field in a serialized area for use cases where you need to
+    /// know the range of the data that was written. The dimensions are returned
+    /// as a `(min_row, col, max_row, col)` tuple in a `Result<>`.
     ///
-    pub fn set_active(&mut self, enable: bool) -> &mut Worksheet {
-        self.active = enable;
-
-        // Activated worksheets must also be selected and cannot be hidden.
-        if self.active {
-            self.selected = true;
-            self.visible = Visible::Default;
-        }
-
-        self
-    }
-
-    /// Set a worksheet tab as selected.
+    /// # Parameters
     ///
-    /// The `set_selected()` method is used to indicate that a worksheet is
-    /// selected in a multi-sheet workbook.
+    /// - `struct_name`: The name/type of the target struct, as a string.
+    /// - `struct_name`: The name of the field in the target struct, as a
+    ///   string.
     ///
-    /// A selected worksheet has its tab highlighted. Selecting worksheets is a
-    /// way of grouping them together so that, for example, several worksheets
-    /// could be printed in one go. A worksheet that has been activated via the
-    /// [`Worksheet::set_active()`] method will also appear as selected.
+    /// # Errors
     ///
-    /// # Parameters
+    /// - [`XlsxError::ParameterError`] - Unknown or unserialized struct name or
+    ///   field.
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates selecting worksheet in a workbook.
-    /// The active worksheet is selected by default so in this example the first
-    /// two worksheets are selected.
+    /// Example of getting the field/column dimensions of some serialized data. In
+    /// this example we use the dimensions to set a conditional format range.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_selected.rs
+    /// # // This code is available in examples/doc_worksheet_serialize_dimensions2.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
+    /// # use rust_xlsxwriter::{
+    /// #     ConditionalFormatCell, ConditionalFormatCellRule, Format, Workbook, XlsxError,
+    /// # };
+    /// # use serde::Serialize;
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    ///     let worksheet1 = Worksheet::new();
-    ///     let worksheet3 = Worksheet::new();
-    ///     let mut worksheet2 = Worksheet::new();
-    ///
-    ///     worksheet2.set_selected(true);
-    /// #
-    /// #   workbook.push_worksheet(worksheet1);
-    /// #   workbook.push_worksheet(worksheet2);
-    /// #   workbook.push_worksheet(worksheet3);
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
-    ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_selected.png">
-    ///
-    pub fn set_selected(&mut self, enable: bool) -> &mut Worksheet {
-        self.selected = enable;
-
-        // Selected worksheets cannot be hidden.
-        if self.selected {
-            self.visible = Visible::Default;
-        }
-
-        self
-    }
-
-    /// Hide a worksheet.
-    ///
-    /// The `set_hidden()` method is used to hide a worksheet. This can be used
-    /// to hide a worksheet in order to avoid confusing a user with intermediate
-    /// data or calculations.
+    ///     // Create a serializable struct.
+    ///     #[derive(Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct MyStruct {
+    ///         col1: u8,
+    ///         col2: u8,
+    ///         col3: u8,
+    ///         col4: u8,
+    ///     }
     ///
-    /// In Excel a hidden worksheet can not be activated or selected so this
-    /// method is mutually exclusive with the [`Worksheet::set_active()`] and
-    /// [`Worksheet::set_selected()`] methods. In addition, since the first
-    /// worksheet will default to being the active worksheet, you cannot hide
-    /// the first worksheet without activating another sheet.
+    ///     // Create some sample data.
+    /// #     #[rustfmt::skip]
+    ///     let data = [
+    ///         MyStruct {col1: 34,  col2: 73, col3: 39, col4: 32},
+    ///         MyStruct {col1: 5,   col2: 24, col3: 1,  col4: 84},
+    ///         MyStruct {col1: 28,  col2: 79, col3: 97, col4: 13},
+    ///         MyStruct {col1: 27,  col2: 71, col3: 40, col4: 17},
+    ///         MyStruct {col1: 88,  col2: 25, col3: 33, col4: 23},
+    ///         MyStruct {col1: 23,  col2: 99, col3: 20, col4: 88},
+    ///         MyStruct {col1: 7,   col2: 57, col3: 88, col4: 28},
+    ///         MyStruct {col1: 53,  col2: 78, col3: 1,  col4: 96},
+    ///         MyStruct {col1: 60,  col2: 54, col3: 81, col4: 66},
+    ///         MyStruct {col1: 70,  col2: 5,  col3: 46, col4: 14},
+    ///     ];
     ///
-    /// # Parameters
+    ///     // Set the serialization location and headers.
+    ///     worksheet.serialize_headers(0, 0, &data[1])?;
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&data)?;
     ///
-    /// # Examples
+    ///     // Add a format. Green fill with dark green text.
+    ///     let format = Format::new()
+    ///         .set_font_color("006100")
+    ///         .set_background_color("C6EFCE");
     ///
-    /// The following example demonstrates hiding a worksheet.
+    ///     // Create a conditional format.
+    ///     let conditional_format = ConditionalFormatCell::new()
+    ///         .set_rule(ConditionalFormatCellRule::GreaterThanOrEqualTo(50))
+    ///         .set_format(format);
     ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_set_hidden.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    ///     let worksheet1 = Worksheet::new();
-    ///     let worksheet3 = Worksheet::new();
-    ///     let mut worksheet2 = Worksheet::new();
+    ///     // Get the range that the serialization field applies to. Note that we must
+    ///     // match the Serde field name which has been renamed in PascalCase to Col3
+    ///     // (not col3). Also note that min_col and max_col are the same in this case
+    ///     // but we give them separate names for the sake of the example.
+    ///     let (min_row, min_col, max_row, max_col) =
+    ///         worksheet.get_serialize_column_dimensions("MyStruct", "Col3")?;
     ///
-    ///     worksheet2.set_hidden(true);
-    /// #
-    /// #    workbook.push_worksheet(worksheet1);
-    /// #    workbook.push_worksheet(worksheet2);
-    /// #    workbook.push_worksheet(worksheet3);
+    ///     // Write the conditional format to the serialization area. Note, we add 1 to
+    ///     // the minimum row number to skip the headers.
+    ///     worksheet.add_conditional_format(
+    ///         min_row + 1,
+    ///         min_col,
+    ///         max_row,
+    ///         max_col,
+    ///         &conditional_format,
+    ///     )?;
     /// #
-    /// #     workbook.save("worksheet.xlsx")?;
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
@@ -12414,303 +13303,406 @@ impl Worksheet {
     ///
     /// Output file:
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_hidden.png">
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_serialize_dimensions2.png">
     ///
-    pub fn set_hidden(&mut self, enable: bool) -> &mut Worksheet {
-        if enable {
-            self.visible = Visible::Hidden;
-        } else {
-            self.visible = Visible::Default;
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn get_serialize_column_dimensions(
+        &mut self,
+        struct_name: &str,
+        field_name: &str,
+    ) -> Result<(RowNum, ColNum, RowNum, ColNum), XlsxError> {
+        self.serializer_state
+            .get_column_dimensions(struct_name, field_name)
+    }
+
+    // Store serialization headers and options.
+    #[cfg(feature = "serde")]
+    fn store_serialization_headers_with_options(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        headers: &SerializerHeader,
+        header_options: &SerializeFieldOptions,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check that any custom field names match the actual field names.
+        let field_names: HashSet<String> = HashSet::from_iter(headers.field_names.clone());
+        for custom_header in &header_options.custom_headers {
+            if !field_names.contains(&custom_header.field_name) {
+                return Err(XlsxError::ParameterError(format!(
+                    "No custom field name '{}' found for struct '{}'",
+                    custom_header.field_name, headers.struct_name
+                )));
+            }
         }
 
-        // Hidden worksheets cannot be active or hidden.
-        if self.visible == Visible::Hidden {
-            self.selected = false;
-            self.active = false;
+        // Create a map of the user defined custom field settings to overwrite
+        // the default field settings.
+        let mut custom_fields: HashMap<&String, &CustomSerializeField> = HashMap::new();
+        for custom_header in &header_options.custom_headers {
+            custom_fields.insert(&custom_header.field_name, custom_header);
         }
 
-        self
-    }
+        // Clone the header options to modify it and store it internally.
+        let mut header_options = header_options.clone();
+        header_options.struct_name.clone_from(&headers.struct_name);
 
-    /// Hide a worksheet. Can only be unhidden in Excel by VBA.
-    ///
-    /// The `set_very_hidden()` method can be used to hide a worksheet similar
-    /// to the [`Worksheet::set_hidden()`] method. The difference is that the
-    /// worksheet cannot be unhidden in the the Excel user interface. The Excel
-    /// worksheet `xlSheetVeryHidden` option can only be unset programmatically
-    /// by VBA.
-    ///
-    /// # Parameters
-    ///
-    /// - `enable`: Turn the property on/off. It is off by default.
-    ///
-    pub fn set_very_hidden(&mut self, enable: bool) -> &mut Worksheet {
-        if enable {
-            self.visible = Visible::VeryHidden;
-        } else {
-            self.visible = Visible::Default;
-        }
+        // Create a "custom" header for default fields or replace them with user
+        // specified custom fields. The "use_custom_headers_only" overrides the
+        // default headers to allow users to skip fields.
+        if !header_options.use_custom_headers_only {
+            let mut custom_headers: Vec<CustomSerializeField> = vec![];
 
-        // Hidden worksheets cannot be active or hidden.
-        if self.visible == Visible::VeryHidden {
-            self.selected = false;
-            self.active = false;
+            for field_name in &headers.field_names {
+                match custom_fields.get(field_name) {
+                    Some(custom_field) => {
+                        if !custom_field.skip {
+                            custom_headers.push((*custom_field).clone());
+                        }
+                    }
+                    None => custom_headers.push(CustomSerializeField::new(field_name)),
+                }
+            }
+
+            header_options.custom_headers = custom_headers;
         }
 
-        self
+        self.store_custom_serialization_headers(row, col, &header_options)
     }
 
-    /// Set current worksheet as the first visible sheet tab.
-    ///
-    /// The [`Worksheet::set_active()`]  method determines which worksheet is
-    /// initially selected. However, if there are a large number of worksheets
-    /// the selected worksheet may not appear on the screen. To avoid this you
-    /// can select which is the leftmost visible worksheet tab using
-    /// `set_first_tab()`.
-    ///
-    /// This method is not required very often. The default is the first
-    /// worksheet.
-    ///
-    /// # Parameters
-    ///
-    /// - `enable`: Turn the property on/off. It is off by default.
-    ///
-    pub fn set_first_tab(&mut self, enable: bool) -> &mut Worksheet {
-        self.first_sheet = enable;
+    // Store serialization headers with default options.
+    #[cfg(feature = "serde")]
+    fn store_serialization_headers(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        headers: &SerializerHeader,
+        header_format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Convert the field names to custom header structs.
+        let custom_headers: Vec<CustomSerializeField> = headers
+            .field_names
+            .iter()
+            .map(CustomSerializeField::new)
+            .collect();
 
-        // First visible worksheet cannot be hidden.
-        if self.selected {
-            self.visible = Visible::Default;
-        }
-        self
+        // Transfer the options to a default option struct.
+        let header_options = SerializeFieldOptions {
+            struct_name: headers.struct_name.clone(),
+            header_format: Some(header_format.clone()),
+            custom_headers,
+            ..Default::default()
+        };
+
+        self.store_custom_serialization_headers(row, col, &header_options)
     }
 
-    /// Set the color of the worksheet tab.
-    ///
-    /// The `set_tab_color()` method can be used to change the color of the
-    /// worksheet tab. This is useful for highlighting the important tab in a
-    /// group of worksheets.
-    ///
-    /// # Parameters
-    ///
-    /// - `color`: The tab color property defined by a [`Color`] enum
-    ///   value.
-    ///
-    /// # Examples
-    ///
-    /// The following example demonstrates set the tab color of worksheets.
-    ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_set_tab_color.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, Worksheet, Color, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    ///     let mut worksheet1 = Worksheet::new();
-    ///     let mut worksheet2 = Worksheet::new();
-    ///     let mut worksheet3 = Worksheet::new();
-    ///     let mut worksheet4 = Worksheet::new();
-    ///
-    ///     worksheet1.set_tab_color(Color::Red);
-    ///     worksheet2.set_tab_color(Color::Green);
-    ///     worksheet3.set_tab_color(Color::RGB(0xFF9900));
-    ///
-    ///     // worksheet4 will have the default color.
-    ///     worksheet4.set_active(true);
-    /// #
-    /// #    workbook.push_worksheet(worksheet1);
-    /// #    workbook.push_worksheet(worksheet2);
-    /// #    workbook.push_worksheet(worksheet3);
-    /// #    workbook.push_worksheet(worksheet4);
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
-    ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_tab_color.png">
-    ///
-    pub fn set_tab_color(&mut self, color: impl Into<Color>) -> &mut Worksheet {
-        let color = color.into();
-        if color.is_valid() {
-            self.tab_color = color;
+    // Write serialization headers to the worksheet.
+    #[cfg(feature = "serde")]
+    fn store_custom_serialization_headers(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        header_options: &SerializeFieldOptions,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and columns are in the allowed range.
+
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
         }
 
-        self
-    }
+        // Check for empty struct name.
+        if header_options.struct_name.is_empty() {
+            return Err(XlsxError::ParameterError(
+                "Struct not found or serialized/deserialized.".to_string(),
+            ));
+        }
 
-    /// Set the paper type/size when printing.
-    ///
-    /// This method is used to set the paper format for the printed output of a
-    /// worksheet. The following paper styles are available:
-    ///
-    /// | Index    | Paper format            | Paper size           |
-    /// | :------- | :---------------------- | :------------------- |
-    /// | 0        | Printer default         | Printer default      |
-    /// | 1        | Letter                  | 8 1/2 x 11 in        |
-    /// | 2        | Letter Small            | 8 1/2 x 11 in        |
-    /// | 3        | Tabloid                 | 11 x 17 in           |
-    /// | 4        | Ledger                  | 17 x 11 in           |
-    /// | 5        | Legal                   | 8 1/2 x 14 in        |
-    /// | 6        | Statement               | 5 1/2 x 8 1/2 in     |
-    /// | 7        | Executive               | 7 1/4 x 10 1/2 in    |
-    /// | 8        | A3                      | 297 x 420 mm         |
-    /// | 9        | A4                      | 210 x 297 mm         |
-    /// | 10       | A4 Small                | 210 x 297 mm         |
-    /// | 11       | A5                      | 148 x 210 mm         |
-    /// | 12       | B4                      | 250 x 354 mm         |
-    /// | 13       | B5                      | 182 x 257 mm         |
-    /// | 14       | Folio                   | 8 1/2 x 13 in        |
-    /// | 15       | Quarto                  | 215 x 275 mm         |
-    /// | 16       | ---                     | 10x14 in             |
-    /// | 17       | ---                     | 11x17 in             |
-    /// | 18       | Note                    | 8 1/2 x 11 in        |
-    /// | 19       | Envelope 9              | 3 7/8 x 8 7/8        |
-    /// | 20       | Envelope 10             | 4 1/8 x 9 1/2        |
-    /// | 21       | Envelope 11             | 4 1/2 x 10 3/8       |
-    /// | 22       | Envelope 12             | 4 3/4 x 11           |
-    /// | 23       | Envelope 14             | 5 x 11 1/2           |
-    /// | 24       | C size sheet            | ---                  |
-    /// | 25       | D size sheet            | ---                  |
-    /// | 26       | E size sheet            | ---                  |
-    /// | 27       | Envelope DL             | 110 x 220 mm         |
-    /// | 28       | Envelope C3             | 324 x 458 mm         |
-    /// | 29       | Envelope C4             | 229 x 324 mm         |
-    /// | 30       | Envelope C5             | 162 x 229 mm         |
-    /// | 31       | Envelope C6             | 114 x 162 mm         |
-    /// | 32       | Envelope C65            | 114 x 229 mm         |
-    /// | 33       | Envelope B4             | 250 x 353 mm         |
-    /// | 34       | Envelope B5             | 176 x 250 mm         |
-    /// | 35       | Envelope B6             | 176 x 125 mm         |
-    /// | 36       | Envelope                | 110 x 230 mm         |
-    /// | 37       | Monarch                 | 3.875 x 7.5 in       |
-    /// | 38       | Envelope                | 3 5/8 x 6 1/2 in     |
-    /// | 39       | Fanfold                 | 14 7/8 x 11 in       |
-    /// | 40       | German Std Fanfold      | 8 1/2 x 12 in        |
-    /// | 41       | German Legal Fanfold    | 8 1/2 x 13 in        |
-    ///
-    /// Note, it is likely that not all of these paper types will be available
-    /// to the end user since it will depend on the paper formats that the
-    /// user's printer supports. Therefore, it is best to stick to standard
-    /// paper types of 1 for US Letter and 9 for A4.
-    ///
-    /// If you do not specify a paper type the worksheet will print using the
-    /// printer's default paper style.
-    ///
-    /// # Parameters
-    ///
-    /// - `paper_size`: The paper size index from the list above .
-    ///
-    /// # Examples
-    ///
-    /// The following example demonstrates setting the worksheet paper size/type for
-    /// the printed output.
-    ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_set_paper.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     // Set the printer paper size.
-    ///     worksheet.set_paper_size(9); // A4 paper size.
-    ///
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
-    pub fn set_paper_size(&mut self, paper_size: u8) -> &mut Worksheet {
-        self.paper_size = paper_size;
-        self.page_setup_changed = true;
-        self
-    }
+        // Check for empty struct members.
+        if header_options.custom_headers.is_empty() {
+            return Err(XlsxError::ParameterError(format!(
+                "No members found/specified for struct '{}'",
+                header_options.struct_name
+            )));
+        }
 
-    /// Set the order in which pages are printed.
-    ///
-    /// The `set_page_order()` method is used to change the default print
-    /// direction. This is referred to by Excel as the sheet "page order":
+        let mut fields = HashMap::new();
+        let min_row = row;
+        let min_col = col;
+        let mut max_row = row;
+        let mut max_col = col;
+
+        let col_initial = col;
+        let write_headers = header_options.has_headers;
+
+        let mut col_offset = 0;
+        for custom_header in &header_options.custom_headers {
+            if custom_header.skip {
+                continue;
+            }
+
+            let col = col_initial + col_offset as u16;
+            let mut custom_header = custom_header.clone();
+            custom_header.col = col;
+            max_col = col;
+            col_offset += 1;
+
+            // Set the column width if specified by user.
+            if let Some(width) = custom_header.width {
+                self.set_column_width(col, width)?;
+            } else if let Some(pixel_width) = custom_header.pixel_width {
+                self.set_column_width_pixels(col, pixel_width)?;
+            }
+
+            // Set the column format if specified by user.
+            if let Some(format) = &custom_header.column_format {
+                self.set_column_format(col, format)?;
+            }
+
+            // Use the column specific header format or else the header row
+            // format, and if neither of those have been specified then write
+            // without a format.
+            if write_headers {
+                if let Some(format) = &custom_header.header_format {
+                    self.write_string_with_format(
+                        max_row,
+                        col,
+                        &custom_header.header_name,
+                        format,
+                    )?;
+                } else if let Some(format) = &header_options.header_format {
+                    self.write_string_with_format(
+                        max_row,
+                        col,
+                        &custom_header.header_name,
+                        format,
+                    )?;
+                } else {
+                    self.write_string(max_row, col, &custom_header.header_name)?;
+                };
+            }
+
+            fields.insert(custom_header.field_name.clone(), custom_header);
+        }
+
+        // Start the data serialization one row down if headers were written.
+        if write_headers {
+            max_row += 1;
+        }
+
+        // If a previous serialization was carried out with the same struct name
+        // then write the previous table formatting.
+        if let Some(header_config) = self
+            .serializer_state
+            .structs
+            .get_mut(&header_options.struct_name)
+        {
+            if let Some(table_data) = header_config.get_table() {
+                self.write_serialized_table(&table_data)?;
+            }
+        }
+
+        // Clone the new user defined table format, if present.
+        let table = match &header_options.table {
+            Some(table) => {
+                let mut table = table.clone();
+                if !header_options.has_headers {
+                    table.show_header_row = false;
+                }
+                table.is_serde_table = true;
+                Some(table)
+            }
+            None => None,
+        };
+
+        // Store meta data for the struct/headers.
+        self.serializer_state.structs.insert(
+            header_options.struct_name.clone(),
+            SerializationHeaderConfig {
+                fields,
+                min_row,
+                min_col,
+                max_row,
+                max_col,
+                table,
+            },
+        );
+
+        Ok(self)
+    }
+
+    // Serialize the parent data structure to the worksheet.
+    #[cfg(feature = "serde")]
+    fn serialize_data_structure<T>(&mut self, data_structure: &T) -> Result<(), XlsxError>
+    where
+        T: Serialize,
+    {
+        data_structure.serialize(self)?;
+        Ok(())
+    }
+
+    // Serialize individual data items to a worksheet cell.
+    #[cfg(feature = "serde")]
+    pub(crate) fn serialize_to_worksheet_cell(
+        &mut self,
+        data: impl IntoExcelData,
+    ) -> Result<(), XlsxError> {
+        let result = self.serializer_state.current_state();
+
+        match result {
+            Ok(result) => {
+                let (row, col, value_format) = result;
+                match &*value_format {
+                    Some(format) => self.write_with_format(row, col, data, format).map(|_| ()),
+                    None => self.write(row, col, data).map(|_| ()),
+                }
+            }
+            Err(()) => Ok(()),
+        }
+    }
+
+    // Add any tables that were added as part of serialization formatting.
+    #[cfg(feature = "serde")]
+    pub(crate) fn store_serialized_tables(&mut self) -> Result<&mut Worksheet, XlsxError> {
+        let tables = self.serializer_state.get_tables();
+
+        for table_data in tables {
+            self.write_serialized_table(&table_data)?;
+        }
+
+        Ok(self)
+    }
+
+    // Write a table that is part of serialization formatting.
+    #[cfg(feature = "serde")]
+    pub(crate) fn write_serialized_table(
+        &mut self,
+        table_data: &TableData,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let min_col = table_data.1;
+        let max_col = table_data.3;
+        let min_row = table_data.0;
+        let mut max_row = table_data.2;
+        let table = &table_data.4;
+
+        if table.show_total_row {
+            max_row += 1;
+        }
+
+        self.add_table(min_row, min_col, max_row, max_col, table)
+    }
+
+    // -----------------------------------------------------------------------
+    // Worksheet page setup methods.
+    // -----------------------------------------------------------------------
+
+    /// Display the worksheet cells from right to left for some versions of
+    /// Excel.
     ///
-    /// The default page order is shown below for a worksheet that extends over
-    /// 4 pages. The order is called "down then over":
+    /// The `set_right_to_left()` method is used to change the default direction
+    /// of the worksheet from left-to-right, with the A1 cell in the top left,
+    /// to right-to-left, with the A1 cell in the top right.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_page_order.png">
+    /// This is useful when creating Arabic, Hebrew or other near or far eastern
+    /// worksheets that use right-to-left as the default direction.
     ///
-    /// However, by using `set_page_order(false)` the print order will be
-    /// changed to "over then down".
+    /// Depending on your use case, and text, you may also need to use the
+    /// [`Format::set_reading_direction()`](crate::Format::set_reading_direction)
+    /// method to set the direction of the text within the cells.
     ///
     /// # Parameters
     ///
-    /// - `enable`: Turn the property on/off. Set `true` to get "Down, then
-    ///   over" (the default) and `false` to get "Over, then down".
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet printed page
-    /// order.
+    /// The following example demonstrates changing the default worksheet and
+    /// cell text direction changed from left-to-right to right-to-left, as
+    /// required by some middle eastern versions of Excel.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_page_order.rs
+    /// # // This code is available in examples/doc_worksheet_set_right_to_left.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     // Set the page print to "over then down"
-    ///     worksheet.set_page_order(false);
+    ///     // Add the cell formats.
+    ///     let format_left_to_right = Format::new().set_reading_direction(1);
+    ///     let format_right_to_left = Format::new().set_reading_direction(2);
+    ///
+    ///     // Add a worksheet in the standard left to right direction.
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///
+    ///     // Make the column wider for clarity.
+    ///     worksheet1.set_column_width(0,25)?;
+    ///
+    ///     // Standard direction:         | A1 | B1 | C1 | ...
+    ///     worksheet1.write(0, 0, "نص عربي / English text")?;
+    ///     worksheet1.write_with_format(1, 0, "نص عربي / English text", &format_left_to_right)?;
+    ///     worksheet1.write_with_format(2, 0, "نص عربي / English text", &format_right_to_left)?;
+    ///
+    ///     // Add a worksheet and change it to right to left direction.
+    ///     let worksheet2 = workbook.add_worksheet();
+    ///     worksheet2.set_right_to_left(true);
+    ///
+    ///     // Make the column wider for clarity.
+    ///     worksheet2.set_column_width(0, 25)?;
+    ///
+    ///     // Right to left direction:    ... | C1 | B1 | A1 |
+    ///     worksheet2.write(0, 0, "نص عربي / English text")?;
+    ///     worksheet2.write_with_format(1, 0, "نص عربي / English text", &format_left_to_right)?;
+    ///     worksheet2.write_with_format(2, 0, "نص عربي / English text", &format_right_to_left)?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn set_page_order(&mut self, enable: bool) -> &mut Worksheet {
-        self.default_page_order = enable;
-
-        if !enable {
-            self.page_setup_changed = true;
-        }
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_right_to_left.png">
+    ///
+    pub fn set_right_to_left(&mut self, enable: bool) -> &mut Worksheet {
+        self.right_to_left = enable;
         self
     }
 
-    /// Set the page orientation to landscape.
+    /// Make a worksheet the active/initially visible worksheet in a workbook.
     ///
-    /// The `set_landscape()` method is used to set the orientation of a
-    /// worksheet's printed page to landscape.
+    /// The `set_active()` method is used to specify which worksheet is
+    /// initially visible in a multi-sheet workbook. If no worksheet is set then
+    /// the first worksheet is made the active worksheet, like in Excel.
     ///
-    /// # Examples
+    /// # Parameters
     ///
-    /// The following example demonstrates setting the worksheet page orientation to
-    /// landscape.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_set_landscape.rs
+    /// # Examples
+    ///
+    /// The following example demonstrates setting a worksheet as the visible
+    /// worksheet when a file is opened.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_active.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
+    ///     let worksheet1 = Worksheet::new();
+    ///     let worksheet3 = Worksheet::new();
+    ///     let mut worksheet2 = Worksheet::new();
+    ///
+    ///     worksheet2.set_active(true);
     /// #
-    ///     worksheet.set_landscape();
+    /// #   workbook.push_worksheet(worksheet1);
+    /// #   workbook.push_worksheet(worksheet2);
+    /// #   workbook.push_worksheet(worksheet3);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -12718,94 +13710,59 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    pub fn set_landscape(&mut self) -> &mut Worksheet {
-        self.is_portrait = false;
-        self.page_setup_changed = true;
-        self
-    }
-
-    /// Set the page orientation to portrait.
+    /// Output file:
     ///
-    ///  This `set_portrait()` method  is used to set the orientation of a
-    ///  worksheet's printed page to portrait. The default worksheet orientation
-    ///  is portrait, so this function is rarely required.
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_active.png">
     ///
-    pub fn set_portrait(&mut self) -> &mut Worksheet {
-        self.is_portrait = true;
-        self.page_setup_changed = true;
-        self
-    }
+    pub fn set_active(&mut self, enable: bool) -> &mut Worksheet {
+        self.active = enable;
 
-    /// Set the page view mode to normal layout.
-    ///
-    /// This method is used to display the worksheet in “View -> Normal”
-    /// mode. This is the default.
-    ///
-    pub fn set_view_normal(&mut self) -> &mut Worksheet {
-        self.page_view = PageView::Normal;
-        self
-    }
+        // Activated worksheets must also be selected and cannot be hidden.
+        if self.active {
+            self.selected = true;
+            self.visible = Visible::Default;
+        }
 
-    /// Set the page view mode to page layout.
-    ///
-    /// This method is used to display the worksheet in “View -> Page Layout”
-    /// mode.
-    ///
-    pub fn set_view_page_layout(&mut self) -> &mut Worksheet {
-        self.page_view = PageView::PageLayout;
-        self.page_setup_changed = true;
         self
     }
 
-    /// Set the page view mode to page break preview.
-    ///
-    /// This method is used to display the worksheet in “View -> Page Break
-    /// Preview” mode.
+    /// Set a worksheet tab as selected.
     ///
-    pub fn set_view_page_break_preview(&mut self) -> &mut Worksheet {
-        self.page_view = PageView::PageBreaks;
-        self.page_setup_changed = true;
-        self
-    }
-
-    /// Set the horizontal page breaks on a worksheet.
+    /// The `set_selected()` method is used to indicate that a worksheet is
+    /// selected in a multi-sheet workbook.
     ///
-    /// The `set_page_breaks()` method adds horizontal page breaks to a
-    /// worksheet. A page break causes all the data that follows it to be
-    /// printed on the next page. Horizontal page breaks act between rows.
+    /// A selected worksheet has its tab highlighted. Selecting worksheets is a
+    /// way of grouping them together so that, for example, several worksheets
+    /// could be printed in one go. A worksheet that has been activated via the
+    /// [`Worksheet::set_active()`] method will also appear as selected.
     ///
     /// # Parameters
     ///
-    /// - `breaks`: A list of one or more row numbers where the page breaks
-    ///   occur. To create a page break between rows 20 and 21 you must specify
-    ///   the break at row 21. However in zero index notation this is actually
-    ///   row 20. So you can pretend for a small while that you are using 1
-    ///   index notation.
-    ///
-    /// # Errors
-    ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::ParameterError`] - The number of page breaks exceeds
-    ///   Excel's limit of 1023 page breaks.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting page breaks for a worksheet.
+    /// The following example demonstrates selecting worksheet in a workbook.
+    /// The active worksheet is selected by default so in this example the first
+    /// two worksheets are selected.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_page_breaks.rs
+    /// # // This code is available in examples/doc_worksheet_set_selected.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #     worksheet.write_string(100, 100, "Test")?;
+    ///     let worksheet1 = Worksheet::new();
+    ///     let worksheet3 = Worksheet::new();
+    ///     let mut worksheet2 = Worksheet::new();
+    ///
+    ///     worksheet2.set_selected(true);
     /// #
-    ///     // Set a page break at rows 20, 40 and 60.
-    ///     worksheet.set_page_breaks(&[20, 40, 60])?;
+    /// #   workbook.push_worksheet(worksheet1);
+    /// #   workbook.push_worksheet(worksheet2);
+    /// #   workbook.push_worksheet(worksheet3);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -12816,96 +13773,56 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_page_breaks.png">
-    ///
-    pub fn set_page_breaks(&mut self, breaks: &[RowNum]) -> Result<&mut Worksheet, XlsxError> {
-        // Ignore empty input.
-        if breaks.is_empty() {
-            return Ok(self);
-        }
-
-        // Sort list and remove any duplicates and 0.
-        let breaks = Self::process_pagebreaks(breaks)?;
-
-        // Check max break value is within Excel column limit.
-        if *breaks.last().unwrap() >= ROW_MAX {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        self.horizontal_breaks = breaks;
-
-        Ok(self)
-    }
-
-    /// Set the vertical page breaks on a worksheet.
-    ///
-    /// The `set_vertical_page_breaks()` method adds vertical page breaks to a
-    /// worksheet. This is much less common than the
-    /// [`Worksheet::set_page_breaks()`] method shown above.
-    ///
-    /// # Parameters
-    ///
-    /// - `breaks`: A list of one or more column numbers where the page breaks
-    ///   occur.
-    ///
-    /// # Errors
-    ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::ParameterError`] - The number of page breaks exceeds
-    ///   Excel's limit of 1023 page breaks.
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_selected.png">
     ///
-    pub fn set_vertical_page_breaks(
-        &mut self,
-        breaks: &[u32],
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Ignore empty input.
-        if breaks.is_empty() {
-            return Ok(self);
-        }
-
-        // Sort list and remove any duplicates and 0.
-        let breaks = Self::process_pagebreaks(breaks)?;
+    pub fn set_selected(&mut self, enable: bool) -> &mut Worksheet {
+        self.selected = enable;
 
-        // Check max break value is within Excel col limit.
-        if *breaks.last().unwrap() >= u32::from(COL_MAX) {
-            return Err(XlsxError::RowColumnLimitError);
+        // Selected worksheets cannot be hidden.
+        if self.selected {
+            self.visible = Visible::Default;
         }
 
-        self.vertical_breaks = breaks;
-
-        Ok(self)
+        self
     }
 
-    /// Set the worksheet zoom factor.
+    /// Hide a worksheet.
     ///
-    /// Set the worksheet zoom factor in the range 10 <= zoom <= 400.
+    /// The `set_hidden()` method is used to hide a worksheet. This can be used
+    /// to hide a worksheet in order to avoid confusing a user with intermediate
+    /// data or calculations.
     ///
-    /// The default zoom level is 100. The `set_zoom()` method does not affect
-    /// the scale of the printed page in Excel. For that you should use
-    /// [`Worksheet::set_print_scale()`].
+    /// In Excel a hidden worksheet can not be activated or selected so this
+    /// method is mutually exclusive with the [`Worksheet::set_active()`] and
+    /// [`Worksheet::set_selected()`] methods. In addition, since the first
+    /// worksheet will default to being the active worksheet, you cannot hide
+    /// the first worksheet without activating another sheet.
     ///
     /// # Parameters
     ///
-    /// - `zoom`: The worksheet zoom level.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet zoom level.
+    /// The following example demonstrates hiding a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_zoom.rs
+    /// # // This code is available in examples/doc_worksheet_set_hidden.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
+    ///     let worksheet1 = Worksheet::new();
+    ///     let worksheet3 = Worksheet::new();
+    ///     let mut worksheet2 = Worksheet::new();
+    ///
+    ///     worksheet2.set_hidden(true);
     /// #
-    ///     worksheet.write_string(0, 0, "Hello")?;
-    ///     worksheet.set_zoom(200);
+    /// #    workbook.push_worksheet(worksheet1);
+    /// #    workbook.push_worksheet(worksheet2);
+    /// #    workbook.push_worksheet(worksheet3);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -12916,74 +13833,116 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_zoom.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_hidden.png">
     ///
-    pub fn set_zoom(&mut self, zoom: u16) -> &mut Worksheet {
-        if !(10..=400).contains(&zoom) {
-            eprintln!("Zoom factor {zoom} outside Excel range: 10 <= zoom <= 400.");
-            return self;
+    pub fn set_hidden(&mut self, enable: bool) -> &mut Worksheet {
+        if enable {
+            self.visible = Visible::Hidden;
+        } else {
+            self.visible = Visible::Default;
+        }
+
+        // Hidden worksheets cannot be active or hidden.
+        if self.visible == Visible::Hidden {
+            self.selected = false;
+            self.active = false;
         }
 
-        self.zoom = zoom;
         self
     }
 
-    /// Freeze panes in a worksheet.
-    ///
-    /// The `set_freeze_panes()` method can be used to divide a worksheet into
-    /// horizontal or vertical regions known as panes and to “freeze” these
-    /// panes so that the splitter bars are not visible.
-    ///
-    /// As with Excel the split is to the top and left of the cell. So to freeze
-    /// the top row and leftmost column you would use `(1, 1)` (zero-indexed).
-    /// Also, you can set one of the row and col parameters as 0 if you do not
-    /// want either the vertical or horizontal split. See the example below.
+    /// Hide a worksheet. Can only be unhidden in Excel by VBA.
     ///
-    /// In Excel it is also possible to set "split" panes without freezing them.
-    /// That feature isn't currently supported by `rust_xlsxwriter`.
+    /// The `set_very_hidden()` method can be used to hide a worksheet similar
+    /// to the [`Worksheet::set_hidden()`] method. The difference is that the
+    /// worksheet cannot be unhidden in the the Excel user interface. The Excel
+    /// worksheet `xlSheetVeryHidden` option can only be unset programmatically
+    /// by VBA.
     ///
     /// # Parameters
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
-    ///
-    /// # Errors
-    ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
-    /// # Examples
+    pub fn set_very_hidden(&mut self, enable: bool) -> &mut Worksheet {
+        if enable {
+            self.visible = Visible::VeryHidden;
+        } else {
+            self.visible = Visible::Default;
+        }
+
+        // Hidden worksheets cannot be active or hidden.
+        if self.visible == Visible::VeryHidden {
+            self.selected = false;
+            self.active = false;
+        }
+
+        self
+    }
+
+    /// Set current worksheet as the first visible sheet tab.
     ///
-    /// The following example demonstrates setting the worksheet panes.
+    /// The [`Worksheet::set_active()`]  method determines which worksheet is
+    /// initially selected. However, if there are a large number of worksheets
+    /// the selected worksheet may not appear on the screen. To avoid this you
+    /// can select which is the leftmost visible worksheet tab using
+    /// `set_first_tab()`.
+    ///
+    /// This method is not required very often. The default is the first
+    /// worksheet.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_first_tab(&mut self, enable: bool) -> &mut Worksheet {
+        self.first_sheet = enable;
+
+        // First visible worksheet cannot be hidden.
+        if self.selected {
+            self.visible = Visible::Default;
+        }
+        self
+    }
+
+    /// Set the color of the worksheet tab.
+    ///
+    /// The `set_tab_color()` method can be used to change the color of the
+    /// worksheet tab. This is useful for highlighting the important tab in a
+    /// group of worksheets.
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: The tab color property defined by a [`Color`] enum
+    ///   value.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates set the tab color of worksheets.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_freeze_panes.rs
+    /// # // This code is available in examples/doc_worksheet_set_tab_color.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, Worksheet, Color, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     let mut worksheet1 = Worksheet::new();
-    /// #     let mut worksheet2 = Worksheet::new();
-    /// #     let mut worksheet3 = Worksheet::new();
-    /// #
-    /// #     worksheet1.write_string(0, 0, "Scroll down")?;
-    /// #     worksheet2.write_string(0, 0, "Scroll across")?;
-    /// #     worksheet3.write_string(0, 0, "Scroll down or across")?;
-    /// #
-    ///     // Freeze the top row only.
-    ///     worksheet1.set_freeze_panes(1, 0)?;
+    ///     let mut worksheet1 = Worksheet::new();
+    ///     let mut worksheet2 = Worksheet::new();
+    ///     let mut worksheet3 = Worksheet::new();
+    ///     let mut worksheet4 = Worksheet::new();
     ///
-    ///     // Freeze the leftmost column only.
-    ///     worksheet2.set_freeze_panes(0, 1)?;
+    ///     worksheet1.set_tab_color(Color::Red);
+    ///     worksheet2.set_tab_color(Color::Green);
+    ///     worksheet3.set_tab_color(Color::RGB(0xFF9900));
     ///
-    ///     // Freeze the top row and leftmost column.
-    ///     worksheet3.set_freeze_panes(1, 1)?;
+    ///     // worksheet4 will have the default color.
+    ///     worksheet4.set_active(true);
     /// #
-    /// #     workbook.push_worksheet(worksheet1);
-    /// #     workbook.push_worksheet(worksheet2);
-    /// #     workbook.push_worksheet(worksheet3);
+    /// #    workbook.push_worksheet(worksheet1);
+    /// #    workbook.push_worksheet(worksheet2);
+    /// #    workbook.push_worksheet(worksheet3);
+    /// #    workbook.push_worksheet(worksheet4);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -12993,252 +13952,173 @@ impl Worksheet {
     ///
     /// Output file:
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes.png">
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_tab_color.png">
     ///
-    pub fn set_freeze_panes(
-        &mut self,
-        row: RowNum,
-        col: ColNum,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check row and col are in the allowed range.
-        if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+    pub fn set_tab_color(&mut self, color: impl Into<Color>) -> &mut Worksheet {
+        let color = color.into();
+        if color.is_valid() {
+            self.tab_color = color;
         }
 
-        self.panes.freeze_cell = (row, col);
-        Ok(self)
+        self
     }
 
-    /// Set the top most cell in the scrolling area of a freeze pane.
+    /// Set the paper type/size when printing.
     ///
-    /// This method is used in conjunction with the
-    /// [`Worksheet::set_freeze_panes()`] method to set the top most visible
-    /// cell in the scrolling range. For example you may want to freeze the top
-    /// row but have the worksheet pre-scrolled so that cell `A20` is visible in
-    /// the scrolled area. See the example below.
+    /// This method is used to set the paper format for the printed output of a
+    /// worksheet. The following paper styles are available:
     ///
-    /// # Parameters
+    /// | Index    | Paper format            | Paper size           |
+    /// | :------- | :---------------------- | :------------------- |
+    /// | 0        | Printer default         | Printer default      |
+    /// | 1        | Letter                  | 8 1/2 x 11 in        |
+    /// | 2        | Letter Small            | 8 1/2 x 11 in        |
+    /// | 3        | Tabloid                 | 11 x 17 in           |
+    /// | 4        | Ledger                  | 17 x 11 in           |
+    /// | 5        | Legal                   | 8 1/2 x 14 in        |
+    /// | 6        | Statement               | 5 1/2 x 8 1/2 in     |
+    /// | 7        | Executive               | 7 1/4 x 10 1/2 in    |
+    /// | 8        | A3                      | 297 x 420 mm         |
+    /// | 9        | A4                      | 210 x 297 mm         |
+    /// | 10       | A4 Small                | 210 x 297 mm         |
+    /// | 11       | A5                      | 148 x 210 mm         |
+    /// | 12       | B4                      | 250 x 354 mm         |
+    /// | 13       | B5                      | 182 x 257 mm         |
+    /// | 14       | Folio                   | 8 1/2 x 13 in        |
+    /// | 15       | Quarto                  | 215 x 275 mm         |
+    /// | 16       | ---                     | 10x14 in             |
+    /// | 17       | ---                     | 11x17 in             |
+    /// | 18       | Note                    | 8 1/2 x 11 in        |
+    /// | 19       | Envelope 9              | 3 7/8 x 8 7/8        |
+    /// | 20       | Envelope 10             | 4 1/8 x 9 1/2        |
+    /// | 21       | Envelope 11             | 4 1/2 x 10 3/8       |
+    /// | 22       | Envelope 12             | 4 3/4 x 11           |
+    /// | 23       | Envelope 14             | 5 x 11 1/2           |
+    /// | 24       | C size sheet            | ---                  |
+    /// | 25       | D size sheet            | ---                  |
+    /// | 26       | E size sheet            | ---                  |
+    /// | 27       | Envelope DL             | 110 x 220 mm         |
+    /// | 28       | Envelope C3             | 324 x 458 mm         |
+    /// | 29       | Envelope C4             | 229 x 324 mm         |
+    /// | 30       | Envelope C5             | 162 x 229 mm         |
+    /// | 31       | Envelope C6             | 114 x 162 mm         |
+    /// | 32       | Envelope C65            | 114 x 229 mm         |
+    /// | 33       | Envelope B4             | 250 x 353 mm         |
+    /// | 34       | Envelope B5             | 176 x 250 mm         |
+    /// | 35       | Envelope B6             | 176 x 125 mm         |
+    /// | 36       | Envelope                | 110 x 230 mm         |
+    /// | 37       | Monarch                 | 3.875 x 7.5 in       |
+    /// | 38       | Envelope                | 3 5/8 x 6 1/2 in     |
+    /// | 39       | Fanfold                 | 14 7/8 x 11 in       |
+    /// | 40       | German Std Fanfold      | 8 1/2 x 12 in        |
+    /// | 41       | German Legal Fanfold    | 8 1/2 x 13 in        |
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
+    /// Note, it is likely that not all of these paper types will be available
+    /// to the end user since it will depend on the paper formats that the
+    /// user's printer supports. Therefore, it is best to stick to standard
+    /// paper types of 1 for US Letter and 9 for A4.
     ///
-    /// # Errors
+    /// If you do not specify a paper type the worksheet will print using the
+    /// printer's default paper style.
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
+    /// # Parameters
+    ///
+    /// - `paper_size`: The paper size index from the list above .
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet panes and also
-    /// setting the topmost visible cell in the scrolled area.
+    /// The following example demonstrates setting the worksheet paper size/type for
+    /// the printed output.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_freeze_panes_top_cell.rs
+    /// # // This code is available in examples/doc_worksheet_set_paper.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
+    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     worksheet.write_string(0, 0, "Scroll down")?;
-    /// #
-    ///     // Freeze the top row only.
-    ///     worksheet.set_freeze_panes(1, 0)?;
+    ///     // Set the printer paper size.
+    ///     worksheet.set_paper_size(9); // A4 paper size.
     ///
-    ///     // Pre-scroll to the row 20.
-    ///     worksheet.set_freeze_panes_top_cell(19, 0)?;
-    /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
+    pub fn set_paper_size(&mut self, paper_size: u8) -> &mut Worksheet {
+        self.paper_size = paper_size;
+        self.page_setup_changed = true;
+        self
+    }
+
+    /// Set the order in which pages are printed.
     ///
-    /// Output file:
+    /// The `set_page_order()` method is used to change the default print
+    /// direction. This is referred to by Excel as the sheet "page order":
+    ///
+    /// The default page order is shown below for a worksheet that extends over
+    /// 4 pages. The order is called "down then over":
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes_top_cell.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_page_order.png">
     ///
-    pub fn set_freeze_panes_top_cell(
-        &mut self,
-        row: RowNum,
-        col: ColNum,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check row and col are in the allowed range.
-        if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        self.panes.top_cell = (row, col);
-        Ok(self)
-    }
-
-    /// Set the printed page header caption.
+    /// However, by using `set_page_order(false)` the print order will be
+    /// changed to "over then down".
     ///
-    /// The `set_header()` method can be used to set the header for a worksheet.
+    /// # Parameters
     ///
-    /// Headers and footers are generated using a string which is a combination
-    /// of plain text and optional control characters.
+    /// - `enable`: Turn the property on/off. Set `true` to get "Down, then
+    ///   over" (the default) and `false` to get "Over, then down".
     ///
-    /// The available control characters are:
+    /// # Examples
     ///
-    /// | Control              | Category      | Description           |
-    /// | -------------------- | ------------- | --------------------- |
-    /// | `&L`                 | Alignment     | Left                  |
-    /// | `&C`                 |               | Center                |
-    /// | `&R`                 |               | Right                 |
-    /// | `&[Page]`  or `&P`   | Information   | Page number           |
-    /// | `&[Pages]` or `&N`   |               | Total number of pages |
-    /// | `&[Date]`  or `&D`   |               | Date                  |
-    /// | `&[Time]`  or `&T`   |               | Time                  |
-    /// | `&[File]`  or `&F`   |               | File name             |
-    /// | `&[Tab]`   or `&A`   |               | Worksheet name        |
-    /// | `&[Path]`  or `&Z`   |               | Workbook path         |
-    /// | `&fontsize`          | Font          | Font size             |
-    /// | `&"font,style"`      |               | Font name and style   |
-    /// | `&U`                 |               | Single underline      |
-    /// | `&E`                 |               | Double underline      |
-    /// | `&S`                 |               | Strikethrough         |
-    /// | `&X`                 |               | Superscript           |
-    /// | `&Y`                 |               | Subscript             |
-    /// | `&[Picture]` or `&G` | Images        | Picture/image         |
-    /// | `&&`                 | Miscellaneous | Literal ampersand &   |
+    /// The following example demonstrates setting the worksheet printed page
+    /// order.
     ///
-    /// Some of the placeholder variables have a long version like `&[Page]` and
-    /// a short version like `&P`. The longer version is displayed in the Excel
-    /// interface but the shorter version is the way that it is stored in the
-    /// file format. Either version is okay since `rust_xlsxwriter` will
-    /// translate as required.
-    ///
-    /// Headers and footers have 3 edit areas to the left, center and right.
-    /// Text can be aligned to these areas by prefixing the text with the
-    /// control characters `&L`, `&C` and `&R`.
-    ///
-    /// For example:
-    ///
-    /// ```text
-    /// worksheet.set_header("&LHello");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// | Hello                                                         |
-    /// |                                                               |
-    ///
-    /// worksheet.set_header("&CHello");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// |                          Hello                                |
-    /// |                                                               |
-    ///
-    /// worksheet.set_header("&RHello");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// |                                                         Hello |
-    /// |                                                               |
-    /// ```
-    ///
-    /// You can also have text in each of the alignment areas:
-    ///
-    /// ```text
-    /// worksheet.set_header("&LCiao&CBello&RCielo");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// | Ciao                     Bello                          Cielo |
-    /// |                                                               |
-    /// ```
-    ///
-    /// The information control characters act as variables/templates that Excel
-    /// will update/expand as the workbook or worksheet changes.
-    ///
-    /// ```text
-    /// worksheet.set_header("&CPage &[Page] of &[Pages]");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// |                        Page 1 of 6                            |
-    /// |                                                               |
-    /// ```
-    ///
-    /// Times and dates are in the user's default format:
-    ///
-    /// ```text
-    /// worksheet.set_header("&CUpdated at &[Time]");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// |                    Updated at 12:30 PM                        |
-    /// |                                                               |
-    /// ```
-    ///
-    /// To include a single literal ampersand `&` in a header or footer you
-    /// should use a double ampersand `&&`:
-    ///
-    /// ```text
-    /// worksheet.set_header("&CCuriouser && Curiouser - Attorneys at Law");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// |                   Curiouser & Curiouser                       |
-    /// |                                                               |
-    /// ```
-    ///
-    /// You can specify the font size of a section of the text by prefixing it
-    /// with the control character `&n` where `n` is the font size:
-    ///
-    /// ```text
-    /// worksheet1.set_header("&C&30Hello Big");
-    /// worksheet2.set_header("&C&10Hello Small");
-    /// ```
-    ///
-    /// You can specify the font of a section of the text by prefixing it with
-    /// the control sequence `&"font,style"` where `fontname` is a font name
-    /// such as Windows font descriptions: "Regular", "Italic", "Bold" or "Bold
-    /// Italic": "Courier New" or "Times New Roman" and `style` is one of the
-    /// standard Windows font descriptions like “Regular”, “Italic”, “Bold” or
-    /// “Bold Italic”:
-    ///
-    /// ```text
-    /// worksheet1.set_header(r#"&C&"Courier New,Italic"Hello"#);
-    /// worksheet2.set_header(r#"&C&"Courier New,Bold Italic"Hello"#);
-    /// worksheet3.set_header(r#"&C&"Times New Roman,Regular"Hello"#);
     /// ```
-    ///
-    /// It is possible to combine all of these features together to create
-    /// complex headers and footers. If you set up a complex header in Excel you
-    /// can transfer it to `rust_xlsxwriter` by inspecting the string in the
-    /// Excel file. For example the following shows how unzip and grep the Excel
-    /// XML sub-files on a Linux system. The example uses libxml's xmllint to
-    /// format the XML for clarity:
-    ///
-    /// ```text
-    /// $ unzip myfile.xlsm -d myfile
-    /// $ xmllint --format `find myfile -name "*.xml" | xargs` | \
-    ///     egrep "Header|Footer" | sed 's/&amp;/\&/g'
-    ///
-    ///  <headerFooter scaleWithDoc="0">
-    ///    <oddHeader>&L&P</oddHeader>
-    ///  </headerFooter>
+    /// # // This code is available in examples/doc_worksheet_set_page_order.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Set the page print to "over then down"
+    ///     worksheet.set_page_order(false);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
     /// ```
+    pub fn set_page_order(&mut self, enable: bool) -> &mut Worksheet {
+        self.default_page_order = enable;
+
+        if !enable {
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Set the page orientation to landscape.
     ///
-    /// Note: Excel requires that the header or footer string be less than 256
-    /// characters, including the control characters. Strings longer than this
-    /// will not be written, and a warning will be output.
-    ///
-    /// # Parameters
-    ///
-    /// - `header`: The header string with optional control characters.
+    /// The `set_landscape()` method is used to set the orientation of a
+    /// worksheet's printed page to landscape.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet header.
+    /// The following example demonstrates setting the worksheet page orientation to
+    /// landscape.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_header.rs
+    /// # // This code is available in examples/doc_worksheet_set_landscape.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
@@ -13248,11 +14128,7 @@ impl Worksheet {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     worksheet.set_header("&CPage &P of &N");
-    /// #
-    /// #     worksheet.write_string(0, 0, "Hello")?;
-    /// #     worksheet.write_string(200, 0, "Hello")?;
-    /// #     worksheet.set_view_page_layout();
+    ///     worksheet.set_landscape();
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -13260,117 +14136,95 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
+    pub fn set_landscape(&mut self) -> &mut Worksheet {
+        self.is_portrait = false;
+        self.page_setup_changed = true;
+        self
+    }
+
+    /// Set the page orientation to portrait.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_header.png">
+    ///  This `set_portrait()` method  is used to set the orientation of a
+    ///  worksheet's printed page to portrait. The default worksheet orientation
+    ///  is portrait, so this function is rarely required.
     ///
-    pub fn set_header(&mut self, header: impl Into<String>) -> &mut Worksheet {
-        let header = header.into();
-        let header_expanded = header
-            .replace("&[Tab]", "&A")
-            .replace("&[Date]", "&D")
-            .replace("&[File]", "&F")
-            .replace("&[Page]", "&P")
-            .replace("&[Path]", "&Z")
-            .replace("&[Time]", "&T")
-            .replace("&[Pages]", "&N")
-            .replace("&[Picture]", "&G");
-
-        if header_expanded.chars().count() > 255 {
-            eprintln!("Header string exceeds Excel's limit of 255 characters.");
-            return self;
-        }
-
-        self.header = header;
+    pub fn set_portrait(&mut self) -> &mut Worksheet {
+        self.is_portrait = true;
         self.page_setup_changed = true;
-        self.head_footer_changed = true;
         self
     }
 
-    /// Set the printed page footer caption.
-    ///
-    /// The `set_footer()` method can be used to set the footer for a worksheet.
+    /// Set the page view mode to normal layout.
     ///
-    /// See the documentation for [`Worksheet::set_header()`] for more details
-    /// on the syntax of the header/footer string.
+    /// This method is used to display the worksheet in “View -> Normal”
+    /// mode. This is the default.
     ///
-    /// # Parameters
+    pub fn set_view_normal(&mut self) -> &mut Worksheet {
+        self.page_view = PageView::Normal;
+        self
+    }
+
+    /// Set the page view mode to page layout.
     ///
-    /// - `footer`: The footer string with optional control characters.
+    /// This method is used to display the worksheet in “View -> Page Layout”
+    /// mode.
     ///
-    pub fn set_footer(&mut self, footer: impl Into<String>) -> &mut Worksheet {
-        let footer = footer.into();
-        let footer_expanded = footer
-            .replace("&[Tab]", "&A")
-            .replace("&[Date]", "&D")
-            .replace("&[File]", "&F")
-            .replace("&[Page]", "&P")
-            .replace("&[Path]", "&Z")
-            .replace("&[Time]", "&T")
-            .replace("&[Pages]", "&N")
-            .replace("&[Picture]", "&G");
-
-        if footer_expanded.chars().count() > 255 {
-            eprintln!("Footer string exceeds Excel's limit of 255 characters.");
-            return self;
-        }
+    pub fn set_view_page_layout(&mut self) -> &mut Worksheet {
+        self.page_view = PageView::PageLayout;
+        self.page_setup_changed = true;
+        self
+    }
 
-        self.footer = footer;
+    /// Set the page view mode to page break preview.
+    ///
+    /// This method is used to display the worksheet in “View -> Page Break
+    /// Preview” mode.
+    ///
+    pub fn set_view_page_break_preview(&mut self) -> &mut Worksheet {
+        self.page_view = PageView::PageBreaks;
         self.page_setup_changed = true;
-        self.head_footer_changed = true;
         self
     }
 
-    /// Insert an image in a worksheet header.
+    /// Set the horizontal page breaks on a worksheet.
     ///
-    /// Insert an image in a worksheet header in one of the 3 sections supported
-    /// by Excel: Left, Center and Right. This needs to be preceded by a call to
-    /// [`Worksheet::set_header()`] where a corresponding `&[Picture]` element
-    /// is added to the header formatting string such as `"&L&[Picture]"`.
+    /// The `set_page_breaks()` method adds horizontal page breaks to a
+    /// worksheet. A page break causes all the data that follows it to be
+    /// printed on the next page. Horizontal page breaks act between rows.
     ///
     /// # Parameters
     ///
-    /// - `position`: The image position as defined by the
-    ///   [`HeaderImagePosition`] enum.
+    /// - `breaks`: A list of one or more row numbers where the page breaks
+    ///   occur. To create a page break between rows 20 and 21 you must specify
+    ///   the break at row 21. However in zero index notation this is actually
+    ///   row 20. So you can pretend for a small while that you are using 1
+    ///   index notation.
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
-    ///   corresponding `&[Picture]`/`&[G]` variable in the header string.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The number of page breaks exceeds
+    ///   Excel's limit of 1023 page breaks.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates adding a header image to a worksheet.
+    /// The following example demonstrates setting page breaks for a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_header_image.rs
+    /// # // This code is available in examples/doc_worksheet_set_page_breaks.rs
     /// #
-    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError, HeaderImagePosition};
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.write_string(100, 100, "Test")?;
     /// #
-    /// #     // Scale the image so it fits in the header.
-    ///       let image = Image::new("examples/rust_logo.png")?
-    ///           .set_scale_height(0.5)
-    ///           .set_scale_width(0.5);
+    ///     // Set a page break at rows 20, 40 and 60.
+    ///     worksheet.set_page_breaks(&[20, 40, 60])?;
     /// #
-    ///     // Insert the watermark image in the header.
-    ///     worksheet.set_header("&C&[Picture]");
-    ///     worksheet.set_header_image(&image, HeaderImagePosition::Center)?;
-    /// #
-    /// #     // Increase the top margin to 1.2 for clarity. The -1.0 values are ignored.
-    /// #     worksheet.set_margins(-1.0, -1.0, 1.2, -1.0, -1.0, -1.0);
-    /// #
-    /// #     // Set Page View mode so the watermark is visible.
-    /// #     worksheet.set_view_page_layout();
-    /// #
-    /// #     // Save the file to disk.
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
@@ -13380,175 +14234,85 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_header_image.png">
-    ///
-    /// An example of adding a worksheet watermark image using the
-    /// `rust_xlsxwriter` library. This is based on the method of putting an
-    /// image in the worksheet header as suggested in the [Microsoft
-    /// documentation].
-    ///
-    /// [Microsoft documentation]:
-    ///     https://support.microsoft.com/en-us/office/add-a-watermark-in-excel-a372182a-d733-484e-825c-18ddf3edf009
-    ///
-    /// ```
-    /// # // This code is available in examples/app_watermark.rs
-    /// #
-    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError, HeaderImagePosition};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     let image = Image::new("examples/watermark.png")?;
-    ///
-    ///     // Insert the watermark image in the header.
-    ///     worksheet.set_header("&C&[Picture]");
-    ///     worksheet.set_header_image(&image, HeaderImagePosition::Center)?;
-    /// #
-    /// #     // Set Page View mode so the watermark is visible.
-    /// #     worksheet.set_view_page_layout();
-    /// #
-    /// #     // Save the file to disk.
-    /// #     workbook.save("watermark.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
-    ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/app_watermark.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_page_breaks.png">
     ///
-    pub fn set_header_image(
-        &mut self,
-        image: &Image,
-        position: HeaderImagePosition,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check that there is a matching  &[Picture]/&[G] variable in the
-        // header string.
-        if !Self::verify_header_footer_image(&self.header, &position) {
-            let error = format!(
-                "No &[Picture] or &[G] variable in header string: '{}' for position = '{:?}'",
-                self.header, position
-            );
-            return Err(XlsxError::ParameterError(error));
+    pub fn set_page_breaks(&mut self, breaks: &[RowNum]) -> Result<&mut Worksheet, XlsxError> {
+        // Ignore empty input.
+        if breaks.is_empty() {
+            return Ok(self);
         }
 
-        let mut image = image.clone();
-        image.header_position = position.clone();
-        image.is_header = true;
-        self.header_footer_images[position as usize] = Some(image);
+        // Sort list and remove any duplicates and 0.
+        let breaks = Self::process_pagebreaks(breaks)?;
+
+        // Check max break value is within Excel column limit.
+        if *breaks.last().unwrap() >= ROW_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        self.horizontal_breaks = breaks;
 
         Ok(self)
     }
 
-    /// Insert an image in a worksheet footer.
+    /// Set the vertical page breaks on a worksheet.
     ///
-    /// See the documentation for [`Worksheet::set_header_image()`] for more
-    /// details.
+    /// The `set_vertical_page_breaks()` method adds vertical page breaks to a
+    /// worksheet. This is much less common than the
+    /// [`Worksheet::set_page_breaks()`] method shown above.
     ///
     /// # Parameters
     ///
-    /// - `position`: The image position as defined by the
-    ///   [`HeaderImagePosition`] enum.
+    /// - `breaks`: A list of one or more column numbers where the page breaks
+    ///   occur.
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
-    ///   corresponding `&[Picture]`/`&[G]` variable in the header string.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The number of page breaks exceeds
+    ///   Excel's limit of 1023 page breaks.
     ///
-    pub fn set_footer_image(
+    pub fn set_vertical_page_breaks(
         &mut self,
-        image: &Image,
-        position: HeaderImagePosition,
+        breaks: &[u32],
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check that there is a matching  &[Picture]/&[G] variable in the
-        // footer string.
-        if !Self::verify_header_footer_image(&self.footer, &position) {
-            let error = format!(
-                "No &[Picture] or &[G] variable in footer string: '{}' for position = '{:?}'",
-                self.footer, position
-            );
-            return Err(XlsxError::ParameterError(error));
+        // Ignore empty input.
+        if breaks.is_empty() {
+            return Ok(self);
         }
 
-        let mut image = image.clone();
-        image.header_position = position.clone();
-        image.is_header = false;
-        self.header_footer_images[3 + position as usize] = Some(image);
-
-        Ok(self)
-    }
-
-    /// Set the page setup option to scale the header/footer with the document.
-    ///
-    /// This option determines whether the headers and footers use the same
-    /// scaling as the worksheet. This defaults to "on" in Excel.
-    ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Header/Footer](../worksheet/index.html#page-setup---headerfooter).
-    ///
-    /// # Parameters
-    ///
-    /// - `enable`: Turn the property on/off. It is on by default.
-    ///
-    pub fn set_header_footer_scale_with_doc(&mut self, enable: bool) -> &mut Worksheet {
-        self.header_footer_scale_with_doc = enable;
+        // Sort list and remove any duplicates and 0.
+        let breaks = Self::process_pagebreaks(breaks)?;
 
-        if !enable {
-            self.page_setup_changed = true;
-            self.head_footer_changed = true;
+        // Check max break value is within Excel col limit.
+        if *breaks.last().unwrap() >= u32::from(COL_MAX) {
+            return Err(XlsxError::RowColumnLimitError);
         }
 
-        self
-    }
-
-    /// Set the page setup option to align the header/footer with the margins.
-    ///
-    /// This option determines whether the headers and footers align with the
-    /// left and right margins of the worksheet. This defaults to "on" in Excel.
-    ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Header/Footer](../worksheet/index.html#page-setup---headerfooter).
-    ///
-    /// # Parameters
-    ///
-    /// - `enable`: Turn the property on/off. It is on by default. S
-    pub fn set_header_footer_align_with_page(&mut self, enable: bool) -> &mut Worksheet {
-        self.header_footer_align_with_page = enable;
+        self.vertical_breaks = breaks;
 
-        if !enable {
-            self.page_setup_changed = true;
-            self.head_footer_changed = true;
-        }
-        self
+        Ok(self)
     }
 
-    /// Set the page margins.
+    /// Set the worksheet zoom factor.
     ///
-    /// The `set_margins()` method is used to set the margins of the worksheet
-    /// when it is printed. The units are in inches. Specifying `-1.0` for any
-    /// parameter will give the default Excel value. The defaults are shown
-    /// below.
+    /// Set the worksheet zoom factor in the range 10 <= zoom <= 400.
+    ///
+    /// The default zoom level is 100. The `set_zoom()` method does not affect
+    /// the scale of the printed page in Excel. For that you should use
+    /// [`Worksheet::set_print_scale()`].
     ///
     /// # Parameters
     ///
-    /// - `left`: Left margin in inches. Excel default is 0.7.
-    /// - `right`: Right margin in inches. Excel default is 0.7.
-    /// - `top`: Top margin in inches. Excel default is 0.75.
-    /// - `bottom`: Bottom margin in inches. Excel default is 0.75.
-    /// - `header`: Header margin in inches. Excel default is 0.3.
-    /// - `footer`: Footer margin in inches. Excel default is 0.3.
+    /// - `zoom`: The worksheet zoom level.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet margins.
+    /// The following example demonstrates setting the worksheet zoom level.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_margins.rs
+    /// # // This code is available in examples/doc_worksheet_set_zoom.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
@@ -13558,7 +14322,8 @@ impl Worksheet {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     worksheet.set_margins(1.0, 1.25, 1.5, 1.75, 0.75, 0.25);
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///     worksheet.set_zoom(200);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -13569,123 +14334,74 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_margins.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_zoom.png">
     ///
-    pub fn set_margins(
-        &mut self,
-        left: f64,
-        right: f64,
-        top: f64,
-        bottom: f64,
-        header: f64,
-        footer: f64,
-    ) -> &mut Worksheet {
-        if left >= 0.0 {
-            self.margin_left = left;
-            self.page_setup_changed = true;
-        }
-        if right >= 0.0 {
-            self.margin_right = right;
-            self.page_setup_changed = true;
-        }
-        if top >= 0.0 {
-            self.margin_top = top;
-            self.page_setup_changed = true;
-        }
-        if bottom >= 0.0 {
-            self.margin_bottom = bottom;
-            self.page_setup_changed = true;
-        }
-        if header >= 0.0 {
-            self.margin_header = header;
-            self.page_setup_changed = true;
-        }
-        if footer >= 0.0 {
-            self.margin_footer = footer;
-            self.page_setup_changed = true;
+    pub fn set_zoom(&mut self, zoom: u16) -> &mut Worksheet {
+        if !(10..=400).contains(&zoom) {
+            eprintln!("Zoom factor {zoom} outside Excel range: 10 <= zoom <= 400.");
+            return self;
         }
 
+        self.zoom = zoom;
         self
     }
 
-    /// Set the first page number when printing.
+    /// Freeze panes in a worksheet.
     ///
-    /// The `set_print_first_page_number()` method is used to set the page
-    /// number of the first page when the worksheet is printed out. This option
-    /// will only have and effect if you have a header/footer with the `&[Page]`
-    /// control character, see [`Worksheet::set_header()`].
+    /// The `set_freeze_panes()` method can be used to divide a worksheet into
+    /// horizontal or vertical regions known as panes and to “freeze” these
+    /// panes so that the splitter bars are not visible.
     ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Page](../worksheet/index.html#page-setup---page).
+    /// As with Excel the split is to the top and left of the cell. So to freeze
+    /// the top row and leftmost column you would use `(1, 1)` (zero-indexed).
+    /// Also, you can set one of the row and col parameters as 0 if you do not
+    /// want either the vertical or horizontal split. See the example below.
+    ///
+    /// In Excel it is also possible to set "split" panes without freezing them.
+    /// See [`Worksheet::set_split_panes()`].
     ///
     /// # Parameters
     ///
-    /// - `page_number`: The page number of the first printed page.
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the page number on the
-    /// printed page.
+    /// The following example demonstrates setting the worksheet panes.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_print_first_page_number.rs
+    /// # // This code is available in examples/doc_worksheet_set_freeze_panes.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     worksheet.set_header("&CPage &P of &N");
-    ///     worksheet.set_print_first_page_number(2);
+    /// #     let mut worksheet1 = Worksheet::new();
+    /// #     let mut worksheet2 = Worksheet::new();
+    /// #     let mut worksheet3 = Worksheet::new();
     /// #
-    /// #     workbook.save("worksheet.xlsx")?;
+    /// #     worksheet1.write_string(0, 0, "Scroll down")?;
+    /// #     worksheet2.write_string(0, 0, "Scroll across")?;
+    /// #     worksheet3.write_string(0, 0, "Scroll down or across")?;
     /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
-    pub fn set_print_first_page_number(&mut self, page_number: u16) -> &mut Worksheet {
-        self.first_page_number = page_number;
-        self.page_setup_changed = true;
-        self
-    }
-
-    /// Set the page setup option to set the print scale.
-    ///
-    /// Set the scale factor of the printed page, in the range 10 <= scale <=
-    /// 400.
-    ///
-    /// The default scale factor is 100. The `set_print_scale()` method
-    /// does not affect the scale of the visible page in Excel. For that you
-    /// should use [`Worksheet::set_zoom()`].
-    ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Page](../worksheet/index.html#page-setup---page).
-    ///
-    /// # Parameters
-    ///
-    /// - `scale`: The print scale factor.
-    ///
-    /// # Examples
+    ///     // Freeze the top row only.
+    ///     worksheet1.set_freeze_panes(1, 0)?;
     ///
-    /// The following example demonstrates setting the scale of the worksheet page
-    /// when printed.
+    ///     // Freeze the leftmost column only.
+    ///     worksheet2.set_freeze_panes(0, 1)?;
     ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_set_print_scale.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
+    ///     // Freeze the top row and leftmost column.
+    ///     worksheet3.set_freeze_panes(1, 1)?;
     /// #
-    ///     // Scale the printed worksheet to 50%.
-    ///     worksheet.set_print_scale(50);
+    /// #     workbook.push_worksheet(worksheet1);
+    /// #     workbook.push_worksheet(worksheet2);
+    /// #     workbook.push_worksheet(worksheet3);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -13693,78 +14409,65 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    pub fn set_print_scale(&mut self, scale: u16) -> &mut Worksheet {
-        if !(10..=400).contains(&scale) {
-            eprintln!("Scale factor {scale} outside Excel range: 10 <= zoom <= 400.");
-            return self;
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes.png">
+    ///
+    pub fn set_freeze_panes(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
         }
 
-        // This property is mutually exclusive with fit to page.
-        self.fit_to_page = false;
-
-        self.print_scale = scale;
-        self.page_setup_changed = true;
-        self
+        self.panes.freeze_cell = (row, col);
+        self.panes.split_size = (0, 0);
+        Ok(self)
     }
 
-    /// Fit the printed area to a specific number of pages both vertically and
-    /// horizontally.
-    ///
-    /// The `set_print_fit_to_pages()` method is used to fit the printed area to
-    /// a specific number of pages both vertically and horizontally. If the
-    /// printed area exceeds the specified number of pages it will be scaled
-    /// down to fit. This ensures that the printed area will always appear on
-    /// the specified number of pages even if the page size or margins change:
-    ///
-    /// ```text
-    ///     worksheet1.set_print_fit_to_pages(1, 1); // Fit to 1x1 pages.
-    ///     worksheet2.set_print_fit_to_pages(2, 1); // Fit to 2x1 pages.
-    ///     worksheet3.set_print_fit_to_pages(1, 2); // Fit to 1x2 pages.
-    /// ```
-    ///
-    /// The print area can be defined using the `set_print_area()` method.
-    ///
-    /// A common requirement is to fit the printed output to `n` pages wide but
-    /// have the height be as long as necessary. To achieve this set the
-    /// `height` to 0, see the example below.
-    ///
-    /// **Notes**:
+    /// Set the top most cell in the scrolling area of a freeze pane.
     ///
-    /// - The `set_print_fit_to_pages()` will override any manual page breaks
-    ///   that are defined in the worksheet.
+    /// This method is used in conjunction with the
+    /// [`Worksheet::set_freeze_panes()`] method to set the top most visible
+    /// cell in the scrolling range. For example you may want to freeze the top
+    /// row but have the worksheet pre-scrolled so that cell `A20` is visible in
+    /// the scrolled area. See the example below.
     ///
-    /// - When using `set_print_fit_to_pages()` it may also be required to set
-    ///   the printer paper size using [`Worksheet::set_paper_size()`] or else
-    ///   Excel will default to "US Letter".
+    /// # Parameters
     ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Page](../worksheet/index.html#page-setup---page).
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
     ///
-    /// # Parameters
+    /// # Errors
     ///
-    /// - `width`: Number of pages horizontally.
-    /// - `height`: Number of pages vertically.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the scale of the worksheet to
-    /// fit a defined number of pages vertically and horizontally. This example
-    /// shows a common use case which is to fit the printed output to 1 page
-    /// wide but have the height be as long as necessary.
+    /// The following example demonstrates setting the worksheet panes and also
+    /// setting the topmost visible cell in the scrolled area.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_print_fit_to_pages.rs
+    /// # // This code is available in examples/doc_worksheet_set_freeze_panes_top_cell.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Set the printed output to fit 1 page wide and as long as necessary.
-    ///     worksheet.set_print_fit_to_pages(1, 0);
+    /// #     worksheet.write_string(0, 0, "Scroll down")?;
+    /// #
+    ///     // Freeze the top row only.
+    ///     worksheet.set_freeze_panes(1, 0)?;
+    ///
+    ///     // Pre-scroll to the row 20.
+    ///     worksheet.set_freeze_panes_top_cell(19, 0)?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -13772,99 +14475,73 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output:
+    /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_print_fit_to_pages.png">
-    ///
-    pub fn set_print_fit_to_pages(&mut self, width: u16, height: u16) -> &mut Worksheet {
-        self.fit_width = width;
-        self.fit_height = height;
-
-        // This property is mutually exclusive with print scale.
-        self.print_scale = 100;
-
-        self.fit_to_page = true;
-        self.page_setup_changed = true;
-        self
-    }
-
-    /// Center the printed page horizontally.
-    ///
-    /// Center the worksheet data horizontally between the margins on the
-    /// printed page
-    ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Margins](../worksheet/index.html#page-setup---margins).
-    ///
-    /// # Parameters
-    ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes_top_cell.png">
     ///
-    pub fn set_print_center_horizontally(&mut self, enable: bool) -> &mut Worksheet {
-        self.center_horizontally = enable;
-
-        if enable {
-            self.print_options_changed = true;
-            self.page_setup_changed = true;
+    pub fn set_freeze_panes_top_cell(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
         }
-        self
-    }
-
-    /// Center the printed page vertically.
-    ///
-    /// Center the worksheet data vertically between the margins on the printed
-    /// page
-    ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Margins](../worksheet/index.html#page-setup---margins).
-    ///
-    /// # Parameters
-    ///
-    /// - `enable`: Turn the property on/off. It is off by default.
-    ///
-    pub fn set_print_center_vertically(&mut self, enable: bool) -> &mut Worksheet {
-        self.center_vertically = enable;
 
-        if enable {
-            self.print_options_changed = true;
-            self.page_setup_changed = true;
-        }
-        self
+        self.panes.top_cell = (row, col);
+        Ok(self)
     }
 
-    /// Set the option to turn on/off the screen gridlines.
+    /// Split a worksheet into panes.
     ///
-    /// The `set_screen_gridlines()` method is use to turn on/off gridlines on
-    /// displayed worksheet. It is on by default.
+    /// The `set_split_panes()` method can be used to divide a worksheet into
+    /// horizontal or vertical regions known as panes. Unlike
+    /// [`Worksheet::set_freeze_panes()`] the panes aren't frozen and the user
+    /// can drag the splitter bars to resize them. Each pane can be scrolled
+    /// independently.
     ///
-    /// To turn on/off the printed gridlines see the
-    /// [`Worksheet::set_print_gridlines()`] method below.
+    /// The position of the split is given in pixels, from the top and left of
+    /// the worksheet cells, rather than as a cell position. You can set one of
+    /// the `height` and `width` parameters to 0 if you do not want either the
+    /// horizontal or vertical split. The default row height is 20 pixels and
+    /// the default column width is 64 pixels. The split can't be larger than
+    /// the worksheet at the default row height and column width, and larger
+    /// values are ignored.
+    ///
+    /// The top left cell of the top left pane can be set with
+    /// [`Worksheet::set_top_left_cell()`] and the top left cell of the other
+    /// panes with [`Worksheet::set_split_panes_top_cell()`]. The selected cells
+    /// in each pane can be set with [`Worksheet::set_pane_selection()`].
     ///
     /// # Parameters
     ///
-    /// - `enable`: Turn the property on/off. It is on by default.
+    /// - `height`: The height of the top pane in pixels.
+    /// - `width`: The width of the left pane in pixels.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates turn off the worksheet worksheet screen
-    /// gridlines.
+    /// The following example demonstrates splitting a worksheet into panes.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_screen_gridlines.rs
+    /// # // This code is available in examples/doc_worksheet_set_split_panes.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{PanePosition, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     worksheet.write(0, 0, "Hello")?;
+    ///     // Split the worksheet below the fifth row and right of the second
+    ///     // column.
+    ///     worksheet.set_split_panes(100, 128);
     ///
-    ///     // Turn off the screen gridlines.
-    ///     worksheet.set_screen_gridlines(false);
+    ///     // Scroll the bottom right pane to cell D21.
+    ///     worksheet.set_split_panes_top_cell(20, 3)?;
+    ///
+    ///     // Select some cells in the bottom right pane.
+    ///     worksheet.set_pane_selection(PanePosition::BottomRight, &[(20, 3, 22, 4)])?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -13872,160 +14549,228 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_screen_gridlines.png">
-    ///
-    pub fn set_screen_gridlines(&mut self, enable: bool) -> &mut Worksheet {
-        self.screen_gridlines = enable;
+    pub fn set_split_panes(&mut self, height: u32, width: u32) -> &mut Worksheet {
+        let max_height = ROW_MAX * 20;
+        let max_width = u32::from(COL_MAX) * 64;
+
+        if height > max_height || width > max_width {
+            eprintln!(
+                "Split pane size ({height}, {width}) outside Excel range: height <= {max_height}, width <= {max_width}."
+            );
+            return self;
+        }
 
+        self.panes.split_size = (height, width);
+        self.panes.freeze_cell = (0, 0);
         self
     }
 
-    /// Set the page setup option to turn on printed gridlines.
-    ///
-    /// The `set_print_gridlines()` method is use to turn on/off gridlines on
-    /// the printed pages. It is off by default.
+    /// Set the top left cell of the scrolling panes of a split worksheet.
     ///
-    /// To turn on/off the screen gridlines see the
-    /// [`Worksheet::set_screen_gridlines()`] method above.
+    /// This method is used in conjunction with the
+    /// [`Worksheet::set_split_panes()`] method to set the top left visible cell
+    /// in the panes to the right and bottom of the split. If it isn't set the
+    /// cell is estimated from the size of the top left pane, so that the
+    /// worksheet appears unscrolled.
     ///
+    /// # Parameters
     ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
     ///
-    /// # Parameters
+    /// # Errors
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
     ///
-    pub fn set_print_gridlines(&mut self, enable: bool) -> &mut Worksheet {
-        self.print_gridlines = enable;
+    pub fn set_split_panes_top_cell(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.set_freeze_panes_top_cell(row, col)
+    }
 
-        if enable {
-            self.print_options_changed = true;
-            self.page_setup_changed = true;
-        }
-        self
-    }
-
-    /// Set the page setup option to print in black and white.
+    /// Set the printed page header caption.
     ///
-    /// This `set_print_black_and_white()` method can be used to force printing
-    /// in black and white only. It is off by default.
+    /// The `set_header()` method can be used to set the header for a worksheet.
     ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    /// Headers and footers are generated using a string which is a combination
+    /// of plain text and optional control characters. The string can also be
+    /// created from text, fields and font properties with the
+    /// [`HeaderFooter`](crate::HeaderFooter) builder.
     ///
-    /// # Parameters
+    /// The available control characters are:
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// | Control              | Category      | Description           |
+    /// | -------------------- | ------------- | --------------------- |
+    /// | `&L`                 | Alignment     | Left                  |
+    /// | `&C`                 |               | Center                |
+    /// | `&R`                 |               | Right                 |
+    /// | `&[Page]`  or `&P`   | Information   | Page number           |
+    /// | `&[Pages]` or `&N`   |               | Total number of pages |
+    /// | `&[Date]`  or `&D`   |               | Date                  |
+    /// | `&[Time]`  or `&T`   |               | Time                  |
+    /// | `&[File]`  or `&F`   |               | File name             |
+    /// | `&[Tab]`   or `&A`   |               | Worksheet name        |
+    /// | `&[Path]`  or `&Z`   |               | Workbook path         |
+    /// | `&fontsize`          | Font          | Font size             |
+    /// | `&"font,style"`      |               | Font name and style   |
+    /// | `&U`                 |               | Single underline      |
+    /// | `&E`                 |               | Double underline      |
+    /// | `&S`                 |               | Strikethrough         |
+    /// | `&X`                 |               | Superscript           |
+    /// | `&Y`                 |               | Subscript             |
+    /// | `&[Picture]` or `&G` | Images        | Picture/image         |
+    /// | `&&`                 | Miscellaneous | Literal ampersand &   |
     ///
-    pub fn set_print_black_and_white(&mut self, enable: bool) -> &mut Worksheet {
-        self.print_black_and_white = enable;
-
-        if enable {
-            self.page_setup_changed = true;
-        }
-        self
-    }
-
-    /// Set the page setup option to print in draft quality.
+    /// Some of the placeholder variables have a long version like `&[Page]` and
+    /// a short version like `&P`. The longer version is displayed in the Excel
+    /// interface but the shorter version is the way that it is stored in the
+    /// file format. Either version is okay since `rust_xlsxwriter` will
+    /// translate as required.
     ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    /// Headers and footers have 3 edit areas to the left, center and right.
+    /// Text can be aligned to these areas by prefixing the text with the
+    /// control characters `&L`, `&C` and `&R`.
     ///
-    /// # Parameters
+    /// For example:
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// ```text
+    /// worksheet.set_header("&LHello");
     ///
-    pub fn set_print_draft(&mut self, enable: bool) -> &mut Worksheet {
-        self.print_draft = enable;
-
-        if enable {
-            self.page_setup_changed = true;
-        }
-        self
-    }
-
-    /// Set the page setup option to print the row and column headers on the
-    /// printed page.
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// | Hello                                                         |
+    /// |                                                               |
     ///
-    /// The `set_print_headings()` method turns on the row and column headers
-    /// when printing a worksheet. This option is off by default.
+    /// worksheet.set_header("&CHello");
     ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// |                          Hello                                |
+    /// |                                                               |
     ///
-    /// # Parameters
+    /// worksheet.set_header("&RHello");
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// |                                                         Hello |
+    /// |                                                               |
+    /// ```
     ///
-    pub fn set_print_headings(&mut self, enable: bool) -> &mut Worksheet {
-        self.print_headings = enable;
-
-        if enable {
-            self.print_options_changed = true;
-            self.page_setup_changed = true;
-        }
-        self
-    }
-
-    /// Set the print area for the worksheet.
+    /// You can also have text in each of the alignment areas:
     ///
-    /// This method is used to specify the area of the worksheet that will be
-    /// printed.
+    /// ```text
+    /// worksheet.set_header("&LCiao&CBello&RCielo");
     ///
-    /// In order to specify an entire row or column range such as `1:20` or
-    /// `A:H` you must specify the corresponding maximum column or row range.
-    /// For example:
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// | Ciao                     Bello                          Cielo |
+    /// |                                                               |
+    /// ```
     ///
-    /// - `(0, 0, 31, 16_383) == 1:32`.
-    /// - `(0, 0, 1_048_575, 12) == A:M`.
+    /// The information control characters act as variables/templates that Excel
+    /// will update/expand as the workbook or worksheet changes.
     ///
-    /// In these examples 16,383 is the maximum column and 1,048,575 is the
-    /// maximum row (zero indexed).
+    /// ```text
+    /// worksheet.set_header("&CPage &[Page] of &[Pages]");
     ///
-    /// See also the example below and the documentation on
-    /// [Worksheet Page Setup - Sheet](../worksheet/index.html#page-setup---sheet).
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// |                        Page 1 of 6                            |
+    /// |                                                               |
+    /// ```
     ///
-    /// # Parameters
+    /// Times and dates are in the user's default format:
     ///
-    /// - `first_row`: The first row of the range. (All zero indexed.)
-    /// - `first_col`: The first column of the range.
-    /// - `last_row`: The last row of the range.
-    /// - `last_col`: The last column of the range.
+    /// ```text
+    /// worksheet.set_header("&CUpdated at &[Time]");
     ///
-    /// # Errors
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// |                    Updated at 12:30 PM                        |
+    /// |                                                               |
+    /// ```
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
-    ///   than the last row or column.
+    /// To include a single literal ampersand `&` in a header or footer you
+    /// should use a double ampersand `&&`:
+    ///
+    /// ```text
+    /// worksheet.set_header("&CCuriouser && Curiouser - Attorneys at Law");
+    ///
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// |                   Curiouser & Curiouser                       |
+    /// |                                                               |
+    /// ```
+    ///
+    /// You can specify the font size of a section of the text by prefixing it
+    /// with the control character `&n` where `n` is the font size:
+    ///
+    /// ```text
+    /// worksheet1.set_header("&C&30Hello Big");
+    /// worksheet2.set_header("&C&10Hello Small");
+    /// ```
+    ///
+    /// You can specify the font of a section of the text by prefixing it with
+    /// the control sequence `&"font,style"` where `fontname` is a font name
+    /// such as Windows font descriptions: "Regular", "Italic", "Bold" or "Bold
+    /// Italic": "Courier New" or "Times New Roman" and `style` is one of the
+    /// standard Windows font descriptions like “Regular”, “Italic”, “Bold” or
+    /// “Bold Italic”:
+    ///
+    /// ```text
+    /// worksheet1.set_header(r#"&C&"Courier New,Italic"Hello"#);
+    /// worksheet2.set_header(r#"&C&"Courier New,Bold Italic"Hello"#);
+    /// worksheet3.set_header(r#"&C&"Times New Roman,Regular"Hello"#);
+    /// ```
+    ///
+    /// It is possible to combine all of these features together to create
+    /// complex headers and footers. If you set up a complex header in Excel you
+    /// can transfer it to `rust_xlsxwriter` by inspecting the string in the
+    /// Excel file. For example the following shows how unzip and grep the Excel
+    /// XML sub-files on a Linux system. The example uses libxml's xmllint to
+    /// format the XML for clarity:
+    ///
+    /// ```text
+    /// $ unzip myfile.xlsm -d myfile
+    /// $ xmllint --format `find myfile -name "*.xml" | xargs` | \
+    ///     egrep "Header|Footer" | sed 's/&amp;/\&/g'
+    ///
+    ///  <headerFooter scaleWithDoc="0">
+    ///    <oddHeader>&L&P</oddHeader>
+    ///  </headerFooter>
+    /// ```
+    ///
+    /// Note: Excel requires that the header or footer string be less than 256
+    /// characters, including the control characters. Strings longer than this
+    /// will not be written, and a warning will be output.
+    ///
+    /// # Parameters
+    ///
+    /// - `header`: The header string with optional control characters.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the print area for several
-    /// worksheets.
+    /// The following example demonstrates setting the worksheet header.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_print_area.rs
+    /// # // This code is available in examples/doc_worksheet_set_header.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    ///     let worksheet1 = workbook.add_worksheet();
-    ///     // Set the print area to "A1:M32"
-    ///     worksheet1.set_print_area(0, 0, 31, 12)?;
-    ///
-    ///     let worksheet2 = workbook.add_worksheet();
-    ///     // Set the print area to "1:32"
-    ///     worksheet2.set_print_area(0, 0, 31, 16_383)?;
-    ///
-    ///     let worksheet3 = workbook.add_worksheet();
-    ///     // Set the print area to "A:M"
-    ///     worksheet3.set_print_area(0, 0, 1_048_575, 12)?;
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.set_header("&CPage &P of &N");
+    /// #
+    /// #     worksheet.write_string(0, 0, "Hello")?;
+    /// #     worksheet.write_string(200, 0, "Hello")?;
+    /// #     worksheet.set_view_page_layout();
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -14033,166 +14778,217 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file, page setup dialog for worksheet1:
+    /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_print_area.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_header.png">
     ///
-    pub fn set_print_area(
-        &mut self,
-        first_row: RowNum,
-        first_col: ColNum,
-        last_row: RowNum,
-        last_col: ColNum,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows and cols are in the allowed range.
-        if !self.check_dimensions_only(first_row, first_col)
-            || !self.check_dimensions_only(last_row, last_col)
-        {
-            return Err(XlsxError::RowColumnLimitError);
+    pub fn set_header(&mut self, header: impl Into<String>) -> &mut Worksheet {
+        let header = header.into();
+
+        if !Self::verify_header_footer_length(&header, "Header") {
+            return self;
         }
 
-        // Check order of first/last values.
-        if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
-        }
+        self.header = header;
+        self.page_setup_changed = true;
+        self.head_footer_changed = true;
+        self
+    }
 
-        // The print range is the entire worksheet, therefore it is the same
-        // as the default, so we can ignore it.
-        if first_row == 0 && first_col == 0 && last_row == ROW_MAX - 1 && last_col == COL_MAX - 1 {
-            return Ok(self);
-        }
+    /// Set the printed page footer caption.
+    ///
+    /// The `set_footer()` method can be used to set the footer for a worksheet.
+    ///
+    /// See the documentation for [`Worksheet::set_header()`] for more details
+    /// on the syntax of the header/footer string.
+    ///
+    /// # Parameters
+    ///
+    /// - `footer`: The footer string with optional control characters.
+    ///
+    pub fn set_footer(&mut self, footer: impl Into<String>) -> &mut Worksheet {
+        let footer = footer.into();
 
-        // Store the defined name information.
-        self.print_area_defined_name.in_use = true;
-        self.print_area_defined_name.name_type = DefinedNameType::PrintArea;
-        self.print_area_defined_name.first_row = first_row;
-        self.print_area_defined_name.first_col = first_col;
-        self.print_area_defined_name.last_row = last_row;
-        self.print_area_defined_name.last_col = last_col;
+        if !Self::verify_header_footer_length(&footer, "Footer") {
+            return self;
+        }
 
+        self.footer = footer;
         self.page_setup_changed = true;
-        Ok(self)
+        self.head_footer_changed = true;
+        self
     }
 
-    /// Set the number of rows to repeat at the top of each printed page.
-    ///
-    /// For large Excel documents it is often desirable to have the first row or
-    /// rows of the worksheet print out at the top of each page.
+    /// Insert an image in a worksheet header.
     ///
-    /// See the example below and the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    /// Insert an image in a worksheet header in one of the 3 sections supported
+    /// by Excel: Left, Center and Right. This needs to be preceded by a call to
+    /// [`Worksheet::set_header()`] where a corresponding `&[Picture]` element
+    /// is added to the header formatting string such as `"&L&[Picture]"`.
     ///
     /// # Parameters
     ///
-    /// - `first_row`: The first row of the range. (Zero indexed.)
-    /// - `last_row`: The last row of the range.
+    /// - `position`: The image position as defined by the
+    ///   [`HeaderImagePosition`] enum.
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
-    ///   row.
+    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the header string.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the rows to repeat on each
-    /// printed page.
+    /// The following example demonstrates adding a header image to a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_repeat_rows.rs
+    /// # // This code is available in examples/doc_worksheet_set_header_image.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError, HeaderImagePosition};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
-    ///     let worksheet1 = workbook.add_worksheet();
-    ///     // Repeat the first row in the printed output.
-    ///     worksheet1.set_repeat_rows(0, 0)?;
-    ///
-    ///     let worksheet2 = workbook.add_worksheet();
-    ///     // Repeat the first 2 rows in the printed output.
-    ///     worksheet2.set_repeat_rows(0, 1)?;
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Scale the image so it fits in the header.
+    ///       let image = Image::new("examples/rust_logo.png")?
+    ///           .set_scale_height(0.5)
+    ///           .set_scale_width(0.5);
+    /// #
+    ///     // Insert the watermark image in the header.
+    ///     worksheet.set_header("&C&[Picture]");
+    ///     worksheet.set_header_image(&image, HeaderImagePosition::Center)?;
+    /// #
+    /// #     // Increase the top margin to 1.2 for clarity. The -1.0 values are ignored.
+    /// #     worksheet.set_margins(-1.0, -1.0, 1.2, -1.0, -1.0, -1.0);
     /// #
+    /// #     // Set Page View mode so the watermark is visible.
+    /// #     worksheet.set_view_page_layout();
+    /// #
+    /// #     // Save the file to disk.
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    /// Output file, page setup dialog for worksheet2:
+    /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_repeat_rows.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_header_image.png">
     ///
-    pub fn set_repeat_rows(
+    /// An example of adding a worksheet watermark image using the
+    /// `rust_xlsxwriter` library. This is based on the method of putting an
+    /// image in the worksheet header as suggested in the [Microsoft
+    /// documentation].
+    ///
+    /// [Microsoft documentation]:
+    ///     https://support.microsoft.com/en-us/office/add-a-watermark-in-excel-a372182a-d733-484e-825c-18ddf3edf009
+    ///
+    /// ```
+    /// # // This code is available in examples/app_watermark.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError, HeaderImagePosition};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let image = Image::new("examples/watermark.png")?;
+    ///
+    ///     // Insert the watermark image in the header.
+    ///     worksheet.set_header("&C&[Picture]");
+    ///     worksheet.set_header_image(&image, HeaderImagePosition::Center)?;
+    /// #
+    /// #     // Set Page View mode so the watermark is visible.
+    /// #     worksheet.set_view_page_layout();
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("watermark.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/app_watermark.png">
+    ///
+    pub fn set_header_image(
         &mut self,
-        first_row: RowNum,
-        last_row: RowNum,
+        image: &Image,
+        position: HeaderImagePosition,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows are in the allowed range.
-        if !self.check_dimensions_only(first_row, 0) || !self.check_dimensions_only(last_row, 0) {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Check order of first/last values.
-        if first_row > last_row {
-            return Err(XlsxError::RowColumnOrderError);
-        }
-
-        // Store the range data.
-        self.repeat_row_cols_defined_name.in_use = true;
-        self.repeat_row_cols_defined_name.name_type = DefinedNameType::PrintTitles;
-        self.repeat_row_cols_defined_name.first_row = first_row;
-        self.repeat_row_cols_defined_name.last_row = last_row;
-
-        self.page_setup_changed = true;
-        Ok(self)
+        self.store_header_footer_image(image, position, HeaderFooterPage::Default, true)
     }
 
-    /// Set the columns to repeat at the left hand side of each printed page.
-    ///
-    /// For large Excel documents it is often desirable to have the first column
-    /// or columns of the worksheet print out at the left hand side of each
-    /// page.
+    /// Insert an image in a worksheet footer.
     ///
-    /// See the example below and the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    /// See the documentation for [`Worksheet::set_header_image()`] for more
+    /// details.
     ///
     /// # Parameters
     ///
-    /// - `first_col`: The first column of the range. (Zero indexed.)
-    /// - `last_col`: The last column of the range.
+    /// - `position`: The image position as defined by the
+    ///   [`HeaderImagePosition`] enum.
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
-    ///   than the last row or column.
+    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the header string.
+    ///
+    pub fn set_footer_image(
+        &mut self,
+        image: &Image,
+        position: HeaderImagePosition,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_header_footer_image(image, position, HeaderFooterPage::Default, false)
+    }
+
+    /// Set the printed page header caption for the first page.
+    ///
+    /// The `set_header_first()` method is used to set a different header for
+    /// the first printed page of the worksheet, for example for a cover page.
+    /// The other pages use the header set with [`Worksheet::set_header()`].
+    ///
+    /// See the documentation for [`Worksheet::set_header()`] for more details
+    /// on the syntax of the header string.
+    ///
+    /// # Parameters
+    ///
+    /// - `header`: The header string with optional control characters.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the columns to repeat on each
-    /// printed page.
+    /// The following example demonstrates setting different headers and
+    /// footers for the first page and for the odd and even pages of a
+    /// worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_repeat_columns.rs
+    /// # // This code is available in examples/doc_worksheet_set_header_first.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     let worksheet1 = workbook.add_worksheet();
-    ///     // Repeat the first column in the printed output.
-    ///     worksheet1.set_repeat_columns(0, 0)?;
+    ///     // Set a header for the first page only.
+    ///     worksheet.set_header_first("&CAccount Statement");
     ///
-    ///     let worksheet2 = workbook.add_worksheet();
-    ///     // Repeat the first 2 columns in the printed output.
-    ///     worksheet2.set_repeat_columns(0, 1)?;
+    ///     // Set mirrored footers for the odd and even pages.
+    ///     worksheet.set_footer("&RPage &P");
+    ///     worksheet.set_footer_even("&LPage &P");
+    /// #
+    /// #     worksheet.write_string(0, 0, "Hello")?;
+    /// #     worksheet.write_string(200, 0, "Hello")?;
+    /// #     worksheet.set_view_page_layout();
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -14200,508 +14996,2968 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file, page setup dialog for worksheet2:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_repeat_columns.png">
-    ///
-    pub fn set_repeat_columns(
-        &mut self,
-        first_col: ColNum,
-        last_col: ColNum,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check columns are in the allowed range.
-        if !self.check_dimensions_only(0, first_col) || !self.check_dimensions_only(0, last_col) {
-            return Err(XlsxError::RowColumnLimitError);
-        }
+    pub fn set_header_first(&mut self, header: impl Into<String>) -> &mut Worksheet {
+        let header = header.into();
 
-        // Check order of first/last values.
-        if first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+        if !Self::verify_header_footer_length(&header, "Header") {
+            return self;
         }
 
-        // Store the defined name information.
-        self.repeat_row_cols_defined_name.in_use = true;
-        self.repeat_row_cols_defined_name.name_type = DefinedNameType::PrintTitles;
-        self.repeat_row_cols_defined_name.first_col = first_col;
-        self.repeat_row_cols_defined_name.last_col = last_col;
-
+        self.header_first = header;
         self.page_setup_changed = true;
-        Ok(self)
+        self.head_footer_changed = true;
+        self
     }
 
-    /// Autofit the worksheet column widths to the widest data in the column,
-    /// approximately.
+    /// Set the printed page footer caption for the first page.
     ///
-    /// Excel autofits columns at runtime when it has access to all of the
-    /// required worksheet information as well as the Windows functions for
-    /// calculating display areas based on fonts and formatting.
-    ///
-    /// The `rust_xlsxwriter` library doesn't have access to these Windows
-    /// functions so it simulates autofit by calculating string widths based on
-    /// metrics taken from Excel.
+    /// See the documentation for [`Worksheet::set_header_first()`] for more
+    /// details.
     ///
-    /// This isn't perfect but for most cases it should be sufficient and
-    /// indistinguishable from the output of Excel. However there are some
-    /// limitations to be aware of when using this method:
+    /// # Parameters
     ///
-    /// - It is based on the default Excel font type and size of Calibri 11. It
-    ///   will not give accurate results for other fonts or font sizes.
-    /// - It doesn't take formatting of numbers or dates account, although this
-    ///   may be addressed in a later version.
-    /// - Autofit is a relatively expensive operation since it performs a
-    ///   calculation for all the populated cells in a worksheet. See the note
-    ///   on performance below.
+    /// - `footer`: The footer string with optional control characters.
     ///
-    /// For cases that don't match your desired output you can set explicit
-    /// column widths via [`Worksheet::set_column_width()`] or
-    /// [`Worksheet::set_column_width_pixels()`]. The `autofit()` method ignores
-    /// columns that have already been explicitly set if the width is greater
-    /// than the calculated autofit width. Alternatively, setting the column
-    /// width explicitly after calling `autofit()` will override the autofit
-    /// value. See also [`Worksheet::autofit_to_max_width()`] below.
+    pub fn set_footer_first(&mut self, footer: impl Into<String>) -> &mut Worksheet {
+        let footer = footer.into();
+
+        if !Self::verify_header_footer_length(&footer, "Footer") {
+            return self;
+        }
+
+        self.footer_first = footer;
+        self.page_setup_changed = true;
+        self.head_footer_changed = true;
+        self
+    }
+
+    /// Set the printed page header caption for the even pages.
     ///
-    /// **Performance**: By default `autofit()` performs a length calculation
-    /// for each populated cell in a worksheet. For very large worksheets this
-    /// could be slow. However, it is possible to mitigate this by calling
-    /// `autofit()` after writing the first 100 or 200 rows. This will produce a
-    /// reasonably accurate autofit for the first visible page of data without
-    /// incurring the performance penalty of autofitting thousands of
-    /// non-visible rows.
+    /// The `set_header_even()` method is used to set a different header for
+    /// the even printed pages of the worksheet. The odd pages use the header
+    /// set with [`Worksheet::set_header()`]. See
+    /// [`Worksheet::set_header_first()`] for an example.
     ///
-    /// # Examples
+    /// # Parameters
     ///
-    /// The following example demonstrates auto-fitting the worksheet column
-    /// widths based on the data in the columns. See all the [Autofitting
-    /// Columns] example in the user guide/examples directory.
+    /// - `header`: The header string with optional control characters.
     ///
-    /// [Autofitting Columns]:
-    ///     https://rustxlsxwriter.github.io/examples/autofit.html
+    pub fn set_header_even(&mut self, header: impl Into<String>) -> &mut Worksheet {
+        let header = header.into();
+
+        if !Self::verify_header_footer_length(&header, "Header") {
+            return self;
+        }
+
+        self.header_even = header;
+        self.page_setup_changed = true;
+        self.head_footer_changed = true;
+        self
+    }
+
+    /// Set the printed page footer caption for the even pages.
     ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_autofit.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     // Add some data to the worksheet.
-    ///     worksheet.write_string(0, 0, "Hello")?;
-    ///     worksheet.write_string(0, 1, "Hello")?;
-    ///     worksheet.write_string(1, 1, "Hello World")?;
-    ///     worksheet.write_number(0, 2, 123)?;
-    ///     worksheet.write_number(0, 3, 123456)?;
+    /// See the documentation for [`Worksheet::set_header_even()`] for more
+    /// details.
     ///
-    ///     // Autofit the columns.
-    ///     worksheet.autofit();
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    /// # Parameters
     ///
-    /// Output file:
+    /// - `footer`: The footer string with optional control characters.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_autofit.png">
+    pub fn set_footer_even(&mut self, footer: impl Into<String>) -> &mut Worksheet {
+        let footer = footer.into();
+
+        if !Self::verify_header_footer_length(&footer, "Footer") {
+            return self;
+        }
+
+        self.footer_even = footer;
+        self.page_setup_changed = true;
+        self.head_footer_changed = true;
+        self
+    }
+
+    /// Insert an image in the first page header of a worksheet.
     ///
+    /// See the documentation for [`Worksheet::set_header_image()`] for more
+    /// details. This needs to be preceded by a call to
+    /// [`Worksheet::set_header_first()`] with a corresponding `&[Picture]`
+    /// element.
     ///
+    /// # Parameters
     ///
-    pub fn autofit(&mut self) -> &mut Worksheet {
-        self.autofit_worksheet(MAX_AUTOFIT_WIDTH_PIXELS)
+    /// - `position`: The image position as defined by the
+    ///   [`HeaderImagePosition`] enum.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the header string.
+    ///
+    pub fn set_header_first_image(
+        &mut self,
+        image: &Image,
+        position: HeaderImagePosition,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_header_footer_image(image, position, HeaderFooterPage::First, true)
     }
 
-    /// Autofit the worksheet columns up to a maximum width.
+    /// Insert an image in the first page footer of a worksheet.
     ///
-    /// The [`Worksheet::autofit()`] method above simulates Excel's column
-    /// autofit. On undesirable side-effect of this is that Excel autofits very
-    /// long strings up to limit of 255 characters/1790 pixels. This is often
-    /// too wide to display on a single screen at normal zoom. As such the
-    /// `autofit_to_max_width()` method is provided to enable a smaller upper
-    /// limit for autofitting long strings. A value of 300 pixels is recommended
-    /// as a good compromise between column width and readability.
+    /// See the documentation for [`Worksheet::set_header_image()`] for more
+    /// details.
     ///
     /// # Parameters
     ///
-    /// - `max_autofit_width`: The maximum column width, in pixels, to use for
-    ///   autofitting.
+    /// - `position`: The image position as defined by the
+    ///   [`HeaderImagePosition`] enum.
     ///
+    /// # Errors
     ///
-    pub fn autofit_to_max_width(&mut self, max_autofit_width: u16) -> &mut Worksheet {
-        let max_autofit_width = std::cmp::min(max_autofit_width, MAX_AUTOFIT_WIDTH_PIXELS);
-        self.autofit_worksheet(max_autofit_width)
+    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the footer string.
+    ///
+    pub fn set_footer_first_image(
+        &mut self,
+        image: &Image,
+        position: HeaderImagePosition,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_header_footer_image(image, position, HeaderFooterPage::First, false)
     }
 
-    /// Set the worksheet name used in VBA macros.
+    /// Insert an image in the even page header of a worksheet.
     ///
-    /// This method can be used to set the VBA name for the worksheet. This is
-    /// sometimes required when a VBA macro included via
-    /// [`Workbook::add_vba_project()`](crate::Workbook::add_vba_project())
-    /// makes reference to the worksheet with a name other than the default
-    /// Excel VBA names of `Sheet1`, `Sheet2`, etc.
+    /// See the documentation for [`Worksheet::set_header_image()`] for more
+    /// details. This needs to be preceded by a call to
+    /// [`Worksheet::set_header_even()`] with a corresponding `&[Picture]`
+    /// element.
     ///
-    /// See also the
-    /// [`Workbook::set_vba_name()`](crate::Workbook::set_vba_name()) method for
-    /// setting the workbook VBA name.
+    /// # Parameters
     ///
-    /// The name must be a valid Excel VBA object name as defined by the
-    /// following rules:
+    /// - `position`: The image position as defined by the
+    ///   [`HeaderImagePosition`] enum.
     ///
-    /// - The name must be less than 32 characters.
-    /// - The name can only contain word characters: letters, numbers and
-    ///   underscores.
-    /// - The name must start with a letter.
-    /// - The name cannot be blank.
+    /// # Errors
     ///
-    /// The name must be also be unique across the worksheets in the workbook.
+    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the header string.
+    ///
+    pub fn set_header_even_image(
+        &mut self,
+        image: &Image,
+        position: HeaderImagePosition,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_header_footer_image(image, position, HeaderFooterPage::Even, true)
+    }
+
+    /// Insert an image in the even page footer of a worksheet.
+    ///
+    /// See the documentation for [`Worksheet::set_header_image()`] for more
+    /// details.
     ///
     /// # Parameters
     ///
-    /// - `name`: The vba name. It must follow the Excel rules, shown above.
+    /// - `position`: The image position as defined by the
+    ///   [`HeaderImagePosition`] enum.
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::VbaNameError`] - The name doesn't meet one of Excel's
-    ///   criteria, shown above.
+    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the footer string.
     ///
-    pub fn set_vba_name(&mut self, name: impl Into<String>) -> Result<&mut Worksheet, XlsxError> {
-        let name = name.into();
-        utility::validate_vba_name(&name)?;
-        self.vba_codename = Some(name);
+    pub fn set_footer_even_image(
+        &mut self,
+        image: &Image,
+        position: HeaderImagePosition,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_header_footer_image(image, position, HeaderFooterPage::Even, false)
+    }
 
-        Ok(self)
+    /// Set the page setup option to scale the header/footer with the document.
+    ///
+    /// This option determines whether the headers and footers use the same
+    /// scaling as the worksheet. This defaults to "on" in Excel.
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Header/Footer](../worksheet/index.html#page-setup---headerfooter).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_header_footer_scale_with_doc(&mut self, enable: bool) -> &mut Worksheet {
+        self.header_footer_scale_with_doc = enable;
+
+        if !enable {
+            self.page_setup_changed = true;
+            self.head_footer_changed = true;
+        }
+
+        self
     }
 
-    /// Set the default string used for NaN values.
+    /// Set the page setup option to align the header/footer with the margins.
     ///
-    /// Excel doesn't support storing `NaN` (Not a Number) values. If a `NAN` is
-    /// generated as the result of a calculation Excel stores and displays the
-    /// error `#NUM!`. However, this error isn't usually used outside of a
-    /// formula result and it isn't stored as a number.
+    /// This option determines whether the headers and footers align with the
+    /// left and right margins of the worksheet. This defaults to "on" in Excel.
     ///
-    /// In order to deal with [`f64::NAN`] in a reasonable way `rust_xlsxwriter`
-    /// writes it as the string "NAN". The `set_nan_value()` method allows you
-    /// to override this default value.
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Header/Footer](../worksheet/index.html#page-setup---headerfooter).
     ///
     /// # Parameters
     ///
-    /// - `value`: The string to use for NaN values.
+    /// - `enable`: Turn the property on/off. It is on by default. S
+    pub fn set_header_footer_align_with_page(&mut self, enable: bool) -> &mut Worksheet {
+        self.header_footer_align_with_page = enable;
+
+        if !enable {
+            self.page_setup_changed = true;
+            self.head_footer_changed = true;
+        }
+        self
+    }
+
+    /// Set the page margins.
+    ///
+    /// The `set_margins()` method is used to set the margins of the worksheet
+    /// when it is printed. The units are in inches. Specifying `-1.0` for any
+    /// parameter will give the default Excel value. The defaults are shown
+    /// below.
+    ///
+    /// # Parameters
+    ///
+    /// - `left`: Left margin in inches. Excel default is 0.7.
+    /// - `right`: Right margin in inches. Excel default is 0.7.
+    /// - `top`: Top margin in inches. Excel default is 0.75.
+    /// - `bottom`: Bottom margin in inches. Excel default is 0.75.
+    /// - `header`: Header margin in inches. Excel default is 0.3.
+    /// - `footer`: Footer margin in inches. Excel default is 0.3.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates handling NaN and Infinity values and
-    /// also setting custom string representations.
+    /// The following example demonstrates setting the worksheet margins.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_nan_string.rs
+    /// # // This code is available in examples/doc_worksheet_set_margins.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Write NaN and Infinity default values.
-    ///     worksheet.write(0, 0, "Default:")?;
-    ///     worksheet.write(0, 1, f64::NAN)?;
-    ///     worksheet.write(1, 1, f64::INFINITY)?;
-    ///     worksheet.write(2, 1, f64::NEG_INFINITY)?;
-    ///
-    ///     // Overwrite the default values.
-    ///     worksheet.set_nan_value("Nan");
-    ///     worksheet.set_infinity_value("Infinity");
-    ///     worksheet.set_neg_infinity_value("NegInfinity");
-    ///
-    ///     // Write NaN and Infinity custom values.
-    ///     worksheet.write(4, 0, "Custom:")?;
-    ///     worksheet.write(4, 1, f64::NAN)?;
-    ///     worksheet.write(5, 1, f64::INFINITY)?;
-    ///     worksheet.write(6, 1, f64::NEG_INFINITY)?;
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    ///     worksheet.set_margins(1.0, 1.25, 1.5, 1.75, 0.75, 0.25);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
     ///
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_nan_string.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_margins.png">
     ///
-    pub fn set_nan_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
-        self.nan = value.into();
+    pub fn set_margins(
+        &mut self,
+        left: f64,
+        right: f64,
+        top: f64,
+        bottom: f64,
+        header: f64,
+        footer: f64,
+    ) -> &mut Worksheet {
+        if left >= 0.0 {
+            self.margin_left = left;
+            self.page_setup_changed = true;
+        }
+        if right >= 0.0 {
+            self.margin_right = right;
+            self.page_setup_changed = true;
+        }
+        if top >= 0.0 {
+            self.margin_top = top;
+            self.page_setup_changed = true;
+        }
+        if bottom >= 0.0 {
+            self.margin_bottom = bottom;
+            self.page_setup_changed = true;
+        }
+        if header >= 0.0 {
+            self.margin_header = header;
+            self.page_setup_changed = true;
+        }
+        if footer >= 0.0 {
+            self.margin_footer = footer;
+            self.page_setup_changed = true;
+        }
+
         self
     }
 
-    /// Set the default string used for Infinite values.
-    ///
-    /// Excel doesn't support storing `Inf` (infinity) values. If an `Inf` is
-    /// generated as the result of a calculation Excel stores and displays the
-    /// error `#DIV/0`. However, this error isn't usually used outside of a
-    /// formula result and it isn't stored as a number.
+    /// Set the first page number when printing.
     ///
-    /// In order to deal with [`f64::INFINITY`] in a reasonable way
-    /// `rust_xlsxwriter` writes it as the string "INF". The
-    /// `set_infinite_value()` method allows you to override this default value.
+    /// The `set_print_first_page_number()` method is used to set the page
+    /// number of the first page when the worksheet is printed out. This option
+    /// will only have and effect if you have a header/footer with the `&[Page]`
+    /// control character, see [`Worksheet::set_header()`].
     ///
-    /// See the example for [`Worksheet::set_nan_value()`] above.
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Page](../worksheet/index.html#page-setup---page).
     ///
     /// # Parameters
     ///
-    /// - `value`: The string to use for `Inf` values.
+    /// - `page_number`: The page number of the first printed page.
     ///
-    pub fn set_infinity_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
-        self.infinity = value.into();
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the page number on the
+    /// printed page.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_print_first_page_number.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.set_header("&CPage &P of &N");
+    ///     worksheet.set_print_first_page_number(2);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn set_print_first_page_number(&mut self, page_number: u16) -> &mut Worksheet {
+        self.first_page_number = page_number;
+        self.page_setup_changed = true;
         self
     }
 
-    /// Set the default string used for negative Infinite values.
-    ///
-    /// Excel doesn't support storing `-Inf` (negative infinity) values. If a
-    /// `-Inf` is generated as the result of a calculation Excel stores and
-    /// displays the error `#DIV/0`. However, this error isn't usually used
-    /// outside of a formula result and it isn't stored as a number.
+    /// Set the page setup option to set the print scale.
     ///
+    /// Set the scale factor of the printed page, in the range 10 <= scale <=
+    /// 400.
     ///
-    /// In order to deal with [`f64::NEG_INFINITY`] in a reasonable way
-    /// `rust_xlsxwriter` writes it as the string "-INF". The
-    /// `set_infinite_value()` method method allows you to override this default
-    /// value.
+    /// The default scale factor is 100. The `set_print_scale()` method
+    /// does not affect the scale of the visible page in Excel. For that you
+    /// should use [`Worksheet::set_zoom()`].
     ///
-    /// See the example for [`Worksheet::set_nan_value()`] above.
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Page](../worksheet/index.html#page-setup---page).
     ///
     /// # Parameters
     ///
-    /// - `value`: The string to use for `-Inf` values.
+    /// - `scale`: The print scale factor.
     ///
-    pub fn set_neg_infinity_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
-        self.neg_infinity = value.into();
-        self
-    }
-
-    /// Ignore an Excel error or warning in a worksheet cell.
+    /// # Examples
     ///
-    /// Excel flags a number of data errors and inconsistencies with a a small
-    /// green triangle in the top left hand corner of the cell. For example the
-    /// following causes a warning of "Number Stored as Text":
+    /// The following example demonstrates setting the scale of the worksheet page
+    /// when printed.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_ignore_error1.rs
+    /// # // This code is available in examples/doc_worksheet_set_print_scale.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Write a string that looks like a number.
-    ///     worksheet.write_string(1, 2, "123")?;
+    ///     // Scale the printed worksheet to 50%.
+    ///     worksheet.set_print_scale(50);
     /// #
-    /// #     // Save the file to disk.
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_ignore_error1.png">
-    ///
-    /// These warnings can be useful indicators that there is an issue in the
-    /// spreadsheet but sometimes it is preferable to turn them off. At the file
-    /// level these errors can be ignored for a cell or cell range using
-    /// `Worksheet::ignore_error()` and [`Worksheet::ignore_error_range()`] (see
-    /// below).
+    pub fn set_print_scale(&mut self, scale: u16) -> &mut Worksheet {
+        if !(10..=400).contains(&scale) {
+            eprintln!("Scale factor {scale} outside Excel range: 10 <= zoom <= 400.");
+            return self;
+        }
+
+        // This property is mutually exclusive with fit to page.
+        self.fit_to_page = false;
+
+        self.print_scale = scale;
+        self.page_setup_changed = true;
+        self
+    }
+
+    /// Fit the printed area to a specific number of pages both vertically and
+    /// horizontally.
     ///
-    /// The errors and warnings that can be turned off at the file level are
-    /// represented by the [`IgnoreError`] enum values. These equate, with some
-    /// minor exceptions, to the error categories shown in the Excel Error
-    /// Checking dialog:
+    /// The `set_print_fit_to_pages()` method is used to fit the printed area to
+    /// a specific number of pages both vertically and horizontally. If the
+    /// printed area exceeds the specified number of pages it will be scaled
+    /// down to fit. This ensures that the printed area will always appear on
+    /// the specified number of pages even if the page size or margins change:
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/ignore_errors_dialog.png">
+    /// ```text
+    ///     worksheet1.set_print_fit_to_pages(1, 1); // Fit to 1x1 pages.
+    ///     worksheet2.set_print_fit_to_pages(2, 1); // Fit to 2x1 pages.
+    ///     worksheet3.set_print_fit_to_pages(1, 2); // Fit to 1x2 pages.
+    /// ```
     ///
-    /// (Note: some of the items shown in the above dialog such as "Misleading
-    /// Number Formats" aren't saved in the output file by Excel and can't be
-    /// turned off permanently.)
+    /// The print area can be defined using the `set_print_area()` method.
     ///
-    /// <br>
+    /// A common requirement is to fit the printed output to `n` pages wide but
+    /// have the height be as long as necessary. To achieve this set the
+    /// `height` to 0, see the example below.
     ///
-    /// The `Worksheet::ignore_error()` method can be called repeatedly to
-    /// ignore errors in different cells but **Excel only allows one ignored
-    /// error per cell**.
+    /// **Notes**:
     ///
-    /// An error can be turned off for a range of cells using the
-    /// [`Worksheet::ignore_error_range()`] method (see below).
+    /// - The `set_print_fit_to_pages()` will override any manual page breaks
+    ///   that are defined in the worksheet.
     ///
-    /// # Parameters
+    /// - When using `set_print_fit_to_pages()` it may also be required to set
+    ///   the printer paper size using [`Worksheet::set_paper_size()`] or else
+    ///   Excel will default to "US Letter".
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
-    /// - `error_type`: An [`IgnoreError`] enum value.
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Page](../worksheet/index.html#page-setup---page).
     ///
-    /// # Errors
+    /// # Parameters
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::ParameterError`] - Parameter error if more than one rule
-    ///   is added to the same cell.
+    /// - `width`: Number of pages horizontally.
+    /// - `height`: Number of pages vertically.
     ///
     /// # Examples
     ///
-    /// An example of turning off worksheet cells errors/warnings using using
-    /// the `rust_xlsxwriter` library.
+    /// The following example demonstrates setting the scale of the worksheet to
+    /// fit a defined number of pages vertically and horizontally. This example
+    /// shows a common use case which is to fit the printed output to 1 page
+    /// wide but have the height be as long as necessary.
     ///
     /// ```
-    /// # // This code is available in examples/app_ignore_errors.rs
+    /// # // This code is available in examples/doc_worksheet_set_print_fit_to_pages.rs
     /// #
-    /// # use rust_xlsxwriter::{Format, IgnoreError, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Create a format to use in descriptions.
-    /// #     let bold = Format::new().set_bold();
-    /// #
-    /// #     // Make the column wider for clarity.
-    /// #     worksheet.set_column_width(1, 16)?;
-    /// #
-    /// #     // Write some descriptions for the cells.
-    /// #     worksheet.write_with_format(1, 1, "Warning:", &bold)?;
-    /// #     worksheet.write_with_format(2, 1, "Warning turned off:", &bold)?;
-    /// #     worksheet.write_with_format(4, 1, "Warning:", &bold)?;
-    /// #     worksheet.write_with_format(5, 1, "Warning turned off:", &bold)?;
-    /// #
-    ///     // Write strings that looks like numbers. This will cause an Excel warning.
-    ///     worksheet.write_string(1, 2, "123")?;
-    ///     worksheet.write_string(2, 2, "123")?;
-    ///
-    ///     // Write a divide by zero formula. This will also cause an Excel warning.
-    ///     worksheet.write_formula(4, 2, "=1/0")?;
-    ///     worksheet.write_formula(5, 2, "=1/0")?;
-    ///
-    ///     // Turn off some of the warnings:
-    ///     worksheet.ignore_error(2, 2, IgnoreError::NumberStoredAsText)?;
-    ///     worksheet.ignore_error(5, 2, IgnoreError::FormulaError)?;
+    ///     // Set the printed output to fit 1 page wide and as long as necessary.
+    ///     worksheet.set_print_fit_to_pages(1, 0);
     /// #
-    /// #     // Save the file to disk.
-    /// #     workbook.save("ignore_errors.xlsx")?;
+    /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    /// Output file:
+    /// Output:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/app_ignore_errors.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_print_fit_to_pages.png">
     ///
-    pub fn ignore_error(
-        &mut self,
-        row: RowNum,
-        col: ColNum,
-        error_type: IgnoreError,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        self.ignore_error_range(row, col, row, col, error_type)
+    pub fn set_print_fit_to_pages(&mut self, width: u16, height: u16) -> &mut Worksheet {
+        self.fit_width = width;
+        self.fit_height = height;
+
+        // This property is mutually exclusive with print scale.
+        self.print_scale = 100;
+
+        self.fit_to_page = true;
+        self.page_setup_changed = true;
+        self
     }
 
-    /// Ignore an Excel error or warning in a range of worksheet cells.
+    /// Center the printed page horizontally.
     ///
-    /// See [`Worksheet::ignore_error()`] above for a detailed explanation of
-    /// Excel worksheet errors.
+    /// Center the worksheet data horizontally between the margins on the
+    /// printed page
     ///
-    /// The `Worksheet::ignore_error_range()` method can be used to ignore an
-    /// error in a range, a row, a column, or the entire worksheet and it can be
-    /// called repeatedly to ignore errors in different cells ranges. It should
-    /// be noted however that **Excel only allows one ignored error per cell**.
-    /// The `rust_xlsxwriter` library verifies that multiple rules aren't added
-    /// to the same cell or cell range and will raise an error if this occurs.
-    /// However it doesn't currently verify whether cells within ranges overlap.
-    /// It is up to the user to ensure that this doesn't happen when using
-    /// ranges.
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Margins](../worksheet/index.html#page-setup---margins).
     ///
     /// # Parameters
     ///
-    /// - `first_row`: The first row of the range. (All zero indexed.)
-    /// - `first_col`: The first column of the range.
-    /// - `last_row`: The last row of the range.
-    /// - `last_col`: The last column of the range.
-    /// - `error_type`: An [`IgnoreError`] enum value.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
-    /// # Errors
+    pub fn set_print_center_horizontally(&mut self, enable: bool) -> &mut Worksheet {
+        self.center_horizontally = enable;
+
+        if enable {
+            self.print_options_changed = true;
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Center the printed page vertically.
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
-    ///   than the last row or column.
-    /// - [`XlsxError::ParameterError`] - Parameter error if more than one rule
-    ///   is added to the same range.
+    /// Center the worksheet data vertically between the margins on the printed
+    /// page
     ///
-    pub fn ignore_error_range(
-        &mut self,
-        first_row: RowNum,
-        first_col: ColNum,
-        last_row: RowNum,
-        last_col: ColNum,
-        error_type: IgnoreError,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows and cols are in the allowed range.
-        if !self.check_dimensions_only(first_row, first_col)
-            || !self.check_dimensions_only(last_row, last_col)
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Margins](../worksheet/index.html#page-setup---margins).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_center_vertically(&mut self, enable: bool) -> &mut Worksheet {
+        self.center_vertically = enable;
+
+        if enable {
+            self.print_options_changed = true;
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Set the option to turn on/off the screen gridlines.
+    ///
+    /// The `set_screen_gridlines()` method is use to turn on/off gridlines on
+    /// displayed worksheet. It is on by default.
+    ///
+    /// To turn on/off the printed gridlines see the
+    /// [`Worksheet::set_print_gridlines()`] method below.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates turn off the worksheet worksheet screen
+    /// gridlines.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_screen_gridlines.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write(0, 0, "Hello")?;
+    ///
+    ///     // Turn off the screen gridlines.
+    ///     worksheet.set_screen_gridlines(false);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_screen_gridlines.png">
+    ///
+    pub fn set_screen_gridlines(&mut self, enable: bool) -> &mut Worksheet {
+        self.screen_gridlines = enable;
+
+        self
+    }
+
+    /// Set the color of the screen gridlines.
+    ///
+    /// The `set_screen_gridlines_color()` method is used to change the color of
+    /// the gridlines on the displayed worksheet. Excel only supports colors from
+    /// its legacy 56 color palette for gridlines so the color is converted to
+    /// the nearest palette color.
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: The gridline color property defined by a [`Color`] enum
+    ///   value or a type that can convert [`Into`] a [`Color`].
+    ///
+    pub fn set_screen_gridlines_color(&mut self, color: impl Into<Color>) -> &mut Worksheet {
+        let color = color.into();
+
+        // Check that the color is valid.
+        if !color.is_valid() {
+            return self;
+        }
+
+        self.screen_gridlines_color = color;
+
+        self
+    }
+
+    /// Set the option to turn on/off the screen row and column headings.
+    ///
+    /// The `set_screen_headings()` method is use to turn on/off the row and
+    /// column headers on the displayed worksheet. It is on by default.
+    ///
+    /// To turn on/off the printed headings see the
+    /// [`Worksheet::set_print_headings()`] method.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_screen_headings(&mut self, enable: bool) -> &mut Worksheet {
+        self.screen_headings = enable;
+
+        self
+    }
+
+    /// Set the option to display formulas instead of their results.
+    ///
+    /// The `set_show_formulas()` method is used to display the formulas in the
+    /// cells of the worksheet instead of the calculated results, like the Excel
+    /// `Formulas -> Show Formulas` option.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates displaying the formulas in a
+    /// worksheet instead of their results, and hiding zero values.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_show_formulas.rs
+    /// #
+    /// # use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write_column(0, 0, [10, 0, 30])?;
+    ///     worksheet.write_formula(3, 0, Formula::new("=SUM(A1:A3)"))?;
+    ///
+    ///     // Display the formulas instead of the results.
+    ///     worksheet.set_show_formulas(true);
+    ///
+    ///     // Hide the zero values.
+    ///     worksheet.set_show_zero_values(false);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_show_formulas(&mut self, enable: bool) -> &mut Worksheet {
+        self.show_formulas = enable;
+
+        self
+    }
+
+    /// Set the option to turn on/off the display of zero values.
+    ///
+    /// The `set_show_zero_values()` method is used to hide cells that have a
+    /// zero value, like the Excel `Show a zero in cells that have zero value`
+    /// option. It is on by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_show_zero_values(&mut self, enable: bool) -> &mut Worksheet {
+        self.show_zero_values = enable;
+
+        self
+    }
+
+    /// Set the option to turn on/off the outline symbols.
+    ///
+    /// The `set_show_outline_symbols()` method is used to hide the outline
+    /// symbols and level bars of grouped rows and columns. It is on by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_show_outline_symbols(&mut self, enable: bool) -> &mut Worksheet {
+        self.show_outline_symbols = enable;
+
+        self
+    }
+
+    /// Set the option to turn on/off the ruler in the "Page Layout" view.
+    ///
+    /// See also [`Worksheet::set_view_page_layout()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_page_layout_ruler(&mut self, enable: bool) -> &mut Worksheet {
+        self.page_layout_ruler = enable;
+
+        self
+    }
+
+    /// Add a custom view to the worksheet.
+    ///
+    /// The `add_custom_view()` method adds a named set of display and print
+    /// settings to the worksheet. See [`CustomView`] for details and an
+    /// example.
+    ///
+    /// Views with the same name in several worksheets are combined into a
+    /// single workbook view by Excel. Adding a view with the same name as an
+    /// existing view in the worksheet replaces the existing view.
+    ///
+    /// # Parameters
+    ///
+    /// - `view`: The [`CustomView`] to add.
+    ///
+    pub fn add_custom_view(&mut self, view: &CustomView) -> &mut Worksheet {
+        if let Some(existing) = self.custom_views.iter_mut().find(|v| v.name == view.name) {
+            *existing = view.clone();
+        } else {
+            self.custom_views.push(view.clone());
+        }
+
+        self
+    }
+
+    /// Set the page setup option to turn on printed gridlines.
+    ///
+    /// The `set_print_gridlines()` method is use to turn on/off gridlines on
+    /// the printed pages. It is off by default.
+    ///
+    /// To turn on/off the screen gridlines see the
+    /// [`Worksheet::set_screen_gridlines()`] method above.
+    ///
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_gridlines(&mut self, enable: bool) -> &mut Worksheet {
+        self.print_gridlines = enable;
+
+        if enable {
+            self.print_options_changed = true;
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Set the page setup option to print in black and white.
+    ///
+    /// This `set_print_black_and_white()` method can be used to force printing
+    /// in black and white only. It is off by default.
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_black_and_white(&mut self, enable: bool) -> &mut Worksheet {
+        self.print_black_and_white = enable;
+
+        if enable {
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Set the page setup option to print in draft quality.
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_draft(&mut self, enable: bool) -> &mut Worksheet {
+        self.print_draft = enable;
+
+        if enable {
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Set the page setup option to print the row and column headers on the
+    /// printed page.
+    ///
+    /// The `set_print_headings()` method turns on the row and column headers
+    /// when printing a worksheet. This option is off by default.
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_headings(&mut self, enable: bool) -> &mut Worksheet {
+        self.print_headings = enable;
+
+        if enable {
+            self.print_options_changed = true;
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Set the print area for the worksheet.
+    ///
+    /// This method is used to specify the area of the worksheet that will be
+    /// printed.
+    ///
+    /// In order to specify an entire row or column range such as `1:20` or
+    /// `A:H` you must specify the corresponding maximum column or row range.
+    /// For example:
+    ///
+    /// - `(0, 0, 31, 16_383) == 1:32`.
+    /// - `(0, 0, 1_048_575, 12) == A:M`.
+    ///
+    /// In these examples 16,383 is the maximum column and 1,048,575 is the
+    /// maximum row (zero indexed).
+    ///
+    /// See also the example below and the documentation on
+    /// [Worksheet Page Setup - Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the print area for several
+    /// worksheets.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_print_area.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///     // Set the print area to "A1:M32"
+    ///     worksheet1.set_print_area(0, 0, 31, 12)?;
+    ///
+    ///     let worksheet2 = workbook.add_worksheet();
+    ///     // Set the print area to "1:32"
+    ///     worksheet2.set_print_area(0, 0, 31, 16_383)?;
+    ///
+    ///     let worksheet3 = workbook.add_worksheet();
+    ///     // Set the print area to "A:M"
+    ///     worksheet3.set_print_area(0, 0, 1_048_575, 12)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file, page setup dialog for worksheet1:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_print_area.png">
+    ///
+    pub fn set_print_area(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // The print range is the entire worksheet, therefore it is the same
+        // as the default, so we can ignore it.
+        if first_row == 0 && first_col == 0 && last_row == ROW_MAX - 1 && last_col == COL_MAX - 1 {
+            return Ok(self);
+        }
+
+        // Store the defined name information.
+        self.print_area_defined_name.in_use = true;
+        self.print_area_defined_name.name_type = DefinedNameType::PrintArea;
+        self.print_area_defined_name.first_row = first_row;
+        self.print_area_defined_name.first_col = first_col;
+        self.print_area_defined_name.last_row = last_row;
+        self.print_area_defined_name.last_col = last_col;
+
+        self.page_setup_changed = true;
+        Ok(self)
+    }
+
+    /// Set the number of rows to repeat at the top of each printed page.
+    ///
+    /// For large Excel documents it is often desirable to have the first row or
+    /// rows of the worksheet print out at the top of each page.
+    ///
+    /// See the example below and the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (Zero indexed.)
+    /// - `last_row`: The last row of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the rows to repeat on each
+    /// printed page.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_repeat_rows.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///     // Repeat the first row in the printed output.
+    ///     worksheet1.set_repeat_rows(0, 0)?;
+    ///
+    ///     let worksheet2 = workbook.add_worksheet();
+    ///     // Repeat the first 2 rows in the printed output.
+    ///     worksheet2.set_repeat_rows(0, 1)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file, page setup dialog for worksheet2:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_repeat_rows.png">
+    ///
+    pub fn set_repeat_rows(
+        &mut self,
+        first_row: RowNum,
+        last_row: RowNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows are in the allowed range.
+        if !self.check_dimensions_only(first_row, 0) || !self.check_dimensions_only(last_row, 0) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Store the range data.
+        self.repeat_row_cols_defined_name.in_use = true;
+        self.repeat_row_cols_defined_name.name_type = DefinedNameType::PrintTitles;
+        self.repeat_row_cols_defined_name.first_row = first_row;
+        self.repeat_row_cols_defined_name.last_row = last_row;
+
+        self.page_setup_changed = true;
+        Ok(self)
+    }
+
+    /// Set the columns to repeat at the left hand side of each printed page.
+    ///
+    /// For large Excel documents it is often desirable to have the first column
+    /// or columns of the worksheet print out at the left hand side of each
+    /// page.
+    ///
+    /// See the example below and the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `first_col`: The first column of the range. (Zero indexed.)
+    /// - `last_col`: The last column of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the columns to repeat on each
+    /// printed page.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_repeat_columns.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///     // Repeat the first column in the printed output.
+    ///     worksheet1.set_repeat_columns(0, 0)?;
+    ///
+    ///     let worksheet2 = workbook.add_worksheet();
+    ///     // Repeat the first 2 columns in the printed output.
+    ///     worksheet2.set_repeat_columns(0, 1)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file, page setup dialog for worksheet2:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_repeat_columns.png">
+    ///
+    pub fn set_repeat_columns(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check columns are in the allowed range.
+        if !self.check_dimensions_only(0, first_col) || !self.check_dimensions_only(0, last_col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Store the defined name information.
+        self.repeat_row_cols_defined_name.in_use = true;
+        self.repeat_row_cols_defined_name.name_type = DefinedNameType::PrintTitles;
+        self.repeat_row_cols_defined_name.first_col = first_col;
+        self.repeat_row_cols_defined_name.last_col = last_col;
+
+        self.page_setup_changed = true;
+        Ok(self)
+    }
+
+    /// Autofit the worksheet column widths to the widest data in the column,
+    /// approximately.
+    ///
+    /// Excel autofits columns at runtime when it has access to all of the
+    /// required worksheet information as well as the Windows functions for
+    /// calculating display areas based on fonts and formatting.
+    ///
+    /// The `rust_xlsxwriter` library doesn't have access to these Windows
+    /// functions so it simulates autofit by calculating string widths based on
+    /// metrics taken from Excel.
+    ///
+    /// This isn't perfect but for most cases it should be sufficient and
+    /// indistinguishable from the output of Excel. However there are some
+    /// limitations to be aware of when using this method:
+    ///
+    /// - The widths are based on the font name, size, bold and italic
+    ///   properties of the cell format. There are built-in character widths for
+    ///   Calibri, Arial, Times New Roman and Courier New. Other fonts use the
+    ///   Calibri widths scaled to the font size unless the font metrics are
+    ///   added with [`Worksheet::set_autofit_font_metrics()`].
+    /// - Rotated text is measured at its rotated angle.
+    /// - Merged cells are ignored, as they are in Excel, since the data
+    ///   spans several columns.
+    /// - Only the column widths are autofit. The heights of rows with wrapped
+    ///   or rotated text can be autofit separately with
+    ///   [`Worksheet::autofit_rows()`].
+    /// - Numbers and dates with a number format are measured as they would be
+    ///   displayed by Excel, see [`format_number()`](crate::format_number).
+    ///   Numbers in the default `General` format are measured from the raw
+    ///   value since Excel's display of these depends on the column width.
+    ///   Worksheets in constant or low memory mode don't have access to the
+    ///   cell formats so the default Calibri 11 font and the raw values are
+    ///   used.
+    /// - Autofit is a relatively expensive operation since it performs a
+    ///   calculation for all the populated cells in a worksheet. See the note
+    ///   on performance below.
+    ///
+    /// For cases that don't match your desired output you can set explicit
+    /// column widths via [`Worksheet::set_column_width()`] or
+    /// [`Worksheet::set_column_width_pixels()`]. The `autofit()` method ignores
+    /// columns that have already been explicitly set if the width is greater
+    /// than the calculated autofit width. Alternatively, setting the column
+    /// width explicitly after calling `autofit()` will override the autofit
+    /// value. See also [`Worksheet::autofit_to_max_width()`] below.
+    ///
+    /// **Performance**: By default `autofit()` performs a length calculation
+    /// for each populated cell in a worksheet. For very large worksheets this
+    /// could be slow. However, it is possible to mitigate this by calling
+    /// `autofit()` after writing the first 100 or 200 rows. This will produce a
+    /// reasonably accurate autofit for the first visible page of data without
+    /// incurring the performance penalty of autofitting thousands of
+    /// non-visible rows.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates auto-fitting the worksheet column
+    /// widths based on the data in the columns. See all the [Autofitting
+    /// Columns] example in the user guide/examples directory.
+    ///
+    /// [Autofitting Columns]:
+    ///     https://rustxlsxwriter.github.io/examples/autofit.html
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_autofit.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Add some data to the worksheet.
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///     worksheet.write_string(0, 1, "Hello")?;
+    ///     worksheet.write_string(1, 1, "Hello World")?;
+    ///     worksheet.write_number(0, 2, 123)?;
+    ///     worksheet.write_number(0, 3, 123456)?;
+    ///
+    ///     // Autofit the columns.
+    ///     worksheet.autofit();
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_autofit.png">
+    ///
+    ///
+    ///
+    pub fn autofit(&mut self) -> &mut Worksheet {
+        self.autofit_worksheet(MAX_AUTOFIT_WIDTH_PIXELS)
+    }
+
+    /// Autofit the worksheet columns up to a maximum width.
+    ///
+    /// The [`Worksheet::autofit()`] method above simulates Excel's column
+    /// autofit. On undesirable side-effect of this is that Excel autofits very
+    /// long strings up to limit of 255 characters/1790 pixels. This is often
+    /// too wide to display on a single screen at normal zoom. As such the
+    /// `autofit_to_max_width()` method is provided to enable a smaller upper
+    /// limit for autofitting long strings. A value of 300 pixels is recommended
+    /// as a good compromise between column width and readability.
+    ///
+    /// # Parameters
+    ///
+    /// - `max_autofit_width`: The maximum column width, in pixels, to use for
+    ///   autofitting.
+    ///
+    ///
+    pub fn autofit_to_max_width(&mut self, max_autofit_width: u16) -> &mut Worksheet {
+        let max_autofit_width = std::cmp::min(max_autofit_width, MAX_AUTOFIT_WIDTH_PIXELS);
+        self.autofit_worksheet(max_autofit_width)
+    }
+
+    /// Autofit the height of worksheet rows that contain wrapped or rotated
+    /// text, approximately.
+    ///
+    /// Excel adjusts the height of rows with wrapped or rotated text to fit
+    /// the text when the file is edited. The `autofit_rows()` method simulates
+    /// this in the same way as [`Worksheet::autofit()`] does for column
+    /// widths:
+    ///
+    /// - Wrapped text is wrapped at the current column width, including
+    ///   explicit newlines, so `autofit_rows()` should be called after the
+    ///   column widths have been set or autofit. Note, Excel only displays
+    ///   text with newlines on several lines if the format has text wrap.
+    /// - Rotated text is measured at its rotated angle.
+    /// - Only rows with wrapped or rotated text are changed. Rows that only
+    ///   need a different height for a larger font size aren't changed and
+    ///   should be set with [`Worksheet::set_row_height()`] if required.
+    /// - Rows with a height set by [`Worksheet::set_row_height()`] aren't
+    ///   changed, and rows are never made smaller than the default height.
+    /// - Merged cells are ignored.
+    /// - Worksheets in constant or low memory mode don't have access to the
+    ///   cell formats so the rows aren't changed.
+    ///
+    /// The autofit heights are written to the file as explicit row heights,
+    /// in the same way as [`Worksheet::set_row_height()`], so Excel doesn't
+    /// change them when the file is opened.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates auto-fitting the worksheet row
+    /// heights for wrapped text.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_autofit_rows.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let wrap = Format::new().set_text_wrap();
+    ///
+    ///     // Add some wrapped text to the worksheet.
+    ///     worksheet.write_with_format(0, 0, "Hello\nWorld", &wrap)?;
+    ///     worksheet.write_with_format(1, 0, "Some longer text that wraps", &wrap)?;
+    ///
+    ///     // Autofit the columns and then the rows.
+    ///     worksheet.autofit_to_max_width(100);
+    ///     worksheet.autofit_rows();
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn autofit_rows(&mut self) -> &mut Worksheet {
+        // In constant memory mode the formats and previous rows aren't
+        // available.
+        if self.use_constant_memory {
+            return self;
+        }
+
+        let mut autofit_fonts: HashMap<u32, AutofitFont> = HashMap::new();
+        let mut tall_cells: Vec<(RowNum, ColNum, String, u32)> = vec![];
+
+        for (row_num, columns) in &self.data_table {
+            for (col_num, cell) in columns {
+                // Ignore merged cells, like Excel.
+                if self.merged_cells.contains_key(&(*row_num, *col_num)) {
+                    continue;
+                }
+
+                let xf_index = CellValue::cell_xf_index(cell);
+                let font = autofit_fonts
+                    .entry(xf_index)
+                    .or_insert_with(|| self.autofit_font(xf_index));
+
+                // Only wrapped and rotated text changes the row height.
+                if !font.text_wrap && !font.is_rotated() {
+                    continue;
+                }
+
+                let (text, _) = self.autofit_cell_text(cell, xf_index);
+                if !text.is_empty() {
+                    tall_cells.push((*row_num, *col_num, text.into_owned(), xf_index));
+                }
+            }
+        }
+
+        self.store_autofit_row_heights(&tall_cells, &autofit_fonts);
+
+        self
+    }
+
+    /// Set the character widths of a font for use with autofit.
+    ///
+    /// The [`Worksheet::autofit()`] and [`Worksheet::autofit_rows()`] methods
+    /// calculate cell widths and heights from the character widths of the
+    /// cell font. There are built-in widths
+    /// for common fonts. This method can be used to add the widths for other
+    /// fonts or to override the built-in widths. See [`FontMetrics`] for
+    /// details.
+    ///
+    /// # Parameters
+    ///
+    /// - `metrics`: A [`FontMetrics`] instance. It replaces any previous
+    ///   metrics for the same font name.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding font metrics for autofit.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_autofit_font_metrics.rs
+    /// #
+    /// # use rust_xlsxwriter::{FontMetrics, Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Add metrics for a font with narrow digits.
+    ///     let metrics = FontMetrics::new("Arial Narrow")
+    ///         .set_chars_width("0123456789", 456)
+    ///         .set_default_width(460)
+    ///         .set_line_height(1.25);
+    ///
+    ///     worksheet.set_autofit_font_metrics(&metrics);
+    ///
+    ///     let format = Format::new().set_font_name("Arial Narrow");
+    ///     worksheet.write_with_format(0, 0, 1234567890, &format)?;
+    ///     worksheet.write_with_format(1, 0, "Some narrow text", &format)?;
+    ///
+    ///     worksheet.autofit();
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_autofit_font_metrics(&mut self, metrics: &FontMetrics) -> &mut Worksheet {
+        self.font_metrics
+            .insert(metrics.name.to_lowercase(), metrics.clone());
+
+        self
+    }
+
+    /// Set the worksheet name used in VBA macros.
+    ///
+    /// This method can be used to set the VBA name for the worksheet. This is
+    /// sometimes required when a VBA macro included via
+    /// [`Workbook::add_vba_project()`](crate::Workbook::add_vba_project())
+    /// makes reference to the worksheet with a name other than the default
+    /// Excel VBA names of `Sheet1`, `Sheet2`, etc.
+    ///
+    /// See also the
+    /// [`Workbook::set_vba_name()`](crate::Workbook::set_vba_name()) method for
+    /// setting the workbook VBA name.
+    ///
+    /// The name must be a valid Excel VBA object name as defined by the
+    /// following rules:
+    ///
+    /// - The name must be less than 32 characters.
+    /// - The name can only contain word characters: letters, numbers and
+    ///   underscores.
+    /// - The name must start with a letter.
+    /// - The name cannot be blank.
+    ///
+    /// The name must be also be unique across the worksheets in the workbook.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The vba name. It must follow the Excel rules, shown above.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::VbaNameError`] - The name doesn't meet one of Excel's
+    ///   criteria, shown above.
+    ///
+    pub fn set_vba_name(&mut self, name: impl Into<String>) -> Result<&mut Worksheet, XlsxError> {
+        let name = name.into();
+        utility::validate_vba_name(&name)?;
+        self.vba_codename = Some(name);
+
+        Ok(self)
+    }
+
+    /// Set the default string used for NaN values.
+    ///
+    /// Excel doesn't support storing `NaN` (Not a Number) values. If a `NAN` is
+    /// generated as the result of a calculation Excel stores and displays the
+    /// error `#NUM!`. However, this error isn't usually used outside of a
+    /// formula result and it isn't stored as a number.
+    ///
+    /// In order to deal with [`f64::NAN`] in a reasonable way `rust_xlsxwriter`
+    /// writes it as the string "NAN". The `set_nan_value()` method allows you
+    /// to override this default value.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The string to use for NaN values.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates handling NaN and Infinity values and
+    /// also setting custom string representations.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_nan_string.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write NaN and Infinity default values.
+    ///     worksheet.write(0, 0, "Default:")?;
+    ///     worksheet.write(0, 1, f64::NAN)?;
+    ///     worksheet.write(1, 1, f64::INFINITY)?;
+    ///     worksheet.write(2, 1, f64::NEG_INFINITY)?;
+    ///
+    ///     // Overwrite the default values.
+    ///     worksheet.set_nan_value("Nan");
+    ///     worksheet.set_infinity_value("Infinity");
+    ///     worksheet.set_neg_infinity_value("NegInfinity");
+    ///
+    ///     // Write NaN and Infinity custom values.
+    ///     worksheet.write(4, 0, "Custom:")?;
+    ///     worksheet.write(4, 1, f64::NAN)?;
+    ///     worksheet.write(5, 1, f64::INFINITY)?;
+    ///     worksheet.write(6, 1, f64::NEG_INFINITY)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_nan_string.png">
+    ///
+    pub fn set_nan_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
+        self.nan = value.into();
+        self
+    }
+
+    /// Set the default string used for Infinite values.
+    ///
+    /// Excel doesn't support storing `Inf` (infinity) values. If an `Inf` is
+    /// generated as the result of a calculation Excel stores and displays the
+    /// error `#DIV/0`. However, this error isn't usually used outside of a
+    /// formula result and it isn't stored as a number.
+    ///
+    /// In order to deal with [`f64::INFINITY`] in a reasonable way
+    /// `rust_xlsxwriter` writes it as the string "INF". The
+    /// `set_infinite_value()` method allows you to override this default value.
+    ///
+    /// See the example for [`Worksheet::set_nan_value()`] above.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The string to use for `Inf` values.
+    ///
+    pub fn set_infinity_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
+        self.infinity = value.into();
+        self
+    }
+
+    /// Set the default string used for negative Infinite values.
+    ///
+    /// Excel doesn't support storing `-Inf` (negative infinity) values. If a
+    /// `-Inf` is generated as the result of a calculation Excel stores and
+    /// displays the error `#DIV/0`. However, this error isn't usually used
+    /// outside of a formula result and it isn't stored as a number.
+    ///
+    ///
+    /// In order to deal with [`f64::NEG_INFINITY`] in a reasonable way
+    /// `rust_xlsxwriter` writes it as the string "-INF". The
+    /// `set_infinite_value()` method method allows you to override this default
+    /// value.
+    ///
+    /// See the example for [`Worksheet::set_nan_value()`] above.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The string to use for `-Inf` values.
+    ///
+    pub fn set_neg_infinity_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
+        self.neg_infinity = value.into();
+        self
+    }
+
+    /// Ignore an Excel error or warning in a worksheet cell.
+    ///
+    /// Excel flags a number of data errors and inconsistencies with a a small
+    /// green triangle in the top left hand corner of the cell. For example the
+    /// following causes a warning of "Number Stored as Text":
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_ignore_error1.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write a string that looks like a number.
+    ///     worksheet.write_string(1, 2, "123")?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_ignore_error1.png">
+    ///
+    /// These warnings can be useful indicators that there is an issue in the
+    /// spreadsheet but sometimes it is preferable to turn them off. At the file
+    /// level these errors can be ignored for a cell or cell range using
+    /// `Worksheet::ignore_error()` and [`Worksheet::ignore_error_range()`] (see
+    /// below).
+    ///
+    /// The errors and warnings that can be turned off at the file level are
+    /// represented by the [`IgnoreError`] enum values. These equate, with some
+    /// minor exceptions, to the error categories shown in the Excel Error
+    /// Checking dialog:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/ignore_errors_dialog.png">
+    ///
+    /// (Note: some of the items shown in the above dialog such as "Misleading
+    /// Number Formats" aren't saved in the output file by Excel and can't be
+    /// turned off permanently.)
+    ///
+    /// <br>
+    ///
+    /// The `Worksheet::ignore_error()` method can be called repeatedly to
+    /// ignore errors in different cells but **Excel only allows one ignored
+    /// error per cell**.
+    ///
+    /// An error can be turned off for a range of cells using the
+    /// [`Worksheet::ignore_error_range()`] method (see below).
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `error_type`: An [`IgnoreError`] enum value.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - Parameter error if more than one rule
+    ///   is added to the same cell.
+    ///
+    /// # Examples
+    ///
+    /// An example of turning off worksheet cells errors/warnings using using
+    /// the `rust_xlsxwriter` library.
+    ///
+    /// ```
+    /// # // This code is available in examples/app_ignore_errors.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, IgnoreError, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Create a format to use in descriptions.
+    /// #     let bold = Format::new().set_bold();
+    /// #
+    /// #     // Make the column wider for clarity.
+    /// #     worksheet.set_column_width(1, 16)?;
+    /// #
+    /// #     // Write some descriptions for the cells.
+    /// #     worksheet.write_with_format(1, 1, "Warning:", &bold)?;
+    /// #     worksheet.write_with_format(2, 1, "Warning turned off:", &bold)?;
+    /// #     worksheet.write_with_format(4, 1, "Warning:", &bold)?;
+    /// #     worksheet.write_with_format(5, 1, "Warning turned off:", &bold)?;
+    /// #
+    ///     // Write strings that looks like numbers. This will cause an Excel warning.
+    ///     worksheet.write_string(1, 2, "123")?;
+    ///     worksheet.write_string(2, 2, "123")?;
+    ///
+    ///     // Write a divide by zero formula. This will also cause an Excel warning.
+    ///     worksheet.write_formula(4, 2, "=1/0")?;
+    ///     worksheet.write_formula(5, 2, "=1/0")?;
+    ///
+    ///     // Turn off some of the warnings:
+    ///     worksheet.ignore_error(2, 2, IgnoreError::NumberStoredAsText)?;
+    ///     worksheet.ignore_error(5, 2, IgnoreError::FormulaError)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("ignore_errors.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/app_ignore_errors.png">
+    ///
+    pub fn ignore_error(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        error_type: IgnoreError,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.ignore_error_range(row, col, row, col, error_type)
+    }
+
+    /// Ignore an Excel error or warning in a range of worksheet cells.
+    ///
+    /// See [`Worksheet::ignore_error()`] above for a detailed explanation of
+    /// Excel worksheet errors.
+    ///
+    /// The `Worksheet::ignore_error_range()` method can be used to ignore an
+    /// error in a range, a row, a column, or the entire worksheet and it can be
+    /// called repeatedly to ignore errors in different cells ranges. It should
+    /// be noted however that **Excel only allows one ignored error per cell**.
+    /// The `rust_xlsxwriter` library verifies that multiple rules aren't added
+    /// to the same cell or cell range and will raise an error if this occurs.
+    /// However it doesn't currently verify whether cells within ranges overlap.
+    /// It is up to the user to ensure that this doesn't happen when using
+    /// ranges.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `error_type`: An [`IgnoreError`] enum value.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    /// - [`XlsxError::ParameterError`] - Parameter error if more than one rule
+    ///   is added to the same range.
+    ///
+    pub fn ignore_error_range(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        error_type: IgnoreError,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        let range = utility::cell_range(first_row, first_col, last_row, last_col);
+
+        // Check if the range was used previously. Note, that this doesn't
+        // current check for range overlaps. One of the rules in an overlap will
+        // be ignored since Excel only allows one rule per cell.
+        if !self
+            .ignored_error_cells
+            .insert((first_row, first_col, last_row, last_col))
+        {
+            return Err(XlsxError::ParameterError(format!(
+                "Excel only allows on rule per cell. Cell/Range '{range}' was previously used."
+            )));
+        }
+
+        // Store the range or append it to an existing range.
+        self.ignored_errors
+            .entry(error_type)
+            .and_modify(|sqref| *sqref = format!("{sqref} {range}"))
+            .or_insert(range);
+
+        Ok(self)
+    }
+
+    // -----------------------------------------------------------------------
+    // Worksheet data access methods.
+    // -----------------------------------------------------------------------
+
+    /// Get the value of a worksheet cell.
+    ///
+    /// The `cell_value()` method returns the data that was written to a cell as
+    /// a [`CellValue`] enum, or `None` if the cell hasn't been written. This
+    /// can be used to read back data that was written in an earlier part of
+    /// an application, for example to add totals or charts based on it.
+    ///
+    /// Note, in "constant memory" and "low memory" mode the worksheet data is
+    /// flushed to disk a row at a time, see
+    /// [`Workbook::add_worksheet_with_constant_memory()`](crate::Workbook::add_worksheet_with_constant_memory).
+    /// In these modes only the cells in rows that haven't been flushed yet are
+    /// visible and `None` is returned for previously written rows.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates reading back the value of a cell
+    /// written to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_cell_value.rs
+    /// #
+    /// # use rust_xlsxwriter::{CellValue, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write(0, 0, "Total")?;
+    ///     worksheet.write(0, 1, 123)?;
+    ///
+    ///     assert_eq!(
+    ///         worksheet.cell_value(0, 0),
+    ///         Some(CellValue::String("Total".to_string()))
+    ///     );
+    ///     assert_eq!(worksheet.cell_value(0, 1), Some(CellValue::Number(123.0)));
+    ///     assert_eq!(worksheet.cell_value(0, 2), None);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn cell_value(&self, row: RowNum, col: ColNum) -> Option<CellValue> {
+        self.get_cell(row, col).map(CellValue::from_cell)
+    }
+
+    /// Get the format of a worksheet cell.
+    ///
+    /// The `cell_format()` method returns the [`Format`] that was applied to a
+    /// cell, or `None` if the cell hasn't been written or doesn't have a
+    /// format. Row and column formats that Excel applies to unformatted cells
+    /// aren't included, unless they were combined into the cell format by
+    /// [`Worksheet::set_cascading_formats()`].
+    ///
+    /// Note, in "constant memory" and "low memory" mode only the cells in rows
+    /// that haven't been flushed yet are visible. See
+    /// [`Worksheet::cell_value()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    ///
+    pub fn cell_format(&self, row: RowNum, col: ColNum) -> Option<Format> {
+        let xf_index = CellValue::cell_xf_index(self.get_cell(row, col)?);
+
+        self.xf_format(xf_index)
+    }
+
+    /// Get the range of cells used in the worksheet.
+    ///
+    /// The `used_range()` method returns the `(first_row, first_col, last_row,
+    /// last_col)` range of the worksheet, or `None` if the worksheet is empty.
+    /// This is the same range that Excel reports as the used range, so as well
+    /// as cells with data it includes formatted cells and rows and cells with
+    /// notes.
+    ///
+    /// Note, in "constant memory" and "low memory" mode the range includes
+    /// rows that have already been flushed to disk.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates getting the used range of a
+    /// worksheet and adding a row of totals below the data.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_used_range.rs
+    /// #
+    /// # use rust_xlsxwriter::{utility, Formula, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write some data.
+    ///     let data = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    ///     worksheet.write_row_matrix(1, 1, data)?;
+    ///
+    ///     // Add a total formula below each column of the data.
+    ///     if let Some((first_row, first_col, last_row, last_col)) = worksheet.used_range() {
+    ///         for col in first_col..=last_col {
+    ///             let range = utility::cell_range(first_row, col, last_row, col);
+    ///             let formula = Formula::new(format!("=SUM({range})"));
+    ///
+    ///             worksheet.write_formula(last_row + 1, col, formula)?;
+    ///         }
+    ///     }
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn used_range(&self) -> Option<(RowNum, ColNum, RowNum, ColNum)> {
+        if self.dimensions.first_row == ROW_MAX || self.dimensions.first_col == COL_MAX {
+            return None;
+        }
+
+        Some((
+            self.dimensions.first_row,
+            self.dimensions.first_col,
+            self.dimensions.last_row,
+            self.dimensions.last_col,
+        ))
+    }
+
+    /// Get an iterator over the cells written to the worksheet.
+    ///
+    /// The `cells()` method returns an iterator over the `(row, col,
+    /// CellValue)` data of the written cells, in row and then column order.
+    ///
+    /// Note, in "constant memory" and "low memory" mode each row is flushed to
+    /// a temporary file when a later row is written, see
+    /// [`Worksheet::cell_value()`]. The cells in flushed rows are no longer
+    /// held by the worksheet and they are skipped by the iterator without an
+    /// error. The [`Worksheet::used_range()`] still includes the flushed rows so
+    /// in these modes the iterator can return fewer rows than the used range.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates iterating over the cells written to
+    /// a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_cells.rs
+    /// #
+    /// # use rust_xlsxwriter::{CellValue, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write_column(0, 0, [10, 20, 30])?;
+    ///     worksheet.write(3, 0, "Done")?;
+    ///
+    ///     // Add up the numbers in the worksheet.
+    ///     let total: f64 = worksheet
+    ///         .cells()
+    ///         .filter_map(|(_, _, value)| match value {
+    ///             CellValue::Number(number) => Some(number),
+    ///             _ => None,
+    ///         })
+    ///         .sum();
+    ///
+    ///     assert_eq!(total, 60.0);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn cells(&self) -> impl Iterator<Item = (RowNum, ColNum, CellValue)> + '_ {
+        let rows = self.data_table.iter();
+
+        // Include any rows that are held back in constant memory mode.
+        #[cfg(feature = "constant_memory")]
+        let rows = rows.chain(self.write_ahead.iter());
+
+        rows.flat_map(|(row, columns)| {
+            columns
+                .iter()
+                .map(move |(col, cell)| (*row, *col, CellValue::from_cell(cell)))
+        })
+    }
+
+    // -----------------------------------------------------------------------
+    // Crate level helper methods.
+    // -----------------------------------------------------------------------
+
+    // Set the write ahead mode when using constant memory.
+    fn set_writing_ahead(&mut self, enable: bool) {
+        if self.use_constant_memory {
+            self.is_writing_ahead = enable;
+        }
+    }
+
+    // Insert or delete rows or columns and adjust the cell data and the
+    // references to the cells. The changes are checked before the worksheet is
+    // modified so that it is unchanged if there is an error.
+    fn shift_cells(
+        &mut self,
+        axis: ShiftAxis,
+        position: u32,
+        count: u32,
+        is_insert: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        if self.use_constant_memory {
+            return Err(XlsxError::ParameterError(
+                "Rows and columns can't be inserted or deleted in constant memory mode".to_string(),
+            ));
+        }
+
+        // Get the limit and the last used row or column for the axis.
+        let (max, last_used) = match axis {
+            ShiftAxis::Rows => {
+                let last_row =
+                    (self.dimensions.first_row != ROW_MAX).then_some(self.dimensions.last_row);
+                (
+                    ROW_MAX,
+                    last_row.max(self.changed_rows.keys().max().copied()),
+                )
+            }
+            ShiftAxis::Columns => {
+                let last_col =
+                    (self.dimensions.first_col != COL_MAX).then_some(self.dimensions.last_col);
+                let last_col = last_col.max(self.changed_cols.keys().max().copied());
+                (u32::from(COL_MAX), last_col.map(u32::from))
+            }
+        };
+
+        // Check that the rows or columns are in the allowed range.
+        if position >= max || count > max - position {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        if count == 0 {
+            return Ok(self);
+        }
+
+        // Check that inserted rows or columns don't push data off the worksheet.
+        if is_insert {
+            if let Some(last_used) = last_used {
+                if last_used >= position && last_used + count >= max {
+                    return Err(XlsxError::RowColumnLimitError);
+                }
+            }
+        }
+
+        // Check that the shift doesn't change the columns or header of a table.
+        let shift = ReferenceShift::new(axis, position, count, is_insert);
+        for table in &self.tables {
+            let cell_range = &table.cell_range;
+            let Some(shifted_range) = shift.cell_range(cell_range) else {
+                continue;
+            };
+
+            let (first, last) = match axis {
+                ShiftAxis::Rows => (cell_range.first_row, cell_range.last_row),
+                ShiftAxis::Columns => (
+                    u32::from(cell_range.first_col),
+                    u32::from(cell_range.last_col),
+                ),
+            };
+
+            let error = match axis {
+                ShiftAxis::Columns if is_insert && position > first && position <= last => {
+                    "Columns can't be inserted into a table"
+                }
+                ShiftAxis::Columns
+                    if !is_insert && position <= last && position + count > first =>
+                {
+                    "Columns can't be deleted from a table unless the entire table is deleted"
+                }
+                ShiftAxis::Rows
+                    if !is_insert
+                        && table.show_header_row
+                        && first >= position
+                        && first < position + count =>
+                {
+                    "The header row of a table can't be deleted unless the entire table is deleted"
+                }
+                ShiftAxis::Rows
+                    if table.show_header_row
+                        && shifted_range.first_row == shifted_range.last_row =>
+                {
+                    "Table must have at least one row"
+                }
+                _ => continue,
+            };
+
+            return Err(XlsxError::TableError(format!(
+                "{error}: {}",
+                cell_range.to_error_string()
+            )));
+        }
+
+        self.shift_references(&shift);
+
+        // Store the shift so that the workbook can adjust the references to
+        // the worksheet in the other worksheets and in the defined names.
+        self.reference_changes
+            .push((self.name.clone(), ReferenceChange::Shift(shift)));
+
+        Ok(self)
+    }
+
+    // Adjust the references to another worksheet in the formulas, charts,
+    // conditional formats, data validations, sparklines and table formulas of
+    // the worksheet for rows or columns that have been inserted into, or
+    // deleted from, the other worksheet, or for cells that have been moved.
+    // This is called by the workbook for the changes in its other worksheets.
+    pub(crate) fn apply_external_change(&mut self, sheet_name: &str, change: &ReferenceChange) {
+        let shift = |formula: &str| change.formula(formula, sheet_name, false);
+
+        for columns in self.data_table.values_mut() {
+            for cell in columns.values_mut() {
+                Self::shift_external_formula(cell, change, sheet_name);
+            }
+        }
+
+        for chart in self.charts.values_mut() {
+            chart.shift_ranges(change, sheet_name);
+        }
+
+        for rules in self.conditional_formats.values_mut() {
+            for rule in rules {
+                rule.shift_formulas(&shift);
+            }
+        }
+
+        for data_validation in self.data_validations.values_mut() {
+            data_validation.shift_formulas(&shift);
+        }
+
+        for table in &mut self.tables {
+            for column in &mut table.columns {
+                if let Some(formula) = column.formula.as_mut() {
+                    formula.formula_string = shift(&formula.formula_string);
+                }
+            }
+        }
+
+        self.shift_hyperlink_locations(shift);
+
+        // Sparklines with deleted data ranges are removed, as in the source
+        // worksheet.
+        for sparkline in &mut self.sparklines {
+            sparkline.ranges = std::mem::take(&mut sparkline.ranges)
+                .into_iter()
+                .filter_map(|(location, data_range)| {
+                    let data_range = shift(&data_range);
+                    (!data_range.contains("#REF!")).then_some((location, data_range))
+                })
+                .collect();
+        }
+        self.sparklines
+            .retain(|sparkline| !sparkline.ranges.is_empty());
+        self.has_sparklines = !self.sparklines.is_empty();
+    }
+
+    // Adjust the cell locations of the internal hyperlinks in the worksheet.
+    // Locations without a sheet name refer to the worksheet itself.
+    fn shift_hyperlink_locations(&mut self, shift: impl Fn(&str) -> String) {
+        for hyperlink in self.hyperlinks.values_mut() {
+            if hyperlink.link_type == HyperlinkType::Internal {
+                hyperlink.anchor = shift(&hyperlink.anchor);
+            }
+        }
+    }
+
+    // Adjust the references to another worksheet in a formula cell.
+    fn shift_external_formula(cell: &mut CellType, change: &ReferenceChange, sheet_name: &str) {
+        if let CellType::Formula { formula, .. } | CellType::ArrayFormula { formula, .. } = cell {
+            *formula = change.formula(formula, sheet_name, false).into();
+        }
+    }
+
+    // Move the cell data, objects and cell references in the worksheet for an
+    // insertion or deletion of rows or columns. Objects that are anchored to
+    // deleted cells are removed.
+    fn shift_references(&mut self, shift: &ReferenceShift) {
+        let sheet_name = self.name.clone();
+        let change = ReferenceChange::Shift(shift.clone());
+
+        // Move the cells and adjust the references in formulas.
+        for (row, columns) in std::mem::take(&mut self.data_table) {
+            for (col, mut cell) in columns {
+                let Some((row, col)) = shift.cell(row, col) else {
+                    continue;
+                };
+
+                match &mut cell {
+                    CellType::Formula { formula, .. } => {
+                        *formula = shift.formula(formula, &sheet_name, true).into();
+                    }
+                    CellType::ArrayFormula { formula, range, .. } => {
+                        *formula = shift.formula(formula, &sheet_name, true).into();
+                        if let Some(shifted_range) = shift.sqref(range) {
+                            *range = shifted_range.into();
+                        }
+                    }
+                    _ => {}
+                }
+
+                self.data_table.entry(row).or_default().insert(col, cell);
+            }
+        }
+
+        // Move the row and column options.
+        self.changed_rows = std::mem::take(&mut self.changed_rows)
+            .into_iter()
+            .filter_map(|(row, options)| Some((shift.row(row)?, options)))
+            .collect();
+
+        self.changed_cols = std::mem::take(&mut self.changed_cols)
+            .into_iter()
+            .filter_map(|(col, options)| Some((shift.col(col)?, options)))
+            .collect();
+
+        // Adjust the merged ranges. Ranges that are reduced to a single cell
+        // are no longer merged.
+        self.merged_cells.clear();
+        for cell_range in std::mem::take(&mut self.merged_ranges) {
+            let Some(cell_range) = shift.cell_range(&cell_range) else {
+                continue;
+            };
+
+            if cell_range.first_row == cell_range.last_row
+                && cell_range.first_col == cell_range.last_col
+            {
+                continue;
+            }
+
+            let index = self.merged_ranges.len();
+            for row in cell_range.first_row..=cell_range.last_row {
+                for col in cell_range.first_col..=cell_range.last_col {
+                    self.merged_cells.insert((row, col), index);
+                }
+            }
+            self.merged_ranges.push(cell_range);
+        }
+
+        // Adjust the tables and the column formulas.
+        self.table_ranges.clear();
+        self.table_cells.clear();
+        for mut table in std::mem::take(&mut self.tables) {
+            let Some(cell_range) = shift.cell_range(&table.cell_range) else {
+                continue;
+            };
+
+            for column in &mut table.columns {
+                if let Some(formula) = column.formula.as_mut() {
+                    formula.formula_string =
+                        shift.formula(&formula.formula_string, &sheet_name, true);
+                }
+            }
+
+            let index = self.table_ranges.len();
+            for row in cell_range.first_row..=cell_range.last_row {
+                for col in cell_range.first_col..=cell_range.last_col {
+                    self.table_cells.insert((row, col), index);
+                }
+            }
+
+            table.cell_range = cell_range.clone();
+            self.table_ranges.push(cell_range);
+            self.tables.push(table);
+        }
+
+        // Adjust the worksheet autofilter and the autofilter cells.
+        self.cells_with_autofilter.clear();
+        if self.autofilter_defined_name.in_use {
+            let autofilter = &mut self.autofilter_defined_name;
+            match shift.range(
+                autofilter.first_row,
+                autofilter.first_col,
+                autofilter.last_row,
+                autofilter.last_col,
+            ) {
+                Some((first_row, first_col, last_row, last_col)) => {
+                    autofilter.first_row = first_row;
+                    autofilter.first_col = first_col;
+                    autofilter.last_row = last_row;
+                    autofilter.last_col = last_col;
+
+                    let autofilter_area = CellRange::new(first_row, first_col, last_row, last_col);
+                    for col in first_col..=last_col {
+                        self.cells_with_autofilter.insert(
+                            (first_row, col),
+                            (FilterType::Worksheet, autofilter_area.clone()),
+                        );
+                    }
+                    self.autofilter_area = autofilter_area.to_range_string();
+
+                    self.filter_conditions = std::mem::take(&mut self.filter_conditions)
+                        .into_iter()
+                        .filter_map(|(col, condition)| Some((shift.col(col)?, condition)))
+                        .collect();
+                }
+                None => {
+                    self.autofilter_defined_name = DefinedName::new();
+                    self.autofilter_area.clear();
+                    self.filter_conditions.clear();
+                }
+            }
+        }
+
+        for table in self.tables.iter().filter(|table| table.show_autofilter) {
+            let cell_range = &table.cell_range;
+            for col in cell_range.first_col..=cell_range.last_col {
+                self.cells_with_autofilter.insert(
+                    (cell_range.first_row, col),
+                    (FilterType::Table, cell_range.clone()),
+                );
+            }
+        }
+
+        // Adjust the conditional format ranges, merging any that become the
+        // same range.
+        let mut conditional_formats = std::mem::take(&mut self.conditional_formats);
+        for cell_range in std::mem::take(&mut self.conditional_format_order) {
+            let mut rules = conditional_formats.remove(&cell_range).unwrap_or_default();
+            let Some(cell_range) = shift.sqref(&cell_range) else {
+                continue;
+            };
+
+            for rule in &mut rules {
+                rule.shift_formulas(&|formula| shift.formula(formula, &sheet_name, true));
+            }
+
+            match self.conditional_formats.entry(cell_range.clone()) {
+                Entry::Occupied(mut entry) => entry.get_mut().extend(rules),
+                Entry::Vacant(entry) => {
+                    entry.insert(rules);
+                    self.conditional_format_order.push(cell_range);
+                }
+            }
+        }
+
+        let rules = self.conditional_formats.values().flatten();
+        self.has_conditional_formats = rules.clone().any(|rule| !rule.has_x14_only());
+        self.has_x14_conditional_formats = rules.clone().any(|rule| rule.has_x14_extensions());
+
+        // Adjust the data validation ranges and rule references.
+        for (cell_range, mut data_validation) in std::mem::take(&mut self.data_validations) {
+            let Some(cell_range) = shift.sqref(&cell_range) else {
+                continue;
+            };
+
+            if !data_validation.multi_range.is_empty() {
+                data_validation.multi_range.clone_from(&cell_range);
+            }
+            data_validation.shift_formulas(&|formula| shift.formula(formula, &sheet_name, true));
+
+            self.data_validations.insert(cell_range, data_validation);
+        }
+
+        // Move the notes.
+        for (row, columns) in std::mem::take(&mut self.notes) {
+            for (col, mut note) in columns {
+                let Some((row, col)) = shift.cell(row, col) else {
+                    continue;
+                };
+
+                note.cell_row = row;
+                note.cell_col = col;
+                self.notes.entry(row).or_default().insert(col, note);
+            }
+        }
+
+        // Move the objects that are anchored to cells.
+        self.images = std::mem::take(&mut self.images)
+            .into_iter()
+            .filter_map(|(anchor, image)| {
+                Some((shift.anchor(anchor, image.object_movement)?, image))
+            })
+            .collect();
+
+        self.charts = std::mem::take(&mut self.charts)
+            .into_iter()
+            .filter_map(|(anchor, mut chart)| {
+                chart.shift_ranges(&change, &sheet_name);
+                Some((shift.anchor(anchor, chart.object_movement)?, chart))
+            })
+            .collect();
+
+        self.buttons = std::mem::take(&mut self.buttons)
+            .into_iter()
+            .filter_map(|(anchor, button)| {
+                Some((shift.anchor(anchor, button.object_movement)?, button))
+            })
+            .collect();
+
+        self.shapes = std::mem::take(&mut self.shapes)
+            .into_iter()
+            .filter_map(|(anchor, shape)| {
+                Some((shift.anchor(anchor, shape.object_movement)?, shape))
+            })
+            .collect();
+
+        self.ole_objects = std::mem::take(&mut self.ole_objects)
+            .into_iter()
+            .filter_map(|(anchor, ole_object)| {
+                Some((
+                    shift.anchor(anchor, ole_object.object_movement)?,
+                    ole_object,
+                ))
+            })
+            .collect();
+
+        self.hyperlinks = std::mem::take(&mut self.hyperlinks)
+            .into_iter()
+            .filter_map(|((row, col), url)| Some((shift.cell(row, col)?, url)))
+            .collect();
+        self.shift_hyperlink_locations(|location| shift.formula(location, &sheet_name, true));
+
+        // Adjust the sparkline locations and data ranges.
+        for sparkline in &mut self.sparklines {
+            sparkline.ranges = std::mem::take(&mut sparkline.ranges)
+                .into_iter()
+                .filter_map(|(location, data_range)| {
+                    let location = shift.sqref(&location)?;
+                    let data_range = shift.formula(&data_range, &sheet_name, false);
+
+                    (!data_range.contains("#REF!")).then_some((location, data_range))
+                })
+                .collect();
+        }
+        self.sparklines
+            .retain(|sparkline| !sparkline.ranges.is_empty());
+        self.has_sparklines = !self.sparklines.is_empty();
+
+        // Adjust the print area and the repeat rows and columns.
+        let print_area = &mut self.print_area_defined_name;
+        if print_area.in_use {
+            match shift.range(
+                print_area.first_row,
+                print_area.first_col,
+                print_area.last_row,
+                print_area.last_col,
+            ) {
+                Some((first_row, first_col, last_row, last_col)) => {
+                    print_area.first_row = first_row;
+                    print_area.first_col = first_col;
+                    print_area.last_row = last_row;
+                    print_area.last_col = last_col;
+                }
+                None => *print_area = DefinedName::new(),
+            }
+        }
+
+        let repeat = &mut self.repeat_row_cols_defined_name;
+        if repeat.in_use {
+            if repeat.first_row != ROW_MAX {
+                match shift.range(repeat.first_row, 0, repeat.last_row, 0) {
+                    Some((first_row, _, last_row, _)) => {
+                        repeat.first_row = first_row;
+                        repeat.last_row = last_row;
+                    }
+                    None => {
+                        repeat.first_row = ROW_MAX;
+                        repeat.last_row = 0;
+                    }
+                }
+            }
+
+            if repeat.first_col != COL_MAX {
+                match shift.range(0, repeat.first_col, 0, repeat.last_col) {
+                    Some((_, first_col, _, last_col)) => {
+                        repeat.first_col = first_col;
+                        repeat.last_col = last_col;
+                    }
+                    None => {
+                        repeat.first_col = COL_MAX;
+                        repeat.last_col = 0;
+                    }
+                }
+            }
+
+            if repeat.first_row == ROW_MAX && repeat.first_col == COL_MAX {
+                *repeat = DefinedName::new();
+            }
+        }
+
+        // Adjust the ignored error ranges.
+        self.ignored_error_cells = std::mem::take(&mut self.ignored_error_cells)
+            .into_iter()
+            .filter_map(|(first_row, first_col, last_row, last_col)| {
+                shift.range(first_row, first_col, last_row, last_col)
+            })
+            .collect();
+
+        self.ignored_errors = std::mem::take(&mut self.ignored_errors)
+            .into_iter()
+            .filter_map(|(error_type, sqref)| Some((error_type, shift.sqref(&sqref)?)))
+            .collect();
+
+        // Move the cascading cell and range formats.
+        self.cascading_cell_formats = std::mem::take(&mut self.cascading_cell_formats)
+            .into_iter()
+            .filter_map(|((row, col), format)| Some((shift.cell(row, col)?, format)))
+            .collect();
+
+        self.cascading_range_formats = std::mem::take(&mut self.cascading_range_formats)
+            .into_iter()
+            .filter_map(|((row, col), format)| Some((shift.cell(row, col)?, format)))
+            .collect();
+
+        // Adjust the unprotected ranges and the page breaks.
+        self.unprotected_ranges = std::mem::take(&mut self.unprotected_ranges)
+            .into_iter()
+            .filter_map(|(range, name, password_hash)| {
+                Some((shift.sqref(&range)?, name, password_hash))
+            })
+            .collect();
+
+        self.horizontal_breaks = std::mem::take(&mut self.horizontal_breaks)
+            .into_iter()
+            .filter_map(|row| shift.row(row))
+            .collect();
+
+        self.vertical_breaks = std::mem::take(&mut self.vertical_breaks)
+            .into_iter()
+            .filter_map(|col| shift.col(col as ColNum).map(u32::from))
+            .collect();
+
+        // Adjust the worksheet dimensions.
+        self.dimensions =
+            if self.dimensions.first_row == ROW_MAX || self.dimensions.first_col == COL_MAX {
+                CellRange::default()
+            } else {
+                shift.cell_range(&self.dimensions).unwrap_or_default()
+            };
+    }
+
+    // Paste a range of copied cells into the worksheet. The relative references
+    // in the copied formulas are adjusted for a copy but not for a move.
+    fn paste_range_internal(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: &CopiedRange,
+        options: &PasteOptions,
+        adjust_formulas: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        if self.use_constant_memory {
+            return Err(XlsxError::ParameterError(
+                "Ranges can't be pasted in constant memory mode".to_string(),
+            ));
+        }
+
+        let transpose = options.transpose;
+        let paste_type = options.paste_type;
+        let cell_range = Self::paste_target_range(row, col, data, transpose)?;
+
+        if paste_type != PasteType::Values {
+            self.check_paste_merged_ranges(&cell_range, None)?;
+        }
+
+        // Get the target position of a cell in the copied range.
+        let position = |copied_row: RowNum, copied_col: ColNum| {
+            if transpose {
+                (row + RowNum::from(copied_col), col + copied_row as ColNum)
+            } else {
+                (row + copied_row, col + copied_col)
+            }
+        };
+
+        let copied_range = CellRange::new(
+            data.first_row,
+            data.first_col,
+            data.first_row + data.num_rows - 1,
+            data.first_col + data.num_cols - 1,
+        );
+
+        let copied_cells: BTreeMap<(RowNum, ColNum), &CopiedCell> = data
+            .cells
+            .iter()
+            .map(|copied| (position(copied.row, copied.col), copied))
+            .collect();
+
+        // Get the existing cells in the target range and their formats.
+        let mut existing_cells = HashMap::new();
+        for (cell_row, columns) in self
+            .data_table
+            .range(cell_range.first_row..=cell_range.last_row)
+        {
+            for (cell_col, cell) in columns.range(cell_range.first_col..=cell_range.last_col) {
+                let is_blank = matches!(cell, CellType::Blank { .. });
+                existing_cells.insert(
+                    (*cell_row, *cell_col),
+                    (CellValue::cell_xf_index(cell), is_blank),
+                );
+            }
+        }
+
+        match paste_type {
+            PasteType::All => {
+                self.clear_paste_range(&cell_range);
+
+                for (&(cell_row, cell_col), copied) in &copied_cells {
+                    let Some(mut cell) = self.pasted_cell(copied, false) else {
+                        continue;
+                    };
+
+                    // Adjust the formula references for the new position.
+                    let row_offset = i64::from(cell_row) - i64::from(data.first_row + copied.row);
+                    let col_offset = i64::from(cell_col) - i64::from(data.first_col + copied.col);
+                    let adjust_formula = |formula: &str| {
+                        if !adjust_formulas {
+                            formula.into()
+                        } else if transpose {
+                            shift::transpose_formula(
+                                formula,
+                                &copied_range,
+                                row,
+                                col,
+                                row_offset,
+                                col_offset,
+                            )
+                            .into_boxed_str()
+                        } else {
+                            shift::offset_formula(formula, row_offset, col_offset).into_boxed_str()
+                        }
+                    };
+
+                    match &mut cell {
+                        CellType::Formula { formula, .. } => *formula = adjust_formula(formula),
+                        CellType::ArrayFormula { formula, range, .. } => {
+                            *formula = adjust_formula(formula);
+
+                            if let Some((first_row, first_col, last_row, last_col)) =
+                                shift::parse_range(range)
+                            {
+                                let (height, width) = if transpose {
+                                    (
+                                        RowNum::from(last_col - first_col),
+                                        (last_row - first_row) as ColNum,
+                                    )
+                                } else {
+                                    (last_row - first_row, last_col - first_col)
+                                };
+
+                                let (last_row, last_col) = (cell_row + height, cell_col + width);
+                                *range =
+                                    utility::cell_range(cell_row, cell_col, last_row, last_col)
+                                        .into_boxed_str();
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    let xf_index = match &copied.format {
+                        Some(format) => self.format_xf_index(format),
+                        None => 0,
+                    };
+
+                    self.check_dimensions(cell_row, cell_col);
+                    self.insert_cell(cell_row, cell_col, cell);
+                    self.update_cell_format(cell_row, cell_col, xf_index);
+                    self.paste_cascading_formats(cell_row, cell_col, copied);
+                }
+
+                for (copied_row, copied_col, url) in &data.hyperlinks {
+                    let (cell_row, cell_col) = position(*copied_row, *copied_col);
+                    self.hyperlinks.insert((cell_row, cell_col), url.clone());
+                }
+
+                for (copied_row, copied_col, note) in &data.notes {
+                    let (cell_row, cell_col) = position(*copied_row, *copied_col);
+                    self.insert_note(cell_row, cell_col, note)?;
+                }
+            }
+            PasteType::Values => {
+                // Clear the values of the target cells that don't have a copied
+                // value but keep their formatting.
+                for (&(cell_row, cell_col), &(xf_index, _)) in &existing_cells {
+                    let has_value = copied_cells
+                        .get(&(cell_row, cell_col))
+                        .is_some_and(|copied| !matches!(copied.cell, CellType::Blank { .. }));
+
+                    if has_value {
+                        continue;
+                    }
+
+                    if xf_index == 0 {
+                        self.clear_cell_internal(cell_row, cell_col);
+                    } else {
+                        self.insert_cell(cell_row, cell_col, CellType::Blank { xf_index });
+                    }
+                }
+
+                for (&(cell_row, cell_col), copied) in &copied_cells {
+                    let Some(cell) = self.pasted_cell(copied, true) else {
+                        continue;
+                    };
+
+                    let xf_index = match existing_cells.get(&(cell_row, cell_col)) {
+                        Some(&(xf_index, _)) => xf_index,
+                        None => self.row_col_format(cell_row, cell_col),
+                    };
+
+                    self.check_dimensions(cell_row, cell_col);
+                    self.insert_cell(cell_row, cell_col, cell);
+                    self.update_cell_format(cell_row, cell_col, xf_index);
+                }
+            }
+            PasteType::Formats => {
+                self.remove_merged_ranges(&cell_range);
+                self.clear_cascading_formats(&cell_range);
+
+                for (&(cell_row, cell_col), &(_, is_blank)) in &existing_cells {
+                    if copied_cells.contains_key(&(cell_row, cell_col)) {
+                        continue;
+                    }
+
+                    if is_blank {
+                        self.clear_cell_internal(cell_row, cell_col);
+                    } else {
+                        self.update_cell_format(cell_row, cell_col, 0);
+                    }
+                }
+
+                for (&(cell_row, cell_col), copied) in &copied_cells {
+                    match &copied.format {
+                        Some(format) => {
+                            let xf_index = self.format_xf_index(format);
+                            self.check_dimensions(cell_row, cell_col);
+                            self.insert_cell_format(cell_row, cell_col, xf_index);
+                            self.paste_cascading_formats(cell_row, cell_col, copied);
+                        }
+                        None => match existing_cells.get(&(cell_row, cell_col)) {
+                            Some((_, true)) => self.clear_cell_internal(cell_row, cell_col),
+                            Some((_, false)) => self.update_cell_format(cell_row, cell_col, 0),
+                            None => {}
+                        },
+                    }
+                }
+            }
+        }
+
+        // Paste the merged ranges and conditional formats.
+        if paste_type != PasteType::Values {
+            for merged_range in &data.merged_ranges {
+                let (first_row, first_col) =
+                    position(merged_range.first_row, merged_range.first_col);
+                let (last_row, last_col) = position(merged_range.last_row, merged_range.last_col);
+
+                self.store_merged_range(CellRange::new(first_row, first_col, last_row, last_col));
+            }
+
+            for (ranges, conditional_format) in &data.conditional_formats {
+                let sqref: Vec<String> = ranges
+                    .iter()
+                    .map(|range| {
+                        let (first_row, first_col) = position(range.first_row, range.first_col);
+                        let (last_row, last_col) = position(range.last_row, range.last_col);
+                        utility::cell_range(first_row, first_col, last_row, last_col)
+                    })
+                    .collect();
+
+                self.store_conditional_format(sqref.join(" "), conditional_format.box_clone());
+            }
+        }
+
+        Ok(self)
+    }
+
+    // Get a copy of a copied cell to paste into the worksheet. For a values
+    // only paste formulas are replaced by their results and None is returned
+    // if the cell doesn't have a value.
+    fn pasted_cell(&mut self, copied: &CopiedCell, values_only: bool) -> Option<CellType> {
+        let mut cell = copied.cell.clone();
+
+        match &mut cell {
+            CellType::Blank { .. } if values_only => return None,
+            CellType::Formula { result, .. } | CellType::ArrayFormula { result, .. }
+                if values_only =>
+            {
+                let result: &str = result;
+                cell = match result {
+                    "" => return None,
+                    "TRUE" | "FALSE" => CellType::Boolean {
+                        boolean: result == "TRUE",
+                        xf_index: 0,
+                    },
+                    _ => match (result.parse::<f64>(), result.parse::<ExcelError>()) {
+                        (Ok(number), _) => CellType::Number {
+                            number,
+                            xf_index: 0,
+                        },
+                        // The #SPILL! and #CALC! errors aren't formula result
+                        // errors so they are pasted as strings.
+                        (_, Ok(error)) if error.value_metadata_id().is_none() => CellType::Error {
+                            error,
+                            xf_index: 0,
+                            image_index: None,
+                        },
+                        _ => CellType::String {
+                            string: Arc::from(result),
+                            xf_index: 0,
+                            string_id: None,
+                        },
+                    },
+                };
+            }
+            CellType::ArrayFormula {
+                is_dynamic: true, ..
+            } => self.has_dynamic_arrays = true,
+            CellType::String { string_id, .. } => *string_id = None,
+            CellType::RichString {
+                string_id,
+                phonetic,
+                ..
+            } => {
+                *string_id = None;
+                if phonetic.is_some() {
+                    self.has_phonetic_strings = true;
+                }
+            }
+            CellType::Error {
+                image_index: Some(index),
+                ..
+            } => {
+                let image = copied.image.as_ref()?;
+                *index = self.store_embedded_image_id(image);
+            }
+            _ => {}
+        }
+
+        if matches!(cell, CellType::String { .. } | CellType::RichString { .. })
+            && !self.use_inline_strings
         {
+            self.has_local_string_table = true;
+        }
+
+        Some(cell)
+    }
+
+    // Get the target range for pasting a range of copied cells.
+    fn paste_target_range(
+        row: RowNum,
+        col: ColNum,
+        data: &CopiedRange,
+        transpose: bool,
+    ) -> Result<CellRange, XlsxError> {
+        let (num_rows, num_cols) = if transpose {
+            (RowNum::from(data.num_cols), data.num_rows)
+        } else {
+            (data.num_rows, u32::from(data.num_cols))
+        };
+
+        // Check the first cell before calculating the range to avoid overflow.
+        if row >= ROW_MAX || col >= COL_MAX {
             return Err(XlsxError::RowColumnLimitError);
         }
 
-        // Check order of first/last values.
-        if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+        let last_row = row + num_rows - 1;
+        let last_col = u32::from(col) + num_cols - 1;
+
+        if last_row >= ROW_MAX || last_col >= u32::from(COL_MAX) {
+            return Err(XlsxError::RowColumnLimitError);
         }
 
-        let range = utility::cell_range(first_row, first_col, last_row, last_col);
+        Ok(CellRange::new(row, col, last_row, last_col as ColNum))
+    }
 
-        // Check if the range was used previously. Note, that this doesn't
-        // current check for range overlaps. One of the rules in an overlap will
-        // be ignored since Excel only allows one rule per cell.
-        if !self
-            .ignored_error_cells
-            .insert((first_row, first_col, last_row, last_col))
+    // Check that a paste target range doesn't overlap part of a merged range.
+    // Merged ranges within the `ignored_range`, such as the original range of
+    // moved cells, aren't checked.
+    fn check_paste_merged_ranges(
+        &self,
+        cell_range: &CellRange,
+        ignored_range: Option<&CellRange>,
+    ) -> Result<(), XlsxError> {
+        for merged_range in &self.merged_ranges {
+            let is_ignored = ignored_range
+                .is_some_and(|ignored_range| ignored_range.contains_range(merged_range));
+
+            if cell_range.overlaps(merged_range)
+                && !cell_range.contains_range(merged_range)
+                && !is_ignored
+            {
+                return Err(XlsxError::MergeRangeOverlaps(
+                    cell_range.to_error_string(),
+                    merged_range.to_error_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Clear the cells in a range and the hyperlinks, notes, merged ranges and
+    // conditional formats within it.
+    fn clear_paste_range(&mut self, cell_range: &CellRange) {
+        for columns in self
+            .data_table
+            .range_mut(cell_range.first_row..=cell_range.last_row)
+            .map(|(_, columns)| columns)
         {
-            return Err(XlsxError::ParameterError(format!(
-                "Excel only allows on rule per cell. Cell/Range '{range}' was previously used."
-            )));
+            columns.retain(|col, _| !(cell_range.first_col..=cell_range.last_col).contains(col));
         }
 
-        // Store the range or append it to an existing range.
-        self.ignored_errors
-            .entry(error_type)
-            .and_modify(|sqref| *sqref = format!("{sqref} {range}"))
-            .or_insert(range);
+        for columns in self
+            .notes
+            .range_mut(cell_range.first_row..=cell_range.last_row)
+            .map(|(_, columns)| columns)
+        {
+            columns.retain(|col, _| !(cell_range.first_col..=cell_range.last_col).contains(col));
+        }
+
+        self.hyperlinks
+            .retain(|(row, col), _| !cell_range.contains(*row, *col));
+
+        self.remove_merged_ranges(cell_range);
+        self.clear_cascading_formats(cell_range);
+
+        // Remove the conditional format ranges within the range.
+        let mut conditional_formats = std::mem::take(&mut self.conditional_formats);
+        for sqref in std::mem::take(&mut self.conditional_format_order) {
+            let rules = conditional_formats.remove(&sqref).unwrap_or_default();
+            let ranges: Vec<&str> = sqref
+                .split_whitespace()
+                .filter(|range| {
+                    !shift::parse_range(range).is_some_and(
+                        |(first_row, first_col, last_row, last_col)| {
+                            cell_range.contains(first_row, first_col)
+                                && cell_range.contains(last_row, last_col)
+                        },
+                    )
+                })
+                .collect();
+
+            if ranges.is_empty() {
+                continue;
+            }
 
-        Ok(self)
+            let sqref = ranges.join(" ");
+            match self.conditional_formats.entry(sqref.clone()) {
+                Entry::Occupied(mut entry) => entry.get_mut().extend(rules),
+                Entry::Vacant(entry) => {
+                    entry.insert(rules);
+                    self.conditional_format_order.push(sqref);
+                }
+            }
+        }
+
+        let rules = self.conditional_formats.values().flatten();
+        self.has_conditional_formats = rules.clone().any(|rule| !rule.has_x14_only());
+        self.has_x14_conditional_formats = rules.clone().any(|rule| rule.has_x14_extensions());
     }
 
-    // -----------------------------------------------------------------------
-    // Crate level helper methods.
-    // -----------------------------------------------------------------------
+    // Store the cascading cell and range formats of a pasted cell so that they
+    // are layered with any later row, column or range formats. Cells copied
+    // from a worksheet without cascading formats use the cell format.
+    fn paste_cascading_formats(&mut self, row: RowNum, col: ColNum, copied: &CopiedCell) {
+        if !self.cascading_formats {
+            return;
+        }
 
-    // Set the write ahead mode when using constant memory.
-    fn set_writing_ahead(&mut self, enable: bool) {
-        if self.use_constant_memory {
-            self.is_writing_ahead = enable;
+        let (cell_format, range_format) = match &copied.cascading_formats {
+            Some((cell_format, range_format)) => (cell_format.as_ref(), range_format.as_ref()),
+            None => (copied.format.as_ref(), None),
+        };
+
+        if let Some(format) = cell_format {
+            let layer_index = self.cascading_layer_index(format);
+            self.cascading_cell_formats.insert((row, col), layer_index);
+        }
+
+        if let Some(format) = range_format {
+            let layer_index = self.cascading_layer_index(format);
+            self.cascading_range_formats.insert((row, col), layer_index);
+        }
+    }
+
+    // Remove the cascading cell and range formats within a range.
+    fn clear_cascading_formats(&mut self, cell_range: &CellRange) {
+        self.cascading_cell_formats
+            .retain(|(row, col), _| !cell_range.contains(*row, *col));
+        self.cascading_range_formats
+            .retain(|(row, col), _| !cell_range.contains(*row, *col));
+    }
+
+    // Remove the merged ranges that are within a range.
+    fn remove_merged_ranges(&mut self, cell_range: &CellRange) {
+        self.merged_cells.clear();
+        for merged_range in std::mem::take(&mut self.merged_ranges) {
+            if !cell_range.contains_range(&merged_range) {
+                self.store_merged_range(merged_range);
+            }
+        }
+    }
+
+    // Store a merged range without checking for overlaps.
+    fn store_merged_range(&mut self, cell_range: CellRange) {
+        let index = self.merged_ranges.len();
+        for row in cell_range.first_row..=cell_range.last_row {
+            for col in cell_range.first_col..=cell_range.last_col {
+                self.merged_cells.insert((row, col), index);
+            }
+        }
+        self.merged_ranges.push(cell_range);
+    }
+
+    // Get the active cell and the space separated ranges for a selection. The
+    // first/last order of a range can be reversed to allow a selection to go
+    // from the end to the start. We take the active cell from the user first
+    // row/col of the first range and then reverse them as required for the
+    // full range.
+    fn selection(
+        &mut self,
+        ranges: &[(RowNum, ColNum, RowNum, ColNum)],
+    ) -> Result<(String, String), XlsxError> {
+        let Some(&(first_row, first_col, _, _)) = ranges.first() else {
+            return Err(XlsxError::ParameterError(
+                "Selection must contain at least one range".to_string(),
+            ));
+        };
+
+        let active_cell = utility::row_col_to_cell(first_row, first_col);
+        let mut sqref = vec![];
+
+        for &(first_row, first_col, last_row, last_col) in ranges {
+            // Check rows and cols are in the allowed range.
+            if !self.check_dimensions_only(first_row, first_col)
+                || !self.check_dimensions_only(last_row, last_col)
+            {
+                return Err(XlsxError::RowColumnLimitError);
+            }
+
+            let (first_row, last_row) = (first_row.min(last_row), first_row.max(last_row));
+            let (first_col, last_col) = (first_col.min(last_col), first_col.max(last_col));
+
+            sqref.push(utility::cell_range(
+                first_row, first_col, last_row, last_col,
+            ));
         }
+
+        Ok((active_cell, sqref.join(" ")))
     }
 
     // Get the minimum row number for the dimension check/set.
@@ -15017,10 +18273,7 @@ impl Worksheet {
         }
 
         // Get the index of the format object, if any.
-        let xf_index = match format {
-            Some(format) => self.format_xf_index(format),
-            None => self.row_col_format(row, col),
-        };
+        let xf_index = self.cell_format_index(row, col, format);
 
         // Create the appropriate cell type to hold the data.
         let cell = if is_datetime {
@@ -15062,10 +18315,7 @@ impl Worksheet {
         }
 
         // Get the index of the format object, if any.
-        let xf_index = match format {
-            Some(format) => self.format_xf_index(format),
-            None => self.row_col_format(row, col),
-        };
+        let xf_index = self.cell_format_index(row, col, format);
 
         // Create the appropriate cell type to hold the data.
         let cell = if self.use_inline_strings {
@@ -15106,6 +18356,7 @@ impl Worksheet {
         string: &str,
         raw_string: &str,
         format: Option<&Format>,
+        phonetic: Option<&PhoneticString>,
     ) -> Result<&mut Worksheet, XlsxError> {
         // Empty strings are ignored by Excel unless they have a format in which
         // case they are treated as a blank cell.
@@ -15127,10 +18378,20 @@ impl Worksheet {
         }
 
         // Get the index of the format object, if any.
-        let xf_index = match format {
-            Some(format) => self.format_xf_index(format),
-            None => self.row_col_format(row, col),
-        };
+        let xf_index = self.cell_format_index(row, col, format);
+
+        // Phonetic strings that are written to the workbook SST, or inline in
+        // constant memory mode, are completed with the <phoneticPr> element at
+        // this point. Otherwise it is added when the local SST is updated.
+        let mut string = string.to_string();
+        let phonetic = phonetic.map(|phonetic| phonetic.visible);
+        if phonetic.is_some() {
+            self.has_phonetic_strings = true;
+
+            if self.has_workbook_global_sst || self.use_constant_memory {
+                string.push_str(&self.phonetic_properties_xml());
+            }
+        }
 
         // Create the appropriate cell type to hold the data.
         let mut string_id = None;
@@ -15139,7 +18400,7 @@ impl Worksheet {
 
         if self.has_workbook_global_sst {
             let mut string_table = self.string_table.lock().unwrap();
-            string_id = Some(string_table.shared_string_index(Arc::clone(&string)));
+            string_id = Some(string_table.shared_xml_string_index(Arc::clone(&string)));
         }
 
         let cell = CellType::RichString {
@@ -15147,6 +18408,7 @@ impl Worksheet {
             xf_index,
             raw_string,
             string_id,
+            phonetic,
         };
 
         self.insert_cell(row, col, cell);
@@ -15177,10 +18439,7 @@ impl Worksheet {
         }
 
         // Get the index of the format object, if any.
-        let xf_index = match format {
-            Some(format) => self.format_xf_index(format),
-            None => self.row_col_format(row, col),
-        };
+        let xf_index = self.cell_format_index(row, col, format);
 
         // Set the formula result to the default or user defined
         let result = if formula.result.is_empty() {
@@ -15226,10 +18485,7 @@ impl Worksheet {
         }
 
         // Get the index of the format object, if any.
-        let xf_index = match format {
-            Some(format) => self.format_xf_index(format),
-            None => self.row_col_format(first_row, first_col),
-        };
+        let xf_index = self.cell_format_index(first_row, first_col, format);
 
         // Create the array range reference.
         let range = utility::cell_range(first_row, first_col, last_row, last_col);
@@ -15298,7 +18554,7 @@ impl Worksheet {
         }
 
         // Get the index of the format object.
-        let mut xf_index = self.format_xf_index(format);
+        let mut xf_index = self.cell_format_index(row, col, Some(format));
 
         // If the cell has the default format we need to check if it should have
         // a row/col intersection format.
@@ -15328,10 +18584,7 @@ impl Worksheet {
         }
 
         // Get the index of the format object, if any.
-        let xf_index = match format {
-            Some(format) => self.format_xf_index(format),
-            None => self.row_col_format(row, col),
-        };
+        let xf_index = self.cell_format_index(row, col, format);
 
         // Create the appropriate cell type to hold the data.
         let cell = CellType::Boolean { boolean, xf_index };
@@ -15341,6 +18594,34 @@ impl Worksheet {
         Ok(self)
     }
 
+    // Store an Excel error value such as #N/A.
+    fn store_error(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        error: ExcelError,
+        format: Option<&Format>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Get the index of the format object, if any.
+        let xf_index = self.cell_format_index(row, col, format);
+
+        // Create the appropriate cell type to hold the data.
+        let cell = CellType::Error {
+            error,
+            xf_index,
+            image_index: None,
+        };
+
+        self.insert_cell(row, col, cell);
+
+        Ok(self)
+    }
+
     // Store a url and associated properties. Urls in Excel are stored in a
     // number of places: they are written as a string similar to
     // write_string_with_format(), they are written in the <hyperlinks> element
@@ -15368,6 +18649,47 @@ impl Worksheet {
         Ok(self)
     }
 
+    // Store a conditional format rule for a range, or a space separated list
+    // of ranges, of cells.
+    fn store_conditional_format(
+        &mut self,
+        cell_range: String,
+        mut conditional_format: Box<dyn ConditionalFormat + Sync + Send>,
+    ) {
+        // Check for extended Excel 2010 data bars/icons.
+        if conditional_format.has_x14_extensions() {
+            self.use_x14_extensions = true;
+            self.has_x14_conditional_formats = true;
+        }
+
+        // Only write standard cond formats for non-x14 icons.
+        if !conditional_format.has_x14_only() {
+            self.has_conditional_formats = true;
+        }
+
+        // Set the dxf format local index if required.
+        if let Some(format) = conditional_format.format_as_mut() {
+            format.dxf_index = self.format_dxf_index(format);
+        }
+
+        match self.conditional_formats.entry(cell_range.clone()) {
+            Entry::Occupied(mut entry) => {
+                // The conditional format range already exists. Append the rule.
+                let rules = entry.get_mut();
+                rules.push(conditional_format);
+            }
+            Entry::Vacant(entry) => {
+                // The row doesn't exist, create a new row with columns and insert
+                // the cell value.
+                let rules = vec![conditional_format];
+                entry.insert(rules);
+
+                // Also store the order that the ranges are added in.
+                self.conditional_format_order.push(cell_range);
+            }
+        }
+    }
+
     // Store a reference to an embedded cell image.
     fn store_embedded_image(
         &mut self,
@@ -15389,23 +18711,7 @@ impl Worksheet {
             )));
         }
 
-        let image_id = match self.embedded_image_ids.get(&image.hash) {
-            Some(image_id) => *image_id,
-            None => {
-                let image_id = self.embedded_image_ids.len() as u32;
-                self.embedded_image_ids.insert(image.hash.clone(), image_id);
-                self.embedded_images.push(image.clone());
-                image_id
-            }
-        };
-
-        // Check for alt text in the image.
-        if !image.alt_text.is_empty() {
-            self.has_embedded_image_descriptions = true;
-        }
-
-        // Store the used image type for the Content Type file.
-        self.image_types[image.image_type.clone() as usize] = true;
+        let image_id = self.store_embedded_image_id(image);
 
         // Store the image hyperlink, if any.
         if let Some(url) = &image.url {
@@ -15426,8 +18732,9 @@ impl Worksheet {
 
         // Create the appropriate cell type to hold the data.
         let cell = CellType::Error {
+            error: ExcelError::Value,
             xf_index,
-            value: image_id,
+            image_index: Some(image_id),
         };
 
         // Store the cell error value.
@@ -15436,11 +18743,35 @@ impl Worksheet {
         Ok(self)
     }
 
+    // Store an embedded image, if it hasn't already been stored, and return its
+    // index.
+    fn store_embedded_image_id(&mut self, image: &Image) -> u32 {
+        let image_id = match self.embedded_image_ids.get(&image.hash) {
+            Some(image_id) => *image_id,
+            None => {
+                let image_id = self.embedded_image_ids.len() as u32;
+                self.embedded_image_ids.insert(image.hash.clone(), image_id);
+                self.embedded_images.push(image.clone());
+                image_id
+            }
+        };
+
+        // Check for alt text in the image.
+        if !image.alt_text.is_empty() {
+            self.has_embedded_image_descriptions = true;
+        }
+
+        // Store the used image type for the Content Type file.
+        self.image_types[image.image_type.clone() as usize] = true;
+
+        image_id
+    }
+
     // A rich string is handled in Excel like any other shared string except
     // that it has inline font markup within the string. To generate the
     // required font xml we use an instance of the Style struct.
     #[allow(clippy::similar_names)]
-    fn get_rich_string(segments: &[(&Format, &str)]) -> Result<(String, String), XlsxError> {
+    fn get_rich_string(&self, segments: &[(&Format, &str)]) -> Result<(String, String), XlsxError> {
         // Check that there is at least one segment tuple.
         if segments.is_empty() {
             let error = "Rich string must contain at least 1 (&Format, &str) tuple.";
@@ -15461,6 +18792,8 @@ impl Worksheet {
             false,
             true,
         );
+        styler.minor_font.clone_from(&self.theme_font.name);
+        styler.major_font.clone_from(&self.theme_font.major_name);
         let mut raw_string = String::new();
 
         let mut first_segment = true;
@@ -15474,34 +18807,119 @@ impl Worksheet {
             // Accumulate the string segments into a unformatted string.
             raw_string.push_str(string);
 
-            let whitespace = ['\t', '\n', ' '];
-            let attributes = if string.starts_with(whitespace) || string.ends_with(whitespace) {
-                vec![("xml:space", "preserve")]
-            } else {
-                vec![]
-            };
+            let whitespace = ['\t', '\n', ' '];
+            let attributes = if string.starts_with(whitespace) || string.ends_with(whitespace) {
+                vec![("xml:space", "preserve")]
+            } else {
+                vec![]
+            };
+
+            // First segment doesn't require a font run for the default format.
+            if format.is_default() && first_segment {
+                xml_start_tag_only(&mut styler.writer, "r");
+                xml_data_element(&mut styler.writer, "t", string, &attributes);
+                xml_end_tag(&mut styler.writer, "r");
+            } else {
+                xml_start_tag_only(&mut styler.writer, "r");
+                styler.write_font(&format.font, false);
+                xml_data_element(&mut styler.writer, "t", string, &attributes);
+                xml_end_tag(&mut styler.writer, "r");
+            }
+            first_segment = false;
+        }
+
+        let got = xmlwriter::cursor_to_string(&styler.writer);
+
+        Ok((got, raw_string))
+    }
+
+    // Get the string and phonetic run xml for a phonetic string, and the
+    // unformatted string, in the same way as a rich string.
+    fn get_phonetic_string(
+        &self,
+        phonetic: &PhoneticString,
+    ) -> Result<(String, String), XlsxError> {
+        phonetic.validate()?;
+
+        let (mut string, raw_string) = if phonetic.is_rich {
+            let segments: Vec<(&Format, &str)> = phonetic
+                .segments
+                .iter()
+                .map(|(format, text)| (format, text.as_str()))
+                .collect();
+
+            self.get_rich_string(&segments)?
+        } else {
+            let raw_string = phonetic.text();
+            if raw_string.is_empty() {
+                return Ok((String::new(), raw_string));
+            }
+
+            let whitespace = ['\t', '\n', ' '];
+            let attributes =
+                if raw_string.starts_with(whitespace) || raw_string.ends_with(whitespace) {
+                    vec![("xml:space", "preserve")]
+                } else {
+                    vec![]
+                };
+
+            let mut writer = Cursor::new(Vec::with_capacity(128));
+            xml_data_element(&mut writer, "t", &raw_string, &attributes);
+
+            (xmlwriter::cursor_to_string(&writer), raw_string)
+        };
+
+        string.push_str(&phonetic.runs_xml());
+
+        Ok((string, raw_string))
+    }
+
+    // Get the <phoneticPr> element that is added to phonetic strings.
+    fn phonetic_properties_xml(&self) -> String {
+        let phonetic_properties = self.phonetic_properties.clone().unwrap_or_default();
+        let mut writer = Cursor::new(Vec::with_capacity(64));
+
+        xml_empty_tag(
+            &mut writer,
+            "phoneticPr",
+            &phonetic_properties.attributes(self.phonetic_font_id),
+        );
+
+        xmlwriter::cursor_to_string(&writer)
+    }
 
-            // First segment doesn't require a font run for the default format.
-            if format.is_default() && first_segment {
-                xml_start_tag_only(&mut styler.writer, "r");
-                xml_data_element(&mut styler.writer, "t", string, &attributes);
-                xml_end_tag(&mut styler.writer, "r");
-            } else {
-                xml_start_tag_only(&mut styler.writer, "r");
-                styler.write_font(&format.font, false);
-                xml_data_element(&mut styler.writer, "t", string, &attributes);
-                xml_end_tag(&mut styler.writer, "r");
-            }
-            first_segment = false;
+    // Get a cell from the worksheet data table structure, or from the write
+    // ahead buffer in constant memory mode.
+    fn get_cell(&self, row: RowNum, col: ColNum) -> Option<&CellType> {
+        if let Some(cell) = self
+            .data_table
+            .get(&row)
+            .and_then(|columns| columns.get(&col))
+        {
+            return Some(cell);
         }
 
-        let got = xmlwriter::cursor_to_string(&styler.writer);
+        #[cfg(feature = "constant_memory")]
+        if let Some(cell) = self
+            .write_ahead
+            .get(&row)
+            .and_then(|columns| columns.get(&col))
+        {
+            return Some(cell);
+        }
 
-        Ok((got, raw_string))
+        None
     }
 
     // Insert a cell value into the worksheet data table structure.
     fn insert_cell(&mut self, row: RowNum, col: ColNum, cell: CellType) {
+        // The #SPILL! and #CALC! errors are stored as rich values.
+        if let CellType::Error { error, .. } = &cell {
+            if error.value_metadata_id().is_some() {
+                self.has_rich_value_errors = true;
+            }
+        }
+
         if self.use_constant_memory {
             #[cfg(feature = "constant_memory")]
             {
@@ -15606,6 +19024,63 @@ impl Worksheet {
         }
     }
 
+    // Store a range format for cascading mode and apply the layered format to
+    // new or existing cells in the range. Each cell stores the index of the
+    // combined range formats that apply to it. The combinations are resolved
+    // once for each range since most cells in a range share the same previous
+    // range formats.
+    fn set_cascading_range_format(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let range_index = self.cascading_layer_index(format);
+        let mut combined_indices: HashMap<u32, u32> = HashMap::new();
+
+        for row in first_row..=last_row {
+            for col in first_col..=last_col {
+                // Later range formats take precedence over earlier ones.
+                let layer_index = match self.cascading_range_formats.get(&(row, col)) {
+                    Some(&previous) => match combined_indices.get(&previous) {
+                        Some(&index) => index,
+                        None => {
+                            let combined = format.merge(&self.cascading_layers[previous as usize]);
+                            let index = self.cascading_layer_index(&combined);
+                            combined_indices.insert(previous, index);
+                            index
+                        }
+                    },
+                    None => range_index,
+                };
+
+                self.cascading_range_formats.insert((row, col), layer_index);
+
+                let xf_index = self.cascading_format(row, col);
+                self.insert_cell_format(row, col, xf_index);
+            }
+        }
+
+        Ok(self)
+    }
+
+    // Get the index of a cell or range format in the table of cascading
+    // format layers. These are stored separately from the cell formats since
+    // they aren't written to the file.
+    fn cascading_layer_index(&mut self, format: &Format) -> u32 {
+        if let Some(index) = self.cascading_layer_indices.get(format) {
+            return *index;
+        }
+
+        let index = self.cascading_layers.len() as u32;
+        self.cascading_layers.push(format.clone());
+        self.cascading_layer_indices.insert(format.clone(), index);
+
+        index
+    }
+
     // Update the format index in a worksheet cell. This function ignores
     // non-existing cells (unlike the previous function).
     fn update_cell_format(&mut self, row: RowNum, col: ColNum, format_id: u32) {
@@ -16030,6 +19505,23 @@ impl Worksheet {
         }
     }
 
+    // Get the Format for a cell format index, or None for the default format.
+    fn xf_format(&self, xf_index: u32) -> Option<Format> {
+        if xf_index == 0 {
+            return None;
+        }
+
+        if self.has_workbook_global_xfs {
+            let xf_indices = self.workbook_xf_indices.read().expect("RwLock poisoned");
+            xf_indices
+                .iter()
+                .find(|(_, index)| **index == xf_index)
+                .map(|(format, _)| format.clone())
+        } else {
+            self.xf_formats.get(xf_index as usize).cloned()
+        }
+    }
+
     // Get the remapped local to global format index.
     fn get_global_xf_index(&self, xf_index: u32) -> u32 {
         if self.has_workbook_global_xfs {
@@ -16073,6 +19565,80 @@ impl Worksheet {
         self.format_xf_index(&combined_format)
     }
 
+    // Get the format index for a cell that is being written. In the default
+    // mode this is the index of the cell format, or the row/col intersection
+    // format for unformatted cells. In cascading mode the cell format is also
+    // stored so that it can be layered with any range, row or column formats.
+    fn cell_format_index(&mut self, row: RowNum, col: ColNum, format: Option<&Format>) -> u32 {
+        if self.cascading_formats {
+            match format {
+                Some(format) => {
+                    let layer_index = self.cascading_layer_index(format);
+                    self.cascading_cell_formats.insert((row, col), layer_index);
+                }
+                None => {
+                    self.cascading_cell_formats.remove(&(row, col));
+                }
+            }
+
+            return self.cascading_format(row, col);
+        }
+
+        match format {
+            Some(format) => self.format_xf_index(format),
+            None => self.row_col_format(row, col),
+        }
+    }
+
+    // Build the effective format for a cell in cascading mode by layering the
+    // cell, range, row and column formats, in that order of precedence.
+    fn cascading_format(&mut self, row: RowNum, col: ColNum) -> u32 {
+        let mut format = self
+            .cascading_cell_formats
+            .get(&(row, col))
+            .map(|index| self.cascading_layers[*index as usize].clone())
+            .unwrap_or_default();
+
+        if let Some(index) = self.cascading_range_formats.get(&(row, col)) {
+            format = format.merge(&self.cascading_layers[*index as usize]);
+        }
+
+        if let Some(row_format) = self.changed_rows.get(&row).and_then(|r| r.format.as_ref()) {
+            format = format.merge(row_format);
+        }
+
+        if let Some(col_format) = self.changed_cols.get(&col).and_then(|c| c.format.as_ref()) {
+            format = format.merge(col_format);
+        }
+
+        self.format_xf_index(&format)
+    }
+
+    // Recalculate the cascading format of existing cells in a range after a
+    // row, column or range format has changed.
+    fn update_cascading_formats(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) {
+        let cells: Vec<(RowNum, ColNum)> = self
+            .data_table
+            .range(first_row..=last_row)
+            .flat_map(|(row, columns)| {
+                columns
+                    .range(first_col..=last_col)
+                    .map(move |(col, _)| (*row, *col))
+            })
+            .collect();
+
+        for (row, col) in cells {
+            let xf_index = self.cascading_format(row, col);
+            self.update_cell_format(row, col, xf_index);
+        }
+    }
+
     /// Get the local instance DXF id for a format.
     ///
     /// Get the local instance DXF id for a format. These indexes will be
@@ -16111,6 +19677,41 @@ impl Worksheet {
         self.global_xf_indices = workbook_xf_indices.to_vec();
     }
 
+    // Set the workbook font id used by the phonetic properties, from the font
+    // of the global format that the phonetic font format maps to.
+    pub(crate) fn set_phonetic_font_id(&mut self, xf_formats: &[Format]) {
+        self.phonetic_font_id = match self.phonetic_font_xf_index {
+            Some(xf_index) => {
+                let xf_index = self.get_global_xf_index(xf_index) as usize;
+                xf_formats[xf_index].font_index
+            }
+            None => 0,
+        };
+    }
+
+    // Get the workbook font id of a global format. Font ids are assigned in
+    // the order that unique fonts occur in the workbook formats, and since
+    // format indices are only ever appended, the id of an existing format
+    // doesn't change when the workbook is saved.
+    fn global_font_id(&self, xf_index: u32) -> u16 {
+        let xf_indices = self.workbook_xf_indices.read().expect("RwLock poisoned");
+
+        let mut formats: Vec<(&Format, &u32)> = xf_indices
+            .iter()
+            .filter(|(_, index)| **index <= xf_index)
+            .collect();
+        formats.sort_by_key(|(_, index)| **index);
+
+        let mut font_ids: HashMap<&Font, u16> = HashMap::new();
+        let mut font_id = 0;
+        for (format, _) in formats {
+            let font_count = font_ids.len() as u16;
+            font_id = *font_ids.entry(&format.font).or_insert(font_count);
+        }
+
+        font_id
+    }
+
     // Set the mapping between the local format indices and the global/workbook
     // indices for cell formats.
     pub(crate) fn set_global_dxf_indices(&mut self, workbook_dxf_indices: &[u32]) {
@@ -16328,7 +19929,12 @@ impl Worksheet {
         image_id: &mut u32,
     ) {
         let mut rel_ids: HashMap<String, u32> = HashMap::new();
-        for image in self.header_footer_images.clone().into_iter().flatten() {
+        let images = self.header_footer_images.clone();
+        for (index, image) in images.into_iter().enumerate() {
+            let Some(image) = image else {
+                continue;
+            };
+
             let image_id = match image_ids.get(&image.hash) {
                 Some(image_id) => *image_id,
                 None => {
@@ -16362,6 +19968,14 @@ impl Worksheet {
             let mut vml_info = image.vml_info();
             vml_info.rel_id = rel_id;
 
+            // Excel identifies the even and first page images with a suffix
+            // on the position id, like "CHFIRST".
+            match index / 6 {
+                1 => vml_info.header_position.push_str("EVEN"),
+                2 => vml_info.header_position.push_str("FIRST"),
+                _ => {}
+            }
+
             // Store the header/footer vml data.
             self.header_footer_vml_info.push(vml_info);
 
@@ -16370,6 +19984,79 @@ impl Worksheet {
         }
     }
 
+    // Set up embedded OLE objects. These are stored in the worksheet VML file
+    // along with an image used to display them and they are also referenced
+    // from the worksheet <oleObjects> element.
+    pub(crate) fn prepare_ole_objects(
+        &mut self,
+        image_ids: &mut HashMap<String, u32>,
+        image_id: &mut u32,
+        ole_object_id: u32,
+    ) {
+        let mut ole_object_id = ole_object_id;
+        let mut rel_ids: HashMap<String, u32> = HashMap::new();
+
+        // Take the objects out of the worksheet to avoid copying the embedded
+        // data while the other worksheet fields are updated.
+        let ole_objects = std::mem::take(&mut self.ole_objects);
+
+        for ((row, col, _, _), object) in &ole_objects {
+            let Some(image) = &object.image else {
+                continue;
+            };
+
+            let image_id = match image_ids.get(&image.hash) {
+                Some(image_id) => *image_id,
+                None => {
+                    *image_id += 1;
+                    image_ids.insert(image.hash.clone(), *image_id);
+                    *image_id
+                }
+            };
+
+            let image_name = format!("../media/image{image_id}.{}", image.image_type.extension());
+
+            // Store the linkage to the vmlDrawing rels file.
+            let rel_id = match rel_ids.get(&image.hash) {
+                Some(rel_id) => *rel_id,
+                None => {
+                    let rel_id = 1 + rel_ids.len() as u32;
+                    rel_ids.insert(image.hash.clone(), rel_id);
+
+                    self.vml_object_relationships.push((
+                        "image".to_string(),
+                        image_name.clone(),
+                        String::new(),
+                    ));
+
+                    rel_id
+                }
+            };
+
+            // Store the linkages to the worksheet rels file.
+            self.ole_object_relationships.push((
+                "oleObject".to_string(),
+                format!("../embeddings/oleObject{ole_object_id}.bin"),
+                String::new(),
+            ));
+            self.ole_object_relationships
+                .push(("image".to_string(), image_name, String::new()));
+
+            let mut vml_info = object.vml_info();
+            vml_info.rel_id = rel_id;
+            vml_info.drawing_info = self.position_object_pixels(*row, *col, object);
+
+            self.ole_objects_vml_info.push(vml_info);
+
+            // Store the used image type for the Content Type file.
+            self.image_types[image.image_type.clone() as usize] = true;
+
+            ole_object_id += 1;
+        }
+
+        self.ole_objects = ole_objects;
+    }
+
     // Store the vmlDrawingN.xml file linkage to the worksheets rels file.
     pub(crate) fn add_vml_drawing_rel_link(&mut self, drawing_id: u32) {
         let vml_drawing_name = format!("../drawings/vmlDrawing{drawing_id}.vml");
@@ -16380,7 +20067,8 @@ impl Worksheet {
         ));
     }
 
-    // Convert buttons into VML objects.
+    // Convert notes and buttons into VML objects and return the number of VML
+    // shapes in the worksheet, including the OLE objects.
     pub(crate) fn prepare_vml_objects(&mut self, vml_data_id: u32, vml_shape_id: u32) -> u32 {
         let mut button_id = 1;
         let mut note_count = 0;
@@ -16452,11 +20140,14 @@ impl Worksheet {
             button_id += 1;
         }
 
+        // The VML shapes are the notes, buttons and OLE objects.
+        let shape_count = note_count + self.buttons.len() as u32 + self.ole_objects.len() as u32;
+
         // The VML o:idmap data id contains a comma separated range when there
-        // is more than one 1024 block of comments, like this: data="1,2".
+        // is more than one 1024 block of shapes, like this: data="1,2".
         let mut oid_map = vml_data_id.to_string();
 
-        for i in 0..note_count / 1024 {
+        for i in 0..shape_count / 1024 {
             let next_id = vml_data_id + i + 1;
             oid_map = format!("{oid_map},{next_id}");
         }
@@ -16464,7 +20155,7 @@ impl Worksheet {
         self.vml_data_id = oid_map;
         self.vml_shape_id = vml_shape_id;
 
-        note_count
+        shape_count
     }
 
     // Store the commentN.xml file linkage to the worksheets rels file.
@@ -16623,11 +20314,18 @@ impl Worksheet {
     where
         T: DrawingObject,
     {
+        // Objects with an absolute position are positioned from the top left
+        // cell of the worksheet instead of the cell they were inserted in.
+        let (row, col, x_offset, y_offset) = match object.absolute_position() {
+            Some((x, y)) => (0, 0, x, y),
+            None => (row, col, object.x_offset(), object.y_offset()),
+        };
+
         let mut row_start: RowNum = row; // Row containing top left corner.
         let mut col_start: ColNum = col; // Column containing upper left corner.
 
-        let mut x1: u32 = object.x_offset(); // Distance to left side of object.
-        let mut y1: u32 = object.y_offset(); // Distance to top of object.
+        let mut x1: u32 = x_offset; // Distance to left side of object.
+        let mut y1: u32 = y_offset; // Distance to top of object.
 
         let mut row_end: RowNum; // Row containing bottom right corner.
         let mut col_end: ColNum; // Column containing lower right corner.
@@ -16741,46 +20439,324 @@ impl Worksheet {
     // Convert the width of a cell from character units to pixels. Excel rounds
     // the column width to the nearest pixel.
     fn column_pixel_width(&mut self, col: ColNum, position: ObjectMovement) -> u32 {
-        let max_digit_width = 7.0_f64;
-        let padding = 5.0_f64;
-
         match self.changed_cols.get(&col) {
             Some(col_options) => {
-                let pixel_width = col_options.width;
                 let hidden = col_options.hidden;
 
                 if hidden && position != ObjectMovement::MoveAndSizeWithCellsAfter {
                     // A hidden column is treated as having a width of zero unless
                     // the "object_movement" is MoveAndSizeWithCellsAfter.
                     0u32
-                } else if pixel_width < 1.0 {
-                    (pixel_width * (max_digit_width + padding)).round() as u32
                 } else {
-                    (pixel_width * max_digit_width).round() as u32 + padding as u32
+                    self.theme_font.width_to_pixels(col_options.width)
                 }
             }
             // If the width hasn't been set we use the default value.
-            None => 64,
+            None => self.theme_font.width_to_pixels(DEFAULT_COL_WIDTH),
+        }
+    }
+
+    // Convert the height of a cell from character units to pixels. If the
+    // height hasn't been set by the user we use the default value.
+    fn row_pixel_height(&mut self, row: RowNum, position: ObjectMovement) -> u32 {
+        match self.changed_rows.get(&row) {
+            Some(row_options) => {
+                let hidden = row_options.hidden;
+
+                if hidden && position != ObjectMovement::MoveAndSizeWithCellsAfter {
+                    // A hidden row is treated as having a height of zero unless
+                    // the "object_movement" is MoveAndSizeWithCellsAfter.
+                    0u32
+                } else {
+                    (row_options.height * 4.0 / 3.0) as u32
+                }
+            }
+            None => (self.user_default_row_height * 4.0 / 3.0) as u32,
+        }
+    }
+
+    // Change the sheet name in the formula references to another worksheet,
+    // or replace it with #REF! if the worksheet has been removed.
+    pub(crate) fn rename_sheet_references(&mut self, old_name: &str, new_name: Option<&str>) {
+        let rename = |formula: &str| shift::rename_sheet_references(formula, old_name, new_name);
+
+        for columns in self.data_table.values_mut() {
+            for cell in columns.values_mut() {
+                if let CellType::Formula { formula, .. } | CellType::ArrayFormula { formula, .. } =
+                    cell
+                {
+                    *formula = rename(formula).into();
+                }
+            }
+        }
+
+        for table in &mut self.tables {
+            for column in &mut table.columns {
+                if let Some(formula) = column.formula.as_mut() {
+                    formula.formula_string = rename(&formula.formula_string);
+                }
+            }
+        }
+
+        for rules in self.conditional_formats.values_mut() {
+            for rule in rules {
+                rule.shift_formulas(&rename);
+            }
+        }
+
+        for data_validation in self.data_validations.values_mut() {
+            data_validation.shift_formulas(&rename);
+        }
+
+        for hyperlink in self.hyperlinks.values_mut() {
+            if hyperlink.link_type == HyperlinkType::Internal {
+                hyperlink.anchor = rename(&hyperlink.anchor);
+            }
+        }
+
+        // Sparklines with data in a removed worksheet are removed, like Excel.
+        for sparkline in &mut self.sparklines {
+            for (_, data_range) in &mut sparkline.ranges {
+                *data_range = rename(data_range);
+            }
+            sparkline
+                .ranges
+                .retain(|(_, data_range)| !data_range.contains("#REF!"));
+        }
+        self.sparklines
+            .retain(|sparkline| !sparkline.ranges.is_empty());
+        self.has_sparklines = !self.sparklines.is_empty();
+    }
+
+    // Get the 3D references, such as `Sheet1:Sheet3!A1`, in the formulas of the
+    // worksheet as the first and last worksheet names of the span. This is
+    // used to check that moving a worksheet doesn't change the worksheets that
+    // the references span. The formula rules are read via the shift methods
+    // with a function that returns the formula unchanged.
+    pub(crate) fn sheet_spans(&mut self) -> Vec<(String, String)> {
+        let spans = std::cell::RefCell::new(vec![]);
+        let read = |formula: &str| {
+            spans.borrow_mut().extend(shift::sheet_spans(formula));
+            formula.to_string()
+        };
+
+        for columns in self.data_table.values() {
+            for cell in columns.values() {
+                if let CellType::Formula { formula, .. } | CellType::ArrayFormula { formula, .. } =
+                    cell
+                {
+                    read(formula);
+                }
+            }
+        }
+
+        for table in &self.tables {
+            for column in &table.columns {
+                if let Some(formula) = &column.formula {
+                    read(&formula.formula_string);
+                }
+            }
+        }
+
+        for rules in self.conditional_formats.values_mut() {
+            for rule in rules {
+                rule.shift_formulas(&read);
+            }
+        }
+
+        for data_validation in self.data_validations.values_mut() {
+            data_validation.shift_formulas(&read);
         }
+
+        spans.into_inner()
     }
 
-    // Convert the height of a cell from character units to pixels. If the
-    // height hasn't been set by the user we use the default value.
-    fn row_pixel_height(&mut self, row: RowNum, position: ObjectMovement) -> u32 {
-        match self.changed_rows.get(&row) {
-            Some(row_options) => {
-                let hidden = row_options.hidden;
+    // Create a copy of the worksheet for `Workbook::duplicate_worksheet()`.
+    // The worksheet data and settings are copied. The xml writers, the file
+    // relationship data and the state that depends on the worksheet position
+    // in the workbook are reset. The handles that are shared with the workbook
+    // are new and are set up by the workbook. All the fields are listed so
+    // that new fields have to be considered here.
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn duplicate(&self) -> Worksheet {
+        let mut worksheet = Worksheet {
+            writer: Cursor::new(Vec::with_capacity(2048)),
+            name: self.name.clone(),
+            sheet_index: 0,
+            active: false,
+            selected: false,
+            visible: self.visible,
+            first_sheet: false,
+            has_local_string_table: self.has_local_string_table,
+            has_dynamic_arrays: self.has_dynamic_arrays,
+            has_rich_value_errors: self.has_rich_value_errors,
+            print_area_defined_name: self.print_area_defined_name.clone(),
+            repeat_row_cols_defined_name: self.repeat_row_cols_defined_name.clone(),
+            autofilter_defined_name: self.autofilter_defined_name.clone(),
+            autofilter_area: self.autofilter_area.clone(),
+            xf_formats: self.xf_formats.clone(),
+            dxf_formats: self.dxf_formats.clone(),
+            has_vml: self.has_vml,
+            has_hyperlink_style: self.has_hyperlink_style,
+            images: self.images.clone(),
+            buttons_vml_info: vec![],
+            comments_vml_info: vec![],
+            ole_objects_vml_info: vec![],
+            drawing: Drawing::new(),
+            image_types: self.image_types,
+            header_footer_images: self.header_footer_images.clone(),
+            charts: self.charts.clone(),
+            buttons: self.buttons.clone(),
+            notes: self.notes.clone(),
+            shapes: self.shapes.clone(),
+            ole_objects: self.ole_objects.clone(),
+            tables: self.tables.clone(),
+            custom_views: self.custom_views.clone(),
+            has_embedded_image_descriptions: self.has_embedded_image_descriptions,
+            embedded_images: self.embedded_images.clone(),
+            global_embedded_image_indices: vec![],
+            vba_codename: self.vba_codename.clone(),
+            reference_changes: vec![],
+            note_authors: self.note_authors.clone(),
+            vml_data_id: String::new(),
+            vml_shape_id: 0,
+            is_chartsheet: self.is_chartsheet,
+            use_constant_memory: self.use_constant_memory,
+            use_inline_strings: self.use_inline_strings,
+            current_row: 0,
+            workbook_xf_indices: Arc::new(RwLock::new(HashMap::new())),
+            string_table: Arc::new(Mutex::new(SharedStringsTable::new())),
+            has_workbook_global_xfs: false,
+            has_workbook_global_sst: false,
+            background_image: self.background_image.clone(),
+            drawing_rel_ids: HashMap::new(),
+            comment_relationships: vec![],
+            drawing_object_relationships: vec![],
+            drawing_relationships: vec![],
+            header_footer_vml_info: vec![],
+            hyperlink_relationships: vec![],
+            table_relationships: vec![],
+            vml_drawing_relationships: vec![],
+            background_relationships: vec![],
+            ole_object_relationships: vec![],
+            vml_object_relationships: vec![],
+            data_table: self.data_table.clone(),
+            is_writing_ahead: false,
+            merged_ranges: self.merged_ranges.clone(),
+            merged_cells: self.merged_cells.clone(),
+            table_ranges: self.table_ranges.clone(),
+            table_cells: self.table_cells.clone(),
+            dimensions: self.dimensions.clone(),
+            xf_indices: self.xf_indices.clone(),
+            dxf_indices: self.dxf_indices.clone(),
+            global_xf_indices: vec![],
+            global_dxf_indices: vec![],
+            changed_rows: self.changed_rows.clone(),
+            changed_cols: self.changed_cols.clone(),
+            page_setup_changed: self.page_setup_changed,
+            tab_color: self.tab_color,
+            fit_to_page: self.fit_to_page,
+            fit_width: self.fit_width,
+            fit_height: self.fit_height,
+            paper_size: self.paper_size,
+            default_page_order: self.default_page_order,
+            right_to_left: self.right_to_left,
+            is_portrait: self.is_portrait,
+            page_view: self.page_view,
+            zoom: self.zoom,
+            print_scale: self.print_scale,
+            print_options_changed: self.print_options_changed,
+            center_horizontally: self.center_horizontally,
+            center_vertically: self.center_vertically,
+            screen_gridlines: self.screen_gridlines,
+            screen_gridlines_color: self.screen_gridlines_color,
+            screen_headings: self.screen_headings,
+            show_formulas: self.show_formulas,
+            show_zero_values: self.show_zero_values,
+            show_outline_symbols: self.show_outline_symbols,
+            page_layout_ruler: self.page_layout_ruler,
+            print_gridlines: self.print_gridlines,
+            print_black_and_white: self.print_black_and_white,
+            print_draft: self.print_draft,
+            print_headings: self.print_headings,
+            header: self.header.clone(),
+            footer: self.footer.clone(),
+            header_even: self.header_even.clone(),
+            footer_even: self.footer_even.clone(),
+            header_first: self.header_first.clone(),
+            footer_first: self.footer_first.clone(),
+            head_footer_changed: self.head_footer_changed,
+            header_footer_scale_with_doc: self.header_footer_scale_with_doc,
+            header_footer_align_with_page: self.header_footer_align_with_page,
+            margin_left: self.margin_left,
+            margin_right: self.margin_right,
+            margin_top: self.margin_top,
+            margin_bottom: self.margin_bottom,
+            margin_header: self.margin_header,
+            margin_footer: self.margin_footer,
+            first_page_number: self.first_page_number,
+            default_result: self.default_result.clone(),
+            panes: self.panes.clone(),
+            hyperlinks: self.hyperlinks.clone(),
+            rel_count: 0,
+            protection_on: self.protection_on,
+            protection_hash: self.protection_hash,
+            protection_options: self.protection_options.clone(),
+            unprotected_ranges: self.unprotected_ranges.clone(),
+            selected_range: self.selected_range.clone(),
+            pane_selections: self.pane_selections.clone(),
+            top_left_cell: self.top_left_cell.clone(),
+            horizontal_breaks: self.horizontal_breaks.clone(),
+            vertical_breaks: self.vertical_breaks.clone(),
+            filter_conditions: self.filter_conditions.clone(),
+            filter_automatic_off: self.filter_automatic_off,
+            has_drawing_object_linkage: false,
+            cells_with_autofilter: self.cells_with_autofilter.clone(),
+            conditional_formats: self.conditional_formats.clone(),
+            conditional_format_order: self.conditional_format_order.clone(),
+            data_validations: self.data_validations.clone(),
+            has_conditional_formats: self.has_conditional_formats,
+            use_x14_extensions: self.use_x14_extensions,
+            has_x14_conditional_formats: self.has_x14_conditional_formats,
+            has_sparklines: self.has_sparklines,
+            sparklines: self.sparklines.clone(),
+            embedded_image_ids: self.embedded_image_ids.clone(),
+            show_all_notes: self.show_all_notes,
+            user_default_row_height: self.user_default_row_height,
+            font_metrics: self.font_metrics.clone(),
+            theme_font: self.theme_font.clone(),
+            cascading_formats: self.cascading_formats,
+            cascading_layers: self.cascading_layers.clone(),
+            cascading_layer_indices: self.cascading_layer_indices.clone(),
+            cascading_cell_formats: self.cascading_cell_formats.clone(),
+            cascading_range_formats: self.cascading_range_formats.clone(),
+            phonetic_properties: self.phonetic_properties.clone(),
+            phonetic_font_xf_index: self.phonetic_font_xf_index,
+            phonetic_font_id: self.phonetic_font_id,
+            has_phonetic_strings: self.has_phonetic_strings,
+            hide_unused_rows: self.hide_unused_rows,
+            has_sheet_data: self.has_sheet_data,
+            nan: self.nan.clone(),
+            infinity: self.infinity.clone(),
+            neg_infinity: self.neg_infinity.clone(),
+            ignored_errors: self.ignored_errors.clone(),
+            ignored_error_cells: self.ignored_error_cells.clone(),
+            max_outline_row_level: self.max_outline_row_level,
+            max_outline_col_level: self.max_outline_col_level,
+            outline_symbols_above: self.outline_symbols_above,
+            outline_symbols_left: self.outline_symbols_left,
+            #[cfg(feature = "constant_memory")]
+            file_writer: BufWriter::new(tempfile_in(std::env::temp_dir()).unwrap()),
+            #[cfg(feature = "constant_memory")]
+            write_ahead: BTreeMap::new(),
+            #[cfg(feature = "serde")]
+            serializer_state: SerializerState::new(),
+        };
+
+        // Reset the chart and table writers.
+        worksheet.reset();
 
-                if hidden && position != ObjectMovement::MoveAndSizeWithCellsAfter {
-                    // A hidden row is treated as having a height of zero unless
-                    // the "object_movement" is MoveAndSizeWithCellsAfter.
-                    0u32
-                } else {
-                    (row_options.height * 4.0 / 3.0) as u32
-                }
-            }
-            None => (self.user_default_row_height * 4.0 / 3.0) as u32,
-        }
+        worksheet
     }
 
     // Reset an worksheet global data or structures between saves.
@@ -16807,6 +20783,9 @@ impl Worksheet {
         self.table_relationships.clear();
         self.vml_drawing_relationships.clear();
         self.background_relationships.clear();
+        self.ole_object_relationships.clear();
+        self.vml_object_relationships.clear();
+        self.ole_objects_vml_info.clear();
     }
 
     // Check if any external relationships are required.
@@ -16815,16 +20794,111 @@ impl Worksheet {
             || !self.drawing_object_relationships.is_empty()
             || !self.table_relationships.is_empty()
             || !self.background_relationships.is_empty()
+            || !self.ole_object_relationships.is_empty()
     }
 
     // Check if there is a header image.
     pub(crate) fn has_header_footer_images(&self) -> bool {
-        self.header_footer_images[0].is_some()
-            || self.header_footer_images[1].is_some()
-            || self.header_footer_images[2].is_some()
-            || self.header_footer_images[3].is_some()
-            || self.header_footer_images[4].is_some()
-            || self.header_footer_images[5].is_some()
+        self.header_footer_images.iter().any(Option::is_some)
+    }
+
+    // Store a header/footer image after checking that there is a matching
+    // &[Picture]/&[G] variable in the corresponding header/footer string. The
+    // images are stored in groups of 3 positions for the header and footer of
+    // the default, even and first pages.
+    fn store_header_footer_image(
+        &mut self,
+        image: &Image,
+        position: HeaderImagePosition,
+        page: HeaderFooterPage,
+        is_header: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let (string, caption) = match (page, is_header) {
+            (HeaderFooterPage::Default, true) => (&self.header, "header"),
+            (HeaderFooterPage::Default, false) => (&self.footer, "footer"),
+            (HeaderFooterPage::Even, true) => (&self.header_even, "even page header"),
+            (HeaderFooterPage::Even, false) => (&self.footer_even, "even page footer"),
+            (HeaderFooterPage::First, true) => (&self.header_first, "first page header"),
+            (HeaderFooterPage::First, false) => (&self.footer_first, "first page footer"),
+        };
+
+        if !Self::verify_header_footer_image(string, &position) {
+            let error = format!(
+                "No &[Picture] or &[G] variable in {caption} string: '{string}' for position = '{position:?}'"
+            );
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        let mut index = page as usize * 6 + position.clone() as usize;
+        if !is_header {
+            index += 3;
+        }
+
+        let mut image = image.clone();
+        image.header_position = position;
+        image.is_header = is_header;
+        self.header_footer_images[index] = Some(image);
+
+        Ok(self)
+    }
+
+    // Check that a header/footer string, with the long form control
+    // characters expanded, is within Excel's limit of 255 characters.
+    fn verify_header_footer_length(string: &str, caption: &str) -> bool {
+        if Self::expand_header_footer(string).chars().count() > 255 {
+            eprintln!("{caption} string exceeds Excel's limit of 255 characters.");
+            return false;
+        }
+
+        true
+    }
+
+    // Expand the long form header/footer control characters like `&[Page]`
+    // into the short form used in the file.
+    pub(crate) fn expand_header_footer(string: &str) -> String {
+        const VARIABLES: [(&str, &str); 8] = [
+            ("&[Tab]", "&A"),
+            ("&[Date]", "&D"),
+            ("&[File]", "&F"),
+            ("&[Page]", "&P"),
+            ("&[Path]", "&Z"),
+            ("&[Time]", "&T"),
+            ("&[Pages]", "&N"),
+            ("&[Picture]", "&G"),
+        ];
+
+        let mut expanded = String::with_capacity(string.len());
+        let mut remaining = string;
+
+        while let Some(position) = remaining.find('&') {
+            expanded.push_str(&remaining[..position]);
+            remaining = &remaining[position..];
+
+            // Escaped "&&" ampersands are literal text and aren't the start of
+            // a variable.
+            if remaining.starts_with("&&") {
+                expanded.push_str("&&");
+                remaining = &remaining[2..];
+                continue;
+            }
+
+            match VARIABLES
+                .iter()
+                .find(|(variable, _)| remaining.starts_with(variable))
+            {
+                Some((variable, code)) => {
+                    expanded.push_str(code);
+                    remaining = &remaining[variable.len()..];
+                }
+                None => {
+                    expanded.push('&');
+                    remaining = &remaining[1..];
+                }
+            }
+        }
+
+        expanded.push_str(remaining);
+        expanded
     }
 
     // Check that there is a header/footer &[Picture] variable in the correct
@@ -16879,22 +20953,6 @@ impl Worksheet {
         }
     }
 
-    // Convert column pixel width to character width.
-    pub(crate) fn pixels_to_width(pixels: u16) -> f64 {
-        // Properties for Calibri 11.
-        let max_digit_width = 7.0_f64;
-        let padding = 5.0_f64;
-        let mut width = f64::from(pixels);
-
-        if width < 12.0 {
-            width /= max_digit_width + padding;
-        } else {
-            width = (width - padding) / max_digit_width;
-        }
-
-        width
-    }
-
     // Return a range of data from a worksheet to use as cache data in a chart
     // file. If the range doesn't contain string/number data then we return a
     // default struct with an empty cache.
@@ -16930,6 +20988,11 @@ impl Worksheet {
                                     }
                                     data.push(number.to_string());
                                 }
+                                CellType::Error {
+                                    error,
+                                    image_index: None,
+                                    ..
+                                } => data.push(error.to_string()),
 
                                 _ => data.push(String::new()),
                             },
@@ -16993,6 +21056,7 @@ impl Worksheet {
     // or to a user defined value.
     fn autofit_worksheet(&mut self, max_autofit_width: u16) -> &mut Worksheet {
         let mut max_widths: HashMap<ColNum, u16> = HashMap::new();
+        let mut autofit_fonts: HashMap<u32, AutofitFont> = HashMap::new();
 
         let (first_row, last_row) = if self.use_constant_memory {
             (self.current_row, self.current_row)
@@ -17006,62 +21070,39 @@ impl Worksheet {
             if let Some(columns) = self.data_table.get(&row_num) {
                 for col_num in self.dimensions.first_col..=self.dimensions.last_col {
                     if let Some(cell) = columns.get(&col_num) {
-                        let mut pixel_width = match cell {
-                            // For strings we do a calculation based on
-                            // character widths taken from Excel. For rich
-                            // strings we use the unformatted string. We also
-                            // split multi-line strings and handle each part
-                            // separately.
-                            CellType::String { string, .. }
-                            | CellType::InlineString { string, .. }
-                            | CellType::RichString {
-                                raw_string: string, ..
-                            } => {
-                                let mut max = 0;
-                                for segment in string.lines() {
-                                    let length = utility::pixel_width(segment);
-                                    max = cmp::max(max, length);
-                                }
-                                max
-                            }
+                        // Ignore merged cells, like Excel.
+                        if self.merged_cells.contains_key(&(row_num, col_num)) {
+                            continue;
+                        }
 
-                            // For numbers we use a workaround/optimization
-                            // since digits all have a pixel width of 7. This
-                            // gives a slightly greater width for the decimal
-                            // place and minus sign but only by a few pixels and
-                            // over-estimation is okay.
-                            CellType::Number { number, .. } => 7 * number.to_string().len() as u16,
-
-                            // For Boolean types we use the Excel standard
-                            // widths for TRUE and FALSE.
-                            CellType::Boolean { boolean, .. } => {
-                                if *boolean {
-                                    31
-                                } else {
-                                    36
-                                }
-                            }
+                        let xf_index = CellValue::cell_xf_index(cell);
+                        let font = autofit_fonts
+                            .entry(xf_index)
+                            .or_insert_with(|| self.autofit_font(xf_index));
 
-                            // For formulas we autofit the result of the formula
-                            // if it has a non-zero/default value.
-                            CellType::Formula { result, .. }
-                            | CellType::ArrayFormula { result, .. } => {
-                                if result.as_ref() == "0" || result.is_empty() {
-                                    0
-                                } else {
-                                    utility::pixel_width(result)
-                                }
-                            }
+                        // Get the text that Excel would display for the cell.
+                        let (text, is_general_number) = self.autofit_cell_text(cell, xf_index);
 
-                            // Datetimes are just numbers but they also have an
-                            // Excel format. It isn't feasible to parse the
-                            // number format to get the actual string width for
-                            // all format types so we use a width based on the
-                            // Excel's default format: mm/dd/yyyy.
-                            CellType::DateTime { .. } => 68,
+                        if text.is_empty() {
+                            continue;
+                        }
 
-                            // Ignore the following types which don't add to the width.
-                            CellType::Blank { .. } | CellType::Error { .. } => 0,
+                        let mut pixel_width = if !font.is_default {
+                            // Measure the text using the cell font metrics.
+                            font.cell_width(&text)
+                        } else if is_general_number {
+                            // For unformatted numbers we use a
+                            // workaround/optimization since digits all have a
+                            // pixel width of 7. This gives a slightly greater
+                            // width for the decimal place and minus sign but
+                            // only by a few pixels and over-estimation is okay.
+                            7 * text.len() as u16
+                        } else {
+                            // For strings in the default font we do a
+                            // calculation based on character widths taken from
+                            // Excel. We also split multi-line strings and
+                            // handle each part separately.
+                            text.lines().map(utility::pixel_width).max().unwrap_or(0)
                         };
 
                         // If the cell is in an autofilter header we add an
@@ -17109,13 +21150,170 @@ impl Worksheet {
 
         // Set the max character width for each column.
         for (col, pixels) in &max_widths {
-            let width = Self::pixels_to_width(*pixels);
+            let width = self.theme_font.pixels_to_width(*pixels);
             self.store_column_width(*col, width, true);
         }
 
         self
     }
 
+    // Get the text that Excel would display for a cell, for autofitting, and
+    // whether it is a number in the default General format.
+    fn autofit_cell_text<'a>(&self, cell: &'a CellType, xf_index: u32) -> (Cow<'a, str>, bool) {
+        let mut is_general_number = false;
+        let text: Cow<str> = match cell {
+            // For rich strings we use the unformatted string.
+            CellType::String { string, .. }
+            | CellType::InlineString { string, .. }
+            | CellType::RichString {
+                raw_string: string, ..
+            } => Cow::Borrowed(string),
+
+            // Numbers with a number format are formatted as
+            // Excel would display them.
+            CellType::Number { number, .. } => match self.autofit_num_format(xf_index) {
+                Some(num_format) => Cow::Owned(utility::format_number(*number, num_format)),
+                None => {
+                    is_general_number = true;
+                    Cow::Owned(number.to_string())
+                }
+            },
+
+            // For Boolean types we use the Excel standard
+            // widths for TRUE and FALSE.
+            CellType::Boolean { boolean, .. } => {
+                if *boolean {
+                    Cow::Borrowed("TRUE")
+                } else {
+                    Cow::Borrowed("FALSE")
+                }
+            }
+
+            // For formulas we autofit the result of the formula
+            // if it has a non-zero/default value.
+            CellType::Formula { result, .. } | CellType::ArrayFormula { result, .. } => {
+                if result.as_ref() == "0" {
+                    Cow::Borrowed("")
+                } else {
+                    Cow::Borrowed(result)
+                }
+            }
+
+            // Datetimes are just numbers with an Excel number
+            // format so we measure the formatted date. Excel's
+            // default locale date format, index 14, and dates
+            // with an unknown format use a width based on
+            // mm/dd/yyyy.
+            CellType::DateTime { number, .. } => match self.autofit_num_format(xf_index) {
+                Some(num_format) if self.xf_formats[xf_index as usize].num_format_index != 14 => {
+                    Cow::Owned(utility::format_number(*number, num_format))
+                }
+                _ => Cow::Borrowed("00/00/0000"),
+            },
+
+            // Error values are measured as their string value.
+            CellType::Error {
+                error,
+                image_index: None,
+                ..
+            } => Cow::Borrowed(error.as_str()),
+
+            // Ignore blank cells and embedded images, which are
+            // stored as error values, since they don't add to
+            // the width.
+            CellType::Blank { .. } | CellType::Error { .. } => Cow::Borrowed(""),
+        };
+
+        (text, is_general_number)
+    }
+
+    // Store the autofit height of rows that contain wrapped or rotated text.
+    // Rows with a user defined height are ignored.
+    fn store_autofit_row_heights(
+        &mut self,
+        cells: &[(RowNum, ColNum, String, u32)],
+        autofit_fonts: &HashMap<u32, AutofitFont>,
+    ) {
+        let mut row_heights: BTreeMap<RowNum, f64> = BTreeMap::new();
+
+        for (row, col, text, xf_index) in cells {
+            let Some(font) = autofit_fonts.get(xf_index) else {
+                continue;
+            };
+
+            // The text area is the column width less the cell padding.
+            let column_width =
+                self.column_pixel_width(*col, ObjectMovement::MoveAndSizeWithCellsAfter);
+            let column_width = f64::from(column_width).max(8.0) - 7.0;
+
+            let height = font.cell_height(text, column_width);
+            let max_height = row_heights.entry(*row).or_insert(0.0);
+            if height > *max_height {
+                *max_height = height;
+            }
+        }
+
+        for (row, pixels) in row_heights {
+            // Convert the height to points. Excel row heights are in whole
+            // pixels of 0.75 points.
+            let height = pixels.round() * 0.75;
+            if height <= self.user_default_row_height {
+                continue;
+            }
+
+            match self.changed_rows.get_mut(&row) {
+                Some(row_options) => {
+                    if row_options.autofit || row_options.height == self.user_default_row_height {
+                        row_options.height = height;
+                        row_options.autofit = true;
+                    }
+                }
+                None => {
+                    let row_options = RowOptions {
+                        height,
+                        xf_index: 0,
+                        hidden: false,
+                        level: 0,
+                        collapsed: false,
+                        format: None,
+                        autofit: true,
+                    };
+                    self.changed_rows.insert(row, row_options);
+                }
+            }
+        }
+    }
+
+    // Get the font properties of a cell format for autofitting. The worksheet
+    // formats aren't available when the worksheet uses the workbook's global
+    // format indices so the default font is used.
+    fn autofit_font(&self, xf_index: u32) -> AutofitFont {
+        let format = if self.has_workbook_global_xfs {
+            None
+        } else {
+            self.xf_formats.get(xf_index as usize)
+        };
+
+        AutofitFont::new(format, &self.font_metrics, &self.theme_font)
+    }
+
+    // Get the number format of a cell for autofitting, if it isn't the default
+    // General format. The worksheet formats aren't available when the worksheet
+    // uses the workbook's global format indices.
+    fn autofit_num_format(&self, xf_index: u32) -> Option<&str> {
+        if self.has_workbook_global_xfs {
+            return None;
+        }
+
+        let num_format = self.xf_formats.get(xf_index as usize)?.num_format.as_str();
+
+        if num_format.is_empty() || num_format.eq_ignore_ascii_case("General") {
+            None
+        } else {
+            Some(num_format)
+        }
+    }
+
     /// Set the row properties (outline level and hidden) for a range of grouped
     /// rows in an outline.
     fn set_grouped_rows(
@@ -17179,6 +21377,7 @@ impl Worksheet {
                     level: 1,
                     collapsed: false,
                     format: None,
+                    autofit: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -17206,6 +21405,7 @@ impl Worksheet {
                     level: 0,
                     collapsed: true,
                     format: None,
+                    autofit: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -17394,11 +21594,21 @@ impl Worksheet {
             self.write_auto_filter();
         }
 
+        // Write the customSheetViews element.
+        if !self.custom_views.is_empty() {
+            self.write_custom_sheet_views();
+        }
+
         // Write the mergeCells element.
         if !self.merged_ranges.is_empty() {
             self.write_merge_cells();
         }
 
+        // Write the phoneticPr element.
+        if self.has_phonetic_strings || self.phonetic_properties.is_some() {
+            self.write_phonetic_pr();
+        }
+
         // Write the conditionalFormatting element.
         if self.has_conditional_formats {
             self.write_conditional_formats();
@@ -17467,6 +21677,11 @@ impl Worksheet {
             self.write_picture();
         }
 
+        // Write the oleObjects element.
+        if !self.ole_objects_vml_info.is_empty() {
+            self.write_ole_objects();
+        }
+
         // Write the tableParts element.
         if !self.tables.is_empty() {
             self.write_table_parts();
@@ -17561,6 +21776,17 @@ impl Worksheet {
             attributes.push(("mc:Ignorable", "x14ac"));
         }
 
+        if !self.ole_objects.is_empty() {
+            attributes.push((
+                "xmlns:xdr",
+                "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing",
+            ));
+            attributes.push((
+                "xmlns:x14",
+                "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main",
+            ));
+        }
+
         xml_start_tag(&mut self.writer, "worksheet", &attributes);
     }
 
@@ -17710,10 +21936,22 @@ impl Worksheet {
     fn write_sheet_view(&mut self) {
         let mut attributes = vec![];
 
+        if self.show_formulas {
+            attributes.push(("showFormulas", "1".to_string()));
+        }
+
         if !self.screen_gridlines {
             attributes.push(("showGridLines", "0".to_string()));
         }
 
+        if !self.screen_headings {
+            attributes.push(("showRowColHeaders", "0".to_string()));
+        }
+
+        if !self.show_zero_values {
+            attributes.push(("showZeros", "0".to_string()));
+        }
+
         if self.right_to_left {
             attributes.push(("rightToLeft", "1".to_string()));
         }
@@ -17722,6 +21960,18 @@ impl Worksheet {
             attributes.push(("tabSelected", "1".to_string()));
         }
 
+        if !self.page_layout_ruler {
+            attributes.push(("showRuler", "0".to_string()));
+        }
+
+        if !self.show_outline_symbols {
+            attributes.push(("showOutlineSymbols", "0".to_string()));
+        }
+
+        if !self.screen_gridlines_color.is_auto_or_default() {
+            attributes.push(("defaultGridColor", "0".to_string()));
+        }
+
         match self.page_view {
             PageView::PageLayout => {
                 attributes.push(("view", "pageLayout".to_string()));
@@ -17736,6 +21986,13 @@ impl Worksheet {
             attributes.push(("topLeftCell", self.top_left_cell.clone()));
         }
 
+        if !self.screen_gridlines_color.is_auto_or_default() {
+            attributes.push((
+                "colorId",
+                self.screen_gridlines_color.palette_index().to_string(),
+            ));
+        }
+
         if self.zoom != 100 {
             attributes.push(("zoomScale", self.zoom.to_string()));
 
@@ -17784,34 +22041,39 @@ impl Worksheet {
             return;
         }
 
-        let row = self.panes.freeze_cell.0;
-        let col = self.panes.freeze_cell.1;
+        // Get the split position and the cell that the default pane
+        // selections are based on.
+        let (row, col, has_rows, has_cols) = if self.panes.is_split() {
+            let (row, col) = self.panes.split_top_cell();
+            let (height, width) = self.panes.split_size;
+            (row, col, height > 0, width > 0)
+        } else {
+            let (row, col) = self.panes.freeze_cell;
+            (row, col, row > 0, col > 0)
+        };
 
         // Write the pane and selection elements.
-        if row > 0 && col > 0 {
+        if has_rows && has_cols {
             self.write_pane("bottomRight");
-            self.write_selection(
-                "topRight",
-                &utility::row_col_to_cell(0, col),
-                &utility::row_col_to_cell(0, col),
-            );
-            self.write_selection(
-                "bottomLeft",
-                &utility::row_col_to_cell(row, 0),
-                &utility::row_col_to_cell(row, 0),
-            );
-            self.write_selection("bottomRight", "", "");
-        } else if col > 0 {
+            self.write_pane_selection(PanePosition::TopRight, &utility::row_col_to_cell(0, col));
+            self.write_pane_selection(PanePosition::BottomLeft, &utility::row_col_to_cell(row, 0));
+            self.write_pane_selection(PanePosition::BottomRight, "");
+        } else if has_cols {
             self.write_pane("topRight");
-            self.write_selection("topRight", "", "");
+            self.write_pane_selection(PanePosition::TopRight, "");
         } else {
             self.write_pane("bottomLeft");
-            self.write_selection("bottomLeft", "", "");
+            self.write_pane_selection(PanePosition::BottomLeft, "");
         }
     }
 
     // Write the <pane> element.
     fn write_pane(&mut self, active_pane: &str) {
+        if self.panes.is_split() {
+            self.write_split_pane(active_pane);
+            return;
+        }
+
         let row = self.panes.freeze_cell.0;
         let col = self.panes.freeze_cell.1;
         let mut attributes = vec![];
@@ -17831,6 +22093,40 @@ impl Worksheet {
         xml_empty_tag(&mut self.writer, "pane", &attributes);
     }
 
+    // Write the <pane> element for split panes. The split position is stored in
+    // twips (1/20 of a point) and includes the width of the column headers and
+    // the height of the row headers.
+    fn write_split_pane(&mut self, active_pane: &str) {
+        let (height, width) = self.panes.split_size;
+        let (row, col) = self.panes.split_top_cell();
+        let mut attributes = vec![];
+
+        if width > 0 {
+            attributes.push(("xSplit", (width * 15 + 390).to_string()));
+        }
+
+        if height > 0 {
+            attributes.push(("ySplit", (height * 15 + 300).to_string()));
+        }
+
+        attributes.push(("topLeftCell", utility::row_col_to_cell(row, col)));
+        attributes.push(("activePane", active_pane.to_string()));
+        attributes.push(("state", "split".to_string()));
+
+        xml_empty_tag(&mut self.writer, "pane", &attributes);
+    }
+
+    // Write the <selection> element for a pane. The user selection is used, if
+    // there is one, otherwise the default cell.
+    fn write_pane_selection(&mut self, pane: PanePosition, default_cell: &str) {
+        let (active_cell, range) = match self.pane_selections.get(&pane) {
+            Some(selection) => selection.clone(),
+            None => (default_cell.to_string(), default_cell.to_string()),
+        };
+
+        self.write_selection(&pane.to_string(), &active_cell, &range);
+    }
+
     // Write the <selection> element.
     fn write_selection(&mut self, position: &str, active_cell: &str, range: &str) {
         let mut attributes = vec![];
@@ -17898,6 +22194,14 @@ impl Worksheet {
         xml_empty_tag(&mut self.writer, "mergeCell", &attributes);
     }
 
+    // Write the <phoneticPr> element.
+    fn write_phonetic_pr(&mut self) {
+        let phonetic_properties = self.phonetic_properties.clone().unwrap_or_default();
+        let attributes = phonetic_properties.attributes(self.phonetic_font_id);
+
+        xml_empty_tag(&mut self.writer, "phoneticPr", &attributes);
+    }
+
     // Write the <hyperlinks> element.
     fn write_hyperlinks(&mut self) {
         xml_start_tag_only(&mut self.writer, "hyperlinks");
@@ -18242,7 +22546,136 @@ impl Worksheet {
             ("footer", self.margin_footer.to_string()),
         ];
 
-        xml_empty_tag(&mut self.writer, "pageMargins", &attributes);
+        xml_empty_tag(&mut self.writer, "pageMargins", &attributes);
+    }
+
+    // Write the <customSheetViews> element.
+    fn write_custom_sheet_views(&mut self) {
+        xml_start_tag_only(&mut self.writer, "customSheetViews");
+
+        for view in &self.custom_views.clone() {
+            self.write_custom_sheet_view(view);
+        }
+
+        xml_end_tag(&mut self.writer, "customSheetViews");
+    }
+
+    // Write the <customSheetView> element.
+    fn write_custom_sheet_view(&mut self, view: &CustomView) {
+        let mut attributes = vec![("guid", view.guid())];
+
+        if view.zoom != 100 {
+            attributes.push(("scale", view.zoom.to_string()));
+        }
+
+        if view.show_formulas {
+            attributes.push(("showFormulas", "1".to_string()));
+        }
+
+        if !view.screen_gridlines {
+            attributes.push(("showGridLines", "0".to_string()));
+        }
+
+        if !view.screen_headings {
+            attributes.push(("showRowCol", "0".to_string()));
+        }
+
+        if !view.show_outline_symbols {
+            attributes.push(("outlineSymbols", "0".to_string()));
+        }
+
+        if !view.show_zero_values {
+            attributes.push(("zeroValues", "0".to_string()));
+        }
+
+        if view.fit_to_pages.is_some() {
+            attributes.push(("fitToPage", "1".to_string()));
+        }
+
+        if view.hidden {
+            attributes.push(("state", "hidden".to_string()));
+        }
+
+        match view.page_view {
+            CustomViewType::PageLayout => {
+                attributes.push(("view", "pageLayout".to_string()));
+            }
+            CustomViewType::PageBreaks => {
+                attributes.push(("view", "pageBreakPreview".to_string()));
+            }
+            CustomViewType::Normal => {}
+        }
+
+        if !view.page_layout_ruler {
+            attributes.push(("showRuler", "0".to_string()));
+        }
+
+        if let Some((row, col)) = view.top_left_cell {
+            attributes.push(("topLeftCell", utility::row_col_to_cell(row, col)));
+        }
+
+        xml_start_tag(&mut self.writer, "customSheetView", &attributes);
+
+        self.write_page_margins();
+
+        if view.print_gridlines || view.print_headings {
+            let mut attributes = vec![];
+
+            if view.print_headings {
+                attributes.push(("headings", "1".to_string()));
+            }
+
+            if view.print_gridlines {
+                attributes.push(("gridLines", "1".to_string()));
+            }
+
+            xml_empty_tag(&mut self.writer, "printOptions", &attributes);
+        }
+
+        if view.has_page_setup() {
+            let mut attributes = vec![];
+
+            if view.paper_size > 0 {
+                attributes.push(("paperSize", view.paper_size.to_string()));
+            }
+
+            if view.print_scale != 100 {
+                attributes.push(("scale", view.print_scale.to_string()));
+            }
+
+            if let Some((width, height)) = view.fit_to_pages {
+                if width != 1 {
+                    attributes.push(("fitToWidth", width.to_string()));
+                }
+                if height != 1 {
+                    attributes.push(("fitToHeight", height.to_string()));
+                }
+            }
+
+            if view.is_portrait {
+                attributes.push(("orientation", "portrait".to_string()));
+            } else {
+                attributes.push(("orientation", "landscape".to_string()));
+            }
+
+            xml_empty_tag(&mut self.writer, "pageSetup", &attributes);
+        }
+
+        if !view.header.is_empty() || !view.footer.is_empty() {
+            xml_start_tag_only(&mut self.writer, "headerFooter");
+
+            if !view.header.is_empty() {
+                xml_data_element_only(&mut self.writer, "oddHeader", &view.header);
+            }
+
+            if !view.footer.is_empty() {
+                xml_data_element_only(&mut self.writer, "oddFooter", &view.footer);
+            }
+
+            xml_end_tag(&mut self.writer, "headerFooter");
+        }
+
+        xml_end_tag(&mut self.writer, "customSheetView");
     }
 
     // Write the <pageSetup> element.
@@ -18399,20 +22832,33 @@ impl Worksheet {
         }
 
         let mut string_table = string_table.lock().unwrap();
+        let phonetic_properties = self.phonetic_properties_xml();
 
         for columns in self.data_table.values_mut() {
             for cell in columns.values_mut() {
                 match cell {
                     CellType::String {
                         string, string_id, ..
+                    } if string_id.is_none() => {
+                        let string_index = string_table.shared_string_index(Arc::clone(string));
+                        *string_id = Some(string_index);
                     }
-                    | CellType::RichString {
-                        string, string_id, ..
-                    } => {
-                        if string_id.is_none() {
-                            let string_index = string_table.shared_string_index(Arc::clone(string));
-                            *string_id = Some(string_index);
-                        }
+                    CellType::RichString {
+                        string,
+                        string_id,
+                        phonetic,
+                        ..
+                    } if string_id.is_none() => {
+                        // Add the worksheet phonetic properties, which require
+                        // the workbook font id, to phonetic strings.
+                        let string = if phonetic.is_some() {
+                            Arc::from(format!("{string}{phonetic_properties}"))
+                        } else {
+                            Arc::clone(string)
+                        };
+
+                        let string_index = string_table.shared_xml_string_index(string);
+                        *string_id = Some(string_index);
                     }
                     _ => {}
                 }
@@ -18481,6 +22927,7 @@ impl Worksheet {
                     | CellType::RichString {
                         string_id,
                         xf_index,
+                        phonetic: None | Some(false),
                         ..
                     } => {
                         if let Some(string_id) = string_id {
@@ -18495,6 +22942,24 @@ impl Worksheet {
                         }
                     }
 
+                    CellType::RichString {
+                        string_id,
+                        xf_index,
+                        phonetic: Some(true),
+                        ..
+                    } => {
+                        if let Some(string_id) = string_id {
+                            let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
+                            Self::write_phonetic_string_cell(
+                                &mut self.writer,
+                                row_num + 1,
+                                col_name,
+                                *string_id,
+                                xf_index,
+                            );
+                        }
+                    }
+
                     CellType::InlineString {
                         string, xf_index, ..
                     } => {
@@ -18559,13 +23024,19 @@ impl Worksheet {
                         );
                     }
 
-                    CellType::Error { value, xf_index } => {
+                    CellType::Error {
+                        error,
+                        xf_index,
+                        image_index,
+                    } => {
                         let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
-                        let image_id = self.global_embedded_image_indices[*value as usize];
+                        let image_id = image_index
+                            .map(|index| self.global_embedded_image_indices[index as usize]);
                         Self::write_error_cell(
                             &mut self.writer,
                             row_num + 1,
                             col_name,
+                            *error,
                             image_id,
                             xf_index,
                         );
@@ -18718,7 +23189,10 @@ impl Worksheet {
                 }
 
                 CellType::RichString {
-                    string, xf_index, ..
+                    string,
+                    xf_index,
+                    phonetic,
+                    ..
                 } => {
                     let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
                     Self::write_inline_rich_string_cell(
@@ -18727,6 +23201,7 @@ impl Worksheet {
                         col_name,
                         string,
                         xf_index,
+                        *phonetic == Some(true),
                     );
                 }
 
@@ -18801,13 +23276,19 @@ impl Worksheet {
                     );
                 }
 
-                CellType::Error { value, xf_index } => {
+                CellType::Error {
+                    error,
+                    xf_index,
+                    image_index,
+                } => {
                     let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
-                    let image_id = self.global_embedded_image_indices[*value as usize];
+                    let image_id =
+                        image_index.map(|index| self.global_embedded_image_indices[index as usize]);
                     Self::write_error_cell(
                         &mut self.file_writer,
                         current_row + 1,
                         col_name,
+                        *error,
                         image_id,
                         xf_index,
                     );
@@ -19052,6 +23533,29 @@ impl Worksheet {
         }
     }
 
+    // Write the <c> element for a string with visible phonetic text.
+    fn write_phonetic_string_cell<W: Write>(
+        writer: &mut W,
+        row: RowNum,
+        col_name: &str,
+        string_index: u32,
+        xf_index: u32,
+    ) {
+        if xf_index > 0 {
+            write!(
+                writer,
+                r#"<c r="{col_name}{row}" s="{xf_index}" t="s" ph="1"><v>{string_index}</v></c>"#
+            )
+            .expect(XML_WRITE_ERROR);
+        } else {
+            write!(
+                writer,
+                r#"<c r="{col_name}{row}" t="s" ph="1"><v>{string_index}</v></c>"#
+            )
+            .expect(XML_WRITE_ERROR);
+        }
+    }
+
     // Write the <c> element for an inline string.
     fn write_inline_string_cell<W: Write>(
         writer: &mut W,
@@ -19093,17 +23597,20 @@ impl Worksheet {
         col_name: &str,
         string: &str,
         xf_index: u32,
+        show_phonetic: bool,
     ) {
+        let phonetic = if show_phonetic { r#" ph="1""# } else { "" };
+
         if xf_index > 0 {
             write!(
                 writer,
-                r#"<c r="{col_name}{row}" s="{xf_index}" t="inlineStr"><is>{string}</is></c>"#
+                r#"<c r="{col_name}{row}" s="{xf_index}" t="inlineStr"{phonetic}><is>{string}</is></c>"#
             )
             .expect(XML_WRITE_ERROR);
         } else {
             write!(
                 writer,
-                r#"<c r="{col_name}{row}" t="inlineStr"><is>{string}</is></c>"#
+                r#"<c r="{col_name}{row}" t="inlineStr"{phonetic}><is>{string}</is></c>"#
             )
             .expect(XML_WRITE_ERROR);
         }
@@ -19248,28 +23755,35 @@ impl Worksheet {
         }
     }
 
-    // Write the <c> element for an error cell. We currently only support the
-    // #VALUE! error type which is used for embedded images.
+    // Write the <c> element for an error cell. Embedded images, and the #SPILL!
+    // and #CALC! errors, are stored as #VALUE! errors with a reference to the
+    // rich value metadata.
     fn write_error_cell<W: Write>(
         writer: &mut W,
         row: RowNum,
         col_name: &str,
-        value: u32,
+        error: ExcelError,
+        image_id: Option<u32>,
         xf_index: u32,
     ) {
-        if xf_index > 0 {
-            write!(
-                writer,
-                r#"<c r="{col_name}{row}" s="{xf_index}" t="e" vm="{value}"><v>#VALUE!</v></c>"#
-            )
-            .expect(XML_WRITE_ERROR);
+        let style = if xf_index > 0 {
+            format!(r#" s="{xf_index}""#)
         } else {
-            write!(
-                writer,
-                r#"<c r="{col_name}{row}" t="e" vm="{value}"><v>#VALUE!</v></c>"#
-            )
-            .expect(XML_WRITE_ERROR);
-        }
+            String::new()
+        };
+
+        let value_metadata = match image_id.or(error.value_metadata_id()) {
+            Some(value_metadata_id) => format!(r#" vm="{value_metadata_id}""#),
+            None => String::new(),
+        };
+
+        let error = error.file_str();
+
+        write!(
+            writer,
+            r#"<c r="{col_name}{row}"{style} t="e"{value_metadata}><v>{error}</v></c>"#
+        )
+        .expect(XML_WRITE_ERROR);
     }
 
     // Write the <cols> element.
@@ -19334,19 +23848,7 @@ impl Worksheet {
 
         // Convert column width from user units to character width.
         if width > 0.0 {
-            // Properties for Calibri 11.
-            let max_digit_width = 7.0_f64;
-            let padding = 5.0_f64;
-
-            if width < 1.0 {
-                width = ((width * (max_digit_width + padding)).round() / max_digit_width * 256.0)
-                    .floor()
-                    / 256.0;
-            } else {
-                width = (((width * max_digit_width).round() + padding) / max_digit_width * 256.0)
-                    .floor()
-                    / 256.0;
-            }
+            width = self.theme_font.stored_width(width);
         }
 
         let mut attributes = vec![
@@ -19386,6 +23888,16 @@ impl Worksheet {
     // Write the <headerFooter> element.
     fn write_header_footer(&mut self) {
         let mut attributes = vec![];
+        let has_even = !self.header_even.is_empty() || !self.footer_even.is_empty();
+        let has_first = !self.header_first.is_empty() || !self.footer_first.is_empty();
+
+        if has_even {
+            attributes.push(("differentOddEven", "1".to_string()));
+        }
+
+        if has_first {
+            attributes.push(("differentFirst", "1".to_string()));
+        }
 
         if !self.header_footer_scale_with_doc {
             attributes.push(("scaleWithDoc", "0".to_string()));
@@ -19395,57 +23907,32 @@ impl Worksheet {
             attributes.push(("alignWithMargins", "0".to_string()));
         }
 
-        if self.header.is_empty() && self.footer.is_empty() {
+        if self.header.is_empty() && self.footer.is_empty() && !has_even && !has_first {
             xml_empty_tag(&mut self.writer, "headerFooter", &attributes);
         } else {
             xml_start_tag(&mut self.writer, "headerFooter", &attributes);
 
-            // Write the oddHeader element.
-            if !self.header.is_empty() {
-                self.write_odd_header();
-            }
+            let strings = [
+                ("oddHeader", self.header.clone()),
+                ("oddFooter", self.footer.clone()),
+                ("evenHeader", self.header_even.clone()),
+                ("evenFooter", self.footer_even.clone()),
+                ("firstHeader", self.header_first.clone()),
+                ("firstFooter", self.footer_first.clone()),
+            ];
 
-            // Write the oddFooter element.
-            if !self.footer.is_empty() {
-                self.write_odd_footer();
+            // Write the oddHeader, oddFooter, evenHeader, etc. elements.
+            for (tag, string) in strings {
+                if !string.is_empty() {
+                    let string = Self::expand_header_footer(&string);
+                    xml_data_element_only(&mut self.writer, tag, &string);
+                }
             }
 
             xml_end_tag(&mut self.writer, "headerFooter");
         }
     }
 
-    // Write the <oddHeader> element.
-    fn write_odd_header(&mut self) {
-        let header = self
-            .header
-            .replace("&[Tab]", "&A")
-            .replace("&[Date]", "&D")
-            .replace("&[File]", "&F")
-            .replace("&[Page]", "&P")
-            .replace("&[Path]", "&Z")
-            .replace("&[Time]", "&T")
-            .replace("&[Pages]", "&N")
-            .replace("&[Picture]", "&G");
-
-        xml_data_element_only(&mut self.writer, "oddHeader", &header);
-    }
-
-    // Write the <oddFooter> element.
-    fn write_odd_footer(&mut self) {
-        let footer = self
-            .footer
-            .replace("&[Tab]", "&A")
-            .replace("&[Date]", "&D")
-            .replace("&[File]", "&F")
-            .replace("&[Page]", "&P")
-            .replace("&[Path]", "&Z")
-            .replace("&[Time]", "&T")
-            .replace("&[Pages]", "&N")
-            .replace("&[Picture]", "&G");
-
-        xml_data_element_only(&mut self.writer, "oddFooter", &footer);
-    }
-
     // Write the <drawing> element.
     fn write_drawing(&mut self) {
         self.rel_count += 1;
@@ -19478,6 +23965,145 @@ impl Worksheet {
         xml_empty_tag(&mut self.writer, "picture", &attributes);
     }
 
+    // Write the <oleObjects> element.
+    fn write_ole_objects(&mut self) {
+        xml_start_tag_only(&mut self.writer, "oleObjects");
+
+        let dv_aspects: Vec<String> = self
+            .ole_objects
+            .values()
+            .map(|object| object.dv_aspect().to_string())
+            .collect();
+
+        for (i, (vml_info, dv_aspect)) in self
+            .ole_objects_vml_info
+            .clone()
+            .iter()
+            .zip(dv_aspects)
+            .enumerate()
+        {
+            let shape_id = self.vml_shape_id + 1 + i as u32;
+
+            self.rel_count += 1;
+            let object_rel_id = self.rel_count;
+            self.rel_count += 1;
+            let image_rel_id = self.rel_count;
+
+            let attributes = [(
+                "xmlns:mc",
+                "http://schemas.openxmlformats.org/markup-compatibility/2006",
+            )];
+            xml_start_tag(&mut self.writer, "mc:AlternateContent", &attributes);
+
+            let attributes = [("Requires", "x14")];
+            xml_start_tag(&mut self.writer, "mc:Choice", &attributes);
+
+            // Write the oleObject element with the object properties.
+            let attributes = [
+                ("progId", "Package".to_string()),
+                ("dvAspect", dv_aspect.clone()),
+                ("shapeId", shape_id.to_string()),
+                ("r:id", format!("rId{object_rel_id}")),
+            ];
+            xml_start_tag(&mut self.writer, "oleObject", &attributes);
+
+            // Write the objectPr element.
+            self.write_object_pr(vml_info, image_rel_id);
+
+            xml_end_tag(&mut self.writer, "oleObject");
+            xml_end_tag(&mut self.writer, "mc:Choice");
+
+            // Write the fallback oleObject element for older versions of Excel.
+            xml_start_tag_only(&mut self.writer, "mc:Fallback");
+
+            let attributes = [
+                ("progId", "Package".to_string()),
+                ("dvAspect", dv_aspect),
+                ("shapeId", shape_id.to_string()),
+                ("r:id", format!("rId{object_rel_id}")),
+            ];
+            xml_empty_tag(&mut self.writer, "oleObject", &attributes);
+
+            xml_end_tag(&mut self.writer, "mc:Fallback");
+            xml_end_tag(&mut self.writer, "mc:AlternateContent");
+        }
+
+        xml_end_tag(&mut self.writer, "oleObjects");
+    }
+
+    // Write the <objectPr> element.
+    fn write_object_pr(&mut self, vml_info: &VmlInfo, rel_id: u32) {
+        let mut attributes = vec![
+            ("defaultSize", "0".to_string()),
+            ("autoPict", "0".to_string()),
+        ];
+
+        if !vml_info.alt_text.is_empty() {
+            attributes.push(("altText", vml_info.alt_text.clone()));
+        }
+
+        attributes.push(("r:id", format!("rId{rel_id}")));
+
+        xml_start_tag(&mut self.writer, "objectPr", &attributes);
+
+        // Write the anchor element.
+        let mut attributes = vec![];
+        match vml_info.drawing_info.object_movement {
+            ObjectMovement::MoveAndSizeWithCells | ObjectMovement::MoveAndSizeWithCellsAfter => {
+                attributes.push(("moveWithCells", "1"));
+                attributes.push(("sizeWithCells", "1"));
+            }
+            ObjectMovement::MoveButDontSizeWithCells => {
+                attributes.push(("moveWithCells", "1"));
+            }
+            ObjectMovement::DontMoveOrSizeWithCells | ObjectMovement::AbsoluteAnchor => {}
+        }
+
+        xml_start_tag(&mut self.writer, "anchor", &attributes);
+
+        let from = &vml_info.drawing_info.from;
+        let to = &vml_info.drawing_info.to;
+        self.write_object_anchor_position(
+            "from",
+            from.col,
+            from.col_offset,
+            from.row,
+            from.row_offset,
+        );
+        self.write_object_anchor_position("to", to.col, to.col_offset, to.row, to.row_offset);
+
+        xml_end_tag(&mut self.writer, "anchor");
+        xml_end_tag(&mut self.writer, "objectPr");
+    }
+
+    // Write the <from> and <to> elements of an object anchor. The offsets are
+    // in EMUs.
+    fn write_object_anchor_position(
+        &mut self,
+        tag: &str,
+        col: u32,
+        col_offset: f64,
+        row: u32,
+        row_offset: f64,
+    ) {
+        xml_start_tag_only(&mut self.writer, tag);
+
+        xml_data_element_only(&mut self.writer, "xdr:col", &col.to_string());
+        xml_data_element_only(
+            &mut self.writer,
+            "xdr:colOff",
+            &round_to_emus(col_offset).to_string(),
+        );
+        xml_data_element_only(&mut self.writer, "xdr:row", &row.to_string());
+        xml_data_element_only(
+            &mut self.writer,
+            "xdr:rowOff",
+            &round_to_emus(row_offset).to_string(),
+        );
+
+        xml_end_tag(&mut self.writer, tag);
+    }
+
     // Write the <tableParts> element.
     fn write_table_parts(&mut self) {
         let num_tables = self.tables.len();
@@ -20114,6 +24740,27 @@ impl IntoExcelData for bool {
     }
 }
 
+impl IntoExcelData for ExcelError {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        worksheet.store_error(row, col, self, None)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        worksheet.store_error(row, col, self, Some(format))
+    }
+}
+
 impl IntoExcelData for &ExcelDateTime {
     fn write(
         self,
@@ -20444,6 +25091,23 @@ impl CellRange {
         utility::cell_range(self.first_row, self.first_col, self.last_row, self.last_col)
     }
 
+    pub(crate) fn contains(&self, row: RowNum, col: ColNum) -> bool {
+        (self.first_row..=self.last_row).contains(&row)
+            && (self.first_col..=self.last_col).contains(&col)
+    }
+
+    pub(crate) fn contains_range(&self, other: &CellRange) -> bool {
+        self.contains(other.first_row, other.first_col)
+            && self.contains(other.last_row, other.last_col)
+    }
+
+    pub(crate) fn overlaps(&self, other: &CellRange) -> bool {
+        self.first_row <= other.last_row
+            && self.last_row >= other.first_row
+            && self.first_col <= other.last_col
+            && self.last_col >= other.first_col
+    }
+
     pub(crate) fn to_error_string(&self) -> String {
         format!(
             "({}, {}, {}, {}) / {}",
@@ -20475,6 +25139,7 @@ struct RowOptions {
     hidden: bool,
     collapsed: bool,
     format: Option<Format>,
+    autofit: bool,
 }
 
 #[derive(Clone, PartialEq)]
@@ -20505,8 +25170,9 @@ enum CellType {
         xf_index: u32,
     },
     Error {
+        error: ExcelError,
         xf_index: u32,
-        value: u32,
+        image_index: Option<u32>,
     },
     Formula {
         formula: Box<str>,
@@ -20531,6 +25197,7 @@ enum CellType {
         xf_index: u32,
         raw_string: Arc<str>,
         string_id: Option<u32>,
+        phonetic: Option<bool>,
     },
     InlineString {
         string: Arc<str>,
@@ -20545,7 +25212,15 @@ enum PageView {
     PageBreaks,
 }
 
-#[derive(PartialEq)]
+// The page type of a header or footer.
+#[derive(Clone, Copy)]
+enum HeaderFooterPage {
+    Default,
+    Even,
+    First,
+}
+
+#[derive(Clone, PartialEq)]
 enum FilterType {
     Table,
     Worksheet,
@@ -20555,11 +25230,31 @@ enum FilterType {
 struct Panes {
     freeze_cell: (RowNum, ColNum),
     top_cell: (RowNum, ColNum),
+    split_size: (u32, u32),
 }
 
 impl Panes {
     fn is_empty(&self) -> bool {
-        self.freeze_cell.0 == 0 && self.freeze_cell.1 == 0
+        self.freeze_cell.0 == 0 && self.freeze_cell.1 == 0 && !self.is_split()
+    }
+
+    fn is_split(&self) -> bool {
+        self.split_size.0 > 0 || self.split_size.1 > 0
+    }
+
+    // Get the top left cell of the scrolling panes of a split worksheet. If it
+    // isn't set by the user it is estimated from the default row height and
+    // column width.
+    fn split_top_cell(&self) -> (RowNum, ColNum) {
+        if self.top_cell.0 == 0 && self.top_cell.1 == 0 {
+            let (height, width) = self.split_size;
+            (
+                ((height + 10) / 20).min(ROW_MAX - 1),
+                ((width + 32) / 64).min(u32::from(COL_MAX) - 1) as ColNum,
+            )
+        } else {
+            self.top_cell
+        }
     }
 
     fn top_left(&self) -> String {
@@ -20719,6 +25414,194 @@ impl DefinedName {
     }
 }
 
+/// The `CellValue` enum represents the data written to a worksheet cell.
+///
+/// It is returned by the [`Worksheet::cell_value()`] and
+/// [`Worksheet::cells()`] methods to read back the data in a worksheet.
+///
+/// The enum is marked as `#[non_exhaustive]` since new cell types may be added
+/// in later versions, so matches on it should include a wildcard arm.
+///
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum CellValue {
+    /// A number.
+    Number(f64),
+
+    /// A date or time, as an Excel serial date number.
+    DateTime(f64),
+
+    /// A string.
+    String(String),
+
+    /// A rich or phonetic string. The value is the text of the string without
+    /// formatting.
+    RichString(String),
+
+    /// A boolean value.
+    Boolean(bool),
+
+    /// A formula. The formula is stored in the form used in the file, without
+    /// a leading `=` and with any future function prefixes.
+    Formula {
+        /// The formula string.
+        formula: String,
+
+        /// The result stored for the formula. See
+        /// [`Formula::set_result()`](crate::Formula::set_result).
+        result: String,
+    },
+
+    /// An array or dynamic array formula. This is stored in the first cell of
+    /// the range. The other cells of the range are stored as `0` numbers.
+    ArrayFormula {
+        /// The formula string.
+        formula: String,
+
+        /// The result stored for the formula. See
+        /// [`Formula::set_result()`](crate::Formula::set_result).
+        result: String,
+
+        /// The range of the array formula such as `"A1:A3"`.
+        range: String,
+
+        /// True for dynamic array formulas.
+        is_dynamic: bool,
+    },
+
+    /// An Excel error value such as `#N/A`.
+    Error(ExcelError),
+
+    /// A formatted blank cell.
+    Blank,
+
+    /// An image embedded in the cell.
+    Image,
+}
+
+impl CellValue {
+    // Convert the internal cell data to the public value.
+    fn from_cell(cell: &CellType) -> CellValue {
+        match cell {
+            CellType::Number { number, .. } => CellValue::Number(*number),
+            CellType::DateTime { number, .. } => CellValue::DateTime(*number),
+            CellType::String { string, .. } | CellType::InlineString { string, .. } => {
+                CellValue::String(string.to_string())
+            }
+            CellType::RichString { raw_string, .. } => {
+                CellValue::RichString(raw_string.to_string())
+            }
+            CellType::Boolean { boolean, .. } => CellValue::Boolean(*boolean),
+            CellType::Formula {
+                formula, result, ..
+            } => CellValue::Formula {
+                formula: formula.to_string(),
+                result: result.to_string(),
+            },
+            CellType::ArrayFormula {
+                formula,
+                result,
+                range,
+                is_dynamic,
+                ..
+            } => CellValue::ArrayFormula {
+                formula: formula.to_string(),
+                result: result.to_string(),
+                range: range.to_string(),
+                is_dynamic: *is_dynamic,
+            },
+            CellType::Blank { .. } => CellValue::Blank,
+            CellType::Error {
+                image_index: Some(_),
+                ..
+            } => CellValue::Image,
+            CellType::Error { error, .. } => CellValue::Error(*error),
+        }
+    }
+
+    // Get the format index of the internal cell data.
+    fn cell_xf_index(cell: &CellType) -> u32 {
+        match cell {
+            CellType::ArrayFormula { xf_index, .. }
+            | CellType::Blank { xf_index }
+            | CellType::Boolean { xf_index, .. }
+            | CellType::Error { xf_index, .. }
+            | CellType::Formula { xf_index, .. }
+            | CellType::Number { xf_index, .. }
+            | CellType::DateTime { xf_index, .. }
+            | CellType::String { xf_index, .. }
+            | CellType::RichString { xf_index, .. }
+            | CellType::InlineString { xf_index, .. } => *xf_index,
+        }
+    }
+}
+
+/// The `CopiedRange` struct represents a range of cells copied from a
+/// worksheet.
+///
+/// It is returned by the [`Worksheet::copy_range_data()`] method and holds the
+/// values and formats of the copied cells and any merged ranges, notes,
+/// hyperlinks and conditional formats in the range. It can be pasted into the
+/// same worksheet, or another worksheet, with [`Worksheet::paste_range()`].
+///
+pub struct CopiedRange {
+    first_row: RowNum,
+    first_col: ColNum,
+    num_rows: RowNum,
+    num_cols: ColNum,
+    cells: Vec<CopiedCell>,
+    hyperlinks: Vec<(RowNum, ColNum, Url)>,
+    merged_ranges: Vec<CellRange>,
+    notes: Vec<(RowNum, ColNum, Note)>,
+    conditional_formats: Vec<(Vec<CellRange>, Box<dyn ConditionalFormat + Sync + Send>)>,
+}
+
+// A copied cell with its position relative to the start of the copied range.
+struct CopiedCell {
+    row: RowNum,
+    col: ColNum,
+    cell: CellType,
+    format: Option<Format>,
+    image: Option<Image>,
+    cascading_formats: Option<(Option<Format>, Option<Format>)>,
+}
+
+/// The `PanePosition` enum defines the panes of a worksheet that is divided
+/// into panes.
+///
+/// It is used with the [`Worksheet::set_pane_selection()`] method to set the
+/// selected cells in a pane. The panes that exist depend on the position of
+/// the split set with [`Worksheet::set_split_panes()`] or
+/// [`Worksheet::set_freeze_panes()`]. For example a worksheet that is only
+/// split horizontally has [`PanePosition::TopLeft`] and
+/// [`PanePosition::BottomLeft`] panes.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PanePosition {
+    /// The top left pane. This is the only pane in a worksheet without panes.
+    TopLeft,
+
+    /// The top right pane.
+    TopRight,
+
+    /// The bottom left pane.
+    BottomLeft,
+
+    /// The bottom right pane.
+    BottomRight,
+}
+
+impl fmt::Display for PanePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TopLeft => write!(f, "topLeft"),
+            Self::TopRight => write!(f, "topRight"),
+            Self::BottomLeft => write!(f, "bottomLeft"),
+            Self::BottomRight => write!(f, "bottomRight"),
+        }
+    }
+}
+
 /// The `IgnoreError` enum defines the Excel cell error types that can be
 /// ignored.
 ///
@@ -7,11 +7,20 @@
 #[cfg(test)]
 mod worksheet_tests {
 
+    use crate::data_validation::DataValidationRuleInternal;
+    use crate::font_metrics::ThemeFont;
+    use crate::shared_strings_table::SharedStringsTable;
     use crate::test_functions::xml_to_vec;
     use crate::worksheet::*;
-    use crate::XlsxError;
+    use crate::{
+        Button, CellValue, Chart, ChartType, ConditionalFormatBlank, ConditionalFormatCell,
+        ConditionalFormatCellRule, ConditionalFormatFormula, DataValidation, ExcelError,
+        FormatBorder, Formula, Note, OleObject, PanePosition, PasteOptions, PasteType,
+        PhoneticProperties, PhoneticString, PhoneticType, Sparkline, XlsxError,
+    };
     use pretty_assertions::assert_eq;
     use std::collections::HashMap;
+    use std::sync::{Arc, Mutex, RwLock};
 
     #[test]
     fn test_assemble() {
@@ -83,6 +92,40 @@ mod worksheet_tests {
         }
     }
 
+    #[test]
+    fn first_and_even_headers_footers() {
+        let mut worksheet = Worksheet::new();
+        let image = Image::new("tests/input/images/red.jpg").unwrap();
+
+        worksheet.set_header("&CPage &[Page]");
+        worksheet.set_footer_even("&L&[Picture]");
+        worksheet.set_header_first("&CCover");
+
+        let result = worksheet.set_header_first_image(&image, HeaderImagePosition::Left);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.set_footer_even_image(&image, HeaderImagePosition::Left);
+        assert!(result.is_ok());
+        assert!(worksheet.header_footer_images[9].is_some());
+
+        worksheet.write_header_footer();
+
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <headerFooter differentOddEven="1" differentFirst="1">
+              <oddHeader>&amp;CPage &amp;P</oddHeader>
+              <evenFooter>&amp;L&amp;G</evenFooter>
+              <firstHeader>&amp;CCover</firstHeader>
+            </headerFooter>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn get_serialize_dimensions() {
@@ -254,6 +297,1113 @@ mod worksheet_tests {
         assert!(matches!(result, Err(XlsxError::ParameterError(_))));
     }
 
+    #[test]
+    fn phonetic_string() {
+        let mut worksheet = Worksheet::new();
+        let string_table = Arc::new(Mutex::new(SharedStringsTable::new()));
+
+        let properties = PhoneticProperties::new().set_type(PhoneticType::Hiragana);
+        worksheet.set_phonetic_properties(&properties);
+
+        let string = PhoneticString::new("漢字")
+            .add_run(0, 2, "かんじ")
+            .set_visible(true);
+        worksheet.write_phonetic_string(0, 0, &string).unwrap();
+
+        let string = PhoneticString::new("漢字").add_run(0, 3, "かんじ");
+        let result = worksheet.write_phonetic_string(1, 0, &string);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        worksheet.set_global_xf_indices(&[0]);
+        worksheet.update_string_table_ids(Arc::clone(&string_table));
+
+        let string_table = string_table.lock().unwrap();
+        let expected = r#"<t>漢字</t><rPh sb="0" eb="2"><t>かんじ</t></rPh><phoneticPr fontId="0" type="Hiragana"/>"#;
+        assert_eq!(string_table.strings.get(expected), Some(&0));
+        assert!(string_table.xml_strings.contains(&0));
+
+        worksheet.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" spans="1:1">
+                  <c r="A1" t="s" ph="1">
+                    <v>0</v>
+                  </c>
+                </row>
+              </sheetData>
+              <phoneticPr fontId="0" type="Hiragana"/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn phonetic_string_global_sst() {
+        let mut worksheet = Worksheet::new();
+
+        // Simulate a "low memory" worksheet that uses the workbook formats and
+        // shared string table.
+        worksheet.has_workbook_global_xfs = true;
+        worksheet.has_workbook_global_sst = true;
+        worksheet.workbook_xf_indices =
+            Arc::new(RwLock::new(HashMap::from([(Format::default(), 0)])));
+
+        let bold = Format::new().set_bold();
+        worksheet.write_with_format(0, 1, "Foo", &bold).unwrap();
+
+        let font = Format::new().set_font_size(8);
+        let properties = PhoneticProperties::new().set_font(&font);
+        worksheet.set_phonetic_properties(&properties);
+
+        let string = PhoneticString::new("漢字").add_run(0, 2, "かんじ");
+        worksheet.write_phonetic_string(0, 0, &string).unwrap();
+
+        // The phonetic font is the third font after the default and bold fonts.
+        let string_table = worksheet.string_table.lock().unwrap();
+        let expected =
+            r#"<t>漢字</t><rPh sb="0" eb="2"><t>かんじ</t></rPh><phoneticPr fontId="2"/>"#;
+        assert!(string_table.strings.contains_key(expected));
+    }
+
+    #[test]
+    fn excel_error_cells() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write(0, 0, ExcelError::NA).unwrap();
+        worksheet.write(1, 0, ExcelError::Div0).unwrap();
+        worksheet.write(2, 0, 1).unwrap();
+        worksheet
+            .write(3, 0, Formula::new("=1/0").set_result(ExcelError::Div0))
+            .unwrap();
+        worksheet.write(4, 0, ExcelError::Spill).unwrap();
+        worksheet.write(5, 0, ExcelError::Calc).unwrap();
+
+        assert_eq!(
+            worksheet.cell_value(0, 0),
+            Some(CellValue::Error(ExcelError::NA))
+        );
+        assert_eq!(
+            worksheet.cell_value(4, 0),
+            Some(CellValue::Error(ExcelError::Spill))
+        );
+        assert!(worksheet.has_rich_value_errors);
+
+        let cache = worksheet.get_cache_data(0, 0, 2, 0);
+        assert_eq!(cache.data, vec!["#N/A", "#DIV/0!", "1"]);
+
+        worksheet.set_global_xf_indices(&[0]);
+        worksheet.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:A6"/>
+              <sheetViews>
+                <sheetView workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" spans="1:1">
+                  <c r="A1" t="e">
+                    <v>#N/A</v>
+                  </c>
+                </row>
+                <row r="2" spans="1:1">
+                  <c r="A2" t="e">
+                    <v>#DIV/0!</v>
+                  </c>
+                </row>
+                <row r="3" spans="1:1">
+                  <c r="A3">
+                    <v>1</v>
+                  </c>
+                </row>
+                <row r="4" spans="1:1">
+                  <c r="A4" t="e">
+                    <f>1/0</f>
+                    <v>#DIV/0!</v>
+                  </c>
+                </row>
+                <row r="5" spans="1:1">
+                  <c r="A5" t="e" vm="1">
+                    <v>#VALUE!</v>
+                  </c>
+                </row>
+                <row r="6" spans="1:1">
+                  <c r="A6" t="e" vm="2">
+                    <v>#VALUE!</v>
+                  </c>
+                </row>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn excel_error_formula_results() {
+        let mut worksheet = Worksheet::new();
+
+        // String results aren't converted to errors.
+        worksheet
+            .write(0, 0, Formula::new("=A5").set_result("#SPILL!"))
+            .unwrap();
+
+        // The dynamic array errors are ignored as formula results.
+        worksheet
+            .write(1, 0, Formula::new("=A5").set_result(ExcelError::Spill))
+            .unwrap();
+        worksheet
+            .write_dynamic_array_formula(2, 0, 2, 0, "=SEQUENCE(0)")
+            .unwrap()
+            .set_formula_result(2, 0, "1")
+            .set_formula_result(2, 0, ExcelError::Calc);
+        worksheet
+            .write(3, 0, Formula::new("=1/0").set_result(ExcelError::Div0))
+            .unwrap();
+        worksheet
+            .write_formula(4, 0, "=NA()")
+            .unwrap()
+            .set_formula_result(4, 0, ExcelError::NA);
+        assert!(!worksheet.has_rich_value_errors);
+
+        assert_eq!(
+            worksheet.cell_value(1, 0),
+            Some(CellValue::Formula {
+                formula: "A5".to_string(),
+                result: "0".to_string(),
+            })
+        );
+
+        // Pasting the values converts the error results but not the strings.
+        let mut worksheet2 = Worksheet::new();
+        worksheet2
+            .write(0, 0, Formula::new("=A5").set_result("#SPILL!"))
+            .unwrap();
+        worksheet2
+            .write(1, 0, Formula::new("=1/0").set_result("#DIV/0!"))
+            .unwrap();
+        let options = PasteOptions::new().set_type(PasteType::Values);
+        worksheet2
+            .copy_range_with_options(0, 0, 1, 0, 0, 1, &options)
+            .unwrap();
+
+        assert_eq!(
+            worksheet2.cell_value(0, 1),
+            Some(CellValue::String("#SPILL!".to_string()))
+        );
+        assert_eq!(
+            worksheet2.cell_value(1, 1),
+            Some(CellValue::Error(ExcelError::Div0))
+        );
+
+        worksheet.set_global_xf_indices(&[0]);
+        worksheet.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:A5"/>
+              <sheetViews>
+                <sheetView workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" spans="1:1">
+                  <c r="A1" t="str">
+                    <f>A5</f>
+                    <v>#SPILL!</v>
+                  </c>
+                </row>
+                <row r="2" spans="1:1">
+                  <c r="A2">
+                    <f>A5</f>
+                    <v>0</v>
+                  </c>
+                </row>
+                <row r="3" spans="1:1">
+                  <c r="A3" cm="1">
+                    <f t="array" ref="A3">_xlfn.SEQUENCE(0)</f>
+                    <v>1</v>
+                  </c>
+                </row>
+                <row r="4" spans="1:1">
+                  <c r="A4" t="e">
+                    <f>1/0</f>
+                    <v>#DIV/0!</v>
+                  </c>
+                </row>
+                <row r="5" spans="1:1">
+                  <c r="A5" t="e">
+                    <f>NA()</f>
+                    <v>#N/A</v>
+                  </c>
+                </row>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_excel_error() {
+        let mut worksheet = Worksheet::new();
+
+        #[derive(Serialize)]
+        struct MyStruct {
+            value: ExcelError,
+        }
+
+        let data = MyStruct {
+            value: ExcelError::NA,
+        };
+
+        worksheet.serialize_headers(0, 0, &data).unwrap();
+        worksheet.serialize(&data).unwrap();
+
+        let data = MyStruct {
+            value: ExcelError::Spill,
+        };
+
+        worksheet.serialize(&data).unwrap();
+
+        assert_eq!(
+            worksheet.cell_value(1, 0),
+            Some(CellValue::Error(ExcelError::NA))
+        );
+        assert_eq!(
+            worksheet.cell_value(2, 0),
+            Some(CellValue::Error(ExcelError::Spill))
+        );
+    }
+
+    #[test]
+    fn read_cell_values() {
+        let mut worksheet = Worksheet::new();
+        let bold = Format::new().set_bold();
+
+        assert_eq!(worksheet.used_range(), None);
+        assert_eq!(worksheet.cells().count(), 0);
+
+        worksheet.write_number(1, 1, 1.5).unwrap();
+        worksheet
+            .write_string_with_format(1, 2, "Hello", &bold)
+            .unwrap();
+        worksheet.write_boolean(2, 1, true).unwrap();
+        worksheet.write_formula(2, 2, "=B2*2").unwrap();
+        worksheet.write_blank(3, 3, &bold).unwrap();
+        worksheet
+            .write_rich_string(4, 1, &[(&bold, "Rich"), (&Format::default(), " text")])
+            .unwrap();
+        worksheet
+            .write_array_formula(5, 1, 5, 2, "{=B2:C2}")
+            .unwrap();
+
+        assert_eq!(worksheet.cell_value(1, 1), Some(CellValue::Number(1.5)));
+        assert_eq!(
+            worksheet.cell_value(1, 2),
+            Some(CellValue::String("Hello".to_string()))
+        );
+        assert_eq!(worksheet.cell_value(2, 1), Some(CellValue::Boolean(true)));
+        assert_eq!(
+            worksheet.cell_value(2, 2),
+            Some(CellValue::Formula {
+                formula: "B2*2".to_string(),
+                result: "0".to_string()
+            })
+        );
+        assert_eq!(worksheet.cell_value(3, 3), Some(CellValue::Blank));
+        assert_eq!(
+            worksheet.cell_value(4, 1),
+            Some(CellValue::RichString("Rich text".to_string()))
+        );
+        assert_eq!(
+            worksheet.cell_value(5, 1),
+            Some(CellValue::ArrayFormula {
+                formula: "B2:C2".to_string(),
+                result: "0".to_string(),
+                range: "B6:C6".to_string(),
+                is_dynamic: false,
+            })
+        );
+        assert_eq!(worksheet.cell_value(5, 2), Some(CellValue::Number(0.0)));
+        assert_eq!(worksheet.cell_value(0, 0), None);
+
+        assert_eq!(worksheet.cell_format(1, 1), None);
+        assert_eq!(worksheet.cell_format(1, 2), Some(bold.clone()));
+        assert_eq!(worksheet.cell_format(3, 3), Some(bold));
+        assert_eq!(worksheet.cell_format(0, 0), None);
+
+        assert_eq!(worksheet.used_range(), Some((1, 1, 5, 3)));
+
+        let cells: Vec<(RowNum, ColNum)> =
+            worksheet.cells().map(|(row, col, _)| (row, col)).collect();
+        assert_eq!(
+            cells,
+            vec![
+                (1, 1),
+                (1, 2),
+                (2, 1),
+                (2, 2),
+                (3, 3),
+                (4, 1),
+                (5, 1),
+                (5, 2)
+            ]
+        );
+    }
+
+    #[cfg(feature = "constant_memory")]
+    #[test]
+    fn read_back_cells_in_constant_memory_mode() {
+        let mut workbook = crate::Workbook::new();
+        let worksheet = workbook.add_worksheet_with_constant_memory();
+
+        worksheet.write_number(0, 0, 1).unwrap();
+        worksheet.write_number(1, 0, 2).unwrap();
+        worksheet.write_number(2, 0, 3).unwrap();
+
+        // Only the row that hasn't been flushed is visible.
+        let cells: Vec<(RowNum, ColNum, CellValue)> = worksheet.cells().collect();
+        assert_eq!(cells, vec![(2, 0, CellValue::Number(3.0))]);
+
+        assert_eq!(worksheet.cell_value(0, 0), None);
+        assert_eq!(worksheet.used_range(), Some((0, 0, 2, 0)));
+    }
+
+    #[test]
+    fn insert_and_delete_rows_and_columns() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_name("Data").unwrap();
+
+        worksheet.write_number(0, 0, 1).unwrap();
+        worksheet.write_number(1, 0, 2).unwrap();
+        worksheet.write_number(2, 0, 3).unwrap();
+        worksheet
+            .write_formula(3, 0, "=SUM(A1:A3)+Data!$A$2")
+            .unwrap();
+        worksheet.write_formula(3, 1, "=Other!A2+A2").unwrap();
+        worksheet
+            .merge_range(5, 0, 6, 1, "Merged", &Format::default())
+            .unwrap();
+        worksheet.insert_note(2, 0, &Note::new("Note")).unwrap();
+
+        worksheet.insert_rows(1, 2).unwrap();
+
+        assert_eq!(worksheet.cell_value(0, 0), Some(CellValue::Number(1.0)));
+        assert_eq!(worksheet.cell_value(1, 0), None);
+        assert_eq!(worksheet.cell_value(3, 0), Some(CellValue::Number(2.0)));
+        assert_eq!(
+            worksheet.cell_value(5, 0),
+            Some(CellValue::Formula {
+                formula: "SUM(A1:A5)+Data!$A$4".to_string(),
+                result: "0".to_string()
+            })
+        );
+        assert_eq!(
+            worksheet.cell_value(5, 1),
+            Some(CellValue::Formula {
+                formula: "Other!A2+A4".to_string(),
+                result: "0".to_string()
+            })
+        );
+        assert_eq!(worksheet.merged_ranges[0].to_range_string(), "A8:B9");
+        assert_eq!(worksheet.merged_cells.get(&(8, 1)), Some(&0));
+        assert!(worksheet.notes[&4].contains_key(&0));
+        assert_eq!(worksheet.used_range(), Some((0, 0, 8, 1)));
+
+        worksheet.delete_rows(3, 1).unwrap();
+
+        assert_eq!(worksheet.cell_value(3, 0), Some(CellValue::Number(3.0)));
+        assert_eq!(
+            worksheet.cell_value(4, 0),
+            Some(CellValue::Formula {
+                formula: "SUM(A1:A4)+Data!#REF!".to_string(),
+                result: "0".to_string()
+            })
+        );
+        assert!(worksheet.notes[&3].contains_key(&0));
+
+        worksheet.delete_columns(1, 1).unwrap();
+        assert_eq!(worksheet.cell_value(4, 1), None);
+        assert_eq!(worksheet.merged_ranges[0].to_range_string(), "A7:A8");
+
+        worksheet.insert_columns(0, 1).unwrap();
+        assert_eq!(worksheet.cell_value(0, 1), Some(CellValue::Number(1.0)));
+        assert_eq!(worksheet.used_range(), Some((0, 1, 7, 1)));
+
+        // Check the limits and the table checks.
+        let result = worksheet.insert_rows(ROW_MAX, 1);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.insert_rows(0, ROW_MAX - 7);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        worksheet.add_table(10, 1, 12, 3, &Table::new()).unwrap();
+
+        let result = worksheet.insert_columns(2, 1);
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        let result = worksheet.delete_rows(10, 1);
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        worksheet.insert_rows(11, 1).unwrap();
+        assert_eq!(worksheet.tables[0].cell_range.to_range_string(), "B11:D14");
+
+        worksheet.delete_columns(0, 5).unwrap();
+        assert!(worksheet.tables.is_empty());
+        assert!(worksheet.table_cells.is_empty());
+    }
+
+    #[test]
+    fn delete_rows_with_conditional_formats_and_charts() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_name("Data").unwrap();
+
+        worksheet.write_column(0, 0, [1, 2, 3, 4, 5, 6]).unwrap();
+        worksheet
+            .add_conditional_format(
+                2,
+                1,
+                5,
+                1,
+                &ConditionalFormatFormula::new().set_rule("=$A3>$A$6"),
+            )
+            .unwrap();
+        worksheet
+            .add_conditional_format(
+                0,
+                2,
+                3,
+                2,
+                &ConditionalFormatCell::new().set_rule(ConditionalFormatCellRule::GreaterThan(
+                    Formula::new("=Data!$A$2"),
+                )),
+            )
+            .unwrap();
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Data!$A$1:$A$2");
+        chart.add_series().set_values("Data!$A$3:$A$6");
+        worksheet.insert_chart(8, 0, &chart).unwrap();
+
+        worksheet.delete_rows(0, 2).unwrap();
+
+        let rule = worksheet.conditional_formats["B1:B4"][0].rule(None, 1, "B1", "");
+        assert!(rule.contains("<formula>$A1&gt;$A$4</formula>"));
+
+        let rule = worksheet.conditional_formats["C1:C2"][0].rule(None, 1, "C1", "");
+        assert!(rule.contains("<formula>Data!#REF!</formula>"));
+
+        let chart = worksheet.charts.values().next().unwrap();
+        assert_eq!(chart.series[0].value_range.formula_abs(), "Data!#REF!");
+        assert_eq!(chart.series[1].value_range.formula_abs(), "Data!$A$1:$A$4");
+    }
+
+    #[test]
+    fn shift_hyperlink_locations() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_name("Data").unwrap();
+
+        worksheet.write_url(0, 0, "internal:Data!A3").unwrap();
+        worksheet
+            .write_url(1, 0, "internal:'Data'!$A$3:$B$4")
+            .unwrap();
+        worksheet.write_url(2, 0, "internal:Other!A3").unwrap();
+        worksheet.write_url(3, 0, "internal:A5").unwrap();
+        worksheet.write_url(4, 0, "internal:Data!A2").unwrap();
+        worksheet
+            .write_url(5, 0, "https://www.rust-lang.org")
+            .unwrap();
+
+        worksheet.insert_rows(2, 2).unwrap();
+
+        assert_eq!(worksheet.hyperlinks[&(0, 0)].anchor, "Data!A5");
+        assert_eq!(worksheet.hyperlinks[&(1, 0)].anchor, "'Data'!$A$5:$B$6");
+        assert_eq!(worksheet.hyperlinks[&(4, 0)].anchor, "Other!A3");
+        assert_eq!(worksheet.hyperlinks[&(5, 0)].anchor, "A7");
+        assert_eq!(worksheet.hyperlinks[&(6, 0)].anchor, "Data!A2");
+        assert_eq!(worksheet.hyperlinks[&(7, 0)].anchor, "");
+
+        worksheet.delete_rows(1, 1).unwrap();
+
+        assert_eq!(worksheet.hyperlinks[&(0, 0)].anchor, "Data!A4");
+        assert_eq!(worksheet.hyperlinks[&(5, 0)].anchor, "Data!#REF!");
+
+        // Links in another worksheet to the shifted worksheet.
+        let mut other = Worksheet::new();
+        other.write_url(0, 0, "internal:Data!A3").unwrap();
+        other.write_url(1, 0, "internal:A3").unwrap();
+
+        let change = ReferenceChange::Shift(ReferenceShift::new(ShiftAxis::Rows, 0, 2, true));
+        other.apply_external_change("Data", &change);
+
+        assert_eq!(other.hyperlinks[&(0, 0)].anchor, "Data!A5");
+        assert_eq!(other.hyperlinks[&(1, 0)].anchor, "A3");
+    }
+
+    #[test]
+    fn apply_external_change() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet
+            .write_formula(0, 0, "=SUM(Data!A1:A3)+A1")
+            .unwrap();
+        worksheet
+            .add_conditional_format(
+                1,
+                0,
+                1,
+                0,
+                &ConditionalFormatFormula::new().set_rule("=ISNUMBER(Data!$A$1)"),
+            )
+            .unwrap();
+        worksheet
+            .add_data_validation(
+                2,
+                0,
+                2,
+                0,
+                &DataValidation::new().allow_list_formula("=Data!$A$1:$A$3".into()),
+            )
+            .unwrap();
+        worksheet
+            .add_sparkline(3, 0, &Sparkline::new().set_range(("Data", 0, 0, 2, 0)))
+            .unwrap();
+        worksheet
+            .add_sparkline(4, 0, &Sparkline::new().set_range(("Data", 5, 0, 6, 0)))
+            .unwrap();
+
+        let change = ReferenceChange::Shift(ReferenceShift::new(ShiftAxis::Rows, 0, 2, true));
+        worksheet.apply_external_change("Data", &change);
+
+        assert_eq!(
+            worksheet.cell_value(0, 0),
+            Some(CellValue::Formula {
+                formula: "SUM(Data!A3:A5)+A1".to_string(),
+                result: "0".to_string()
+            })
+        );
+        assert!(worksheet.conditional_formats["A2"][0]
+            .rule(None, 1, "A2", "")
+            .contains("<formula>ISNUMBER(Data!$A$3)</formula>"));
+        assert!(matches!(
+            &worksheet.data_validations["A3"].rule,
+            DataValidationRuleInternal::ListSource(list) if list == "Data!$A$3:$A$5"
+        ));
+        assert_eq!(worksheet.sparklines[0].ranges[0].1, "Data!A3:A5");
+
+        // Sparklines with deleted data are removed.
+        let change = ReferenceChange::Shift(ReferenceShift::new(ShiftAxis::Rows, 7, 2, false));
+        worksheet.apply_external_change("Data", &change);
+
+        assert_eq!(worksheet.sparklines.len(), 1);
+        assert_eq!(worksheet.sparklines[0].ranges[0].1, "Data!A3:A5");
+    }
+
+    #[test]
+    fn remove_sheet_references() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write_formula(0, 0, "=Template!A1+A2").unwrap();
+        worksheet
+            .add_data_validation(
+                1,
+                0,
+                1,
+                0,
+                &DataValidation::new().allow_list_formula("=Template!$A$1:$A$3".into()),
+            )
+            .unwrap();
+        worksheet
+            .add_conditional_format(
+                2,
+                0,
+                2,
+                0,
+                &ConditionalFormatFormula::new().set_rule("=A3>Template!$A$1"),
+            )
+            .unwrap();
+        worksheet.write_url(3, 0, "internal:Template!A1").unwrap();
+        worksheet
+            .add_sparkline(4, 0, &Sparkline::new().set_range("Template!A1:A3"))
+            .unwrap();
+
+        worksheet.rename_sheet_references("Template", None);
+
+        assert_eq!(
+            worksheet.cell_value(0, 0),
+            Some(CellValue::Formula {
+                formula: "#REF!A1+A2".to_string(),
+                result: "0".to_string()
+            })
+        );
+        assert!(matches!(
+            &worksheet.data_validations["A2"].rule,
+            DataValidationRuleInternal::ListSource(list) if list == "#REF!$A$1:$A$3"
+        ));
+        assert!(worksheet.conditional_formats["A3"][0]
+            .rule(None, 1, "A3", "")
+            .contains("<formula>A3&gt;#REF!$A$1</formula>"));
+        assert_eq!(worksheet.hyperlinks[&(3, 0)].anchor, "#REF!A1");
+        assert!(worksheet.sparklines.is_empty());
+        assert!(!worksheet.has_sparklines);
+    }
+
+    #[test]
+    fn expand_header_footer() {
+        let tests = [
+            ("&CPage &[Page] of &[Pages]", "&CPage &P of &N"),
+            ("&L&[Tab]&C&[Date] &[Time]&R&[File]", "&L&A&C&D &T&R&F"),
+            ("&L&[Path]&[Picture]", "&L&Z&G"),
+            // Escaped ampersands aren't expanded.
+            ("&C&&[Page] &[Page]", "&C&&[Page] &P"),
+            ("&C&&&[Page]", "&C&&&P"),
+            ("&C&[Unknown]&", "&C&[Unknown]&"),
+        ];
+
+        for (string, expected) in tests {
+            assert_eq!(expected, Worksheet::expand_header_footer(string));
+        }
+    }
+
+    #[test]
+    fn absolute_position() {
+        let mut worksheet = Worksheet::new();
+
+        let image = Image::new("tests/input/images/red.png")
+            .unwrap()
+            .set_absolute_position(150, 60);
+        let shape = Shape::textbox().set_absolute_position(10, 20);
+        let button = Button::new().set_absolute_position(30, 40);
+
+        // The insert cell and offsets are ignored for absolute positions.
+        worksheet
+            .insert_image_with_offset(5, 5, &image, 3, 3)
+            .unwrap();
+        worksheet.insert_shape(1, 1, &shape).unwrap();
+        worksheet.insert_button(2, 2, &button).unwrap();
+
+        // Absolutely positioned objects are stored at their insert cell but
+        // don't move with the cells.
+        worksheet.insert_rows(0, 2).unwrap();
+        worksheet.insert_columns(0, 2).unwrap();
+
+        assert_eq!(
+            worksheet.images.keys().copied().collect::<Vec<_>>(),
+            vec![(5, 5, 3, 3)]
+        );
+        assert_eq!(
+            worksheet.shapes.keys().copied().collect::<Vec<_>>(),
+            vec![(1, 1, 0, 0)]
+        );
+        assert_eq!(
+            worksheet.buttons.keys().copied().collect::<Vec<_>>(),
+            vec![(2, 2, 0, 0)]
+        );
+        assert_eq!(
+            worksheet.images[&(5, 5, 3, 3)].object_movement,
+            ObjectMovement::AbsoluteAnchor
+        );
+    }
+
+    #[test]
+    fn vml_shape_count() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.insert_note(0, 0, &Note::new("Note")).unwrap();
+        worksheet.insert_button(1, 0, &Button::new()).unwrap();
+        let image = Image::new("tests/input/images/red.jpg").unwrap();
+        let object = OleObject::new_from_buffer(b"1,2", "data.csv").set_image(&image);
+        worksheet.insert_object(2, 0, &object).unwrap();
+
+        // The VML shape id block includes the notes, buttons and OLE objects.
+        assert_eq!(3, worksheet.prepare_vml_objects(1, 1024));
+        assert_eq!("1", worksheet.vml_data_id);
+    }
+
+    #[test]
+    fn absolute_position_objects() {
+        let mut worksheet = Worksheet::new();
+
+        // Absolute objects at the same position, and a cell anchored object at
+        // the same offsets from A1, are stored separately.
+        let image = Image::new("tests/input/images/red.png").unwrap();
+        let absolute_image = image.clone().set_absolute_position(256, 160);
+        worksheet.insert_image(0, 0, &absolute_image).unwrap();
+        worksheet.insert_image(5, 2, &absolute_image).unwrap();
+        worksheet
+            .insert_image_with_offset(0, 0, &image, 256, 160)
+            .unwrap();
+        assert_eq!(3, worksheet.images.len());
+
+        let button = Button::new().set_absolute_position(128, 20);
+        worksheet.insert_button(0, 0, &button).unwrap();
+        worksheet.insert_button(3, 3, &button).unwrap();
+        assert_eq!(2, worksheet.buttons.len());
+
+        // The absolute objects keep their position when rows are inserted.
+        worksheet.insert_rows(0, 2).unwrap();
+
+        worksheet.prepare_worksheet_images(&mut HashMap::new(), &mut 0, 1);
+        worksheet.prepare_vml_objects(1, 1024);
+
+        let positions: Vec<(u32, u32, u64, u64)> = worksheet
+            .drawing
+            .drawings
+            .iter()
+            .map(|drawing| {
+                (
+                    drawing.from.col,
+                    drawing.from.row,
+                    drawing.col_absolute,
+                    drawing.row_absolute,
+                )
+            })
+            .collect();
+
+        // The absolute images are 256 x 160 pixels from A1 and the cell
+        // anchored image is offset from A3.
+        assert_eq!(
+            vec![
+                (4, 8, 2438400, 1524000),
+                (4, 10, 2438400, 1905000),
+                (4, 8, 2438400, 1524000),
+            ],
+            positions
+        );
+
+        let positions: Vec<(u32, u32, f64, f64)> = worksheet
+            .buttons_vml_info
+            .iter()
+            .map(|vml_info| {
+                let from = &vml_info.drawing_info.from;
+                (from.col, from.row, from.col_offset, from.row_offset)
+            })
+            .collect();
+
+        assert_eq!(vec![(2, 1, 0.0, 0.0), (2, 1, 0.0, 0.0)], positions);
+    }
+
+    #[test]
+    fn ole_objects() {
+        let mut worksheet = Worksheet::new();
+
+        let image = Image::new("tests/input/images/red.png").unwrap();
+        let object = OleObject::new_from_buffer(b"1,2", "data.csv").set_image(&image);
+        worksheet.insert_object(2, 1, &object).unwrap();
+
+        worksheet.prepare_vml_objects(1, 1024);
+        worksheet.prepare_ole_objects(&mut HashMap::new(), &mut 0, 1);
+
+        worksheet.set_global_xf_indices(&[0]);
+        worksheet.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        let got: Vec<String> = xml_to_vec(got)
+            .into_iter()
+            .skip_while(|element| !element.starts_with("<legacyDrawing"))
+            .take_while(|element| element != "</worksheet>")
+            .collect();
+
+        let expected = xml_to_vec(
+            r#"
+            <legacyDrawing r:id="rId1"/>
+            <oleObjects>
+              <mc:AlternateContent xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006">
+                <mc:Choice Requires="x14">
+                  <oleObject progId="Package" dvAspect="DVASPECT_ICON" shapeId="1025" r:id="rId2">
+                    <objectPr defaultSize="0" autoPict="0" r:id="rId3">
+                      <anchor moveWithCells="1">
+                        <from>
+                          <xdr:col>1</xdr:col>
+                          <xdr:colOff>0</xdr:colOff>
+                          <xdr:row>2</xdr:row>
+                          <xdr:rowOff>0</xdr:rowOff>
+                        </from>
+                        <to>
+                          <xdr:col>1</xdr:col>
+                          <xdr:colOff>304800</xdr:colOff>
+                          <xdr:row>3</xdr:row>
+                          <xdr:rowOff>114300</xdr:rowOff>
+                        </to>
+                      </anchor>
+                    </objectPr>
+                  </oleObject>
+                </mc:Choice>
+                <mc:Fallback>
+                  <oleObject progId="Package" dvAspect="DVASPECT_ICON" shapeId="1025" r:id="rId2"/>
+                </mc:Fallback>
+              </mc:AlternateContent>
+            </oleObjects>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+        assert_eq!(
+            vec![
+                (
+                    "oleObject".to_string(),
+                    "../embeddings/oleObject1.bin".to_string(),
+                    String::new()
+                ),
+                (
+                    "image".to_string(),
+                    "../media/image1.png".to_string(),
+                    String::new()
+                ),
+            ],
+            worksheet.ole_object_relationships
+        );
+    }
+
+    #[test]
+    fn copy_and_move_ranges() {
+        let mut worksheet = Worksheet::new();
+        let bold = Format::new().set_bold();
+
+        worksheet.write_with_format(0, 0, "Total", &bold).unwrap();
+        worksheet.write_number(1, 0, 1).unwrap();
+        worksheet
+            .write_formula(2, 0, Formula::new("=A2+$A$2").set_result("2"))
+            .unwrap();
+        worksheet.merge_range(3, 0, 3, 1, "Merged", &bold).unwrap();
+        worksheet.insert_note(1, 0, &Note::new("Note")).unwrap();
+        worksheet
+            .add_conditional_format(0, 0, 9, 0, &ConditionalFormatBlank::new())
+            .unwrap();
+
+        // Copy the range with all the options.
+        worksheet.copy_range(0, 0, 3, 1, 1, 3).unwrap();
+
+        assert_eq!(
+            worksheet.cell_value(1, 3),
+            Some(CellValue::String("Total".to_string()))
+        );
+        assert_eq!(worksheet.cell_format(1, 3), Some(bold.clone()));
+        assert_eq!(
+            worksheet.cell_value(3, 3),
+            Some(CellValue::Formula {
+                formula: "D3+$A$2".to_string(),
+                result: "2".to_string()
+            })
+        );
+        assert_eq!(worksheet.merged_ranges[1].to_range_string(), "D5:E5");
+        assert!(worksheet.notes[&2].contains_key(&3));
+        assert_eq!(
+            worksheet.conditional_format_order,
+            vec!["A1:A10".to_string(), "D2:D5".to_string()]
+        );
+
+        // Copy the values only, with the rows and columns transposed.
+        let options = PasteOptions::new()
+            .set_type(PasteType::Values)
+            .set_transpose(true);
+        worksheet
+            .copy_range_with_options(0, 0, 2, 0, 10, 0, &options)
+            .unwrap();
+
+        assert_eq!(worksheet.cell_value(10, 1), Some(CellValue::Number(1.0)));
+        assert_eq!(worksheet.cell_value(10, 2), Some(CellValue::Number(2.0)));
+        assert_eq!(worksheet.cell_format(10, 0), None);
+
+        // Copy the formats only.
+        let options = PasteOptions::new().set_type(PasteType::Formats);
+        worksheet
+            .copy_range_with_options(0, 0, 0, 0, 10, 1, &options)
+            .unwrap();
+
+        assert_eq!(worksheet.cell_value(10, 1), Some(CellValue::Number(1.0)));
+        assert_eq!(worksheet.cell_format(10, 1), Some(bold.clone()));
+
+        // Move a range. The reference to the moved cell is adjusted.
+        worksheet.move_range(1, 3, 4, 4, 20, 0).unwrap();
+
+        assert_eq!(worksheet.cell_value(1, 3), None);
+        assert!(!worksheet.notes[&2].contains_key(&3));
+        assert_eq!(
+            worksheet.cell_value(22, 0),
+            Some(CellValue::Formula {
+                formula: "A22+$A$2".to_string(),
+                result: "2".to_string()
+            })
+        );
+        assert_eq!(worksheet.merged_ranges[1].to_range_string(), "A24:B24");
+
+        // Internal hyperlinks to the moved cells are adjusted.
+        worksheet.write_url(30, 0, "internal:A23").unwrap();
+        worksheet.move_range(22, 0, 22, 0, 25, 5).unwrap();
+        assert_eq!(worksheet.hyperlinks[&(30, 0)].anchor, "F26");
+
+        // Paste into another worksheet.
+        let data = worksheet.copy_range_data(0, 0, 3, 1).unwrap();
+        let mut worksheet2 = Worksheet::new();
+        worksheet2
+            .paste_range(0, 0, &data, &PasteOptions::new())
+            .unwrap();
+
+        assert_eq!(worksheet2.cell_format(0, 0), Some(bold.clone()));
+        assert_eq!(worksheet2.merged_ranges[0].to_range_string(), "A4:B4");
+
+        // Check the limits and merged range overlaps.
+        let result = worksheet.copy_range(0, 0, 3, 1, ROW_MAX - 1, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let options = PasteOptions::new();
+        let result = worksheet2.paste_range(RowNum::MAX, 0, &data, &options);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet2.paste_range(0, ColNum::MAX, &data, &options);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.copy_range(0, 0, 1, 0, 3, 1);
+        assert!(matches!(result, Err(XlsxError::MergeRangeOverlaps(_, _))));
+    }
+
+    #[test]
+    fn split_panes_and_selections() {
+        let mut worksheet = Worksheet::new();
+
+        // Sizes outside the worksheet are ignored.
+        worksheet.set_split_panes(u32::MAX, 0);
+        worksheet.set_split_panes(0, u32::MAX);
+        assert_eq!(worksheet.panes.split_size, (0, 0));
+
+        worksheet.set_split_panes(100, 128);
+        worksheet.set_selection_ranges(&[(0, 0, 0, 1)]).unwrap();
+        worksheet
+            .set_pane_selection(PanePosition::BottomRight, &[(7, 4, 5, 3), (9, 3, 9, 3)])
+            .unwrap();
+
+        let result = worksheet.set_selection_ranges(&[]);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        worksheet.write_sheet_views();
+
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <sheetViews>
+              <sheetView workbookViewId="0">
+                <pane xSplit="2310" ySplit="1800" topLeftCell="C6" activePane="bottomRight" state="split"/>
+                <selection pane="topRight" activeCell="C1" sqref="C1"/>
+                <selection pane="bottomLeft" activeCell="A6" sqref="A6"/>
+                <selection pane="bottomRight" activeCell="E8" sqref="D6:E8 D10"/>
+                <selection activeCell="A1" sqref="A1:B1"/>
+              </sheetView>
+            </sheetViews>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn sheet_view_display_options() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.set_show_formulas(true);
+        worksheet.set_screen_headings(false);
+        worksheet.set_show_zero_values(false);
+        worksheet.set_page_layout_ruler(false);
+        worksheet.set_show_outline_symbols(false);
+        worksheet.set_screen_gridlines_color(Color::Red);
+
+        worksheet.write_sheet_views();
+
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <sheetViews>
+              <sheetView showFormulas="1" showRowColHeaders="0" showZeros="0" showRuler="0" showOutlineSymbols="0" defaultGridColor="0" colorId="10" workbookViewId="0"/>
+            </sheetViews>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn custom_sheet_views() {
+        let mut worksheet = Worksheet::new();
+
+        let view1 = CustomView::new("Edit")
+            .set_zoom(150)
+            .set_show_zero_values(false)
+            .set_top_left_cell(10, 2);
+
+        let view2 = CustomView::new("Print")
+            .set_view_page_layout()
+            .set_landscape()
+            .set_print_gridlines(true)
+            .set_header("&C&[Tab]")
+            .set_footer("&LSales && Costs&R&[Page]");
+
+        worksheet.add_custom_view(&view1);
+        worksheet.add_custom_view(&view2);
+        worksheet.write_custom_sheet_views();
+
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(&format!(
+            r#"
+            <customSheetViews>
+              <customSheetView guid="{}" scale="150" zeroValues="0" topLeftCell="C11">
+                <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+              </customSheetView>
+              <customSheetView guid="{}" view="pageLayout">
+                <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+                <printOptions gridLines="1"/>
+                <pageSetup orientation="landscape"/>
+                <headerFooter>
+                  <oddHeader>&amp;C&amp;A</oddHeader>
+                  <oddFooter>&amp;LSales &amp;&amp; Costs&amp;R&amp;P</oddFooter>
+                </headerFooter>
+              </customSheetView>
+            </customSheetViews>
+            "#,
+            view1.guid(),
+            view2.guid()
+        ));
+
+        assert_eq!(expected, got);
+    }
+
     #[test]
     fn test_calculate_spans_1() {
         let mut worksheet = Worksheet::new();
@@ -653,6 +1803,53 @@ mod worksheet_tests {
         assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
     }
 
+    #[test]
+    fn merge_range_with_data() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::default();
+        let bold = Format::new().set_bold();
+
+        worksheet
+            .merge_range_with_data(0, 0, 0, 1, 123, &format)
+            .unwrap();
+        worksheet
+            .merge_range_with_data(1, 0, 1, 1, Formula::new("=A1*2"), &format)
+            .unwrap();
+        worksheet
+            .merge_range_with_rich_string(
+                2,
+                0,
+                2,
+                1,
+                &[(&bold, "Rich"), (&format, " text")],
+                &format,
+            )
+            .unwrap();
+
+        assert_eq!(worksheet.cell_value(0, 0), Some(CellValue::Number(123.0)));
+        assert_eq!(
+            worksheet.cell_value(1, 0),
+            Some(CellValue::Formula {
+                formula: "A1*2".to_string(),
+                result: "0".to_string()
+            })
+        );
+        assert_eq!(
+            worksheet.cell_value(2, 0),
+            Some(CellValue::RichString("Rich text".to_string()))
+        );
+        assert_eq!(worksheet.cell_value(2, 1), Some(CellValue::Blank));
+        assert_eq!(worksheet.merged_ranges.len(), 3);
+
+        // Test for errors before the first cell is written.
+        let result = worksheet.merge_range_with_data(5, 5, 5, 5, 1, &format);
+        assert!(matches!(result, Err(XlsxError::MergeRangeSingleCell)));
+        assert_eq!(worksheet.cell_value(5, 5), None);
+
+        let result = worksheet.merge_range_with_rich_string(5, 1, 5, 2, &[], &format);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
     #[test]
     fn check_dimensions() {
         let mut worksheet = Worksheet::new();
@@ -722,4 +1919,220 @@ mod worksheet_tests {
             assert_worksheet_sync::<Worksheet>();
         };
     }
+
+    #[test]
+    fn autofit_formatted_numbers() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        let currency = Format::new().set_num_format("$#,##0.00");
+        let date = Format::new().set_num_format("dddd, mmmm d, yyyy");
+        let time = Format::new().set_num_format("h:mm");
+        let default_date = Format::new().set_num_format_index(14);
+
+        worksheet.write_number(0, 0, 1_234_567.0)?;
+        worksheet.write_number_with_format(0, 1, 1_234_567.0, &currency)?;
+        worksheet.write_datetime_with_format(0, 2, ExcelDateTime::from_ymd(2023, 9, 20)?, &date)?;
+        worksheet.write_datetime_with_format(0, 3, ExcelDateTime::from_hms(9, 30, 0)?, &time)?;
+        worksheet.write_datetime_with_format(
+            0,
+            4,
+            ExcelDateTime::from_ymd(2023, 1, 1)?,
+            &default_date,
+        )?;
+
+        worksheet.autofit();
+
+        // The widths in pixels of the displayed strings plus 7 pixels of padding.
+        let expected = [
+            7 * "1234567".len() as u16 + 7,
+            utility::pixel_width("$1,234,567.00") + 7,
+            utility::pixel_width("Wednesday, September 20, 2023") + 7,
+            utility::pixel_width("9:30") + 7,
+            68 + 7,
+        ];
+
+        for (col, pixels) in expected.iter().enumerate() {
+            assert_eq!(
+                ThemeFont::default().pixels_to_width(*pixels),
+                worksheet.changed_cols[&(col as ColNum)].width
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn autofit_merged_cells() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        let format = Format::default();
+
+        worksheet.write_string(0, 0, "Hello")?;
+        worksheet.merge_range(1, 0, 1, 2, "This is a long merged string", &format)?;
+
+        worksheet.autofit();
+
+        assert_eq!(
+            ThemeFont::default().pixels_to_width(utility::pixel_width("Hello") + 7),
+            worksheet.changed_cols[&0].width
+        );
+        assert!(!worksheet.changed_cols.contains_key(&1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn autofit_fonts_and_row_heights() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        let arial = Format::new().set_font_name("Arial").set_font_size(9);
+        let wrap = Format::new().set_text_wrap();
+        let rotated = Format::new().set_rotation(90);
+
+        worksheet.write_with_format(0, 0, "Hello", &arial)?;
+        worksheet.write_with_format(1, 1, "Hello\nWorld", &wrap)?;
+        worksheet.write_with_format(2, 2, "Hello", &rotated)?;
+        worksheet.write_with_format(3, 1, "Hello\nWorld", &wrap)?;
+        worksheet.set_row_height(3, 20)?;
+
+        worksheet.autofit();
+
+        // Row heights are only autofit separately.
+        assert!(!worksheet.changed_rows.contains_key(&1));
+        worksheet.autofit_rows();
+
+        // Arial 9: "Hello" is 2278 units, 27 pixels.
+        assert_eq!(
+            ThemeFont::default().pixels_to_width(27 + 7),
+            worksheet.changed_cols[&0].width
+        );
+
+        // Wrapped text is autofit to the widest line and the row to two lines.
+        assert_eq!(
+            ThemeFont::default().pixels_to_width(utility::pixel_width("World") + 7),
+            worksheet.changed_cols[&1].width
+        );
+        assert_eq!(30.0, worksheet.changed_rows[&1].height);
+
+        // Rotated text is autofit to the line height and the row to the text.
+        assert_eq!(
+            ThemeFont::default().pixels_to_width(20 + 7),
+            worksheet.changed_cols[&2].width
+        );
+        assert_eq!(24.75, worksheet.changed_rows[&2].height);
+
+        // User defined row heights aren't changed.
+        assert_eq!(20.0, worksheet.changed_rows[&3].height);
+
+        // Autofit row heights are written as custom heights.
+        worksheet.assemble_xml_file();
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        assert!(got.contains(r#"<row r="2" spans="1:3" ht="30" customHeight="1">"#));
+        assert!(got.contains(r#"<row r="4" spans="1:3" ht="20" customHeight="1">"#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cascading_formats() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_cascading_formats(true);
+
+        let red_fill = Format::new().set_background_color(Color::Red);
+        let bold = Format::new().set_bold();
+        let italic = Format::new().set_italic();
+        let border = Format::new().set_border(FormatBorder::Thin);
+        let blue_font = Format::new().set_font_color(Color::Blue);
+
+        worksheet.set_column_format(0, &blue_font)?;
+        worksheet.set_row_format(1, &red_fill)?;
+        worksheet.write_with_format(1, 0, "Hello", &bold)?;
+        worksheet.write(1, 1, "Hello")?;
+        worksheet.write(2, 2, "Hello")?;
+
+        // Ranges are layered onto existing cells, under the cell format.
+        worksheet.set_range_format(1, 0, 2, 2, &border)?;
+        worksheet.set_cell_format(1, 0, &italic)?;
+
+        // Row formats added later are layered onto existing cells.
+        worksheet.set_row_format(2, &bold)?;
+
+        let xf_format = |worksheet: &Worksheet, row, col| {
+            let xf_index = match worksheet.data_table[&row][&col] {
+                CellType::Blank { xf_index }
+                | CellType::String { xf_index, .. }
+                | CellType::Number { xf_index, .. } => xf_index,
+                _ => unreachable!(),
+            };
+            worksheet.xf_formats[xf_index as usize].clone()
+        };
+
+        let expected = bold
+            .clone()
+            .set_italic()
+            .set_border(FormatBorder::Thin)
+            .set_background_color(Color::Red)
+            .set_font_color(Color::Blue);
+        assert_eq!(expected, xf_format(&worksheet, 1, 0));
+
+        let expected = red_fill.clone().set_border(FormatBorder::Thin);
+        assert_eq!(expected, xf_format(&worksheet, 1, 1));
+
+        let expected = border.clone().set_bold();
+        assert_eq!(expected, xf_format(&worksheet, 2, 2));
+
+        // Blank cells are added for unwritten cells in the range.
+        let expected = border.clone().set_bold().set_font_color(Color::Blue);
+        assert_eq!(expected, xf_format(&worksheet, 2, 0));
+
+        // Identical merged formats are deduplicated.
+        let num_formats = worksheet.xf_formats.len();
+        worksheet.write_with_format(1, 1, 123, &Format::new())?;
+        assert_eq!(num_formats, worksheet.xf_formats.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn cascading_formats_copy_range() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_cascading_formats(true);
+
+        let bold = Format::new().set_bold();
+        let border = Format::new().set_border(FormatBorder::Thin);
+        let red_fill = Format::new().set_background_color(Color::Red);
+
+        worksheet.write_with_format(0, 0, "Hello", &bold)?;
+        worksheet.set_range_format(0, 0, 9, 1, &border)?;
+
+        // The range format is stored once for all the cells in the range.
+        assert_eq!(2, worksheet.cascading_layers.len());
+
+        // Copied and moved cells keep their cell and range format layers.
+        worksheet.copy_range(0, 0, 0, 1, 0, 3)?;
+        worksheet.move_range(1, 0, 1, 1, 1, 3)?;
+        worksheet.set_row_format(0, &red_fill)?;
+        worksheet.set_row_format(1, &red_fill)?;
+
+        let xf_format = |worksheet: &Worksheet, row, col| {
+            let xf_index = match worksheet.data_table[&row][&col] {
+                CellType::Blank { xf_index } | CellType::String { xf_index, .. } => xf_index,
+                _ => unreachable!(),
+            };
+            worksheet.xf_formats[xf_index as usize].clone()
+        };
+
+        let expected = bold
+            .clone()
+            .set_border(FormatBorder::Thin)
+            .set_background_color(Color::Red);
+        assert_eq!(expected, xf_format(&worksheet, 0, 3));
+
+        let expected = border.clone().set_background_color(Color::Red);
+        assert_eq!(expected, xf_format(&worksheet, 0, 4));
+        assert_eq!(expected, xf_format(&worksheet, 1, 3));
+
+        // The moved cells are cleared, including their format layers.
+        assert!(!worksheet.data_table[&1].contains_key(&0));
+        assert!(!worksheet.cascading_range_formats.contains_key(&(1, 0)));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,43 @@
+// Test case that compares a file generated by rust_xlsxwriter with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+use rust_xlsxwriter::{Color, Format, Workbook, XlsxError};
+
+// Test case to test cascading formats copied with a range.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_cascading_formats(true);
+
+    let format1 = Format::new().set_bold().set_foreground_color(Color::Red);
+    let italic = Format::new().set_italic();
+
+    worksheet.set_range_format(0, 0, 0, 0, &format1)?;
+    worksheet.write_string(0, 0, "Foo")?;
+
+    // The range format is copied with the cell and merged with the new format.
+    worksheet.copy_range(0, 0, 0, 0, 1, 0)?;
+    worksheet.write_string_with_format(1, 0, "Bar", &italic)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_copy_range01() {
+    let test_runner = common::TestRunner::new()
+        .set_name("format03")
+        .set_function(create_new_xlsx_file)
+        .unique("copy_range01")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
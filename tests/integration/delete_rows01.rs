@@ -0,0 +1,57 @@
+// Test case that compares a file generated by rust_xlsxwriter with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+// Test the chart ranges in a chartsheet when rows are deleted from the data
+// worksheet after the chart is added.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Add some test data for the chart(s), below 2 rows that are deleted.
+    let data = [[1, 2, 3, 4, 5], [2, 4, 6, 8, 10], [3, 6, 9, 12, 15]];
+    for (col_num, col_data) in data.iter().enumerate() {
+        for (row_num, row_data) in col_data.iter().enumerate() {
+            worksheet.write(row_num as u32 + 2, col_num as u16, *row_data)?;
+        }
+    }
+
+    let mut chart = Chart::new(ChartType::Bar);
+    chart.set_axis_ids(79858304, 79860096);
+
+    chart.add_series().set_values(("Sheet1", 2, 0, 6, 0));
+    chart.add_series().set_values(("Sheet1", 2, 1, 6, 1));
+    chart.add_series().set_values(("Sheet1", 2, 2, 6, 2));
+
+    let chartsheet = workbook.add_chartsheet();
+    chartsheet.set_active(true);
+
+    chartsheet.insert_chart(8, 4, &chart)?;
+
+    // The chart ranges in the chartsheet are adjusted for the deleted rows.
+    let worksheet = workbook.worksheet_from_index(0)?;
+    worksheet.delete_rows(0, 2)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_delete_rows01() {
+    let test_runner = common::TestRunner::new()
+        .set_name("chartsheet01")
+        .set_function(create_new_xlsx_file)
+        .unique("delete_rows01")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,39 @@
+// Test case that compares a file generated by rust_xlsxwriter with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+use rust_xlsxwriter::{ExcelError, Formula, IgnoreError, Workbook, XlsxError};
+
+// Create rust_xlsxwriter file to compare against Excel file. This uses the
+// ignore_error05 file which has a #DIV/0! formula result.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_string(0, 0, "123")?;
+    worksheet.write(1, 0, Formula::new("=1/0").set_result(ExcelError::Div0))?;
+
+    worksheet.ignore_error(0, 0, IgnoreError::NumberStoredAsText)?;
+    worksheet.ignore_error(1, 0, IgnoreError::FormulaError)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_excel_error01() {
+    let test_runner = common::TestRunner::new()
+        .set_name("ignore_error05")
+        .ignore_calc_chain()
+        .set_function(create_new_xlsx_file)
+        .unique("excel_error01")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -550,6 +550,7 @@ mod cond_format19;
 mod cond_format20;
 mod cond_format21;
 mod cond_format22;
+mod copy_range01;
 mod data_validation01;
 mod data_validation02;
 mod data_validation03;
@@ -565,6 +566,7 @@ mod defined_name01;
 mod defined_name02;
 mod defined_name03;
 mod defined_name04;
+mod delete_rows01;
 mod dynamic_array01;
 mod dynamic_array02;
 mod dynamic_array03;
@@ -591,6 +593,7 @@ mod escapes06;
 mod escapes07;
 mod escapes08;
 mod escapes09;
+mod excel_error01;
 mod firstsheet01;
 mod format01;
 mod format02;
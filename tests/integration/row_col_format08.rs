@@ -59,6 +59,30 @@ fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test to demonstrate row or column formatting with cascading formats. The
+// cell format is merged with the row and column formats.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_cascading_formats(true);
+
+    let bold = Format::new().set_bold();
+    let italic = Format::new().set_italic();
+
+    worksheet.set_row_format(0, &bold)?;
+    worksheet.write_string_with_format(0, 0, "Foo", &italic)?;
+    worksheet.set_column_format(0, &italic)?;
+
+    worksheet.write_string(0, 1, "Foo")?;
+    worksheet.write_string(1, 0, "Foo")?;
+    worksheet.write_string(1, 1, "Foo")?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_row_col_format08_1() {
     let test_runner = common::TestRunner::new()
@@ -82,3 +106,15 @@ fn test_row_col_format08_2() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_row_col_format08_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("row_col_format08")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}